@@ -285,6 +285,7 @@ mod tests {
                     .collect::<Vec<NamespaceChild>>(),
                 attributes: Default::default(),
                 is_virtual: false,
+                implements: vec![],
             });
             Ok(())
         }
@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use crate::generator::Generator;
+use crate::model::chunk;
+use crate::output::Output;
+use crate::view;
+
+/// A generator that writes out the model's [crate::model::Api] in apyxl's JSON representation,
+/// which can be parsed again with [crate::parser::Json].
+///
+/// The model is written as-is, i.e. view transforms are not applied, so that the output can be
+/// parsed back into an equivalent model.
+#[derive(Debug, Default)]
+pub struct Json {}
+
+impl Generator for Json {
+    fn generate(&mut self, model: view::Model, output: &mut dyn Output) -> Result<()> {
+        output.write_chunk(&chunk::Chunk::with_relative_file_path("api.json"))?;
+        output.write(&serde_json::to_string_pretty(model.target().api())?)?;
+        output.newline()
+    }
+}
@@ -2,12 +2,14 @@ use anyhow::Result;
 use std::fmt::Debug;
 
 pub use dbg::Dbg;
+pub use json::Json;
 pub use rust::Rust;
 
 use crate::output::Output;
 use crate::view;
 
 mod dbg;
+mod json;
 mod rust;
 mod util;
 
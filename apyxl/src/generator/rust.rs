@@ -4,12 +4,14 @@ use anyhow::{anyhow, Result};
 use itertools::Itertools;
 
 use crate::generator::{util, Generator};
-use crate::model::{attributes, Chunk, Comment, Semantics};
+use crate::model::{
+    attributes, well_known, Chunk, Comment, Deprecation, Docs, Semantics, TupleElement, Value,
+};
 use crate::output::{Indented, Output};
 use crate::rust_util;
 use crate::view::{
-    Attributes, Dto, EntityId, Enum, EnumValue, Field, Model, Namespace, Rpc, SubView, Type,
-    TypeAlias, TypeRef,
+    Attributes, Dto, EntityId, Enum, EnumValue, Field, FlattenInheritance, Interface, Model,
+    Namespace, Rpc, SubView, Transformer, Type, TypeAlias, TypeRef, Union, Variant, VariantPayload,
 };
 
 #[derive(Debug, Default)]
@@ -21,6 +23,9 @@ impl Generator for Rust {
     fn generate(&mut self, model: Model, output: &mut dyn Output) -> Result<()> {
         let mut o = Indented::new(output, INDENT);
 
+        // Rust has no inheritance.
+        let model = model.with_dto_transform(FlattenInheritance);
+
         // Write combined API w/out chunks.
         write_namespace_contents(model.api(), &mut o)?;
 
@@ -94,6 +99,11 @@ fn write_namespace(namespace: Namespace, o: &mut Indented) -> Result<()> {
 }
 
 fn write_namespace_contents(namespace: Namespace, o: &mut Indented) -> Result<()> {
+    for field in namespace.fields().filter(|field| field.value().is_some()) {
+        write_const(field, o)?;
+        o.newline()?;
+    }
+
     for alias in namespace.ty_aliases() {
         write_alias(alias, o)?;
         o.newline()?;
@@ -109,6 +119,16 @@ fn write_namespace_contents(namespace: Namespace, o: &mut Indented) -> Result<()
         o.newline()?;
     }
 
+    for union in namespace.unions() {
+        write_union(union, o)?;
+        o.newline()?;
+    }
+
+    for interface in namespace.interfaces() {
+        write_interface(interface, o)?;
+        o.newline()?;
+    }
+
     for dto in namespace.dtos() {
         write_dto(dto, o)?;
         o.newline()?;
@@ -127,6 +147,7 @@ fn write_alias(alias: TypeAlias, o: &mut Indented) -> Result<()> {
 
     o.write("pub type ")?;
     o.write(&alias.name())?;
+    write_type_params(alias.type_params(), o)?;
     o.write(" = ")?;
     write_type(alias.target_ty(), o)?;
     o.write_char(';')?;
@@ -147,12 +168,21 @@ fn write_dto(dto: Dto, o: &mut Indented) -> Result<()> {
 
     write_block_end(o)?;
 
+    write_default_impl(dto, o)?;
+
     if let Some(ns) = dto.namespace() {
-        o.write("impl ")?;
+        o.write("impl")?;
+        write_type_params(dto.type_params(), o)?;
+        o.write_char(' ')?;
         o.write(&dto.name())?;
+        write_type_params(dto.type_params(), o)?;
         o.write(" {")?;
 
         o.indent(1);
+        for field in ns.fields().filter(|field| field.value().is_some()) {
+            o.newline()?;
+            write_const(field, o)?;
+        }
         for rpc in ns.rpcs() {
             o.newline()?;
             write_rpc(rpc, o)?;
@@ -163,39 +193,249 @@ fn write_dto(dto: Dto, o: &mut Indented) -> Result<()> {
         o.newline()?;
     }
 
+    // An empty impl won't compile if the trait has required methods, so implemented interfaces
+    // are only noted.
+    for interface_id in dto.implements() {
+        o.write("// implements: ")?;
+        write_entity_id(interface_id, o)?;
+        o.newline()?;
+    }
+
     Ok(())
 }
 
+/// Fields with a value as `pub const NAME: Type = value;`. `String` consts are written as `&str`.
+fn write_const(field: Field, o: &mut Indented) -> Result<()> {
+    let value = field
+        .value()
+        .ok_or_else(|| anyhow!("const field '{}' must have a value", field.name()))?;
+    write_attributes(&field.attributes(), o)?;
+
+    o.write("pub const ")?;
+    o.write(&field.name())?;
+    o.write(": ")?;
+    if let Type::String = field.ty().value() {
+        o.write("&str")?;
+    } else {
+        write_type(field.ty(), o)?;
+    }
+    o.write(" = ")?;
+    write_value(value, field.ty(), o)?;
+    o.write_char(';')?;
+    o.newline()
+}
+
+fn write_value(value: &Value, ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    if let Type::Optional(ty) = ty.value() {
+        o.write("Some(")?;
+        write_value(value, *ty, o)?;
+        return o.write_char(')');
+    }
+    // `rust_decimal::Decimal` has no literals, so numbers are written with its macro.
+    if matches!(ty.value(), Type::User(name) if name == well_known::DECIMAL) {
+        let number = match value {
+            Value::Int(n) => Some(n.to_string()),
+            Value::UInt(n) => Some(n.to_string()),
+            Value::Float(n) => Some(format!("{:?}", n)),
+            _ => None,
+        };
+        if let Some(number) = number {
+            return o.write(&format!("rust_decimal_macros::dec!({})", number));
+        }
+    }
+    match value {
+        Value::Int(n) => match ty.value() {
+            Type::F8 | Type::F16 | Type::F32 | Type::F64 | Type::F128 => {
+                o.write(&format!("{:?}", *n as f64))
+            }
+            _ => o.write(&n.to_string()),
+        },
+        Value::UInt(n) => match ty.value() {
+            Type::F8 | Type::F16 | Type::F32 | Type::F64 | Type::F128 => {
+                o.write(&format!("{:?}", *n as f64))
+            }
+            _ => o.write(&n.to_string()),
+        },
+        Value::Float(n) => o.write(&format!("{:?}", n)),
+        Value::Bool(b) => o.write(&b.to_string()),
+        // Debug formatting writes a quoted literal with Rust escapes.
+        Value::Char(c) => o.write(&format!("{:?}", c)),
+        // Debug formatting writes a quoted literal with Rust escapes.
+        Value::String(s) => o.write(&format!("{:?}", s)),
+        Value::EnumValue(name) => {
+            write_inner_type(ty, o)?;
+            o.write("::")?;
+            o.write(name)
+        }
+    }
+}
+
+/// Rust has no default values, so DTOs with field defaults implement `Default` with them. Fields
+/// without a default use their type's `Default`.
+fn write_default_impl(dto: Dto, o: &mut Indented) -> Result<()> {
+    if dto.fields().all(|field| field.default().is_none()) {
+        return Ok(());
+    }
+    o.write("impl")?;
+    if !dto.type_params().is_empty() {
+        o.write_char('<')?;
+        util::write_joined(dto.type_params(), ", ", o, |name, o| {
+            o.write(name)?;
+            o.write(": Default")
+        })?;
+        o.write_char('>')?;
+    }
+    o.write(" Default for ")?;
+    o.write(&dto.name())?;
+    write_type_params(dto.type_params(), o)?;
+    o.write_char(' ')?;
+    write_block_start(o)?;
+    o.write("fn default() -> Self ")?;
+    write_block_start(o)?;
+    o.write("Self ")?;
+    write_block_start(o)?;
+    for field in dto.fields() {
+        o.write(&field.name())?;
+        o.write(": ")?;
+        match field.default() {
+            Some(default) => write_default(default, field.ty(), o)?,
+            None => o.write("Default::default()")?,
+        }
+        o.write_char(',')?;
+        o.newline()?;
+    }
+    write_block_end(o)?;
+    write_block_end(o)?;
+    write_block_end(o)
+}
+
+/// Like [write_value], but `String` values are converted from their `&str` literals.
+fn write_default(value: &Value, ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    match ty.value() {
+        Type::Optional(ty) => {
+            o.write("Some(")?;
+            write_default(value, *ty, o)?;
+            o.write_char(')')
+        }
+        Type::String => {
+            write_value(value, ty, o)?;
+            o.write(".to_string()")
+        }
+        _ => write_value(value, ty, o),
+    }
+}
+
 fn write_rpc(rpc: Rpc, o: &mut Indented) -> Result<()> {
     write_attributes(&rpc.attributes(), o)?;
 
-    o.write("pub fn ")?;
+    o.write("pub ")?;
+    write_rpc_signature(rpc, false, o)?;
+    o.write(" {}")?;
+    o.newline()
+}
+
+/// Writes e.g. `fn name(param: Type) -> ReturnType` without a body. With `receiver`, non-static
+/// [Rpc]s take `&self` as their first param.
+fn write_rpc_signature(rpc: Rpc, receiver: bool, o: &mut Indented) -> Result<()> {
+    let kind = rpc.kind();
+    if kind.is_async {
+        o.write("async ")?;
+    }
+    o.write("fn ")?;
     o.write(&rpc.name())?;
+    write_type_params(rpc.type_params(), o)?;
 
+    let has_receiver = receiver && !rpc.is_static();
     o.write_char('(')?;
     o.indent(1);
+    if has_receiver {
+        o.newline()?;
+        o.write("&self,")?;
+    }
     for field in rpc.params() {
         o.newline()?;
-        write_field(field, o)?;
+        if kind.streaming.is_client() {
+            write_stream_param(field, o)?;
+        } else {
+            write_field(field, o)?;
+        }
     }
     o.indent(-1);
 
-    if rpc.params().count() > 0 {
+    if has_receiver || rpc.params().count() > 0 {
         o.newline()?;
     }
 
     o.write_char(')')?;
 
-    if let Some(return_type) = rpc.return_type() {
+    let error_type = rpc.error_type();
+    if rpc.return_type().is_some() || kind.streaming.is_server() || error_type.is_some() {
         o.write(" -> ")?;
-        write_type(return_type, o)?;
+    }
+    if error_type.is_some() {
+        o.write("Result<")?;
+    }
+    match (rpc.return_type(), kind.streaming.is_server()) {
+        (Some(return_type), false) => write_type(return_type, o)?,
+        (Some(return_type), true) => write_stream(return_type, o)?,
+        (None, true) => o.write("impl futures::Stream<Item = ()>")?,
+        (None, false) if error_type.is_some() => o.write("()")?,
+        (None, false) => {}
+    }
+    if let Some(error_type) = error_type {
+        o.write(", ")?;
+        write_type(error_type, o)?;
+        o.write_char('>')?;
     }
 
-    o.write(" {}")?;
-    o.newline()
+    Ok(())
+}
+
+/// Writes e.g. `name: impl futures::Stream<Item = Type>,` for client streaming [Rpc]s.
+fn write_stream_param(field: Field, o: &mut dyn Output) -> Result<()> {
+    write_attributes(&field.attributes(), o)?;
+    o.write(&field.name())?;
+    o.write(": ")?;
+    write_stream(field.ty(), o)?;
+    o.write_char(',')
+}
+
+fn write_stream(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    o.write("impl futures::Stream<Item = ")?;
+    write_type(ty, o)?;
+    o.write_char('>')
+}
+
+fn write_interface(interface: Interface, o: &mut Indented) -> Result<()> {
+    write_attributes(&interface.attributes(), o)?;
+
+    o.write("pub trait ")?;
+    o.write(&interface.name())?;
+
+    let super_interfaces = interface.super_interfaces().collect_vec();
+    if !super_interfaces.is_empty() {
+        o.write(": ")?;
+        util::write_joined(&super_interfaces, " + ", o, |id, o| write_entity_id(*id, o))?;
+    }
+
+    o.write_char(' ')?;
+    write_block_start(o)?;
+
+    for rpc in interface.rpcs() {
+        write_attributes(&rpc.attributes(), o)?;
+        write_rpc_signature(rpc, true, o)?;
+        o.write_char(';')?;
+        o.newline()?;
+    }
+
+    write_block_end(o)
 }
 
 fn write_enum(en: Enum, o: &mut Indented) -> Result<()> {
+    if en.is_flags() {
+        return write_flags(en, o);
+    }
+
     write_attributes(&en.attributes(), o)?;
 
     o.write("pub enum ")?;
@@ -211,6 +451,51 @@ fn write_enum(en: Enum, o: &mut Indented) -> Result<()> {
     write_block_end(o)
 }
 
+fn write_flags(en: Enum, o: &mut Indented) -> Result<()> {
+    o.write("bitflags::bitflags! ")?;
+    write_block_start(o)?;
+    write_attributes(&en.attributes(), o)?;
+
+    o.write("pub struct ")?;
+    o.write(&en.name())?;
+    o.write(": ")?;
+    o.write(flags_repr(en.underlying_type()))?;
+    o.write_char(' ')?;
+    write_block_start(o)?;
+
+    for value in en.values() {
+        write_attributes(&value.attributes(), o)?;
+        o.write("const ")?;
+        o.write(&value.name())?;
+        o.write(" = ")?;
+        o.write(&value.number().to_string())?;
+        o.write_char(';')?;
+        o.newline()?;
+    }
+
+    write_block_end(o)?;
+    write_block_end(o)
+}
+
+/// The integer type backing a `bitflags!` struct. Falls back to `u64` if the enum has no integer
+/// underlying type.
+fn flags_repr(ty: Option<&crate::model::Type>) -> &'static str {
+    use crate::model::Type;
+    match ty {
+        Some(Type::U8) => "u8",
+        Some(Type::U16) => "u16",
+        Some(Type::U32) => "u32",
+        Some(Type::U128) => "u128",
+        Some(Type::USIZE) => "usize",
+        Some(Type::I8) => "i8",
+        Some(Type::I16) => "i16",
+        Some(Type::I32) => "i32",
+        Some(Type::I64) => "i64",
+        Some(Type::I128) => "i128",
+        _ => "u64",
+    }
+}
+
 fn write_enum_value(value: EnumValue, o: &mut dyn Output) -> Result<()> {
     write_attributes(&value.attributes(), o)?;
 
@@ -220,9 +505,51 @@ fn write_enum_value(value: EnumValue, o: &mut dyn Output) -> Result<()> {
     o.write_char(',')
 }
 
+fn write_union(union: Union, o: &mut Indented) -> Result<()> {
+    write_attributes(&union.attributes(), o)?;
+
+    o.write("pub enum ")?;
+    o.write(&union.name())?;
+    o.write_char(' ')?;
+    write_block_start(o)?;
+
+    for variant in union.variants() {
+        write_variant(variant, o)?;
+        o.newline()?;
+    }
+
+    write_block_end(o)
+}
+
+fn write_variant(variant: Variant, o: &mut Indented) -> Result<()> {
+    write_attributes(&variant.attributes(), o)?;
+
+    o.write(&variant.name())?;
+    match variant.payload() {
+        None => {}
+        Some(VariantPayload::Tuple(tys)) => {
+            o.write_char('(')?;
+            util::write_joined(&tys, ", ", o, |ty, o| write_type(*ty, o))?;
+            o.write_char(')')?;
+        }
+        Some(VariantPayload::Struct(fields)) => {
+            o.write_char(' ')?;
+            write_block_start(o)?;
+            for field in fields {
+                write_field(field, o)?;
+                o.newline()?;
+            }
+            o.indent(-1);
+            o.write_char('}')?;
+        }
+    }
+    o.write_char(',')
+}
+
 fn write_dto_start(dto: Dto, o: &mut Indented) -> Result<()> {
     o.write("pub struct ")?;
     o.write(&dto.name())?;
+    write_type_params(dto.type_params(), o)?;
     o.write_char(' ')?;
     write_block_start(o)
 }
@@ -254,6 +581,11 @@ fn write_field(field: Field, o: &mut dyn Output) -> Result<()> {
 
 fn write_param(field: Field, o: &mut dyn Output) -> Result<()> {
     write_attributes(&field.attributes(), o)?;
+    if let Some(default) = field.default() {
+        o.write("// default: ")?;
+        write_value(default, field.ty(), o)?;
+        o.newline()?;
+    }
 
     o.write(&field.name())?;
     o.write(": ")?;
@@ -262,10 +594,102 @@ fn write_param(field: Field, o: &mut dyn Output) -> Result<()> {
 
 fn write_attributes(attributes: &Attributes, o: &mut dyn Output) -> Result<()> {
     write_comments(&attributes.comments(), o)?;
+    if let Some(docs) = attributes.docs() {
+        write_docs(docs, o)?;
+    }
+    if let Some(deprecation) = attributes.deprecation() {
+        write_deprecation(deprecation, o)?;
+    }
     write_user_attributes(&attributes.user(), o)?;
     Ok(())
 }
 
+/// Writes rustdoc with the conventional `# Arguments`, `# Returns`, `# Examples`, and `# See also`
+/// sections.
+fn write_docs(docs: &Docs, o: &mut dyn Output) -> Result<()> {
+    let mut sections = vec![];
+    let lines = |comment: &Comment| comment.lines().map(|line| line.to_string()).collect_vec();
+    if !docs.summary.is_empty() {
+        sections.push(lines(&docs.summary));
+    }
+    if !docs.description.is_empty() {
+        sections.push(lines(&docs.description));
+    }
+    if !docs.params.is_empty() {
+        let mut section = vec!["# Arguments".to_string(), "".to_string()];
+        for param in &docs.params {
+            for (i, line) in param.comment.lines().enumerate() {
+                match i {
+                    0 => section.push(format!("* `{}` - {}", param.name, line)),
+                    _ => section.push(format!("  {}", line)),
+                }
+            }
+        }
+        sections.push(section);
+    }
+    if !docs.returns.is_empty() {
+        let mut section = vec!["# Returns".to_string(), "".to_string()];
+        section.append(&mut lines(&docs.returns));
+        sections.push(section);
+    }
+    if !docs.examples.is_empty() {
+        let mut section = vec!["# Examples".to_string()];
+        for example in &docs.examples {
+            section.push("".to_string());
+            section.push("```".to_string());
+            section.append(&mut lines(example));
+            section.push("```".to_string());
+        }
+        sections.push(section);
+    }
+    if !docs.see_also.is_empty() {
+        let mut section = vec!["# See also".to_string(), "".to_string()];
+        for name in &docs.see_also {
+            section.push(format!("* `{}`", name));
+        }
+        sections.push(section);
+    }
+    let blank = vec![String::new()];
+    let lines = Itertools::intersperse(sections.iter(), &blank).flatten();
+    for line in lines {
+        o.write("///")?;
+        if !line.is_empty() {
+            o.write_char(' ')?;
+            o.write(line)?;
+        }
+        o.newline()?;
+    }
+    Ok(())
+}
+
+/// Rust's `#[deprecated]` has no replacement so it's folded into the note.
+fn write_deprecation(deprecation: &Deprecation, o: &mut dyn Output) -> Result<()> {
+    let note = match (&deprecation.note, &deprecation.replacement) {
+        (Some(note), Some(replacement)) => Some(format!("{} Use `{}` instead.", note, replacement)),
+        (None, Some(replacement)) => Some(format!("Use `{}` instead.", replacement)),
+        (note, None) => note.as_ref().map(|note| note.to_string()),
+    };
+    let data = [
+        deprecation
+            .since
+            .as_ref()
+            .map(|since| format!("since = {:?}", since)),
+        note.map(|note| format!("note = {:?}", note)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect_vec();
+    o.write("#[deprecated")?;
+    if !data.is_empty() {
+        o.write_char('(')?;
+        o.write(&data.join(", "))?;
+        o.write_char(')')?;
+    }
+    o.write_char(']')?;
+    o.newline()?;
+    Ok(())
+}
+
 fn write_comments(comments: &[Comment], o: &mut dyn Output) -> Result<()> {
     util::write_joined(comments, "\n", o, |comment, o| {
         for line in comment.lines() {
@@ -302,22 +726,62 @@ fn write_user_attribute(
     }
     o.write_char('(')?;
     util::write_joined(data, ", ", o, |data, o| {
-        match data.key {
+        match &data.key {
             None => {}
             Some(key) => {
                 o.write(key)?;
                 o.write(" = ")?;
             }
         }
-        o.write(data.value)
+        o.write(&data.value)
     })?;
     o.write_char(')')?;
     Ok(())
 }
 
 fn write_type(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
-    write_semantics(ty.semantics(), o)?;
-    write_inner_type(ty, o)
+    match ty.semantics() {
+        Semantics::Value => write_inner_type(ty, o),
+        Semantics::Ref => {
+            o.write_char('&')?;
+            write_pointee(ty, o)
+        }
+        Semantics::Mut => {
+            o.write("&mut ")?;
+            write_pointee(ty, o)
+        }
+        Semantics::Boxed => write_pointer("Box", ty, o),
+        Semantics::Shared => write_pointer("std::sync::Arc", ty, o),
+        Semantics::Slice => write_slice(ty, o),
+    }
+}
+
+/// Types behind a reference or pointer, where string views are unsized `str`.
+fn write_pointee(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    match ty.value() {
+        Type::StringView => o.write("str"),
+        _ => write_inner_type(ty, o),
+    }
+}
+
+fn write_pointer(pointer: &str, ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    o.write(pointer)?;
+    o.write_char('<')?;
+    write_pointee(ty, o)?;
+    o.write_char('>')
+}
+
+fn write_slice(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    o.write_char('&')?;
+    match ty.value() {
+        Type::Bytes => o.write("[u8]"),
+        Type::Array(ty) => {
+            o.write_char('[')?;
+            write_type(*ty, o)?;
+            o.write_char(']')
+        }
+        _ => write_pointee(ty, o),
+    }
 }
 
 fn write_inner_type(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
@@ -342,10 +806,14 @@ fn write_inner_type(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
         Type::StringView => o.write("&str"),
         Type::String => o.write("String"),
         Type::Bytes => o.write("Vec<u8>"),
-        // For the sake of example, just write the user type name.
-        Type::User(s) => o.write(s),
+        Type::User(s) => write_user_type(s, o),
         Type::Api(id) => write_entity_id(id, o),
+        Type::Generic { ty, args } => write_generic(ty, args, o),
+        Type::TypeParam(name) => o.write(name),
         Type::Array(array_ty) => write_vec(*array_ty, o),
+        Type::Set(set_ty) => write_set(*set_ty, o),
+        Type::FixedArray { ty, len } => write_fixed_array(*ty, len, o),
+        Type::Tuple(elements) => write_tuple(elements, o),
         Type::Map { key, value } => write_map(*key, *value, o),
         Type::Optional(opt_ty) => write_option(*opt_ty, o),
         Type::Function { params, return_ty } => write_function(
@@ -356,6 +824,18 @@ fn write_inner_type(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
     }
 }
 
+fn write_user_type(name: &str, o: &mut dyn Output) -> Result<()> {
+    match name {
+        well_known::UUID => o.write("uuid::Uuid"),
+        well_known::TIMESTAMP => o.write("std::time::SystemTime"),
+        well_known::DURATION => o.write("std::time::Duration"),
+        well_known::DECIMAL => o.write("rust_decimal::Decimal"),
+        well_known::CHAR => o.write("char"),
+        // For the sake of example, just write the user type name.
+        _ => o.write(name),
+    }
+}
+
 fn write_entity_id(entity_id: EntityId, o: &mut dyn Output) -> Result<()> {
     // Fully qualify everything by crate.
     o.write("crate::")?;
@@ -366,12 +846,21 @@ fn write_entity_id(entity_id: EntityId, o: &mut dyn Output) -> Result<()> {
     )
 }
 
-fn write_semantics(semantics: Semantics, o: &mut dyn Output) -> Result<()> {
-    match semantics {
-        Semantics::Value => Ok(()),
-        Semantics::Ref => o.write_char('&'),
-        Semantics::Mut => o.write("&mut "),
+fn write_generic(entity_id: EntityId, args: Vec<TypeRef>, o: &mut dyn Output) -> Result<()> {
+    write_entity_id(entity_id, o)?;
+    o.write_char('<')?;
+    util::write_joined(&args, ", ", o, |arg, o| write_type(*arg, o))?;
+    o.write_char('>')
+}
+
+/// Writes e.g. `<T, U>`, or nothing if there are no `type_params`.
+fn write_type_params(type_params: &[&str], o: &mut dyn Output) -> Result<()> {
+    if type_params.is_empty() {
+        return Ok(());
     }
+    o.write_char('<')?;
+    util::write_joined_str(type_params, ", ", o)?;
+    o.write_char('>')
 }
 
 fn write_vec(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
@@ -380,6 +869,30 @@ fn write_vec(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
     o.write_char('>')
 }
 
+fn write_set(ty: TypeRef, o: &mut dyn Output) -> Result<()> {
+    o.write("HashSet<")?;
+    write_type(ty, o)?;
+    o.write_char('>')
+}
+
+fn write_fixed_array(ty: TypeRef, len: usize, o: &mut dyn Output) -> Result<()> {
+    o.write_char('[')?;
+    write_type(ty, o)?;
+    o.write("; ")?;
+    o.write(&len.to_string())?;
+    o.write_char(']')
+}
+
+/// Rust tuples can't name their elements, so names are dropped.
+fn write_tuple(elements: Vec<TupleElement<TypeRef, &str>>, o: &mut dyn Output) -> Result<()> {
+    o.write_char('(')?;
+    util::write_joined(&elements, ", ", o, |element, o| write_type(element.ty, o))?;
+    if elements.len() == 1 {
+        o.write_char(',')?;
+    }
+    o.write_char(')')
+}
+
 fn write_map(key: TypeRef, value: TypeRef, o: &mut dyn Output) -> Result<()> {
     o.write("HashMap<")?;
     write_type(key, o)?;
@@ -424,11 +937,12 @@ mod tests {
     use std::borrow::Cow;
 
     use crate::generator::rust::{
-        write_dto, write_entity_id, write_enum, write_field, write_rpc, INDENT,
+        write_attributes, write_const, write_dto, write_entity_id, write_enum, write_field,
+        write_interface, write_rpc, write_union, INDENT,
     };
     use crate::generator::util::tests::{assert_e2e, assert_output, assert_output_slice, indent};
     use crate::generator::Rust;
-    use crate::model::{attributes, Attributes, Semantics, Type};
+    use crate::model::{attributes, well_known, Attributes, Semantics, Type};
     use crate::output::Indented;
     use crate::view::Transforms;
     use crate::{model, view};
@@ -492,30 +1006,40 @@ pub mod ns0 {
                     view::Dto::new(
                         &model::Dto {
                             name: "DtoName",
+                            type_params: vec![],
                             fields: vec![
                                 model::Field {
                                     name: "field0",
                                     ty: model::TypeRef::new_api("Type0", Semantics::Value)?,
                                     attributes: test_attributes(),
                                     is_static: false,
+                                    value: None,
+                                    default: None,
                                 },
                                 model::Field {
                                     name: "field1",
                                     ty: model::TypeRef::new_api("Type1", Semantics::Ref)?,
                                     attributes: test_attributes(),
                                     is_static: false,
+                                    value: None,
+                                    default: None,
                                 },
                                 model::Field {
                                     name: "field2",
                                     ty: model::TypeRef::new_api("Type2", Semantics::Mut)?,
                                     attributes: test_attributes(),
                                     is_static: false,
+                                    value: None,
+                                    default: None,
                                 },
                             ],
                             rpcs: vec![],
+                            extends: vec![],
+                            implements: vec![],
                             attributes: test_attributes(),
                             namespace: None,
                         },
+                        &model::Namespace::default(),
                         &Transforms::default(),
                     ),
                     &mut Indented::new(o, INDENT),
@@ -543,23 +1067,30 @@ pub mod ns0 {
                     view::Rpc::new(
                         &model::Rpc {
                             name: Cow::Borrowed("rpc_name"),
+                            type_params: vec![],
                             params: vec![
                                 model::Field {
                                     name: "param0",
                                     ty: model::TypeRef::new_api("Type0", Semantics::Value)?,
                                     attributes: test_attributes(),
                                     is_static: false,
+                                    value: None,
+                                    default: None,
                                 },
                                 model::Field {
                                     name: "param1",
                                     ty: model::TypeRef::new_api("Type1", Semantics::Ref)?,
                                     attributes: test_attributes(),
                                     is_static: false,
+                                    value: None,
+                                    default: None,
                                 },
                             ],
                             return_type: None,
+                            error_type: None,
                             attributes: test_attributes(),
                             is_static: true,
+                            kind: model::RpcKind::default(),
                         },
                         &Transforms::default(),
                     ),
@@ -586,13 +1117,16 @@ pub mod ns0 {
                     view::Rpc::new(
                         &model::Rpc {
                             name: Cow::Borrowed("rpc_name"),
+                            type_params: vec![],
                             params: vec![],
                             return_type: Some(model::TypeRef::new_api(
                                 "ReturnType",
                                 Semantics::Ref,
                             )?),
+                            error_type: None,
                             attributes: Default::default(),
                             is_static: true,
+                            kind: model::RpcKind::default(),
                         },
                         &Transforms::default(),
                     ),
@@ -603,6 +1137,155 @@ pub mod ns0 {
         )
     }
 
+    #[test]
+    fn rpc_async() -> Result<()> {
+        assert_output(
+            |o| {
+                write_rpc(
+                    view::Rpc::new(
+                        &model::Rpc {
+                            name: Cow::Borrowed("rpc_name"),
+                            return_type: Some(model::TypeRef::new(Type::U32, Semantics::Value)),
+                            kind: model::RpcKind {
+                                is_async: true,
+                                streaming: model::Streaming::Unary,
+                            },
+                            ..Default::default()
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            "pub async fn rpc_name() -> u32 {}\n",
+        )
+    }
+
+    #[test]
+    fn rpc_streaming() -> Result<()> {
+        assert_output(
+            |o| {
+                write_rpc(
+                    view::Rpc::new(
+                        &model::Rpc {
+                            name: Cow::Borrowed("rpc_name"),
+                            params: vec![model::Field {
+                                name: "param",
+                                ty: model::TypeRef::new(Type::String, Semantics::Value),
+                                attributes: Default::default(),
+                                is_static: false,
+                                value: None,
+                                default: None,
+                            }],
+                            return_type: Some(model::TypeRef::new(Type::U32, Semantics::Value)),
+                            kind: model::RpcKind {
+                                is_async: false,
+                                streaming: model::Streaming::Bidirectional,
+                            },
+                            ..Default::default()
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"pub fn rpc_name(
+    param: impl futures::Stream<Item = String>,
+) -> impl futures::Stream<Item = u32> {}
+"#,
+        )
+    }
+
+    #[test]
+    fn rpc_error_type() -> Result<()> {
+        assert_output(
+            |o| {
+                write_rpc(
+                    view::Rpc::new(
+                        &model::Rpc {
+                            name: Cow::Borrowed("rpc_name"),
+                            error_type: Some(model::TypeRef::new_api(
+                                "ApiError",
+                                Semantics::Value,
+                            )?),
+                            ..Default::default()
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            "pub fn rpc_name() -> Result<(), crate::ApiError> {}\n",
+        )
+    }
+
+    #[test]
+    fn dto_generic() -> Result<()> {
+        assert_output(
+            |o| {
+                write_dto(
+                    view::Dto::new(
+                        &model::Dto {
+                            name: "Page",
+                            type_params: vec!["T"],
+                            fields: vec![model::Field {
+                                name: "items",
+                                ty: model::TypeRef::new_array(
+                                    model::TypeRef::new(
+                                        model::Type::TypeParam("T".to_string()),
+                                        Semantics::Value,
+                                    ),
+                                    Semantics::Value,
+                                ),
+                                attributes: Default::default(),
+                                is_static: false,
+                                value: None,
+                                default: None,
+                            }],
+                            rpcs: vec![],
+                            extends: vec![],
+                            implements: vec![],
+                            attributes: Default::default(),
+                            namespace: None,
+                        },
+                        &model::Namespace::default(),
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            "pub struct Page<T> {\n    items: Vec<T>,\n}\n",
+        )
+    }
+
+    #[test]
+    fn rpc_generic() -> Result<()> {
+        assert_output(
+            |o| {
+                write_rpc(
+                    view::Rpc::new(
+                        &model::Rpc {
+                            name: Cow::Borrowed("rpc_name"),
+                            type_params: vec!["K", "V"],
+                            params: vec![],
+                            return_type: Some(model::TypeRef::new(
+                                model::Type::TypeParam("V".to_string()),
+                                Semantics::Value,
+                            )),
+                            error_type: None,
+                            attributes: Default::default(),
+                            is_static: true,
+                            kind: model::RpcKind::default(),
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            "pub fn rpc_name<K, V>() -> V {}\n",
+        )
+    }
+
     #[test]
     fn field() -> Result<()> {
         assert_output_slice(
@@ -614,6 +1297,8 @@ pub mod ns0 {
                             ty: model::TypeRef::new_api("Type", Semantics::Value)?,
                             attributes: test_attributes(),
                             is_static: false,
+                            value: None,
+                            default: None,
                         },
                         &vec![],
                         &vec![],
@@ -626,6 +1311,149 @@ pub mod ns0 {
         )
     }
 
+    #[test]
+    fn deprecated() -> Result<()> {
+        let attributes = model::Attributes {
+            deprecation: Some(model::Deprecation {
+                since: Some(Cow::Borrowed("1.0")),
+                note: Some(Cow::Borrowed("Old.")),
+                replacement: Some(Cow::Borrowed("new_field")),
+            }),
+            ..Default::default()
+        };
+        assert_output(
+            |o| write_attributes(&view::Attributes::new(&attributes, &vec![], &vec![]), o),
+            "#[deprecated(since = \"1.0\", note = \"Old. Use `new_field` instead.\")]\n",
+        )
+    }
+
+    #[test]
+    fn docs() -> Result<()> {
+        let attributes = model::Attributes {
+            docs: Some(Box::new(model::Docs {
+                summary: model::Comment::from(vec!["Summary."]),
+                description: model::Comment::default(),
+                params: vec![model::ParamDoc::new(
+                    "a",
+                    model::Comment::from(vec!["Line one", "line two."]),
+                )],
+                returns: model::Comment::from(vec!["The result."]),
+                examples: vec![model::Comment::from(vec!["rpc(1);"])],
+                see_also: vec![Cow::Borrowed("other")],
+            })),
+            ..Default::default()
+        };
+        assert_output(
+            |o| write_attributes(&view::Attributes::new(&attributes, &vec![], &vec![]), o),
+            r#"/// Summary.
+///
+/// # Arguments
+///
+/// * `a` - Line one
+///   line two.
+///
+/// # Returns
+///
+/// The result.
+///
+/// # Examples
+///
+/// ```
+/// rpc(1);
+/// ```
+///
+/// # See also
+///
+/// * `other`
+"#,
+        )
+    }
+
+    #[test]
+    fn field_default() -> Result<()> {
+        assert_output(
+            |o| {
+                write_field(
+                    view::Field::new(
+                        &model::Field {
+                            name: "asdf",
+                            ty: model::TypeRef::new(
+                                model::Type::new_optional(model::TypeRef::new(
+                                    model::Type::U32,
+                                    Semantics::Value,
+                                )),
+                                Semantics::Value,
+                            ),
+                            attributes: Default::default(),
+                            is_static: false,
+                            value: None,
+                            default: Some(model::Value::Int(30)),
+                        },
+                        &vec![],
+                        &vec![],
+                        &vec![],
+                    ),
+                    o,
+                )
+            },
+            "// default: Some(30)\nasdf: Option<u32>,",
+        )
+    }
+
+    #[test]
+    fn dto_default() -> Result<()> {
+        let field = |name, ty, default| model::Field {
+            name,
+            ty: model::TypeRef::new(ty, Semantics::Value),
+            attributes: Default::default(),
+            is_static: false,
+            value: None,
+            default,
+        };
+        assert_output(
+            |o| {
+                write_dto(
+                    view::Dto::new(
+                        &model::Dto {
+                            name: "DtoName",
+                            type_params: vec!["T"],
+                            fields: vec![
+                                field("retries", model::Type::I32, Some(model::Value::Int(3))),
+                                field(
+                                    "name",
+                                    model::Type::String,
+                                    Some(model::Value::String("name".into())),
+                                ),
+                                field("value", model::Type::TypeParam("T".to_string()), None),
+                            ],
+                            ..Default::default()
+                        },
+                        &model::Namespace::default(),
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"pub struct DtoName<T> {
+    // default: 3
+    retries: i32,
+    // default: "name"
+    name: String,
+    value: T,
+}
+impl<T: Default> Default for DtoName<T> {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            name: "name".to_string(),
+            value: Default::default(),
+        }
+    }
+}
+"#,
+        )
+    }
+
     #[test]
     fn field_self() -> Result<()> {
         assert_output_slice(
@@ -640,6 +1468,8 @@ pub mod ns0 {
                             },
                             attributes: Attributes::default(),
                             is_static: false,
+                            value: None,
+                            default: None,
                         },
                         &vec![],
                         &vec![],
@@ -672,6 +1502,8 @@ pub mod ns0 {
                                     attributes: test_attributes(),
                                 },
                             ],
+                            kind: model::EnumKind::Plain,
+                            underlying_type: None,
                             attributes: test_attributes(),
                         },
                         &Transforms::default(),
@@ -691,6 +1523,292 @@ pub mod ns0 {
         )
     }
 
+    #[test]
+    fn flags() -> Result<()> {
+        assert_output(
+            |o| {
+                write_enum(
+                    view::Enum::new(
+                        &model::Enum {
+                            name: "en",
+                            values: vec![
+                                model::EnumValue {
+                                    name: "A",
+                                    number: 1,
+                                    ..Default::default()
+                                },
+                                model::EnumValue {
+                                    name: "B",
+                                    number: 2,
+                                    ..Default::default()
+                                },
+                            ],
+                            kind: model::EnumKind::Flags,
+                            ..Default::default()
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"bitflags::bitflags! {
+    pub struct en: u64 {
+        const A = 1;
+        const B = 2;
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn flags_underlying_type() -> Result<()> {
+        assert_output(
+            |o| {
+                write_enum(
+                    view::Enum::new(
+                        &model::Enum {
+                            name: "en",
+                            values: vec![model::EnumValue {
+                                name: "A",
+                                number: 1,
+                                ..Default::default()
+                            }],
+                            kind: model::EnumKind::Flags,
+                            underlying_type: Some(Type::U8),
+                            ..Default::default()
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"bitflags::bitflags! {
+    pub struct en: u8 {
+        const A = 1;
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn const_value() -> Result<()> {
+        for (ty, value, expected) in [
+            (model::Type::U32, model::Value::Int(5), "pub const NAME: u32 = 5;\n"),
+            (model::Type::F32, model::Value::Float(1.0), "pub const NAME: f32 = 1.0;\n"),
+            (model::Type::F64, model::Value::Int(5), "pub const NAME: f64 = 5.0;\n"),
+            (model::Type::Bool, model::Value::Bool(true), "pub const NAME: bool = true;\n"),
+            (
+                model::Type::String,
+                model::Value::String("str".into()),
+                "pub const NAME: &str = \"str\";\n",
+            ),
+            (
+                model::Type::String,
+                model::Value::String("a\"b\u{7}\\".into()),
+                "pub const NAME: &str = \"a\\\"b\\u{7}\\\\\";\n",
+            ),
+            (
+                model::Type::Api(model::EntityId::new_unqualified("Status")),
+                model::Value::EnumValue("Active".into()),
+                "pub const NAME: crate::Status = crate::Status::Active;\n",
+            ),
+            (
+                model::Type::new_optional(model::TypeRef::new(model::Type::I32, Semantics::Value)),
+                model::Value::Int(-1),
+                "pub const NAME: Option<i32> = Some(-1);\n",
+            ),
+            (
+                model::Type::U64,
+                model::Value::UInt(u64::MAX as u128),
+                "pub const NAME: u64 = 18446744073709551615;\n",
+            ),
+            (
+                model::Type::User(well_known::CHAR.to_string()),
+                model::Value::Char('\''),
+                "pub const NAME: char = '\\'';\n",
+            ),
+            (
+                model::Type::User(well_known::DECIMAL.to_string()),
+                model::Value::Float(1.5),
+                "pub const NAME: rust_decimal::Decimal = rust_decimal_macros::dec!(1.5);\n",
+            ),
+        ] {
+            assert_output(
+                |o| {
+                    write_const(
+                        view::Field::new(
+                            &model::Field {
+                                name: "NAME",
+                                ty: model::TypeRef::new(ty.clone(), Semantics::Value),
+                                attributes: Default::default(),
+                                is_static: true,
+                                value: Some(value.clone()),
+                                default: None,
+                            },
+                            &vec![],
+                            &vec![],
+                            &vec![],
+                        ),
+                        &mut Indented::new(o, INDENT),
+                    )
+                },
+                expected,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn union() -> Result<()> {
+        assert_output(
+            |o| {
+                write_union(
+                    view::Union::new(
+                        &model::Union {
+                            name: "Event",
+                            variants: vec![
+                                model::Variant {
+                                    name: "Logout",
+                                    payload: None,
+                                    attributes: Default::default(),
+                                },
+                                model::Variant {
+                                    name: "Move",
+                                    payload: Some(model::VariantPayload::Tuple(vec![
+                                        model::TypeRef::new(model::Type::I32, Semantics::Value),
+                                        model::TypeRef::new(model::Type::I32, Semantics::Value),
+                                    ])),
+                                    attributes: Default::default(),
+                                },
+                                model::Variant {
+                                    name: "Login",
+                                    payload: Some(model::VariantPayload::Struct(vec![
+                                        model::Field {
+                                            name: "user",
+                                            ty: model::TypeRef::new(
+                                                model::Type::String,
+                                                Semantics::Value,
+                                            ),
+                                            attributes: Default::default(),
+                                            is_static: false,
+                                            value: None,
+                                            default: None,
+                                        },
+                                    ])),
+                                    attributes: Default::default(),
+                                },
+                            ],
+                            attributes: Default::default(),
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"pub enum Event {
+    Logout,
+    Move(i32, i32),
+    Login {
+        user: String,
+    },
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn interface() -> Result<()> {
+        assert_output(
+            |o| {
+                write_interface(
+                    view::Interface::new(
+                        &model::Interface {
+                            name: "Service",
+                            rpcs: vec![
+                                model::Rpc {
+                                    name: "get".into(),
+                                    params: vec![model::Field {
+                                        name: "id",
+                                        ty: model::TypeRef::new(model::Type::U32, Semantics::Value),
+                                        attributes: Default::default(),
+                                        is_static: false,
+                                        value: None,
+                                        default: None,
+                                    }],
+                                    return_type: Some(model::TypeRef::new_api(
+                                        "Dto",
+                                        Semantics::Value,
+                                    )?),
+                                    ..Default::default()
+                                },
+                                model::Rpc {
+                                    name: "create".into(),
+                                    params: vec![model::Field {
+                                        name: "name",
+                                        ty: model::TypeRef::new(
+                                            model::Type::String,
+                                            Semantics::Value,
+                                        ),
+                                        attributes: Default::default(),
+                                        is_static: false,
+                                        value: None,
+                                        default: None,
+                                    }],
+                                    return_type: Some(model::TypeRef::new_api(
+                                        "Dto",
+                                        Semantics::Value,
+                                    )?),
+                                    is_static: true,
+                                    ..Default::default()
+                                },
+                            ],
+                            super_interfaces: vec![model::EntityId::try_from("ns.i:Base")?],
+                            attributes: Default::default(),
+                        },
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"pub trait Service: crate::ns::Base {
+    fn get(
+        &self,
+        id: u32,
+    ) -> crate::Dto;
+    fn create(
+        name: String,
+    ) -> crate::Dto;
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn dto_implements() -> Result<()> {
+        assert_output(
+            |o| {
+                write_dto(
+                    view::Dto::new(
+                        &model::Dto {
+                            name: "DtoName",
+                            implements: vec![model::EntityId::try_from("ns.i:Service")?],
+                            ..Default::default()
+                        },
+                        &model::Namespace::default(),
+                        &Transforms::default(),
+                    ),
+                    &mut Indented::new(o, INDENT),
+                )
+            },
+            r#"pub struct DtoName {
+}
+// implements: crate::ns::Service
+"#,
+        )
+    }
+
     fn test_attributes<'a>() -> Attributes<'a> {
         Attributes {
             user: vec![
@@ -786,7 +1904,7 @@ pub mod ns0 {
 
         use crate::generator::rust::write_type;
         use crate::generator::util::tests::assert_output;
-        use crate::model::{Semantics, Type, TypeRef};
+        use crate::model::{well_known, EntityId, Semantics, TupleElement, Type, TypeRef};
         use crate::view;
 
         macro_rules! test {
@@ -824,6 +1942,19 @@ pub mod ns0 {
             "Vec<u8>",
             TypeRef::new(Type::Bytes, Semantics::Value)
         );
+        test!(
+            user,
+            "user_type",
+            TypeRef::new(Type::User("user_type".to_string()), Semantics::Value)
+        );
+        test!(
+            well_known,
+            "std::time::Duration",
+            TypeRef::new(
+                Type::User(well_known::DURATION.to_string()),
+                Semantics::Value
+            )
+        );
         test!(
             entity_id_value,
             "crate::a::b::c",
@@ -839,6 +1970,34 @@ pub mod ns0 {
             "&mut crate::a::b::c",
             TypeRef::new_api("a.b.c", Semantics::Mut).unwrap()
         );
+        test!(
+            str_slice,
+            "&str",
+            TypeRef::new(Type::StringView, Semantics::Slice)
+        );
+        test!(
+            bytes_slice,
+            "&[u8]",
+            TypeRef::new(Type::Bytes, Semantics::Slice)
+        );
+        test!(
+            slice,
+            "&[crate::a::b::c]",
+            TypeRef::new_array(
+                TypeRef::new_api("a.b.c", Semantics::Value).unwrap(),
+                Semantics::Slice
+            )
+        );
+        test!(
+            boxed,
+            "Box<str>",
+            TypeRef::new(Type::StringView, Semantics::Boxed)
+        );
+        test!(
+            shared,
+            "std::sync::Arc<crate::a::b::c>",
+            TypeRef::new_api("a.b.c", Semantics::Shared).unwrap()
+        );
         test!(
             vec,
             "Vec<String>",
@@ -876,6 +2035,50 @@ pub mod ns0 {
                 Semantics::Value
             )
         );
+        test!(
+            set,
+            "HashSet<String>",
+            TypeRef::new_set(
+                TypeRef::new(Type::String, Semantics::Value),
+                Semantics::Value
+            )
+        );
+        test!(
+            fixed_array,
+            "[u8; 32]",
+            TypeRef::new_fixed_array(TypeRef::new(Type::U8, Semantics::Value), 32, Semantics::Value)
+        );
+        test!(
+            tuple,
+            "(String, &i32)",
+            TypeRef::new(
+                Type::Tuple(vec![
+                    TupleElement::new(
+                        Some("name".to_string()),
+                        TypeRef::new(Type::String, Semantics::Value)
+                    ),
+                    TupleElement::unnamed(TypeRef::new(Type::I32, Semantics::Ref)),
+                ]),
+                Semantics::Value
+            )
+        );
+        test!(
+            tuple_single,
+            "(i32,)",
+            TypeRef::new_tuple([TypeRef::new(Type::I32, Semantics::Value)], Semantics::Value)
+        );
+        test!(
+            generic,
+            "crate::a::Page<String, T>",
+            TypeRef::new_generic(
+                EntityId::try_from("a.Page").unwrap(),
+                vec![
+                    TypeRef::new(Type::String, Semantics::Value),
+                    TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value)
+                ],
+                Semantics::Value
+            )
+        );
 
         fn run_test(ty: TypeRef, expected: &str) -> Result<()> {
             assert_output(
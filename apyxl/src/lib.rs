@@ -10,9 +10,8 @@ pub mod input;
 pub mod model;
 pub mod output;
 pub mod parser;
+pub mod rust_util;
 pub mod view;
 
 // Used and useful in crates that provide parsers/generators so not cfg(test).
 pub mod test_util;
-
-mod rust_util;
@@ -3,35 +3,98 @@ use std::borrow::Cow;
 use itertools::Itertools;
 
 use crate::model::{chunk, EntityId};
+use serde::{Deserialize, Serialize};
 
 /// Additional metadata attached to entities.
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+///
+/// `chunk` and `entity_id` are populated by the [crate::model::Builder] and are not serialized.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Attributes<'a> {
+    #[serde(skip)]
     pub chunk: Option<chunk::Attribute>,
+    #[serde(skip)]
     pub entity_id: EntityId,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub comments: Vec<Comment<'a>>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub user: Vec<User<'a>>,
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation<'a>>,
+    /// Boxed since most entities don't have structured docs.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<Box<Docs<'a>>>,
 }
 
 pub trait AttributesHolder {
     fn attributes(&self) -> &Attributes;
 }
 
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+/// Serialized as an array of lines.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Comment<'a> {
+    #[serde(borrow)]
     lines: Vec<Cow<'a, str>>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// Marks an entity as deprecated, e.g. from Rust's `#[deprecated]` or C#'s `[Obsolete]`.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Deprecation<'a> {
+    /// Version the entity was deprecated in, e.g. `1.2`.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<Cow<'a, str>>,
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<Cow<'a, str>>,
+    /// Name of the entity that should be used instead.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<Cow<'a, str>>,
+}
+
+/// Structured documentation parsed from doc comment conventions, e.g. C# XML doc tags or rustdoc
+/// sections. Each part is empty if it wasn't documented.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Docs<'a> {
+    /// First paragraph, e.g. `<summary>`.
+    #[serde(borrow, default, skip_serializing_if = "Comment::is_empty")]
+    pub summary: Comment<'a>,
+    /// Remaining text that isn't part of another section, e.g. `<remarks>`.
+    #[serde(borrow, default, skip_serializing_if = "Comment::is_empty")]
+    pub description: Comment<'a>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<ParamDoc<'a>>,
+    #[serde(borrow, default, skip_serializing_if = "Comment::is_empty")]
+    pub returns: Comment<'a>,
+    /// Each example is usually a code block.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Comment<'a>>,
+    /// Names of related entities as written in the source, e.g. `Dto` or `ns::Dto`.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub see_also: Vec<Cow<'a, str>>,
+}
+
+/// Docs for a single rpc param.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ParamDoc<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub comment: Comment<'a>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct User<'a> {
+    #[serde(borrow)]
     pub name: Cow<'a, str>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub data: Vec<UserData<'a>>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct UserData<'a> {
-    pub key: Option<&'a str>,
-    pub value: &'a str,
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub value: Cow<'a, str>,
 }
 
 impl<'a> Attributes<'a> {
@@ -39,6 +102,8 @@ impl<'a> Attributes<'a> {
         self.merge_chunks(other.chunk);
         self.merge_comments(other.comments);
         self.merge_user(other.user);
+        self.merge_deprecation(other.deprecation);
+        self.merge_docs(other.docs);
         // Note: entity_id should typically be equivalent, but in the case where it's not it
         // makes sense to keep the current entity_id.
     }
@@ -60,6 +125,18 @@ impl<'a> Attributes<'a> {
     fn merge_user(&mut self, mut other: Vec<User<'a>>) {
         self.user.append(&mut other);
     }
+
+    fn merge_deprecation(&mut self, other: Option<Deprecation<'a>>) {
+        if self.deprecation.is_none() {
+            self.deprecation = other;
+        }
+    }
+
+    fn merge_docs(&mut self, other: Option<Box<Docs<'a>>>) {
+        if self.docs.is_none() {
+            self.docs = other;
+        }
+    }
 }
 
 impl<'a> Comment<'a> {
@@ -79,6 +156,10 @@ impl<'a> Comment<'a> {
     pub fn lines_mut(&mut self) -> impl Iterator<Item = &mut Cow<'a, str>> {
         self.lines.iter_mut()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
 }
 
 impl<'a> From<Vec<Cow<'a, str>>> for Comment<'a> {
@@ -95,6 +176,24 @@ impl<'a> From<Vec<&'a str>> for Comment<'a> {
     }
 }
 
+impl<'a> Docs<'a> {
+    pub fn param(&self, name: &str) -> Option<&Comment<'a>> {
+        self.params
+            .iter()
+            .find(|param| param.name == name)
+            .map(|param| &param.comment)
+    }
+}
+
+impl<'a> ParamDoc<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>, comment: Comment<'a>) -> Self {
+        Self {
+            name: name.into(),
+            comment,
+        }
+    }
+}
+
 impl<'a> User<'a> {
     pub fn new(name: &'a str, data: Vec<UserData<'a>>) -> Self {
         Self {
@@ -113,14 +212,19 @@ impl<'a> User<'a> {
 
 impl<'a> UserData<'a> {
     pub fn new(key: Option<&'a str>, value: &'a str) -> Self {
-        Self { key, value }
+        Self {
+            key: key.map(Cow::Borrowed),
+            value: Cow::Borrowed(value),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use crate::model::attributes::User;
-    use crate::model::{Attributes, Comment};
+    use crate::model::{Attributes, Comment, Deprecation, Docs, ParamDoc};
 
     mod merge_chunks {
         use crate::model::{chunk, Attributes};
@@ -216,4 +320,52 @@ mod tests {
             vec![User::new_flag("hi"), User::new_flag("there")],
         );
     }
+
+    #[test]
+    fn merge_deprecation() {
+        let deprecation = |note| Deprecation {
+            note: Some(Cow::Borrowed(note)),
+            ..Default::default()
+        };
+        let mut attr = Attributes::default();
+        attr.merge(Attributes {
+            deprecation: Some(deprecation("first")),
+            ..Default::default()
+        });
+        attr.merge(Attributes {
+            deprecation: Some(deprecation("second")),
+            ..Default::default()
+        });
+        assert_eq!(attr.deprecation, Some(deprecation("first")));
+    }
+
+    #[test]
+    fn merge_docs() {
+        let docs = |summary| {
+            Some(Box::new(Docs {
+                summary: Comment::from(vec![summary]),
+                ..Default::default()
+            }))
+        };
+        let mut attr = Attributes::default();
+        attr.merge(Attributes {
+            docs: docs("first"),
+            ..Default::default()
+        });
+        attr.merge(Attributes {
+            docs: docs("second"),
+            ..Default::default()
+        });
+        assert_eq!(attr.docs, docs("first"));
+    }
+
+    #[test]
+    fn docs_param() {
+        let docs = Docs {
+            params: vec![ParamDoc::new("a", Comment::from(vec!["doc"]))],
+            ..Default::default()
+        };
+        assert_eq!(docs.param("a"), Some(&Comment::from(vec!["doc"])));
+        assert_eq!(docs.param("b"), None);
+    }
 }
@@ -1,4 +1,5 @@
-use crate::model::{Api, EntityId, EntityType, Namespace, NamespaceChild, Type};
+use crate::model::entity::FindEntity;
+use crate::model::{Api, EntityId, EntityType, Namespace, NamespaceChild, Type, VariantPayload};
 use itertools::Itertools;
 use log::debug;
 use petgraph::graph::{DiGraph, NodeIndex};
@@ -50,6 +51,26 @@ impl Dependencies {
             .collect_vec()
     }
 
+    /// Returns all (dependent, dependency) pairs.
+    pub fn edges(&self) -> Vec<(&EntityId, &EntityId)> {
+        self.graph
+            .edge_indices()
+            .filter_map(|edge| self.graph.edge_endpoints(edge))
+            .filter_map(|(from, to)| {
+                Some((self.graph.node_weight(from)?, self.graph.node_weight(to)?))
+            })
+            .collect_vec()
+    }
+
+    /// Returns all (dependent, dependency) pairs where the dependency is deprecated but the
+    /// dependent is not. An entity is deprecated if it or any of its ancestors are.
+    pub fn deprecated(&self, api: &Api) -> Vec<(&EntityId, &EntityId)> {
+        self.edges()
+            .into_iter()
+            .filter(|(from, to)| is_deprecated(api, to) && !is_deprecated(api, from))
+            .collect_vec()
+    }
+
     fn add_nodes_recursively(&mut self, namespace: &Namespace, namespace_id: &EntityId) {
         // unwraps ok here because we're iterating known children.
 
@@ -68,6 +89,16 @@ impl Dependencies {
                 NamespaceChild::Enum(en) => {
                     self.add_node(&namespace_id.child(EntityType::Enum, en.name).unwrap());
                 }
+                NamespaceChild::Union(union) => {
+                    self.add_node(&namespace_id.child(EntityType::Union, union.name).unwrap());
+                }
+                NamespaceChild::Interface(interface) => {
+                    self.add_node(
+                        &namespace_id
+                            .child(EntityType::Interface, interface.name)
+                            .unwrap(),
+                    );
+                }
                 NamespaceChild::TypeAlias(alias) => {
                     self.add_node(
                         &namespace_id
@@ -102,6 +133,14 @@ impl Dependencies {
                 debug!("add edge from dto {} field {}", from_id, field.name);
                 self.add_edge(from, namespace_id, &field.ty.value);
             }
+            for base_id in &dto.extends {
+                debug!("add edge from dto {} extends {}", from_id, base_id);
+                self.add_edge_relative(from, namespace_id, base_id);
+            }
+            for interface_id in &dto.implements {
+                debug!("add edge from dto {} implements {}", from_id, interface_id);
+                self.add_edge_relative(from, namespace_id, interface_id);
+            }
             if let Some(namespace) = &dto.namespace {
                 self.add_edges_recursively(namespace, &from_id);
             }
@@ -118,6 +157,65 @@ impl Dependencies {
                 debug!("add edge from rpc {} return_ty", from_id);
                 self.add_edge(from, namespace_id, &return_type.value);
             }
+            if let Some(error_type) = &rpc.error_type {
+                debug!("add edge from rpc {} error_ty", from_id);
+                self.add_edge(from, namespace_id, &error_type.value);
+            }
+        }
+
+        for union in namespace.unions() {
+            let from_id = namespace_id.child(EntityType::Union, union.name).unwrap();
+            let from = *self.node(&from_id).unwrap();
+            for variant in &union.variants {
+                debug!("add edge from union {} variant {}", from_id, variant.name);
+                match &variant.payload {
+                    Some(VariantPayload::Tuple(tys)) => {
+                        for ty in tys {
+                            self.add_edge(from, namespace_id, &ty.value);
+                        }
+                    }
+                    Some(VariantPayload::Struct(fields)) => {
+                        for field in fields {
+                            self.add_edge(from, namespace_id, &field.ty.value);
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        for interface in namespace.interfaces() {
+            let from_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            let from = *self.node(&from_id).unwrap();
+            for rpc in &interface.rpcs {
+                for param in &rpc.params {
+                    debug!(
+                        "add edge from interface {} rpc {} param {}",
+                        from_id, rpc.name, param.name
+                    );
+                    self.add_edge(from, namespace_id, &param.ty.value);
+                }
+                if let Some(return_type) = &rpc.return_type {
+                    debug!(
+                        "add edge from interface {} rpc {} return_ty",
+                        from_id, rpc.name
+                    );
+                    self.add_edge(from, namespace_id, &return_type.value);
+                }
+                if let Some(error_type) = &rpc.error_type {
+                    debug!(
+                        "add edge from interface {} rpc {} error_ty",
+                        from_id, rpc.name
+                    );
+                    self.add_edge(from, namespace_id, &error_type.value);
+                }
+            }
+            for super_id in &interface.super_interfaces {
+                debug!("add edge from interface {} super {}", from_id, super_id);
+                self.add_edge_relative(from, namespace_id, super_id);
+            }
         }
 
         for alias in namespace.ty_aliases() {
@@ -190,11 +288,27 @@ impl Dependencies {
             | Type::StringView
             | Type::String
             | Type::Bytes
-            | Type::User(_) => (),
+            | Type::User(_)
+            | Type::TypeParam(_) => (),
 
             Type::Api(entity_id) => self.add_edge_relative(from, namespace_id, entity_id),
 
-            Type::Array(ty) | Type::Optional(ty) => self.add_edge(from, namespace_id, &ty.value),
+            Type::Generic { ty, args } => {
+                self.add_edge_relative(from, namespace_id, ty);
+                for arg in args {
+                    self.add_edge(from, namespace_id, &arg.value)
+                }
+            }
+
+            Type::Array(ty) | Type::Set(ty) | Type::FixedArray { ty, .. } | Type::Optional(ty) => {
+                self.add_edge(from, namespace_id, &ty.value)
+            }
+
+            Type::Tuple(elements) => {
+                for element in elements {
+                    self.add_edge(from, namespace_id, &element.ty.value)
+                }
+            }
 
             Type::Map { key, value } => {
                 self.add_edge(from, namespace_id, &key.value);
@@ -226,6 +340,21 @@ impl Dependencies {
     }
 }
 
+fn is_deprecated(api: &Api, entity_id: &EntityId) -> bool {
+    let mut it = Some(entity_id.clone());
+    while let Some(entity_id) = it {
+        let deprecated = api
+            .find_entity(entity_id.clone())
+            .and_then(|entity| entity.attributes())
+            .is_some_and(|attributes| attributes.deprecation.is_some());
+        if deprecated {
+            return true;
+        }
+        it = entity_id.parent();
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use crate::model::{Api, Dependencies};
@@ -271,7 +400,8 @@ mod tests {
 
     mod contains_edge {
         use crate::model::api::dependencies::tests::run_test;
-        use crate::model::EntityId;
+        use crate::model::{Dependencies, EntityId};
+        use crate::test_util::executor::TestExecutor;
 
         #[test]
         fn sibling() {
@@ -380,6 +510,27 @@ mod tests {
             );
         }
 
+        #[test]
+        fn union_payload() {
+            let from = EntityId::try_from("u:union").unwrap();
+            let tuple_to = EntityId::try_from("d:dto").unwrap();
+            let struct_to = EntityId::try_from("e:en").unwrap();
+            run_test(
+                r#"
+            struct dto {}
+            enum en {}
+            enum union {
+                tuple(dto),
+                structure { field: en },
+            }
+            "#,
+                |deps| {
+                    assert!(deps.contains_edge(&from, &tuple_to));
+                    assert!(deps.contains_edge(&from, &struct_to));
+                },
+            );
+        }
+
         #[test]
         fn rpc_param() {
             let from = EntityId::try_from("r:rpc").unwrap();
@@ -406,6 +557,19 @@ mod tests {
             );
         }
 
+        #[test]
+        fn rpc_error_type() {
+            let from = EntityId::try_from("r:rpc").unwrap();
+            let to = EntityId::try_from("d:error").unwrap();
+            run_test(
+                r#"
+            struct error {}
+            fn rpc() -> Result<u32, error> {}
+            "#,
+                |deps| assert!(deps.contains_edge(&from, &to)),
+            );
+        }
+
         #[test]
         fn alias_target_ty() {
             let from = EntityId::try_from("a:alias").unwrap();
@@ -435,6 +599,65 @@ mod tests {
             );
         }
 
+        #[test]
+        fn dto_extends() {
+            let from = EntityId::try_from("d:dto").unwrap();
+            let to = EntityId::try_from("d:base").unwrap();
+            let mut exe = TestExecutor::new("struct base {} struct dto {}");
+            let mut api = exe.build().api;
+            api.find_dto_mut(&from).unwrap().extends = vec![to.clone()];
+            let mut dependencies = Dependencies::default();
+            dependencies.build(&api);
+            assert!(dependencies.contains_edge(&from, &to));
+        }
+
+        #[test]
+        fn dto_implements() {
+            let from = EntityId::try_from("d:dto").unwrap();
+            let to = EntityId::try_from("i:interface").unwrap();
+            run_test(
+                r#"
+            trait interface {}
+            struct dto {}
+            impl interface for dto {}
+            "#,
+                |deps| assert!(deps.contains_edge(&from, &to)),
+            );
+        }
+
+        #[test]
+        fn interface_rpc() {
+            let from = EntityId::try_from("i:interface").unwrap();
+            let param_to = EntityId::try_from("d:dto0").unwrap();
+            let return_to = EntityId::try_from("d:dto1").unwrap();
+            run_test(
+                r#"
+            struct dto0 {}
+            struct dto1 {}
+            trait interface {
+                fn rpc(&self, d: dto0) -> dto1;
+            }
+            "#,
+                |deps| {
+                    assert!(deps.contains_edge(&from, &param_to));
+                    assert!(deps.contains_edge(&from, &return_to));
+                },
+            );
+        }
+
+        #[test]
+        fn super_interface() {
+            let from = EntityId::try_from("i:interface").unwrap();
+            let to = EntityId::try_from("i:base").unwrap();
+            run_test(
+                r#"
+            trait base {}
+            trait interface: base {}
+            "#,
+                |deps| assert!(deps.contains_edge(&from, &to)),
+            );
+        }
+
         #[test]
         fn impl_block_alias_target_ty() {
             let from = EntityId::try_from("d:other.a:alias").unwrap();
@@ -579,6 +802,25 @@ mod tests {
                 },
             );
         }
+
+        #[test]
+        fn union() {
+            run_test(
+                r#"
+            enum union { a(u32) }
+            mod ns0 {
+                enum union { a(u32) }
+            }
+            "#,
+                |deps| {
+                    assert!(deps.node(&EntityId::try_from("u:union").unwrap()).is_some());
+                    assert!(deps
+                        .node(&EntityId::try_from("ns0.u:union").unwrap())
+                        .is_some());
+                    assert_eq!(deps.graph.node_count(), 2);
+                },
+            );
+        }
     }
 
     mod adds_edges_for {
@@ -680,8 +922,71 @@ mod tests {
             );
         }
 
+        #[test]
+        fn set() {
+            run_complex_type_test(
+                r#"
+                mod ns {
+                    struct src {
+                        field_a: HashSet<dto>,
+                        field_b: BTreeSet<en>,
+                    }
+                    struct dto {}
+                    enum en {}
+                }
+                "#,
+            );
+        }
+
+        #[test]
+        fn fixed_array() {
+            run_complex_type_test(
+                r#"
+                mod ns {
+                    struct src {
+                        field_a: [dto; 2],
+                        field_b: [en; 4],
+                    }
+                    struct dto {}
+                    enum en {}
+                }
+                "#,
+            );
+        }
+
+        #[test]
+        fn tuple() {
+            run_complex_type_test(
+                r#"
+                mod ns {
+                    struct src {
+                        field: (u32, (en, dto)),
+                    }
+                    struct dto {}
+                    enum en {}
+                }
+                "#,
+            );
+        }
+
         // todo pyx - function type
 
+        #[test]
+        fn generic() {
+            run_complex_type_test(
+                r#"
+                mod ns {
+                    struct src {
+                        field: Page<en, dto>,
+                    }
+                    struct Page<K, V> {}
+                    struct dto {}
+                    enum en {}
+                }
+                "#,
+            );
+        }
+
         #[test]
         fn transitive() {
             run_complex_type_test(
@@ -708,6 +1013,83 @@ mod tests {
         }
     }
 
+    mod deprecated {
+        use crate::model::api::dependencies::tests::run_test;
+        use crate::model::EntityId;
+        use crate::test_util::executor::TestExecutor;
+
+        #[test]
+        fn dependency_deprecated() {
+            run_deprecated_test(
+                r#"
+                #[deprecated]
+                struct old {}
+                struct dto { field: old }
+                "#,
+                vec![("d:dto", "d:old")],
+            );
+        }
+
+        #[test]
+        fn ancestor_deprecated() {
+            run_deprecated_test(
+                r#"
+                #[deprecated]
+                mod ns {
+                    struct old {}
+                }
+                struct dto { field: ns::old }
+                "#,
+                vec![("d:dto", "ns.d:old")],
+            );
+        }
+
+        #[test]
+        fn dependent_deprecated() {
+            run_deprecated_test(
+                r#"
+                #[deprecated]
+                struct old {}
+                #[deprecated]
+                struct dto { field: old }
+                "#,
+                vec![],
+            );
+        }
+
+        #[test]
+        fn not_deprecated() {
+            run_test(
+                r#"
+                struct other {}
+                struct dto { field: other }
+                "#,
+                |deps| assert_eq!(deps.edges().len(), 1),
+            );
+        }
+
+        fn run_deprecated_test(data: &str, expected: Vec<(&str, &str)>) {
+            let mut exe = TestExecutor::new(data);
+            let model = exe.build();
+            let expected = expected
+                .into_iter()
+                .map(|(from, to)| {
+                    (
+                        EntityId::try_from(from).unwrap(),
+                        EntityId::try_from(to).unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let actual = model
+                .dependencies()
+                .deprecated(model.api())
+                .into_iter()
+                .map(|(from, to)| (from.clone(), to.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected);
+        }
+    }
+
     #[test]
     fn clears_existing_on_build() {
         let mut exe = TestExecutor::new("struct dto {} fn rpc() {}");
@@ -3,16 +3,32 @@ use crate::model::attributes::AttributesHolder;
 use crate::model::entity::{EntityMut, FindEntity};
 use crate::model::{Attributes, Entity, EntityId, EntityType, Field, Namespace, Rpc};
 use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
 
 /// A single Data Transfer Object (DTO) used in an [Rpc], either directly or nested in another [Dto].
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Dto<'a> {
     pub name: &'a str,
+    /// Names of the generic type parameters, e.g. `T` in `Page<T>`.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<&'a str>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<Field<'a>>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub rpcs: Vec<Rpc<'a>>,
+    /// Base [Dto]s this [Dto] inherits from, e.g. `Entity` in `class Player : Entity`. Qualified
+    /// at build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<EntityId>,
+    /// [crate::model::Interface]s implemented by this [Dto], e.g. `Trait` in
+    /// `impl Trait for Dto`. Qualified at build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<EntityId>,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 
     /// Namespace that holds e.g. nested [Dtos], [Rpcs], and [TypeAliases].
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub namespace: Option<Namespace<'a>>,
 }
 
@@ -32,6 +48,20 @@ impl<'a> Dto<'a> {
     pub fn rpc_mut(&mut self, name: &str) -> Option<&mut Rpc<'a>> {
         self.rpcs.iter_mut().find(|rpc| rpc.name == name)
     }
+
+    /// Applies [crate::model::TypeRef::apply_type_params] to the types of all fields, rpcs, and
+    /// nested entities.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        for field in &mut self.fields {
+            field.ty.apply_type_params(type_params);
+        }
+        for rpc in &mut self.rpcs {
+            rpc.apply_type_params(type_params);
+        }
+        if let Some(namespace) = &mut self.namespace {
+            namespace.apply_type_params(type_params);
+        }
+    }
 }
 
 impl ToEntity for Dto<'_> {
@@ -97,13 +127,16 @@ impl<'api> FindEntity<'api> for Dto<'api> {
                     .and_then(|x| x.find_entity(id.clone()))
                     .or_else(|| self.namespace.as_ref()?.find_entity(static_id)),
 
-                EntityType::Dto | EntityType::TypeAlias => {
-                    self.namespace.as_ref()?.find_entity(static_id)
-                }
+                EntityType::Dto
+                | EntityType::TypeAlias
+                | EntityType::Union
+                | EntityType::Interface => self.namespace.as_ref()?.find_entity(static_id),
 
-                EntityType::None | EntityType::Namespace | EntityType::Enum | EntityType::Type => {
-                    None
-                }
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Enum
+                | EntityType::Variant
+                | EntityType::Type => None,
             }
         } else {
             Some(Entity::Dto(self))
@@ -140,13 +173,16 @@ impl<'api> FindEntity<'api> for Dto<'api> {
                     }
                 }
 
-                EntityType::Dto | EntityType::TypeAlias => {
-                    self.namespace.as_mut()?.find_entity_mut(static_id)
-                }
+                EntityType::Dto
+                | EntityType::TypeAlias
+                | EntityType::Union
+                | EntityType::Interface => self.namespace.as_mut()?.find_entity_mut(static_id),
 
-                EntityType::None | EntityType::Namespace | EntityType::Enum | EntityType::Type => {
-                    None
-                }
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Enum
+                | EntityType::Variant
+                | EntityType::Type => None,
             }
         } else {
             Some(EntityMut::Dto(self))
@@ -1,24 +1,46 @@
 use crate::model::api::entity::ToEntity;
 use crate::model::attributes::AttributesHolder;
 use crate::model::entity::{EntityMut, FindEntity};
-use crate::model::{Attributes, Entity, EntityId};
+use crate::model::{Attributes, Entity, EntityId, Type};
 use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
 
 /// A single enum type in the within an [Api].
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Enum<'a> {
     pub name: &'a str,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub values: Vec<EnumValue<'a>>,
+    #[serde(default)]
+    pub kind: EnumKind,
+    /// Integer type the values are stored as, if declared by the source, e.g. `byte` in C#
+    /// `enum E : byte` or `u32` in Rust `bitflags! { struct F: u32 { ... } }`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlying_type: Option<Type>,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 }
 
+/// How the values of an [Enum] relate to each other.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnumKind {
+    /// Exactly one value at a time.
+    #[default]
+    Plain,
+    /// Bit flags that can be combined, e.g. C# `[Flags]` enums or Rust `bitflags!`. Each value is
+    /// either a power of two or a combination of other values.
+    Flags,
+}
+
 pub type EnumValueNumber = i64;
 
 /// A single value within an [Enum].
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct EnumValue<'a> {
     pub name: &'a str,
     pub number: EnumValueNumber,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 }
 
@@ -30,6 +52,10 @@ impl<'a> Enum<'a> {
     pub fn value_mut(&mut self, name: &str) -> Option<&mut EnumValue<'a>> {
         self.values.iter_mut().find(|value| value.name == name)
     }
+
+    pub fn is_flags(&self) -> bool {
+        self.kind == EnumKind::Flags
+    }
 }
 
 impl ToEntity for Enum<'_> {
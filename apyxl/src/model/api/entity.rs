@@ -1,6 +1,9 @@
 use anyhow::{anyhow, Result};
 
-use crate::model::{Dto, EntityId, Enum, Field, Namespace, Rpc, TypeAlias, TypeRef};
+use crate::model::{
+    Attributes, Dto, EntityId, Enum, Field, Interface, Namespace, Rpc, TypeAlias, TypeRef, Union,
+    Variant,
+};
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub enum EntityType {
@@ -9,6 +12,9 @@ pub enum EntityType {
     Dto,
     Rpc,
     Enum,
+    Union,
+    Variant,
+    Interface,
     Field,
     TypeAlias,
     Type,
@@ -20,6 +26,9 @@ pub enum Entity<'a, 'api> {
     Dto(&'a Dto<'api>),
     Rpc(&'a Rpc<'api>),
     Enum(&'a Enum<'api>),
+    Union(&'a Union<'api>),
+    Variant(&'a Variant<'api>),
+    Interface(&'a Interface<'api>),
     Field(&'a Field<'api>),
     TypeAlias(&'a TypeAlias<'api>),
     Type(&'a TypeRef),
@@ -31,6 +40,9 @@ pub enum EntityMut<'a, 'api> {
     Dto(&'a mut Dto<'api>),
     Rpc(&'a mut Rpc<'api>),
     Enum(&'a mut Enum<'api>),
+    Union(&'a mut Union<'api>),
+    Variant(&'a mut Variant<'api>),
+    Interface(&'a mut Interface<'api>),
     Field(&'a mut Field<'api>),
     TypeAlias(&'a mut TypeAlias<'api>),
     Type(&'a mut TypeRef),
@@ -55,6 +67,9 @@ impl<'api> FindEntity<'api> for Entity<'_, 'api> {
             Entity::Dto(dto) => dto.qualify_id(id, referenceable),
             Entity::Rpc(rpc) => rpc.qualify_id(id, referenceable),
             Entity::Enum(en) => en.qualify_id(id, referenceable),
+            Entity::Union(union) => union.qualify_id(id, referenceable),
+            Entity::Variant(variant) => variant.qualify_id(id, referenceable),
+            Entity::Interface(interface) => interface.qualify_id(id, referenceable),
             Entity::Field(field) => field.qualify_id(id, referenceable),
             Entity::TypeAlias(alias) => alias.qualify_id(id, referenceable),
             Entity::Type(ty) => ty.qualify_id(id, referenceable),
@@ -67,6 +82,9 @@ impl<'api> FindEntity<'api> for Entity<'_, 'api> {
             Entity::Dto(dto) => dto.find_entity(id),
             Entity::Rpc(rpc) => rpc.find_entity(id),
             Entity::Enum(en) => en.find_entity(id),
+            Entity::Union(union) => union.find_entity(id),
+            Entity::Variant(variant) => variant.find_entity(id),
+            Entity::Interface(interface) => interface.find_entity(id),
             Entity::Field(field) => field.find_entity(id),
             Entity::TypeAlias(alias) => alias.find_entity(id),
             Entity::Type(ty) => ty.find_entity(id),
@@ -85,6 +103,9 @@ impl<'api> FindEntity<'api> for EntityMut<'_, 'api> {
             EntityMut::Dto(dto) => dto.qualify_id(id, referenceable),
             EntityMut::Rpc(rpc) => rpc.qualify_id(id, referenceable),
             EntityMut::Enum(en) => en.qualify_id(id, referenceable),
+            EntityMut::Union(union) => union.qualify_id(id, referenceable),
+            EntityMut::Variant(variant) => variant.qualify_id(id, referenceable),
+            EntityMut::Interface(interface) => interface.qualify_id(id, referenceable),
             EntityMut::Field(field) => field.qualify_id(id, referenceable),
             EntityMut::TypeAlias(alias) => alias.qualify_id(id, referenceable),
             EntityMut::Type(ty) => ty.qualify_id(id, referenceable),
@@ -97,6 +118,9 @@ impl<'api> FindEntity<'api> for EntityMut<'_, 'api> {
             EntityMut::Dto(dto) => dto.find_entity(id),
             EntityMut::Rpc(rpc) => rpc.find_entity(id),
             EntityMut::Enum(en) => en.find_entity(id),
+            EntityMut::Union(union) => union.find_entity(id),
+            EntityMut::Variant(variant) => variant.find_entity(id),
+            EntityMut::Interface(interface) => interface.find_entity(id),
             EntityMut::Field(field) => field.find_entity(id),
             EntityMut::TypeAlias(alias) => alias.find_entity(id),
             EntityMut::Type(ty) => ty.find_entity(id),
@@ -109,6 +133,9 @@ impl<'api> FindEntity<'api> for EntityMut<'_, 'api> {
             EntityMut::Dto(dto) => dto.find_entity_mut(id),
             EntityMut::Rpc(rpc) => rpc.find_entity_mut(id),
             EntityMut::Enum(en) => en.find_entity_mut(id),
+            EntityMut::Union(union) => union.find_entity_mut(id),
+            EntityMut::Variant(variant) => variant.find_entity_mut(id),
+            EntityMut::Interface(interface) => interface.find_entity_mut(id),
             EntityMut::Field(field) => field.find_entity_mut(id),
             EntityMut::TypeAlias(alias) => alias.find_entity_mut(id),
             EntityMut::Type(ty) => ty.find_entity_mut(id),
@@ -138,12 +165,19 @@ pub mod subtype {
     pub const ENUM: &str =                  "enum";
     pub const ENUM_MED: &str =              "en";
     pub const ENUM_SHORT: &str =            "e";
+    pub const UNION: &str =                 "union";
+    pub const UNION_SHORT: &str =           "u";
+    pub const VARIANT: &str =               "variant";
+    pub const VARIANT_SHORT: &str =         "v";
+    pub const INTERFACE: &str =             "interface";
+    pub const INTERFACE_SHORT: &str =       "i";
     pub const FIELD: &str =                 "field";
     pub const FIELD_SHORT: &str =           "f";
     pub const PARAM: &str =                 "param";
     pub const PARAM_SHORT: &str =           "p";
     pub const TY: &str =                    "ty";
     pub const RETURN_TY: &str =             "return_ty";
+    pub const ERROR_TY: &str =              "error_ty";
     pub const TY_ALIAS: &str =              "alias";
     pub const TY_ALIAS_SHORT: &str =        "a";
     pub const TY_ALIAS_TARGET: &str =       "target_ty";
@@ -152,10 +186,14 @@ pub mod subtype {
     pub const DTO_ALL: &[&str] = &[DTO, DTO_SHORT];
     pub const RPC_ALL: &[&str] = &[RPC, RPC_SHORT];
     pub const ENUM_ALL: &[&str] = &[ENUM, ENUM_MED, ENUM_SHORT];
+    pub const UNION_ALL: &[&str] = &[UNION, UNION_SHORT];
+    pub const VARIANT_ALL: &[&str] = &[VARIANT, VARIANT_SHORT];
+    pub const INTERFACE_ALL: &[&str] = &[INTERFACE, INTERFACE_SHORT];
     pub const FIELD_ALL: &[&str] = &[FIELD, FIELD_SHORT];
     pub const PARAM_ALL: &[&str] = &[PARAM, PARAM_SHORT];
     pub const TY_ALL: &[&str] = &[TY];
     pub const RETURN_TY_ALL: &[&str] = &[RETURN_TY];
+    pub const ERROR_TY_ALL: &[&str] = &[ERROR_TY];
     pub const TY_ALIAS_ALL: &[&str] = &[TY_ALIAS, TY_ALIAS_SHORT];
     pub const TY_ALIAS_TARGET_ALL: &[&str] = &[TY_ALIAS_TARGET];
 }
@@ -172,9 +210,11 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field => true,
-                EntityType::Type | EntityType::None => false,
+                EntityType::Variant | EntityType::Type | EntityType::None => false,
             },
 
             EntityType::Dto => match ty {
@@ -182,9 +222,14 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::TypeAlias
-                | EntityType::Enum => true,
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Interface => true,
 
-                EntityType::Namespace | EntityType::Type | EntityType::None => false,
+                EntityType::Namespace
+                | EntityType::Variant
+                | EntityType::Type
+                | EntityType::None => false,
             },
 
             EntityType::Rpc => match ty {
@@ -193,6 +238,9 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::None => false,
             },
@@ -202,6 +250,50 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::Type
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::None => false,
+            },
+
+            EntityType::Union => match ty {
+                EntityType::Variant => true,
+                EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Interface
+                | EntityType::Type
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::None => false,
+            },
+
+            EntityType::Variant => match ty {
+                EntityType::Field | EntityType::Type => true,
+                EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::TypeAlias
+                | EntityType::None => false,
+            },
+
+            EntityType::Interface => match ty {
+                EntityType::Rpc => true,
+                EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::Type
                 | EntityType::TypeAlias
                 | EntityType::Field
@@ -214,6 +306,9 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field
                 | EntityType::None => false,
@@ -224,6 +319,9 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::Type
                 | EntityType::TypeAlias
                 | EntityType::Field
@@ -236,6 +334,9 @@ impl EntityType {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field
                 | EntityType::None => false,
@@ -244,7 +345,23 @@ impl EntityType {
     }
 }
 
-impl Entity<'_, '_> {
+impl<'a, 'api> Entity<'a, 'api> {
+    /// Returns the [Attributes] of this [Entity], if it has any.
+    pub fn attributes(&self) -> Option<&'a Attributes<'api>> {
+        match self {
+            Entity::Namespace(namespace) => Some(&namespace.attributes),
+            Entity::Dto(dto) => Some(&dto.attributes),
+            Entity::Rpc(rpc) => Some(&rpc.attributes),
+            Entity::Enum(en) => Some(&en.attributes),
+            Entity::Union(union) => Some(&union.attributes),
+            Entity::Variant(variant) => Some(&variant.attributes),
+            Entity::Interface(interface) => Some(&interface.attributes),
+            Entity::Field(field) => Some(&field.attributes),
+            Entity::TypeAlias(alias) => Some(&alias.attributes),
+            Entity::Type(_) => None,
+        }
+    }
+
     pub fn ty(&self) -> EntityType {
         match self {
             Entity::Namespace(ns) => {
@@ -257,6 +374,9 @@ impl Entity<'_, '_> {
             Entity::Dto(_) => EntityType::Dto,
             Entity::Rpc(_) => EntityType::Rpc,
             Entity::Enum(_) => EntityType::Enum,
+            Entity::Union(_) => EntityType::Union,
+            Entity::Variant(_) => EntityType::Variant,
+            Entity::Interface(_) => EntityType::Interface,
             Entity::Field(_) => EntityType::Field,
             Entity::Type(_) => EntityType::Type,
             Entity::TypeAlias(_) => EntityType::TypeAlias,
@@ -273,10 +393,14 @@ impl TryFrom<&str> for EntityType {
             _ if subtype::DTO_ALL.contains(&value) => Ok(EntityType::Dto),
             _ if subtype::RPC_ALL.contains(&value) => Ok(EntityType::Rpc),
             _ if subtype::ENUM_ALL.contains(&value) => Ok(EntityType::Enum),
+            _ if subtype::UNION_ALL.contains(&value) => Ok(EntityType::Union),
+            _ if subtype::VARIANT_ALL.contains(&value) => Ok(EntityType::Variant),
+            _ if subtype::INTERFACE_ALL.contains(&value) => Ok(EntityType::Interface),
             _ if subtype::FIELD_ALL.contains(&value) => Ok(EntityType::Field),
             _ if subtype::PARAM_ALL.contains(&value) => Ok(EntityType::Field),
             _ if subtype::TY_ALL.contains(&value) => Ok(EntityType::Type),
             _ if subtype::RETURN_TY_ALL.contains(&value) => Ok(EntityType::Type),
+            _ if subtype::ERROR_TY_ALL.contains(&value) => Ok(EntityType::Type),
             _ if subtype::TY_ALIAS_TARGET_ALL.contains(&value) => Ok(EntityType::Type),
             _ if subtype::TY_ALIAS_ALL.contains(&value) => Ok(EntityType::TypeAlias),
             _ => Err(anyhow!(
@@ -500,6 +624,16 @@ mod tests {
             run_test(input, false, "ns.rpc.return_ty", "ns:ns.r:rpc.return_ty")
         }
 
+        #[test]
+        fn rpc_error_ty() -> Result<()> {
+            let input = r#"
+            mod ns {
+                fn rpc() -> Result<i32, i32> {}
+            }
+            "#;
+            run_test(input, false, "ns.rpc.error_ty", "ns:ns.r:rpc.error_ty")
+        }
+
         #[test]
         fn en() -> Result<()> {
             let input = r#"
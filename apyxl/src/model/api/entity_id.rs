@@ -5,6 +5,7 @@ use std::hash::{Hash, Hasher};
 
 use anyhow::{anyhow, Result};
 use itertools::{zip_eq, Itertools};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::model::api::entity;
 use crate::model::api::entity::EntityType;
@@ -54,6 +55,8 @@ use crate::model::api::entity::EntityType;
 ///     [crate::model::Namespace]: `d`, `dto`:                [crate::model::Dto],
 ///                                `r`, `rpc`:                [crate::model::Rpc],
 ///                                `e`, `enum`, `en`:         [crate::model::Enum],
+///                                `u`, `union`:              [crate::model::Union],
+///                                `i`, `interface`:          [crate::model::Interface],
 ///                                `a`, `alias`:              [crate::model::TypeAlias],
 ///                                `f`, `field`:              [crate::model::Field],
 ///     [crate::model::Dto]:       `f`, `field`:              [crate::model::Field],
@@ -61,11 +64,18 @@ use crate::model::api::entity::EntityType;
 ///                                `r`, `rpc`:                [crate::model::Rpc],
 ///                                `a`, `alias`:              [crate::model::TypeAlias],
 ///                                `e`, `enum`, `en`:         [crate::model::Enum],
+///                                `u`, `union`:              [crate::model::Union],
+///                                `i`, `interface`:          [crate::model::Interface],
 ///     [crate::model::Rpc]:       `p`, `param`:              [crate::model::Field],
 ///                                `return_ty`:               [crate::model::TypeRef] (nameless),
+///                                `error_ty`:                [crate::model::TypeRef] (nameless),
 ///     [crate::model::Field]:     `ty`:                      [crate::model::TypeRef] (nameless),
 ///     [crate::model::TypeAlias]: `target`:                  [crate::model::TypeRef] (nameless),
 ///     [crate::model::Enum]:      <none>
+///     [crate::model::Union]:     `v`, `variant`:            [crate::model::Variant],
+///     [crate::model::Variant]:   `f`, `field`:              [crate::model::Field] (struct payloads),
+///                                `ty:<index>`:              [crate::model::TypeRef] (tuple payloads),
+///     [crate::model::Interface]: `r`, `rpc`:                [crate::model::Rpc],
 ///     [crate::model::TypeRef]:      <none>
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct EntityId {
@@ -364,6 +374,15 @@ impl Display for EntityId {
                 EntityType::Enum => {
                     path.push(format!("{}:{}", entity::subtype::ENUM, component.name))
                 }
+                EntityType::Union => {
+                    path.push(format!("{}:{}", entity::subtype::UNION, component.name))
+                }
+                EntityType::Variant => {
+                    path.push(format!("{}:{}", entity::subtype::VARIANT, component.name))
+                }
+                EntityType::Interface => {
+                    path.push(format!("{}:{}", entity::subtype::INTERFACE, component.name))
+                }
                 EntityType::Field => {
                     path.push(format!("{}:{}", entity::subtype::FIELD, component.name))
                 }
@@ -377,6 +396,9 @@ impl Display for EntityId {
                     Some(c) if c.ty == EntityType::TypeAlias => {
                         path.push(entity::subtype::TY_ALIAS_TARGET.to_owned())
                     }
+                    Some(c) if c.ty == EntityType::Variant => {
+                        path.push(format!("{}:{}", entity::subtype::TY, component.name))
+                    }
                     _ => return Err(std::fmt::Error),
                 },
                 EntityType::TypeAlias => {
@@ -389,6 +411,21 @@ impl Display for EntityId {
     }
 }
 
+/// Serialized as the unqualified, `.`-separated component names, e.g. `ns0.ns1.DtoName`, since
+/// [EntityId]s are qualified by the [crate::model::Builder].
+impl Serialize for EntityId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.component_names().join("."))
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::new_unqualified(&s))
+    }
+}
+
 impl Hash for EntityId {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.components.hash(state)
@@ -614,6 +651,10 @@ mod tests {
             let alias = id.child(EntityType::TypeAlias, "c").unwrap();
             let field = id.child(EntityType::Field, "f").unwrap();
             let target = alias.child(EntityType::Type, "target_ty").unwrap();
+            let union = id.child(EntityType::Union, "u").unwrap();
+            let variant = union.child(EntityType::Variant, "v").unwrap();
+            let variant_field = variant.child(EntityType::Field, "f").unwrap();
+            let variant_ty = variant.child(EntityType::Type, "0").unwrap();
             assert_eq!(dto, EntityId::try_from("a.b.dto:c").unwrap());
             assert_eq!(dto_field, EntityId::try_from("a.b.dto:c.field:d").unwrap());
             assert_eq!(dto_rpc, EntityId::try_from("a.b.dto:c.rpc:r").unwrap());
@@ -623,6 +664,16 @@ mod tests {
             assert_eq!(field, EntityId::try_from("a.b.field:f").unwrap());
             assert_eq!(en, EntityId::try_from("a.b.enum:e").unwrap());
             assert_eq!(target, EntityId::try_from("a.b.alias:c.target_ty").unwrap());
+            assert_eq!(union, EntityId::try_from("a.b.union:u").unwrap());
+            assert_eq!(variant, EntityId::try_from("a.b.u:u.variant:v").unwrap());
+            assert_eq!(variant_field, EntityId::try_from("a.b.u:u.v:v.f:f").unwrap());
+            assert_eq!(variant_ty, EntityId::try_from("a.b.u:u.v:v.ty:0").unwrap());
+            assert_eq!(variant_ty.to_string(), "a.b.union:u.variant:v.ty:0");
+            let interface = id.child(EntityType::Interface, "i").unwrap();
+            let interface_rpc = interface.child(EntityType::Rpc, "r").unwrap();
+            assert_eq!(interface, EntityId::try_from("a.b.interface:i").unwrap());
+            assert_eq!(interface_rpc, EntityId::try_from("a.b.i:i.r:r").unwrap());
+            assert_eq!(interface_rpc.to_string(), "a.b.interface:i.rpc:r");
         }
 
         #[test]
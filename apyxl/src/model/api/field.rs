@@ -1,18 +1,29 @@
 use anyhow::anyhow;
 use crate::model::attributes::AttributesHolder;
 use crate::model::entity::{EntityMut, FindEntity, ToEntity};
-use crate::model::{entity, Attributes, Entity, EntityId, EntityType, TypeRef};
+use crate::model::{entity, Attributes, Entity, EntityId, EntityType, TypeRef, Value};
+use serde::{Deserialize, Serialize};
 
 /// A pair of name and type that describe a named instance of a type e.g. within a [Dto] or [Rpc].
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Field<'a> {
     pub name: &'a str,
     pub ty: TypeRef,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 
     /// True if owned by a namespace rather than a Dto.
     /// This member is unused for rpc params. (Yes that's a design flaw).
+    #[serde(default)]
     pub is_static: bool,
+
+    /// Constant value of the field, e.g. `5` in `const X: u32 = 5;`.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value<'a>>,
+
+    /// Value used when the field or param is not set, e.g. `30` in C#'s `int timeout = 30`.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value<'a>>,
 }
 
 impl ToEntity for Field<'_> {
@@ -60,6 +71,9 @@ impl<'api> FindEntity<'api> for Field<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field => None,
             }
@@ -84,6 +98,9 @@ impl<'api> FindEntity<'api> for Field<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field => None,
             }
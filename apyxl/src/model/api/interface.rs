@@ -0,0 +1,140 @@
+use crate::model::api::entity::ToEntity;
+use crate::model::attributes::AttributesHolder;
+use crate::model::entity::{EntityMut, FindEntity};
+use crate::model::{Attributes, Entity, EntityId, EntityType, Rpc};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+/// A named set of [Rpc]s that [crate::model::Dto]s can implement, e.g. a Rust `trait` or a C#
+/// `interface`.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Interface<'a> {
+    pub name: &'a str,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub rpcs: Vec<Rpc<'a>>,
+    /// Other [Interface]s this one extends, e.g. `Base` in `trait Derived: Base`. Qualified at
+    /// build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub super_interfaces: Vec<EntityId>,
+    #[serde(borrow, default)]
+    pub attributes: Attributes<'a>,
+}
+
+impl<'a> Interface<'a> {
+    pub fn rpc(&self, name: &str) -> Option<&Rpc<'a>> {
+        self.rpcs.iter().find(|rpc| rpc.name == name)
+    }
+
+    pub fn rpc_mut(&mut self, name: &str) -> Option<&mut Rpc<'a>> {
+        self.rpcs.iter_mut().find(|rpc| rpc.name == name)
+    }
+
+    /// Applies [crate::model::TypeRef::apply_type_params] to the types of all rpcs.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        for rpc in &mut self.rpcs {
+            rpc.apply_type_params(type_params);
+        }
+    }
+}
+
+impl ToEntity for Interface<'_> {
+    fn to_entity(&self) -> Entity<'_, '_> {
+        Entity::Interface(self)
+    }
+}
+
+impl AttributesHolder for Interface<'_> {
+    fn attributes(&self) -> &Attributes<'_> {
+        &self.attributes
+    }
+}
+
+impl<'api> FindEntity<'api> for Interface<'api> {
+    fn qualify_id(&self, mut id: EntityId, referenceable: bool) -> anyhow::Result<EntityId> {
+        match id.pop_front() {
+            None => Ok(EntityId::default()),
+            Some((_, name)) => {
+                if referenceable {
+                    return Err(anyhow!(
+                        "failed to qualify_id {}, interface has no referenceable types",
+                        name
+                    ));
+                }
+                match self.rpc(&name) {
+                    Some(rpc) => Ok(EntityId::new(EntityType::Rpc, name)
+                        .concat(&rpc.qualify_id(id, referenceable)?)?),
+                    None => Err(anyhow!("qualify_id: failed to find interface rpc {}", name)),
+                }
+            }
+        }
+    }
+
+    fn find_entity<'a>(&'a self, mut id: EntityId) -> Option<Entity<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Rpc => self.rpc(&name).and_then(|x| x.find_entity(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::Type => None,
+            }
+        } else {
+            Some(Entity::Interface(self))
+        }
+    }
+
+    fn find_entity_mut<'a>(&'a mut self, mut id: EntityId) -> Option<EntityMut<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Rpc => self.rpc_mut(&name).and_then(|x| x.find_entity_mut(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::Type => None,
+            }
+        } else {
+            Some(EntityMut::Interface(self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::entity::FindEntity;
+    use crate::model::{EntityId, Interface, Rpc};
+
+    #[test]
+    fn qualify_rpc() {
+        let interface = Interface {
+            name: "Service",
+            rpcs: vec![Rpc {
+                name: "get".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            interface
+                .qualify_id(EntityId::new_unqualified("get"), false)
+                .unwrap(),
+            EntityId::try_from("r:get").unwrap()
+        );
+        assert!(interface
+            .qualify_id(EntityId::new_unqualified("get"), true)
+            .is_err());
+    }
+}
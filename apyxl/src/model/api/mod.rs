@@ -1,24 +1,36 @@
 pub use attributes::Attributes;
 pub use attributes::Comment;
+pub use attributes::Deprecation;
+pub use attributes::Docs;
+pub use attributes::ParamDoc;
 pub use dependencies::Dependencies;
 pub use dto::Dto;
 pub use en::Enum;
+pub use en::EnumKind;
 pub use en::EnumValue;
 pub use en::EnumValueNumber;
 pub use entity::Entity;
 pub use entity::EntityType;
 pub use entity_id::EntityId;
 pub use field::Field;
+pub use interface::Interface;
 pub use namespace::Namespace;
 pub use namespace::NamespaceChild;
 pub use rpc::Rpc;
+pub use rpc::RpcKind;
+pub use rpc::Streaming;
 pub use ty::BaseType;
 pub use ty::Semantics;
 pub use ty::Type;
+pub use ty::TupleElement;
 pub use ty::TypeRef;
 pub use ty::UserTypeName;
 pub use ty_alias::TypeAlias;
+pub use union::Union;
+pub use union::Variant;
+pub use union::VariantPayload;
 pub use validate::ValidationError;
+pub use value::Value;
 
 pub mod attributes;
 mod dependencies;
@@ -27,11 +39,15 @@ mod en;
 pub mod entity;
 mod entity_id;
 mod field;
+mod interface;
 mod namespace;
 mod rpc;
 mod ty;
 mod ty_alias;
+mod union;
 pub mod validate;
+pub mod well_known;
+mod value;
 
 use crate::model::entity::FindEntity;
 use anyhow::{anyhow, Result};
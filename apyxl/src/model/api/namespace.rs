@@ -1,31 +1,52 @@
 use crate::model::api::entity::{Entity, EntityType, ToEntity};
 use crate::model::attributes::AttributesHolder;
 use crate::model::entity::{EntityMut, FindEntity};
-use crate::model::{Attributes, Dto, EntityId, Enum, Field, Rpc, TypeAlias};
+use crate::model::{Attributes, Dto, EntityId, Enum, Field, Interface, Rpc, TypeAlias, Union};
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
 
 /// A named, nestable wrapper for a set of API entities.
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Namespace<'a> {
+    #[serde(borrow)]
     pub name: Cow<'a, str>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<NamespaceChild<'a>>,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 
     /// 'virtual' is a temporary namespace indicating it belongs to a [Dto] and should be moved
     /// to the [Dto] at build time. Useful for handling [Rpc]s or other [Dto]s nested inside
     /// or that belong to a [Dto].
+    #[serde(default)]
     pub is_virtual: bool,
+
+    /// Only used by 'virtual' namespaces. [Interface]s implemented by the [Dto], e.g. from
+    /// `impl Trait for Dto`, which are moved to [Dto::implements] at build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<EntityId>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NamespaceChild<'a> {
+    #[serde(borrow)]
     Field(Field<'a>),
+    #[serde(borrow)]
     Dto(Dto<'a>),
+    #[serde(borrow)]
     Rpc(Rpc<'a>),
+    #[serde(borrow)]
     Enum(Enum<'a>),
+    #[serde(borrow)]
+    Union(Union<'a>),
+    #[serde(borrow)]
+    Interface(Interface<'a>),
+    #[serde(borrow)]
     TypeAlias(TypeAlias<'a>),
+    #[serde(borrow)]
     Namespace(Namespace<'a>),
 }
 
@@ -73,10 +94,12 @@ impl<'api> FindEntity<'api> for Namespace<'api> {
                 EntityType::Dto => self.dto(&name).and_then(|x| x.find_entity(id)),
                 EntityType::Rpc => self.rpc(&name).and_then(|x| x.find_entity(id)),
                 EntityType::Enum => self.en(&name).and_then(|x| x.find_entity(id)),
+                EntityType::Union => self.union(&name).and_then(|x| x.find_entity(id)),
+                EntityType::Interface => self.interface(&name).and_then(|x| x.find_entity(id)),
                 EntityType::TypeAlias => self.ty_alias(&name).and_then(|x| x.find_entity(id)),
                 EntityType::Field => self.field(&name).and_then(|x| x.find_entity(id)),
 
-                EntityType::None | EntityType::Type => None,
+                EntityType::None | EntityType::Variant | EntityType::Type => None,
             }
         } else {
             Some(Entity::Namespace(self))
@@ -92,12 +115,16 @@ impl<'api> FindEntity<'api> for Namespace<'api> {
                 EntityType::Dto => self.dto_mut(&name).and_then(|x| x.find_entity_mut(id)),
                 EntityType::Rpc => self.rpc_mut(&name).and_then(|x| x.find_entity_mut(id)),
                 EntityType::Enum => self.en_mut(&name).and_then(|x| x.find_entity_mut(id)),
+                EntityType::Union => self.union_mut(&name).and_then(|x| x.find_entity_mut(id)),
+                EntityType::Interface => self
+                    .interface_mut(&name)
+                    .and_then(|x| x.find_entity_mut(id)),
                 EntityType::TypeAlias => {
                     self.ty_alias_mut(&name).and_then(|x| x.find_entity_mut(id))
                 }
                 EntityType::Field => self.field_mut(&name).and_then(|x| x.find_entity_mut(id)),
 
-                EntityType::None | EntityType::Type => None,
+                EntityType::None | EntityType::Variant | EntityType::Type => None,
             }
         } else {
             Some(EntityMut::Namespace(self))
@@ -111,6 +138,7 @@ impl<'a> Namespace<'a> {
     /// result in duplicate children.
     pub fn merge(&mut self, mut other: Namespace<'a>) {
         self.children.append(&mut other.children);
+        self.implements.append(&mut other.implements);
         self.attributes.merge(other.attributes);
     }
 
@@ -135,6 +163,20 @@ impl<'a> Namespace<'a> {
         self.children.push(NamespaceChild::Enum(en));
     }
 
+    /// Add the [Union] `union` as a child of this [Namespace].
+    /// No validation is performed to ensure the [Union] does not already exist, which may result
+    /// in duplicates.
+    pub fn add_union(&mut self, union: Union<'a>) {
+        self.children.push(NamespaceChild::Union(union));
+    }
+
+    /// Add the [Interface] `interface` as a child of this [Namespace].
+    /// No validation is performed to ensure the [Interface] does not already exist, which may
+    /// result in duplicates.
+    pub fn add_interface(&mut self, interface: Interface<'a>) {
+        self.children.push(NamespaceChild::Interface(interface));
+    }
+
     /// Add the [TypeAlias] `ty_alias` as a child of this [Namespace].
     /// No validation is performed to ensure the [TypeAlias] does not already exist, which may result
     /// in duplicates.
@@ -216,6 +258,38 @@ impl<'a> Namespace<'a> {
         })
     }
 
+    /// Get a [Union] within this [Namespace] by name.
+    pub fn union(&self, name: &str) -> Option<&Union<'a>> {
+        self.children.iter().find_map(|s| match s {
+            NamespaceChild::Union(union) if union.name == name => Some(union),
+            _ => None,
+        })
+    }
+
+    /// Get a mutable [Union] within this [Namespace] by name.
+    pub fn union_mut(&mut self, name: &str) -> Option<&mut Union<'a>> {
+        self.children.iter_mut().find_map(|s| match s {
+            NamespaceChild::Union(union) if union.name == name => Some(union),
+            _ => None,
+        })
+    }
+
+    /// Get an [Interface] within this [Namespace] by name.
+    pub fn interface(&self, name: &str) -> Option<&Interface<'a>> {
+        self.children.iter().find_map(|s| match s {
+            NamespaceChild::Interface(interface) if interface.name == name => Some(interface),
+            _ => None,
+        })
+    }
+
+    /// Get a mutable [Interface] within this [Namespace] by name.
+    pub fn interface_mut(&mut self, name: &str) -> Option<&mut Interface<'a>> {
+        self.children.iter_mut().find_map(|s| match s {
+            NamespaceChild::Interface(interface) if interface.name == name => Some(interface),
+            _ => None,
+        })
+    }
+
     /// Get a [TypeAlias] within this [Namespace] by name.
     pub fn ty_alias(&self, name: &str) -> Option<&TypeAlias<'a>> {
         self.children.iter().find_map(|s| match s {
@@ -336,6 +410,50 @@ impl<'a> Namespace<'a> {
         })
     }
 
+    /// Iterate over all [Union]s within this [Namespace].
+    pub fn unions(&self) -> impl Iterator<Item = &Union<'a>> {
+        self.children.iter().filter_map(|child| {
+            if let NamespaceChild::Union(value) = child {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Iterate over all [Union]s mutably within this [Namespace].
+    pub fn unions_mut(&mut self) -> impl Iterator<Item = &mut Union<'a>> {
+        self.children.iter_mut().filter_map(|child| {
+            if let NamespaceChild::Union(value) = child {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Iterate over all [Interface]s within this [Namespace].
+    pub fn interfaces(&self) -> impl Iterator<Item = &Interface<'a>> {
+        self.children.iter().filter_map(|child| {
+            if let NamespaceChild::Interface(value) = child {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Iterate over all [Interface]s mutably within this [Namespace].
+    pub fn interfaces_mut(&mut self) -> impl Iterator<Item = &mut Interface<'a>> {
+        self.children.iter_mut().filter_map(|child| {
+            if let NamespaceChild::Interface(value) = child {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Iterate over all [TypeAlias]s within this [Namespace].
     pub fn ty_aliases(&self) -> impl Iterator<Item = &TypeAlias<'a>> {
         self.children.iter().filter_map(|child| {
@@ -525,6 +643,70 @@ impl<'a> Namespace<'a> {
         }
     }
 
+    /// Find a [Union] by [EntityId] relative to this [Namespace].
+    pub fn find_union(&self, entity_id: &EntityId) -> Option<&Union<'a>> {
+        let name = unqualified_name(entity_id)?;
+        if let Some(namespace) = self.find_namespace(&unqualified_namespace(entity_id)) {
+            namespace.union(name)
+        } else if let Some(dto) = self.find_dto(&unqualified_namespace(entity_id)) {
+            dto.namespace.as_ref()?.union(name)
+        } else {
+            None
+        }
+    }
+
+    /// Find a mutable [Union] by [EntityId] relative to this [Namespace].
+    pub fn find_union_mut(&mut self, entity_id: &EntityId) -> Option<&mut Union<'a>> {
+        let name = unqualified_name(entity_id)?;
+        // This if is a workaround for multiple mutable borrows.
+        if self
+            .find_namespace_mut(&unqualified_namespace(entity_id))
+            .is_some()
+        {
+            let namespace = self
+                .find_namespace_mut(&unqualified_namespace(entity_id))
+                .unwrap();
+            return namespace.union_mut(name);
+        }
+        if let Some(dto) = self.find_dto_mut(&unqualified_namespace(entity_id)) {
+            dto.namespace.as_mut()?.union_mut(name)
+        } else {
+            None
+        }
+    }
+
+    /// Find an [Interface] by [EntityId] relative to this [Namespace].
+    pub fn find_interface(&self, entity_id: &EntityId) -> Option<&Interface<'a>> {
+        let name = unqualified_name(entity_id)?;
+        if let Some(namespace) = self.find_namespace(&unqualified_namespace(entity_id)) {
+            namespace.interface(name)
+        } else if let Some(dto) = self.find_dto(&unqualified_namespace(entity_id)) {
+            dto.namespace.as_ref()?.interface(name)
+        } else {
+            None
+        }
+    }
+
+    /// Find a mutable [Interface] by [EntityId] relative to this [Namespace].
+    pub fn find_interface_mut(&mut self, entity_id: &EntityId) -> Option<&mut Interface<'a>> {
+        let name = unqualified_name(entity_id)?;
+        // This if is a workaround for multiple mutable borrows.
+        if self
+            .find_namespace_mut(&unqualified_namespace(entity_id))
+            .is_some()
+        {
+            let namespace = self
+                .find_namespace_mut(&unqualified_namespace(entity_id))
+                .unwrap();
+            return namespace.interface_mut(name);
+        }
+        if let Some(dto) = self.find_dto_mut(&unqualified_namespace(entity_id)) {
+            dto.namespace.as_mut()?.interface_mut(name)
+        } else {
+            None
+        }
+    }
+
     /// Find a [TypeAlias] by [EntityId] relative to this [Namespace].
     pub fn find_ty_alias(&self, entity_id: &EntityId) -> Option<&TypeAlias<'a>> {
         let namespace = self.find_namespace(&unqualified_namespace(entity_id));
@@ -622,6 +804,23 @@ impl<'a> Namespace<'a> {
         }
     }
 
+    /// Applies [crate::model::TypeRef::apply_type_params] to the types of all children,
+    /// recursively. Used by parsers to apply the type parameters of an enclosing generic entity.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        for child in &mut self.children {
+            match child {
+                NamespaceChild::Dto(dto) => dto.apply_type_params(type_params),
+                NamespaceChild::Rpc(rpc) => rpc.apply_type_params(type_params),
+                NamespaceChild::TypeAlias(alias) => alias.target_ty.apply_type_params(type_params),
+                NamespaceChild::Field(field) => field.ty.apply_type_params(type_params),
+                NamespaceChild::Namespace(namespace) => namespace.apply_type_params(type_params),
+                NamespaceChild::Union(union) => union.apply_type_params(type_params),
+                NamespaceChild::Interface(interface) => interface.apply_type_params(type_params),
+                NamespaceChild::Enum(_) => {}
+            }
+        }
+    }
+
     pub fn extract_non_static<'b>(&'b mut self) -> (Vec<Field<'a>>, Vec<Rpc<'a>>) {
         let mut fields = vec![];
         let mut rpcs = vec![];
@@ -643,6 +842,8 @@ impl<'a> NamespaceChild<'a> {
             NamespaceChild::Dto(dto) => &dto.name,
             NamespaceChild::Rpc(rpc) => &rpc.name,
             NamespaceChild::Enum(en) => &en.name,
+            NamespaceChild::Union(union) => &union.name,
+            NamespaceChild::Interface(interface) => interface.name,
             NamespaceChild::Namespace(namespace) => &namespace.name,
             NamespaceChild::TypeAlias(alias) => &alias.name,
             NamespaceChild::Field(field) => &field.name,
@@ -654,6 +855,8 @@ impl<'a> NamespaceChild<'a> {
             NamespaceChild::Dto(dto) => &dto.attributes,
             NamespaceChild::Rpc(rpc) => &rpc.attributes,
             NamespaceChild::Enum(en) => &en.attributes,
+            NamespaceChild::Union(union) => &union.attributes,
+            NamespaceChild::Interface(interface) => &interface.attributes,
             NamespaceChild::Namespace(namespace) => &namespace.attributes,
             NamespaceChild::TypeAlias(alias) => &alias.attributes,
             NamespaceChild::Field(field) => &field.attributes,
@@ -665,6 +868,8 @@ impl<'a> NamespaceChild<'a> {
             NamespaceChild::Dto(dto) => &mut dto.attributes,
             NamespaceChild::Rpc(rpc) => &mut rpc.attributes,
             NamespaceChild::Enum(en) => &mut en.attributes,
+            NamespaceChild::Union(union) => &mut union.attributes,
+            NamespaceChild::Interface(interface) => &mut interface.attributes,
             NamespaceChild::Namespace(namespace) => &mut namespace.attributes,
             NamespaceChild::TypeAlias(alias) => &mut alias.attributes,
             NamespaceChild::Field(field) => &mut field.attributes,
@@ -682,6 +887,8 @@ impl ToEntity for NamespaceChild<'_> {
             NamespaceChild::Dto(dto) => dto.to_entity(),
             NamespaceChild::Rpc(rpc) => rpc.to_entity(),
             NamespaceChild::Enum(en) => en.to_entity(),
+            NamespaceChild::Union(union) => union.to_entity(),
+            NamespaceChild::Interface(interface) => interface.to_entity(),
             NamespaceChild::Namespace(namespace) => namespace.to_entity(),
             NamespaceChild::TypeAlias(alias) => alias.to_entity(),
             NamespaceChild::Field(field) => field.to_entity(),
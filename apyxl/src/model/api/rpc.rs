@@ -4,17 +4,80 @@ use crate::model::entity::{EntityMut, FindEntity};
 use crate::model::{entity, Attributes, Entity, EntityId, EntityType, Field, TypeRef};
 use anyhow::anyhow;
 use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
 
 /// A single Remote Procedure Call (RPC) within an [Api].
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Rpc<'a> {
+    #[serde(borrow)]
     pub name: Cow<'a, str>,
+    /// Names of the generic type parameters, e.g. `T` in `fn rpc<T>(value: T)`. Parameters of
+    /// an enclosing [crate::model::Dto] are also in scope and are not repeated here.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<&'a str>,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
     pub params: Vec<Field<'a>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub return_type: Option<TypeRef>,
+    /// Type of the error returned by a fallible [Rpc], e.g. `E` in `fn rpc() -> Result<T, E>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<TypeRef>,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 
     /// True if owned by a namespace rather than a Dto.
+    #[serde(default)]
     pub is_static: bool,
+
+    /// Asynchrony and streaming of the [Rpc]. Wrapper types that imply it, e.g. C# `Task<T>`, are
+    /// removed from the `params` and `return_type` by parsers.
+    #[serde(default)]
+    pub kind: RpcKind,
+}
+
+/// How an [Rpc] is invoked and how values flow between the caller and the callee.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcKind {
+    /// True if the [Rpc] completes asynchronously, e.g. `async fn` or a C# `Task<T>` return.
+    #[serde(default)]
+    pub is_async: bool,
+    #[serde(default)]
+    pub streaming: Streaming,
+}
+
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Streaming {
+    /// A single request and a single response.
+    #[default]
+    Unary,
+    /// The callee streams values of the `return_type`.
+    Server,
+    /// The caller streams values of every param, e.g. a C# `IAsyncEnumerable<T>` param. Typically
+    /// there is a single param. Parsers reject rpcs that stream only some of their params.
+    Client,
+    /// Both [Streaming::Client] and [Streaming::Server].
+    Bidirectional,
+}
+
+impl Streaming {
+    /// Combines whether the caller and the callee stream values.
+    pub fn new(client: bool, server: bool) -> Self {
+        match (client, server) {
+            (false, false) => Self::Unary,
+            (false, true) => Self::Server,
+            (true, false) => Self::Client,
+            (true, true) => Self::Bidirectional,
+        }
+    }
+
+    pub fn is_client(&self) -> bool {
+        matches!(self, Self::Client | Self::Bidirectional)
+    }
+
+    pub fn is_server(&self) -> bool {
+        matches!(self, Self::Server | Self::Bidirectional)
+    }
 }
 
 impl<'a> Rpc<'a> {
@@ -25,6 +88,20 @@ impl<'a> Rpc<'a> {
     pub fn param_mut(&mut self, name: &str) -> Option<&mut Field<'a>> {
         self.params.iter_mut().find(|param| param.name == name)
     }
+
+    /// Applies [TypeRef::apply_type_params] to the types of all params, the return type, and the
+    /// error type.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        for param in &mut self.params {
+            param.ty.apply_type_params(type_params);
+        }
+        if let Some(return_type) = &mut self.return_type {
+            return_type.apply_type_params(type_params);
+        }
+        if let Some(error_type) = &mut self.error_type {
+            error_type.apply_type_params(type_params);
+        }
+    }
 }
 
 impl ToEntity for Rpc<'_> {
@@ -53,6 +130,8 @@ impl<'api> FindEntity<'api> for Rpc<'api> {
                         .concat(&param.qualify_id(id, referenceable)?)?)
                 } else if child_name.as_str() == entity::subtype::RETURN_TY {
                     Ok(EntityId::new(EntityType::Type, entity::subtype::RETURN_TY))
+                } else if child_name.as_str() == entity::subtype::ERROR_TY {
+                    Ok(EntityId::new(EntityType::Type, entity::subtype::ERROR_TY))
                 } else {
                     Err(anyhow!(
                         "failed to qualify_id: {} is an invalid rpc child",
@@ -71,6 +150,8 @@ impl<'api> FindEntity<'api> for Rpc<'api> {
                 EntityType::Type => {
                     if entity::subtype::RETURN_TY_ALL.contains(&name.as_str()) {
                         self.return_type.as_ref().map(Entity::Type)
+                    } else if entity::subtype::ERROR_TY_ALL.contains(&name.as_str()) {
+                        self.error_type.as_ref().map(Entity::Type)
                     } else {
                         None
                     }
@@ -81,7 +162,10 @@ impl<'api> FindEntity<'api> for Rpc<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::TypeAlias
-                | EntityType::Enum => None,
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface => None,
             }
         } else {
             Some(Entity::Rpc(self))
@@ -96,6 +180,8 @@ impl<'api> FindEntity<'api> for Rpc<'api> {
                 EntityType::Type => {
                     if entity::subtype::RETURN_TY_ALL.contains(&name.as_str()) {
                         self.return_type.as_mut().map(EntityMut::Type)
+                    } else if entity::subtype::ERROR_TY_ALL.contains(&name.as_str()) {
+                        self.error_type.as_mut().map(EntityMut::Type)
                     } else {
                         None
                     }
@@ -106,7 +192,10 @@ impl<'api> FindEntity<'api> for Rpc<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::TypeAlias
-                | EntityType::Enum => None,
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface => None,
             }
         } else {
             Some(EntityMut::Rpc(self))
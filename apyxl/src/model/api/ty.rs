@@ -4,6 +4,7 @@ use crate::model::entity::{EntityMut, FindEntity};
 use crate::model::{Entity, EntityId, Namespace};
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 /// A type within the language or API. Types other than [TypeRef::Api] are assumed to always
 /// exist during API validation and can be used by [crate::Generator]s to map to the relevant known
@@ -15,7 +16,8 @@ use itertools::Itertools;
 /// exist after the API is built.
 ///
 /// This is generic so that view::Type can provide relevant view types for variants with data.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BaseType<TypeRef, ApiType, UserTypeName>
 where
     TypeRef: Debug + Clone,
@@ -30,6 +32,7 @@ where
     U32,
     U64,
     U128,
+    #[serde(rename = "usize")]
     USIZE,
 
     // Signed integers.
@@ -65,9 +68,29 @@ where
     /// the API when built.
     Api(ApiType),
 
+    /// Instantiation of a generic type within the API, e.g. `Page<User>`. `ty` must reference an
+    /// existing [crate::model::Dto] or [crate::model::TypeAlias] whose number of type parameters
+    /// matches the number of `args`.
+    Generic { ty: ApiType, args: Vec<TypeRef> },
+
+    /// Reference to a type parameter, e.g. the `T` in `struct Page<T> { items: Vec<T> }`. The
+    /// parameter must be declared by the entity using it or by an enclosing [crate::model::Dto].
+    TypeParam(UserTypeName),
+
     /// An array of the contained type.
     Array(Box<TypeRef>),
 
+    /// An unordered collection of unique values of the contained type. Generators for targets
+    /// without sets should fall back to an [BaseType::Array] of the contained type.
+    Set(Box<TypeRef>),
+
+    /// An array of exactly `len` elements of the contained type, e.g. `[u8; 32]`.
+    FixedArray { ty: Box<TypeRef>, len: usize },
+
+    /// An ordered, fixed-size list of types, e.g. `(u32, String)`. Elements may be named, e.g.
+    /// C#'s `(int x, int y)`.
+    Tuple(Vec<TupleElement<TypeRef, UserTypeName>>),
+
     /// A key-value map.
     Map {
         key: Box<TypeRef>,
@@ -88,17 +111,52 @@ where
 pub type UserTypeName = String;
 pub type Type = BaseType<TypeRef, EntityId, UserTypeName>;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// A single element of a [BaseType::Tuple].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TupleElement<TypeRef, Name> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Name>,
+    pub ty: TypeRef,
+}
+
+impl<TypeRef, Name> TupleElement<TypeRef, Name> {
+    pub fn new(name: Option<Name>, ty: TypeRef) -> Self {
+        Self { name, ty }
+    }
+
+    pub fn unnamed(ty: TypeRef) -> Self {
+        Self { name: None, ty }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TypeRef {
     pub value: Type,
     pub semantics: Semantics,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Semantics {
     Value,
     Ref,
     Mut,
+    /// An owned value behind a unique pointer, e.g. Rust's `Box<T>`.
+    Boxed,
+    /// A value behind a reference counted pointer, e.g. Rust's `Rc<T>` or `Arc<T>`.
+    Shared,
+    /// A borrowed view of contiguous values, e.g. Rust's `&[T]` and `&str` or C#'s
+    /// `ReadOnlySpan<T>`. The value is a [BaseType::Array], [BaseType::Bytes], or
+    /// [BaseType::StringView].
+    Slice,
+}
+
+impl Semantics {
+    /// True if the value is borrowed rather than owned, i.e. [Semantics::Ref], [Semantics::Mut],
+    /// or [Semantics::Slice].
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Semantics::Ref | Semantics::Mut | Semantics::Slice)
+    }
 }
 
 impl TypeRef {
@@ -131,6 +189,22 @@ impl TypeRef {
         Self::new(Type::Optional(Box::new(ty)), semantics)
     }
 
+    pub fn new_set(ty: TypeRef, semantics: Semantics) -> Self {
+        Self::new(Type::new_set(ty), semantics)
+    }
+
+    pub fn new_fixed_array(ty: TypeRef, len: usize, semantics: Semantics) -> Self {
+        Self::new(Type::new_fixed_array(ty, len), semantics)
+    }
+
+    pub fn new_tuple(tys: impl IntoIterator<Item = TypeRef>, semantics: Semantics) -> Self {
+        Self::new(Type::new_tuple(tys), semantics)
+    }
+
+    pub fn new_generic(id: EntityId, args: Vec<TypeRef>, semantics: Semantics) -> Self {
+        Self::new(Type::new_generic(id, args), semantics)
+    }
+
     pub fn new_function(
         params: impl IntoIterator<Item = TypeRef>,
         return_ty: Option<TypeRef>,
@@ -148,6 +222,11 @@ impl TypeRef {
     pub fn is_primitive(&self, api: &Namespace) -> bool {
         self.value.is_primitive(api)
     }
+
+    /// See [Type::apply_type_params].
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        self.value.apply_type_params(type_params)
+    }
 }
 
 impl Type {
@@ -178,6 +257,26 @@ impl Type {
         Self::Optional(Box::new(ty))
     }
 
+    pub fn new_set(ty: TypeRef) -> Self {
+        Self::Set(Box::new(ty))
+    }
+
+    pub fn new_fixed_array(ty: TypeRef, len: usize) -> Self {
+        Self::FixedArray {
+            ty: Box::new(ty),
+            len,
+        }
+    }
+
+    /// A tuple of unnamed elements.
+    pub fn new_tuple(tys: impl IntoIterator<Item = TypeRef>) -> Self {
+        Self::Tuple(tys.into_iter().map(TupleElement::unnamed).collect_vec())
+    }
+
+    pub fn new_generic(id: EntityId, args: Vec<TypeRef>) -> Self {
+        Self::Generic { ty: id, args }
+    }
+
     pub fn new_function(
         params: impl IntoIterator<Item = TypeRef>,
         return_ty: Option<TypeRef>,
@@ -221,9 +320,83 @@ impl Type {
             | Type::Bytes
             | Type::User(_)
             | Type::Array(_)
+            | Type::Set(_)
+            | Type::FixedArray { .. }
+            | Type::Tuple(_)
             | Type::Map { .. }
             | Type::Optional(_)
-            | Type::Function { .. } => false,
+            | Type::Function { .. }
+            | Type::Generic { .. }
+            | Type::TypeParam(_) => false,
+        }
+    }
+
+    /// Replaces every [Type::Api] with a single-component [EntityId] that matches one of
+    /// `type_params` with a [Type::TypeParam], recursively.
+    ///
+    /// Parsers typically can't tell the two apart while parsing a type, so this should be applied
+    /// to each type once the type parameters in scope are known.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        if type_params.is_empty() {
+            return;
+        }
+        match self {
+            Type::Api(id) => {
+                if id.len() == 1 {
+                    let name = id.component_names().next().unwrap();
+                    if type_params.contains(&name) {
+                        *self = Type::TypeParam(name.to_string());
+                    }
+                }
+            }
+            Type::Generic { args, .. } => {
+                for arg in args {
+                    arg.apply_type_params(type_params);
+                }
+            }
+            Type::Array(ty) | Type::Set(ty) | Type::FixedArray { ty, .. } | Type::Optional(ty) => {
+                ty.apply_type_params(type_params)
+            }
+            Type::Tuple(elements) => {
+                for element in elements {
+                    element.ty.apply_type_params(type_params);
+                }
+            }
+            Type::Map { key, value } => {
+                key.apply_type_params(type_params);
+                value.apply_type_params(type_params);
+            }
+            Type::Function { params, return_ty } => {
+                for param in params {
+                    param.apply_type_params(type_params);
+                }
+                if let Some(return_ty) = return_ty {
+                    return_ty.apply_type_params(type_params);
+                }
+            }
+
+            Type::Bool
+            | Type::U8
+            | Type::U16
+            | Type::U32
+            | Type::U64
+            | Type::U128
+            | Type::USIZE
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::I128
+            | Type::F8
+            | Type::F16
+            | Type::F32
+            | Type::F64
+            | Type::F128
+            | Type::String
+            | Type::StringView
+            | Type::Bytes
+            | Type::User(_)
+            | Type::TypeParam(_) => {}
         }
     }
 }
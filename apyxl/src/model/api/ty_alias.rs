@@ -2,12 +2,17 @@ use crate::model::attributes::AttributesHolder;
 use crate::model::entity::{EntityMut, FindEntity, ToEntity};
 use crate::model::{entity, Attributes, Entity, EntityId, EntityType, TypeRef};
 use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
 
 /// A single enum type in the within an [Api].
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TypeAlias<'a> {
     pub name: &'a str,
+    /// Names of the generic type parameters, e.g. `T` in `type Page<T> = Vec<T>`.
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<&'a str>,
     pub target_ty: TypeRef,
+    #[serde(borrow, default)]
     pub attributes: Attributes<'a>,
 }
 
@@ -65,6 +70,9 @@ impl<'api> FindEntity<'api> for TypeAlias<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field => None,
             }
@@ -89,6 +97,9 @@ impl<'api> FindEntity<'api> for TypeAlias<'api> {
                 | EntityType::Dto
                 | EntityType::Rpc
                 | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
                 | EntityType::TypeAlias
                 | EntityType::Field => None,
             }
@@ -0,0 +1,265 @@
+use crate::model::api::entity::ToEntity;
+use crate::model::attributes::AttributesHolder;
+use crate::model::entity::{EntityMut, FindEntity};
+use crate::model::{Attributes, Entity, EntityId, EntityType, Field, TypeRef};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+/// A tagged union (sum type) within an [Api], e.g. a Rust `enum` with data. Unlike an
+/// [crate::model::Enum], each [Variant] can carry its own payload.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Union<'a> {
+    pub name: &'a str,
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<Variant<'a>>,
+    #[serde(borrow, default)]
+    pub attributes: Attributes<'a>,
+}
+
+/// A single case of a [Union].
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Variant<'a> {
+    pub name: &'a str,
+    /// Data carried by the variant. `None` for unit variants.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<VariantPayload<'a>>,
+    #[serde(borrow, default)]
+    pub attributes: Attributes<'a>,
+}
+
+/// Data carried by a [Variant].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantPayload<'a> {
+    /// Positional types, e.g. `Move(i32, i32)`. Each type is addressed by its index, e.g.
+    /// `v:Move.ty:0`.
+    Tuple(Vec<TypeRef>),
+    /// Named fields, e.g. `Login { user: Id }`.
+    #[serde(borrow)]
+    Struct(Vec<Field<'a>>),
+}
+
+impl<'a> Union<'a> {
+    pub fn variant(&self, name: &str) -> Option<&Variant<'a>> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    pub fn variant_mut(&mut self, name: &str) -> Option<&mut Variant<'a>> {
+        self.variants.iter_mut().find(|variant| variant.name == name)
+    }
+
+    /// Applies [TypeRef::apply_type_params] to the payload types of all variants.
+    pub fn apply_type_params(&mut self, type_params: &[&str]) {
+        for variant in &mut self.variants {
+            match &mut variant.payload {
+                Some(VariantPayload::Tuple(tys)) => {
+                    for ty in tys {
+                        ty.apply_type_params(type_params);
+                    }
+                }
+                Some(VariantPayload::Struct(fields)) => {
+                    for field in fields {
+                        field.ty.apply_type_params(type_params);
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+impl<'a> Variant<'a> {
+    /// Get a [Field] of a struct payload by name.
+    pub fn field(&self, name: &str) -> Option<&Field<'a>> {
+        match &self.payload {
+            Some(VariantPayload::Struct(fields)) => fields.iter().find(|field| field.name == name),
+            Some(VariantPayload::Tuple(_)) | None => None,
+        }
+    }
+
+    /// Get a mutable [Field] of a struct payload by name.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Field<'a>> {
+        match &mut self.payload {
+            Some(VariantPayload::Struct(fields)) => {
+                fields.iter_mut().find(|field| field.name == name)
+            }
+            Some(VariantPayload::Tuple(_)) | None => None,
+        }
+    }
+
+    /// Get a type of a tuple payload by its index, as a string.
+    pub fn tuple_ty(&self, index: &str) -> Option<&TypeRef> {
+        match &self.payload {
+            Some(VariantPayload::Tuple(tys)) => tys.get(index.parse::<usize>().ok()?),
+            Some(VariantPayload::Struct(_)) | None => None,
+        }
+    }
+
+    /// Get a mutable type of a tuple payload by its index, as a string.
+    pub fn tuple_ty_mut(&mut self, index: &str) -> Option<&mut TypeRef> {
+        match &mut self.payload {
+            Some(VariantPayload::Tuple(tys)) => tys.get_mut(index.parse::<usize>().ok()?),
+            Some(VariantPayload::Struct(_)) | None => None,
+        }
+    }
+}
+
+impl ToEntity for Union<'_> {
+    fn to_entity(&self) -> Entity<'_, '_> {
+        Entity::Union(self)
+    }
+}
+
+impl AttributesHolder for Union<'_> {
+    fn attributes(&self) -> &Attributes<'_> {
+        &self.attributes
+    }
+}
+
+impl ToEntity for Variant<'_> {
+    fn to_entity(&self) -> Entity<'_, '_> {
+        Entity::Variant(self)
+    }
+}
+
+impl AttributesHolder for Variant<'_> {
+    fn attributes(&self) -> &Attributes<'_> {
+        &self.attributes
+    }
+}
+
+impl<'api> FindEntity<'api> for Union<'api> {
+    fn qualify_id(&self, mut id: EntityId, referenceable: bool) -> anyhow::Result<EntityId> {
+        match id.pop_front() {
+            None => Ok(EntityId::default()),
+            Some((_, name)) => {
+                if referenceable {
+                    return Err(anyhow!(
+                        "failed to qualify_id {}, union has no referenceable types",
+                        name
+                    ));
+                }
+                match self.variant(&name) {
+                    Some(variant) => Ok(EntityId::new(EntityType::Variant, name)
+                        .concat(&variant.qualify_id(id, referenceable)?)?),
+                    None => Err(anyhow!(
+                        "qualify_id: failed to find union variant {}",
+                        name
+                    )),
+                }
+            }
+        }
+    }
+
+    fn find_entity<'a>(&'a self, mut id: EntityId) -> Option<Entity<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Variant => self.variant(&name).and_then(|x| x.find_entity(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Interface
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::Type => None,
+            }
+        } else {
+            Some(Entity::Union(self))
+        }
+    }
+
+    fn find_entity_mut<'a>(&'a mut self, mut id: EntityId) -> Option<EntityMut<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Variant => self
+                    .variant_mut(&name)
+                    .and_then(|x| x.find_entity_mut(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Interface
+                | EntityType::TypeAlias
+                | EntityType::Field
+                | EntityType::Type => None,
+            }
+        } else {
+            Some(EntityMut::Union(self))
+        }
+    }
+}
+
+impl<'api> FindEntity<'api> for Variant<'api> {
+    fn qualify_id(&self, mut id: EntityId, referenceable: bool) -> anyhow::Result<EntityId> {
+        if referenceable {
+            return Err(anyhow!("variants are not referenceable"));
+        }
+        match id.pop_front() {
+            None => Ok(EntityId::default()),
+            Some((_, name)) => {
+                if let Some(field) = self.field(&name) {
+                    Ok(EntityId::new(EntityType::Field, name)
+                        .concat(&field.qualify_id(id, referenceable)?)?)
+                } else if self.tuple_ty(&name).is_some() && id.is_empty() {
+                    Ok(EntityId::new(EntityType::Type, name))
+                } else {
+                    Err(anyhow!(
+                        "failed to qualify_id: {} is an invalid variant child",
+                        name
+                    ))
+                }
+            }
+        }
+    }
+
+    fn find_entity<'a>(&'a self, mut id: EntityId) -> Option<Entity<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Field => self.field(&name).and_then(|x| x.find_entity(id)),
+                EntityType::Type => self.tuple_ty(&name).and_then(|x| x.find_entity(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::TypeAlias => None,
+            }
+        } else {
+            Some(Entity::Variant(self))
+        }
+    }
+
+    fn find_entity_mut<'a>(&'a mut self, mut id: EntityId) -> Option<EntityMut<'a, 'api>> {
+        if let Some((ty, name)) = id.pop_front() {
+            match ty {
+                EntityType::Field => self.field_mut(&name).and_then(|x| x.find_entity_mut(id)),
+                EntityType::Type => self
+                    .tuple_ty_mut(&name)
+                    .and_then(|x| x.find_entity_mut(id)),
+
+                EntityType::None
+                | EntityType::Namespace
+                | EntityType::Dto
+                | EntityType::Rpc
+                | EntityType::Enum
+                | EntityType::Union
+                | EntityType::Variant
+                | EntityType::Interface
+                | EntityType::TypeAlias => None,
+            }
+        } else {
+            Some(EntityMut::Variant(self))
+        }
+    }
+}
@@ -7,8 +7,11 @@ use log::{debug, error};
 use thiserror::Error;
 
 pub use crate::model::validate::mutation::Mutation;
+use crate::model::entity::FindEntity;
 use crate::model::{
-    entity, Api, EntityId, EntityType, Field, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+    entity, Api, Entity, EntityId, EntityType, Field, Rpc, TupleElement, Type, TypeRef, Value,
+    VariantPayload,
+    UNDEFINED_NAMESPACE,
 };
 
 #[derive(Error, Debug, Eq, PartialEq)]
@@ -28,6 +31,13 @@ pub enum ValidationError {
     #[error("Invalid enum name within namespace '{0}', index #{1}. Enum names cannot be empty.")]
     InvalidEnumName(EntityId, usize),
 
+    #[error("Invalid union name within namespace '{0}', index #{1}. Union names cannot be empty.")]
+    InvalidUnionName(EntityId, usize),
+
+    #[error("Invalid interface name within namespace '{0}', index #{1}. Interface names cannot be empty."
+    )]
+    InvalidInterfaceName(EntityId, usize),
+
     #[error("Invalid type alias name within namespace '{0}', index #{1}. Type alias names cannot be empty."
     )]
     InvalidTypeAliasName(EntityId, usize),
@@ -38,24 +48,56 @@ pub enum ValidationError {
     #[error("Invalid enum value name at '{0}', index {1}. Enum value names cannot be empty.")]
     InvalidEnumValueName(EntityId, usize),
 
-    #[error("Invalid field type at '{0}'. Type '{1}' must be a valid DTO, enum, or type alias in the API."
+    #[error("Invalid flags value within enum '{0}': '{1}'. Values of flags enums must be a power of two or a combination of other values."
+    )]
+    InvalidFlagsValue(EntityId, String),
+
+    #[error("Invalid variant name at '{0}', index {1}. Variant names cannot be empty.")]
+    InvalidVariantName(EntityId, usize),
+
+    #[error("Invalid field type at '{0}'. Type '{1}' must be a valid DTO, enum, union, or type alias in the API."
     )]
     InvalidFieldType(EntityId, EntityId),
 
     #[error(
-        "Invalid field type '{0}::{1}', index {2}. Type '{3}' must be a valid DTO, enum, or type alias in the API."
+        "Invalid field type '{0}::{1}', index {2}. Type '{3}' must be a valid DTO, enum, union, or type alias in the API."
     )]
     InvalidFieldOrParamType(EntityId, String, usize, EntityId),
 
-    #[error("Invalid return type for RPC {0}. Type '{1}' must be a valid DTO, enum, or type alias in the API."
+    #[error("Invalid return type for RPC {0}. Type '{1}' must be a valid DTO, enum, union, or type alias in the API."
     )]
     InvalidRpcReturnType(EntityId, EntityId),
 
-    #[error("Invalid target type for type alias {0}. Type '{1}' must be a valid DTO, enum, or type alias in the API."
+    #[error("Invalid error type for RPC {0}. Type '{1}' must be a valid DTO, enum, union, or type alias in the API."
+    )]
+    InvalidRpcErrorType(EntityId, EntityId),
+
+    #[error("Invalid interface '{1}' implemented or extended by '{0}'. Type must be an interface in the API."
+    )]
+    InvalidInterface(EntityId, EntityId),
+
+    #[error("Invalid base type '{1}' of DTO '{0}'. Type must be a DTO in the API.")]
+    InvalidBaseDto(EntityId, EntityId),
+
+    #[error("Invalid value for field '{0}'. Value '{1}' must be a literal of the field's type.")]
+    InvalidFieldValue(EntityId, String),
+
+    #[error("Invalid default for field '{0}'. Value '{1}' must be a literal of the field's type.")]
+    InvalidFieldDefault(EntityId, String),
+
+    #[error("Invalid target type for type alias {0}. Type '{1}' must be a valid DTO, enum, union, or type alias in the API."
     )]
     InvalidTypeAliasTargetType(EntityId, EntityId),
 
-    #[error("Duplicate DTO, enum, or type alias definition: '{0}'")]
+    #[error("Invalid type parameter '{1}' used at '{0}'. Type parameters must be declared by the entity or an enclosing DTO."
+    )]
+    InvalidTypeParam(EntityId, String),
+
+    #[error("Invalid type arguments for '{1}' used at '{0}'. Expected {2} type argument(s), found {3}."
+    )]
+    InvalidTypeArgCount(EntityId, EntityId, usize, usize),
+
+    #[error("Duplicate DTO, enum, union, interface, or type alias definition: '{0}'")]
     DuplicateDtoOrEnumOrAlias(EntityId),
 
     #[error("Duplicate RPC or field definition: '{0}'")]
@@ -64,6 +106,9 @@ pub enum ValidationError {
     #[error("Duplicate enum value name within enum '{0}': '{1}'")]
     DuplicateEnumValue(EntityId, String),
 
+    #[error("Duplicate variant name within union '{0}': '{1}'")]
+    DuplicateVariant(EntityId, String),
+
     #[error("Duplicate field name within entity '{0}': '{1}'")]
     DuplicateFieldName(EntityId, String),
 }
@@ -94,9 +139,13 @@ pub fn no_duplicate_dto_enum_alias(api: &Api, namespace_id: EntityId) -> Vec<Val
         .expect("namespace must exist in api");
     let dto_names = namespace.dtos().map(|dto| dto.name);
     let enum_names = namespace.enums().map(|en| en.name);
+    let union_names = namespace.unions().map(|union| union.name);
+    let interface_names = namespace.interfaces().map(|interface| interface.name);
     let alias_names = namespace.ty_aliases().map(|alias| alias.name);
     dto_names
         .chain(enum_names)
+        .chain(union_names)
+        .chain(interface_names)
         .chain(alias_names)
         .duplicates()
         .map(|name| {
@@ -280,6 +329,34 @@ pub fn enum_value_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResu
         .collect_vec()
 }
 
+/// Values of [EnumKind::Flags](crate::model::EnumKind::Flags) enums must be zero, a power of two,
+/// or a combination of the powers of two within the same enum.
+pub fn enum_flags_values(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .enums()
+        .filter(|en| en.is_flags())
+        .flat_map(|en| {
+            let en_id = namespace_id.child(EntityType::Enum, en.name).unwrap();
+            let bits = en
+                .values
+                .iter()
+                .filter(|value| value.number > 0 && value.number.count_ones() == 1)
+                .fold(0, |bits, value| bits | value.number);
+            en.values.iter().map(move |value| {
+                if value.number >= 0 && value.number & !bits == 0 {
+                    Ok(None)
+                } else {
+                    Err(ValidationError::InvalidFlagsValue(
+                        en_id.clone(),
+                        value.name.to_string(),
+                    ))
+                }
+            })
+        })
+        .collect_vec()
+}
+
 pub fn no_duplicate_enum_value_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
     api.find_namespace(&namespace_id)
         .expect("namespace must exist in api")
@@ -298,6 +375,126 @@ pub fn no_duplicate_enum_value_names(api: &Api, namespace_id: EntityId) -> Vec<V
         .collect_vec()
 }
 
+pub fn union_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+        .enumerate()
+        .map(|(i, union)| {
+            if union.name.is_empty() {
+                Err(ValidationError::InvalidUnionName(namespace_id.clone(), i))
+            } else {
+                Ok(None)
+            }
+        })
+        .collect_vec()
+}
+
+pub fn interface_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .interfaces()
+        .enumerate()
+        .map(|(i, interface)| {
+            if interface.name.is_empty() {
+                Err(ValidationError::InvalidInterfaceName(namespace_id.clone(), i))
+            } else {
+                Ok(None)
+            }
+        })
+        .collect_vec()
+}
+
+pub fn interface_rpc_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .interfaces()
+        .flat_map(|interface| {
+            let interface_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            let mut results = Vec::new();
+            results.append(&mut _rpc_names(interface_id.clone(), interface.rpcs.iter()));
+            results.append(&mut _rpc_param_names(
+                interface_id.clone(),
+                interface.rpcs.iter(),
+            ));
+            results.append(&mut _rpc_param_names_no_duplicates(
+                interface_id,
+                interface.rpcs.iter(),
+            ));
+            results
+        })
+        .collect_vec()
+}
+
+pub fn union_variant_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+        .flat_map(|union| {
+            union
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    if variant.name.is_empty() {
+                        Err(ValidationError::InvalidVariantName(
+                            namespace_id.child(EntityType::Union, union.name).unwrap(),
+                            i,
+                        ))
+                    } else {
+                        Ok(None)
+                    }
+                })
+                .collect_vec()
+        })
+        .collect_vec()
+}
+
+pub fn no_duplicate_union_variant_names(
+    api: &Api,
+    namespace_id: EntityId,
+) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+        .flat_map(|union| {
+            union
+                .variants
+                .iter()
+                .duplicates_by(|variant| variant.name)
+                .map(|variant| {
+                    Err(ValidationError::DuplicateVariant(
+                        namespace_id.child(EntityType::Union, union.name).unwrap(),
+                        variant.name.to_string(),
+                    ))
+                })
+                .collect_vec()
+        })
+        .collect_vec()
+}
+
+/// Field names of struct payloads, like [dto_field_names] and [dto_field_names_no_duplicates].
+pub fn union_variant_field_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for union in api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+    {
+        let union_id = namespace_id.child(EntityType::Union, union.name).unwrap();
+        for variant in &union.variants {
+            if let Some(VariantPayload::Struct(fields)) = &variant.payload {
+                let variant_id = union_id.child(EntityType::Variant, variant.name).unwrap();
+                results.append(&mut field_names(fields, variant_id.clone()));
+                results.append(&mut duplicate_field_names(fields, variant_id));
+            }
+        }
+    }
+    results
+}
+
 pub fn ty_alias_names(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
     api.find_namespace(&namespace_id)
         .expect("namespace must exist in api")
@@ -369,11 +566,140 @@ pub fn dto_rpc_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult>
             let mut results = Vec::new();
             results.append(&mut _rpc_param_types(api, dto_id.clone(), dto.rpcs.iter()));
             results.append(&mut _rpc_return_types(api, dto_id.clone(), dto.rpcs.iter()));
+            results.append(&mut _rpc_error_types(api, dto_id.clone(), dto.rpcs.iter()));
+            results
+        })
+        .collect_vec()
+}
+
+pub fn interface_rpc_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .interfaces()
+        .flat_map(|interface| {
+            let interface_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            let mut results = Vec::new();
+            results.append(&mut _rpc_param_types(
+                api,
+                interface_id.clone(),
+                interface.rpcs.iter(),
+            ));
+            results.append(&mut _rpc_return_types(
+                api,
+                interface_id.clone(),
+                interface.rpcs.iter(),
+            ));
+            results.append(&mut _rpc_error_types(
+                api,
+                interface_id,
+                interface.rpcs.iter(),
+            ));
             results
         })
         .collect_vec()
 }
 
+/// [crate::model::Interface]s implemented by each [crate::model::Dto], including those from
+/// 'virtual' namespaces that will be merged into the [crate::model::Dto] at build time, e.g.
+/// `impl Trait for Dto`.
+pub fn dto_implements(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let namespace = api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api");
+    namespace
+        .dtos()
+        .filter_map(|dto| {
+            let interface_ids = dto
+                .implements
+                .iter()
+                .chain(
+                    namespace
+                        .namespaces()
+                        .filter(|ns| ns.is_virtual && ns.name == dto.name)
+                        .flat_map(|ns| ns.implements.iter()),
+                )
+                .collect_vec();
+            if interface_ids.is_empty() {
+                None
+            } else {
+                let dto_id = namespace_id.child(EntityType::Dto, dto.name).unwrap();
+                Some(qualify_interfaces(api, &namespace_id, dto_id, &interface_ids))
+            }
+        })
+        .collect_vec()
+}
+
+/// Base [crate::model::Dto]s of each [crate::model::Dto]. Unlike interfaces, bases must be in the
+/// API since their fields are inherited.
+pub fn dto_extends(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .dtos()
+        .filter(|dto| !dto.extends.is_empty())
+        .map(|dto| {
+            let dto_id = namespace_id.child(EntityType::Dto, dto.name).unwrap();
+            let mut base_ids = Vec::new();
+            for id in &dto.extends {
+                match api.find_qualified_type_relative(&namespace_id, id) {
+                    Ok(base_id) if api.find_dto(&base_id).is_some() => base_ids.push(base_id),
+                    Ok(base_id) => return Err(ValidationError::InvalidBaseDto(dto_id, base_id)),
+                    Err(_) => return Err(ValidationError::InvalidBaseDto(dto_id, id.clone())),
+                }
+            }
+            Ok(Some(Mutation::new_qualify_extends(dto_id, base_ids)))
+        })
+        .collect_vec()
+}
+
+pub fn interface_super_interfaces(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .interfaces()
+        .filter(|interface| !interface.super_interfaces.is_empty())
+        .map(|interface| {
+            let interface_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            qualify_interfaces(
+                api,
+                &namespace_id,
+                interface_id,
+                &interface.super_interfaces.iter().collect_vec(),
+            )
+        })
+        .collect_vec()
+}
+
+/// Qualifies each of `interface_ids` relative to `namespace_id`. Ids that can't be found in the
+/// `api` are dropped since they refer to something outside of the API, e.g. `impl Display for Dto`.
+fn qualify_interfaces(
+    api: &Api,
+    namespace_id: &EntityId,
+    entity_id: EntityId,
+    interface_ids: &[&EntityId],
+) -> ValidationResult {
+    let mut qualified_ids = Vec::new();
+    for id in interface_ids {
+        let qualified_id = match api.find_qualified_type_relative(namespace_id, id) {
+            Ok(qualified_id) => qualified_id,
+            Err(err) => {
+                debug!("ignoring interface '{}' of '{}': {}", id, entity_id, err);
+                continue;
+            }
+        };
+        if api.find_interface(&qualified_id).is_none() {
+            return Err(ValidationError::InvalidInterface(entity_id, qualified_id));
+        }
+        qualified_ids.push(qualified_id);
+    }
+    Ok(Some(Mutation::new_qualify_interfaces(
+        entity_id,
+        qualified_ids,
+    )))
+}
+
 pub fn rpc_param_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
     let rpcs = api
         .find_namespace(&namespace_id)
@@ -426,6 +752,99 @@ fn _rpc_return_types<'a, 'api: 'a>(
         .collect_vec()
 }
 
+pub fn rpc_error_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let rpcs = api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .rpcs();
+    _rpc_error_types(api, namespace_id, rpcs)
+}
+
+fn _rpc_error_types<'a, 'api: 'a>(
+    api: &Api,
+    namespace_id: EntityId,
+    rpcs: impl Iterator<Item = &'a Rpc<'api>>,
+) -> Vec<ValidationResult> {
+    rpcs.filter_map(|rpc| rpc.error_type.as_ref().map(|ty| (&rpc.name, ty)))
+        .map(|(rpc_name, error_type)| {
+            let rpc_id = namespace_id.child(EntityType::Rpc, rpc_name).unwrap();
+            let error_ty_id = rpc_id
+                .child(EntityType::Type, entity::subtype::ERROR_TY)
+                .unwrap();
+            match qualify_type(api, &namespace_id, error_type) {
+                Ok(Some(qualified_ty)) => {
+                    Ok(Some(Mutation::new_qualify_type(error_ty_id, qualified_ty)))
+                }
+                Err(err_entity_id) => {
+                    Err(ValidationError::InvalidRpcErrorType(rpc_id, err_entity_id))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect_vec()
+}
+
+pub fn union_variant_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for union in api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+    {
+        let union_id = namespace_id.child(EntityType::Union, union.name).unwrap();
+        for variant in &union.variants {
+            let variant_id = union_id.child(EntityType::Variant, variant.name).unwrap();
+            match &variant.payload {
+                Some(VariantPayload::Tuple(tys)) => {
+                    results.append(&mut tuple_types(
+                        api,
+                        tys,
+                        namespace_id.clone(),
+                        variant_id,
+                    ));
+                }
+                Some(VariantPayload::Struct(fields)) => {
+                    results.append(&mut field_list_types(
+                        api,
+                        fields,
+                        namespace_id.clone(),
+                        variant_id,
+                        false,
+                    ));
+                }
+                None => {}
+            }
+        }
+    }
+    results
+}
+
+/// Positional types of a tuple, e.g. a [crate::model::Variant] tuple payload. Each type's
+/// [EntityId] is a child of `parent_entity_id` named by its index.
+fn tuple_types(
+    api: &Api,
+    tys: &[TypeRef],
+    namespace_id: EntityId,
+    parent_entity_id: EntityId,
+) -> Vec<ValidationResult> {
+    tys.iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let ty_id = parent_entity_id.child(EntityType::Type, i).unwrap();
+            match qualify_type(api, &namespace_id, ty) {
+                Ok(Some(qualified_ty)) => Ok(Some(Mutation::new_qualify_type(ty_id, qualified_ty))),
+                Err(err_entity_id) => Err(ValidationError::InvalidFieldOrParamType(
+                    parent_entity_id.clone(),
+                    i.to_string(),
+                    i,
+                    err_entity_id,
+                )),
+                _ => Ok(None),
+            }
+        })
+        .collect_vec()
+}
+
 /// Field contained as a list inside other Entities like Dto fields or Rpc params.
 pub fn field_list_types<'a, 'b: 'a>(
     api: &'b Api<'a>,
@@ -489,6 +908,158 @@ pub fn field_types<'a, 'b: 'a>(api: &'b Api<'a>, namespace_id: EntityId) -> Vec<
         .collect_vec()
 }
 
+/// Values and defaults of namespace fields, DTO fields, union variant fields, and RPC params of
+/// namespaces, DTOs, and interfaces. Integer values of float fields are converted to floats.
+pub fn field_values(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let namespace = api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api");
+    let namespace_fields = namespace
+        .fields()
+        .map(|field| (namespace_id.clone(), field));
+    let dto_fields = namespace.dtos().flat_map(|dto| {
+        let dto_id = namespace_id.child(EntityType::Dto, dto.name).unwrap();
+        dto.fields.iter().map(move |field| (dto_id.clone(), field))
+    });
+    let variant_fields = namespace.unions().flat_map(|union| {
+        let union_id = namespace_id.child(EntityType::Union, union.name).unwrap();
+        union.variants.iter().flat_map(move |variant| {
+            let variant_id = union_id.child(EntityType::Variant, variant.name).unwrap();
+            let fields = match &variant.payload {
+                Some(VariantPayload::Struct(fields)) => fields.as_slice(),
+                _ => &[],
+            };
+            fields.iter().map(move |field| (variant_id.clone(), field))
+        })
+    });
+    let rpc_params = namespace
+        .rpcs()
+        .map(|rpc| (namespace_id.clone(), rpc))
+        .chain(namespace.dtos().flat_map(|dto| {
+            let dto_id = namespace_id.child(EntityType::Dto, dto.name).unwrap();
+            dto.rpcs.iter().map(move |rpc| (dto_id.clone(), rpc))
+        }))
+        .chain(namespace.interfaces().flat_map(|interface| {
+            let interface_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            interface
+                .rpcs
+                .iter()
+                .map(move |rpc| (interface_id.clone(), rpc))
+        }))
+        .flat_map(|(parent_id, rpc)| {
+            let rpc_id = parent_id.child(EntityType::Rpc, &rpc.name).unwrap();
+            rpc.params.iter().map(move |param| (rpc_id.clone(), param))
+        });
+    namespace_fields
+        .chain(dto_fields)
+        .chain(variant_fields)
+        .chain(rpc_params)
+        .flat_map(|(parent_id, field)| {
+            let value = field
+                .value
+                .as_ref()
+                .map(|value| (value, ValidationError::InvalidFieldValue as fn(_, _) -> _));
+            let default = field
+                .default
+                .as_ref()
+                .map(|value| (value, ValidationError::InvalidFieldDefault as fn(_, _) -> _));
+            let namespace_id = &namespace_id;
+            value.into_iter().chain(default).map(move |(value, err)| {
+                let field_id = parent_id.child(EntityType::Field, field.name).unwrap();
+                if !value_matches_type(api, namespace_id, value, &field.ty) {
+                    Err(err(field_id, format!("{:?}", value)))
+                } else if matches!(value, Value::Int(_) | Value::UInt(_))
+                    && is_float_type(api, namespace_id, &field.ty)
+                {
+                    Ok(Some(Mutation::new_float_value(field_id)))
+                } else {
+                    Ok(None)
+                }
+            })
+        })
+        .collect_vec()
+}
+
+/// `true` if `value` is a literal of `ty`. [Type::User] types are opaque, so any value matches.
+fn value_matches_type(api: &Api, namespace_id: &EntityId, value: &Value, ty: &TypeRef) -> bool {
+    match (value, &ty.value) {
+        (Value::Int(n), _) if int_fits(*n, &ty.value) => true,
+        (Value::UInt(n), _) if uint_fits(*n, &ty.value) => true,
+        (
+            Value::Int(_) | Value::UInt(_) | Value::Float(_),
+            Type::F8 | Type::F16 | Type::F32 | Type::F64 | Type::F128,
+        )
+        | (Value::Bool(_), Type::Bool)
+        | (Value::String(_), Type::String | Type::StringView)
+        | (_, Type::User(_)) => true,
+        (_, Type::Optional(ty)) => value_matches_type(api, namespace_id, value, ty),
+        (_, Type::Api(id)) => {
+            let Ok(qualified_id) = api.find_qualified_type_relative(namespace_id, id) else {
+                // Reported by type validation.
+                return true;
+            };
+            match api.find_entity(qualified_id.clone()) {
+                Some(Entity::Enum(en)) => {
+                    matches!(value, Value::EnumValue(name) if en.value(name).is_some())
+                }
+                Some(Entity::TypeAlias(alias)) => {
+                    let alias_namespace = qualified_id.parent().unwrap_or_default();
+                    value_matches_type(api, &alias_namespace, value, &alias.target_ty)
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// `true` if `ty` is a float, an optional float, or an alias of either.
+fn is_float_type(api: &Api, namespace_id: &EntityId, ty: &TypeRef) -> bool {
+    match &ty.value {
+        Type::F8 | Type::F16 | Type::F32 | Type::F64 | Type::F128 => true,
+        Type::Optional(ty) => is_float_type(api, namespace_id, ty),
+        Type::Api(id) => {
+            let Ok(qualified_id) = api.find_qualified_type_relative(namespace_id, id) else {
+                return false;
+            };
+            match api.find_entity(qualified_id.clone()) {
+                Some(Entity::TypeAlias(alias)) => {
+                    let alias_namespace = qualified_id.parent().unwrap_or_default();
+                    is_float_type(api, &alias_namespace, &alias.target_ty)
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn int_fits(n: i64, ty: &Type) -> bool {
+    match ty {
+        Type::U8 => u8::try_from(n).is_ok(),
+        Type::U16 => u16::try_from(n).is_ok(),
+        Type::U32 => u32::try_from(n).is_ok(),
+        Type::U64 | Type::U128 | Type::USIZE => n >= 0,
+        Type::I8 => i8::try_from(n).is_ok(),
+        Type::I16 => i16::try_from(n).is_ok(),
+        Type::I32 => i32::try_from(n).is_ok(),
+        Type::I64 | Type::I128 => true,
+        _ => false,
+    }
+}
+
+/// `true` if `n`, which is above `i64::MAX`, fits in the integer type `ty`.
+fn uint_fits(n: u128, ty: &Type) -> bool {
+    match ty {
+        Type::U64 | Type::USIZE => u64::try_from(n).is_ok(),
+        Type::U128 => true,
+        Type::I128 => i128::try_from(n).is_ok(),
+        _ => false,
+    }
+}
+
 pub fn ty_alias_target_type(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
     api.find_namespace(&namespace_id)
         .expect("namespace must exist in api")
@@ -514,6 +1085,308 @@ pub fn ty_alias_target_type(api: &Api, namespace_id: EntityId) -> Vec<Validation
         .collect_vec()
 }
 
+pub fn dto_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    let mut results = Vec::new();
+    for dto in api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .dtos()
+    {
+        let dto_id = namespace_id.child(EntityType::Dto, dto.name).unwrap();
+        let type_params = [enclosing.as_slice(), &dto.type_params].concat();
+        for field in &dto.fields {
+            let field_id = dto_id.child(EntityType::Field, field.name).unwrap();
+            results.append(&mut generic_type(
+                api,
+                &[&dto_id, &namespace_id],
+                &type_params,
+                &field_id,
+                &field.ty,
+            ));
+        }
+        results.append(&mut _rpc_generic_types(
+            api,
+            &dto_id,
+            &type_params,
+            dto.rpcs.iter(),
+        ));
+    }
+    results
+}
+
+pub fn rpc_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let rpcs = api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .rpcs();
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    _rpc_generic_types(api, &namespace_id, &enclosing, rpcs)
+}
+
+fn _rpc_generic_types<'a, 'api: 'a>(
+    api: &Api,
+    namespace_id: &EntityId,
+    enclosing: &[&str],
+    rpcs: impl Iterator<Item = &'a Rpc<'api>>,
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for rpc in rpcs {
+        let rpc_id = namespace_id.child(EntityType::Rpc, &rpc.name).unwrap();
+        let type_params = [enclosing, &rpc.type_params].concat();
+        for param in &rpc.params {
+            let param_id = rpc_id.child(EntityType::Field, param.name).unwrap();
+            results.append(&mut generic_type(
+                api,
+                &[namespace_id],
+                &type_params,
+                &param_id,
+                &param.ty,
+            ));
+        }
+        if let Some(return_type) = &rpc.return_type {
+            let return_ty_id = rpc_id
+                .child(EntityType::Type, entity::subtype::RETURN_TY)
+                .unwrap();
+            results.append(&mut generic_type(
+                api,
+                &[namespace_id],
+                &type_params,
+                &return_ty_id,
+                return_type,
+            ));
+        }
+        if let Some(error_type) = &rpc.error_type {
+            let error_ty_id = rpc_id
+                .child(EntityType::Type, entity::subtype::ERROR_TY)
+                .unwrap();
+            results.append(&mut generic_type(
+                api,
+                &[namespace_id],
+                &type_params,
+                &error_ty_id,
+                error_type,
+            ));
+        }
+    }
+    results
+}
+
+pub fn interface_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .interfaces()
+        .flat_map(|interface| {
+            let interface_id = namespace_id
+                .child(EntityType::Interface, interface.name)
+                .unwrap();
+            _rpc_generic_types(api, &interface_id, &enclosing, interface.rpcs.iter())
+        })
+        .collect_vec()
+}
+
+pub fn ty_alias_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .ty_aliases()
+        .flat_map(|alias| {
+            let alias_id = namespace_id
+                .child(EntityType::TypeAlias, alias.name)
+                .unwrap();
+            let type_params = [enclosing.as_slice(), &alias.type_params].concat();
+            generic_type(
+                api,
+                &[&namespace_id],
+                &type_params,
+                &alias_id,
+                &alias.target_ty,
+            )
+        })
+        .collect_vec()
+}
+
+pub fn union_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    let mut results = Vec::new();
+    for union in api
+        .find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .unions()
+    {
+        let union_id = namespace_id.child(EntityType::Union, union.name).unwrap();
+        for variant in &union.variants {
+            let variant_id = union_id.child(EntityType::Variant, variant.name).unwrap();
+            match &variant.payload {
+                Some(VariantPayload::Tuple(tys)) => {
+                    for (i, ty) in tys.iter().enumerate() {
+                        let ty_id = variant_id.child(EntityType::Type, i).unwrap();
+                        results.append(&mut generic_type(
+                            api,
+                            &[&namespace_id],
+                            &enclosing,
+                            &ty_id,
+                            ty,
+                        ));
+                    }
+                }
+                Some(VariantPayload::Struct(fields)) => {
+                    for field in fields {
+                        let field_id = variant_id.child(EntityType::Field, field.name).unwrap();
+                        results.append(&mut generic_type(
+                            api,
+                            &[&namespace_id],
+                            &enclosing,
+                            &field_id,
+                            &field.ty,
+                        ));
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+    results
+}
+
+/// Top level fields inside a namespace.
+pub fn field_generic_types(api: &Api, namespace_id: EntityId) -> Vec<ValidationResult> {
+    let enclosing = enclosing_type_params(api, &namespace_id);
+    api.find_namespace(&namespace_id)
+        .expect("namespace must exist in api")
+        .fields()
+        .flat_map(|field| {
+            let field_id = namespace_id.child(EntityType::Field, field.name).unwrap();
+            generic_type(api, &[&namespace_id], &enclosing, &field_id, &field.ty)
+        })
+        .collect_vec()
+}
+
+/// Type parameters of all [crate::model::Dto]s enclosing `namespace_id`, e.g. for static
+/// [Rpc]s in a [crate::model::Dto]'s namespace.
+fn enclosing_type_params<'a>(api: &Api<'a>, namespace_id: &EntityId) -> Vec<&'a str> {
+    let mut type_params = Vec::new();
+    let mut it = Some(namespace_id.clone());
+    while let Some(id) = it {
+        if id
+            .components()
+            .last()
+            .is_some_and(|component| component.ty == EntityType::Dto)
+        {
+            if let Some(dto) = api.find_dto(&id) {
+                type_params.extend(dto.type_params.iter().copied());
+            }
+        }
+        it = id.parent();
+    }
+    type_params
+}
+
+/// Checks, recursively, that every [Type::TypeParam] in `ty` is one of `type_params` and that
+/// every [Type::Api] or [Type::Generic] has as many type arguments as the referenced type has
+/// type parameters. `namespace_ids` are tried in order to find referenced types. Types that can't
+/// be found at all are left to the other type validators.
+fn generic_type(
+    api: &Api,
+    namespace_ids: &[&EntityId],
+    type_params: &[&str],
+    entity_id: &EntityId,
+    ty: &TypeRef,
+) -> Vec<ValidationResult> {
+    let recurse = |ty: &TypeRef| generic_type(api, namespace_ids, type_params, entity_id, ty);
+    match &ty.value {
+        Type::TypeParam(name) => {
+            if type_params.contains(&name.as_str()) {
+                vec![]
+            } else {
+                vec![Err(ValidationError::InvalidTypeParam(
+                    entity_id.clone(),
+                    name.clone(),
+                ))]
+            }
+        }
+
+        Type::Api(id) => type_arg_count(api, namespace_ids, entity_id, id, 0)
+            .into_iter()
+            .collect_vec(),
+
+        Type::Generic { ty: id, args } => type_arg_count(api, namespace_ids, entity_id, id, args.len())
+            .into_iter()
+            .chain(args.iter().flat_map(recurse))
+            .collect_vec(),
+
+        Type::Array(ty) | Type::Set(ty) | Type::FixedArray { ty, .. } | Type::Optional(ty) => {
+            recurse(ty)
+        }
+
+        Type::Tuple(elements) => elements
+            .iter()
+            .flat_map(|element| recurse(&element.ty))
+            .collect_vec(),
+
+        Type::Map { key, value } => recurse(key).into_iter().chain(recurse(value)).collect_vec(),
+
+        Type::Function { params, return_ty } => params
+            .iter()
+            .flat_map(|param| recurse(param))
+            .chain(return_ty.iter().flat_map(|ty| recurse(ty)))
+            .collect_vec(),
+
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::StringView
+        | Type::String
+        | Type::Bytes
+        | Type::User(_) => vec![],
+    }
+}
+
+fn type_arg_count(
+    api: &Api,
+    namespace_ids: &[&EntityId],
+    entity_id: &EntityId,
+    id: &EntityId,
+    arg_count: usize,
+) -> Option<ValidationResult> {
+    let qualified_id = namespace_ids
+        .iter()
+        .find_map(|namespace_id| api.find_qualified_type_relative(namespace_id, id).ok())?;
+    let param_count = api
+        .find_dto(&qualified_id)
+        .map(|dto| dto.type_params.len())
+        .or_else(|| {
+            api.find_ty_alias(&qualified_id)
+                .map(|alias| alias.type_params.len())
+        })
+        .unwrap_or(0);
+    if param_count == arg_count {
+        None
+    } else {
+        Some(Err(ValidationError::InvalidTypeArgCount(
+            entity_id.clone(),
+            qualified_id,
+            param_count,
+            arg_count,
+        )))
+    }
+}
+
 /// Returns a [TypeRef] with all [EntityId]s qualified, recursively. If an [EntityId] does not exist
 /// in the `api`, it returns the [EntityId] which could not be qualified as an error.
 /// If there are no [EntityId]s in the [TypeRef] (i.e. it's all primitives), returns Ok(None).
@@ -542,6 +1415,22 @@ fn qualify_type(
             return Ok(Some(TypeRef::new(Type::Api(qualified_id), ty.semantics)));
         }
 
+        Type::Generic { ty: id, args } => {
+            let qualified_id =
+                api.find_qualified_type_relative(namespace_id, id)
+                    .map_err(|err| {
+                        error!("qualify_type: {}", err);
+                        id.clone()
+                    })?;
+            let args = args
+                .iter()
+                .map(|arg| {
+                    qualify_type(api, namespace_id, arg).map(|ty| ty.unwrap_or_else(|| arg.clone()))
+                })
+                .collect::<Result<Vec<_>, EntityId>>()?;
+            return Ok(Some(TypeRef::new_generic(qualified_id, args, ty.semantics)));
+        }
+
         Type::Array(ty) => {
             return qualify_type(api, namespace_id, ty)
                 .map(|opt| opt.map(|arr_ty| TypeRef::new_array(arr_ty, ty.semantics)))
@@ -552,6 +1441,38 @@ fn qualify_type(
                 .map(|opt| opt.map(|opt_ty| TypeRef::new_optional(opt_ty, ty.semantics)))
         }
 
+        Type::Set(element_ty) => {
+            return qualify_type(api, namespace_id, element_ty)
+                .map(|opt| opt.map(|element_ty| TypeRef::new_set(element_ty, ty.semantics)))
+        }
+
+        Type::FixedArray { ty: element_ty, len } => {
+            return qualify_type(api, namespace_id, element_ty).map(|opt| {
+                opt.map(|element_ty| TypeRef::new_fixed_array(element_ty, *len, ty.semantics))
+            })
+        }
+
+        Type::Tuple(elements) => {
+            let mut has_qualified_elements = false;
+            let elements = elements
+                .iter()
+                .map(|element| {
+                    qualify_type(api, namespace_id, &element.ty).map(|ty| {
+                        has_qualified_elements |= ty.is_some();
+                        TupleElement::new(
+                            element.name.clone(),
+                            ty.unwrap_or_else(|| element.ty.clone()),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, EntityId>>()?;
+            return if has_qualified_elements {
+                Ok(Some(TypeRef::new(Type::Tuple(elements), ty.semantics)))
+            } else {
+                Ok(None)
+            };
+        }
+
         Type::Function { params, return_ty } => {
             let mut has_qualified_params = false;
             let params = params
@@ -616,6 +1537,7 @@ fn qualify_type(
         Type::String => {}
         Type::Bytes => {}
         Type::User(_) => {}
+        Type::TypeParam(_) => {}
     }
     Ok(None)
 }
@@ -1222,6 +2144,8 @@ mod tests {
                                 ty: TypeRef::new(Type::U32, Semantics::Value),
                                 attributes: Default::default(),
                                 is_static: true,
+                                value: None,
+                                default: None,
                             }),
                             NamespaceChild::Rpc(Rpc {
                                 name: Cow::Borrowed("rpc"),
@@ -1238,6 +2162,7 @@ mod tests {
                     ..Default::default()
                 })],
                 is_virtual: false,
+                implements: vec![],
                 ..Default::default()
             };
 
@@ -10,6 +10,9 @@ use crate::model::{Api, EntityId, TypeRef};
 #[derive(Debug)]
 pub enum Mutation {
     QualifyType(qualify_type::Data),
+    QualifyInterfaces(qualify_interfaces::Data),
+    QualifyExtends(qualify_extends::Data),
+    FloatValue(float_value::Data),
 }
 
 impl Mutation {
@@ -17,9 +20,30 @@ impl Mutation {
         Mutation::QualifyType(qualify_type::Data { entity_id, new_ty })
     }
 
+    pub fn new_qualify_interfaces(entity_id: EntityId, interface_ids: Vec<EntityId>) -> Self {
+        Mutation::QualifyInterfaces(qualify_interfaces::Data {
+            entity_id,
+            interface_ids,
+        })
+    }
+
+    pub fn new_qualify_extends(entity_id: EntityId, base_ids: Vec<EntityId>) -> Self {
+        Mutation::QualifyExtends(qualify_extends::Data {
+            entity_id,
+            base_ids,
+        })
+    }
+
+    pub fn new_float_value(entity_id: EntityId) -> Self {
+        Mutation::FloatValue(float_value::Data { entity_id })
+    }
+
     pub fn execute(self, api: &mut Api) -> Result<()> {
         match self {
             Mutation::QualifyType(data) => qualify_type::execute(api, data)?,
+            Mutation::QualifyInterfaces(data) => qualify_interfaces::execute(api, data)?,
+            Mutation::QualifyExtends(data) => qualify_extends::execute(api, data)?,
+            Mutation::FloatValue(data) => float_value::execute(api, data)?,
         }
         Ok(())
     }
@@ -65,6 +89,110 @@ pub mod qualify_type {
     }
 }
 
+/// Replaces [crate::model::Dto::implements] or [crate::model::Interface::super_interfaces] with
+/// their qualified versions.
+pub mod qualify_interfaces {
+    use anyhow::{anyhow, Result};
+
+    use crate::model::entity::{EntityMut, FindEntity};
+    use crate::model::{Api, EntityId};
+
+    #[derive(Debug)]
+    pub struct Data {
+        pub entity_id: EntityId,
+        pub interface_ids: Vec<EntityId>,
+    }
+
+    pub fn execute(api: &mut Api, data: Data) -> Result<()> {
+        match api.find_entity_mut(data.entity_id.clone()) {
+            None => Err(anyhow!(
+                "Mutation::QualifyInterfaces failed: Could not find EntityId '{}' in the API",
+                data.entity_id
+            )),
+            Some(EntityMut::Dto(dto)) => {
+                dto.implements = data.interface_ids;
+                Ok(())
+            }
+            Some(EntityMut::Interface(interface)) => {
+                interface.super_interfaces = data.interface_ids;
+                Ok(())
+            }
+            Some(_) => Err(anyhow!(
+                "Mutation::QualifyInterfaces failed: EntityId '{}' exists, but is not a DTO or interface.",
+                data.entity_id
+            )),
+        }
+    }
+}
+
+/// Replaces [crate::model::Dto::extends] with their qualified versions.
+pub mod qualify_extends {
+    use anyhow::{anyhow, Result};
+
+    use crate::model::entity::{EntityMut, FindEntity};
+    use crate::model::{Api, EntityId};
+
+    #[derive(Debug)]
+    pub struct Data {
+        pub entity_id: EntityId,
+        pub base_ids: Vec<EntityId>,
+    }
+
+    pub fn execute(api: &mut Api, data: Data) -> Result<()> {
+        match api.find_entity_mut(data.entity_id.clone()) {
+            None => Err(anyhow!(
+                "Mutation::QualifyExtends failed: Could not find EntityId '{}' in the API",
+                data.entity_id
+            )),
+            Some(EntityMut::Dto(dto)) => {
+                dto.extends = data.base_ids;
+                Ok(())
+            }
+            Some(_) => Err(anyhow!(
+                "Mutation::QualifyExtends failed: EntityId '{}' exists, but is not a DTO.",
+                data.entity_id
+            )),
+        }
+    }
+}
+
+/// Replaces [crate::model::Value::Int] values and defaults of a float [crate::model::Field] with
+/// the equivalent [crate::model::Value::Float], e.g. for `const X: f64 = 5;`.
+pub mod float_value {
+    use anyhow::{anyhow, Result};
+
+    use crate::model::entity::{EntityMut, FindEntity};
+    use crate::model::{Api, EntityId, Value};
+
+    #[derive(Debug)]
+    pub struct Data {
+        pub entity_id: EntityId,
+    }
+
+    pub fn execute(api: &mut Api, data: Data) -> Result<()> {
+        match api.find_entity_mut(data.entity_id.clone()) {
+            None => Err(anyhow!(
+                "Mutation::FloatValue failed: Could not find EntityId '{}' in the API",
+                data.entity_id
+            )),
+            Some(EntityMut::Field(field)) => {
+                for value in field.value.iter_mut().chain(field.default.iter_mut()) {
+                    match *value {
+                        Value::Int(n) => *value = Value::Float(n as f64),
+                        Value::UInt(n) => *value = Value::Float(n as f64),
+                        _ => {}
+                    }
+                }
+                Ok(())
+            }
+            Some(_) => Err(anyhow!(
+                "Mutation::FloatValue failed: EntityId '{}' exists, but is not a field.",
+                data.entity_id
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod qualify_type {
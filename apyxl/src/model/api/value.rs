@@ -0,0 +1,24 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// A literal value, e.g. the value of a constant [crate::model::Field] like `const X: u32 = 5;`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Value<'a> {
+    Int(i64),
+    /// Integer above `i64::MAX`, e.g. a large `u64` constant. Other integers are [Value::Int].
+    #[serde(rename = "uint")]
+    UInt(u128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    /// Contents of a string literal without the quotes, with escape sequences resolved.
+    String(#[serde(borrow)] Cow<'a, str>),
+    /// Name of a value within the [crate::model::Enum] that is the owning field's type, e.g.
+    /// `Active` in `const S: Status = Status::Active;`.
+    EnumValue(#[serde(borrow)] Cow<'a, str>),
+}
+
+// Parsed float literals are never NaN, so equality is total.
+impl Eq for Value<'_> {}
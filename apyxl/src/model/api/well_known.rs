@@ -0,0 +1,25 @@
+// Reserved [crate::model::UserTypeName]s for common types that aren't primitives in the model. See
+// [ALL].
+
+/// A 128-bit universally unique identifier, e.g. Rust's `uuid::Uuid` or C#'s `Guid`.
+pub const UUID: &str = "uuid";
+
+/// A point in time, e.g. Rust's `SystemTime` or C#'s `DateTimeOffset`.
+pub const TIMESTAMP: &str = "timestamp";
+
+/// A span of time, e.g. Rust's `Duration` or C#'s `TimeSpan`.
+pub const DURATION: &str = "duration";
+
+/// A base-10 decimal number, e.g. Rust's `rust_decimal::Decimal` or C#'s `decimal`.
+pub const DECIMAL: &str = "decimal";
+
+/// A single character, e.g. `char` in both Rust and C#.
+pub const CHAR: &str = "char";
+
+/// All well-known type names.
+///
+/// Built-in [crate::Parser]s map their language's spellings of these types to
+/// [crate::model::Type::User] with these names out of the box, and built-in [crate::Generator]s
+/// map them back to the idiomatic type in their target language. See [crate::parser::Config] to
+/// add spellings or disable types.
+pub const ALL: &[&str] = &[UUID, TIMESTAMP, DURATION, DECIMAL, CHAR];
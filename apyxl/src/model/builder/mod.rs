@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use anyhow::Result;
 use itertools::Itertools;
-use log::{debug, error};
+use log::{debug, error, warn};
 
 pub use config::*;
 
@@ -140,13 +140,33 @@ impl<'a> Builder<'a> {
             validate::recurse_api(&self.api, validate::rpc_param_names_no_duplicates),
             validate::recurse_api(&self.api, validate::rpc_param_types),
             validate::recurse_api(&self.api, validate::rpc_return_types),
+            validate::recurse_api(&self.api, validate::rpc_error_types),
             validate::recurse_api(&self.api, validate::ty_alias_names),
             validate::recurse_api(&self.api, validate::ty_alias_target_type),
             validate::recurse_api(&self.api, validate::enum_names),
             validate::recurse_api(&self.api, validate::enum_value_names),
+            validate::recurse_api(&self.api, validate::enum_flags_values),
+            validate::recurse_api(&self.api, validate::union_names),
+            validate::recurse_api(&self.api, validate::union_variant_names),
+            validate::recurse_api(&self.api, validate::union_variant_field_names),
+            validate::recurse_api(&self.api, validate::union_variant_types),
+            validate::recurse_api(&self.api, validate::interface_names),
+            validate::recurse_api(&self.api, validate::interface_rpc_names),
+            validate::recurse_api(&self.api, validate::interface_rpc_types),
+            validate::recurse_api(&self.api, validate::interface_super_interfaces),
+            validate::recurse_api(&self.api, validate::dto_implements),
+            validate::recurse_api(&self.api, validate::dto_extends),
+            validate::recurse_api(&self.api, validate::field_values),
             validate::recurse_api(&self.api, validate::no_duplicate_dto_enum_alias),
             validate::recurse_api(&self.api, validate::no_duplicate_rpc_or_field),
             validate::recurse_api(&self.api, validate::no_duplicate_enum_value_names),
+            validate::recurse_api(&self.api, validate::no_duplicate_union_variant_names),
+            validate::recurse_api(&self.api, validate::dto_generic_types),
+            validate::recurse_api(&self.api, validate::rpc_generic_types),
+            validate::recurse_api(&self.api, validate::ty_alias_generic_types),
+            validate::recurse_api(&self.api, validate::union_generic_types),
+            validate::recurse_api(&self.api, validate::interface_generic_types),
+            validate::recurse_api(&self.api, validate::field_generic_types),
         ]
         .into_iter()
         .flatten()
@@ -169,7 +189,11 @@ impl<'a> Builder<'a> {
             mutation.execute(&mut self.api).unwrap();
         }
 
-        Ok(Model::new(self.api, self.metadata))
+        let model = Model::new(self.api, self.metadata);
+        for (dependent, dependency) in model.dependencies().deprecated(model.api()) {
+            warn!("{} depends on deprecated {}", dependent, dependency);
+        }
+        Ok(model)
     }
 
     pub fn metadata(&self) -> &Metadata {
@@ -274,9 +298,11 @@ fn devirtualize_namespaces(
                 }
                 Some(dto) => {
                     let (mut fields, mut rpcs) = virtual_namespace.extract_non_static();
+                    let mut implements = std::mem::take(&mut virtual_namespace.implements);
                     dto.namespace = Some(virtual_namespace);
                     dto.fields.append(&mut fields);
                     dto.rpcs.append(&mut rpcs);
+                    dto.implements.append(&mut implements);
                     Ok(None)
                 }
             },
@@ -796,6 +822,7 @@ mod tests {
                             struct dto0 {}
                             fn rpc0() {}
                             enum en0 {}
+                            enum un0 { A(u32) }
                         }
 
                         struct dto1 {}
@@ -810,6 +837,7 @@ mod tests {
                 assert_entity_id_attr(&model, "ns0.ns1.d:dto0");
                 assert_entity_id_attr(&model, "ns0.ns1.r:rpc0");
                 assert_entity_id_attr(&model, "ns0.ns1.e:en0");
+                assert_entity_id_attr(&model, "ns0.ns1.u:un0");
                 assert_entity_id_attr(&model, "ns0.d:dto1.r:rpc1");
             }
 
@@ -828,6 +856,9 @@ mod tests {
                     Entity::Dto(entity) => Some(&entity.attributes.entity_id),
                     Entity::Rpc(entity) => Some(&entity.attributes.entity_id),
                     Entity::Enum(entity) => Some(&entity.attributes.entity_id),
+                    Entity::Union(entity) => Some(&entity.attributes.entity_id),
+                    Entity::Variant(_) => None,
+                    Entity::Interface(entity) => Some(&entity.attributes.entity_id),
                     Entity::Field(entity) => Some(&entity.attributes.entity_id),
                     Entity::Type(_) => None,
                     Entity::TypeAlias(entity) => Some(&entity.attributes.entity_id),
@@ -930,6 +961,25 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn dto_interface() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        struct asdf {}
+                        trait asdf {}
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::DuplicateDtoOrEnumOrAlias(EntityId::new_unqualified(
+                        "ns.asdf",
+                    )),
+                );
+            }
+
             #[test]
             fn enum_alias() {
                 let mut exe = TestExecutor::new(
@@ -1346,6 +1396,25 @@ mod tests {
                     ),
                 );
             }
+
+            #[test]
+            fn error_type_invalid_linkage() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    fn rpc() -> Result<(), ns::error> {}
+                    mod ns {
+                        struct definitely_not_error {}
+                    }"#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidRpcErrorType(
+                        EntityId::try_from("r:rpc").unwrap(),
+                        EntityId::new_unqualified("ns.error"),
+                    ),
+                );
+            }
         }
 
         mod validate_enum {
@@ -1438,221 +1507,1037 @@ mod tests {
                     ),
                 );
             }
-        }
-
-        mod validate_ty_alias {
-            use crate::model::builder::tests::{
-                assert_contains_error, build_from_input, test_builder,
-            };
-            use crate::model::builder::ValidationError;
-            use crate::model::EntityId;
-            use crate::test_util::executor::TestExecutor;
 
             #[test]
-            fn name_empty() {
+            fn flags_values() {
                 let mut exe = TestExecutor::new(
                     r#"
                     mod ns {
-                        type alias0 = u32;
-                        type alias1 = u32;
-                        type alias2 = u32;
+                        #[flags]
+                        enum en {
+                            none = 0,
+                            a = 1,
+                            b = 2,
+                            ab = 3,
+                            c = 8,
+                        }
                     }
                 "#,
                 );
-                let mut builder = test_builder(&mut exe);
-                builder
-                    .api
-                    .find_ty_alias_mut(&EntityId::new_unqualified("ns.alias2"))
-                    .unwrap()
-                    .name = "";
+                let result = build_from_input(&mut exe);
+                assert!(result.is_ok());
+            }
 
-                let result = builder.build();
-                let expected_entity_id = EntityId::try_from("ns").unwrap();
-                let expected_index = 2;
+            #[test]
+            fn flags_value_invalid() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        #[flags]
+                        enum en {
+                            a = 1,
+                            b = 2,
+                            c = 5,
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
                 assert_contains_error(
                     &result,
-                    ValidationError::InvalidTypeAliasName(
-                        expected_entity_id.to_owned(),
-                        expected_index,
+                    ValidationError::InvalidFlagsValue(
+                        EntityId::try_from("ns.e:en").unwrap(),
+                        "c".to_string(),
                     ),
                 );
             }
 
             #[test]
-            fn target_ty_invalid_linkage() {
+            fn flags_value_negative() {
                 let mut exe = TestExecutor::new(
                     r#"
-                    type alias = ns::dto;
                     mod ns {
-                        struct definitely_not_dto {}
-                    }"#,
+                        #[flags]
+                        enum en {
+                            a = 1,
+                        }
+                    }
+                "#,
                 );
-                let result = build_from_input(&mut exe);
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_enum_mut(&EntityId::new_unqualified("ns.en"))
+                    .unwrap()
+                    .value_mut("a")
+                    .unwrap()
+                    .number = -1;
+
+                let result = builder.build();
                 assert_contains_error(
                     &result,
-                    ValidationError::InvalidTypeAliasTargetType(
-                        EntityId::try_from("a:alias").unwrap(),
-                        EntityId::new_unqualified("ns.dto"),
+                    ValidationError::InvalidFlagsValue(
+                        EntityId::try_from("ns.e:en").unwrap(),
+                        "a".to_string(),
                     ),
                 );
             }
         }
 
-        mod validate_namespace {
-            use crate::model::builder::tests::{assert_contains_error, build_from_input};
+        mod validate_union {
+            use crate::model::builder::tests::{
+                assert_contains_error, build_from_input, test_builder,
+            };
             use crate::model::builder::ValidationError;
-            use crate::model::{Builder, EntityId, UNDEFINED_NAMESPACE};
+            use crate::model::{EntityId, Type};
             use crate::test_util::executor::TestExecutor;
 
             #[test]
-            fn root_namespace_undefined_allowed() {
-                let builder = Builder::default();
-                assert!(builder.build().is_ok());
-            }
+            fn name_empty() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        enum union0 { a(u32) }
+                        enum union1 { a(u32) }
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_union_mut(&EntityId::new_unqualified("ns.union1"))
+                    .unwrap()
+                    .name = "";
 
-            #[test]
-            fn name_within_root_not_undefined() {
-                let mut exe = TestExecutor::new("mod asdf {}".replace("asdf", UNDEFINED_NAMESPACE));
+                let result = builder.build();
                 assert_contains_error(
-                    &build_from_input(&mut exe),
-                    ValidationError::InvalidNamespaceName(
-                        EntityId::new_unqualified(UNDEFINED_NAMESPACE).with_qualified_namespaces(),
-                    ),
+                    &result,
+                    ValidationError::InvalidUnionName(EntityId::try_from("ns").unwrap(), 1),
                 );
             }
 
             #[test]
-            fn name_below_root_not_undefined() {
+            fn variant_name_empty() {
                 let mut exe = TestExecutor::new(
                     r#"
                     mod ns {
-                        mod asdf {}
-                    }"#
-                    .replace("asdf", UNDEFINED_NAMESPACE),
+                        enum union {
+                            variant0(u32),
+                            variant1,
+                        }
+                    }"#,
                 );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_union_mut(&EntityId::new_unqualified("ns.union"))
+                    .unwrap()
+                    .variant_mut("variant1")
+                    .unwrap()
+                    .name = "";
+
+                let result = builder.build();
                 assert_contains_error(
-                    &build_from_input(&mut exe),
-                    ValidationError::InvalidNamespaceName(
-                        EntityId::new_unqualified_vec(["ns", UNDEFINED_NAMESPACE].iter())
-                            .with_qualified_namespaces(),
+                    &result,
+                    ValidationError::InvalidVariantName(
+                        EntityId::try_from("ns.u:union").unwrap(),
+                        1,
                     ),
                 );
             }
-        }
-
-        // todo pyx - no function parsing in rust parser
-        // mod validate_function {}
-
-        mod metadata {
-            use std::path::PathBuf;
-
-            use anyhow::Result;
-
-            use crate::model::{chunk, Builder, EntityId, ValidationError};
 
             #[test]
-            fn passed_through() -> Result<(), Vec<ValidationError>> {
-                let mut builder = Builder::default();
-                let chunk_metadata = chunk::Metadata {
-                    root_namespace: EntityId::new_unqualified("hi"),
-                    chunk: chunk::Chunk::with_relative_file_path(PathBuf::from("hi")),
-                };
-                builder.metadata.chunks.push(chunk_metadata.clone());
-                let model = builder.build()?;
-                let actual_chunk_metadata = model.metadata.chunks.get(0).unwrap();
-                assert_eq!(
-                    actual_chunk_metadata.root_namespace,
-                    chunk_metadata.root_namespace
+            fn duplicate_variant_names() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        enum union {
+                            variant(u32),
+                            variant { field: u32 },
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::DuplicateVariant(
+                        EntityId::try_from("ns.u:union").unwrap(),
+                        "variant".to_string(),
+                    ),
                 );
-                assert_eq!(actual_chunk_metadata.chunk, chunk_metadata.chunk);
-                Ok(())
             }
-        }
-
-        mod qualifies_types {
-            use crate::model::entity::FindEntity;
-            use crate::model::{Api, Entity, EntityId};
-            use crate::test_util::executor::TestExecutor;
 
             #[test]
-            fn dto_field_types() {
+            fn duplicate_variant_field_names() {
                 let mut exe = TestExecutor::new(
                     r#"
-                    mod ns0 {
-                        struct dep0 {}
-                        mod ns1 {
-                            enum dep1 {}
-                        }
-                    }
-                    mod ns2 {
-                        struct dto {
-                            zero: ns0::dep0,
-                            one: ns0::ns1::dep1,
+                    mod ns {
+                        enum union {
+                            variant { field: u32, field: u32 },
                         }
                     }
                 "#,
                 );
-                let model = exe.build();
-
-                assert_qualified_ty(&model.api, "ns2.d:dto.f:zero.ty", "ns0.dto:dep0");
-                assert_qualified_ty(&model.api, "ns2.d:dto.f:one.ty", "ns0.ns1.enum:dep1");
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::DuplicateFieldName(
+                        EntityId::try_from("ns.u:union.v:variant").unwrap(),
+                        "field".to_string(),
+                    ),
+                );
             }
 
             #[test]
-            fn rpc_param_types() {
+            fn tuple_ty_invalid_linkage() {
                 let mut exe = TestExecutor::new(
                     r#"
-                    mod ns0 {
-                        struct dep0 {}
-                        mod ns1 {
-                            enum dep1 {}
+                    mod ns {
+                        enum union {
+                            variant(u32, dto),
                         }
                     }
-                    mod ns2 {
-                        fn rpc(
-                            zero: ns0::dep0,
-                            one: ns0::ns1::dep1,
-                        ) {}
-                    }
                 "#,
                 );
-                let model = exe.build();
-
-                assert_qualified_ty(&model.api, "ns2.r:rpc.f:zero.ty", "ns0.dto:dep0");
-                assert_qualified_ty(&model.api, "ns2.r:rpc.f:one.ty", "ns0.ns1.enum:dep1");
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldOrParamType(
+                        EntityId::try_from("ns.u:union.v:variant").unwrap(),
+                        "1".to_string(),
+                        1,
+                        EntityId::new_unqualified("dto"),
+                    ),
+                );
             }
 
             #[test]
-            fn rpc_return_type() {
+            fn struct_field_ty_invalid_linkage() {
                 let mut exe = TestExecutor::new(
                     r#"
-                    mod ns0 {
-                        mod ns1 {
-                            enum dep {}
+                    mod ns {
+                        enum union {
+                            variant { field: dto },
                         }
                     }
-                    mod ns2 {
-                        fn rpc() -> ns0::ns1::dep {}
-                    }
                 "#,
                 );
-                let model = exe.build();
-
-                assert_qualified_ty(&model.api, "ns2.r:rpc.return_ty", "ns0.ns1.enum:dep");
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldOrParamType(
+                        EntityId::try_from("ns.u:union.v:variant").unwrap(),
+                        "field".to_string(),
+                        0,
+                        EntityId::new_unqualified("dto"),
+                    ),
+                );
             }
 
             #[test]
-            fn field_type() {
+            fn qualifies_payload_types() {
                 let mut exe = TestExecutor::new(
                     r#"
-                    mod ns0 {
-                        mod ns1 {
-                            enum dep {}
+                    mod ns {
+                        struct dto {}
+                        enum union {
+                            tuple(dto),
+                            structure { field: dto },
                         }
                     }
-                    mod ns2 {
-                        pub const field: ns0::ns1::dep = 0;
-                    }
+                "#,
+                );
+                let model = build_from_input(&mut exe).unwrap();
+                let union = model
+                    .api()
+                    .find_union(&EntityId::try_from("ns.u:union").unwrap())
+                    .unwrap();
+                let expected = Type::Api(EntityId::try_from("ns.d:dto").unwrap());
+                assert_eq!(
+                    union.variant("tuple").unwrap().tuple_ty("0").unwrap().value,
+                    expected
+                );
+                assert_eq!(
+                    union.variant("structure").unwrap().field("field").unwrap().ty.value,
+                    expected
+                );
+            }
+        }
+
+        mod validate_interface {
+            use crate::model::builder::tests::{
+                assert_contains_error, build_from_input, test_builder,
+            };
+            use crate::model::builder::ValidationError;
+            use crate::model::EntityId;
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn name_empty() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        trait interface0 {}
+                        trait interface1 {}
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_interface_mut(&EntityId::new_unqualified("ns.interface1"))
+                    .unwrap()
+                    .name = "";
+
+                let result = builder.build();
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidInterfaceName(EntityId::try_from("ns").unwrap(), 1),
+                );
+            }
+
+            #[test]
+            fn rpc_param_type_invalid_linkage() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        trait interface {
+                            fn rpc(&self, param: dto);
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldOrParamType(
+                        EntityId::try_from("ns.i:interface.r:rpc").unwrap(),
+                        "param".to_string(),
+                        0,
+                        EntityId::new_unqualified("dto"),
+                    ),
+                );
+            }
+
+            #[test]
+            fn qualifies_implements() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        pub trait interface {}
+                    }
+                    mod ns1 {
+                        struct dto {}
+                        impl ns0::interface for dto {}
+                        impl Display for dto {
+                            fn fmt(&self) {}
+                        }
+                    }
+                "#,
+                );
+                let model = build_from_input(&mut exe).unwrap();
+                let dto = model
+                    .api()
+                    .find_dto(&EntityId::try_from("ns1.d:dto").unwrap())
+                    .unwrap();
+                assert_eq!(
+                    dto.implements,
+                    vec![EntityId::try_from("ns0.i:interface").unwrap()]
+                );
+                assert!(dto.rpcs.is_empty());
+            }
+
+            #[test]
+            fn qualifies_super_interfaces() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        trait base {}
+                        trait interface: base + Send {}
+                    }
+                "#,
+                );
+                let model = build_from_input(&mut exe).unwrap();
+                let interface = model
+                    .api()
+                    .find_interface(&EntityId::try_from("ns.i:interface").unwrap())
+                    .unwrap();
+                assert_eq!(
+                    interface.super_interfaces,
+                    vec![EntityId::try_from("ns.i:base").unwrap()]
+                );
+            }
+
+            #[test]
+            fn implements_non_interface() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        struct other {}
+                        struct dto {}
+                        impl other for dto {}
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidInterface(
+                        EntityId::try_from("ns.d:dto").unwrap(),
+                        EntityId::try_from("ns.d:other").unwrap(),
+                    ),
+                );
+            }
+        }
+
+        mod validate_extends {
+            use crate::model::builder::tests::{assert_contains_error, test_builder};
+            use crate::model::builder::ValidationError;
+            use crate::model::EntityId;
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn qualifies_extends() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        struct base {}
+                    }
+                    mod ns1 {
+                        struct dto {}
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_dto_mut(&EntityId::new_unqualified("ns1.dto"))
+                    .unwrap()
+                    .extends = vec![EntityId::new_unqualified("ns0.base")];
+
+                let model = builder.build().unwrap();
+                let dto = model
+                    .api()
+                    .find_dto(&EntityId::try_from("ns1.d:dto").unwrap())
+                    .unwrap();
+                assert_eq!(
+                    dto.extends,
+                    vec![EntityId::try_from("ns0.d:base").unwrap()]
+                );
+            }
+
+            #[test]
+            fn extends_non_dto() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        enum en {}
+                        struct dto {}
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_dto_mut(&EntityId::new_unqualified("ns.dto"))
+                    .unwrap()
+                    .extends = vec![EntityId::new_unqualified("en")];
+
+                let result = builder.build();
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidBaseDto(
+                        EntityId::try_from("ns.d:dto").unwrap(),
+                        EntityId::try_from("ns.e:en").unwrap(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn extends_missing() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        struct dto {}
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_dto_mut(&EntityId::new_unqualified("ns.dto"))
+                    .unwrap()
+                    .extends = vec![EntityId::new_unqualified("missing")];
+
+                let result = builder.build();
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidBaseDto(
+                        EntityId::try_from("ns.d:dto").unwrap(),
+                        EntityId::new_unqualified("missing"),
+                    ),
+                );
+            }
+        }
+
+        mod validate_field_value {
+            use crate::model::builder::tests::{assert_contains_error, build_from_input};
+            use crate::model::builder::ValidationError;
+            use crate::model::{EntityId, Value};
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn valid() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const int: u8 = 255;
+                        const uint: u64 = 18446744073709551615;
+                        const float: f32 = 1.5;
+                        const int_as_float: f64 = 1;
+                        const boolean: bool = true;
+                        const string: &str = "str";
+                        const en: Status = Status::Active;
+                        const alias: Alias = 5;
+                        const opt: Option<i32> = -5;
+                        const non_literal: u32 = 1 << 2;
+                        struct dto {
+                            #[default(Status::Active)]
+                            field: Option<Status>,
+                        }
+                        fn rpc(#[default("name")] param: String) {}
+                        enum Status { Active }
+                        type Alias = u32;
+                    }
+                "#,
+                );
+                build_from_input(&mut exe).unwrap();
+            }
+
+            #[test]
+            fn wrong_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const field: u32 = "str";
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldValue(
+                        EntityId::try_from("ns.f:field").unwrap(),
+                        r#"String("str")"#.to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn int_out_of_range() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const field: u8 = 256;
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldValue(
+                        EntityId::try_from("ns.f:field").unwrap(),
+                        "Int(256)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn uint_out_of_range() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const field: i64 = 9223372036854775808;
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldValue(
+                        EntityId::try_from("ns.f:field").unwrap(),
+                        "UInt(9223372036854775808)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn unknown_enum_value() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const field: Status = Status::Inactive;
+                        enum Status { Active }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldValue(
+                        EntityId::try_from("ns.f:field").unwrap(),
+                        r#"EnumValue("Inactive")"#.to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn invalid_dto_field_default() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        struct dto {
+                            #[default(true)]
+                            field: u32,
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldDefault(
+                        EntityId::try_from("ns.d:dto.f:field").unwrap(),
+                        "Bool(true)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn invalid_rpc_param_default() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        fn rpc(#[default(-1)] param: u32) {}
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldDefault(
+                        EntityId::try_from("ns.r:rpc.f:param").unwrap(),
+                        "Int(-1)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn invalid_interface_rpc_param_default() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        trait Service {
+                            fn rpc(#[default(-1)] param: u32);
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldDefault(
+                        EntityId::try_from("ns.i:Service.r:rpc.f:param").unwrap(),
+                        "Int(-1)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn invalid_variant_field_default() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        enum Payload {
+                            A {
+                                #[default(true)]
+                                field: u32,
+                            },
+                        }
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidFieldDefault(
+                        EntityId::try_from("ns.u:Payload.v:A.f:field").unwrap(),
+                        "Bool(true)".to_string(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn int_as_float() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        const field: f64 = 1;
+                        const alias: Ratio = 2;
+                        type Ratio = Option<f32>;
+                        trait Service {
+                            fn rpc(#[default(3)] param: f32);
+                        }
+                    }
+                "#,
+                );
+                let model = build_from_input(&mut exe).unwrap();
+                let ns = model
+                    .api()
+                    .find_namespace(&EntityId::try_from("ns").unwrap())
+                    .unwrap();
+                assert_eq!(ns.field("field").unwrap().value, Some(Value::Float(1.0)));
+                assert_eq!(ns.field("alias").unwrap().value, Some(Value::Float(2.0)));
+                assert_eq!(
+                    ns.interface("Service").unwrap().rpcs[0].params[0].default,
+                    Some(Value::Float(3.0))
+                );
+            }
+        }
+
+        mod validate_ty_alias {
+            use crate::model::builder::tests::{
+                assert_contains_error, build_from_input, test_builder,
+            };
+            use crate::model::builder::ValidationError;
+            use crate::model::EntityId;
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn name_empty() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        type alias0 = u32;
+                        type alias1 = u32;
+                        type alias2 = u32;
+                    }
+                "#,
+                );
+                let mut builder = test_builder(&mut exe);
+                builder
+                    .api
+                    .find_ty_alias_mut(&EntityId::new_unqualified("ns.alias2"))
+                    .unwrap()
+                    .name = "";
+
+                let result = builder.build();
+                let expected_entity_id = EntityId::try_from("ns").unwrap();
+                let expected_index = 2;
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidTypeAliasName(
+                        expected_entity_id.to_owned(),
+                        expected_index,
+                    ),
+                );
+            }
+
+            #[test]
+            fn target_ty_invalid_linkage() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    type alias = ns::dto;
+                    mod ns {
+                        struct definitely_not_dto {}
+                    }"#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidTypeAliasTargetType(
+                        EntityId::try_from("a:alias").unwrap(),
+                        EntityId::new_unqualified("ns.dto"),
+                    ),
+                );
+            }
+        }
+
+        mod validate_generics {
+            use crate::model::builder::tests::{assert_contains_error, build_from_input};
+            use crate::model::builder::ValidationError;
+            use crate::model::EntityId;
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn valid() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    struct Page<T> {
+                        items: Vec<T>,
+                    }
+                    impl<T> Page<T> {
+                        fn first(page: Page<T>) -> Option<T> {}
+                    }
+                    struct dto {}
+                    type DtoPage = Page<dto>;
+                    fn rpc<T>(page: Page<T>) -> T {}
+                "#,
+                );
+                assert!(build_from_input(&mut exe).is_ok());
+            }
+
+            #[test]
+            fn ty_alias_uses_enclosing_type_param() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    struct outer<T> {}
+                    impl<T> outer<T> {
+                        type alias = Vec<T>;
+                    }
+                "#,
+                );
+                assert!(build_from_input(&mut exe).is_ok());
+            }
+
+            #[test]
+            fn too_many_type_args() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    struct Page<T> {}
+                    struct dto {
+                        field: Page<u32, u32>,
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidTypeArgCount(
+                        EntityId::try_from("d:dto.f:field").unwrap(),
+                        EntityId::try_from("d:Page").unwrap(),
+                        1,
+                        2,
+                    ),
+                );
+            }
+
+            #[test]
+            fn missing_type_args() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    struct Page<T> {}
+                    type alias = Page;
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidTypeArgCount(
+                        EntityId::try_from("a:alias").unwrap(),
+                        EntityId::try_from("d:Page").unwrap(),
+                        1,
+                        0,
+                    ),
+                );
+            }
+
+            #[test]
+            fn undeclared_type_param() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    struct Page<T> {}
+                    impl<U> Page<U> {
+                        fn rpc() -> U {}
+                    }
+                "#,
+                );
+                let result = build_from_input(&mut exe);
+                assert_contains_error(
+                    &result,
+                    ValidationError::InvalidTypeParam(
+                        EntityId::try_from("d:Page.r:rpc.ty:return_ty").unwrap(),
+                        "U".to_string(),
+                    ),
+                );
+            }
+        }
+
+        mod validate_namespace {
+            use crate::model::builder::tests::{assert_contains_error, build_from_input};
+            use crate::model::builder::ValidationError;
+            use crate::model::{Builder, EntityId, UNDEFINED_NAMESPACE};
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn root_namespace_undefined_allowed() {
+                let builder = Builder::default();
+                assert!(builder.build().is_ok());
+            }
+
+            #[test]
+            fn name_within_root_not_undefined() {
+                let mut exe = TestExecutor::new("mod asdf {}".replace("asdf", UNDEFINED_NAMESPACE));
+                assert_contains_error(
+                    &build_from_input(&mut exe),
+                    ValidationError::InvalidNamespaceName(
+                        EntityId::new_unqualified(UNDEFINED_NAMESPACE).with_qualified_namespaces(),
+                    ),
+                );
+            }
+
+            #[test]
+            fn name_below_root_not_undefined() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns {
+                        mod asdf {}
+                    }"#
+                    .replace("asdf", UNDEFINED_NAMESPACE),
+                );
+                assert_contains_error(
+                    &build_from_input(&mut exe),
+                    ValidationError::InvalidNamespaceName(
+                        EntityId::new_unqualified_vec(["ns", UNDEFINED_NAMESPACE].iter())
+                            .with_qualified_namespaces(),
+                    ),
+                );
+            }
+        }
+
+        // todo pyx - no function parsing in rust parser
+        // mod validate_function {}
+
+        mod metadata {
+            use std::path::PathBuf;
+
+            use anyhow::Result;
+
+            use crate::model::{chunk, Builder, EntityId, ValidationError};
+
+            #[test]
+            fn passed_through() -> Result<(), Vec<ValidationError>> {
+                let mut builder = Builder::default();
+                let chunk_metadata = chunk::Metadata {
+                    root_namespace: EntityId::new_unqualified("hi"),
+                    chunk: chunk::Chunk::with_relative_file_path(PathBuf::from("hi")),
+                };
+                builder.metadata.chunks.push(chunk_metadata.clone());
+                let model = builder.build()?;
+                let actual_chunk_metadata = model.metadata.chunks.get(0).unwrap();
+                assert_eq!(
+                    actual_chunk_metadata.root_namespace,
+                    chunk_metadata.root_namespace
+                );
+                assert_eq!(actual_chunk_metadata.chunk, chunk_metadata.chunk);
+                Ok(())
+            }
+        }
+
+        mod qualifies_types {
+            use crate::model::entity::FindEntity;
+            use crate::model::{Api, Entity, EntityId, Type};
+            use crate::test_util::executor::TestExecutor;
+
+            #[test]
+            fn dto_field_types() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        struct dep0 {}
+                        mod ns1 {
+                            enum dep1 {}
+                        }
+                    }
+                    mod ns2 {
+                        struct dto {
+                            zero: ns0::dep0,
+                            one: ns0::ns1::dep1,
+                        }
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                assert_qualified_ty(&model.api, "ns2.d:dto.f:zero.ty", "ns0.dto:dep0");
+                assert_qualified_ty(&model.api, "ns2.d:dto.f:one.ty", "ns0.ns1.enum:dep1");
+            }
+
+            #[test]
+            fn rpc_param_types() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        struct dep0 {}
+                        mod ns1 {
+                            enum dep1 {}
+                        }
+                    }
+                    mod ns2 {
+                        fn rpc(
+                            zero: ns0::dep0,
+                            one: ns0::ns1::dep1,
+                        ) {}
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                assert_qualified_ty(&model.api, "ns2.r:rpc.f:zero.ty", "ns0.dto:dep0");
+                assert_qualified_ty(&model.api, "ns2.r:rpc.f:one.ty", "ns0.ns1.enum:dep1");
+            }
+
+            #[test]
+            fn rpc_return_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        mod ns1 {
+                            enum dep {}
+                        }
+                    }
+                    mod ns2 {
+                        fn rpc() -> ns0::ns1::dep {}
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                assert_qualified_ty(&model.api, "ns2.r:rpc.return_ty", "ns0.ns1.enum:dep");
+            }
+
+            #[test]
+            fn rpc_error_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        mod ns1 {
+                            enum dep {}
+                        }
+                    }
+                    mod ns2 {
+                        fn rpc() -> Result<u32, ns0::ns1::dep> {}
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                assert_qualified_ty(&model.api, "ns2.r:rpc.error_ty", "ns0.ns1.enum:dep");
+            }
+
+            #[test]
+            fn field_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        mod ns1 {
+                            enum dep { A }
+                        }
+                    }
+                    mod ns2 {
+                        pub const field: ns0::ns1::dep = ns0::ns1::dep::A;
+                    }
                 "#,
                 );
                 let model = exe.build();
@@ -1660,6 +2545,69 @@ mod tests {
                 assert_qualified_ty(&model.api, "ns2.f:field.ty", "ns0.ns1.enum:dep");
             }
 
+            #[test]
+            fn set_element_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        mod ns1 {
+                            enum dep {}
+                        }
+                    }
+                    mod ns2 {
+                        struct dto {
+                            field: HashSet<ns0::ns1::dep>,
+                        }
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                let ty_id = EntityId::try_from("ns2.d:dto.f:field.ty").unwrap();
+                let Some(Entity::Type(ty)) = model.api.find_entity(ty_id) else {
+                    panic!("found wrong type");
+                };
+                let Type::Set(ty) = &ty.value else {
+                    panic!("expected set");
+                };
+                let target = ty.value.api().unwrap();
+                assert!(target.is_qualified(), "target not qualified");
+                assert_eq!(target.to_string(), "ns0.ns1.enum:dep");
+            }
+
+            #[test]
+            fn fixed_array_tuple_element_type() {
+                let mut exe = TestExecutor::new(
+                    r#"
+                    mod ns0 {
+                        mod ns1 {
+                            enum dep {}
+                        }
+                    }
+                    mod ns2 {
+                        struct dto {
+                            field: [(u32, ns0::ns1::dep); 2],
+                        }
+                    }
+                "#,
+                );
+                let model = exe.build();
+
+                let ty_id = EntityId::try_from("ns2.d:dto.f:field.ty").unwrap();
+                let Some(Entity::Type(ty)) = model.api.find_entity(ty_id) else {
+                    panic!("found wrong type");
+                };
+                let Type::FixedArray { ty, len: 2 } = &ty.value else {
+                    panic!("expected fixed array");
+                };
+                let Type::Tuple(elements) = &ty.value else {
+                    panic!("expected tuple");
+                };
+                let target = elements[1].ty.value.api().unwrap();
+                assert!(target.is_qualified(), "target not qualified");
+                assert_eq!(target.to_string(), "ns0.ns1.enum:dep");
+            }
+
             #[test]
             fn ty_alias_target_type() {
                 let mut exe = TestExecutor::new(
@@ -0,0 +1,243 @@
+use chumsky::prelude::*;
+
+use crate::model::{Attributes, Dto, Field};
+use crate::parser::c::preprocessor::{skip_macros, Macros};
+use crate::parser::c::{comment, ty};
+use crate::parser::error::Error;
+use crate::parser::{util, Config};
+
+/// `struct Name { ... };`
+pub fn parser<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Dto<'a>, Error<'a>> {
+    let prefix = util::keyword_ex("struct").then(text::whitespace().at_least(1));
+    comment::multi()
+        .padded()
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then(fields(config, macros))
+        .then_ignore(skip_macros(macros))
+        .then_ignore(just(';').padded())
+        .map(|((comments, name), fields)| Dto {
+            name,
+            type_params: vec![],
+            fields,
+            rpcs: vec![],
+            extends: vec![],
+            implements: vec![],
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+            namespace: None,
+        })
+}
+
+/// Forward declaration of a struct, e.g. `struct Name;`. Returns the name of the struct.
+pub fn declaration<'a>(macros: &Macros) -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    let prefix = util::keyword_ex("struct").then(text::whitespace().at_least(1));
+    comment::multi()
+        .padded()
+        .then(skip_macros(macros))
+        .ignore_then(prefix)
+        .ignore_then(text::ident())
+        .then_ignore(just(';').padded())
+}
+
+/// The body of a struct, e.g. `{ int a, b; const char *name; uint8_t data[16]; }`.
+pub fn fields<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    field_decl(config, macros)
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(|decls| decls.into_iter().flatten().collect::<Vec<_>>())
+        .then_ignore(comment::multi().padded())
+        .delimited_by(
+            just('{').padded(),
+            just('}').padded().recover_with(skip_then_retry_until(
+                none_of("}").ignored(),
+                just('}').ignored(),
+            )),
+        )
+}
+
+/// A single declaration within a struct which may declare multiple fields, e.g. `int a, *b;`.
+/// Bit field widths are ignored.
+fn field_decl<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    let bit_field = just(':').padded().then(text::int(10)).padded();
+    comment::multi()
+        .padded()
+        .then_ignore(skip_macros(macros))
+        .then(ty::base(config))
+        .then(
+            ty::declarator(config)
+                .then_ignore(bit_field.or_not())
+                .map_with_span(|declarator, span| (declarator, span))
+                .separated_by(just(',').padded())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .then_ignore(skip_macros(macros))
+        .then_ignore(just(';').padded())
+        .try_map(move |((comments, base), declarators), _| {
+            declarators
+                .into_iter()
+                .map(|(declarator, span)| {
+                    let name = declarator
+                        .name
+                        .ok_or_else(|| Rich::custom(span, "anonymous fields are not supported"))?;
+                    let ty = declarator
+                        .ty(config, base.clone(), span)?
+                        .ok_or_else(|| Rich::custom(span, "fields cannot be `void`"))?;
+                    Ok(Field {
+                        name,
+                        ty,
+                        attributes: Attributes {
+                            comments: comments.clone(),
+                            ..Default::default()
+                        },
+                        is_static: false,
+                        value: None,
+                        default: None,
+                    })
+                })
+                .collect()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::rc::Rc;
+
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use crate::parser::c::dto;
+    use crate::parser::c::preprocessor::Macros;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn empty() -> Result<()> {
+        let macros = Macros::default();
+        let dto = dto::parser(&TEST_CONFIG, &macros)
+            .parse("struct Empty {};")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Empty");
+        assert!(dto.fields.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn fields() -> Result<()> {
+        let macros = Macros::default();
+        let dto = dto::parser(&TEST_CONFIG, &macros)
+            .parse(
+                r#"
+                struct Name {
+                    int a, *b;
+                    const char *name;
+                    uint8_t data[16];
+                    struct Name *next;
+                    unsigned flag : 1;
+                };
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let fields = dto
+            .fields
+            .iter()
+            .map(|field| (field.name, field.ty.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("a", TypeRef::new(Type::I32, Semantics::Value)),
+                ("b", TypeRef::new(Type::I32, Semantics::Mut)),
+                ("name", TypeRef::new(Type::String, Semantics::Value)),
+                (
+                    "data",
+                    TypeRef::new_fixed_array(
+                        TypeRef::new(Type::U8, Semantics::Value),
+                        16,
+                        Semantics::Value
+                    )
+                ),
+                (
+                    "next",
+                    TypeRef::new(Type::Api(EntityId::new_unqualified("Name")), Semantics::Mut)
+                ),
+                ("flag", TypeRef::new(Type::U32, Semantics::Value)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comments() -> Result<()> {
+        let macros = Macros::default();
+        let dto = dto::parser(&TEST_CONFIG, &macros)
+            .parse(
+                r#"
+                // dto comment
+                struct Name {
+                    /* field comment */
+                    int a;
+                    // trailing comment
+                };
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn macros() -> Result<()> {
+        let macros = Rc::new(HashSet::from(["PACKED".to_string()]));
+        let dto = dto::parser(&TEST_CONFIG, &macros)
+            .parse("struct Name { int a; } PACKED __attribute__((aligned(8)));")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let macros = Macros::default();
+        let name = dto::declaration(&macros)
+            .parse("struct Name ;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(name, "Name");
+        Ok(())
+    }
+
+    #[test]
+    fn void_field() {
+        let macros = Macros::default();
+        let result = dto::parser(&TEST_CONFIG, &macros)
+            .parse("struct Name { void a; };")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use chumsky::prelude::*;
+
+use crate::model::{Attributes, Enum, EnumKind, EnumValue, EnumValueNumber};
+use crate::parser::c::comment;
+use crate::parser::error::Error;
+use crate::parser::util;
+
+/// `enum Name { ... };`
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Enum<'a>, Error<'a>> {
+    let prefix = util::keyword_ex("enum").then(text::whitespace().at_least(1));
+    comment::multi()
+        .padded()
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then(values())
+        .then_ignore(just(';').padded())
+        .map(|((comments, name), values)| Enum {
+            name,
+            values,
+            kind: EnumKind::Plain,
+            underlying_type: None,
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+        })
+}
+
+/// The body of an enum, e.g. `{ A, B = 4, C = B | 1 }`.
+///
+/// Values without an initializer are one more than the previous value. Initializers are constant
+/// expressions that can reference previous values in the same enum.
+pub fn values<'a>() -> impl Parser<'a, &'a str, Vec<EnumValue<'a>>, Error<'a>> {
+    let initializer = just('=').padded().ignore_then(expr());
+    let value = comment::multi()
+        .padded()
+        .then(text::ident())
+        .then(initializer.or_not())
+        .padded();
+    value
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi().padded())
+        .delimited_by(just('{').padded(), just('}').padded())
+        .try_map(|values, span| {
+            let mut evaluated = HashMap::new();
+            let mut next_number = 0;
+            values
+                .into_iter()
+                .map(|((comments, name), initializer)| {
+                    let number = match initializer {
+                        None => next_number,
+                        Some(expr) => expr.eval(&evaluated).ok_or_else(|| {
+                            Rich::custom(span, format!("unable to evaluate the value of {}", name))
+                        })?,
+                    };
+                    evaluated.insert(name, number);
+                    next_number = number.wrapping_add(1);
+                    Ok(EnumValue {
+                        name,
+                        number,
+                        attributes: Attributes {
+                            comments,
+                            ..Default::default()
+                        },
+                    })
+                })
+                .collect()
+        })
+}
+
+/// Constant integer expression used to initialize an enum value.
+#[derive(Debug, Clone, PartialEq)]
+enum Expr<'a> {
+    Number(EnumValueNumber),
+    Name(&'a str),
+    Unary(char, Box<Expr<'a>>),
+    Binary(Box<Expr<'a>>, &'a str, Box<Expr<'a>>),
+}
+
+impl Expr<'_> {
+    /// Returns None if the expression references unknown names or overflows.
+    fn eval(&self, values: &HashMap<&str, EnumValueNumber>) -> Option<EnumValueNumber> {
+        match self {
+            Expr::Number(number) => Some(*number),
+            Expr::Name(name) => values.get(name).copied(),
+            Expr::Unary(op, expr) => {
+                let value = expr.eval(values)?;
+                match op {
+                    '-' => value.checked_neg(),
+                    '~' => Some(!value),
+                    _ => Some(value),
+                }
+            }
+            Expr::Binary(lhs, op, rhs) => {
+                let lhs = lhs.eval(values)?;
+                let rhs = rhs.eval(values)?;
+                match *op {
+                    "*" => lhs.checked_mul(rhs),
+                    "/" => lhs.checked_div(rhs),
+                    "%" => lhs.checked_rem(rhs),
+                    "+" => lhs.checked_add(rhs),
+                    "-" => lhs.checked_sub(rhs),
+                    "<<" => lhs.checked_shl(u32::try_from(rhs).ok()?),
+                    ">>" => lhs.checked_shr(u32::try_from(rhs).ok()?),
+                    "&" => Some(lhs & rhs),
+                    "^" => Some(lhs ^ rhs),
+                    "|" => Some(lhs | rhs),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+fn expr<'a>() -> impl Parser<'a, &'a str, Expr<'a>, Error<'a>> {
+    recursive(|expr| {
+        let atom = choice((
+            number().map(Expr::Number),
+            text::ident().map(Expr::Name),
+            expr.delimited_by(just('(').padded(), just(')').padded()),
+        ))
+        .padded();
+
+        let unary = one_of("-~+")
+            .padded()
+            .repeated()
+            .foldr(atom, |op, expr| Expr::Unary(op, Box::new(expr)));
+
+        // Binary operators by increasing precedence level, following C.
+        let binary = |operand: Boxed<'a, 'a, &'a str, Expr<'a>, Error<'a>>, ops: &'a [&'a str]| {
+            operand
+                .clone()
+                .foldl(
+                    choice((just("<<"), just(">>"), one_of("*/%+-&^|").slice()))
+                        .try_map(move |op: &str, span| {
+                            if ops.contains(&op) {
+                                Ok(op)
+                            } else {
+                                Err(Rich::custom(span, format!("unexpected operator {}", op)))
+                            }
+                        })
+                        .padded()
+                        .then(operand)
+                        .repeated(),
+                    |lhs, (op, rhs)| Expr::Binary(Box::new(lhs), op, Box::new(rhs)),
+                )
+                .boxed()
+        };
+        let product = binary(unary.boxed(), &["*", "/", "%"]);
+        let sum = binary(product, &["+", "-"]);
+        let shift = binary(sum, &["<<", ">>"]);
+        let and = binary(shift, &["&"]);
+        let xor = binary(and, &["^"]);
+        binary(xor, &["|"])
+    })
+}
+
+/// Integer and character literals, e.g. `42`, `0x2A`, `052`, `0b101010`, `42UL`, or `'*'`.
+fn number<'a>() -> impl Parser<'a, &'a str, EnumValueNumber, Error<'a>> {
+    let radix = |prefixes: [&'static str; 2], radix: u32| {
+        just(prefixes[0])
+            .or(just(prefixes[1]))
+            .ignore_then(text::digits(radix).slice())
+            .try_map(move |digits: &str, span| {
+                EnumValueNumber::from_str_radix(digits, radix)
+                    .map_err(|_| Rich::custom(span, "invalid integer literal"))
+            })
+    };
+    let decimal_or_octal = text::digits(10).slice().try_map(|digits: &str, span| {
+        let result = match digits.strip_prefix('0') {
+            Some(octal) if !octal.is_empty() => EnumValueNumber::from_str_radix(octal, 8),
+            _ => digits.parse::<EnumValueNumber>(),
+        };
+        result.map_err(|_| Rich::custom(span, "invalid integer literal"))
+    });
+    let integer = choice((
+        radix(["0x", "0X"], 16),
+        radix(["0b", "0B"], 2),
+        decimal_or_octal,
+    ))
+    .then_ignore(one_of("uUlL").repeated());
+    let char = none_of("\\'")
+        .delimited_by(just('\''), just('\''))
+        .map(|c: char| c as EnumValueNumber);
+    choice((integer, char))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{EnumValue, EnumValueNumber};
+    use crate::parser::c::en;
+    use crate::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn empty() -> Result<()> {
+        let en = en::parser()
+            .parse("enum Empty {};")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "Empty");
+        assert!(en.values.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn implicit_values() -> Result<()> {
+        let values = run_test("{ A, B, C, }")?;
+        assert_eq!(values, vec![("A", 0), ("B", 1), ("C", 2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_values() -> Result<()> {
+        let values = run_test("{ A = 5, B, C = -1, D }")?;
+        assert_eq!(values, vec![("A", 5), ("B", 6), ("C", -1), ("D", 0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn literals() -> Result<()> {
+        let values = run_test("{ A = 0x1F, B = 017, C = 0b11, D = 10u, E = 'a', F = 0 }")?;
+        assert_eq!(
+            values,
+            vec![
+                ("A", 31),
+                ("B", 15),
+                ("C", 3),
+                ("D", 10),
+                ("E", 97),
+                ("F", 0)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expressions() -> Result<()> {
+        let values = run_test(
+            r#"{
+                A = 1 << 0,
+                B = 1 << 1,
+                AB = A | B,
+                C = (AB + 2) * 3,
+                D = ~0 & 0xF ^ 1,
+            }"#,
+        )?;
+        assert_eq!(
+            values,
+            vec![("A", 1), ("B", 2), ("AB", 3), ("C", 15), ("D", 14)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comments() -> Result<()> {
+        let en = en::parser()
+            .parse(
+                r#"
+                // enum comment
+                enum Name {
+                    // value comment
+                    A,
+                    // trailing comment
+                };
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.attributes.comments.len(), 1);
+        assert_eq!(en.values[0].attributes.comments.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_name() {
+        let result = en::values().parse("{ A = UNKNOWN }").into_result();
+        assert!(result.is_err());
+    }
+
+    fn run_test(data: &'static str) -> Result<Vec<(&'static str, EnumValueNumber)>> {
+        let values = en::values()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        Ok(values
+            .into_iter()
+            .map(|EnumValue { name, number, .. }| (name, number))
+            .collect())
+    }
+}
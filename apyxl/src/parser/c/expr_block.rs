@@ -0,0 +1,49 @@
+use crate::model::Comment;
+use crate::parser::c::comment;
+use crate::parser::error::Error;
+use chumsky::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExprBlock<'a> {
+    Comment(Comment<'a>),
+    Body(&'a str),
+    Nested(Vec<ExprBlock<'a>>),
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<ExprBlock<'a>>, Error<'a>> {
+    let body = none_of("{}").repeated().at_least(1).slice().map(&str::trim);
+    recursive(|nested| {
+        choice((
+            comment::single().boxed().padded().map(ExprBlock::Comment),
+            nested.map(ExprBlock::Nested),
+            body.map(ExprBlock::Body),
+        ))
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+        .recover_with(via_parser(nested_delimiters('{', '}', [], |_| vec![])))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::Parser;
+
+    use crate::parser::c::expr_block;
+    use crate::parser::c::expr_block::ExprBlock;
+
+    #[test]
+    fn nested() {
+        let result = expr_block::parser()
+            .parse("{ if (x) { return 1; } return 0; }")
+            .into_result();
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                ExprBlock::Body("if (x)"),
+                ExprBlock::Nested(vec![ExprBlock::Body("return 1;")]),
+                ExprBlock::Body("return 0;"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use chumsky::prelude::*;
+
+use crate::model::{Attributes, Field};
+use crate::parser::c::preprocessor::{skip_macros, Macros};
+use crate::parser::c::visibility::Visibility;
+use crate::parser::c::{comment, expr_block, ty, visibility};
+use crate::parser::error::Error;
+use crate::parser::Config;
+
+/// Global variable declarations, e.g. `extern const int a, b[4];`. Initializers are skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Vec<(Field<'a>, Visibility)>, Error<'a>> {
+    let initializer = just('=')
+        .padded()
+        .then(choice((expr_block::parser().ignored(), none_of(",;{").ignored())).repeated());
+    comment::multi()
+        .padded()
+        .then_ignore(skip_macros(macros))
+        .then(visibility::parser())
+        .then_ignore(skip_macros(macros))
+        .then(ty::base(config))
+        .then(
+            ty::declarator(config)
+                .then_ignore(initializer.or_not())
+                .map_with_span(|declarator, span| (declarator, span))
+                .separated_by(just(',').padded())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .then_ignore(skip_macros(macros))
+        .then_ignore(just(';').padded())
+        .try_map(move |(((comments, visibility), base), declarators), _| {
+            declarators
+                .into_iter()
+                .map(|(declarator, span)| {
+                    let name = declarator
+                        .name
+                        .ok_or_else(|| Rich::custom(span, "declaration is missing a name"))?;
+                    let ty = declarator
+                        .ty(config, base.clone(), span)?
+                        .ok_or_else(|| Rich::custom(span, "fields cannot be `void`"))?;
+                    let field = Field {
+                        name,
+                        ty,
+                        attributes: Attributes {
+                            comments: comments.clone(),
+                            ..Default::default()
+                        },
+                        is_static: true,
+                        value: None,
+                        default: None,
+                    };
+                    Ok((field, visibility))
+                })
+                .collect()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{Semantics, Type, TypeRef};
+    use crate::parser::c::field;
+    use crate::parser::c::preprocessor::Macros;
+    use crate::parser::c::visibility::Visibility;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn extern_field() -> Result<()> {
+        let macros = Macros::default();
+        let fields = field::parser(&TEST_CONFIG, &macros)
+            .parse("extern const int a, *b;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 2);
+        let (a, visibility) = &fields[0];
+        assert_eq!(a.name, "a");
+        assert_eq!(a.ty, TypeRef::new(Type::I32, Semantics::Value));
+        assert!(a.is_static);
+        assert_eq!(*visibility, Visibility::Public);
+        assert_eq!(fields[1].0.ty, TypeRef::new(Type::I32, Semantics::Ref));
+        Ok(())
+    }
+
+    #[test]
+    fn initializers() -> Result<()> {
+        let macros = Macros::default();
+        let fields = field::parser(&TEST_CONFIG, &macros)
+            .parse("static int a = 1 + 2, b[2] = { 1, 2 };")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].1, Visibility::Private);
+        Ok(())
+    }
+}
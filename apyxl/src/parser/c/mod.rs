@@ -0,0 +1,659 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use chumsky::prelude::*;
+use log::debug;
+
+use crate::model::{
+    Api, Chunk, Dto, EntityId, Field, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+};
+use crate::parser::c::namespace::Item;
+use crate::parser::{error, Config};
+use crate::{model, Input, Parser as ApyxlParser};
+
+mod comment;
+mod dto;
+mod en;
+mod expr_block;
+mod field;
+mod namespace;
+mod preprocessor;
+mod rpc;
+mod ty;
+mod ty_alias;
+mod visibility;
+
+/// Parses a subset of C header declarations: structs, enums, typedefs, function prototypes, and
+/// global variables.
+///
+/// Each chunk is a file whose relative file path (without extension) determines its namespace.
+/// Types referenced from other chunks via `#include "path"` (directly or transitively) are
+/// qualified with the namespace of the chunk that declares them.
+#[derive(Default)]
+pub struct C {}
+
+struct ParsedChunk<'a> {
+    chunk: &'a Chunk,
+    api: Api<'a>,
+    includes: Vec<&'a str>,
+    declared: Vec<&'a str>,
+}
+
+impl ApyxlParser for C {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let chunks = input.chunks();
+
+        // Macros are collected from all chunks up front since they're typically defined in a
+        // different file than where they're used.
+        let mut macros = HashSet::new();
+        for (_, data) in &chunks {
+            preprocessor::collect_macro_names(data, &mut macros);
+        }
+        let macros = Rc::new(macros);
+
+        let mut parsed_chunks = Vec::new();
+        for (chunk, data) in chunks {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let items = namespace::items(config, &macros)
+                .padded()
+                .then_ignore(end())
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let mut parsed = ParsedChunk {
+                chunk,
+                api: Api {
+                    name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                    children: vec![],
+                    attributes: Default::default(),
+                    is_virtual: false,
+                    implements: vec![],
+                },
+                includes: vec![],
+                declared: vec![],
+            };
+            for item in items {
+                match item {
+                    Item::Child(child, _) => parsed.api.children.push(*child),
+                    Item::Include(path) => parsed.includes.push(path),
+                    Item::Declared(name) => parsed.declared.push(name),
+                }
+            }
+            parsed_chunks.push(parsed);
+        }
+
+        let all_includes = resolve_all_includes(&parsed_chunks);
+        add_opaque_dtos(&mut parsed_chunks, &all_includes);
+        // Resolve again so that opaque dtos can be referenced from other chunks.
+        let all_includes = resolve_all_includes(&parsed_chunks);
+
+        for (mut parsed, includes) in parsed_chunks.into_iter().zip(all_includes) {
+            let chunk_path = parsed.chunk.relative_file_path.as_deref();
+
+            debug!("applying includes to chunk {:?}...", chunk_path);
+            apply_includes(&mut parsed.api, &includes)?;
+
+            if let Some(file_path) = chunk_path {
+                for component in path_to_entity_id(file_path).component_names() {
+                    builder.enter_namespace(component)
+                }
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(parsed.api, parsed.chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Structs that are declared (e.g. `struct Name;`) but not defined in the chunk or any chunk
+/// it includes are opaque, so they are added as empty [Dto]s to the declaring chunk.
+fn add_opaque_dtos(parsed_chunks: &mut [ParsedChunk], all_includes: &[ResolvedIncludes]) {
+    for (parsed, includes) in parsed_chunks.iter_mut().zip(all_includes) {
+        let mut defined = parsed
+            .api
+            .children
+            .iter()
+            .map(|child| child.name().to_string())
+            .collect::<HashSet<_>>();
+        for name in &parsed.declared {
+            if !includes.contains_key(*name) && defined.insert(name.to_string()) {
+                parsed.api.add_dto(Dto {
+                    name,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+}
+
+/// Map of names to the fully qualified [EntityId] of the entity they reference in an included
+/// chunk.
+type ResolvedIncludes = HashMap<String, EntityId>;
+
+fn resolve_all_includes(parsed_chunks: &[ParsedChunk]) -> Vec<ResolvedIncludes> {
+    parsed_chunks
+        .iter()
+        .map(|parsed| resolve_includes(parsed, parsed_chunks))
+        .collect()
+}
+
+/// Walks all includes transitively starting from `parsed`. Names declared in `parsed` itself take
+/// precedence, followed by the nearest include. Includes that don't match any chunk (e.g. system
+/// headers) are skipped.
+fn resolve_includes(parsed: &ParsedChunk, parsed_chunks: &[ParsedChunk]) -> ResolvedIncludes {
+    let chunk_paths = parsed_chunks
+        .iter()
+        .map(|parsed| parsed.chunk.relative_file_path.as_deref().map(normalize))
+        .collect::<Vec<_>>();
+    let local_names = parsed
+        .api
+        .children
+        .iter()
+        .map(NamespaceChild::name)
+        .collect::<HashSet<_>>();
+
+    let mut resolved = ResolvedIncludes::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([parsed]);
+    while let Some(including) = queue.pop_front() {
+        let including_path = including.chunk.relative_file_path.as_deref();
+        for include in &including.includes {
+            let index =
+                match include_candidates(including_path, include)
+                    .iter()
+                    .find_map(|candidate| {
+                        chunk_paths
+                            .iter()
+                            .position(|path| path.as_ref() == Some(candidate))
+                    }) {
+                    None => continue,
+                    Some(index) => index,
+                };
+            if !visited.insert(index) {
+                continue;
+            }
+            let included = &parsed_chunks[index];
+            let namespace_id = included
+                .chunk
+                .relative_file_path
+                .as_deref()
+                .map(path_to_entity_id)
+                .unwrap_or_default();
+            for child in &included.api.children {
+                let name = child.name();
+                if !local_names.contains(name) {
+                    resolved
+                        .entry(name.to_string())
+                        .or_insert_with(|| namespace_id.child_unqualified(name));
+                }
+            }
+            queue.push_back(included);
+        }
+    }
+    resolved
+}
+
+/// Includes are relative to the including file's directory, falling back to the root.
+fn include_candidates(including_path: Option<&Path>, include: &str) -> Vec<PathBuf> {
+    let mut candidates = vec![];
+    if let Some(dir) = including_path.and_then(Path::parent) {
+        candidates.push(normalize(&dir.join(include)));
+    }
+    candidates.push(normalize(Path::new(include)));
+    candidates
+}
+
+/// Resolves `.` and `..` components without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::Normal(name) => normalized.push(name),
+            _ => {}
+        }
+    }
+    normalized
+}
+
+fn path_to_entity_id(path: &Path) -> EntityId {
+    EntityId::new_unqualified_vec(
+        normalize(&path.with_extension(""))
+            .iter()
+            .map(|component| component.to_string_lossy()),
+    )
+}
+
+fn apply_includes(api: &mut Api, includes: &ResolvedIncludes) -> Result<()> {
+    if includes.is_empty() {
+        return Ok(());
+    }
+
+    let apply_include_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_includes_to_type(&mut param.ty, includes)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_includes_to_type(return_ty, includes)?;
+        }
+        Ok(())
+    };
+    let apply_include_to_field =
+        |field: &mut Field| -> Result<()> { apply_includes_to_type(&mut field.ty, includes) };
+
+    for dto in api.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_include_to_field(field)?;
+        }
+    }
+
+    for rpc in api.rpcs_mut() {
+        apply_include_to_rpc(rpc)?;
+    }
+
+    for field in api.fields_mut() {
+        apply_include_to_field(field)?;
+    }
+
+    for alias in api.ty_aliases_mut() {
+        apply_includes_to_type(&mut alias.target_ty, includes)?;
+    }
+
+    // note: enums have no type refs, and C has no nested namespaces.
+
+    Ok(())
+}
+
+fn apply_includes_to_type(ty: &mut TypeRef, includes: &ResolvedIncludes) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_includes_to_type(ty, includes)?,
+        Type::Set(ty) => apply_includes_to_type(ty, includes)?,
+        Type::FixedArray { ty, .. } => apply_includes_to_type(ty, includes)?,
+        Type::Optional(ty) => apply_includes_to_type(ty, includes)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_includes_to_type(&mut element.ty, includes)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_includes_to_type(key, includes)?;
+            apply_includes_to_type(value, includes)?;
+        }
+        // C has no generics.
+        Type::Generic { .. } => {}
+        Type::Api(id) => {
+            let included = id
+                .component_names()
+                .next()
+                .filter(|_| id.len() == 1)
+                .and_then(|name| includes.get(name));
+            if let Some(included) = included {
+                *id = included.clone();
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_includes_to_type(param, includes)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_includes_to_type(return_ty, includes)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{Builder, Comment, UNDEFINED_NAMESPACE};
+    use crate::parser::c::C;
+    use crate::parser::Config;
+    use crate::test_util::executor::TEST_CONFIG;
+    use crate::{input, Parser};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        #ifndef HEADER_H
+        #define HEADER_H
+        #define API __attribute__((visibility("default")))
+
+        // dto comment
+        typedef struct dto {
+            int a;
+        } dto;
+        enum en { A, B };
+        typedef unsigned int alias;
+        /** rpc comment */
+        API void rpc(const dto *value);
+        extern const char *field;
+        struct opaque;
+
+        #endif
+        "#,
+        );
+        let mut builder = Builder::default();
+        C::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("dto").is_some(), "dto");
+        assert!(model.api().en("en").is_some(), "en");
+        assert!(model.api().ty_alias("alias").is_some(), "alias");
+        assert!(model.api().rpc("rpc").is_some(), "rpc");
+        assert!(model.api().field("field").is_some(), "field");
+        assert!(model.api().dto("opaque").is_some(), "opaque");
+        assert_eq!(
+            model.api().dto("dto").unwrap().attributes.comments,
+            vec![Comment::unowned(&["dto comment"])],
+        );
+        assert_eq!(
+            model.api().rpc("rpc").unwrap().attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn conditionals() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        #ifdef _WIN32
+        struct first {};
+        #elif defined(__APPLE__)
+        struct elif_branch {};
+        #else
+        #  if 1
+        struct nested {};
+        #  endif
+        struct else_branch {};
+        #endif
+        #if 0
+        struct disabled {};
+        #else
+        struct enabled {};
+        #endif
+        "#,
+        );
+        let mut builder = Builder::default();
+        C::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("first").is_some());
+        assert!(model.api().dto("elif_branch").is_none());
+        assert!(model.api().dto("nested").is_none());
+        assert!(model.api().dto("else_branch").is_none());
+        assert!(model.api().dto("disabled").is_none());
+        assert!(model.api().dto("enabled").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn void_pointer_callback() -> Result<()> {
+        let mut input = input::Buffer::new("typedef void (*callback_t)(int, void*);");
+        let mut builder = Builder::default();
+        C::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        assert!(builder.build().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        int rpc(void);
+        static int ignored_rpc(void) { return 0; }
+        extern int field;
+        static int ignored_field;
+        "#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        C::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().rpc("rpc").is_some());
+        assert!(model.api().field("field").is_some());
+        assert!(model.api().rpc("ignored_rpc").is_none());
+        assert!(model.api().field("ignored_field").is_none());
+        Ok(())
+    }
+
+    mod file_path_to_namespace {
+        use anyhow::Result;
+
+        use crate::model::{Builder, Chunk, EntityId};
+        use crate::parser::c::C;
+        use crate::test_util::executor::TEST_CONFIG;
+        use crate::{input, Parser};
+
+        #[test]
+        fn header() -> Result<()> {
+            run_test("a/b/c.h", "a.b.c.Dto")
+        }
+
+        #[test]
+        fn source() -> Result<()> {
+            run_test("a/b.c", "a.b.Dto")
+        }
+
+        fn run_test(path: &str, expected_dto_id: &str) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(Chunk::with_relative_file_path(path), "struct Dto {};");
+            let mut builder = Builder::default();
+            C::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            assert!(model
+                .api()
+                .find_dto(&EntityId::new_unqualified(expected_dto_id))
+                .is_some());
+            Ok(())
+        }
+    }
+
+    mod includes {
+        use anyhow::Result;
+
+        use crate::model::{Builder, Chunk, EntityId, Model};
+        use crate::parser::c::C;
+        use crate::test_util::executor::TEST_CONFIG;
+        use crate::{input, Parser};
+
+        #[test]
+        fn relative() -> Result<()> {
+            let dep = "struct Dep {};";
+            let main = r#"
+            #include "dep.h"
+            struct Main { struct Dep dep; };
+            "#;
+            run_dto_chunked_test(
+                &[("a/dep.h", dep), ("a/main.h", main)],
+                "a.main.Main",
+                "ns:a.ns:dep.d:Dep",
+            )
+        }
+
+        #[test]
+        fn root() -> Result<()> {
+            let dep = "typedef struct { int a; } Dep;";
+            let main = r#"
+            #include "a/dep.h"
+            struct Main { Dep dep; };
+            "#;
+            run_dto_chunked_test(
+                &[("a/dep.h", dep), ("b/main.h", main)],
+                "b.main.Main",
+                "ns:a.ns:dep.d:Dep",
+            )
+        }
+
+        #[test]
+        fn parent_dir() -> Result<()> {
+            let dep = "enum Dep { A };";
+            let main = r#"
+            #include "../dep.h"
+            struct Main { enum Dep dep; };
+            "#;
+            run_dto_chunked_test(
+                &[("dep.h", dep), ("a/main.h", main)],
+                "a.main.Main",
+                "ns:dep.e:Dep",
+            )
+        }
+
+        #[test]
+        fn transitive() -> Result<()> {
+            let dep = "struct Dep {};";
+            let mid = r#"#include "dep.h""#;
+            let main = r#"
+            #include "mid.h"
+            struct Main { struct Dep dep; };
+            "#;
+            run_dto_chunked_test(
+                &[("dep.h", dep), ("mid.h", mid), ("main.h", main)],
+                "main.Main",
+                "ns:dep.d:Dep",
+            )
+        }
+
+        #[test]
+        fn local_precedence() -> Result<()> {
+            let dep = "struct Dep {};";
+            let main = r#"
+            #include "dep.h"
+            struct Dep {};
+            struct Main { struct Dep dep; };
+            "#;
+            run_dto_chunked_test(
+                &[("dep.h", dep), ("main.h", main)],
+                "main.Main",
+                "ns:main.d:Dep",
+            )
+        }
+
+        #[test]
+        fn opaque_defined_in_include() -> Result<()> {
+            let imp = "struct Impl { int a; };";
+            let handle = r#"
+            #include "impl.h"
+            typedef struct Impl *Handle;
+            "#;
+            run_chunked_test(&[("impl.h", imp), ("handle.h", handle)], |model| {
+                assert!(model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("handle.Impl"))
+                    .is_none());
+                let alias = model
+                    .api()
+                    .find_ty_alias(&EntityId::new_unqualified("handle.Handle"))
+                    .unwrap();
+                assert_eq!(
+                    alias.target_ty.value.api(),
+                    Some(&EntityId::try_from("ns:impl.d:Impl")?)
+                );
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn opaque() -> Result<()> {
+            let handle = "typedef struct Impl *Handle;";
+            let main = r#"
+            #include "handle.h"
+            struct Main { Handle handle; };
+            "#;
+            run_dto_chunked_test(
+                &[("handle.h", handle), ("main.h", main)],
+                "main.Main",
+                "ns:handle.a:Handle",
+            )
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            C::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+    }
+}
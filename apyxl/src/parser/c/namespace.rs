@@ -0,0 +1,190 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::model::NamespaceChild;
+use crate::parser::c::preprocessor::{Directive, Macros};
+use crate::parser::c::visibility::Visibility;
+use crate::parser::c::{comment, dto, en, field, preprocessor, rpc, ty_alias};
+use crate::parser::error::Error;
+use crate::parser::{util, Config};
+
+/// A top level declaration within a C file.
+#[derive(Debug, PartialEq)]
+pub enum Item<'a> {
+    Child(Box<NamespaceChild<'a>>, Visibility),
+    /// Relative path from `#include "path"`.
+    Include(&'a str),
+    /// A struct that has been declared, e.g. `struct Name;`, but is not necessarily defined.
+    Declared(&'a str),
+}
+
+/// C has no namespaces, so this parses all items within a file. `extern "C" { ... }` blocks are
+/// flattened into the surrounding items.
+pub fn items<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Vec<Item<'a>>, Error<'a>> {
+    let macros = macros.clone();
+    recursive(move |nested| {
+        let extern_c = util::keyword_ex("extern")
+            .padded()
+            .then(just("\"C\"").padded())
+            .ignore_then(nested.delimited_by(just('{').padded(), just('}').padded()));
+        choice((
+            preprocessor::directive().map(|directive| match directive {
+                Directive::Include(path) => vec![Item::Include(path)],
+                Directive::Skipped => vec![],
+            }),
+            extern_c,
+            ty_alias::parser(config, &macros),
+            dto::parser(config, &macros).map(|dto| {
+                vec![Item::Child(
+                    Box::new(NamespaceChild::Dto(dto)),
+                    Visibility::Public,
+                )]
+            }),
+            dto::declaration(&macros).map(|name| vec![Item::Declared(name)]),
+            en::parser().map(|en| {
+                vec![Item::Child(
+                    Box::new(NamespaceChild::Enum(en)),
+                    Visibility::Public,
+                )]
+            }),
+            rpc::parser(config, &macros).map(|(rpc, visibility)| {
+                vec![Item::Child(Box::new(NamespaceChild::Rpc(rpc)), visibility)]
+            }),
+            field::parser(config, &macros).map(|fields| {
+                fields
+                    .into_iter()
+                    .map(|(field, visibility)| {
+                        Item::Child(Box::new(NamespaceChild::Field(field)), visibility)
+                    })
+                    .collect_vec()
+            }),
+            // Catch comments after all items.
+            comment::single().padded().map(|_| vec![]),
+        ))
+        .recover_with(skip_then_retry_until(
+            any().ignored(),
+            choice((just('}').ignored(), end())),
+        ))
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(|items| {
+            items
+                .into_iter()
+                .flatten()
+                .filter(|item| match item {
+                    Item::Child(_, visibility) => visibility.is_visible(config),
+                    _ => true,
+                })
+                .collect_vec()
+        })
+        .boxed()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::NamespaceChild;
+    use crate::parser::c::namespace;
+    use crate::parser::c::namespace::Item;
+    use crate::parser::c::preprocessor::Macros;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn items() -> Result<()> {
+        let macros = Macros::default();
+        let items = namespace::items(&TEST_CONFIG, &macros)
+            .parse(
+                r#"
+                #ifndef HEADER_H
+                #define HEADER_H
+                #include <stdint.h>
+                #include "other.h"
+
+                struct Opaque;
+                typedef struct { int a; } Dto;
+                enum En { A, B };
+                typedef int alias;
+                int rpc(int a);
+                extern int field;
+
+                #endif
+                // trailing comment
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(items.len(), 7);
+        assert_eq!(items[0], Item::Include("other.h"));
+        assert_eq!(items[1], Item::Declared("Opaque"));
+        assert!(
+            matches!(items[2], Item::Child(ref child, _) if matches!(**child, NamespaceChild::Dto(_)))
+        );
+        assert!(
+            matches!(items[3], Item::Child(ref child, _) if matches!(**child, NamespaceChild::Enum(_)))
+        );
+        assert!(
+            matches!(items[4], Item::Child(ref child, _) if matches!(**child, NamespaceChild::TypeAlias(_)))
+        );
+        assert!(
+            matches!(items[5], Item::Child(ref child, _) if matches!(**child, NamespaceChild::Rpc(_)))
+        );
+        assert!(
+            matches!(items[6], Item::Child(ref child, _) if matches!(**child, NamespaceChild::Field(_)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn extern_c() -> Result<()> {
+        let macros = Macros::default();
+        let items = namespace::items(&TEST_CONFIG, &macros)
+            .parse(
+                r#"
+                #ifdef __cplusplus
+                extern "C" {
+                #endif
+
+                int rpc(int a);
+
+                #ifdef __cplusplus
+                }
+                #endif
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(items.len(), 1);
+        assert!(
+            matches!(items[0], Item::Child(ref child, _) if matches!(**child, NamespaceChild::Rpc(_)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let macros = Macros::default();
+        let items = namespace::items(&TEST_PUB_ONLY_CONFIG, &macros)
+            .parse(
+                r#"
+                static int private_rpc(void) { return 0; }
+                static int private_field;
+                int public_rpc(void);
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(items.len(), 1);
+        match &items[0] {
+            Item::Child(child, _) => assert_eq!(child.name(), "public_rpc"),
+            item => panic!("expected rpc, found {:?}", item),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use chumsky::prelude::*;
+
+use crate::parser::error::Error;
+use crate::parser::util;
+
+/// Names of all macros `#define`d within the parsed chunks.
+pub type Macros = Rc<HashSet<String>>;
+
+/// Compiler extensions that are skipped in the same way as [Macros].
+const EXTENSIONS: &[&str] = &["__attribute__", "__declspec", "__cdecl", "__stdcall"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Directive<'a> {
+    /// `#include "path"`. System includes (`#include <path>`) are skipped.
+    Include(&'a str),
+    /// Everything else, e.g. macro definitions, conditionals, and pragmas. These can't be
+    /// evaluated so they are skipped. Only the first branch of a conditional is parsed, unless it
+    /// is `#if 0`, in which case the next branch is parsed instead.
+    Skipped,
+}
+
+const IF: &[&str] = &["if", "ifdef", "ifndef"];
+const ELSE: &[&str] = &["else", "elif", "elifdef", "elifndef"];
+const ENDIF: &[&str] = &["endif"];
+
+pub fn directive<'a>() -> impl Parser<'a, &'a str, Directive<'a>, Error<'a>> {
+    let include = util::keyword_ex("include")
+        .then(text::inline_whitespace())
+        .ignore_then(
+            none_of('"')
+                .repeated()
+                .slice()
+                .delimited_by(just('"'), just('"')),
+        )
+        .then_ignore(rest_of_line())
+        .map(Directive::Include);
+    // The first branch was parsed, so the rest of the conditional is skipped.
+    let other_branches = keyword(ELSE)
+        .then(rest_of_line())
+        .then(text::newline().or(end()))
+        .then(skipped_lines())
+        .then(conditional_line(ELSE).then(skipped_lines()).repeated())
+        .then(conditional_line(ENDIF))
+        .map(|_| Directive::Skipped);
+    // The first branch is never compiled, so parsing continues with the next branch, if any.
+    let if_zero = util::keyword_ex("if")
+        .then(text::inline_whitespace())
+        .then(just('0'))
+        .then(any().filter(char::is_ascii_alphanumeric).not())
+        .then(rest_of_line())
+        .then(text::newline().or(end()))
+        .then(skipped_lines())
+        .then(conditional_line(ELSE).or(conditional_line(ENDIF)))
+        .map(|_| Directive::Skipped);
+    just('#')
+        .then(text::inline_whitespace())
+        .ignore_then(choice((
+            include,
+            other_branches,
+            if_zero,
+            rest_of_line().map(|_| Directive::Skipped),
+        )))
+        .padded()
+}
+
+fn rest_of_line<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    let continuation = just('\\')
+        .then(just('\r').or_not())
+        .then(just('\n'))
+        .ignored();
+    choice((continuation, none_of('\n').ignored()))
+        .repeated()
+        .ignored()
+}
+
+/// Directive name that is one of `names`, e.g. `ifdef`.
+fn keyword<'a>(names: &'static [&'static str]) -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    text::ident()
+        .filter(move |name: &&str| names.contains(name))
+        .ignored()
+}
+
+/// A whole line with a directive that is one of `names`.
+fn conditional_line<'a>(
+    names: &'static [&'static str],
+) -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    text::inline_whitespace()
+        .then(just('#'))
+        .then(text::inline_whitespace())
+        .then(keyword(names))
+        .then(rest_of_line())
+        .then(text::newline().or(end()))
+        .ignored()
+}
+
+/// Lines up to the next `#else`, `#elif`, or `#endif` that isn't part of a nested conditional.
+fn skipped_lines<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    recursive(|skipped_lines| {
+        let nested = conditional_line(IF)
+            .then(skipped_lines.clone())
+            .then(conditional_line(ELSE).then(skipped_lines).repeated())
+            .then(conditional_line(ENDIF))
+            .ignored();
+        let line = conditional_line(IF)
+            .or(conditional_line(ELSE))
+            .or(conditional_line(ENDIF))
+            .not()
+            .then(none_of('\n').repeated())
+            .then(text::newline())
+            .ignored();
+        nested.or(line).repeated()
+    })
+}
+
+/// Skips invocations of known [Macros] and compiler extensions that commonly decorate
+/// declarations, e.g. `API_EXPORT int rpc(void) __attribute__((deprecated));`.
+pub fn skip_macros<'a>(macros: &Macros) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let macros = macros.clone();
+    let args = recursive(|args| {
+        choice((none_of("()").ignored(), args))
+            .repeated()
+            .delimited_by(just('('), just(')'))
+            .ignored()
+    });
+    text::ident()
+        .try_map(move |name: &str, span| {
+            if macros.contains(name) || EXTENSIONS.contains(&name) {
+                Ok(())
+            } else {
+                Err(Rich::custom(span, format!("{} is not a macro", name)))
+            }
+        })
+        .then(args.padded().or_not())
+        .padded()
+        .repeated()
+        .ignored()
+}
+
+/// Collects the names of all macros defined via `#define` in `data`.
+pub fn collect_macro_names(data: &str, macros: &mut HashSet<String>) {
+    for line in data.lines() {
+        let directive = match line.trim_start().strip_prefix('#') {
+            None => continue,
+            Some(directive) => directive.trim_start(),
+        };
+        let definition = match directive.strip_prefix("define") {
+            Some(definition) if definition.starts_with(char::is_whitespace) => definition,
+            _ => continue,
+        };
+        let name = definition
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect::<String>();
+        if !name.is_empty() {
+            macros.insert(name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::rc::Rc;
+
+    use anyhow::Result;
+    use chumsky::prelude::any;
+    use chumsky::Parser;
+
+    use crate::parser::c::preprocessor;
+    use crate::parser::c::preprocessor::Directive;
+    use crate::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn include() -> Result<()> {
+        let directive = preprocessor::directive()
+            .parse(r#"#include "a/b.h""#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(directive, Directive::Include("a/b.h"));
+        Ok(())
+    }
+
+    #[test]
+    fn system_include() -> Result<()> {
+        let directive = preprocessor::directive()
+            .parse("#include <stdint.h>")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(directive, Directive::Skipped);
+        Ok(())
+    }
+
+    #[test]
+    fn multiline_define() -> Result<()> {
+        let directive = preprocessor::directive()
+            .parse("# define MAX(a, b) \\\n ((a) > (b) ? (a) : (b))\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(directive, Directive::Skipped);
+        Ok(())
+    }
+
+    #[test]
+    fn else_branch() -> Result<()> {
+        let (directive, rest) = preprocessor::directive()
+            .then(any().repeated().slice())
+            .parse("#else\nint a;\n#if X\n#else\n#endif\n#endif\nint b;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(directive, Directive::Skipped);
+        assert_eq!(rest, "int b;");
+        Ok(())
+    }
+
+    #[test]
+    fn if_zero() -> Result<()> {
+        let (directive, rest) = preprocessor::directive()
+            .then(any().repeated().slice())
+            .parse("#if 0\nint a;\n#elif X\nint b;\n#endif")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(directive, Directive::Skipped);
+        assert_eq!(rest, "int b;\n#endif");
+        Ok(())
+    }
+
+    #[test]
+    fn collect_macro_names() {
+        let mut macros = HashSet::new();
+        preprocessor::collect_macro_names(
+            r#"
+            #ifndef HEADER_H
+            #define HEADER_H
+            #  define API __attribute__((visibility("default")))
+            #define DEPRECATED(msg)
+            #defined_not
+            #endif
+            "#,
+            &mut macros,
+        );
+        assert_eq!(
+            macros,
+            HashSet::from([
+                "HEADER_H".to_string(),
+                "API".to_string(),
+                "DEPRECATED".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn skip_macros() -> Result<()> {
+        let macros = Rc::new(HashSet::from(["API".to_string(), "DEPRECATED".to_string()]));
+        preprocessor::skip_macros(&macros)
+            .parse(r#"API DEPRECATED("use (other)") __attribute__((unused))"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        Ok(())
+    }
+
+    #[test]
+    fn skip_macros_ignores_unknown() {
+        let macros = Rc::new(HashSet::from(["API".to_string()]));
+        let result = preprocessor::skip_macros(&macros)
+            .parse("NOT_A_MACRO")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::model::{Attributes, Field, Rpc, RpcKind};
+use crate::parser::c::preprocessor::{skip_macros, Macros};
+use crate::parser::c::visibility::Visibility;
+use crate::parser::c::{comment, expr_block, ty, visibility};
+use crate::parser::error::Error;
+use crate::parser::Config;
+
+/// Function prototype, e.g. `int rpc(const char *name);`, or a function definition whose body is
+/// skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, (Rpc<'a>, Visibility), Error<'a>> {
+    let end = choice((
+        just(';').padded().ignored(),
+        expr_block::parser().padded().ignored(),
+    ));
+    comment::multi()
+        .padded()
+        .then_ignore(skip_macros(macros))
+        .then(visibility::parser())
+        .then_ignore(skip_macros(macros))
+        .then(ty::base(config))
+        .then(ty::pointers())
+        .then_ignore(skip_macros(macros))
+        .then(text::ident().padded())
+        .then(ty::params(param(config)))
+        .then_ignore(skip_macros(macros))
+        .then_ignore(end)
+        .try_map(
+            move |(((((comments, visibility), base), pointers), name), params), span| {
+                Ok((
+                    Rpc {
+                        name: Cow::Borrowed(name),
+                        type_params: vec![],
+                        params,
+                        return_type: ty::pointer_ty(config, base, pointers, span)?,
+                        error_type: None,
+                        attributes: Attributes {
+                            comments,
+                            ..Default::default()
+                        },
+                        is_static: true,
+                        kind: RpcKind::default(),
+                    },
+                    visibility,
+                ))
+            },
+        )
+}
+
+fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(ty::base(config))
+        .then(ty::declarator(config))
+        .try_map(move |((comments, base), declarator), span| {
+            let name = declarator
+                .name
+                .ok_or_else(|| Rich::custom(span, "unnamed params are not supported"))?;
+            let ty = declarator
+                .ty(config, base, span)?
+                .ok_or_else(|| Rich::custom(span, "params cannot be `void`"))?;
+            Ok(Field {
+                name,
+                ty,
+                attributes: Attributes {
+                    comments,
+                    ..Default::default()
+                },
+                is_static: false,
+                value: None,
+                default: None,
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::rc::Rc;
+
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use crate::parser::c::preprocessor::Macros;
+    use crate::parser::c::rpc;
+    use crate::parser::c::visibility::Visibility;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn empty_fn() -> Result<()> {
+        let macros = Macros::default();
+        let (rpc, visibility) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("void rpc_name(void);")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert!(rpc.is_static);
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let macros = Macros::default();
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("void rpc_name(int a, const struct Name *b, char c[8], void (*cb)(int));")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let params = rpc
+            .params
+            .iter()
+            .map(|param| (param.name, param.ty.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            vec![
+                ("a", TypeRef::new(Type::I32, Semantics::Value)),
+                (
+                    "b",
+                    TypeRef::new(Type::Api(EntityId::new_unqualified("Name")), Semantics::Ref)
+                ),
+                (
+                    "c",
+                    TypeRef::new_fixed_array(
+                        TypeRef::new(Type::I8, Semantics::Value),
+                        8,
+                        Semantics::Value
+                    )
+                ),
+                (
+                    "cb",
+                    TypeRef::new_function(
+                        [TypeRef::new(Type::I32, Semantics::Value)],
+                        None,
+                        Semantics::Value
+                    )
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn return_type() -> Result<()> {
+        let macros = Macros::default();
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("const char *rpc_name();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn static_is_private() -> Result<()> {
+        let macros = Macros::default();
+        let (_, visibility) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("static inline int rpc_name(int a) { return a + 1; }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn comments() -> Result<()> {
+        let macros = Macros::default();
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse(
+                r#"
+                // rpc comment
+                void rpc_name(
+                    // param comment
+                    int a
+                );
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        assert_eq!(
+            rpc.params[0].attributes.comments,
+            vec![Comment::unowned(&["param comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn macros() -> Result<()> {
+        let macros = Rc::new(HashSet::from(["API".to_string(), "CALL".to_string()]));
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("API int CALL rpc_name(int a) __attribute__((deprecated(\"x\")));")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        Ok(())
+    }
+
+    #[test]
+    fn unnamed_param() {
+        let macros = Macros::default();
+        let result = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("void rpc_name(int);")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn variadic() {
+        let macros = Macros::default();
+        let result = rpc::parser(&TEST_CONFIG, &macros)
+            .parse("void rpc_name(const char *fmt, ...);")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
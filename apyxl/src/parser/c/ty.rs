@@ -0,0 +1,535 @@
+use chumsky::prelude::*;
+
+use crate::model::{EntityId, Semantics, Type, TypeRef};
+use crate::parser::error::Error;
+use crate::parser::{util, Config};
+
+/// Keywords that can't be typedef names.
+const KEYWORDS: &[&str] = &[
+    "typedef", "static", "extern", "inline", "struct", "enum", "union", "const", "volatile",
+    "restrict", "void", "return", "sizeof",
+];
+
+/// Type specifiers that come before any pointers or declarator, e.g. `const unsigned int` or
+/// `struct Name`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseTy<'a> {
+    /// None if `void`.
+    pub ty: Option<Type>,
+    pub is_const: bool,
+    pub is_char: bool,
+    /// Name of the struct if referenced as `struct Name`.
+    pub struct_tag: Option<&'a str>,
+}
+
+/// The part of a declaration after the [BaseTy], e.g. `*name[4]` or `(*name)(int a)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declarator<'a> {
+    pub pointers: usize,
+    /// Names are optional in some contexts like function pointer params.
+    pub name: Option<&'a str>,
+    /// Length of each array dimension, outermost first. None if the length is not an integer
+    /// literal, e.g. `[]` or `[SIZE]`.
+    pub array_lens: Vec<Option<usize>>,
+    /// Some if this is a function pointer.
+    pub fn_params: Option<Vec<TypeRef>>,
+}
+
+pub fn base<'a>(config: &'a Config) -> impl Parser<'a, &'a str, BaseTy<'a>, Error<'a>> {
+    let tag = choice((util::keyword_ex("struct"), util::keyword_ex("enum")))
+        .then(text::whitespace().at_least(1))
+        .then(text::ident())
+        .map(|((kind, _), name)| (kind, name));
+    let name = text::ident().try_map(|name: &str, span| match name {
+        "union" => Err(Rich::custom(span, "unions are not supported")),
+        name if KEYWORDS.contains(&name) => Err(Rich::custom(
+            span,
+            format!("found unexpected keyword {}", name),
+        )),
+        name => Ok(name),
+    });
+
+    let ty = choice((
+        util::keyword_ex("void").map(|_| BaseTy::new(None)),
+        primitive(),
+        tag.map(|(kind, name)| BaseTy {
+            struct_tag: (kind == "struct").then_some(name),
+            ..BaseTy::new(Some(Type::Api(EntityId::new_unqualified(name))))
+        }),
+        user_ty(config).map(|ty| BaseTy::new(Some(Type::User(ty)))),
+        // Note that typedef names should come last because any ident matches.
+        name.map(|name| BaseTy::new(Some(Type::Api(EntityId::new_unqualified(name))))),
+    ));
+
+    qualifiers()
+        .then(ty)
+        .then(qualifiers())
+        .map(|((is_const_lhs, base), is_const_rhs)| BaseTy {
+            is_const: is_const_lhs || is_const_rhs,
+            ..base
+        })
+}
+
+/// Zero or more `*`s, optionally with qualifiers that apply to the pointer itself e.g. `* const`.
+pub fn pointers<'a>() -> impl Parser<'a, &'a str, usize, Error<'a>> {
+    just('*').padded().then(qualifiers()).repeated().count()
+}
+
+pub fn declarator<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Declarator<'a>, Error<'a>> + Clone {
+    recursive(|declarator| {
+        let param = base(config).then(declarator).try_map(
+            move |(base, declarator): (BaseTy, Declarator), span| {
+                declarator
+                    .ty(config, base, span)?
+                    .ok_or_else(|| Rich::custom(span, "`void` is not a valid param type"))
+            },
+        );
+        let fn_ptr = just('*')
+            .padded()
+            .ignore_then(text::ident().or_not())
+            .delimited_by(just('(').padded(), just(')').padded())
+            .then(params(param));
+        let array = none_of("]")
+            .repeated()
+            .slice()
+            .delimited_by(just('['), just(']'))
+            .padded()
+            .map(|len: &str| {
+                len.trim()
+                    .trim_end_matches(['u', 'U', 'l', 'L'])
+                    .parse::<usize>()
+                    .ok()
+            })
+            .repeated()
+            .collect::<Vec<_>>();
+        pointers()
+            .then(
+                choice((
+                    fn_ptr.map(|(name, params)| (name, vec![], Some(params))),
+                    text::ident()
+                        .or_not()
+                        .then(array)
+                        .map(|(name, array_lens)| (name, array_lens, None)),
+                ))
+                .padded(),
+            )
+            .map(|(pointers, (name, array_lens, fn_params))| Declarator {
+                pointers,
+                name,
+                array_lens,
+                fn_params,
+            })
+            .boxed()
+    })
+}
+
+/// Parenthesized list of `param`s, where `(void)` and `()` are both considered empty.
+pub fn params<'a, T>(
+    param: impl Parser<'a, &'a str, T, Error<'a>>,
+) -> impl Parser<'a, &'a str, Vec<T>, Error<'a>> {
+    let void = util::keyword_ex("void")
+        .padded()
+        .then(just(')').rewind())
+        .map(|_| vec![]);
+    let variadic = just("...").padded().try_map(|_, span| {
+        Err::<(), _>(Rich::custom(span, "variadic functions are not supported"))
+    });
+    choice((
+        void,
+        param
+            .padded()
+            .separated_by(just(','))
+            .collect::<Vec<_>>()
+            .then_ignore(just(',').padded().then(variadic).or_not()),
+    ))
+    .delimited_by(just('(').padded(), just(')').padded())
+}
+
+impl<'a> BaseTy<'a> {
+    fn new(ty: Option<Type>) -> Self {
+        Self {
+            ty,
+            is_const: false,
+            is_char: false,
+            struct_tag: None,
+        }
+    }
+}
+
+impl<'a> Declarator<'a> {
+    /// Combine with `base` into the declared type. Returns None for `void`.
+    pub fn ty(
+        self,
+        config: &Config,
+        base: BaseTy<'a>,
+        span: SimpleSpan,
+    ) -> Result<Option<TypeRef>, Rich<'a, char>> {
+        let mut ty = pointer_ty(config, base, self.pointers, span)?;
+        if let Some(params) = self.fn_params {
+            ty = Some(TypeRef::new_function(params, ty, Semantics::Value));
+        }
+        // Arrays with literal lengths are fixed arrays. The innermost dimension is the last.
+        for len in self.array_lens.into_iter().rev() {
+            let element_ty = ty.ok_or_else(|| Rich::custom(span, "arrays of `void`"))?;
+            ty = Some(match len {
+                Some(len) => TypeRef::new_fixed_array(element_ty, len, Semantics::Value),
+                None => TypeRef::new_array(element_ty, Semantics::Value),
+            });
+        }
+        Ok(ty)
+    }
+}
+
+/// Pointers to `const` are [Semantics::Ref] and other pointers are [Semantics::Mut], except for
+/// `const char *` which is a [Type::String].
+///
+/// `void *` uses the [crate::parser::UserType] that parses `void` if there is one, otherwise it is
+/// an opaque [Type::User] named `void`.
+pub fn pointer_ty<'a>(
+    config: &Config,
+    base: BaseTy<'a>,
+    pointers: usize,
+    span: SimpleSpan,
+) -> Result<Option<TypeRef>, Rich<'a, char>> {
+    let semantics = if base.is_const {
+        Semantics::Ref
+    } else {
+        Semantics::Mut
+    };
+    match (base.ty, pointers) {
+        (None, 0) => Ok(None),
+        (Some(ty), 0) => Ok(Some(TypeRef::new(ty, Semantics::Value))),
+        (_, 1) if base.is_char && base.is_const => {
+            Ok(Some(TypeRef::new(Type::String, Semantics::Value)))
+        }
+        (Some(ty), 1) => Ok(Some(TypeRef::new(ty, semantics))),
+        (None, 1) => Ok(Some(TypeRef::new(void_ty(config), semantics))),
+        _ => Err(Rich::custom(span, "pointers to pointers are not supported")),
+    }
+}
+
+fn void_ty(config: &Config) -> Type {
+    match config.user_types.iter().find(|ty| ty.parse == "void") {
+        Some(ty) => Type::User(ty.name.clone()),
+        None => Type::User("void".to_string()),
+    }
+}
+
+/// Returns true if `const` is one of the qualifiers.
+fn qualifiers<'a>() -> impl Parser<'a, &'a str, bool, Error<'a>> {
+    choice((
+        util::keyword_ex("const"),
+        util::keyword_ex("volatile"),
+        util::keyword_ex("restrict"),
+        util::keyword_ex("__restrict"),
+    ))
+    .padded()
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|qualifiers| qualifiers.contains(&"const"))
+}
+
+/// Note that `long` is assumed to be 64 bits.
+fn primitive<'a>() -> impl Parser<'a, &'a str, BaseTy<'a>, Error<'a>> {
+    let fixed = choice((
+        util::keyword_ex("bool").map(|_| Type::Bool),
+        util::keyword_ex("_Bool").map(|_| Type::Bool),
+        util::keyword_ex("int8_t").map(|_| Type::I8),
+        util::keyword_ex("int16_t").map(|_| Type::I16),
+        util::keyword_ex("int32_t").map(|_| Type::I32),
+        util::keyword_ex("int64_t").map(|_| Type::I64),
+        util::keyword_ex("uint8_t").map(|_| Type::U8),
+        util::keyword_ex("uint16_t").map(|_| Type::U16),
+        util::keyword_ex("uint32_t").map(|_| Type::U32),
+        util::keyword_ex("uint64_t").map(|_| Type::U64),
+        util::keyword_ex("size_t").map(|_| Type::USIZE),
+        util::keyword_ex("uintptr_t").map(|_| Type::USIZE),
+    ))
+    .map(|ty| BaseTy::new(Some(ty)));
+
+    let multi_word = choice((
+        util::keyword_ex("unsigned"),
+        util::keyword_ex("signed"),
+        util::keyword_ex("short"),
+        util::keyword_ex("long"),
+        util::keyword_ex("int"),
+        util::keyword_ex("char"),
+        util::keyword_ex("float"),
+        util::keyword_ex("double"),
+    ))
+    .separated_by(text::whitespace().at_least(1))
+    .at_least(1)
+    .collect::<Vec<_>>()
+    .map(|words| {
+        let is_unsigned = words.contains(&"unsigned");
+        let is_char = words.contains(&"char");
+        let long_count = words.iter().filter(|word| **word == "long").count();
+        let ty = if is_char {
+            if is_unsigned {
+                Type::U8
+            } else {
+                Type::I8
+            }
+        } else if words.contains(&"float") {
+            Type::F32
+        } else if words.contains(&"double") {
+            if long_count > 0 {
+                Type::F128
+            } else {
+                Type::F64
+            }
+        } else if words.contains(&"short") {
+            if is_unsigned {
+                Type::U16
+            } else {
+                Type::I16
+            }
+        } else if long_count > 0 {
+            if is_unsigned {
+                Type::U64
+            } else {
+                Type::I64
+            }
+        } else if is_unsigned {
+            Type::U32
+        } else {
+            Type::I32
+        };
+        BaseTy {
+            is_char,
+            ..BaseTy::new(Some(ty))
+        }
+    });
+
+    choice((fixed, multi_word))
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::prelude::*;
+    use lazy_static::lazy_static;
+
+    use crate::model::{EntityId, Semantics, Type, TypeRef};
+    use crate::parser::c::ty;
+    use crate::parser::error::Error;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "void".to_string(),
+                name: "c_void".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(bool, "bool", value(Type::Bool));
+    test!(c_bool, "_Bool", value(Type::Bool));
+    test!(char, "char", value(Type::I8));
+    test!(signed_char, "signed char", value(Type::I8));
+    test!(unsigned_char, "unsigned char", value(Type::U8));
+    test!(short, "short", value(Type::I16));
+    test!(unsigned_short_int, "unsigned short int", value(Type::U16));
+    test!(int, "int", value(Type::I32));
+    test!(unsigned, "unsigned", value(Type::U32));
+    test!(long, "long", value(Type::I64));
+    test!(unsigned_long_long, "unsigned long long", value(Type::U64));
+    test!(float, "float", value(Type::F32));
+    test!(double, "double", value(Type::F64));
+    test!(long_double, "long double", value(Type::F128));
+    test!(int8_t, "int8_t", value(Type::I8));
+    test!(uint64_t, "uint64_t", value(Type::U64));
+    test!(size_t, "size_t", value(Type::USIZE));
+    test!(typedef_name, "name_t", value(api("name_t")));
+    test!(int_prefixed_typedef_name, "integer", value(api("integer")));
+    test!(struct_tag, "struct Name", value(api("Name")));
+    test!(enum_tag, "enum Name", value(api("Name")));
+    test!(pointer, "int *", TypeRef::new(Type::I32, Semantics::Mut));
+    test!(
+        const_pointer,
+        "const int*",
+        TypeRef::new(Type::I32, Semantics::Ref)
+    );
+    test!(
+        const_after_pointer,
+        "struct Name const *",
+        TypeRef::new(api("Name"), Semantics::Ref)
+    );
+    test!(const_char_pointer, "const char *", value(Type::String));
+    test!(char_const_pointer, "char const*", value(Type::String));
+    test!(
+        char_pointer,
+        "char *",
+        TypeRef::new(Type::I8, Semantics::Mut)
+    );
+    test!(
+        void_pointer,
+        "void *",
+        TypeRef::new(Type::User("c_void".to_string()), Semantics::Mut)
+    );
+
+    #[test]
+    fn pointer_to_pointer() {
+        let result = parser(&TY_TEST_CONFIG).parse("int **").into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn void() {
+        let result = parser(&TY_TEST_CONFIG).parse("void").into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn union() {
+        let result = parser(&TY_TEST_CONFIG).parse("union Name").into_result();
+        assert!(result.is_err());
+    }
+
+    mod declarator {
+        use anyhow::Result;
+        use chumsky::Parser;
+
+        use crate::model::{Semantics, Type, TypeRef};
+        use crate::parser::c::ty;
+        use crate::parser::c::ty::tests::{value, TY_TEST_CONFIG};
+        use crate::parser::test_util::wrap_test_err;
+
+        #[test]
+        fn name() -> Result<()> {
+            let ty = run_test("int", "name")?;
+            assert_eq!(ty, Some(value(Type::I32)));
+            Ok(())
+        }
+
+        #[test]
+        fn pointer() -> Result<()> {
+            let ty = run_test("int", "* name")?;
+            assert_eq!(ty, Some(TypeRef::new(Type::I32, Semantics::Mut)));
+            Ok(())
+        }
+
+        #[test]
+        fn fixed_array() -> Result<()> {
+            let ty = run_test("int", "name[4][SIZE]")?;
+            assert_eq!(
+                ty,
+                Some(TypeRef::new_fixed_array(
+                    value(Type::new_array(value(Type::I32))),
+                    4,
+                    Semantics::Value
+                ))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn fn_ptr() -> Result<()> {
+            let ty = run_test("void", "(*name)(int a, const char *, void *user_data)")?;
+            assert_eq!(
+                ty,
+                Some(value(Type::new_function(
+                    [
+                        value(Type::I32),
+                        value(Type::String),
+                        TypeRef::new(Type::User("c_void".to_string()), Semantics::Mut),
+                    ],
+                    None
+                )))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn fn_ptr_void_params() -> Result<()> {
+            let ty = run_test("int", "(*name)(void)")?;
+            assert_eq!(
+                ty,
+                Some(value(Type::new_function([], Some(value(Type::I32)))))
+            );
+            Ok(())
+        }
+
+        fn run_test(base: &'static str, declarator: &'static str) -> Result<Option<TypeRef>> {
+            let base = ty::base(&TY_TEST_CONFIG)
+                .parse(base)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            let declarator = ty::declarator(&TY_TEST_CONFIG)
+                .parse(declarator)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(declarator.name, Some("name"));
+            declarator
+                .ty(&TY_TEST_CONFIG, base, (0..0).into())
+                .map_err(|err| anyhow::anyhow!("{:?}", err))
+        }
+    }
+
+    /// A type name without a declarator e.g. `const char *`.
+    fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+        ty::base(config)
+            .then(ty::pointers())
+            .try_map(move |(base, pointers), span| {
+                ty::pointer_ty(config, base, pointers, span)?
+                    .ok_or_else(|| Rich::custom(span, "`void` is not a valid type here"))
+            })
+    }
+
+    fn run_test(data: &'static str, expected: TypeRef) -> Result<()> {
+        let ty = parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty, expected);
+        Ok(())
+    }
+
+    fn api(name: &str) -> Type {
+        Type::Api(EntityId::new_unqualified(name))
+    }
+
+    pub(super) fn value(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
@@ -0,0 +1,311 @@
+use chumsky::prelude::*;
+
+use crate::model::{Attributes, Comment, Dto, EntityId, Enum, NamespaceChild, Semantics, Type};
+use crate::model::{EnumKind, TypeAlias, TypeRef};
+use crate::parser::c::namespace::Item;
+use crate::parser::c::preprocessor::{skip_macros, Macros};
+use crate::parser::c::visibility::Visibility;
+use crate::parser::c::{comment, dto, en, ty};
+use crate::parser::error::Error;
+use crate::parser::{util, Config};
+
+/// All forms of `typedef`:
+/// - `typedef struct Tag { ... } Name;` is a [Dto] named `Name`.
+/// - `typedef enum Tag { ... } Name;` is an [Enum] named `Name`.
+/// - `typedef <type> <declarator>, ...;` e.g. `typedef int (*name)(int a);` are [TypeAlias]es.
+///
+/// If a struct or enum has both a tag and a name, the tag becomes an alias of the name so that it
+/// can still be referenced as e.g. `struct Tag`. Structs referenced by tag in the type of an alias
+/// are [Item::Declared] in case they are never defined, e.g. `typedef struct Tag *Handle;`.
+pub fn parser<'a>(
+    config: &'a Config,
+    macros: &Macros,
+) -> impl Parser<'a, &'a str, Vec<Item<'a>>, Error<'a>> {
+    let prefix = util::keyword_ex("typedef").then(text::whitespace().at_least(1));
+    let tag = text::ident().or_not().padded();
+    let struct_def = util::keyword_ex("struct")
+        .ignore_then(tag)
+        .then(dto::fields(config, macros))
+        .then_ignore(skip_macros(macros))
+        .then(text::ident().padded())
+        .map(|((tag, fields), name)| {
+            let dto = Dto {
+                name,
+                type_params: vec![],
+                fields,
+                rpcs: vec![],
+                extends: vec![],
+                implements: vec![],
+                attributes: Default::default(),
+                namespace: None,
+            };
+            (tag, NamespaceChild::Dto(dto), name)
+        });
+    let enum_def = util::keyword_ex("enum")
+        .ignore_then(tag)
+        .then(en::values())
+        .then(text::ident().padded())
+        .map(|((tag, values), name)| {
+            let en = Enum {
+                name,
+                values,
+                kind: EnumKind::Plain,
+                underlying_type: None,
+                attributes: Default::default(),
+            };
+            (tag, NamespaceChild::Enum(en), name)
+        });
+    let definition = choice((struct_def, enum_def)).map(|(tag, child, name)| {
+        let mut items = vec![Item::Child(Box::new(child), Visibility::Public)];
+        if let Some(tag) = tag.filter(|tag| *tag != name) {
+            items.push(Item::Child(
+                Box::new(NamespaceChild::TypeAlias(TypeAlias {
+                    name: tag,
+                    type_params: vec![],
+                    target_ty: TypeRef::new(
+                        Type::Api(EntityId::new_unqualified(name)),
+                        Semantics::Value,
+                    ),
+                    attributes: Default::default(),
+                })),
+                Visibility::Public,
+            ));
+        }
+        items
+    });
+    let aliases = ty::base(config)
+        .then(
+            ty::declarator(config)
+                .map_with_span(|declarator, span| (declarator, span))
+                .separated_by(just(',').padded())
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .try_map(move |(base, declarators), _| {
+            let mut items = vec![];
+            if let Some(tag) = base.struct_tag {
+                items.push(Item::Declared(tag));
+            }
+            for (declarator, span) in declarators {
+                let name = declarator
+                    .name
+                    .ok_or_else(|| Rich::custom(span, "typedef is missing a name"))?;
+                let is_self_alias = base.struct_tag == Some(name)
+                    && declarator.pointers == 0
+                    && declarator.array_lens.is_empty()
+                    && declarator.fn_params.is_none();
+                if is_self_alias {
+                    continue;
+                }
+                let target_ty = declarator
+                    .ty(config, base.clone(), span)?
+                    .ok_or_else(|| Rich::custom(span, "aliases of `void` are not supported"))?;
+                items.push(Item::Child(
+                    Box::new(NamespaceChild::TypeAlias(TypeAlias {
+                        name,
+                        type_params: vec![],
+                        target_ty,
+                        attributes: Default::default(),
+                    })),
+                    Visibility::Public,
+                ));
+            }
+            Ok(items)
+        });
+    comment::multi()
+        .padded()
+        .then_ignore(skip_macros(macros))
+        .then_ignore(prefix)
+        .then(choice((definition, aliases)))
+        .then_ignore(skip_macros(macros))
+        .then_ignore(just(';').padded())
+        .map(|(comments, items)| apply_comments(comments, items))
+}
+
+/// Comments belong to the first declared entity.
+fn apply_comments<'a>(comments: Vec<Comment<'a>>, mut items: Vec<Item<'a>>) -> Vec<Item<'a>> {
+    let child = items.iter_mut().find_map(|item| match item {
+        Item::Child(child, _) => Some(child.as_mut()),
+        _ => None,
+    });
+    let attributes: Option<&mut Attributes> = match child {
+        Some(NamespaceChild::Dto(dto)) => Some(&mut dto.attributes),
+        Some(NamespaceChild::Enum(en)) => Some(&mut en.attributes),
+        Some(NamespaceChild::TypeAlias(alias)) => Some(&mut alias.attributes),
+        _ => None,
+    };
+    if let Some(attributes) = attributes {
+        attributes.comments = comments;
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{Comment, EntityId, NamespaceChild, Semantics, Type, TypeRef};
+    use crate::parser::c::namespace::Item;
+    use crate::parser::c::preprocessor::Macros;
+    use crate::parser::c::ty_alias;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        let items = run_test("typedef unsigned int uint;")?;
+        assert_eq!(items.len(), 1);
+        assert_alias(&items[0], "uint", TypeRef::new(Type::U32, Semantics::Value));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_aliases() -> Result<()> {
+        let items = run_test("typedef int a, *b;")?;
+        assert_eq!(items.len(), 2);
+        assert_alias(&items[0], "a", TypeRef::new(Type::I32, Semantics::Value));
+        assert_alias(&items[1], "b", TypeRef::new(Type::I32, Semantics::Mut));
+        Ok(())
+    }
+
+    #[test]
+    fn fn_ptr() -> Result<()> {
+        let items = run_test("typedef int (*callback)(void *user_data, int);")?;
+        assert_eq!(items.len(), 1);
+        assert_alias(
+            &items[0],
+            "callback",
+            TypeRef::new_function(
+                [
+                    TypeRef::new(Type::User("void".to_string()), Semantics::Mut),
+                    TypeRef::new(Type::I32, Semantics::Value),
+                ],
+                Some(TypeRef::new(Type::I32, Semantics::Value)),
+                Semantics::Value,
+            ),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn struct_def() -> Result<()> {
+        let items = run_test("typedef struct { int a; } Name;")?;
+        assert_eq!(items.len(), 1);
+        match child(&items[0]) {
+            Some(NamespaceChild::Dto(dto)) => {
+                assert_eq!(dto.name, "Name");
+                assert_eq!(dto.fields.len(), 1);
+            }
+            item => panic!("expected dto, found {:?}", item),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn struct_def_with_tag() -> Result<()> {
+        let items = run_test("typedef struct Tag { struct Tag *next; } Name;")?;
+        assert_eq!(items.len(), 2);
+        assert_alias(
+            &items[1],
+            "Tag",
+            TypeRef::new(api("Name"), Semantics::Value),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn struct_def_with_same_tag() -> Result<()> {
+        let items = run_test("typedef struct Name { int a; } Name;")?;
+        assert_eq!(items.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn enum_def() -> Result<()> {
+        let items = run_test("typedef enum Tag { A = 1, B } Name;")?;
+        assert_eq!(items.len(), 2);
+        match child(&items[0]) {
+            Some(NamespaceChild::Enum(en)) => {
+                assert_eq!(en.name, "Name");
+                assert_eq!(en.values.len(), 2);
+            }
+            item => panic!("expected enum, found {:?}", item),
+        }
+        assert_alias(
+            &items[1],
+            "Tag",
+            TypeRef::new(api("Name"), Semantics::Value),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opaque_struct() -> Result<()> {
+        let items = run_test("typedef struct Name Name;")?;
+        assert_eq!(items, vec![Item::Declared("Name")]);
+        Ok(())
+    }
+
+    #[test]
+    fn opaque_handle() -> Result<()> {
+        let items = run_test("typedef struct Tag *Handle;")?;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0], Item::Declared("Tag"));
+        assert_alias(
+            &items[1],
+            "Handle",
+            TypeRef::new(api("Tag"), Semantics::Mut),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comments() -> Result<()> {
+        let items = run_test(
+            r#"
+            // alias comment
+            typedef int name;
+            "#,
+        )?;
+        match child(&items[0]) {
+            Some(NamespaceChild::TypeAlias(alias)) => {
+                assert_eq!(
+                    alias.attributes.comments,
+                    vec![Comment::unowned(&["alias comment"])]
+                );
+            }
+            item => panic!("expected alias, found {:?}", item),
+        }
+        Ok(())
+    }
+
+    fn run_test(data: &'static str) -> Result<Vec<Item<'static>>> {
+        let macros = Macros::default();
+        ty_alias::parser(&TEST_CONFIG, &macros)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)
+    }
+
+    fn assert_alias(item: &Item, name: &str, target_ty: TypeRef) {
+        match child(item) {
+            Some(NamespaceChild::TypeAlias(alias)) => {
+                assert_eq!(alias.name, name);
+                assert_eq!(alias.target_ty, target_ty);
+            }
+            item => panic!("expected alias, found {:?}", item),
+        }
+    }
+
+    fn child<'a, 'b>(item: &'b Item<'a>) -> Option<&'b NamespaceChild<'a>> {
+        match item {
+            Item::Child(child, _) => Some(child),
+            _ => None,
+        }
+    }
+
+    fn api(name: &str) -> Type {
+        Type::Api(EntityId::new_unqualified(name))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::parser::error::Error;
+use crate::parser::{util, Config};
+use chumsky::prelude::*;
+
+/// C has no visibility keywords. Declarations with internal linkage (i.e. `static`) are
+/// considered private, and everything else is public.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+}
+
+/// Storage class and function specifiers, e.g. `static inline` or `extern`.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Visibility, Error<'a>> {
+    choice((
+        util::keyword_ex("static"),
+        util::keyword_ex("extern"),
+        util::keyword_ex("inline"),
+    ))
+    .then_ignore(text::whitespace().at_least(1))
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|specifiers| {
+        if specifiers.contains(&"static") {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::c::visibility;
+    use crate::parser::c::visibility::Visibility;
+    use crate::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn public() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("extern ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("static inline ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+}
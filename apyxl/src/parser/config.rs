@@ -9,14 +9,30 @@ pub struct Config {
     /// See [UserType].
     #[serde(default)]
     pub user_types: Vec<UserType>,
-    
+
+    /// Names of [crate::model::well_known] types that parsers should not recognize, e.g.
+    /// `["decimal"]`. Their spellings are then parsed like any other type.
+    #[serde(default)]
+    pub disabled_well_known_types: Vec<UserTypeName>,
+
     /// If true, the parser will include private dtos, rpcs, fields, etc. in the API.
     #[serde(default)]
     pub enable_parse_private: bool,
 }
 
+impl Config {
+    /// `true` if parsers should recognize the [crate::model::well_known] type `name`.
+    pub fn is_well_known_type_enabled(&self, name: &str) -> bool {
+        !self.disabled_well_known_types.iter().any(|ty| ty == name)
+    }
+}
+
 /// When the `parse` string is seen by a [crate::Parser], it is mapped to a
 /// [crate::model::TypeRef::User] variant with the value `name`.
+///
+/// User types take precedence over [crate::model::well_known] types, so they can be used to
+/// remap a well-known spelling or to add spellings for a well-known type by using its name, e.g.
+/// `{ parse: "MyUuid", name: "uuid" }`.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserType {
     pub parse: String,
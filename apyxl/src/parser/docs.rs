@@ -0,0 +1,330 @@
+use std::borrow::Cow;
+
+use itertools::Itertools;
+
+use crate::model::{Comment, Docs, ParamDoc};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Section {
+    Text,
+    Params,
+    Returns,
+    Examples,
+    SeeAlso,
+}
+
+/// Removes the first comment in `comments` with rustdoc sections, e.g. `# Arguments` or
+/// `# Examples`, and returns it as [Docs]. Comments without any known section are kept as regular
+/// comments.
+///
+/// Known sections are `# Arguments` (or `# Parameters`), `# Returns`, `# Examples`, and
+/// `# See also`. Any other text, including unknown sections like `# Errors`, is the summary (first
+/// paragraph) and description.
+pub fn take_rustdoc<'a>(comments: &mut Vec<Comment<'a>>) -> Option<Box<Docs<'a>>> {
+    let (index, docs) = comments
+        .iter()
+        .enumerate()
+        .find_map(|(i, comment)| rustdoc(comment).map(|docs| (i, docs)))?;
+    comments.remove(index);
+    Some(Box::new(docs))
+}
+
+/// Parses `comment` as rustdoc if it has any known sections. See [take_rustdoc].
+pub fn rustdoc<'a>(comment: &Comment<'a>) -> Option<Docs<'a>> {
+    let mut sections = vec![(Section::Text, vec![])];
+    let mut in_code = false;
+    for line in comment.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+        }
+        let section = trimmed
+            .strip_prefix("# ")
+            .filter(|_| !in_code)
+            .and_then(section);
+        match section {
+            Some(section) => sections.push((section, vec![])),
+            None => {
+                // Unknown sections are part of the text.
+                let (section, lines) = sections.last_mut().unwrap();
+                if *section == Section::Text || !is_heading(trimmed) || in_code {
+                    lines.push(line.clone());
+                } else {
+                    sections.push((Section::Text, vec![line.clone()]));
+                }
+            }
+        }
+    }
+    if sections.len() == 1 {
+        return None;
+    }
+
+    let mut docs = Docs::default();
+    let mut text = vec![];
+    for (section, lines) in sections {
+        match section {
+            Section::Text => {
+                let lines = trim_blank(&lines);
+                if !text.is_empty() && !lines.is_empty() {
+                    text.push(Cow::Borrowed(""));
+                }
+                text.extend_from_slice(lines);
+            }
+            Section::Params => docs.params.append(&mut params(&lines)),
+            Section::Returns => docs.returns = trimmed_lines(&lines),
+            Section::Examples => docs.examples.append(&mut examples(&lines)),
+            Section::SeeAlso => docs.see_also.append(&mut see_also(&lines)),
+        }
+    }
+    let (summary, description) = summary_description(&text);
+    docs.summary = summary;
+    docs.description = description;
+    Some(docs)
+}
+
+/// Splits `lines` into the first paragraph and the rest, with surrounding blank lines removed.
+pub fn summary_description<'a>(lines: &[Cow<'a, str>]) -> (Comment<'a>, Comment<'a>) {
+    let lines = trim_blank(lines);
+    let split = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .unwrap_or(lines.len());
+    (
+        trimmed_lines(&lines[..split]),
+        Comment::from(trim_blank(&lines[split..]).to_vec()),
+    )
+}
+
+/// Each line trimmed, without surrounding blank lines.
+pub fn trimmed_lines<'a>(lines: &[Cow<'a, str>]) -> Comment<'a> {
+    Comment::from(trim_blank(lines).iter().map(trim).collect_vec())
+}
+
+/// Removes leading and trailing blank lines.
+pub fn trim_blank<'a, 'b>(lines: &'b [Cow<'a, str>]) -> &'b [Cow<'a, str>] {
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(start, |i| i + 1);
+    &lines[start..end]
+}
+
+fn section(heading: &str) -> Option<Section> {
+    match heading.trim().to_lowercase().as_str() {
+        "arguments" | "parameters" => Some(Section::Params),
+        "returns" => Some(Section::Returns),
+        "examples" | "example" => Some(Section::Examples),
+        "see also" => Some(Section::SeeAlso),
+        _ => None,
+    }
+}
+
+fn is_heading(line: &str) -> bool {
+    line.starts_with("# ")
+}
+
+/// Bullets like `` * `name` - doc `` or `- name: doc`. Lines that aren't bullets continue the
+/// previous param's docs.
+fn params<'a>(lines: &[Cow<'a, str>]) -> Vec<ParamDoc<'a>> {
+    let mut params: Vec<ParamDoc> = vec![];
+    for line in trim_blank(lines) {
+        match bullet(line).and_then(param) {
+            Some((name, doc)) => params.push(ParamDoc::new(name, Comment::from(vec![doc]))),
+            None => {
+                if let Some(param) = params.last_mut() {
+                    if !line.trim().is_empty() {
+                        let mut lines = param.comment.lines().cloned().collect_vec();
+                        lines.push(trim(line));
+                        param.comment = Comment::from(lines);
+                    }
+                }
+            }
+        }
+    }
+    params
+}
+
+fn param<'a>(line: Cow<'a, str>) -> Option<(Cow<'a, str>, Cow<'a, str>)> {
+    let (name, doc) = match &line {
+        Cow::Borrowed(s) => {
+            let (name, doc) = split_param(s)?;
+            (Cow::Borrowed(name), Cow::Borrowed(doc))
+        }
+        Cow::Owned(s) => {
+            let (name, doc) = split_param(s)?;
+            (Cow::Owned(name.to_string()), Cow::Owned(doc.to_string()))
+        }
+    };
+    Some((name, doc))
+}
+
+fn split_param(s: &str) -> Option<(&str, &str)> {
+    let (name, doc) = match s.strip_prefix('`') {
+        Some(rest) => {
+            let (name, doc) = rest.split_once('`')?;
+            (name, doc.trim_start())
+        }
+        None => {
+            let end = s.find(|c: char| !c.is_alphanumeric() && c != '_')?;
+            (&s[..end], s[end..].trim_start())
+        }
+    };
+    let doc = doc
+        .strip_prefix('-')
+        .or_else(|| doc.strip_prefix(':'))
+        .unwrap_or(doc);
+    Some((name, doc.trim()))
+}
+
+/// Each code block is an example. If there are no code blocks, the whole section is one example.
+fn examples<'a>(lines: &[Cow<'a, str>]) -> Vec<Comment<'a>> {
+    let mut examples = vec![];
+    let mut code: Option<Vec<Cow<'a, str>>> = None;
+    for line in lines {
+        if line.trim().starts_with("```") {
+            match code.take() {
+                None => code = Some(vec![]),
+                Some(lines) => examples.push(Comment::from(lines)),
+            }
+        } else if let Some(code) = &mut code {
+            code.push(line.clone());
+        }
+    }
+    if examples.is_empty() && !trim_blank(lines).is_empty() {
+        examples.push(Comment::from(trim_blank(lines).to_vec()));
+    }
+    examples
+}
+
+/// Bullets or lines of entity names, with any link brackets and backticks removed, e.g.
+/// ``* [`Dto`]``.
+fn see_also<'a>(lines: &[Cow<'a, str>]) -> Vec<Cow<'a, str>> {
+    lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| bullet(line).unwrap_or_else(|| trim(line)))
+        .map(|line| match line {
+            Cow::Borrowed(s) => Cow::Borrowed(strip_link(s)),
+            Cow::Owned(s) => Cow::Owned(strip_link(&s).to_string()),
+        })
+        .collect_vec()
+}
+
+fn strip_link(s: &str) -> &str {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+    s.strip_prefix('`')
+        .and_then(|s| s.strip_suffix('`'))
+        .unwrap_or(s)
+}
+
+/// Contents of a `*` or `-` bullet line.
+fn bullet<'a>(line: &Cow<'a, str>) -> Option<Cow<'a, str>> {
+    match line {
+        Cow::Borrowed(s) => strip_bullet(s).map(Cow::Borrowed),
+        Cow::Owned(s) => strip_bullet(s).map(|s| Cow::Owned(s.to_string())),
+    }
+}
+
+fn strip_bullet(s: &str) -> Option<&str> {
+    let s = s.trim();
+    s.strip_prefix("* ")
+        .or_else(|| s.strip_prefix("- "))
+        .map(str::trim)
+}
+
+fn trim<'a>(line: &Cow<'a, str>) -> Cow<'a, str> {
+    match line {
+        Cow::Borrowed(s) => Cow::Borrowed(s.trim()),
+        Cow::Owned(s) => Cow::Owned(s.trim().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Comment, Docs, ParamDoc};
+    use crate::parser::docs;
+
+    #[test]
+    fn no_sections() {
+        let comment = Comment::from(vec!["summary", "", "# Errors", "desc"]);
+        assert_eq!(docs::rustdoc(&comment), None);
+    }
+
+    #[test]
+    fn all_sections() {
+        let comment = Comment::from(vec![
+            "Summary line one",
+            "line two",
+            "",
+            "Description.",
+            "",
+            "# Arguments",
+            "",
+            "* `a` - First param",
+            "  continued.",
+            "- b: Second param",
+            "",
+            "# Errors",
+            "",
+            "On failure.",
+            "",
+            "# Returns",
+            "",
+            "The value.",
+            "",
+            "# Examples",
+            "",
+            "```",
+            "# use crate::x;",
+            "let x = f(1, 2);",
+            "```",
+            "",
+            "# See also",
+            "",
+            "* [`other`]",
+            "- `ns::Dto`",
+        ]);
+        assert_eq!(
+            docs::rustdoc(&comment),
+            Some(Docs {
+                summary: Comment::from(vec!["Summary line one", "line two"]),
+                description: Comment::from(vec!["Description.", "", "# Errors", "", "On failure."]),
+                params: vec![
+                    ParamDoc::new("a", Comment::from(vec!["First param", "continued."])),
+                    ParamDoc::new("b", Comment::from(vec!["Second param"])),
+                ],
+                returns: Comment::from(vec!["The value."]),
+                examples: vec![Comment::from(vec!["# use crate::x;", "let x = f(1, 2);"])],
+                see_also: vec!["other".into(), "ns::Dto".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn examples_without_code_block() {
+        let comment = Comment::from(vec!["# Examples", "f(1)"]);
+        assert_eq!(
+            docs::rustdoc(&comment).unwrap().examples,
+            vec![Comment::from(vec!["f(1)"])]
+        );
+    }
+
+    #[test]
+    fn take_rustdoc() {
+        let regular = Comment::from(vec!["regular"]);
+        let doc = Comment::from(vec!["summary", "# Returns", "value"]);
+        let mut comments = vec![regular.clone(), doc];
+        let docs = docs::take_rustdoc(&mut comments).unwrap();
+        assert_eq!(docs.summary, Comment::from(vec!["summary"]));
+        assert_eq!(docs.returns, Comment::from(vec!["value"]));
+        assert_eq!(comments, vec![regular]);
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Result};
+use log::debug;
+
+use crate::model::Namespace;
+use crate::parser::Config;
+use crate::{model, Input, Parser};
+
+/// Parses apyxl's own JSON representation of a [Namespace], i.e. the serde representation of the
+/// [crate::model] types. This is the inverse of [crate::generator::Json] so that parsing its
+/// output, generating again, and parsing that is lossless.
+///
+/// Each chunk is a single [Namespace] object. A namespace named `_` (i.e. the root [model::Api])
+/// is merged into the root namespace, and any other namespace is added as a child of the root.
+///
+/// ```json
+/// {
+///   "name": "_",
+///   "children": [
+///     { "dto": { "name": "Dto", "fields": [
+///       { "name": "id", "ty": { "value": "u32", "semantics": "value" } }
+///     ] } },
+///     { "rpc": { "name": "get", "return_type": { "value": { "api": "Dto" }, "semantics": "value" } } }
+///   ]
+/// }
+/// ```
+///
+/// Names that are borrowed by the model (e.g. dto and field names) must not contain JSON escape
+/// sequences.
+#[derive(Default)]
+pub struct Json {}
+
+impl Parser for Json {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        _: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+            let namespace = serde_json::from_str::<Namespace>(data).map_err(|err| {
+                anyhow!(
+                    "errors encountered while parsing {:?}: {}",
+                    chunk.relative_file_path,
+                    err
+                )
+            })?;
+            builder.merge_from_chunk(namespace, chunk);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::generator::Json as JsonGenerator;
+    use crate::model::{Builder, EntityId, Semantics, Type, TypeRef};
+    use crate::output::Buffer;
+    use crate::parser::json::Json;
+    use crate::test_util::executor::{TestExecutor, TEST_CONFIG};
+    use crate::{input, view, Generator, Parser};
+
+    #[test]
+    fn parse() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"{
+            "name": "_",
+            "children": [
+                { "namespace": { "name": "ns", "children": [
+                    { "dto": { "name": "Dto", "fields": [
+                        { "name": "id", "ty": { "value": "u32", "semantics": "value" } }
+                    ] } }
+                ] } },
+                { "rpc": {
+                    "name": "get",
+                    "params": [
+                        { "name": "dto", "ty": { "value": { "api": "ns.Dto" }, "semantics": "ref" } }
+                    ],
+                    "return_type": { "value": { "optional": { "value": "string", "semantics": "value" } }, "semantics": "value" },
+                    "attributes": { "comments": [["rpc \"comment\""]] },
+                    "is_static": true
+                } }
+            ]
+        }"#,
+        );
+        let mut builder = Builder::default();
+        Json::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        let dto = model
+            .api()
+            .find_dto(&EntityId::new_unqualified("ns.Dto"))
+            .unwrap();
+        assert_eq!(dto.fields[0].ty, TypeRef::new(Type::U32, Semantics::Value));
+        let rpc = model.api().rpc("get").unwrap();
+        assert_eq!(
+            rpc.params[0].ty,
+            TypeRef::new(
+                Type::Api(EntityId::try_from("ns.d:Dto")?),
+                Semantics::Ref
+            )
+        );
+        assert_eq!(
+            rpc.attributes.comments[0].lines().collect::<Vec<_>>(),
+            vec!["rpc \"comment\""]
+        );
+        assert!(rpc.is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let mut input = input::Buffer::new(r#"{ "name": "_", "children": [ { "dto": {} } ] }"#);
+        let mut builder = Builder::default();
+        let result = Json::default().parse(&TEST_CONFIG, &mut input, &mut builder);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let data = r#"
+        mod ns0 {
+            /// dto comment
+            #[user_attr(key = value, flag)]
+            struct Dto {
+                field0: Vec<Option<u32>>,
+                field1: HashMap<String, ns1::En>,
+                field2: &mut Alias,
+            }
+            impl Dto {
+                fn method(&self, param: &Dto) -> bool {}
+            }
+            type Alias = ns1::En;
+            mod ns1 {
+                enum En {
+                    A = 1,
+                    B = 5,
+                }
+            }
+            pub fn rpc(dto: Dto) -> Option<Dto> {}
+            pub const FIELD: usize = 5;
+            pub const ESCAPED: &str = "a \"b\"";
+        }
+        "#;
+        let mut exe = TestExecutor::new(data);
+        let model = exe.build();
+        let json = generate(&model)?;
+
+        let mut input = input::Buffer::new(&json);
+        let mut builder = Builder::default();
+        Json::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let parsed = builder.build().unwrap();
+        assert_eq!(json, generate(&parsed)?);
+        Ok(())
+    }
+
+    fn generate(model: &crate::model::Model) -> Result<String> {
+        let mut output = Buffer::default();
+        JsonGenerator::default().generate(view::Model::new(model), &mut output)?;
+        Ok(output.to_string())
+    }
+}
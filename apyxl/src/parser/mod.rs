@@ -1,15 +1,20 @@
 use crate::input::Input;
 use crate::model;
 use anyhow::Result;
+pub use c::C;
 pub use config::*;
+pub use json::Json;
 pub use rust::Rust;
 
 pub mod comment;
+pub mod docs;
 pub mod error;
 pub mod test_util;
 pub mod util;
 
+mod c;
 mod config;
+mod json;
 mod rust;
 
 pub trait Parser {
@@ -1,24 +1,28 @@
 use chumsky::prelude::*;
 use std::borrow::Cow;
 
-use crate::model::attributes;
+use crate::model::{attributes, Deprecation};
 use crate::parser::error::Error;
+use crate::parser::rust::value;
+use crate::parser::util;
 
 pub fn attributes<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> {
     let name = text::ident();
     let data = text::ident()
-        .then(just('=').padded().ignore_then(text::ident()).or_not())
-        .map(|(lhs, rhs)| match rhs {
-            None => attributes::UserData::new(None, lhs),
-            Some(rhs) => attributes::UserData::new(Some(lhs), rhs),
-        });
+        .then_ignore(just('=').padded())
+        .or_not()
+        .then(value::token())
+        .map(|(key, value)| attributes::UserData::new(key, value));
+    let assigned_data = just('=')
+        .padded()
+        .ignore_then(value::token())
+        .map(|value| vec![attributes::UserData::new(None, value)]);
     let data_list = data
         .separated_by(just(',').padded())
         .allow_trailing()
         .collect::<Vec<_>>()
-        .delimited_by(just('(').padded(), just(')').padded())
-        .or_not();
-    name.then(data_list)
+        .delimited_by(just('(').padded(), just(')').padded());
+    name.then(assigned_data.or(data_list).or_not())
         .map(|(name, data)| attributes::User {
             name: Cow::Borrowed(name),
             data: data.unwrap_or(vec![]),
@@ -37,12 +41,20 @@ pub fn attributes<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, E
         .map(|opt| opt.unwrap_or(vec![]))
 }
 
+/// Removes a `#[deprecated]` attribute from `user` and returns it as a [Deprecation], e.g.
+/// `#[deprecated]`, `#[deprecated = "note"]`, or
+/// `#[deprecated(since = "1.2", note = "...", replacement = "new_fn")]`.
+pub fn take_deprecation<'a>(user: &mut Vec<attributes::User<'a>>) -> Option<Deprecation<'a>> {
+    util::take_deprecation(user, &["deprecated"])
+}
+
 #[cfg(test)]
 mod tests {
     use chumsky::Parser;
+    use std::borrow::Cow;
 
-    use crate::model::attributes;
     use crate::model::attributes::UserData;
+    use crate::model::{attributes, Deprecation};
     use crate::parser::rust::dto;
     use crate::test_util::executor::TEST_CONFIG;
 
@@ -130,6 +142,67 @@ mod tests {
         )
     }
 
+    #[test]
+    fn literals_and_paths() {
+        run_test(
+            r#"
+                    #[attr0(-1.5, "str", k0 = 5), attr1(path::to::Value)]
+                    struct dto {}
+                    "#,
+            vec![
+                attributes::User::new(
+                    "attr0",
+                    vec![
+                        UserData::new(None, "-1.5"),
+                        UserData::new(None, r#""str""#),
+                        UserData::new(Some("k0"), "5"),
+                    ],
+                ),
+                attributes::User::new("attr1", vec![UserData::new(None, "path::to::Value")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn assigned() {
+        run_test(
+            r#"
+                    #[attr0 = "str", attr1]
+                    struct dto {}
+                    "#,
+            vec![
+                attributes::User::new("attr0", vec![UserData::new(None, r#""str""#)]),
+                attributes::User::new_flag("attr1"),
+            ],
+        )
+    }
+
+    #[test]
+    fn deprecation() {
+        for (content, expected) in [
+            ("#[deprecated]", Deprecation::default()),
+            (
+                r#"#[deprecated = "note"]"#,
+                Deprecation {
+                    note: Some(Cow::Borrowed("note")),
+                    ..Default::default()
+                },
+            ),
+            (
+                r#"#[deprecated(since = "1.2", note = "note", replacement = "new_dto")]"#,
+                Deprecation {
+                    since: Some(Cow::Borrowed("1.2")),
+                    note: Some(Cow::Borrowed("note")),
+                    replacement: Some(Cow::Borrowed("new_dto")),
+                },
+            ),
+        ] {
+            let mut user = super::attributes().parse(content).into_result().unwrap();
+            assert_eq!(super::take_deprecation(&mut user), Some(expected));
+            assert!(user.is_empty());
+        }
+    }
+
     fn run_test(content: &str, expected: Vec<attributes::User>) {
         let (dto, _) = dto::parser(&TEST_CONFIG)
             .parse(content)
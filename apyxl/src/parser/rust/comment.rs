@@ -1,7 +1,7 @@
-use crate::model::Comment;
-use crate::parser::comment;
+use crate::model::{Comment, Docs};
 use crate::parser::error::Error;
-use chumsky::prelude::just;
+use crate::parser::{comment, docs};
+use chumsky::prelude::{choice, just};
 use chumsky::Parser;
 
 pub fn single<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
@@ -12,12 +12,18 @@ pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> {
     comment::multi(line_start(), block_start(), block_end())
 }
 
+/// Removes the first comment with rustdoc sections from `comments` and returns it as [Docs]. See
+/// [docs::take_rustdoc].
+pub fn take_docs<'a>(comments: &mut Vec<Comment<'a>>) -> Option<Box<Docs<'a>>> {
+    docs::take_rustdoc(comments)
+}
+
 fn line_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
-    just("//")
+    choice((just("///"), just("//")))
 }
 
 fn block_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
-    just("/*")
+    choice((just("/**"), just("/*")))
 }
 
 fn block_end<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
@@ -4,7 +4,7 @@ use itertools::Itertools;
 use crate::model::{Attributes, Dto, Field};
 use crate::parser::error::Error;
 use crate::parser::rust::visibility::Visibility;
-use crate::parser::rust::{attributes, comment, ty, visibility};
+use crate::parser::rust::{attributes, comment, ty, value, visibility};
 use crate::parser::{util, Config};
 
 pub fn parser(config: &Config) -> impl Parser<&str, (Dto, Visibility), Error> {
@@ -23,25 +23,31 @@ pub fn parser(config: &Config) -> impl Parser<&str, (Dto, Visibility), Error> {
         .then(visibility::parser())
         .then_ignore(prefix)
         .then(name)
+        .then(ty::type_params().or_not())
         .then(fields)
-        .map(|((((comments, user), visibility), name), fields)| {
-            (
-                Dto {
-                    name,
-                    fields: fields
-                        .into_iter()
-                        .filter_map(|(field, visibility)| visibility.filter(field, config))
-                        .collect_vec(),
-                    rpcs: vec![],
-                    attributes: Attributes {
-                        comments,
-                        user,
-                        ..Default::default()
-                    },
-                    namespace: None,
+        .map(|(((((mut comments, mut user), visibility), name), type_params), fields)| {
+            let type_params = type_params.unwrap_or_default();
+            let mut dto = Dto {
+                name,
+                type_params: type_params.clone(),
+                fields: fields
+                    .into_iter()
+                    .filter_map(|(field, visibility)| visibility.filter(field, config))
+                    .collect_vec(),
+                rpcs: vec![],
+                extends: vec![],
+                implements: vec![],
+                attributes: Attributes {
+                    docs: comment::take_docs(&mut comments),
+                    comments,
+                    deprecation: attributes::take_deprecation(&mut user),
+                    user,
+                    ..Default::default()
                 },
-                visibility,
-            )
+                namespace: None,
+            };
+            dto.apply_type_params(&type_params);
+            (dto, visibility)
         })
 }
 
@@ -53,24 +59,36 @@ fn field(config: &Config) -> impl Parser<&str, (Field, Visibility), Error> {
         .then(attributes::attributes().padded())
         .then(visibility::parser())
         .then(field)
-        .map(|(((comments, user), visibility), (name, ty))| {
-            (
-                Field {
-                    name,
-                    ty,
-                    attributes: Attributes {
-                        comments,
-                        user,
-                        ..Default::default()
+        .validate(
+            |(((mut comments, mut user), visibility), (name, ty)), span, emitter| {
+                let default = value::take_default(&mut user, &ty).unwrap_or_else(|err| {
+                    emitter.emit(Rich::custom(span, err));
+                    None
+                });
+                (
+                    Field {
+                        name,
+                        ty,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                        is_static: false,
+                        value: None,
+                        default,
                     },
-                    is_static: false,
-                },
-                visibility,
-            )
-        })
+                    visibility,
+                )
+            },
+        )
 }
 
-fn fields(config: &Config) -> impl Parser<&str, Vec<(Field, Visibility)>, Error> {
+pub fn fields<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Vec<(Field<'a>, Visibility)>, Error<'a>> {
     field(config)
         .separated_by(just(',').padded())
         .allow_trailing()
@@ -81,8 +99,11 @@ fn fields(config: &Config) -> impl Parser<&str, Vec<(Field, Visibility)>, Error>
 mod tests {
     use anyhow::Result;
     use chumsky::Parser;
+    use std::borrow::Cow;
 
-    use crate::model::{attributes, Comment};
+    use crate::model::{
+        attributes, Comment, Deprecation, EntityId, Semantics, Type, TypeRef, Value,
+    };
     use crate::parser::rust::dto;
     use crate::parser::rust::visibility::Visibility;
     use crate::parser::test_util::wrap_test_err;
@@ -134,6 +155,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn generic() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            struct Page<T> {
+                items: Vec<T>,
+                next: Option<Page<T>>,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Page");
+        assert_eq!(dto.type_params, vec!["T"]);
+        assert_eq!(
+            dto.fields[0].ty,
+            TypeRef::new_array(
+                TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value),
+                Semantics::Value
+            )
+        );
+        assert_eq!(
+            dto.fields[1].ty,
+            TypeRef::new_optional(
+                TypeRef::new_generic(
+                    EntityId::new_unqualified("Page"),
+                    vec![TypeRef::new(
+                        Type::TypeParam("T".to_string()),
+                        Semantics::Value
+                    )],
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+        Ok(())
+    }
+
     #[test]
     fn multiple_fields() -> Result<()> {
         let (dto, _) = dto::parser(&TEST_CONFIG)
@@ -226,6 +286,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn field_default() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            struct StructName {
+                #[default(30), flag]
+                field0: u32,
+                #[default(OTHER)]
+                field1: u32,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields[0].default, Some(Value::Int(30)));
+        assert_eq!(
+            dto.fields[0].attributes.user,
+            vec![attributes::User::new_flag("flag")]
+        );
+        assert_eq!(dto.fields[1].default, None);
+        assert_eq!(dto.fields[1].attributes.user.len(), 1);
+        Ok(())
+    }
+
     #[test]
     fn attributes() -> Result<()> {
         let (dto, _) = dto::parser(&TEST_CONFIG)
@@ -247,4 +332,36 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn deprecated() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                #[deprecated(since = "1.2", note = "use NewDto")]
+                struct StructName {
+                    #[deprecated]
+                    field: u32,
+                    other: u32,
+                }
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            dto.attributes.deprecation,
+            Some(Deprecation {
+                since: Some(Cow::Borrowed("1.2")),
+                note: Some(Cow::Borrowed("use NewDto")),
+                replacement: None,
+            })
+        );
+        assert!(dto.attributes.user.is_empty());
+        assert_eq!(
+            dto.fields[0].attributes.deprecation,
+            Some(Deprecation::default())
+        );
+        assert_eq!(dto.fields[1].attributes.deprecation, None);
+        Ok(())
+    }
 }
@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
 use chumsky::prelude::*;
 
-use crate::model::{Attributes, Enum, EnumValue, EnumValueNumber};
+use crate::model::attributes::User;
+use crate::model::{
+    Attributes, Comment, Enum, EnumKind, EnumValue, EnumValueNumber, Type, Union, Variant,
+    VariantPayload,
+};
 use crate::parser::error::Error;
 use crate::parser::rust::visibility::Visibility;
-use crate::parser::rust::{attributes, comment, visibility};
-use crate::parser::util;
+use crate::parser::rust::{attributes, comment, dto, ty, visibility};
+use crate::parser::{util, Config};
 
 const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
 
@@ -22,13 +28,22 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a
         .then_ignore(prefix)
         .then(name)
         .then(values)
-        .map(|((((comments, user), visibility), name), values)| {
+        .map(|((((mut comments, mut user), visibility), name), values)| {
+            let kind = if take_flags(&mut user) {
+                EnumKind::Flags
+            } else {
+                EnumKind::Plain
+            };
             (
                 Enum {
                     name,
                     values: apply_enum_value_number_defaults(values),
+                    kind,
+                    underlying_type: None,
                     attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
                         comments,
+                        deprecation: attributes::take_deprecation(&mut user),
                         user,
                         ..Default::default()
                     },
@@ -38,6 +53,169 @@ pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a
         })
 }
 
+/// Parses `bitflags!` invocations to [EnumKind::Flags] enums, e.g.
+/// `bitflags! { pub struct Permissions: u32 { const READ = 1; const WRITE = 1 << 1; } }`.
+///
+/// Flag values are integer literals, shifts, or combinations of previous flags joined by `|`, e.g.
+/// `Self::READ.bits() | Self::WRITE.bits()`.
+pub fn flags_parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a>> {
+    let prefix = just("bitflags::")
+        .or_not()
+        .then(util::keyword_ex("bitflags"))
+        .then(just('!').padded());
+    let header = util::keyword_ex("struct")
+        .then(text::whitespace().at_least(1))
+        .ignore_then(text::ident())
+        .then_ignore(just(':').padded())
+        .then(text::ident().map(integer_ty));
+    let values = flags_value()
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+        .try_map(|values: Vec<FlagsValue>, span| {
+            let mut evaluated = HashMap::new();
+            values
+                .into_iter()
+                .map(|(((mut comments, mut user), name), terms)| {
+                    let number = eval_flags(&terms, &evaluated).ok_or_else(|| {
+                        Rich::custom(span, format!("unable to evaluate the value of {}", name))
+                    })?;
+                    evaluated.insert(name, number);
+                    Ok(EnumValue {
+                        name,
+                        number,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        });
+    comment::multi()
+        .then_ignore(prefix.padded())
+        .then_ignore(just('{').padded())
+        .then(comment::multi())
+        .then(attributes::attributes().padded())
+        .then(visibility::parser())
+        .then(header)
+        .then(values)
+        .then_ignore(just('}').padded())
+        .map(
+            |(
+                ((((outer_comments, comments), mut user), visibility), (name, underlying_type)),
+                values,
+            )| {
+                let mut comments = outer_comments.into_iter().chain(comments).collect();
+                (
+                    Enum {
+                        name,
+                        values,
+                        kind: EnumKind::Flags,
+                        underlying_type,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    },
+                    visibility,
+                )
+            },
+        )
+}
+
+/// The integer [Type] of a `bitflags!` struct, e.g. `u32` in `struct Permissions: u32`.
+fn integer_ty(name: &str) -> Option<Type> {
+    Some(match name {
+        "u8" => Type::U8,
+        "u16" => Type::U16,
+        "u32" => Type::U32,
+        "u64" => Type::U64,
+        "u128" => Type::U128,
+        "usize" => Type::USIZE,
+        "i8" => Type::I8,
+        "i16" => Type::I16,
+        "i32" => Type::I32,
+        "i64" => Type::I64,
+        "i128" => Type::I128,
+        _ => return None,
+    })
+}
+
+/// Parses enums with data, e.g. `enum Event { Login { user: Id }, Logout }`. Enums without any
+/// payloads are handled by [parser].
+pub fn union_parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Union<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("enum").then(text::whitespace().at_least(1));
+    let name = text::ident();
+    let variants = variant(config)
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .then(attributes::attributes().padded())
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(name)
+        .then(variants)
+        .map(
+            |((((mut comments, mut user), visibility), name), variants)| {
+                (
+                    Union {
+                        name,
+                        variants,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    },
+                    visibility,
+                )
+            },
+        )
+}
+
+fn variant<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Variant<'a>, Error<'a>> {
+    let tuple = ty::parser(config)
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), just(')').padded())
+        .map(VariantPayload::Tuple);
+    let structure = dto::fields(config)
+        .delimited_by(just('{').padded(), just('}').padded())
+        .map(|fields| {
+            VariantPayload::Struct(fields.into_iter().map(|(field, _)| field).collect())
+        });
+    comment::multi()
+        .then(attributes::attributes().padded())
+        .then(text::ident())
+        .then(tuple.or(structure).or_not())
+        .padded()
+        .map(|(((mut comments, mut user), name), payload)| Variant {
+            name,
+            payload,
+            attributes: Attributes {
+                docs: comment::take_docs(&mut comments),
+                comments,
+                deprecation: attributes::take_deprecation(&mut user),
+                user,
+                ..Default::default()
+            },
+        })
+}
+
 fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
     let number = just('=')
         .padded()
@@ -50,17 +228,104 @@ fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
         .then(text::ident())
         .then(number.or_not())
         .padded()
-        .map(|(((comments, user), name), number)| EnumValue {
+        .map(|(((mut comments, mut user), name), number)| EnumValue {
             name,
             number: number.unwrap_or(INVALID_ENUM_NUMBER),
             attributes: Attributes {
+                docs: comment::take_docs(&mut comments),
                 comments,
+                deprecation: attributes::take_deprecation(&mut user),
                 user,
                 ..Default::default()
             },
         })
 }
 
+/// A single term of a flag value, i.e. an operand of `|`.
+#[derive(Debug, Clone, PartialEq)]
+enum FlagsTerm<'a> {
+    Number(EnumValueNumber),
+    Flag(&'a str),
+}
+
+type FlagsValue<'a> = (
+    ((Vec<Comment<'a>>, Vec<User<'a>>), &'a str),
+    Vec<FlagsTerm<'a>>,
+);
+
+/// A single flag within a `bitflags!` struct, e.g. `const READ = 1 << 0;`.
+fn flags_value<'a>() -> impl Parser<'a, &'a str, FlagsValue<'a>, Error<'a>> {
+    let shift = just("<<").padded().ignore_then(flags_number());
+    let number = flags_number()
+        .then(shift.or_not())
+        .try_map(|(number, shift), span| match shift {
+            None => Ok(number),
+            Some(shift) => u32::try_from(shift)
+                .ok()
+                .and_then(|shift| number.checked_shl(shift))
+                .ok_or_else(|| Rich::custom(span, "invalid shift")),
+        })
+        .map(FlagsTerm::Number);
+    let flag = just("Self::")
+        .or_not()
+        .ignore_then(text::ident())
+        .then_ignore(just(".bits").then(just("()").or_not()).or_not())
+        .map(FlagsTerm::Flag);
+    let terms = number
+        .or(flag)
+        .separated_by(just('|').padded())
+        .at_least(1)
+        .collect::<Vec<_>>();
+    comment::multi()
+        .then(attributes::attributes().padded())
+        .then_ignore(util::keyword_ex("const").then(text::whitespace().at_least(1)))
+        .then(text::ident())
+        .then_ignore(just('=').padded())
+        .then(terms)
+        .then_ignore(just(';').padded())
+        .padded()
+}
+
+/// Integer literal with an optional type suffix, e.g. `10`, `0x1F`, `0b0100_0000`, or `1u32`.
+fn flags_number<'a>() -> impl Parser<'a, &'a str, EnumValueNumber, Error<'a>> {
+    let digits = |radix: u32| {
+        any()
+            .filter(move |c: &char| c.is_digit(radix) || *c == '_')
+            .repeated()
+            .at_least(1)
+            .slice()
+            .try_map(move |digits: &str, span| {
+                EnumValueNumber::from_str_radix(&digits.replace('_', ""), radix)
+                    .map_err(|_| Rich::custom(span, "invalid integer literal"))
+            })
+    };
+    choice((
+        just("0x").ignore_then(digits(16)),
+        just("0o").ignore_then(digits(8)),
+        just("0b").ignore_then(digits(2)),
+        digits(10),
+    ))
+    .then_ignore(text::ident().or_not())
+}
+
+/// Returns None if a term references an unknown flag.
+fn eval_flags(
+    terms: &[FlagsTerm],
+    evaluated: &HashMap<&str, EnumValueNumber>,
+) -> Option<EnumValueNumber> {
+    terms.iter().try_fold(0, |acc, term| match term {
+        FlagsTerm::Number(number) => Some(acc | number),
+        FlagsTerm::Flag(name) => evaluated.get(name).map(|number| acc | number),
+    })
+}
+
+/// Removes a `#[flags]` attribute from `user`. Returns true if it was present.
+fn take_flags(user: &mut Vec<User>) -> bool {
+    let len = user.len();
+    user.retain(|attr| attr.name != "flags" || !attr.data.is_empty());
+    user.len() != len
+}
+
 fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
     let mut i = 0;
     for value in &mut values {
@@ -122,7 +387,7 @@ mod tests {
         use anyhow::Result;
         use chumsky::Parser;
 
-        use crate::model::{attributes, Comment, EnumValue, EnumValueNumber};
+        use crate::model::{attributes, Comment, EnumKind, EnumValue, EnumValueNumber};
         use crate::parser::rust::en;
         use crate::parser::rust::visibility::Visibility;
         use crate::parser::test_util::wrap_test_err;
@@ -299,6 +564,38 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn flags_attribute() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse(
+                    r#"
+                    #[flags, flag1]
+                    enum Enum {
+                        A = 1,
+                        B = 2,
+                    }
+                    "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.kind, EnumKind::Flags);
+            assert_eq!(
+                en.attributes.user,
+                vec![attributes::User::new_flag("flag1")]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn plain() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse("enum Enum { A, B }")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.kind, EnumKind::Plain);
+            Ok(())
+        }
+
         fn assert_value(
             actual: Option<&EnumValue>,
             expected_name: &str,
@@ -314,4 +611,193 @@ mod tests {
             );
         }
     }
+
+    mod flags {
+        use anyhow::Result;
+        use chumsky::Parser;
+
+        use crate::model::{attributes, Comment, EnumKind, Type};
+        use crate::parser::rust::en;
+        use crate::parser::rust::visibility::Visibility;
+        use crate::parser::test_util::wrap_test_err;
+
+        #[test]
+        fn values() -> Result<()> {
+            let (en, visibility) = en::flags_parser()
+                .parse(
+                    r#"
+                    bitflags! {
+                        pub struct Permissions: u32 {
+                            const NONE = 0;
+                            const READ = 0b0001;
+                            const WRITE = 1 << 1;
+                            const EXEC = 0x4;
+                            const LARGE = 1_024u32;
+                            const READ_WRITE = Self::READ.bits() | Self::WRITE.bits();
+                            const ALL = READ | WRITE | Self::EXEC.bits | 8;
+                        }
+                    }
+                "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.name, "Permissions");
+            assert_eq!(en.kind, EnumKind::Flags);
+            assert_eq!(en.underlying_type, Some(Type::U32));
+            assert_eq!(visibility, Visibility::Public);
+            let values = en
+                .values
+                .iter()
+                .map(|value| (value.name, value.number))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                values,
+                vec![
+                    ("NONE", 0),
+                    ("READ", 1),
+                    ("WRITE", 2),
+                    ("EXEC", 4),
+                    ("LARGE", 1024),
+                    ("READ_WRITE", 3),
+                    ("ALL", 15),
+                ]
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn path() -> Result<()> {
+            let (en, visibility) = en::flags_parser()
+                .parse("bitflags::bitflags! { struct Flags: u8 { const A = 1; } }")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.name, "Flags");
+            assert_eq!(visibility, Visibility::Private);
+            assert_eq!(en.values.len(), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_flag() {
+            assert!(en::flags_parser()
+                .parse("bitflags! { struct Flags: u8 { const A = B; } }")
+                .into_result()
+                .is_err());
+        }
+
+        #[test]
+        fn attributes() -> Result<()> {
+            let (en, _) = en::flags_parser()
+                .parse(
+                    r#"
+                    // outer
+                    bitflags! {
+                        // inner
+                        #[derive(Debug)]
+                        struct Flags: u8 {
+                            // value comment
+                            #[flag]
+                            const A = 1;
+                        }
+                    }
+                "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(
+                en.attributes.comments,
+                vec![Comment::unowned(&["outer"]), Comment::unowned(&["inner"])]
+            );
+            assert_eq!(en.attributes.user.len(), 1);
+            assert_eq!(en.attributes.user[0].name, "derive");
+            assert_eq!(
+                en.values[0].attributes.comments,
+                vec![Comment::unowned(&["value comment"])]
+            );
+            assert_eq!(
+                en.values[0].attributes.user,
+                vec![attributes::User::new_flag("flag")]
+            );
+            Ok(())
+        }
+    }
+
+    mod union {
+        use anyhow::Result;
+        use chumsky::Parser;
+
+        use crate::model::{Comment, EntityId, Semantics, Type, TypeRef, VariantPayload};
+        use crate::parser::rust::en;
+        use crate::parser::rust::visibility::Visibility;
+        use crate::parser::test_util::wrap_test_err;
+        use crate::test_util::executor::TEST_CONFIG;
+
+        #[test]
+        fn variants() -> Result<()> {
+            let (union, visibility) = en::union_parser(&TEST_CONFIG)
+                .parse(
+                    r#"
+                    pub enum Event {
+                        Logout,
+                        Move(i32, a::Pos),
+                        Login { user: Id, remember: bool },
+                    }
+                "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(union.name, "Event");
+            assert_eq!(visibility, Visibility::Public);
+            assert_eq!(union.variants.len(), 3);
+            assert_eq!(union.variants[0].name, "Logout");
+            assert_eq!(union.variants[0].payload, None);
+            assert_eq!(union.variants[1].name, "Move");
+            assert_eq!(
+                union.variants[1].payload,
+                Some(VariantPayload::Tuple(vec![
+                    TypeRef::new(Type::I32, Semantics::Value),
+                    TypeRef::new(
+                        Type::Api(EntityId::new_unqualified("a.Pos")),
+                        Semantics::Value,
+                    ),
+                ]))
+            );
+            assert_eq!(union.variants[2].name, "Login");
+            match &union.variants[2].payload {
+                Some(VariantPayload::Struct(fields)) => {
+                    assert_eq!(fields.len(), 2);
+                    assert_eq!(fields[0].name, "user");
+                    assert_eq!(fields[1].name, "remember");
+                    assert_eq!(fields[1].ty.value, Type::Bool);
+                }
+                _ => panic!("expected struct payload"),
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn variant_comments() -> Result<()> {
+            let (union, _) = en::union_parser(&TEST_CONFIG)
+                .parse(
+                    r#"
+                    // union comment
+                    enum Event {
+                        // variant comment
+                        Move(i32),
+                    }
+                "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(
+                union.attributes.comments,
+                vec![Comment::unowned(&["union comment"])]
+            );
+            assert_eq!(
+                union.variants[0].attributes.comments,
+                vec![Comment::unowned(&["variant comment"])]
+            );
+            Ok(())
+        }
+    }
 }
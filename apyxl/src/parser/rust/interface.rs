@@ -0,0 +1,148 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::model::{Attributes, Interface};
+use crate::parser::error::Error;
+use crate::parser::rust::visibility::Visibility;
+use crate::parser::rust::{attributes, comment, rpc, ty, visibility};
+use crate::parser::{util, Config};
+
+// Parses a `trait` into an [Interface]. All fns are included regardless of visibility since they
+// share the visibility of the trait. Associated types and consts are skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Interface<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("trait").then(text::whitespace().at_least(1));
+    let lifetime = just('\'').then(text::ident()).ignored();
+    let super_interfaces = just(':').padded().ignore_then(
+        ty::trait_ref(config)
+            .map(Some)
+            .or(lifetime.map(|_| None))
+            .separated_by(just('+').padded())
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(|v| v.into_iter().flatten().collect_vec()),
+    );
+    let associated_item = comment::multi()
+        .then(choice((
+            util::keyword_ex("type"),
+            util::keyword_ex("const"),
+        )))
+        .then(none_of(";").repeated())
+        .then(just(';'))
+        .padded();
+    let rpcs = choice((
+        rpc::parser(config).map(|(rpc, _)| Some(rpc)),
+        associated_item.map(|_| None),
+    ))
+    .recover_with(skip_then_retry_until(any().ignored(), just('}').ignored()))
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|v| v.into_iter().flatten().collect_vec())
+    .then_ignore(comment::multi());
+    comment::multi()
+        .then(attributes::attributes().padded())
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then(super_interfaces.or_not())
+        .then(rpcs.delimited_by(just('{').padded(), just('}').padded()))
+        .map(
+            |(((((mut comments, mut user), visibility), name), super_interfaces), rpcs)| {
+                (
+                    Interface {
+                        name,
+                        rpcs,
+                        super_interfaces: super_interfaces.unwrap_or_default(),
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    },
+                    visibility,
+                )
+            },
+        )
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::model::{EntityId, Semantics, Type, TypeRef};
+    use crate::parser::rust::interface;
+    use crate::parser::rust::visibility::Visibility;
+    use crate::parser::test_util::wrap_test_err;
+    use crate::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (interface, visibility) = interface::parser(&TEST_CONFIG)
+            .parse("pub trait Service {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(interface.name, "Service");
+        assert_eq!(visibility, Visibility::Public);
+        assert!(interface.rpcs.is_empty());
+        assert!(interface.super_interfaces.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn rpcs() -> Result<()> {
+        let (interface, _) = interface::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            trait Service {
+                fn get(&self, id: u32) -> Dto;
+                // comment
+                fn with_default(&self) {}
+                type Associated;
+                fn after_associated();
+            }
+            "#
+                .trim(),
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            interface
+                .rpcs
+                .iter()
+                .map(|rpc| rpc.name.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["get", "with_default", "after_associated"]
+        );
+        let get = interface.rpc("get").unwrap();
+        assert_eq!(get.params.len(), 1);
+        assert_eq!(
+            get.return_type,
+            Some(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn super_interfaces() -> Result<()> {
+        let (interface, _) = interface::parser(&TEST_CONFIG)
+            .parse("trait Service: a::Base + Other<u32> + 'static {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            interface.super_interfaces,
+            vec![
+                EntityId::new_unqualified("a.Base"),
+                EntityId::new_unqualified("Other"),
+            ]
+        );
+        Ok(())
+    }
+}
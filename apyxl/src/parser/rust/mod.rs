@@ -1,5 +1,6 @@
 use crate::model::{
-    Api, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+    Api, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef, VariantPayload,
+    UNDEFINED_NAMESPACE,
 };
 use crate::parser::rust::import::Import;
 use crate::parser::{error, Config};
@@ -18,10 +19,12 @@ mod dto;
 mod en;
 mod expr_block;
 mod import;
+mod interface;
 mod namespace;
 mod rpc;
 mod ty;
 mod ty_alias;
+mod value;
 mod visibility;
 
 #[derive(Default)]
@@ -63,6 +66,7 @@ impl ApyxlParser for Rust {
                 children,
                 attributes: Default::default(),
                 is_virtual: false,
+                implements: vec![],
             };
 
             // Keep track of all EntityIds in this chunk for use in blanket imports.
@@ -187,6 +191,15 @@ fn apply_imports(
                 imports,
             )?;
         }
+        if let Some(error_ty) = &mut rpc.error_type {
+            apply_imports_to_type(
+                all_entity_ids,
+                &local_entity_ids,
+                &namespace_id,
+                error_ty,
+                imports,
+            )?;
+        }
         Ok(())
     };
 
@@ -203,6 +216,20 @@ fn apply_imports(
         apply_import_to_rpc(rpc)?;
     }
 
+    for interface in namespace.interfaces_mut() {
+        for rpc in &mut interface.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+        for id in &mut interface.super_interfaces {
+            apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+        }
+    }
+
+    // Traits implemented by 'virtual' namespaces, e.g. `impl Trait for Dto`.
+    for id in &mut namespace.implements {
+        apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+    }
+
     for field in namespace.fields_mut() {
         apply_import_to_field(field)?;
     }
@@ -217,6 +244,30 @@ fn apply_imports(
         )?;
     }
 
+    for union in namespace.unions_mut() {
+        for variant in &mut union.variants {
+            match &mut variant.payload {
+                Some(VariantPayload::Tuple(tys)) => {
+                    for ty in tys {
+                        apply_imports_to_type(
+                            all_entity_ids,
+                            &local_entity_ids,
+                            &namespace_id,
+                            ty,
+                            imports,
+                        )?;
+                    }
+                }
+                Some(VariantPayload::Struct(fields)) => {
+                    for field in fields {
+                        apply_import_to_field(field)?;
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
     // note: enums have no type refs.
 
     for ns in namespace.namespaces_mut() {
@@ -265,9 +316,26 @@ fn apply_imports_to_type(
         Type::Array(ty) => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
         }
+        Type::Set(ty) => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
+        }
+        Type::FixedArray { ty, .. } => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
+        }
         Type::Optional(ty) => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
         }
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(
+                    all_entity_ids,
+                    local_entity_ids,
+                    namespace_id,
+                    &mut element.ty,
+                    imports,
+                )?;
+            }
+        }
         Type::Map { key, value } => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, key, imports)?;
             apply_imports_to_type(
@@ -278,23 +346,38 @@ fn apply_imports_to_type(
                 imports,
             )?;
         }
-        Type::Api(id) => {
-            if !local_entity_ids.contains(id) {
-                for import in imports {
-                    if let Some(qualified) = qualify_by_import(all_entity_ids, import, id)? {
-                        *id = qualified;
-                        break;
-                    }
-                }
+        Type::Api(id) => apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?;
+            for arg in args {
+                apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, arg, imports)?;
             }
         }
 
         // Not supported.
         Type::Function { .. } => {} //
+        Type::TypeParam(_) => {}
     };
     Ok(())
 }
 
+fn apply_imports_to_id(
+    all_entity_ids: &HashSet<EntityId>,
+    local_entity_ids: &HashSet<EntityId>,
+    id: &mut EntityId,
+    imports: &[Import],
+) -> Result<()> {
+    if !local_entity_ids.contains(id) {
+        for import in imports {
+            if let Some(qualified) = qualify_by_import(all_entity_ids, import, id)? {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn qualify_by_import(
     all_entity_ids: &HashSet<EntityId>,
     import: &Import,
@@ -532,7 +615,7 @@ mod tests {
     }
 
     mod imports {
-        use crate::model::{Builder, Chunk, EntityId, Model};
+        use crate::model::{Builder, Chunk, EntityId, Model, VariantPayload};
         use crate::test_util::executor::TEST_CONFIG;
         use crate::{input, parser, Parser};
         use anyhow::{anyhow, Result};
@@ -608,6 +691,69 @@ mod tests {
             })
         }
 
+        #[test]
+        fn rpc_error_ty() -> Result<()> {
+            let a = "struct ApiError {}";
+            let test = r#"
+            use a::ApiError;
+            fn rpc() -> Result<u32, ApiError> {}
+            "#;
+
+            run_chunked_test(&[("a", a), ("test", test)], |model| {
+                let actual = model
+                    .api()
+                    .find_rpc(&EntityId::new_unqualified("test.rpc"))
+                    .unwrap()
+                    .error_type
+                    .as_ref()
+                    .ok_or(anyhow!("no error type"))?
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from("ns:a.d:ApiError").unwrap();
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn union_payloads() -> Result<()> {
+            let a = "type Id = u32;";
+            let test = r#"
+            use a::Id;
+            enum Event {
+                Login { user: Id },
+                Move(Id, Id),
+            }
+            "#;
+
+            run_chunked_test(&[("a", a), ("test", test)], |model| {
+                let union = model
+                    .api()
+                    .find_union(&EntityId::new_unqualified("test.Event"))
+                    .unwrap();
+                let expected = EntityId::try_from("ns:a.a:Id").unwrap();
+                match &union.variants[0].payload {
+                    Some(VariantPayload::Struct(fields)) => {
+                        assert_eq!(fields[0].ty.value.api(), Some(&expected))
+                    }
+                    payload => return Err(anyhow!("unexpected payload {:?}", payload)),
+                }
+                match &union.variants[1].payload {
+                    Some(VariantPayload::Tuple(tys)) => {
+                        assert_eq!(tys[1].value.api(), Some(&expected))
+                    }
+                    payload => return Err(anyhow!("unexpected payload {:?}", payload)),
+                }
+                Ok(())
+            })
+        }
+
         #[test]
         fn ty_alias() -> Result<()> {
             let a = "type Id = u32;";
@@ -6,7 +6,9 @@ use itertools::Itertools;
 use crate::model::{Attributes, Field, Namespace, NamespaceChild};
 use crate::parser::error::Error;
 use crate::parser::rust::visibility::Visibility;
-use crate::parser::rust::{attributes, comment, dto, en, rpc, ty, ty_alias, visibility};
+use crate::parser::rust::{
+    attributes, comment, dto, en, expr_block, interface, rpc, ty, ty_alias, value, visibility,
+};
 use crate::parser::{util, Config};
 
 pub fn parser(config: &Config) -> impl Parser<&str, (Namespace, Visibility), Error> {
@@ -21,21 +23,26 @@ pub fn parser(config: &Config) -> impl Parser<&str, (Namespace, Visibility), Err
             .then_ignore(prefix)
             .then(name)
             .then(just(';').padded().map(|_| None).or(body.map(Some)))
-            .map(|((((comments, user), visibility), name), children)| {
-                (
-                    Namespace {
-                        name: Cow::Borrowed(name),
-                        children: children.unwrap_or(vec![]),
-                        attributes: Attributes {
-                            comments,
-                            user,
-                            ..Default::default()
+            .map(
+                |((((mut comments, mut user), visibility), name), children)| {
+                    (
+                        Namespace {
+                            name: Cow::Borrowed(name),
+                            children: children.unwrap_or(vec![]),
+                            attributes: Attributes {
+                                docs: comment::take_docs(&mut comments),
+                                comments,
+                                deprecation: attributes::take_deprecation(&mut user),
+                                user,
+                                ..Default::default()
+                            },
+                            is_virtual: false,
+                            implements: vec![],
                         },
-                        is_virtual: false,
-                    },
-                    visibility,
-                )
-            })
+                        visibility,
+                    )
+                },
+            )
             .boxed()
     })
 }
@@ -49,6 +56,9 @@ pub fn children<'a>(
         dto::parser(config).map(|(c, v)| Some((NamespaceChild::Dto(c), v))),
         rpc::parser(config).map(|(c, v)| Some((NamespaceChild::Rpc(c), v))),
         en::parser().map(|(c, v)| Some((NamespaceChild::Enum(c), v))),
+        en::flags_parser().map(|(c, v)| Some((NamespaceChild::Enum(c), v))),
+        en::union_parser(config).map(|(c, v)| Some((NamespaceChild::Union(c), v))),
+        interface::parser(config).map(|(c, v)| Some((NamespaceChild::Interface(c), v))),
         ty_alias::parser(config).map(|(c, v)| Some((NamespaceChild::TypeAlias(c), v))),
         field(config).map(|(c, v)| Some((NamespaceChild::Field(c), v))),
         namespace.map(|(c, v)| Some((NamespaceChild::Namespace(c), v))),
@@ -72,38 +82,56 @@ fn field(config: &Config) -> impl Parser<&str, (Field, Visibility), Error> {
     let end = just(';');
     let initializer = just('=')
         .padded()
-        .then(any().and_is(end.not()).repeated().slice());
+        .ignore_then(any().and_is(end.not()).repeated().slice());
     let field = util::keyword_ex("const")
         .ignore_then(text::whitespace().at_least(1))
         .ignore_then(text::ident())
         .then_ignore(just(':').padded())
         .then(ty::parser(config))
-        .then_ignore(initializer)
+        .then(initializer)
         .then_ignore(end.padded());
     comment::multi()
         .then(attributes::attributes().padded())
         .then(visibility::parser())
         .then(field)
-        .map(|(((comments, user), visibility), (name, ty))| {
-            (
-                Field {
-                    name,
-                    ty,
-                    attributes: Attributes {
-                        comments,
-                        user,
-                        ..Default::default()
+        .validate(
+            |(((mut comments, mut user), visibility), ((name, ty), initializer)), span, emitter| {
+                let value = value::parse(initializer, &ty).unwrap_or_else(|err| {
+                    emitter.emit(Rich::custom(span, err));
+                    None
+                });
+                (
+                    Field {
+                        name,
+                        ty,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                        is_static: true,
+                        value,
+                        default: None,
                     },
-                    is_static: true,
-                },
-                visibility,
-            )
-        })
+                    visibility,
+                )
+            },
+        )
 }
 
 // Parses to a 'virtual' namespace that will be merged into the DTO with the same name.
+//
+// Type parameters of generic impl blocks, e.g. `impl<T> Dto<T>`, are applied to all children and
+// must match the names of the DTO's type parameters. The DTO's type arguments are ignored.
+//
+// Trait impls, e.g. `impl Trait for Dto`, only record the trait in [Namespace::implements]. Their
+// fns are skipped since they're already declared by the trait.
 pub fn impl_block(config: &Config) -> impl Parser<&str, Namespace, Error> {
-    let prefix = util::keyword_ex("impl").then(text::whitespace().at_least(1));
+    let prefix = util::keyword_ex("impl")
+        .ignore_then(ty::type_params().or_not())
+        .then_ignore(text::whitespace().at_least(1));
 
     let children = choice((
         rpc::parser(config).map(|(c, v)| Some((NamespaceChild::Rpc(c), v))),
@@ -119,20 +147,37 @@ pub fn impl_block(config: &Config) -> impl Parser<&str, Namespace, Error> {
     .collect::<Vec<_>>()
     .map(|v| v.into_iter().flatten().collect_vec());
 
-    comment::multi()
-        .padded()
-        .then_ignore(prefix)
+    let trait_impl = ty::trait_ref(config)
+        .then_ignore(util::keyword_ex("for").padded())
         .then(text::ident())
+        .then_ignore(ty::type_params().or_not())
+        .then_ignore(expr_block::parser().padded())
+        .map(|(interface_id, name)| (name, vec![], vec![interface_id]));
+
+    let inherent_impl = text::ident()
+        .then_ignore(ty::type_params().or_not())
         .then(children.delimited_by(just('{').padded(), just('}').padded()))
-        .map(|((comments, name), children)| Namespace {
-            name: Cow::Borrowed(name),
-            children,
-            attributes: Attributes {
-                comments,
-                ..Default::default()
-            },
-            is_virtual: true,
+        .map(|(name, children)| (name, children, vec![]));
+
+    comment::multi()
+        .padded()
+        .then(prefix)
+        .then(trait_impl.or(inherent_impl))
+        .map(|((comments, type_params), (name, children, implements))| {
+            let mut namespace = Namespace {
+                name: Cow::Borrowed(name),
+                children,
+                attributes: Attributes {
+                    comments,
+                    ..Default::default()
+                },
+                is_virtual: true,
+                implements,
+            };
+            namespace.apply_type_params(&type_params.unwrap_or_default());
+            namespace
         })
+        .boxed()
 }
 
 #[cfg(test)]
@@ -140,7 +185,7 @@ mod tests {
     use anyhow::Result;
     use chumsky::Parser;
 
-    use crate::model::{attributes, Comment, NamespaceChild};
+    use crate::model::{attributes, Comment, EntityId, NamespaceChild, Type, Value};
     use crate::parser::rust::namespace;
     use crate::parser::rust::visibility::Visibility;
     use crate::parser::test_util::wrap_test_err;
@@ -409,6 +454,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn impl_block_trait() -> Result<()> {
+        let namespace = namespace::impl_block(&TEST_CONFIG)
+            .parse(
+                r#"
+                    impl a::Trait for dto {
+                        fn rpc(&self) {}
+                    }
+                    "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(namespace.is_virtual);
+        assert_eq!(namespace.name, "dto");
+        assert_eq!(
+            namespace.implements,
+            vec![EntityId::new_unqualified("a.Trait")]
+        );
+        assert!(namespace.children.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn impl_block_generic() -> Result<()> {
+        let namespace = namespace::impl_block(&TEST_CONFIG)
+            .parse(
+                r#"
+                    impl<T> Page<T> {
+                        fn first(page: Page<T>) -> T {}
+                    }
+                    "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(namespace.name, "Page");
+        let rpc = namespace.rpc("first").unwrap();
+        assert!(rpc.type_params.is_empty());
+        assert_eq!(
+            rpc.return_type.as_ref().map(|ty| &ty.value),
+            Some(&Type::TypeParam("T".to_string()))
+        );
+        Ok(())
+    }
+
     #[test]
     fn impl_block_nested() -> Result<()> {
         let (namespace, _) = namespace::parser(&TEST_CONFIG)
@@ -455,4 +544,32 @@ mod tests {
         assert!(matches!(visibility, Visibility::Private));
         Ok(())
     }
+
+    #[test]
+    fn field_value() -> Result<()> {
+        let (field, _) = namespace::field(&TEST_CONFIG)
+            .parse(r#"const field0: &str = "blahh";"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.value, Some(Value::String("blahh".into())));
+        Ok(())
+    }
+
+    #[test]
+    fn field_non_literal_value() -> Result<()> {
+        let (field, _) = namespace::field(&TEST_CONFIG)
+            .parse(r#"const field0: u32 = 1 << 2;"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.value, None);
+        Ok(())
+    }
+
+    #[test]
+    fn field_unrepresentable_value() {
+        let result = namespace::field(&TEST_CONFIG)
+            .parse(r#"const field0: u128 = 340282366920938463463374607431768211456;"#)
+            .into_result();
+        assert!(result.is_err());
+    }
 }
@@ -1,47 +1,95 @@
 use std::borrow::Cow;
-use crate::model::{Attributes, Field, Rpc};
+use crate::model::{Attributes, Field, Rpc, RpcKind, Streaming, TypeRef};
 use crate::parser::error::Error;
 use crate::parser::rust::visibility::Visibility;
-use crate::parser::rust::{attributes, comment, expr_block, ty, visibility};
+use crate::parser::rust::{attributes, comment, expr_block, ty, value, visibility};
 use crate::parser::{util, Config};
 use chumsky::prelude::*;
 
 pub fn parser(config: &Config) -> impl Parser<&str, (Rpc, Visibility), Error> {
+    let is_async = util::keyword_ex("async")
+        .then(text::whitespace().at_least(1))
+        .or_not()
+        .map(|x| x.is_some());
     let prefix = util::keyword_ex("fn").then(text::whitespace().at_least(1));
     let name = text::ident();
-    let params = params(config).delimited_by(
-        just('(').padded(),
-        just(')').padded().recover_with(skip_then_retry_until(
-            none_of(")").ignored(),
-            just(')').ignored(),
-        )),
+    let params = params(config)
+        .delimited_by(
+            just('(').padded(),
+            just(')').padded().recover_with(skip_then_retry_until(
+                none_of(")").ignored(),
+                just(')').ignored(),
+            )),
+        )
+        .validate(|params: Vec<(Field, bool)>, span, emitter| {
+            // Client streaming applies to the whole rpc, so it can't express which param streams.
+            let streams = params.iter().filter(|(_, is_stream)| *is_stream).count();
+            if streams != 0 && streams != params.len() {
+                emitter.emit(Rich::custom(
+                    span,
+                    "rpcs that stream some but not all of their params are not supported",
+                ));
+            }
+            params
+        });
+    let return_type = just("->").ignore_then(
+        result(config)
+            .or(ty_or_stream(config).map(|ty| (Some(ty), None)))
+            .padded(),
     );
-    let return_type = just("->").ignore_then(ty::parser(config).padded());
     comment::multi()
         .then(attributes::attributes().padded())
         .then(visibility::parser())
+        .then(is_async)
         .then_ignore(prefix)
         .then(name)
+        .then(ty::type_params().or_not())
         .then(is_static())
         .then(params)
         .then(return_type.or_not())
-        .then_ignore(expr_block::parser().padded())
+        // Declarations without a body, e.g. within a `trait`, end with `;` instead.
+        .then_ignore(
+            just(';')
+                .padded()
+                .ignored()
+                .or(expr_block::parser().padded().ignored()),
+        )
         .map(
-            |((((((comments, user), visibility), name), is_static), params), return_type)| {
+            |(
                 (
-                    Rpc {
-                        name: Cow::Borrowed(name),
-                        params,
-                        return_type,
-                        attributes: Attributes {
-                            comments,
-                            user,
-                            ..Default::default()
-                        },
-                        is_static,
+                    ((((((mut comments, mut user), visibility), is_async), name), type_params), is_static),
+                    params,
+                ),
+                return_type,
+            )| {
+                let type_params = type_params.unwrap_or_default();
+                let client_streaming = params.iter().any(|(_, is_stream)| *is_stream);
+                let (return_type, error_type) = return_type.unwrap_or_default();
+                let (return_type, server_streaming) = match return_type {
+                    Some((ty, is_stream)) => (Some(ty), is_stream),
+                    None => (None, false),
+                };
+                let mut rpc = Rpc {
+                    name: Cow::Borrowed(name),
+                    type_params: type_params.clone(),
+                    params: params.into_iter().map(|(param, _)| param).collect(),
+                    return_type,
+                    error_type,
+                    attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
+                        comments,
+                        deprecation: attributes::take_deprecation(&mut user),
+                        user,
+                        ..Default::default()
                     },
-                    visibility,
-                )
+                    is_static,
+                    kind: RpcKind {
+                        is_async,
+                        streaming: Streaming::new(client_streaming, server_streaming),
+                    },
+                };
+                rpc.apply_type_params(&type_params);
+                (rpc, visibility)
             },
         )
 }
@@ -60,26 +108,75 @@ fn self_param<'a>() -> impl Parser<'a, &'a str, Option<&'a str>, Error<'a>> {
         .or_not()
 }
 
-fn param(config: &Config) -> impl Parser<&str, Field, Error> {
+/// A type, or a stream of values of the type, e.g. `impl Stream<Item = T> + Send`. The `bool`
+/// is true for streams.
+fn ty_or_stream<'a>(config: &'a Config) -> impl Parser<'a, &'a str, (TypeRef, bool), Error<'a>> {
+    let path = just("futures::").then(just("stream::").or_not()).or_not();
+    let bound = just('+')
+        .padded()
+        .then(just('\'').or_not())
+        .then(text::ident());
+    let stream = just("impl")
+        .then(text::whitespace().at_least(1))
+        .then(path)
+        .ignore_then(just("Stream<Item"))
+        .ignore_then(just('=').padded())
+        .ignore_then(ty::parser(config))
+        .then_ignore(just('>').padded())
+        .then_ignore(bound.repeated());
+    stream
+        .map(|ty| (ty, true))
+        .or(ty::parser(config).map(|ty| (ty, false)))
+}
+
+/// `Result<T, E>` with an optional `std::result::` path, where `T` may be a stream or `()`.
+fn result<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Option<(TypeRef, bool)>, Option<TypeRef>), Error<'a>> {
+    let ok = just("()").map(|_| None).or(ty_or_stream(config).map(Some));
+    just("std::result::")
+        .or_not()
+        .ignore_then(just("Result<"))
+        .ignore_then(ok.padded())
+        .then_ignore(just(','))
+        .then(ty::parser(config).padded())
+        .then_ignore(just('>'))
+        .map(|(ok, err)| (ok, Some(err)))
+}
+
+fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, (Field<'a>, bool), Error<'a>> {
     let param = text::ident()
         .then_ignore(just(':').padded())
-        .then(ty::parser(config));
+        .then(ty_or_stream(config));
     comment::multi()
         .then(attributes::attributes().padded())
         .then(param)
-        .map(|((comments, user), (name, ty))| Field {
-            name,
-            ty,
-            attributes: Attributes {
-                comments,
-                user,
-                ..Default::default()
+        .validate(
+            |((mut comments, mut user), (name, (ty, is_stream))), span, emitter| {
+                let default = value::take_default(&mut user, &ty).unwrap_or_else(|err| {
+                    emitter.emit(Rich::custom(span, err));
+                    None
+                });
+                let field = Field {
+                    name,
+                    ty,
+                    attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
+                        comments,
+                        deprecation: attributes::take_deprecation(&mut user),
+                        user,
+                        ..Default::default()
+                    },
+                    is_static: false,
+                    value: None,
+                    default,
+                };
+                (field, is_stream)
             },
-            is_static: false,
-        })
+        )
 }
 
-fn params(config: &Config) -> impl Parser<&str, Vec<Field>, Error> {
+fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<(Field<'a>, bool)>, Error<'a>> {
     self_param().ignore_then(
         param(config)
             .separated_by(just(',').padded())
@@ -93,7 +190,9 @@ mod tests {
     use anyhow::Result;
     use chumsky::Parser;
 
-    use crate::model::{attributes, Comment};
+    use crate::model::{
+        attributes, Comment, EntityId, RpcKind, Semantics, Streaming, Type, TypeRef, Value,
+    };
     use crate::parser::rust::rpc;
     use crate::parser::rust::visibility::Visibility;
     use crate::parser::test_util::wrap_test_err;
@@ -225,6 +324,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn docs() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            // regular comment
+
+            /// Summary.
+            ///
+            /// # Arguments
+            ///
+            /// * `a` - The param.
+            ///
+            /// # Returns
+            ///
+            /// The result.
+            fn rpc(a: u32) -> u32 {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["regular comment"])]
+        );
+        let docs = rpc.attributes.docs.unwrap();
+        assert_eq!(docs.summary, Comment::unowned(&["Summary."]));
+        assert_eq!(docs.param("a"), Some(&Comment::unowned(&["The param."])));
+        assert_eq!(docs.returns, Comment::unowned(&["The result."]));
+        Ok(())
+    }
+
     #[test]
     fn single_param() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -250,6 +381,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mut_slice_param() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            fn rpc_name(param0: &mut [u32]) {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.params.len(), 1);
+        assert_eq!(
+            rpc.params[0].ty,
+            TypeRef::new_array(TypeRef::new(Type::U32, Semantics::Value), Semantics::Mut)
+        );
+        Ok(())
+    }
+
     #[test]
     fn nonstatic_param() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -425,6 +574,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn generic() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            fn rpc_name<T, U>(param: T) -> Pair<T, U> {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.type_params, vec!["T", "U"]);
+        assert_eq!(
+            rpc.params[0].ty,
+            TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value)
+        );
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new_generic(
+                EntityId::new_unqualified("Pair"),
+                vec![
+                    TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value),
+                    TypeRef::new(Type::TypeParam("U".to_string()), Semantics::Value),
+                ],
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
     #[test]
     fn return_type_weird_spacing() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -444,6 +622,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn param_default() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                fn rpc(#[default("name")] param0: String, param1: bool) {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.params[0].default, Some(Value::String("name".into())));
+        assert!(rpc.params[0].attributes.user.is_empty());
+        assert_eq!(rpc.params[1].default, None);
+        Ok(())
+    }
+
     #[test]
     fn attributes() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -464,4 +658,153 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn is_async() -> Result<()> {
+        let (rpc, visibility) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                pub async fn rpc(&self) -> u32 {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc");
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            rpc.kind,
+            RpcKind {
+                is_async: true,
+                streaming: Streaming::Unary,
+            }
+        );
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::U32, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn server_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                fn rpc() -> impl futures::Stream<Item = u32> + Send + 'static {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(!rpc.kind.is_async);
+        assert_eq!(rpc.kind.streaming, Streaming::Server);
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::U32, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn client_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                async fn rpc(values: impl Stream<Item = u32>) -> bool {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.kind.is_async);
+        assert_eq!(rpc.kind.streaming, Streaming::Client);
+        assert_eq!(rpc.params[0].ty, TypeRef::new(Type::U32, Semantics::Value));
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::Bool, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn partial_client_streaming() {
+        let result = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                async fn rpc(id: u32, values: impl Stream<Item = u32>) {}
+                "#,
+            )
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bidirectional_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                fn rpc(values: impl Stream<Item = u32>) -> impl Stream<Item = bool> {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.kind.streaming, Streaming::Bidirectional);
+        Ok(())
+    }
+
+    #[test]
+    fn result() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                fn rpc() -> Result<u32, ApiError> {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::U32, Semantics::Value))
+        );
+        assert_eq!(
+            rpc.error_type,
+            Some(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("ApiError")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn result_unit() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                fn rpc() -> std::result::Result<(), ApiError> {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.return_type, None);
+        assert!(rpc.error_type.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn result_stream() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                async fn rpc() -> Result<impl Stream<Item = u32>, ApiError> {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.kind.streaming, Streaming::Server);
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::U32, Semantics::Value))
+        );
+        assert!(rpc.error_type.is_some());
+        Ok(())
+    }
 }
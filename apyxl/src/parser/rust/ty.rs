@@ -1,11 +1,23 @@
 use chumsky::prelude::*;
 
-use crate::model::{EntityId, Semantics, Type, TypeRef};
+use crate::model::{well_known, EntityId, Semantics, Type, TypeRef};
 use crate::parser::error::Error;
 use crate::parser::Config;
 
 const ALLOWED_TYPE_NAME_CHARS: &str = "_<>";
 
+/// Fully qualified Rust spellings of [well_known] types. Bare names, e.g. `Duration`, are parsed as
+/// API types since the API may define its own.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    ("uuid::Uuid", well_known::UUID),
+    ("std::time::SystemTime", well_known::TIMESTAMP),
+    ("chrono::DateTime<chrono::Utc>", well_known::TIMESTAMP),
+    ("chrono::DateTime<Utc>", well_known::TIMESTAMP),
+    ("std::time::Duration", well_known::DURATION),
+    ("rust_decimal::Decimal", well_known::DECIMAL),
+    ("char", well_known::CHAR),
+];
+
 pub fn parser(config: &Config) -> impl Parser<&str, TypeRef, Error> {
     let ref_type = choice((
         just("&mut")
@@ -39,24 +51,48 @@ pub fn parser(config: &Config) -> impl Parser<&str, TypeRef, Error> {
         ))
         .or(choice((
             user_ty(config).map(Type::User),
+            well_known_ty(config).map(Type::User),
             vec(nested.clone()),
+            set(nested.clone()),
             map(nested.clone()),
-            option(nested),
-            // Note that entity_id should come last because it is greedy.
-            entity_id().map(Type::Api),
+            option(nested.clone()),
+            fixed_array(nested.clone()),
+            tuple(nested.clone()),
+            // Note that api_ty should come last because it is greedy.
+            api_ty(nested.clone()),
         )))
         .boxed();
-        ref_type
-            .or_not()
-            .then(ty)
-            .map(|(semantics, ty)| TypeRef {
+        choice((
+            slice(nested),
+            pointer(ty.clone()),
+            ref_type.or_not().then(ty).map(|(semantics, ty)| TypeRef {
                 value: ty,
                 semantics: semantics.unwrap_or(Semantics::Value),
-            })
-            .boxed()
+            }),
+        ))
+        .boxed()
+    })
+}
+
+/// Generic type parameters, e.g. `<T, U>`. Bounds are not supported.
+/// A reference to a trait, e.g. `Trait` in `impl Trait for Dto`. Type arguments are ignored.
+pub fn trait_ref<'a>(config: &'a Config) -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    parser(config).try_map(|ty, span| match ty.value {
+        Type::Api(id) | Type::Generic { ty: id, .. } => Ok(id),
+        _ => Err(Rich::custom(span, "expected a trait")),
     })
 }
 
+pub fn type_params<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .padded()
+        .separated_by(just(','))
+        .allow_trailing()
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .delimited_by(just('<'), just('>'))
+}
+
 fn type_name<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
     any()
         // first char
@@ -81,6 +117,19 @@ fn vec<'a>(
         .map(Type::new_array)
 }
 
+/// e.g. `HashSet<T>` or `BTreeSet<T>`.
+fn set<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    just("HashSet<")
+        .or(just("BTreeSet<"))
+        .then_ignore(text::whitespace())
+        .ignore_then(ty)
+        .then_ignore(text::whitespace())
+        .then_ignore(just('>'))
+        .map(Type::new_set)
+}
+
 fn map<'a>(
     ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
 ) -> impl Parser<'a, &'a str, Type, Error<'a>> {
@@ -105,6 +154,72 @@ fn option<'a>(
         .map(Type::new_optional)
 }
 
+/// e.g. `&[T]`, `&[u8]` or `&str`. Mutable slices, e.g. `&mut [T]`, have [Semantics::Mut].
+fn slice<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    let elems = choice((
+        just("[u8]").map(|_| Type::Bytes),
+        ty.padded()
+            .delimited_by(just('['), just(']'))
+            .map(Type::new_array),
+    ));
+    choice((
+        just('&')
+            .ignore_then(choice((
+                just("str").map(|_| Type::StringView),
+                elems.clone(),
+            )))
+            .map(|ty| TypeRef::new(ty, Semantics::Slice)),
+        just("&mut")
+            .then(text::whitespace().at_least(1))
+            .ignore_then(elems)
+            .map(|ty| TypeRef::new(ty, Semantics::Mut)),
+    ))
+}
+
+/// e.g. `Box<T>`, `Rc<T>` or `Arc<T>`.
+fn pointer<'a>(
+    ty: impl Parser<'a, &'a str, Type, Error<'a>>,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    choice((
+        just("Box<").map(|_| Semantics::Boxed),
+        just("Rc<").map(|_| Semantics::Shared),
+        just("Arc<").map(|_| Semantics::Shared),
+    ))
+    .then(ty.padded())
+    .then_ignore(just('>'))
+    .map(|(semantics, ty)| TypeRef::new(ty, semantics))
+}
+
+/// e.g. `[u8; 32]`.
+fn fixed_array<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let len = text::int(10).try_map(|len: &str, span| {
+        len.parse::<usize>()
+            .map_err(|_| Rich::custom(span, "invalid array length"))
+    });
+    ty.padded()
+        .then_ignore(just(';'))
+        .then(len.padded())
+        .delimited_by(just('['), just(']'))
+        .map(|(ty, len)| Type::new_fixed_array(ty, len))
+}
+
+/// e.g. `(u32, String)`. The unit type `()` is not a tuple.
+fn tuple<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    ty.padded()
+        .separated_by(just(','))
+        .allow_trailing()
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .delimited_by(just('('), just(')'))
+        .map(Type::new_tuple)
+}
+
 fn user_ty(config: &Config) -> impl Parser<&str, String, Error> {
     custom(move |input| {
         for (i, ty) in config.user_types.iter().enumerate() {
@@ -131,6 +246,52 @@ fn user_ty(config: &Config) -> impl Parser<&str, String, Error> {
     })
 }
 
+/// One of the [WELL_KNOWN_TYPES] that is enabled in `config`. The spelling must not be followed by
+/// more of a type name, e.g. `Duration` matches but `DurationRange` does not.
+fn well_known_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        let start = input.offset();
+        for (parse, name) in WELL_KNOWN_TYPES {
+            if !config.is_well_known_type_enabled(name) {
+                continue;
+            }
+            let marker = input.save();
+            let spelling = just(*parse).then_ignore(
+                any()
+                    .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == ':')
+                    .not(),
+            );
+            if input.parse(spelling).is_ok() {
+                return Ok(name.to_string());
+            }
+            input.rewind(marker);
+        }
+        Err(Rich::custom(
+            input.span_since(start),
+            "not a well-known type",
+        ))
+    })
+}
+
+/// A type within the API, optionally with generic type arguments, e.g. `a::Page<u32>`.
+fn api_ty<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let args = ty
+        .padded()
+        .separated_by(just(','))
+        .allow_trailing()
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .delimited_by(just('<'), just('>'));
+    entity_id()
+        .then(args.or_not())
+        .map(|(id, args)| match args {
+            Some(args) => Type::new_generic(id, args),
+            None => Type::Api(id),
+        })
+}
+
 fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
     type_name()
         .separated_by(just("::"))
@@ -146,7 +307,7 @@ mod tests {
         use chumsky::Parser;
         use lazy_static::lazy_static;
 
-        use crate::model::{EntityId, Semantics, Type, TypeRef};
+        use crate::model::{well_known, EntityId, Semantics, Type, TypeRef};
         use crate::parser::rust::ty;
         use crate::parser::test_util::wrap_test_err;
         use crate::parser::{Config, UserType};
@@ -157,6 +318,7 @@ mod tests {
                     parse: "user_type".to_string(),
                     name: "user".to_string()
                 }],
+                disabled_well_known_types: vec![],
                 enable_parse_private: true,
             };
         }
@@ -236,7 +398,16 @@ mod tests {
             TypeRef::new(Type::String, Semantics::Mut)
         );
 
-        test!(str, "&str", TypeRef::new(Type::StringView, Semantics::Ref));
+        test!(
+            str,
+            "&str",
+            TypeRef::new(Type::StringView, Semantics::Slice)
+        );
+        test!(
+            str_mut,
+            "&mut str",
+            TypeRef::new(Type::StringView, Semantics::Mut)
+        );
 
         test!(
             bytes_mut,
@@ -247,7 +418,69 @@ mod tests {
         test!(
             bytes_slice,
             "&[u8]",
-            TypeRef::new(Type::Bytes, Semantics::Ref)
+            TypeRef::new(Type::Bytes, Semantics::Slice)
+        );
+        test!(
+            slice,
+            "&[a::B]",
+            TypeRef::new_array(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.B")),
+                    Semantics::Value
+                ),
+                Semantics::Slice
+            )
+        );
+        test!(
+            slice_mut,
+            "&mut [a::B]",
+            TypeRef::new_array(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.B")),
+                    Semantics::Value
+                ),
+                Semantics::Mut
+            )
+        );
+        test!(
+            bytes_slice_mut,
+            "&mut [u8]",
+            TypeRef::new(Type::Bytes, Semantics::Mut)
+        );
+
+        // Pointers.
+        test!(boxed, "Box<u32>", TypeRef::new(Type::U32, Semantics::Boxed));
+        test!(
+            boxed_str,
+            "Box<str>",
+            TypeRef::new(Type::StringView, Semantics::Boxed)
+        );
+        test!(
+            rc,
+            "Rc<a::B>",
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("a.B")),
+                Semantics::Shared
+            )
+        );
+        test!(
+            arc,
+            "Arc<Vec<String>>",
+            TypeRef::new_array(
+                TypeRef::new(Type::String, Semantics::Value),
+                Semantics::Shared
+            )
+        );
+        test!(
+            pointer_nested,
+            "Vec<Box<a::B>>",
+            TypeRef::new_array(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.B")),
+                    Semantics::Boxed
+                ),
+                Semantics::Value
+            )
         );
         test!(
             entity_id,
@@ -309,6 +542,24 @@ mod tests {
             )
         );
 
+        // Set.
+        test!(
+            set,
+            "HashSet<a::b::c>",
+            TypeRef::new_set(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.b.c")),
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+        test!(
+            btree_set,
+            "BTreeSet<String>",
+            TypeRef::new_set(TypeRef::new(Type::String, Semantics::Value), Semantics::Value)
+        );
+
         // Map.
         test!(
             map,
@@ -426,6 +677,96 @@ mod tests {
             )
         );
 
+        test!(
+            generic,
+            "Page<u32, a::B>",
+            TypeRef::new_generic(
+                EntityId::new_unqualified("Page"),
+                vec![
+                    TypeRef::new(Type::U32, Semantics::Value),
+                    TypeRef::new(
+                        Type::Api(EntityId::new_unqualified("a.B")),
+                        Semantics::Value
+                    ),
+                ],
+                Semantics::Value
+            )
+        );
+
+        test!(
+            generic_nested,
+            "&a::Page<Vec<Page<u8>>>",
+            TypeRef::new_generic(
+                EntityId::new_unqualified("a.Page"),
+                vec![TypeRef::new_array(
+                    TypeRef::new_generic(
+                        EntityId::new_unqualified("Page"),
+                        vec![TypeRef::new(Type::U8, Semantics::Value)],
+                        Semantics::Value
+                    ),
+                    Semantics::Value
+                )],
+                Semantics::Ref
+            )
+        );
+
+        test!(
+            fixed_array,
+            "[u8; 32]",
+            TypeRef::new_fixed_array(TypeRef::new(Type::U8, Semantics::Value), 32, Semantics::Value)
+        );
+        test!(
+            fixed_array_nested,
+            "&[[a::B; 2];4]",
+            TypeRef::new_fixed_array(
+                TypeRef::new_fixed_array(
+                    TypeRef::new(
+                        Type::Api(EntityId::new_unqualified("a.B")),
+                        Semantics::Value
+                    ),
+                    2,
+                    Semantics::Value
+                ),
+                4,
+                Semantics::Ref
+            )
+        );
+
+        test!(
+            tuple,
+            "(u32, &String)",
+            TypeRef::new_tuple(
+                [
+                    TypeRef::new(Type::U32, Semantics::Value),
+                    TypeRef::new(Type::String, Semantics::Ref),
+                ],
+                Semantics::Value
+            )
+        );
+        test!(
+            tuple_nested,
+            "Vec<(a::B, (f32, f32))>",
+            TypeRef::new_array(
+                TypeRef::new_tuple(
+                    [
+                        TypeRef::new(
+                            Type::Api(EntityId::new_unqualified("a.B")),
+                            Semantics::Value
+                        ),
+                        TypeRef::new_tuple(
+                            [
+                                TypeRef::new(Type::F32, Semantics::Value),
+                                TypeRef::new(Type::F32, Semantics::Value),
+                            ],
+                            Semantics::Value
+                        ),
+                    ],
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+
         // Defined in CONFIG.
         test!(
             user,
@@ -433,6 +774,40 @@ mod tests {
             TypeRef::new(Type::User("user".to_string()), Semantics::Value)
         );
 
+        // Well-known.
+        test!(
+            well_known,
+            "uuid::Uuid",
+            TypeRef::new(Type::User(well_known::UUID.to_string()), Semantics::Value)
+        );
+        test!(
+            well_known_generic,
+            "Vec<chrono::DateTime<chrono::Utc>>",
+            TypeRef::new_array(
+                TypeRef::new(
+                    Type::User(well_known::TIMESTAMP.to_string()),
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+        test!(
+            well_known_bare,
+            "Duration",
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Duration")),
+                Semantics::Value
+            )
+        );
+        test!(
+            well_known_prefix,
+            "DurationRange",
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("DurationRange")),
+                Semantics::Value
+            )
+        );
+
         fn run_test(data: &'static str, expected: TypeRef) -> Result<()> {
             let ty = ty::parser(&TY_TEST_CONFIG)
                 .parse(data)
@@ -443,6 +818,34 @@ mod tests {
         }
     }
 
+    mod type_params {
+        use anyhow::Result;
+        use chumsky::Parser;
+
+        use crate::parser::rust::ty::type_params;
+        use crate::parser::test_util::wrap_test_err;
+
+        #[test]
+        fn single() -> Result<()> {
+            let params = type_params()
+                .parse("<T>")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(params, vec!["T"]);
+            Ok(())
+        }
+
+        #[test]
+        fn multiple() -> Result<()> {
+            let params = type_params()
+                .parse("< K, V ,>")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(params, vec!["K", "V"]);
+            Ok(())
+        }
+    }
+
     mod user_ty {
         use chumsky::Parser;
 
@@ -471,6 +874,26 @@ mod tests {
         }
     }
 
+    mod well_known_ty {
+        use chumsky::Parser;
+
+        use crate::model::well_known;
+        use crate::parser::rust::ty::well_known_ty;
+        use crate::parser::Config;
+
+        #[test]
+        fn disabled() {
+            let config = Config {
+                disabled_well_known_types: vec![well_known::DECIMAL.to_string()],
+                ..Default::default()
+            };
+            let ty = well_known_ty(&config).parse("char").into_output();
+            assert_eq!(ty, Some(well_known::CHAR.to_string()));
+            let ty = well_known_ty(&config).parse("rust_decimal::Decimal").into_output();
+            assert_eq!(ty, None);
+        }
+    }
+
     mod entity_id {
         use anyhow::Result;
         use chumsky::Parser;
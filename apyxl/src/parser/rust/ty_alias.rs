@@ -14,17 +14,23 @@ pub fn parser(config: &Config) -> impl Parser<&str, (TypeAlias, Visibility), Err
         .then(visibility::parser())
         .then_ignore(prefix)
         .then(text::ident())
+        .then(ty::type_params().or_not())
         .then_ignore(just("=").padded())
         .then(ty::parser(config))
         .then_ignore(just(';'))
         .padded()
-        .map(|((((comments, user), visibility), name), target)| {
+        .map(|(((((mut comments, mut user), visibility), name), type_params), mut target)| {
+            let type_params = type_params.unwrap_or_default();
+            target.apply_type_params(&type_params);
             (
                 TypeAlias {
                     name,
+                    type_params,
                     target_ty: target,
                     attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
                         comments,
+                        deprecation: attributes::take_deprecation(&mut user),
                         user,
                         ..Default::default()
                     },
@@ -74,4 +80,23 @@ mod tests {
         assert_eq!(alias.target_ty, TypeRef::new(Type::U32, Semantics::Value));
         Ok(())
     }
+
+    #[test]
+    fn generic() -> Result<()> {
+        let (alias, _) = ty_alias::parser(&TEST_CONFIG)
+            .parse("type Map<V> = HashMap<String, V>;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "Map");
+        assert_eq!(alias.type_params, vec!["V"]);
+        assert_eq!(
+            alias.target_ty,
+            TypeRef::new_map(
+                TypeRef::new(Type::String, Semantics::Value),
+                TypeRef::new(Type::TypeParam("V".to_string()), Semantics::Value),
+                Semantics::Value
+            )
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,258 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+use std::borrow::Cow;
+use std::str::Chars;
+
+use crate::model::{attributes, TypeRef, Value};
+use crate::parser::error::Error;
+use crate::parser::util;
+
+/// Literal value of a constant with type `ty`, e.g. `5`, `-1.5`, `"str"`, `'c'`, `true`, or
+/// `Status::Active`. Anything else, e.g. expressions like `1 << 2` or references to other
+/// constants, is `None`. Literals that can't be represented, e.g. integers above `u128::MAX`, are
+/// an error.
+pub fn parse<'a>(initializer: &'a str, ty: &TypeRef) -> Result<Option<Value<'a>>, &'static str> {
+    let initializer = initializer.trim();
+    match literal().parse(initializer).into_result() {
+        Ok(value) => value.map(Some),
+        Err(_) => Ok(path()
+            .parse(initializer)
+            .into_result()
+            .ok()
+            .and_then(|path| util::enum_value(&path, ty))),
+    }
+}
+
+/// Removes a `#[default(<value>)]` attribute from `user` and returns its value, if it is a
+/// literal of type `ty`. Other `default` attributes are left untouched.
+pub fn take_default<'a>(
+    user: &mut Vec<attributes::User<'a>>,
+    ty: &TypeRef,
+) -> Result<Option<Value<'a>>, &'static str> {
+    let Some(index) = user.iter().position(|attr| attr.name == "default") else {
+        return Ok(None);
+    };
+    let value = match user[index].data.as_slice() {
+        [attributes::UserData {
+            key: None,
+            value: Cow::Borrowed(value),
+        }] => parse(value, ty)?,
+        _ => None,
+    };
+    if value.is_some() {
+        user.remove(index);
+    }
+    Ok(value)
+}
+
+/// A literal or path as written, e.g. `5`, `"str"`, `ident`, or `Status::Active`.
+pub fn token<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    literal()
+        .ignored()
+        .or(text::ident().separated_by(just("::")).at_least(1))
+        .slice()
+}
+
+/// A literal, or the reason it can't be represented as a [Value].
+fn literal<'a>() -> impl Parser<'a, &'a str, Result<Value<'a>, &'static str>, Error<'a>> {
+    let boolean = choice((
+        util::keyword_ex("true").to(Ok(Value::Bool(true))),
+        util::keyword_ex("false").to(Ok(Value::Bool(false))),
+    ));
+    let escape = just('\\').then(any()).ignored();
+    let string = escape
+        .or(none_of("\\\"").ignored())
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'))
+        .map(|s| {
+            util::unescape(s, escape_sequence)
+                .map(Value::String)
+                .ok_or("invalid escape sequence")
+        });
+    let char = escape
+        .or(none_of("\\'").ignored())
+        .repeated()
+        .at_least(1)
+        .slice()
+        .delimited_by(just('\''), just('\''))
+        .map(|s| {
+            util::unescape(s, escape_sequence)
+                .and_then(|s| s.chars().exactly_one().ok())
+                .map(Value::Char)
+                .ok_or("invalid char literal")
+        });
+    let number = just('-')
+        .or_not()
+        .then(text::digits(10))
+        .then(
+            any()
+                .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+                .repeated(),
+        )
+        .slice()
+        .map(|s| number(s).ok_or("invalid number literal"));
+    choice((boolean, string, char, number))
+}
+
+/// Integer or float literal with optional `_` separators, `0x`/`0o`/`0b` prefixes, and type
+/// suffixes, e.g. `1_000u32` or `2.5f64`.
+fn number(s: &str) -> Option<Value<'_>> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let s = s.replace('_', "");
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(digits) = s.strip_prefix(prefix) {
+            let digits = digits.split(['i', 'u']).next()?;
+            return u128::from_str_radix(digits, radix)
+                .ok()
+                .and_then(|n| util::int_value(negative, n));
+        }
+    }
+    let (digits, suffix) = s.split_at(s.find(['i', 'u', 'f']).unwrap_or(s.len()));
+    if suffix.starts_with('f') || digits.contains(['.', 'e', 'E']) {
+        let n = digits.parse::<f64>().ok()?;
+        Some(Value::Float(if negative { -n } else { n }))
+    } else {
+        digits
+            .parse::<u128>()
+            .ok()
+            .and_then(|n| util::int_value(negative, n))
+    }
+}
+
+/// Rust string or char escape sequence following a `\`, e.g. `n`, `x41`, or `u{1F600}`.
+fn escape_sequence(chars: &mut Chars) -> Option<char> {
+    Some(match chars.next()? {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        '0' => '\0',
+        c @ ('\\' | '\'' | '"') => c,
+        'x' => util::hex_escape(chars, 2, 2)?,
+        'u' => {
+            let (digits, rest) = chars.as_str().strip_prefix('{')?.split_once('}')?;
+            let c = char::from_u32(u32::from_str_radix(&digits.replace('_', ""), 16).ok()?)?;
+            *chars = rest.chars();
+            c
+        }
+        _ => return None,
+    })
+}
+
+fn path<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .separated_by(just("::"))
+        .at_least(2)
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{EntityId, Semantics, Type, TypeRef, Value};
+    use crate::parser::rust::value;
+
+    #[test]
+    fn int() {
+        let ty = TypeRef::new(Type::I32, Semantics::Value);
+        assert_eq!(value::parse("5", &ty), Ok(Some(Value::Int(5))));
+        assert_eq!(value::parse("-5", &ty), Ok(Some(Value::Int(-5))));
+        assert_eq!(value::parse("1_000u32", &ty), Ok(Some(Value::Int(1000))));
+        assert_eq!(value::parse("0xff", &ty), Ok(Some(Value::Int(255))));
+        assert_eq!(value::parse("0b101", &ty), Ok(Some(Value::Int(5))));
+        assert_eq!(
+            value::parse("-9223372036854775808", &ty),
+            Ok(Some(Value::Int(i64::MIN)))
+        );
+    }
+
+    #[test]
+    fn uint() {
+        let ty = TypeRef::new(Type::U64, Semantics::Value);
+        assert_eq!(
+            value::parse("18_446_744_073_709_551_615u64", &ty),
+            Ok(Some(Value::UInt(u64::MAX as u128)))
+        );
+        assert_eq!(
+            value::parse("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", &ty),
+            Ok(Some(Value::UInt(u128::MAX)))
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let ty = TypeRef::new(Type::U128, Semantics::Value);
+        assert!(value::parse("340282366920938463463374607431768211456", &ty).is_err());
+        assert!(value::parse("-9223372036854775809", &ty).is_err());
+    }
+
+    #[test]
+    fn float() {
+        let ty = TypeRef::new(Type::F64, Semantics::Value);
+        assert_eq!(value::parse("1.5", &ty), Ok(Some(Value::Float(1.5))));
+        assert_eq!(value::parse("-2.5f32", &ty), Ok(Some(Value::Float(-2.5))));
+        assert_eq!(value::parse("1e3", &ty), Ok(Some(Value::Float(1000.0))));
+        assert_eq!(value::parse("2f64", &ty), Ok(Some(Value::Float(2.0))));
+    }
+
+    #[test]
+    fn bool() {
+        let ty = TypeRef::new(Type::Bool, Semantics::Value);
+        assert_eq!(value::parse("true", &ty), Ok(Some(Value::Bool(true))));
+        assert_eq!(value::parse("false", &ty), Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn char() {
+        let ty = TypeRef::new(Type::User("char".to_string()), Semantics::Value);
+        assert_eq!(value::parse("'a'", &ty), Ok(Some(Value::Char('a'))));
+        assert_eq!(value::parse(r"'\''", &ty), Ok(Some(Value::Char('\''))));
+        assert_eq!(value::parse(r"'\u{e9}'", &ty), Ok(Some(Value::Char('\u{e9}'))));
+        assert!(value::parse("'ab'", &ty).is_err());
+    }
+
+    #[test]
+    fn string() {
+        let ty = TypeRef::new(Type::StringView, Semantics::Ref);
+        assert_eq!(
+            value::parse(r#""name""#, &ty),
+            Ok(Some(Value::String("name".into())))
+        );
+        assert_eq!(
+            value::parse(r#""a \"b\"""#, &ty),
+            Ok(Some(Value::String(r#"a "b""#.into())))
+        );
+        assert_eq!(
+            value::parse(r#""\t\x41\u{e9}\\""#, &ty),
+            Ok(Some(Value::String("\tA\u{e9}\\".into())))
+        );
+        assert!(value::parse(r#""\q""#, &ty).is_err());
+    }
+
+    #[test]
+    fn enum_value() {
+        let ty = TypeRef::new(
+            Type::Api(EntityId::new_unqualified("ns.Status")),
+            Semantics::Value,
+        );
+        assert_eq!(
+            value::parse("Status::Active", &ty),
+            Ok(Some(Value::EnumValue("Active".into())))
+        );
+        assert_eq!(
+            value::parse("ns::Status::Active", &ty),
+            Ok(Some(Value::EnumValue("Active".into())))
+        );
+        assert_eq!(value::parse("Other::Active", &ty), Ok(None));
+    }
+
+    #[test]
+    fn not_literal() {
+        let ty = TypeRef::new(Type::U32, Semantics::Value);
+        assert_eq!(value::parse("1 << 2", &ty), Ok(None));
+        assert_eq!(value::parse("OTHER_CONST", &ty), Ok(None));
+        assert_eq!(value::parse("u32::MAX", &ty), Ok(None));
+    }
+}
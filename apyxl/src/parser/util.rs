@@ -1,3 +1,8 @@
+use std::borrow::Cow;
+use std::str::Chars;
+
+use crate::model::attributes::User;
+use crate::model::{Deprecation, Type, TypeRef, Value};
 use crate::parser::error::Error;
 use chumsky::error::Rich;
 use chumsky::{text, Parser};
@@ -14,3 +19,100 @@ pub fn keyword_ex(keyword: &str) -> impl Parser<&str, &str, Error> {
         })
         .slice()
 }
+
+/// [Value::EnumValue] for a `path` like `Status::Active` if the path's qualifier names the type
+/// `ty`, e.g. `Status`. Other paths, like references to other constants, are not literals.
+pub fn enum_value<'a>(path: &[&'a str], ty: &TypeRef) -> Option<Value<'a>> {
+    let (name, qualifier) = path.split_last()?;
+    let ty_name = match &ty.value {
+        Type::Api(id) => id.component_names().last()?,
+        Type::Optional(ty) => return enum_value(path, ty),
+        _ => return None,
+    };
+    (qualifier.last() == Some(&ty_name)).then_some(Value::EnumValue(Cow::Borrowed(name)))
+}
+
+/// [Value::Int] for an integer literal with magnitude `n`, or [Value::UInt] if it's above
+/// `i64::MAX`. `None` if the value can't be represented.
+pub fn int_value<'a>(negative: bool, n: u128) -> Option<Value<'a>> {
+    if negative {
+        i64::try_from(-i128::try_from(n).ok()?).ok().map(Value::Int)
+    } else {
+        Some(i64::try_from(n).map_or(Value::UInt(n), Value::Int))
+    }
+}
+
+/// Contents `s` of a string literal with its escape sequences resolved. `escape` is called with the
+/// characters following each `\\` and returns the escaped char, consuming the rest of the
+/// sequence. `None` if any sequence is invalid.
+pub fn unescape<'a>(
+    s: &'a str,
+    escape: impl Fn(&mut Chars) -> Option<char>,
+) -> Option<Cow<'a, str>> {
+    if !s.contains('\\') {
+        return Some(Cow::Borrowed(s));
+    }
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            unescaped.push(escape(&mut chars)?);
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(Cow::Owned(unescaped))
+}
+
+/// Char with the code point written as `min..=max` hex digits at the start of `chars`, e.g. the
+/// `41` in `\x41`, consuming the digits.
+pub fn hex_escape(chars: &mut Chars, min: usize, max: usize) -> Option<char> {
+    let s = chars.as_str();
+    let len = s
+        .bytes()
+        .take(max)
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count();
+    if len < min {
+        return None;
+    }
+    let c = char::from_u32(u32::from_str_radix(&s[..len], 16).ok()?)?;
+    *chars = s[len..].chars();
+    Some(c)
+}
+
+/// Removes the first user attribute named one of `names`, e.g. `deprecated`, from `user` and
+/// returns it as a [Deprecation]. `since`, `note`, and `replacement` data are read by key and the
+/// first value without a key is the note, e.g. `deprecated("note")`. Quotes around string values
+/// are removed.
+pub fn take_deprecation<'a>(user: &mut Vec<User<'a>>, names: &[&str]) -> Option<Deprecation<'a>> {
+    let index = user
+        .iter()
+        .position(|attr| names.contains(&attr.name.as_ref()))?;
+    let mut deprecation = Deprecation::default();
+    for data in user.remove(index).data {
+        let value = Some(unquote(data.value));
+        match data.key.as_deref() {
+            Some("since") => deprecation.since = value,
+            Some("note") => deprecation.note = value,
+            Some("replacement") => deprecation.replacement = value,
+            None if deprecation.note.is_none() => deprecation.note = value,
+            _ => {}
+        }
+    }
+    Some(deprecation)
+}
+
+fn unquote(value: Cow<str>) -> Cow<str> {
+    match value {
+        Cow::Borrowed(s) => Cow::Borrowed(strip_quotes(s).unwrap_or(s)),
+        Cow::Owned(s) => match strip_quotes(&s) {
+            Some(unquoted) => Cow::Owned(unquoted.to_string()),
+            None => Cow::Owned(s),
+        },
+    }
+}
+
+fn strip_quotes(s: &str) -> Option<&str> {
+    s.strip_prefix('"')?.strip_suffix('"')
+}
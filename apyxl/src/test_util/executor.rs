@@ -12,11 +12,13 @@ lazy_static! {
     pub static ref TEST_CONFIG: parser::Config = parser::Config {
         user_types: vec![],
         // Parse private so tests don't have to specify `pub` on _everything_.
+        disabled_well_known_types: vec![],
         enable_parse_private: true,
     };
 
     pub static ref TEST_PUB_ONLY_CONFIG: parser::Config = parser::Config {
         user_types: vec![],
+        disabled_well_known_types: vec![],
         enable_parse_private: false,
     };
 }
@@ -37,6 +37,7 @@ pub fn test_enum(i: usize) -> model::Enum<'static> {
 pub fn test_ty_alias(i: usize) -> model::TypeAlias<'static> {
     model::TypeAlias {
         name: NAMES[i],
+        type_params: vec![],
         target_ty: TypeRef::new(Type::U32, Semantics::Value),
         attributes: Default::default(),
     }
@@ -48,5 +49,7 @@ pub fn test_field(i: usize) -> model::Field<'static> {
         ty: TypeRef::new(Type::U32, Semantics::Value),
         attributes: Default::default(),
         is_static: false,
+        value: None,
+        default: None,
     }
 }
@@ -47,6 +47,14 @@ impl<'v, 'a> Attributes<'v, 'a> {
         }
         attrs
     }
+
+    pub fn deprecation(&self) -> Option<&'v model::Deprecation<'a>> {
+        self.target.deprecation.as_ref()
+    }
+
+    pub fn docs(&self) -> Option<&'v model::Docs<'a>> {
+        self.target.docs.as_deref()
+    }
 }
 
 pub trait AttributeTransform: Debug + DynClone {
@@ -94,6 +102,54 @@ mod tests {
         assert_eq!(attr.user()[1].name.as_ref(), "nice_attr");
     }
 
+    #[test]
+    fn deprecation() {
+        let mut exe = TestExecutor::new(
+            r#"
+                    #[deprecated(since = "1.0")]
+                    struct dto {}
+                "#,
+        );
+        let model = exe.build();
+        let view = model.view();
+        let root = view.api();
+        let dto = root
+            .find_dto(&model::EntityId::try_from("d:dto").unwrap())
+            .unwrap();
+        assert_eq!(
+            dto.attributes().deprecation(),
+            Some(&model::Deprecation {
+                since: Some(Cow::Borrowed("1.0")),
+                ..Default::default()
+            })
+        );
+        assert!(dto.attributes().user().is_empty());
+    }
+
+    #[test]
+    fn docs() {
+        let mut exe = TestExecutor::new(
+            r#"
+                    /// Summary.
+                    ///
+                    /// # See also
+                    ///
+                    /// * [`other`]
+                    struct dto {}
+                "#,
+        );
+        let model = exe.build();
+        let view = model.view();
+        let root = view.api();
+        let dto = root
+            .find_dto(&model::EntityId::try_from("d:dto").unwrap())
+            .unwrap();
+        let docs = dto.attributes().docs().unwrap();
+        assert_eq!(docs.summary, model::Comment::unowned(&["Summary."]));
+        assert_eq!(docs.see_also, vec![Cow::Borrowed("other")]);
+        assert!(dto.attributes().comments().is_empty());
+    }
+
     #[derive(Debug, Clone)]
     struct WordFilterTransform {}
     impl AttributeTransform for WordFilterTransform {
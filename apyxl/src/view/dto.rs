@@ -1,7 +1,7 @@
 use crate::model;
 use crate::model::entity::ToEntity;
 use crate::model::EntityType;
-use crate::view::{Attributes, Field, Namespace, Rpc, Transforms};
+use crate::view::{Attributes, EntityId, Field, Namespace, Rpc, Transforms};
 use dyn_clone::DynClone;
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -11,6 +11,7 @@ use std::fmt::Debug;
 #[derive(Debug, Copy, Clone)]
 pub struct Dto<'v, 'a> {
     target: &'v model::Dto<'a>,
+    root: &'v model::Namespace<'a>,
     xforms: &'v Transforms,
 }
 
@@ -28,13 +29,38 @@ pub trait DtoTransform: Debug + DynClone {
     fn filter_rpc(&self, _: &model::Rpc) -> bool {
         true
     }
+
+    /// `true`: fields of base [Dto]s are included in derived [Dto]s, which then have no bases.
+    /// `false`: unchanged.
+    fn flatten_inheritance(&self) -> bool {
+        false
+    }
 }
 
 dyn_clone::clone_trait_object!(DtoTransform);
 
+/// Flattens inherited fields into derived [Dto]s for targets that don't support inheritance.
+#[derive(Debug, Default, Clone)]
+pub struct FlattenInheritance;
+
+impl DtoTransform for FlattenInheritance {
+    fn flatten_inheritance(&self) -> bool {
+        true
+    }
+}
+
 impl<'v, 'a> Dto<'v, 'a> {
-    pub fn new(target: &'v model::Dto<'a>, xforms: &'v Transforms) -> Self {
-        Self { target, xforms }
+    /// `root` is the root of the API, used to resolve base [Dto]s.
+    pub fn new(
+        target: &'v model::Dto<'a>,
+        root: &'v model::Namespace<'a>,
+        xforms: &'v Transforms,
+    ) -> Self {
+        Self {
+            target,
+            root,
+            xforms,
+        }
     }
 
     pub fn name(&self) -> Cow<str> {
@@ -49,10 +75,20 @@ impl<'v, 'a> Dto<'v, 'a> {
         self.target.entity_type()
     }
 
+    pub fn type_params(&self) -> &'v [&'a str] {
+        &self.target.type_params
+    }
+
+    /// Fields of this [Dto], preceded by the fields of its base [Dto]s if inheritance is
+    /// flattened. See [FlattenInheritance].
     pub fn fields(&'a self) -> impl Iterator<Item = Field<'v, 'a>> {
-        self.target
-            .fields
-            .iter()
+        let mut fields = Vec::new();
+        if self.flatten_inheritance() {
+            self.collect_inherited_fields(self.target, &mut vec![], &mut fields);
+        }
+        fields.extend(&self.target.fields);
+        fields
+            .into_iter()
             .filter(|field| self.filter_field(field))
             .map(move |field| {
                 Field::new(
@@ -77,6 +113,26 @@ impl<'v, 'a> Dto<'v, 'a> {
             })
     }
 
+    /// Base [Dto]s of this [Dto]. Empty if inheritance is flattened.
+    pub fn extends(&self) -> impl Iterator<Item = EntityId<'v>> {
+        let extends = if self.flatten_inheritance() {
+            &[]
+        } else {
+            self.target.extends.as_slice()
+        };
+        extends
+            .iter()
+            .map(|id| EntityId::new(id, &self.xforms.entity_id))
+    }
+
+    /// [crate::model::Interface]s implemented by this [Dto].
+    pub fn implements(&self) -> impl Iterator<Item = EntityId<'v>> {
+        self.target
+            .implements
+            .iter()
+            .map(|id| EntityId::new(id, &self.xforms.entity_id))
+    }
+
     pub fn attributes(&self) -> Attributes {
         Attributes::new(
             &self.target.attributes,
@@ -89,7 +145,7 @@ impl<'v, 'a> Dto<'v, 'a> {
         self.target
             .namespace
             .as_ref()
-            .map(|ns| Namespace::new(ns, self.xforms))
+            .map(|ns| Namespace::new(ns, self.root, self.xforms))
     }
 
     fn filter_field(&self, field: &model::Field) -> bool {
@@ -99,14 +155,38 @@ impl<'v, 'a> Dto<'v, 'a> {
     fn filter_rpc(&self, rpc: &model::Rpc) -> bool {
         self.xforms.dto.iter().all(|x| x.filter_rpc(rpc))
     }
+
+    fn flatten_inheritance(&self) -> bool {
+        self.xforms.dto.iter().any(|x| x.flatten_inheritance())
+    }
+
+    /// Appends the fields of all bases of `dto` to `fields`, starting with the least-derived base.
+    /// `visited` guards against inheritance cycles.
+    fn collect_inherited_fields(
+        &self,
+        dto: &'v model::Dto<'a>,
+        visited: &mut Vec<&'v model::EntityId>,
+        fields: &mut Vec<&'v model::Field<'a>>,
+    ) {
+        for base_id in &dto.extends {
+            if visited.contains(&base_id) {
+                continue;
+            }
+            visited.push(base_id);
+            if let Some(base) = self.root.find_dto(base_id) {
+                self.collect_inherited_fields(base, visited, fields);
+                fields.extend(&base.fields);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::model::EntityId;
+    use crate::model::{EntityId, Metadata, Model};
     use crate::test_util::executor::TestExecutor;
     use crate::view::tests::{TestFilter, TestRenamer};
-    use crate::view::Transformer;
+    use crate::view::{FlattenInheritance, Transformer};
     use itertools::Itertools;
 
     #[test]
@@ -161,6 +241,62 @@ mod tests {
         assert_eq!(fields, vec!["visible0", "visible1"]);
     }
 
+    #[test]
+    fn extends() {
+        let mut exe = TestExecutor::new(inheritance_input());
+        let model = inheritance_model(&mut exe);
+        let view = model.view();
+        let root = view.api();
+        let dto = root
+            .find_dto(&EntityId::try_from("d:dto").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            dto.extends().map(|id| id.target().clone()).collect_vec(),
+            vec![EntityId::try_from("d:middle").unwrap()]
+        );
+        assert_eq!(
+            dto.fields().map(|f| f.name().to_string()).collect_vec(),
+            vec!["dto_field"]
+        );
+    }
+
+    #[test]
+    fn flatten_inheritance() {
+        let mut exe = TestExecutor::new(inheritance_input());
+        let model = inheritance_model(&mut exe);
+        let view = model.view().with_dto_transform(FlattenInheritance);
+        let root = view.api();
+        let dto = root
+            .find_dto(&EntityId::try_from("d:dto").unwrap())
+            .unwrap();
+
+        assert_eq!(dto.extends().count(), 0);
+        assert_eq!(
+            dto.fields().map(|f| f.name().to_string()).collect_vec(),
+            vec!["base_field", "middle_field", "dto_field"]
+        );
+    }
+
+    fn inheritance_input() -> &'static str {
+        r#"
+        struct base { base_field: u32 }
+        struct middle { middle_field: u32 }
+        struct dto { dto_field: u32 }
+        "#
+    }
+
+    // The Rust parser has no inheritance, so add the bases manually.
+    fn inheritance_model(exe: &mut TestExecutor) -> Model<'_> {
+        let mut api = exe.api();
+        for (derived, base) in [("d:middle", "d:base"), ("d:dto", "d:middle")] {
+            api.find_dto_mut(&EntityId::try_from(derived).unwrap())
+                .unwrap()
+                .extends = vec![EntityId::try_from(base).unwrap()];
+        }
+        Model::without_deps(api, Metadata::default())
+    }
+
     // todo non-static rpc
     // #[test]
     // fn rpcs() {
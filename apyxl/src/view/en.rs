@@ -66,6 +66,18 @@ impl<'v, 'a> Enum<'v, 'a> {
             .map(move |value| EnumValue::new(value, self.xforms))
     }
 
+    pub fn kind(&self) -> model::EnumKind {
+        self.target.kind
+    }
+
+    pub fn is_flags(&self) -> bool {
+        self.target.is_flags()
+    }
+
+    pub fn underlying_type(&self) -> Option<&model::Type> {
+        self.target.underlying_type.as_ref()
+    }
+
     pub fn attributes(&self) -> Attributes {
         Attributes::new(
             &self.target.attributes,
@@ -113,7 +125,7 @@ impl<'v, 'a> EnumValue<'v, 'a> {
 mod tests {
     use itertools::Itertools;
 
-    use crate::model::EntityId;
+    use crate::model::{EntityId, EnumKind};
     use crate::test_util::executor::TestExecutor;
     use crate::view::tests::{TestFilter, TestRenamer};
     use crate::view::Transformer;
@@ -173,4 +185,29 @@ mod tests {
 
         assert_eq!(values, vec!["visible0", "visible1"]);
     }
+
+    #[test]
+    fn kind() {
+        let mut exe = TestExecutor::new(
+            r#"
+            enum en {}
+            #[flags]
+            enum flags {}
+            "#,
+        );
+        let model = exe.model();
+        let view = model.view();
+        let root = view.api();
+        let en = root
+            .find_enum(&EntityId::try_from("e:en").unwrap())
+            .unwrap();
+        let flags = root
+            .find_enum(&EntityId::try_from("e:flags").unwrap())
+            .unwrap();
+
+        assert_eq!(en.kind(), EnumKind::Plain);
+        assert!(!en.is_flags());
+        assert_eq!(flags.kind(), EnumKind::Flags);
+        assert!(flags.is_flags());
+    }
 }
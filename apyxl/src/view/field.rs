@@ -54,6 +54,14 @@ impl<'v, 'a> Field<'v, 'a> {
         TypeRef::new(&self.target.ty, self.entity_id_xforms)
     }
 
+    pub fn value(&self) -> Option<&'v model::Value<'a>> {
+        self.target.value.as_ref()
+    }
+
+    pub fn default(&self) -> Option<&'v model::Value<'a>> {
+        self.target.default.as_ref()
+    }
+
     pub fn attributes(&self) -> Attributes {
         Attributes::new(
             &self.target.attributes,
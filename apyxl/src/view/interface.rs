@@ -0,0 +1,176 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use dyn_clone::DynClone;
+
+use crate::model;
+use crate::model::entity::ToEntity;
+use crate::model::EntityType;
+use crate::view::{Attributes, EntityId, Rpc, Transforms};
+
+/// A single named set of [Rpc]s within an [Api], e.g. a Rust `trait`.
+/// Wraps [model::Interface].
+#[derive(Debug, Copy, Clone)]
+pub struct Interface<'v, 'a> {
+    target: &'v model::Interface<'a>,
+    xforms: &'v Transforms,
+}
+
+pub trait InterfaceTransform: Debug + DynClone {
+    fn name(&self, _: &mut Cow<str>) {}
+
+    /// `true`: included.
+    /// `false`: excluded.
+    fn filter_rpc(&self, _: &model::Rpc) -> bool {
+        true
+    }
+}
+
+dyn_clone::clone_trait_object!(InterfaceTransform);
+
+impl<'v, 'a> Interface<'v, 'a> {
+    pub fn new(target: &'v model::Interface<'a>, xforms: &'v Transforms) -> Self {
+        Self { target, xforms }
+    }
+
+    pub fn name(&self) -> Cow<'_, str> {
+        let mut name = Cow::Borrowed(self.target.name);
+        for x in &self.xforms.interface {
+            x.name(&mut name)
+        }
+        name
+    }
+
+    pub fn entity_type(&self) -> EntityType {
+        self.target.entity_type()
+    }
+
+    pub fn rpcs(&'a self) -> impl Iterator<Item = Rpc<'v, 'a>> {
+        self.target
+            .rpcs
+            .iter()
+            .filter(|rpc| self.filter_rpc(rpc))
+            .map(move |rpc| Rpc::new(rpc, self.xforms))
+    }
+
+    /// Other [Interface]s this one extends.
+    pub fn super_interfaces(&self) -> impl Iterator<Item = EntityId<'v>> {
+        self.target
+            .super_interfaces
+            .iter()
+            .map(|id| EntityId::new(id, &self.xforms.entity_id))
+    }
+
+    pub fn attributes(&self) -> Attributes<'_, '_> {
+        Attributes::new(
+            &self.target.attributes,
+            &self.xforms.attr,
+            &self.xforms.entity_id,
+        )
+    }
+
+    fn filter_rpc(&self, rpc: &model::Rpc) -> bool {
+        self.xforms.interface.iter().all(|x| x.filter_rpc(rpc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::model::EntityId;
+    use crate::test_util::executor::TestExecutor;
+    use crate::view::tests::{TestFilter, TestRenamer};
+    use crate::view::Transformer;
+
+    #[test]
+    fn name() {
+        let mut exe = TestExecutor::new(
+            r#"
+                    mod ns0 {
+                        trait interface0 {}
+                        mod ns1 {
+                            trait interface1 {}
+                        }
+                    }
+                "#,
+        );
+        let model = exe.model();
+        let view = model.view().with_interface_transform(TestRenamer {});
+        let root = view.api();
+
+        assert_eq!(
+            root.find_interface(&EntityId::try_from("ns0.i:interface0").unwrap())
+                .unwrap()
+                .name(),
+            TestRenamer::renamed("interface0")
+        );
+        assert_eq!(
+            root.find_interface(&EntityId::try_from("ns0.ns1.interface1").unwrap())
+                .unwrap()
+                .name(),
+            TestRenamer::renamed("interface1")
+        );
+    }
+
+    #[test]
+    fn rpcs() {
+        let mut exe = TestExecutor::new(
+            r#"
+            trait interface {
+                fn visible0();
+                fn hidden0();
+                fn visible1();
+            }
+            "#,
+        );
+        let model = exe.model();
+        let view = model
+            .view()
+            .with_interface_transform(TestFilter {})
+            .with_rpc_transform(TestRenamer {});
+        let root = view.api();
+        let interface = root
+            .find_interface(&EntityId::try_from("i:interface").unwrap())
+            .unwrap();
+        let rpcs = interface
+            .rpcs()
+            .map(|rpc| rpc.name().to_string())
+            .collect_vec();
+
+        assert_eq!(
+            rpcs,
+            vec![
+                TestRenamer::renamed("visible0"),
+                TestRenamer::renamed("visible1")
+            ]
+        );
+    }
+
+    #[test]
+    fn super_interfaces() {
+        let mut exe = TestExecutor::new(
+            r#"
+            mod ns {
+                trait base {}
+            }
+            trait interface: ns::base {}
+            "#,
+        );
+        let model = exe.build();
+        let view = model.view();
+        let root = view.api();
+        let interface = root
+            .find_interface(&EntityId::try_from("i:interface").unwrap())
+            .unwrap();
+        let super_interfaces = interface
+            .super_interfaces()
+            .map(|id| id.target().clone())
+            .collect_vec();
+
+        assert_eq!(
+            super_interfaces,
+            vec![EntityId::try_from("ns.i:base").unwrap()]
+        );
+    }
+}
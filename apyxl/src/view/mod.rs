@@ -9,11 +9,13 @@ pub use dto::*;
 pub use en::*;
 pub use entity_id::*;
 pub use field::*;
+pub use interface::*;
 pub use namespace::*;
 pub use rpc::*;
 pub use sub_view::*;
 pub use ty::*;
 pub use ty_alias::*;
+pub use union::*;
 
 use crate::model;
 use crate::model::chunk::ChunkFilter;
@@ -24,11 +26,13 @@ mod dto;
 mod en;
 mod entity_id;
 mod field;
+mod interface;
 mod namespace;
 mod rpc;
 mod sub_view;
 mod ty;
 mod ty_alias;
+mod union;
 
 // In everything in this module and submodules:
 //   'v: view
@@ -51,6 +55,9 @@ pub struct Transforms {
     rpc_param: Vec<Box<dyn FieldTransform>>,
     en: Vec<Box<dyn EnumTransform>>,
     en_value: Vec<Box<dyn EnumValueTransform>>,
+    union: Vec<Box<dyn UnionTransform>>,
+    variant: Vec<Box<dyn VariantTransform>>,
+    interface: Vec<Box<dyn InterfaceTransform>>,
     ty_alias: Vec<Box<dyn TypeAliasTransform>>,
     entity_id: Vec<Box<dyn EntityIdTransform>>,
     attr: Vec<Box<dyn AttributeTransform>>,
@@ -66,7 +73,7 @@ impl<'v: 'a, 'a> Model<'v, 'a> {
 
     /// Get the full combined API root with all transforms applied.
     pub fn api(&'v self) -> Namespace<'v, 'a> {
-        Namespace::new(&self.target.api(), &self.xforms)
+        Namespace::new(self.target.api(), self.target.api(), &self.xforms)
     }
 
     /// Iterate over [Chunk]s, where each subsection of the API can be viewed through a [SubView]
@@ -94,6 +101,7 @@ impl<'v: 'a, 'a> Model<'v, 'a> {
             let sub_view = SubView::new(
                 metadata.root_namespace.clone(),
                 namespace,
+                self.target.api(),
                 self.xforms.clone(),
             )
             .with_namespace_transform(ChunkFilter::new(path));
@@ -101,6 +109,11 @@ impl<'v: 'a, 'a> Model<'v, 'a> {
         })
     }
 
+    /// The underlying model without any transforms applied.
+    pub(crate) fn target(&self) -> &'v model::Model<'a> {
+        self.target
+    }
+
     // todo view::Metadata + metadata xforms
     pub fn metadata(&self) -> &model::Metadata {
         &self.target.metadata()
@@ -146,6 +159,21 @@ pub(crate) trait Transformer: Sized {
         self
     }
 
+    fn with_union_transform(mut self, xform: impl UnionTransform + 'static) -> Self {
+        self.xforms().union.push(Box::new(xform));
+        self
+    }
+
+    fn with_variant_transform(mut self, xform: impl VariantTransform + 'static) -> Self {
+        self.xforms().variant.push(Box::new(xform));
+        self
+    }
+
+    fn with_interface_transform(mut self, xform: impl InterfaceTransform + 'static) -> Self {
+        self.xforms().interface.push(Box::new(xform));
+        self
+    }
+
     fn with_field_transform(mut self, xform: impl FieldTransform + 'static) -> Self {
         self.xforms().field.push(Box::new(xform));
         self
@@ -183,6 +211,9 @@ impl Transforms {
     pub fn rpc_param(&self) -> impl Iterator<Item = &Box<dyn FieldTransform>> {
         self.rpc_param.iter()
     }
+    pub fn interface(&self) -> impl Iterator<Item = &Box<dyn InterfaceTransform>> {
+        self.interface.iter()
+    }
     pub fn ty_alias(&self) -> impl Iterator<Item = &Box<dyn TypeAliasTransform>> {
         self.ty_alias.iter()
     }
@@ -202,7 +233,7 @@ mod tests {
     use crate::view::ty_alias::TypeAliasTransform;
     use crate::view::{
         DtoTransform, EntityIdTransform, EnumTransform, EnumValueTransform, FieldTransform,
-        NamespaceTransform, RpcTransform,
+        InterfaceTransform, NamespaceTransform, RpcTransform, UnionTransform, VariantTransform,
     };
 
     #[derive(Default, Debug, Clone)]
@@ -239,6 +270,21 @@ mod tests {
             *value = Cow::Owned(TestRenamer::renamed(value))
         }
     }
+    impl UnionTransform for TestRenamer {
+        fn name(&self, value: &mut Cow<str>) {
+            *value = Cow::Owned(TestRenamer::renamed(value))
+        }
+    }
+    impl VariantTransform for TestRenamer {
+        fn name(&self, value: &mut Cow<str>) {
+            *value = Cow::Owned(TestRenamer::renamed(value))
+        }
+    }
+    impl InterfaceTransform for TestRenamer {
+        fn name(&self, value: &mut Cow<str>) {
+            *value = Cow::Owned(TestRenamer::renamed(value))
+        }
+    }
     impl FieldTransform for TestRenamer {
         fn name(&self, value: &mut Cow<str>) {
             *value = Cow::Owned(TestRenamer::renamed(value))
@@ -271,6 +317,12 @@ mod tests {
         fn filter_enum(&self, en: &model::Enum) -> bool {
             !en.name.contains("hidden")
         }
+        fn filter_union(&self, union: &model::Union) -> bool {
+            !union.name.contains("hidden")
+        }
+        fn filter_interface(&self, interface: &model::Interface) -> bool {
+            !interface.name.contains("hidden")
+        }
         fn filter_ty_alias(&self, en: &model::TypeAlias) -> bool {
             !en.name.contains("hidden")
         }
@@ -293,4 +345,16 @@ mod tests {
             !value.name.contains("hidden")
         }
     }
+
+    impl InterfaceTransform for TestFilter {
+        fn filter_rpc(&self, rpc: &model::Rpc) -> bool {
+            !rpc.name.contains("hidden")
+        }
+    }
+
+    impl UnionTransform for TestFilter {
+        fn filter_variant(&self, variant: &model::Variant) -> bool {
+            !variant.name.contains("hidden")
+        }
+    }
 }
@@ -7,13 +7,14 @@ use crate::model;
 use crate::model::entity::ToEntity;
 use crate::model::EntityType;
 use crate::view::ty_alias::TypeAlias;
-use crate::view::{Attributes, Dto, Enum, Field, Rpc, Transforms};
+use crate::view::{Attributes, Dto, Enum, Field, Interface, Rpc, Transforms, Union};
 
 /// A named, nestable wrapper for a set of API entities.
 /// Wraps [model::Namespace].
 #[derive(Debug, Copy, Clone)]
 pub struct Namespace<'v, 'a> {
     target: &'v model::Namespace<'a>,
+    root: &'v model::Namespace<'a>,
     xforms: &'v Transforms,
 }
 
@@ -23,6 +24,8 @@ pub enum NamespaceChild<'v, 'a> {
     Dto(Dto<'v, 'a>),
     Rpc(Rpc<'v, 'a>),
     Enum(Enum<'v, 'a>),
+    Union(Union<'v, 'a>),
+    Interface(Interface<'v, 'a>),
     TypeAlias(TypeAlias<'v, 'a>),
     Field(Field<'v, 'a>),
     Namespace(Namespace<'v, 'a>),
@@ -55,6 +58,18 @@ pub trait NamespaceTransform: Debug + DynClone {
         true
     }
 
+    /// `true`: included.
+    /// `false`: excluded.
+    fn filter_union(&self, _: &model::Union) -> bool {
+        true
+    }
+
+    /// `true`: included.
+    /// `false`: excluded.
+    fn filter_interface(&self, _: &model::Interface) -> bool {
+        true
+    }
+
     /// `true`: included.
     /// `false`: excluded.
     fn filter_ty_alias(&self, _: &model::TypeAlias) -> bool {
@@ -71,13 +86,25 @@ pub trait NamespaceTransform: Debug + DynClone {
 dyn_clone::clone_trait_object!(NamespaceTransform);
 
 impl<'v, 'a> NamespaceChild<'v, 'a> {
-    pub fn new(target: &'v model::NamespaceChild<'a>, xforms: &'v Transforms) -> Self {
+    pub fn new(
+        target: &'v model::NamespaceChild<'a>,
+        root: &'v model::Namespace<'a>,
+        xforms: &'v Transforms,
+    ) -> Self {
         match target {
-            model::NamespaceChild::Dto(target) => NamespaceChild::Dto(Dto::new(target, xforms)),
+            model::NamespaceChild::Dto(target) => {
+                NamespaceChild::Dto(Dto::new(target, root, xforms))
+            }
             model::NamespaceChild::Namespace(target) => {
-                NamespaceChild::Namespace(Namespace::new(target, xforms))
+                NamespaceChild::Namespace(Namespace::new(target, root, xforms))
             }
             model::NamespaceChild::Enum(target) => NamespaceChild::Enum(Enum::new(target, xforms)),
+            model::NamespaceChild::Union(target) => {
+                NamespaceChild::Union(Union::new(target, xforms))
+            }
+            model::NamespaceChild::Interface(target) => {
+                NamespaceChild::Interface(Interface::new(target, xforms))
+            }
             model::NamespaceChild::Rpc(target) => NamespaceChild::Rpc(Rpc::new(target, xforms)),
             model::NamespaceChild::Field(target) => NamespaceChild::Field(Field::new(
                 target,
@@ -96,6 +123,8 @@ impl<'v, 'a> NamespaceChild<'v, 'a> {
             NamespaceChild::Dto(dto) => dto.name(),
             NamespaceChild::Rpc(rpc) => rpc.name(),
             NamespaceChild::Enum(en) => en.name(),
+            NamespaceChild::Union(union) => union.name(),
+            NamespaceChild::Interface(interface) => interface.name(),
             NamespaceChild::TypeAlias(alias) => alias.name(),
             NamespaceChild::Field(field) => field.name(),
             NamespaceChild::Namespace(namespace) => namespace.name(),
@@ -107,6 +136,8 @@ impl<'v, 'a> NamespaceChild<'v, 'a> {
             NamespaceChild::Dto(dto) => dto.attributes(),
             NamespaceChild::Rpc(rpc) => rpc.attributes(),
             NamespaceChild::Enum(en) => en.attributes(),
+            NamespaceChild::Union(union) => union.attributes(),
+            NamespaceChild::Interface(interface) => interface.attributes(),
             NamespaceChild::TypeAlias(alias) => alias.attributes(),
             NamespaceChild::Field(field) => field.attributes(),
             NamespaceChild::Namespace(namespace) => namespace.attributes(),
@@ -118,6 +149,8 @@ impl<'v, 'a> NamespaceChild<'v, 'a> {
             NamespaceChild::Dto(dto) => dto.entity_type(),
             NamespaceChild::Rpc(rpc) => rpc.entity_type(),
             NamespaceChild::Enum(en) => en.entity_type(),
+            NamespaceChild::Union(union) => union.entity_type(),
+            NamespaceChild::Interface(interface) => interface.entity_type(),
             NamespaceChild::TypeAlias(alias) => alias.entity_type(),
             NamespaceChild::Field(field) => field.entity_type(),
             NamespaceChild::Namespace(namespace) => namespace.entity_type(),
@@ -126,13 +159,23 @@ impl<'v, 'a> NamespaceChild<'v, 'a> {
 }
 
 impl<'v, 'a> Namespace<'v, 'a> {
-    pub fn new(target: &'v model::Namespace<'a>, xforms: &'v Transforms) -> Self {
-        Self { target, xforms }
+    /// `root` is the root of the API, used to resolve [model::EntityId]s such as base [Dto]s.
+    pub fn new(
+        target: &'v model::Namespace<'a>,
+        root: &'v model::Namespace<'a>,
+        xforms: &'v Transforms,
+    ) -> Self {
+        Self {
+            target,
+            root,
+            xforms,
+        }
     }
 
     pub fn clone_with_new_transforms(&self, xforms: &'v Transforms) -> Self {
         Self {
             target: self.target,
+            root: self.root,
             xforms,
         }
     }
@@ -158,7 +201,7 @@ impl<'v, 'a> Namespace<'v, 'a> {
             .children
             .iter()
             .filter(|child| self.filter_child(child))
-            .map(|child| NamespaceChild::new(child, self.xforms))
+            .map(|child| NamespaceChild::new(child, self.root, self.xforms))
     }
 
     pub fn attributes(&self) -> Attributes {
@@ -173,7 +216,7 @@ impl<'v, 'a> Namespace<'v, 'a> {
         self.target
             .find_descendant(id)
             .filter(|child| self.filter_child(child))
-            .map(|child| NamespaceChild::new(child, self.xforms))
+            .map(|child| NamespaceChild::new(child, self.root, self.xforms))
     }
 
     pub fn find_namespace(&'a self, id: &model::EntityId) -> Option<Namespace<'v, 'a>> {
@@ -185,14 +228,14 @@ impl<'v, 'a> Namespace<'v, 'a> {
                     .iter()
                     .all(|x| x.filter_namespace(namespace))
             })
-            .map(|namespace| Namespace::new(namespace, self.xforms))
+            .map(|namespace| Namespace::new(namespace, self.root, self.xforms))
     }
 
     pub fn find_dto(&'a self, id: &model::EntityId) -> Option<Dto<'v, 'a>> {
         self.target
             .find_dto(id)
             .filter(|dto| self.filter_dto(dto))
-            .map(|dto| Dto::new(dto, self.xforms))
+            .map(|dto| Dto::new(dto, self.root, self.xforms))
     }
 
     pub fn find_rpc(&'a self, id: &model::EntityId) -> Option<Rpc<'v, 'a>> {
@@ -209,6 +252,20 @@ impl<'v, 'a> Namespace<'v, 'a> {
             .map(|en| Enum::new(en, self.xforms))
     }
 
+    pub fn find_union(&'a self, id: &model::EntityId) -> Option<Union<'v, 'a>> {
+        self.target
+            .find_union(id)
+            .filter(|union| self.filter_union(union))
+            .map(|union| Union::new(union, self.xforms))
+    }
+
+    pub fn find_interface(&'a self, id: &model::EntityId) -> Option<Interface<'v, 'a>> {
+        self.target
+            .find_interface(id)
+            .filter(|interface| self.filter_interface(interface))
+            .map(|interface| Interface::new(interface, self.xforms))
+    }
+
     pub fn find_ty_alias(&'a self, id: &model::EntityId) -> Option<TypeAlias<'v, 'a>> {
         self.target
             .find_ty_alias(id)
@@ -220,14 +277,14 @@ impl<'v, 'a> Namespace<'v, 'a> {
         self.target
             .namespaces()
             .filter(|ns| self.filter_namespace(ns))
-            .map(|ns| Namespace::new(ns, self.xforms))
+            .map(|ns| Namespace::new(ns, self.root, self.xforms))
     }
 
     pub fn dtos(&'a self) -> impl Iterator<Item = Dto<'v, 'a>> {
         self.target
             .dtos()
             .filter(|dto| self.filter_dto(dto))
-            .map(|dto| Dto::new(dto, self.xforms))
+            .map(|dto| Dto::new(dto, self.root, self.xforms))
     }
 
     pub fn rpcs(&'a self) -> impl Iterator<Item = Rpc<'v, 'a>> {
@@ -244,6 +301,20 @@ impl<'v, 'a> Namespace<'v, 'a> {
             .map(|en| Enum::new(en, self.xforms))
     }
 
+    pub fn unions(&'a self) -> impl Iterator<Item = Union<'v, 'a>> {
+        self.target
+            .unions()
+            .filter(|union| self.filter_union(union))
+            .map(|union| Union::new(union, self.xforms))
+    }
+
+    pub fn interfaces(&'a self) -> impl Iterator<Item = Interface<'v, 'a>> {
+        self.target
+            .interfaces()
+            .filter(|interface| self.filter_interface(interface))
+            .map(|interface| Interface::new(interface, self.xforms))
+    }
+
     pub fn ty_aliases(&'a self) -> impl Iterator<Item = TypeAlias<'v, 'a>> {
         self.target
             .ty_aliases()
@@ -251,11 +322,27 @@ impl<'v, 'a> Namespace<'v, 'a> {
             .map(|alias| TypeAlias::new(alias, self.xforms))
     }
 
+    pub fn fields(&'a self) -> impl Iterator<Item = Field<'v, 'a>> {
+        self.target
+            .fields()
+            .filter(|field| self.filter_field(field))
+            .map(|field| {
+                Field::new(
+                    field,
+                    &self.xforms.field,
+                    &self.xforms.entity_id,
+                    &self.xforms.attr,
+                )
+            })
+    }
+
     fn filter_child(&self, child: &model::NamespaceChild) -> bool {
         match child {
             model::NamespaceChild::Dto(value) => self.filter_dto(value),
             model::NamespaceChild::Rpc(value) => self.filter_rpc(value),
             model::NamespaceChild::Enum(value) => self.filter_enum(value),
+            model::NamespaceChild::Union(value) => self.filter_union(value),
+            model::NamespaceChild::Interface(value) => self.filter_interface(value),
             model::NamespaceChild::TypeAlias(value) => self.filter_ty_alias(value),
             model::NamespaceChild::Field(field) => self.filter_field(field),
             model::NamespaceChild::Namespace(value) => self.filter_namespace(value),
@@ -281,6 +368,17 @@ impl<'v, 'a> Namespace<'v, 'a> {
         self.xforms.namespace.iter().all(|x| x.filter_enum(en))
     }
 
+    fn filter_union(&self, union: &model::Union) -> bool {
+        self.xforms.namespace.iter().all(|x| x.filter_union(union))
+    }
+
+    fn filter_interface(&self, interface: &model::Interface) -> bool {
+        self.xforms
+            .namespace
+            .iter()
+            .all(|x| x.filter_interface(interface))
+    }
+
     fn filter_ty_alias(&self, alias: &model::TypeAlias) -> bool {
         self.xforms
             .namespace
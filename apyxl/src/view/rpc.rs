@@ -45,6 +45,10 @@ impl<'v, 'a> Rpc<'v, 'a> {
         self.target.entity_type()
     }
 
+    pub fn type_params(&self) -> &'v [&'a str] {
+        &self.target.type_params
+    }
+
     pub fn params(&'a self) -> impl Iterator<Item = Field<'v, 'a>> {
         self.target
             .params
@@ -67,6 +71,21 @@ impl<'v, 'a> Rpc<'v, 'a> {
             .map(|target| TypeRef::new(target, &self.xforms.entity_id))
     }
 
+    pub fn error_type(&self) -> Option<TypeRef<'_>> {
+        self.target
+            .error_type
+            .as_ref()
+            .map(|target| TypeRef::new(target, &self.xforms.entity_id))
+    }
+
+    pub fn kind(&self) -> model::RpcKind {
+        self.target.kind
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.target.is_static
+    }
+
     pub fn attributes(&self) -> Attributes {
         Attributes::new(
             &self.target.attributes,
@@ -6,6 +6,7 @@ use crate::view::{Namespace, Transformer, Transforms};
 pub struct SubView<'a> {
     namespace_id: model::EntityId,
     namespace: &'a model::Namespace<'a>,
+    root: &'a model::Namespace<'a>,
     xforms: Transforms,
 }
 
@@ -13,11 +14,13 @@ impl<'a> SubView<'a> {
     pub fn new(
         namespace_id: model::EntityId,
         namespace: &'a model::Namespace<'a>,
+        root: &'a model::Namespace<'a>,
         xforms: Transforms,
     ) -> Self {
         Self {
             namespace_id: namespace_id.with_qualified_namespaces(),
             namespace,
+            root,
             xforms,
         }
     }
@@ -27,7 +30,7 @@ impl<'a> SubView<'a> {
     }
 
     pub fn namespace<'v>(&'v self) -> Namespace<'v, 'a> {
-        Namespace::new(self.namespace, &self.xforms)
+        Namespace::new(self.namespace, self.root, &self.xforms)
     }
 }
 
@@ -61,7 +64,8 @@ mod tests {
         let model = exe.model();
         let sub_view = SubView::new(
             model::EntityId::default(),
-            &model.api(),
+            model.api(),
+            model.api(),
             Transforms::default(),
         )
         .with_namespace_transform(TestFilter {});
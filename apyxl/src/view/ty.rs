@@ -32,6 +32,10 @@ impl<'v> TypeRef<'v> {
         self.target.semantics
     }
 
+    pub fn is_borrowed(&self) -> bool {
+        self.target.semantics.is_borrowed()
+    }
+
     pub fn is_primitive(&self, api: &Namespace) -> bool {
         match &self.value() {
             Type::Api(api_ty) => {
@@ -65,9 +69,14 @@ impl<'v> TypeRef<'v> {
             | Type::Bytes
             | Type::User(_)
             | Type::Array(_)
+            | Type::Set(_)
+            | Type::FixedArray { .. }
+            | Type::Tuple(_)
             | Type::Map { .. }
             | Type::Optional(_)
-            | Type::Function { .. } => false,
+            | Type::Function { .. }
+            | Type::Generic { .. }
+            | Type::TypeParam(_) => false,
         }
     }
 
@@ -95,7 +104,25 @@ impl<'v> TypeRef<'v> {
             model::Type::Bytes => Type::Bytes,
             model::Type::User(name) => Type::User(name),
             model::Type::Api(id) => Type::Api(EntityId::new(id, self.xforms)),
+            model::Type::Generic { ty, args } => Type::Generic {
+                ty: EntityId::new(ty, self.xforms),
+                args: args.iter().map(|arg| self.nested(arg)).collect_vec(),
+            },
+            model::Type::TypeParam(name) => Type::TypeParam(name),
             model::Type::Array(array_ty) => Type::Array(Box::new(self.nested(array_ty))),
+            model::Type::Set(set_ty) => Type::Set(Box::new(self.nested(set_ty))),
+            model::Type::FixedArray { ty, len } => Type::FixedArray {
+                ty: Box::new(self.nested(ty)),
+                len: *len,
+            },
+            model::Type::Tuple(elements) => Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| {
+                        model::TupleElement::new(element.name.as_deref(), self.nested(&element.ty))
+                    })
+                    .collect_vec(),
+            ),
             model::Type::Map { key, value } => Type::Map {
                 key: Box::new(self.nested(key)),
                 value: Box::new(self.nested(value)),
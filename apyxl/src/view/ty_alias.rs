@@ -38,6 +38,10 @@ impl<'v, 'a> TypeAlias<'v, 'a> {
         name
     }
 
+    pub fn type_params(&self) -> &'v [&'a str] {
+        &self.target.type_params
+    }
+
     pub fn target_ty(&self) -> TypeRef {
         TypeRef::new(&self.target.target_ty, &self.xforms.entity_id)
     }
@@ -0,0 +1,241 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use dyn_clone::DynClone;
+use itertools::Itertools;
+
+use crate::model;
+use crate::model::entity::ToEntity;
+use crate::model::EntityType;
+use crate::view::{Attributes, Field, Transforms, TypeRef};
+
+/// A single tagged union within an [Api].
+/// Wraps [model::Union].
+#[derive(Debug, Copy, Clone)]
+pub struct Union<'v, 'a> {
+    target: &'v model::Union<'a>,
+    xforms: &'v Transforms,
+}
+
+/// A single variant within a [Union].
+/// Wraps [model::Variant].
+#[derive(Debug, Copy, Clone)]
+pub struct Variant<'v, 'a> {
+    target: &'v model::Variant<'a>,
+    xforms: &'v Transforms,
+}
+
+/// Data carried by a [Variant].
+/// Wraps [model::VariantPayload].
+#[derive(Debug, Clone)]
+pub enum VariantPayload<'v, 'a> {
+    Tuple(Vec<TypeRef<'v>>),
+    Struct(Vec<Field<'v, 'a>>),
+}
+
+pub trait UnionTransform: Debug + DynClone {
+    fn name(&self, _: &mut Cow<str>) {}
+
+    /// `true`: included.
+    /// `false`: excluded.
+    fn filter_variant(&self, _: &model::Variant) -> bool {
+        true
+    }
+}
+dyn_clone::clone_trait_object!(UnionTransform);
+
+pub trait VariantTransform: Debug + DynClone {
+    fn name(&self, _: &mut Cow<str>) {}
+}
+dyn_clone::clone_trait_object!(VariantTransform);
+
+impl<'v, 'a> Union<'v, 'a> {
+    pub fn new(target: &'v model::Union<'a>, xforms: &'v Transforms) -> Self {
+        Self { target, xforms }
+    }
+
+    pub fn name(&self) -> Cow<'_, str> {
+        let mut name = Cow::Borrowed(self.target.name);
+        for x in &self.xforms.union {
+            x.name(&mut name)
+        }
+        name
+    }
+
+    pub fn entity_type(&self) -> EntityType {
+        self.target.entity_type()
+    }
+
+    pub fn variants(&'a self) -> impl Iterator<Item = Variant<'v, 'a>> {
+        self.target
+            .variants
+            .iter()
+            .filter(|variant| self.filter_variant(variant))
+            .map(move |variant| Variant::new(variant, self.xforms))
+    }
+
+    pub fn attributes(&self) -> Attributes<'_, '_> {
+        Attributes::new(
+            &self.target.attributes,
+            &self.xforms.attr,
+            &self.xforms.entity_id,
+        )
+    }
+
+    fn filter_variant(&self, variant: &model::Variant) -> bool {
+        self.xforms.union.iter().all(|x| x.filter_variant(variant))
+    }
+}
+
+impl<'v, 'a> Variant<'v, 'a> {
+    pub fn new(target: &'v model::Variant<'a>, xforms: &'v Transforms) -> Self {
+        Self { target, xforms }
+    }
+
+    pub fn name(&self) -> Cow<'_, str> {
+        let mut name = Cow::Borrowed(self.target.name);
+        for x in &self.xforms.variant {
+            x.name(&mut name)
+        }
+        name
+    }
+
+    pub fn entity_type(&self) -> EntityType {
+        self.target.entity_type()
+    }
+
+    pub fn payload(&self) -> Option<VariantPayload<'v, 'a>> {
+        self.target.payload.as_ref().map(|payload| match payload {
+            model::VariantPayload::Tuple(tys) => VariantPayload::Tuple(
+                tys.iter()
+                    .map(|ty| TypeRef::new(ty, &self.xforms.entity_id))
+                    .collect_vec(),
+            ),
+            model::VariantPayload::Struct(fields) => VariantPayload::Struct(
+                fields
+                    .iter()
+                    .map(|field| {
+                        Field::new(
+                            field,
+                            &self.xforms.field,
+                            &self.xforms.entity_id,
+                            &self.xforms.attr,
+                        )
+                    })
+                    .collect_vec(),
+            ),
+        })
+    }
+
+    pub fn attributes(&self) -> Attributes<'_, '_> {
+        Attributes::new(
+            &self.target.attributes,
+            &self.xforms.attr,
+            &self.xforms.entity_id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::model::EntityId;
+    use crate::test_util::executor::TestExecutor;
+    use crate::view::tests::{TestFilter, TestRenamer};
+    use crate::view::{Transformer, VariantPayload};
+
+    #[test]
+    fn name() {
+        let mut exe = TestExecutor::new(
+            r#"
+                    mod ns0 {
+                        enum union0 { A(u32) }
+                        mod ns1 {
+                            enum union1 { A(u32) }
+                        }
+                    }
+                "#,
+        );
+        let model = exe.model();
+        let view = model.view().with_union_transform(TestRenamer {});
+        let root = view.api();
+
+        assert_eq!(
+            root.find_union(&EntityId::try_from("ns0.u:union0").unwrap())
+                .unwrap()
+                .name(),
+            TestRenamer::renamed("union0")
+        );
+        assert_eq!(
+            root.find_union(&EntityId::try_from("ns0.ns1.union1").unwrap())
+                .unwrap()
+                .name(),
+            TestRenamer::renamed("union1")
+        );
+    }
+
+    #[test]
+    fn variants() {
+        let mut exe = TestExecutor::new(
+            r#"
+            enum union {
+                visible0(u32),
+                hidden0,
+                visible1 { field: u32 },
+                hidden1,
+            }
+            "#,
+        );
+        let model = exe.model();
+        let view = model
+            .view()
+            .with_union_transform(TestFilter {})
+            .with_variant_transform(TestRenamer {});
+        let root = view.api();
+        let union = root
+            .find_union(&EntityId::try_from("u:union").unwrap())
+            .unwrap();
+        let variants = union
+            .variants()
+            .map(|variant| variant.name().to_string())
+            .collect_vec();
+
+        assert_eq!(
+            variants,
+            vec![
+                TestRenamer::renamed("visible0"),
+                TestRenamer::renamed("visible1")
+            ]
+        );
+    }
+
+    #[test]
+    fn payload() {
+        let mut exe = TestExecutor::new(
+            r#"
+            enum union {
+                unit,
+                tuple(u32, String),
+                structure { field: u32 },
+            }
+            "#,
+        );
+        let model = exe.model();
+        let view = model.view();
+        let root = view.api();
+        let union = root
+            .find_union(&EntityId::try_from("u:union").unwrap())
+            .unwrap();
+        let payloads = union
+            .variants()
+            .map(|variant| match variant.payload() {
+                None => 0,
+                Some(VariantPayload::Tuple(tys)) => tys.len(),
+                Some(VariantPayload::Struct(fields)) => fields.len(),
+            })
+            .collect_vec();
+
+        assert_eq!(payloads, vec![0, 2, 1]);
+    }
+}
@@ -0,0 +1,3 @@
+pub use parser::AvroParser as Parser;
+
+mod parser;
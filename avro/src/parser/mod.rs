@@ -0,0 +1,539 @@
+use std::borrow::Cow;
+
+use anyhow::{Result, anyhow};
+use itertools::Itertools;
+use log::debug;
+
+use apyxl::model::{
+    Api, Attributes, Comment, Dto, EntityId, EnumValue, EnumValueNumber, Field, Namespace,
+    Semantics, Type, TypeAlias, TypeRef, UNDEFINED_NAMESPACE,
+};
+use apyxl::parser::Config;
+use apyxl::{Input, model};
+
+use crate::parser::schema::{Complex, Schema};
+
+mod schema;
+
+/// Parses Apache Avro JSON schemas, i.e. `.avsc` files.
+///
+/// Each chunk is a single schema, or a list of schemas. Named types (`record`s, `enum`s, and
+/// `fixed`s) are placed in the namespace given by their full name, including named types that are
+/// defined inline as the type of a field. The chunk's file path is not used.
+#[derive(Default)]
+pub struct AvroParser {}
+
+impl apyxl::Parser for AvroParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+            let mut api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                ..Default::default()
+            };
+            serde_json::from_str::<Schema>(data)
+                .map_err(anyhow::Error::from)
+                .and_then(|schema| {
+                    // A file can define multiple schemas in a list.
+                    let schemas = match schema {
+                        Schema::Union(schemas) => schemas,
+                        schema => vec![schema],
+                    };
+                    for schema in schemas {
+                        ty(schema, &[], config, &mut api)?;
+                    }
+                    Ok(())
+                })
+                .map_err(|err| {
+                    anyhow!(
+                        "errors encountered while parsing {:?}: {}",
+                        chunk.relative_file_path,
+                        err
+                    )
+                })?;
+            builder.merge_from_chunk(api, chunk);
+        }
+        Ok(())
+    }
+}
+
+/// Converts `schema` to a [Type]. Named types are added to `api` and referred to by their full
+/// name. `namespace` is the namespace of the enclosing named type, which is used by names that
+/// are not fully qualified.
+fn ty<'a>(
+    schema: Schema<'a>,
+    namespace: &[&'a str],
+    config: &Config,
+    api: &mut Api<'a>,
+) -> Result<Type> {
+    let ty = match schema {
+        Schema::Name(name) => primitive(name).unwrap_or_else(|| {
+            let (namespace, name) = full_name(name, None, namespace);
+            api_ty(&namespace, name)
+        }),
+        Schema::Union(schemas) => union(schemas, namespace, config, api)?,
+        Schema::Annotated {
+            ty: schema,
+            logical_type,
+        } => {
+            let user_ty = logical_type.and_then(|logical_type| {
+                config
+                    .user_types
+                    .iter()
+                    .find(|user_ty| user_ty.parse == logical_type)
+            });
+            match user_ty {
+                Some(user_ty) => Type::User(user_ty.name.clone()),
+                None => ty(*schema, namespace, config, api)?,
+            }
+        }
+        Schema::Complex(complex) => match *complex {
+            Complex::Record(record) | Complex::Error(record) => {
+                let (namespace, name) = full_name(record.name, record.namespace, namespace);
+                let fields = record
+                    .fields
+                    .into_iter()
+                    .map(|field| {
+                        Ok(Field {
+                            name: field.name,
+                            ty: TypeRef::new(
+                                ty(field.ty, &namespace, config, api)?,
+                                Semantics::Value,
+                            ),
+                            attributes: Attributes {
+                                comments: comments(field.doc),
+                                ..Default::default()
+                            },
+                            is_static: false,
+                            value: None,
+                            default: None,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                namespace_mut(api, &namespace).add_dto(Dto {
+                    name,
+                    type_params: vec![],
+                    fields,
+                    rpcs: vec![],
+                    extends: vec![],
+                    implements: vec![],
+                    attributes: Attributes {
+                        comments: comments(record.doc),
+                        ..Default::default()
+                    },
+                    namespace: None,
+                });
+                api_ty(&namespace, name)
+            }
+            Complex::Enum(en) => {
+                let (namespace, name) = full_name(en.name, en.namespace, namespace);
+                let values = en
+                    .symbols
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, symbol)| EnumValue {
+                        name: symbol,
+                        number: i as EnumValueNumber,
+                        attributes: Default::default(),
+                    })
+                    .collect_vec();
+                namespace_mut(api, &namespace).add_enum(model::Enum {
+                    name,
+                    values,
+                    kind: model::EnumKind::Plain,
+                    underlying_type: None,
+                    attributes: Attributes {
+                        comments: comments(en.doc),
+                        ..Default::default()
+                    },
+                });
+                api_ty(&namespace, name)
+            }
+            Complex::Fixed(fixed) => {
+                // Named so that it can be referenced elsewhere, but it is just bytes.
+                let (namespace, name) = full_name(fixed.name, fixed.namespace, namespace);
+                namespace_mut(api, &namespace).add_ty_alias(TypeAlias {
+                    name,
+                    type_params: vec![],
+                    target_ty: TypeRef::new(Type::Bytes, Semantics::Value),
+                    attributes: Attributes {
+                        comments: comments(fixed.doc),
+                        ..Default::default()
+                    },
+                });
+                Type::Bytes
+            }
+            Complex::Array { items } => Type::new_array(TypeRef::new(
+                ty(items, namespace, config, api)?,
+                Semantics::Value,
+            )),
+            Complex::Map { values } => Type::new_map(
+                TypeRef::new(Type::String, Semantics::Value),
+                TypeRef::new(ty(values, namespace, config, api)?, Semantics::Value),
+            ),
+        },
+    };
+    Ok(ty)
+}
+
+fn primitive(name: &str) -> Option<Type> {
+    let ty = match name {
+        "boolean" => Type::Bool,
+        "int" => Type::I32,
+        "long" => Type::I64,
+        "float" => Type::F32,
+        "double" => Type::F64,
+        "bytes" => Type::Bytes,
+        "string" => Type::String,
+        _ => return None,
+    };
+    Some(ty)
+}
+
+/// Unions of `"null"` and another type are optional.
+fn union<'a>(
+    schemas: Vec<Schema<'a>>,
+    namespace: &[&'a str],
+    config: &Config,
+    api: &mut Api<'a>,
+) -> Result<Type> {
+    let (nulls, schemas): (Vec<_>, Vec<_>) = schemas
+        .into_iter()
+        .partition(|schema| *schema == Schema::Name("null"));
+    let mut types = schemas
+        .into_iter()
+        .map(|schema| ty(schema, namespace, config, api))
+        .collect::<Result<Vec<_>>>()?;
+    match (types.len(), nulls.is_empty()) {
+        (1, true) => Ok(types.remove(0)),
+        (1, false) => Ok(Type::new_optional(TypeRef::new(
+            types.remove(0),
+            Semantics::Value,
+        ))),
+        (0, false) => Err(anyhow!("'null' is only supported within a union")),
+        _ => Err(anyhow!(
+            "unions of more than one non-null type are not supported"
+        )),
+    }
+}
+
+/// Splits a possibly fully qualified `name` into its namespace and name. Names without a
+/// namespace use the explicit `namespace` if there is one, otherwise the `enclosing` namespace.
+fn full_name<'a>(
+    name: &'a str,
+    namespace: Option<&'a str>,
+    enclosing: &[&'a str],
+) -> (Vec<&'a str>, &'a str) {
+    let split = |namespace: &'a str| {
+        namespace
+            .split('.')
+            .filter(|component| !component.is_empty())
+            .collect_vec()
+    };
+    match (name.rsplit_once('.'), namespace) {
+        (Some((namespace, name)), _) => (split(namespace), name),
+        (None, Some(namespace)) => (split(namespace), name),
+        (None, None) => (enclosing.to_vec(), name),
+    }
+}
+
+fn api_ty(namespace: &[&str], name: &str) -> Type {
+    Type::Api(EntityId::new_unqualified_vec(
+        namespace.iter().chain(std::iter::once(&name)),
+    ))
+}
+
+fn namespace_mut<'a, 'b>(api: &'b mut Api<'a>, path: &[&'a str]) -> &'b mut Namespace<'a> {
+    let Some((name, rest)) = path.split_first() else {
+        return api;
+    };
+    if api.namespace(name).is_none() {
+        api.add_namespace(Namespace {
+            name: Cow::Borrowed(name),
+            ..Default::default()
+        });
+    }
+    namespace_mut(api.namespace_mut(name).unwrap(), rest)
+}
+
+fn comments(doc: Option<Cow<str>>) -> Vec<Comment> {
+    match doc {
+        Some(Cow::Borrowed(doc)) => vec![Comment::from(doc.lines().collect_vec())],
+        Some(Cow::Owned(doc)) => vec![Comment::from(
+            doc.lines()
+                .map(|line| Cow::Owned(line.to_string()))
+                .collect_vec(),
+        )],
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use lazy_static::lazy_static;
+
+    use crate::parser::AvroParser;
+    use apyxl::model::{Builder, Chunk, Comment, EntityId, Model, Semantics, Type, TypeRef};
+    use apyxl::parser::{Config, UserType};
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    lazy_static! {
+        static ref USER_TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "timestamp-millis".to_string(),
+                name: "timestamp".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    #[test]
+    fn record() -> Result<()> {
+        let data = r#"{
+            "type": "record",
+            "name": "User",
+            "namespace": "com.acme",
+            "doc": "A user.\nSecond line.",
+            "fields": [
+                { "name": "id", "type": "long", "doc": "The \"id\"." },
+                { "name": "active", "type": "boolean", "default": true },
+                { "name": "score", "type": "double" },
+                { "name": "nickname", "type": ["null", "string"], "default": null },
+                { "name": "tags", "type": { "type": "array", "items": "string" } },
+                { "name": "counts", "type": { "type": "map", "values": "int" } },
+                { "name": "hash", "type": { "type": "fixed", "name": "Hash", "size": 16 } },
+                { "name": "raw", "type": "bytes" },
+                { "name": "other_hash", "type": "Hash" }
+            ]
+        }"#;
+        run_test(&TEST_CONFIG, &[("user.avsc", data)], |model| {
+            let dto = model
+                .api()
+                .find_dto(&EntityId::new_unqualified("com.acme.User"))
+                .unwrap();
+            assert_eq!(
+                dto.attributes.comments,
+                vec![Comment::unowned(&["A user.", "Second line."])]
+            );
+            let types = dto
+                .fields
+                .iter()
+                .map(|field| (field.name, &field.ty.value))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                types,
+                vec![
+                    ("id", &Type::I64),
+                    ("active", &Type::Bool),
+                    ("score", &Type::F64),
+                    ("nickname", &Type::new_optional(ty(Type::String))),
+                    ("tags", &Type::new_array(ty(Type::String))),
+                    ("counts", &Type::new_map(ty(Type::String), ty(Type::I32))),
+                    ("hash", &Type::Bytes),
+                    ("raw", &Type::Bytes),
+                    (
+                        "other_hash",
+                        &Type::Api(EntityId::try_from("ns:com.ns:acme.a:Hash")?)
+                    ),
+                ]
+            );
+            assert_eq!(
+                dto.fields[0].attributes.comments,
+                vec![Comment::unowned(&["The \"id\"."])]
+            );
+            let alias = model
+                .api()
+                .find_ty_alias(&EntityId::new_unqualified("com.acme.Hash"))
+                .unwrap();
+            assert_eq!(alias.target_ty.value, Type::Bytes);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn enum_symbols() -> Result<()> {
+        let data = r#"{
+            "type": "enum",
+            "name": "Status",
+            "doc": "Status.",
+            "symbols": ["ACTIVE", "DISABLED", "DELETED"],
+            "default": "ACTIVE"
+        }"#;
+        run_test(&TEST_CONFIG, &[("status.avsc", data)], |model| {
+            let en = model.api().en("Status").unwrap();
+            assert_eq!(en.attributes.comments, vec![Comment::unowned(&["Status."])]);
+            let values = en
+                .values
+                .iter()
+                .map(|value| (value.name, value.number))
+                .collect::<Vec<_>>();
+            assert_eq!(values, vec![("ACTIVE", 0), ("DISABLED", 1), ("DELETED", 2)]);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn inline_named_types() -> Result<()> {
+        let data = r#"{
+            "type": "record",
+            "name": "Outer",
+            "namespace": "a",
+            "fields": [
+                { "name": "inner", "type": {
+                    "type": "record",
+                    "name": "Inner",
+                    "fields": [{ "name": "status", "type": {
+                        "type": "enum", "name": "b.Status", "symbols": ["A"]
+                    } }]
+                } },
+                { "name": "other", "type": {
+                    "type": "record", "name": "Other", "namespace": "c", "fields": []
+                } },
+                { "name": "inner_ref", "type": "Inner" }
+            ]
+        }"#;
+        run_test(&TEST_CONFIG, &[("outer.avsc", data)], |model| {
+            let api = model.api();
+            let outer = api.find_dto(&EntityId::new_unqualified("a.Outer")).unwrap();
+            let inner = api.find_dto(&EntityId::new_unqualified("a.Inner")).unwrap();
+            assert!(
+                api.find_dto(&EntityId::new_unqualified("c.Other"))
+                    .is_some()
+            );
+            assert!(
+                api.find_enum(&EntityId::new_unqualified("b.Status"))
+                    .is_some()
+            );
+            assert_eq!(
+                outer.fields[0].ty.value,
+                Type::Api(EntityId::try_from("ns:a.d:Inner")?)
+            );
+            assert_eq!(
+                outer.fields[1].ty.value,
+                Type::Api(EntityId::try_from("ns:c.d:Other")?)
+            );
+            assert_eq!(
+                outer.fields[2].ty.value,
+                Type::Api(EntityId::try_from("ns:a.d:Inner")?)
+            );
+            assert_eq!(
+                inner.fields[0].ty.value,
+                Type::Api(EntityId::try_from("ns:b.e:Status")?)
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn references_across_chunks() -> Result<()> {
+        let id = r#"{ "type": "record", "name": "Id", "namespace": "common", "fields": [] }"#;
+        let event = r#"{
+            "type": "record",
+            "name": "Event",
+            "namespace": "events",
+            "fields": [
+                { "name": "id", "type": "common.Id" },
+                { "name": "ids", "type": { "type": "array", "items": ["null", "common.Id"] } }
+            ]
+        }"#;
+        run_test(
+            &TEST_CONFIG,
+            &[("common/id.avsc", id), ("events/event.avsc", event)],
+            |model| {
+                let dto = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("events.Event"))
+                    .unwrap();
+                let id = TypeRef::new(
+                    Type::Api(EntityId::try_from("ns:common.d:Id")?),
+                    Semantics::Value,
+                );
+                assert_eq!(dto.fields[0].ty, id);
+                assert_eq!(
+                    dto.fields[1].ty.value,
+                    Type::new_array(ty(Type::new_optional(id)))
+                );
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn schema_list() -> Result<()> {
+        let data = r#"[
+            { "type": "enum", "name": "a.En", "symbols": ["A"] },
+            { "type": "record", "name": "a.Dto", "fields": [{ "name": "en", "type": "En" }] }
+        ]"#;
+        run_test(&TEST_CONFIG, &[("a.avsc", data)], |model| {
+            let dto = model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.Dto"))
+                .unwrap();
+            assert_eq!(
+                dto.fields[0].ty.value,
+                Type::Api(EntityId::try_from("ns:a.e:En")?)
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn logical_types() -> Result<()> {
+        let data = r#"{
+            "type": "record",
+            "name": "Dto",
+            "fields": [
+                { "name": "created", "type": { "type": "long", "logicalType": "timestamp-millis" } },
+                { "name": "id", "type": { "type": "string", "logicalType": "uuid" } }
+            ]
+        }"#;
+        run_test(&USER_TY_TEST_CONFIG, &[("dto.avsc", data)], |model| {
+            let dto = model.api().dto("Dto").unwrap();
+            assert_eq!(dto.fields[0].ty.value, Type::User("timestamp".to_string()));
+            assert_eq!(dto.fields[1].ty.value, Type::String);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn unsupported_union() {
+        let data = r#"{
+            "type": "record",
+            "name": "Dto",
+            "fields": [{ "name": "either", "type": ["int", "string"] }]
+        }"#;
+        let mut input = input::Buffer::new(data);
+        let mut builder = Builder::default();
+        let result = AvroParser::default().parse(&TEST_CONFIG, &mut input, &mut builder);
+        assert!(result.is_err());
+    }
+
+    fn run_test(
+        config: &Config,
+        inputs: &[(&str, &str)],
+        assertions: impl FnOnce(&Model) -> Result<()>,
+    ) -> Result<()> {
+        let mut input = input::ChunkBuffer::new();
+        for (path, data) in inputs {
+            input.add_chunk(Chunk::with_relative_file_path(path), data);
+        }
+        let mut builder = Builder::default();
+        AvroParser::default().parse(config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+
+        assertions(&model)?;
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
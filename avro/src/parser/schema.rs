@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+
+/// An Avro schema as it appears in a `.avsc` file or as the type of a record field.
+///
+/// Names are borrowed from the input, so they must not contain JSON escape sequences.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Schema<'a> {
+    /// Primitive type or a reference to a named type, e.g. `"int"` or `"com.acme.User"`.
+    Name(&'a str),
+    /// `["null", "string"]`
+    Union(#[serde(borrow)] Vec<Schema<'a>>),
+    Complex(#[serde(borrow)] Box<Complex<'a>>),
+    /// Any type in object form, e.g. `{ "type": "long", "logicalType": "timestamp-millis" }`.
+    Annotated {
+        #[serde(rename = "type", borrow)]
+        ty: Box<Schema<'a>>,
+        #[serde(rename = "logicalType")]
+        logical_type: Option<&'a str>,
+    },
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Complex<'a> {
+    Record(#[serde(borrow)] Record<'a>),
+    /// Error types are records that are thrown by protocol messages.
+    Error(#[serde(borrow)] Record<'a>),
+    Enum(#[serde(borrow)] Enum<'a>),
+    Fixed(#[serde(borrow)] Fixed<'a>),
+    Array {
+        #[serde(borrow)]
+        items: Schema<'a>,
+    },
+    Map {
+        #[serde(borrow)]
+        values: Schema<'a>,
+    },
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Record<'a> {
+    pub name: &'a str,
+    pub namespace: Option<&'a str>,
+    #[serde(borrow)]
+    pub doc: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub fields: Vec<RecordField<'a>>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct RecordField<'a> {
+    pub name: &'a str,
+    #[serde(borrow)]
+    pub doc: Option<Cow<'a, str>>,
+    #[serde(rename = "type", borrow)]
+    pub ty: Schema<'a>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Enum<'a> {
+    pub name: &'a str,
+    pub namespace: Option<&'a str>,
+    #[serde(borrow)]
+    pub doc: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub symbols: Vec<&'a str>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Fixed<'a> {
+    pub name: &'a str,
+    pub namespace: Option<&'a str>,
+    #[serde(borrow)]
+    pub doc: Option<Cow<'a, str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::schema::{Complex, Schema};
+
+    #[test]
+    fn name() -> Result<()> {
+        let schema = serde_json::from_str::<Schema>(r#""com.acme.User""#)?;
+        assert_eq!(schema, Schema::Name("com.acme.User"));
+        Ok(())
+    }
+
+    #[test]
+    fn union() -> Result<()> {
+        let schema = serde_json::from_str::<Schema>(r#"["null", "string"]"#)?;
+        assert_eq!(
+            schema,
+            Schema::Union(vec![Schema::Name("null"), Schema::Name("string")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn record() -> Result<()> {
+        let schema = serde_json::from_str::<Schema>(
+            r#"{
+            "type": "record",
+            "name": "User",
+            "namespace": "com.acme",
+            "doc": "A \"user\".",
+            "aliases": ["Person"],
+            "fields": [
+                { "name": "id", "type": "long", "default": 0 },
+                { "name": "tags", "type": { "type": "array", "items": "string" } }
+            ]
+        }"#,
+        )?;
+        let Schema::Complex(complex) = schema else {
+            panic!("expected complex schema");
+        };
+        let Complex::Record(record) = *complex else {
+            panic!("expected record");
+        };
+        assert_eq!(record.name, "User");
+        assert_eq!(record.namespace, Some("com.acme"));
+        assert_eq!(record.doc.as_deref(), Some("A \"user\"."));
+        assert_eq!(record.fields.len(), 2);
+        assert_eq!(record.fields[0].ty, Schema::Name("long"));
+        assert_eq!(
+            record.fields[1].ty,
+            Schema::Complex(Box::new(Complex::Array {
+                items: Schema::Name("string")
+            }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotated() -> Result<()> {
+        let schema = serde_json::from_str::<Schema>(
+            r#"{ "type": "long", "logicalType": "timestamp-millis" }"#,
+        )?;
+        assert_eq!(
+            schema,
+            Schema::Annotated {
+                ty: Box::new(Schema::Name("long")),
+                logical_type: Some("timestamp-millis"),
+            }
+        );
+        Ok(())
+    }
+}
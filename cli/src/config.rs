@@ -63,16 +63,47 @@ pub enum ParserName {
     Rust,
     #[clap(name = "csharp")]
     CSharp,
+    #[clap(name = "typescript")]
+    TypeScript,
+    #[clap(name = "c")]
+    C,
+    #[clap(name = "kotlin")]
+    Kotlin,
+    #[clap(name = "python")]
+    Python,
+    #[clap(name = "wit")]
+    Wit,
+    #[clap(name = "java")]
+    Java,
+    #[clap(name = "avro")]
+    Avro,
+    #[clap(name = "go")]
+    Go,
+    #[clap(name = "rust-syn")]
+    RustSyn,
+    #[clap(name = "json")]
+    Json,
 }
 
 pub enum ParserImpl {
     Rust(apyxl::parser::Rust),
     CSharp(csharp::Parser),
+    TypeScript(typescript::Parser),
+    C(apyxl::parser::C),
+    Kotlin(kotlin::Parser),
+    Python(python::Parser),
+    Wit(wit::Parser),
+    Java(java::Parser),
+    Avro(avro::Parser),
+    Go(go::Parser),
+    RustSyn(rust_syn::Parser),
+    Json(apyxl::parser::Json),
 }
 
 #[derive(ValueEnum, Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum GeneratorName {
     Rust,
+    Json,
 }
 
 #[derive(Clone, Debug)]
@@ -99,6 +130,16 @@ impl ParserName {
         match self {
             ParserName::Rust => ParserImpl::Rust(apyxl::parser::Rust::default()),
             ParserName::CSharp => ParserImpl::CSharp(csharp::Parser::default()),
+            ParserName::TypeScript => ParserImpl::TypeScript(typescript::Parser::default()),
+            ParserName::C => ParserImpl::C(apyxl::parser::C::default()),
+            ParserName::Kotlin => ParserImpl::Kotlin(kotlin::Parser::default()),
+            ParserName::Python => ParserImpl::Python(python::Parser::default()),
+            ParserName::Wit => ParserImpl::Wit(wit::Parser::default()),
+            ParserName::Java => ParserImpl::Java(java::Parser::default()),
+            ParserName::Avro => ParserImpl::Avro(avro::Parser::default()),
+            ParserName::Go => ParserImpl::Go(go::Parser::default()),
+            ParserName::RustSyn => ParserImpl::RustSyn(rust_syn::Parser::default()),
+            ParserName::Json => ParserImpl::Json(apyxl::parser::Json::default()),
         }
     }
 }
@@ -113,6 +154,16 @@ impl apyxl::Parser for ParserImpl {
         match self {
             ParserImpl::Rust(p) => p.parse(config, input, builder),
             ParserImpl::CSharp(p) => p.parse(config, input, builder),
+            ParserImpl::TypeScript(p) => p.parse(config, input, builder),
+            ParserImpl::C(p) => p.parse(config, input, builder),
+            ParserImpl::Kotlin(p) => p.parse(config, input, builder),
+            ParserImpl::Python(p) => p.parse(config, input, builder),
+            ParserImpl::Wit(p) => p.parse(config, input, builder),
+            ParserImpl::Java(p) => p.parse(config, input, builder),
+            ParserImpl::Avro(p) => p.parse(config, input, builder),
+            ParserImpl::Go(p) => p.parse(config, input, builder),
+            ParserImpl::RustSyn(p) => p.parse(config, input, builder),
+            ParserImpl::Json(p) => p.parse(config, input, builder),
         }
     }
 }
@@ -121,6 +172,7 @@ impl GeneratorName {
     pub fn create_impl(&self) -> Box<dyn apyxl::Generator> {
         match self {
             GeneratorName::Rust => Box::new(apyxl::generator::Rust::default()),
+            GeneratorName::Json => Box::new(apyxl::generator::Json::default()),
         }
     }
 }
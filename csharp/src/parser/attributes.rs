@@ -1,21 +1,32 @@
 use chumsky::prelude::*;
 use std::borrow::Cow;
 
-use apyxl::model::attributes;
+use apyxl::model::{attributes, Deprecation};
 use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+const OBSOLETE_ATTRIBUTES: &[&str] = &[
+    "Obsolete",
+    "ObsoleteAttribute",
+    "System.Obsolete",
+    "System.ObsoleteAttribute",
+];
 
 pub fn attributes<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> {
     let name = text::ident().separated_by(just(".").padded()).slice();
     let assigned_data = just('=')
         .padded()
-        .ignore_then(text::ident())
+        .ignore_then(value())
         .map(|data| vec![attributes::UserData::new(None, data)]);
-    let data = text::ident()
-        .then(just('=').padded().ignore_then(text::ident()).or_not())
-        .map(|(lhs, rhs)| match rhs {
-            None => attributes::UserData::new(None, lhs),
-            Some(rhs) => attributes::UserData::new(Some(lhs), rhs),
-        });
+    let data = type_of()
+        .or(string())
+        .map(|value| attributes::UserData::new(None, value))
+        .or(text::ident()
+            .then(just('=').padded().ignore_then(value()).or_not())
+            .map(|(lhs, rhs)| match rhs {
+                None => attributes::UserData::new(None, lhs),
+                Some(rhs) => attributes::UserData::new(Some(lhs), rhs),
+            }));
     let data_list = data
         .separated_by(just(',').padded())
         .allow_trailing()
@@ -40,13 +51,41 @@ pub fn attributes<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, E
         .map(|opt| opt.unwrap_or_default())
 }
 
+/// Removes an `[Obsolete]` attribute from `user` and returns it as a [Deprecation], e.g.
+/// `[Obsolete]` or `[Obsolete("note")]`.
+pub fn take_deprecation<'a>(user: &mut Vec<attributes::User<'a>>) -> Option<Deprecation<'a>> {
+    util::take_deprecation(user, OBSOLETE_ATTRIBUTES)
+}
+
+fn value<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    choice((type_of(), string(), text::ident()))
+}
+
+/// e.g. `typeof(ApiError)`, kept as written.
+fn type_of<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    util::keyword_ex("typeof")
+        .then(none_of(")").repeated().delimited_by(just('('), just(')')))
+        .slice()
+}
+
+/// e.g. `"str"`, kept as written including the quotes.
+fn string<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    let escape = just('\\').then(any()).ignored();
+    escape
+        .or(none_of("\\\"").ignored())
+        .repeated()
+        .delimited_by(just('"'), just('"'))
+        .slice()
+}
+
 #[cfg(test)]
 mod tests {
     use chumsky::Parser;
+    use std::borrow::Cow;
 
     use crate::parser::dto;
-    use apyxl::model::attributes;
     use apyxl::model::attributes::UserData;
+    use apyxl::model::{attributes, Deprecation};
     use apyxl::test_util::executor::TEST_CONFIG;
 
     #[test]
@@ -158,6 +197,53 @@ mod tests {
         )
     }
 
+    #[test]
+    fn type_of() {
+        run_test(
+            r#"
+                    [attr0(typeof(a.Type)), attr1(k = typeof(List<int>))]
+                    struct dto {}
+                    "#,
+            vec![
+                attributes::User::new("attr0", vec![UserData::new(None, "typeof(a.Type)")]),
+                attributes::User::new("attr1", vec![UserData::new(Some("k"), "typeof(List<int>)")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn strings() {
+        run_test(
+            r#"
+                    [attr0("a \"b\""), attr1(k = "v"), attr2 = "v"]
+                    struct dto {}
+                    "#,
+            vec![
+                attributes::User::new("attr0", vec![UserData::new(None, r#""a \"b\"""#)]),
+                attributes::User::new("attr1", vec![UserData::new(Some("k"), r#""v""#)]),
+                attributes::User::new("attr2", vec![UserData::new(None, r#""v""#)]),
+            ],
+        )
+    }
+
+    #[test]
+    fn deprecation() {
+        for (content, expected) in [
+            ("[Obsolete]", Deprecation::default()),
+            (
+                r#"[System.ObsoleteAttribute("note", true)]"#,
+                Deprecation {
+                    note: Some(Cow::Borrowed("note")),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            let mut user = super::attributes().parse(content).into_result().unwrap();
+            assert_eq!(super::take_deprecation(&mut user), Some(expected));
+            assert!(user.is_empty());
+        }
+    }
+
     fn run_test(content: &str, expected: Vec<attributes::User>) {
         let (dto, _) = dto::parser(&TEST_CONFIG)
             .parse(content)
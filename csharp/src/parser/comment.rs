@@ -1,8 +1,11 @@
+use std::borrow::Cow;
+
 use apyxl::parser::error::Error;
-use apyxl::model::Comment;
-use apyxl::parser::comment;
+use apyxl::model::{Comment, Docs, ParamDoc};
+use apyxl::parser::{comment, docs};
 use chumsky::prelude::*;
 use chumsky::Parser;
+use itertools::Itertools;
 
 pub fn single<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
     comment::single(line_start(), block_start(), block_end())
@@ -12,6 +15,150 @@ pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> {
     comment::multi(line_start(), block_start(), block_end())
 }
 
+/// Removes the first comment with XML doc tags, e.g. `<summary>`, from `comments` and returns it
+/// as [Docs]. See [xml_docs].
+pub fn take_docs<'a>(comments: &mut Vec<Comment<'a>>) -> Option<Box<Docs<'a>>> {
+    let (index, docs) = comments
+        .iter()
+        .enumerate()
+        .find_map(|(i, comment)| xml_docs(comment).map(|docs| (i, docs)))?;
+    comments.remove(index);
+    Some(Box::new(docs))
+}
+
+/// Parses the `<summary>`, `<remarks>`, `<param name>`, `<returns>`, `<example>`, and
+/// `<seealso cref>` tags of an XML doc comment. Returns `None` if there are none of these tags.
+///
+/// Other tags and text outside of tags are ignored. Inline tags are replaced with their text, e.g.
+/// `<see cref="Dto"/>` becomes `Dto` and `<c>null</c>` becomes `` `null` ``.
+pub fn xml_docs<'a>(comment: &Comment) -> Option<Docs<'a>> {
+    let text = comment.lines().join("\n");
+    let mut docs = Docs::default();
+    let mut found = false;
+    let mut rest = text.as_str();
+    while let Some((tag, content, remaining)) = next_tag(rest) {
+        rest = remaining;
+        match tag.name {
+            "summary" => docs.summary = lines(content),
+            "remarks" => docs.description = lines(content),
+            "param" => match tag.attribute("name") {
+                Some(name) => docs
+                    .params
+                    .push(ParamDoc::new(name.to_string(), lines(content))),
+                None => continue,
+            },
+            "returns" => docs.returns = lines(content),
+            "example" => docs.examples.push(example(content)),
+            "seealso" => match tag.attribute("cref") {
+                Some(cref) => docs.see_also.push(Cow::Owned(cref.to_string())),
+                None => continue,
+            },
+            _ => continue,
+        }
+        found = true;
+    }
+    found.then_some(docs)
+}
+
+struct Tag<'s> {
+    name: &'s str,
+    attributes: &'s str,
+    self_closing: bool,
+}
+
+impl<'s> Tag<'s> {
+    /// Value of a quoted attribute, e.g. `name="value"`.
+    fn attribute(&self, key: &str) -> Option<&'s str> {
+        let start = self.attributes.find(&format!("{}=\"", key))? + key.len() + 2;
+        let len = self.attributes[start..].find('"')?;
+        Some(&self.attributes[start..start + len])
+    }
+}
+
+/// Finds the next tag in `s` and returns it with its content and the text after it.
+fn next_tag(s: &str) -> Option<(Tag<'_>, &str, &str)> {
+    let start = s.find('<')?;
+    let end = start + s[start..].find('>')?;
+    let tag = parse_tag(&s[start + 1..end]);
+    let rest = &s[end + 1..];
+    if tag.self_closing {
+        return Some((tag, "", rest));
+    }
+    let close = format!("</{}>", tag.name);
+    match rest.find(&close) {
+        Some(i) => Some((tag, &rest[..i], &rest[i + close.len()..])),
+        None => Some((tag, rest, "")),
+    }
+}
+
+fn parse_tag(s: &str) -> Tag<'_> {
+    let self_closing = s.ends_with('/');
+    let s = s.trim_end_matches('/').trim();
+    let (name, attributes) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    Tag {
+        name,
+        attributes,
+        self_closing,
+    }
+}
+
+/// Each line of `content` with inline tags replaced and whitespace trimmed.
+fn lines<'a>(content: &str) -> Comment<'a> {
+    let lines = inline(content)
+        .lines()
+        .map(|line| Cow::Owned(line.to_string()))
+        .collect_vec();
+    docs::trimmed_lines(&lines)
+}
+
+/// The `<code>` block of an example, keeping indentation, or the example's text.
+fn example<'a>(content: &str) -> Comment<'a> {
+    match next_tag(content) {
+        Some((tag, code, _)) if tag.name == "code" => {
+            let lines = unescape(code)
+                .lines()
+                .map(|line| Cow::Owned(line.to_string()))
+                .collect_vec();
+            Comment::from(docs::trim_blank(&lines).to_vec())
+        }
+        _ => lines(content),
+    }
+}
+
+fn inline(content: &str) -> String {
+    let mut text = String::new();
+    let mut rest = content;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+        text.push_str(&rest[..start]);
+        let tag = parse_tag(&rest[start + 1..end]);
+        match tag.name {
+            "c" | "/c" => text.push('`'),
+            "para" | "/para" | "br" => text.push('\n'),
+            _ if tag.self_closing => text.push_str(
+                ["cref", "name", "langword", "href"]
+                    .into_iter()
+                    .find_map(|key| tag.attribute(key))
+                    .unwrap_or_default(),
+            ),
+            _ => {}
+        }
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+    unescape(&text)
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
 fn line_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
     choice((just("///"), just("//")))
 }
@@ -23,3 +170,69 @@ fn block_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
 fn block_end<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
     just("*/")
 }
+
+#[cfg(test)]
+mod tests {
+    use apyxl::model::{Comment, Docs, ParamDoc};
+    use chumsky::Parser;
+
+    use crate::parser::comment;
+
+    #[test]
+    fn xml_docs() {
+        let comment = comment::single()
+            .parse(
+                r#"/// <summary>
+                /// Gets a <see cref="User"/> by id.
+                /// </summary>
+                /// <remarks>Returns <c>null</c> if not found &amp; logs.</remarks>
+                /// <param name="id">The user's id.</param>
+                /// <param name="cache">
+                /// Whether to use the cache.
+                /// </param>
+                /// <typeparam name="T">Ignored.</typeparam>
+                /// <returns>The user.</returns>
+                /// <example>
+                /// <code>
+                /// var user = GetUser(1);
+                /// </code>
+                /// </example>
+                /// <seealso cref="Users.List"/>
+"#,
+            )
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            comment::xml_docs(&comment),
+            Some(Docs {
+                summary: Comment::from(vec!["Gets a User by id."]),
+                description: Comment::from(vec!["Returns `null` if not found & logs."]),
+                params: vec![
+                    ParamDoc::new("id", Comment::from(vec!["The user's id."])),
+                    ParamDoc::new("cache", Comment::from(vec!["Whether to use the cache."])),
+                ],
+                returns: Comment::from(vec!["The user."]),
+                examples: vec![Comment::from(vec!["var user = GetUser(1);"])],
+                see_also: vec!["Users.List".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn no_xml_docs() {
+        let comment = Comment::from(vec!["a < b", "plain"]);
+        assert_eq!(comment::xml_docs(&comment), None);
+    }
+
+    #[test]
+    fn take_docs() {
+        let regular = Comment::from(vec!["regular"]);
+        let mut comments = vec![
+            regular.clone(),
+            Comment::from(vec!["<summary>doc</summary>"]),
+        ];
+        let docs = comment::take_docs(&mut comments).unwrap();
+        assert_eq!(docs.summary, Comment::from(vec!["doc"]));
+        assert_eq!(comments, vec![regular]);
+    }
+}
@@ -1,20 +1,16 @@
 use crate::parser::is_static::is_static;
 use crate::parser::visibility::Visibility;
-use crate::parser::{attributes, comment, property, visibility};
-use crate::parser::{en, field, rpc, util, Config};
-use apyxl::model::{Attributes, Dto, Namespace, NamespaceChild};
+use crate::parser::{attributes, comment, property, ty, visibility};
+use crate::parser::{en, field, interface, rpc, util, Config};
+use apyxl::model::{Attributes, Dto, EntityId, Namespace, NamespaceChild};
 use apyxl::parser::error::Error;
 use chumsky::prelude::*;
 use itertools::Itertools;
 
 pub fn parser(config: &Config) -> impl Parser<&str, (Dto, Visibility), Error> {
     recursive(|nested| {
-        let prefix = choice((
-            util::keyword_ex("struct"),
-            util::keyword_ex("class"),
-            util::keyword_ex("interface"),
-        ))
-        .then(text::whitespace().at_least(1));
+        let prefix = choice((util::keyword_ex("struct"), util::keyword_ex("class")))
+            .then(text::whitespace().at_least(1));
         let name = text::ident();
         let children = children(config, nested, just('}').ignored())
             .delimited_by(just('{').padded(), just('}').padded())
@@ -26,38 +22,65 @@ pub fn parser(config: &Config) -> impl Parser<&str, (Dto, Visibility), Error> {
             .then_ignore(is_static())
             .then_ignore(prefix)
             .then(name)
+            .then(ty::type_params().or_not())
+            .then(ty::base_types(config).or_not())
+            .then_ignore(ty::constraints().or_not())
             .then(children)
-            .map(|((((comments, user), visibility), name), children)| {
-                let mut namespace = Namespace {
+            .map(
+                |(
+                    (((((mut comments, mut user), visibility), name), type_params), base_types),
                     children,
-                    ..Default::default()
-                };
-                let (fields, rpcs) = namespace.extract_non_static();
-
-                let namespace = if namespace.children.is_empty() {
-                    None
-                } else {
-                    Some(namespace)
-                };
-
-                let dto = Dto {
-                    name,
-                    fields,
-                    rpcs,
-                    attributes: Attributes {
-                        comments,
-                        user,
+                )| {
+                    let mut namespace = Namespace {
+                        children,
                         ..Default::default()
-                    },
-                    namespace,
-                };
+                    };
+                    let (fields, rpcs) = namespace.extract_non_static();
 
-                (dto, visibility)
-            })
+                    let namespace = if namespace.children.is_empty() {
+                        None
+                    } else {
+                        Some(namespace)
+                    };
+
+                    let (implements, extends) = base_types
+                        .unwrap_or_default()
+                        .into_iter()
+                        .partition(is_interface_name);
+
+                    let type_params = type_params.unwrap_or_default();
+                    let mut dto = Dto {
+                        name,
+                        type_params: type_params.clone(),
+                        fields,
+                        rpcs,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                        namespace,
+                        extends,
+                        implements,
+                    };
+                    dto.apply_type_params(&type_params);
+
+                    (dto, visibility)
+                },
+            )
             .boxed()
     })
 }
 
+/// C# doesn't syntactically distinguish base classes from interfaces, so rely on the `IName`
+/// naming convention. All other base types are base classes.
+fn is_interface_name(id: &EntityId) -> bool {
+    let mut chars = id.component_names().last().unwrap_or_default().chars();
+    chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
 fn children<'a>(
     config: &'a Config,
     dto: impl Parser<'a, &'a str, (Dto<'a>, Visibility), Error<'a>>,
@@ -66,11 +89,12 @@ fn children<'a>(
     choice((
         dto.map(|(c, v)| vec![(NamespaceChild::Dto(c), v)]),
         en::parser().map(|(c, v)| vec![(NamespaceChild::Enum(c), v)]),
+        interface::parser(config).map(|(c, v)| vec![(NamespaceChild::Interface(c), v)]),
         rpc::parser(config).map(|(c, v)| vec![(NamespaceChild::Rpc(c), v)]),
-        property::parser(config).map(|properties| {
-            properties
-                .into_iter()
+        property::parser(config).map(|(rpcs, field)| {
+            rpcs.into_iter()
                 .map(|(rpc, v)| (NamespaceChild::Rpc(rpc), v))
+                .chain(field.map(|(field, v)| (NamespaceChild::Field(field), v)))
                 .collect_vec()
         }),
         // Field after property so that it can be greedy with '=<whatever>;'.
@@ -97,10 +121,13 @@ fn children<'a>(
 mod tests {
     use anyhow::Result;
     use chumsky::Parser;
+    use std::borrow::Cow;
 
     use crate::parser::dto;
     use crate::parser::visibility::Visibility;
-    use apyxl::model::{attributes, Comment};
+    use apyxl::model::{
+        attributes, Comment, Deprecation, EntityId, Semantics, Type, TypeRef, Value,
+    };
     use apyxl::parser::test_util::wrap_test_err;
     use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
 
@@ -206,6 +233,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn generic() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            class Page<T> where T : class {
+                public List<T> Items;
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Page");
+        assert_eq!(dto.type_params, vec!["T"]);
+        assert_eq!(
+            dto.fields[0].ty,
+            TypeRef::new_array(
+                TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value),
+                Semantics::Value
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn base_types() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            class Player<T> : a.Entity<T>, IService, Index where T : class {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            dto.extends,
+            vec![
+                EntityId::new_unqualified("a.Entity"),
+                EntityId::new_unqualified("Index"),
+            ]
+        );
+        assert_eq!(dto.implements, vec![EntityId::new_unqualified("IService")]);
+        Ok(())
+    }
+
     #[test]
     fn field_visibility() -> Result<()> {
         let (dto, _) = dto::parser(&TEST_PUB_ONLY_CONFIG)
@@ -363,6 +435,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn deprecated() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                [Obsolete("use other"), flag]
+                struct StructName {
+                    [Obsolete]
+                    int field;
+                }
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            dto.attributes.deprecation,
+            Some(Deprecation {
+                note: Some(Cow::Borrowed("use other")),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            dto.attributes.user,
+            vec![attributes::User::new_flag("flag")]
+        );
+        assert_eq!(
+            dto.fields[0].attributes.deprecation,
+            Some(Deprecation::default())
+        );
+        Ok(())
+    }
+
     #[test]
     fn rpc() -> Result<()> {
         let (dto, _) = dto::parser(&TEST_CONFIG)
@@ -426,6 +530,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn property_initializer() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                struct StructName {
+                    public int prop { get; set; } = 3;
+                }
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.rpcs.len(), 2);
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "prop");
+        assert_eq!(dto.fields[0].default, Some(Value::Int(3)));
+        Ok(())
+    }
+
     #[test]
     fn static_property() -> Result<()> {
         let (dto, _) = dto::parser(&TEST_CONFIG)
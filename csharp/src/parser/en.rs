@@ -1,77 +1,193 @@
+use std::collections::HashMap;
+
 use chumsky::prelude::*;
 
 use crate::parser::error::Error;
 use crate::parser::visibility::Visibility;
 use crate::parser::{attributes, comment, visibility};
-use apyxl::model::{Attributes, Enum, EnumValue, EnumValueNumber};
+use apyxl::model::attributes::User;
+use apyxl::model::{Attributes, Enum, EnumKind, EnumValue, EnumValueNumber, Type};
 use apyxl::parser::util;
 
-const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+const FLAGS_ATTRIBUTES: &[&str] = &[
+    "Flags",
+    "FlagsAttribute",
+    "System.Flags",
+    "System.FlagsAttribute",
+];
 
 pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a>> {
     let prefix = util::keyword_ex("enum").then(text::whitespace().at_least(1));
     let name = text::ident();
+    let underlying_ty = just(':')
+        .padded()
+        .ignore_then(text::ident().map(integer_ty))
+        .or_not()
+        .map(Option::flatten);
     let values = en_value()
         .separated_by(just(',').padded())
         .allow_trailing()
         .collect::<Vec<_>>()
-        .delimited_by(just('{').padded(), just('}').padded());
+        .delimited_by(just('{').padded(), just('}').padded())
+        .try_map(|values, span| {
+            apply_enum_value_numbers(values)
+                .ok_or_else(|| Rich::custom(span, "unable to evaluate enum value numbers"))
+        });
     comment::multi()
         .then(attributes::attributes().padded())
         .then(visibility::parser(Visibility::Private))
         .then_ignore(prefix)
         .then(name)
+        .then(underlying_ty)
         .then(values)
-        .map(|((((comments, user), visibility), name), values)| {
+        .map(
+            |(((((mut comments, mut user), visibility), name), underlying_type), values)| {
+                let kind = if take_flags(&mut user) {
+                    EnumKind::Flags
+                } else {
+                    EnumKind::Plain
+                };
+                (
+                    Enum {
+                        name,
+                        values,
+                        kind,
+                        underlying_type,
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    },
+                    visibility,
+                )
+            },
+        )
+}
+
+/// The integral [Type] of an enum's underlying type, e.g. `byte` in `enum E : byte`.
+fn integer_ty(name: &str) -> Option<Type> {
+    Some(match name {
+        "byte" | "Byte" => Type::U8,
+        "ushort" | "UInt16" => Type::U16,
+        "uint" | "UInt32" => Type::U32,
+        "ulong" | "UInt64" => Type::U64,
+        "sbyte" | "SByte" => Type::I8,
+        "short" | "Int16" => Type::I16,
+        "int" | "Int32" => Type::I32,
+        "long" | "Int64" => Type::I64,
+        _ => return None,
+    })
+}
+
+/// A single operand of `|` within an enum value's initializer.
+#[derive(Debug, Clone, PartialEq)]
+enum Term<'a> {
+    Number(EnumValueNumber),
+    Value(&'a str),
+}
+
+/// Enum value along with its initializer, if any, e.g. `Value = 1 << 2` or `Value = A | B`.
+fn en_value<'a>() -> impl Parser<'a, &'a str, (EnumValue<'a>, Option<Vec<Term<'a>>>), Error<'a>> {
+    let shift = just("<<").padded().ignore_then(number());
+    let number = number()
+        .then(shift.or_not())
+        .try_map(|(number, shift), span| match shift {
+            None => Ok(number),
+            Some(shift) => u32::try_from(shift)
+                .ok()
+                .and_then(|shift| number.checked_shl(shift))
+                .ok_or_else(|| Rich::custom(span, "invalid shift")),
+        })
+        .map(Term::Number);
+    // Qualified names, e.g. `Permissions.Read`, refer to values within the same enum.
+    let value = text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|names| Term::Value(names.last().unwrap()));
+    let initializer = just('=').padded().ignore_then(
+        number
+            .or(value)
+            .separated_by(just('|').padded())
+            .at_least(1)
+            .collect::<Vec<_>>(),
+    );
+    comment::multi()
+        .then(attributes::attributes().padded())
+        .then(text::ident())
+        .then(initializer.or_not())
+        .padded()
+        .map(|(((mut comments, mut user), name), initializer)| {
             (
-                Enum {
+                EnumValue {
                     name,
-                    values: apply_enum_value_number_defaults(values),
+                    number: 0,
                     attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
                         comments,
+                        deprecation: attributes::take_deprecation(&mut user),
                         user,
                         ..Default::default()
                     },
                 },
-                visibility,
+                initializer,
             )
         })
 }
 
-fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
-    let number = just('=')
-        .padded()
-        .ignore_then(text::int(10).try_map(|s, span| {
-            str::parse::<EnumValueNumber>(s)
-                .map_err(|_| chumsky::error::Error::<&'a str>::expected_found(None, None, span))
-        }));
-    comment::multi()
-        .then(attributes::attributes().padded())
-        .then(text::ident())
-        .then(number.or_not())
-        .padded()
-        .map(|(((comments, user), name), number)| EnumValue {
-            name,
-            number: number.unwrap_or(INVALID_ENUM_NUMBER),
-            attributes: Attributes {
-                comments,
-                user,
-                ..Default::default()
-            },
-        })
+/// Integer literal with an optional suffix, e.g. `10`, `0x1F`, `0b0100_0000`, or `1L`.
+fn number<'a>() -> impl Parser<'a, &'a str, EnumValueNumber, Error<'a>> {
+    let digits = |radix: u32| {
+        any()
+            .filter(move |c: &char| c.is_digit(radix) || *c == '_')
+            .repeated()
+            .at_least(1)
+            .slice()
+            .try_map(move |digits: &str, span| {
+                EnumValueNumber::from_str_radix(&digits.replace('_', ""), radix)
+                    .map_err(|_| Rich::custom(span, "invalid integer literal"))
+            })
+    };
+    choice((
+        just("0x").or(just("0X")).ignore_then(digits(16)),
+        just("0b").or(just("0B")).ignore_then(digits(2)),
+        digits(10),
+    ))
+    .then_ignore(one_of("uUlL").repeated())
 }
 
-fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
+/// Values without an initializer are one more than the previous value. Returns None if an
+/// initializer references an unknown value.
+fn apply_enum_value_numbers<'a>(
+    values: Vec<(EnumValue<'a>, Option<Vec<Term<'a>>>)>,
+) -> Option<Vec<EnumValue<'a>>> {
+    let mut evaluated = HashMap::new();
     let mut i = 0;
-    for value in &mut values {
-        if value.number == INVALID_ENUM_NUMBER {
-            value.number = i;
-            i += 1;
-        } else {
-            i = value.number + 1;
-        }
-    }
     values
+        .into_iter()
+        .map(|(mut value, initializer)| {
+            value.number = match initializer {
+                None => i,
+                Some(terms) => terms.iter().try_fold(0, |acc, term| match term {
+                    Term::Number(number) => Some(acc | number),
+                    Term::Value(name) => evaluated.get(name).map(|number| acc | number),
+                })?,
+            };
+            evaluated.insert(value.name, value.number);
+            i = value.number + 1;
+            Some(value)
+        })
+        .collect()
+}
+
+/// Removes a [FLAGS_ATTRIBUTES] attribute from `user`. Returns true if it was present.
+fn take_flags(user: &mut Vec<User>) -> bool {
+    let len = user.len();
+    user.retain(|attr| !FLAGS_ATTRIBUTES.contains(&attr.name.as_ref()));
+    user.len() != len
 }
 
 #[cfg(test)]
@@ -80,24 +196,43 @@ mod tests {
         use anyhow::Result;
         use chumsky::Parser;
 
-        use crate::parser::en::en_value;
+        use crate::parser::en::{en_value, Term};
         use apyxl::model::attributes;
         use apyxl::parser::test_util::wrap_test_err;
 
         #[test]
         fn test() -> Result<()> {
-            let value = en_value()
+            let (value, initializer) = en_value()
                 .parse("Value = 1")
                 .into_result()
                 .map_err(wrap_test_err)?;
             assert_eq!(value.name, "Value");
-            assert_eq!(value.number, 1);
+            assert_eq!(initializer, Some(vec![Term::Number(1)]));
+            Ok(())
+        }
+
+        #[test]
+        fn initializer_expr() -> Result<()> {
+            let (_, initializer) = en_value()
+                .parse("Value = 0x10 | 1 << 2 | 0b1_0000_0000 | A | Enum.B")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(
+                initializer,
+                Some(vec![
+                    Term::Number(16),
+                    Term::Number(4),
+                    Term::Number(256),
+                    Term::Value("A"),
+                    Term::Value("B"),
+                ])
+            );
             Ok(())
         }
 
         #[test]
         fn attributes() -> Result<()> {
-            let value = en_value()
+            let (value, _) = en_value()
                 .parse(
                     r#"
                     [flag1, flag2]
@@ -123,7 +258,7 @@ mod tests {
 
         use crate::parser::en;
         use crate::parser::visibility::Visibility;
-        use apyxl::model::{attributes, Comment, EnumValue, EnumValueNumber};
+        use apyxl::model::{attributes, Comment, EnumKind, EnumValue, EnumValueNumber, Type};
         use apyxl::parser::test_util::wrap_test_err;
 
         #[test]
@@ -298,6 +433,77 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn flags() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse(
+                    r#"
+                    [Flags, flag1]
+                    public enum Permissions : uint {
+                        None = 0,
+                        Read = 1 << 0,
+                        Write = 0x02,
+                        Exec = 4,
+                        ReadWrite = Read | Permissions.Write,
+                    }
+                    "#,
+                )
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.kind, EnumKind::Flags);
+            assert_eq!(en.underlying_type, Some(Type::U32));
+            assert_eq!(
+                en.attributes.user,
+                vec![attributes::User::new_flag("flag1")]
+            );
+            assert_value(en.values.first(), "None", 0);
+            assert_value(en.values.get(1), "Read", 1);
+            assert_value(en.values.get(2), "Write", 2);
+            assert_value(en.values.get(3), "Exec", 4);
+            assert_value(en.values.get(4), "ReadWrite", 3);
+            Ok(())
+        }
+
+        #[test]
+        fn flags_qualified_attribute() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse("[System.FlagsAttribute] enum Enum {}")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.kind, EnumKind::Flags);
+            assert!(en.attributes.user.is_empty());
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_value() {
+            assert!(en::parser()
+                .parse("enum Enum { A = B }")
+                .into_result()
+                .is_err());
+        }
+
+        #[test]
+        fn plain() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse("enum Enum { A, B }")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.kind, EnumKind::Plain);
+            assert_eq!(en.underlying_type, None);
+            Ok(())
+        }
+
+        #[test]
+        fn underlying_type() -> Result<()> {
+            let (en, _) = en::parser()
+                .parse("enum Enum : byte { A, B }")
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(en.underlying_type, Some(Type::U8));
+            Ok(())
+        }
+
         fn assert_value(
             actual: Option<&EnumValue>,
             expected_name: &str,
@@ -1,9 +1,10 @@
 use crate::parser::is_static::is_static;
 use crate::parser::visibility::Visibility;
-use crate::parser::{attributes, comment, ty, visibility};
+use crate::parser::{attributes, comment, ty, value, visibility};
 use apyxl::model::{Attributes, Field};
 use apyxl::parser::error::Error;
 use apyxl::parser::{util, Config};
+use chumsky::error::Rich;
 use chumsky::prelude::{any, just};
 use chumsky::{text, Parser};
 
@@ -11,32 +12,56 @@ pub fn parser(config: &Config) -> impl Parser<&str, (Field, Visibility), Error>
     let end = just(';');
     let initializer = just('=')
         .padded()
-        .then(any().and_is(end.not()).repeated().slice());
+        .ignore_then(any().and_is(end.not()).repeated().slice());
     let field = ty::parser(config)
         .then_ignore(text::whitespace().at_least(1))
         .then(text::ident())
-        .then_ignore(initializer.or_not())
+        .then(initializer.or_not())
         .then_ignore(end.padded());
     comment::multi()
         .then(attributes::attributes().padded())
         .then(visibility::parser(Visibility::Private))
         .then(is_static())
-        .then_ignore(util::keyword_ex("const").padded().or_not())
+        .then(util::keyword_ex("const").padded().or_not())
         .then_ignore(util::keyword_ex("readonly").padded().or_not())
         .then_ignore(util::keyword_ex("event").padded().or_not())
         .then(field)
-        .map(
-            |((((comments, user), visibility), is_static), (ty, name))| {
+        .validate(
+            |(
+                ((((mut comments, mut user), visibility), is_static), is_const),
+                ((ty, name), initializer),
+            ),
+             span,
+             emitter| {
+                let initializer = initializer
+                    .map(|initializer| value::parse(initializer, &ty))
+                    .transpose()
+                    .unwrap_or_else(|err| {
+                        emitter.emit(Rich::custom(span, err));
+                        None
+                    })
+                    .flatten();
+                // Only `const` initializers are values, since others can be changed at runtime.
+                // Other initializers are defaults.
+                let (value, default) = match is_const {
+                    Some(_) => (initializer, None),
+                    None => (None, initializer),
+                };
                 (
                     Field {
                         name,
                         ty,
                         attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
                             comments,
+                            deprecation: attributes::take_deprecation(&mut user),
                             user,
                             ..Default::default()
                         },
-                        is_static,
+                        // `const` fields are implicitly static.
+                        is_static: is_static || is_const.is_some(),
+                        value,
+                        default,
                     },
                     visibility,
                 )
@@ -50,6 +75,7 @@ pub fn parser(config: &Config) -> impl Parser<&str, (Field, Visibility), Error>
 mod tests {
     use crate::parser::field::parser;
     use anyhow::Result;
+    use apyxl::model::Value;
     use apyxl::parser::test_util::wrap_test_err;
     use apyxl::test_util::executor::TEST_CONFIG;
     use chumsky::Parser;
@@ -64,6 +90,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn const_value() -> Result<()> {
+        let (field, _) = parser(&TEST_CONFIG)
+            .parse(r#"public const string field = "name";"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(field.is_static);
+        assert_eq!(field.value, Some(Value::String("name".into())));
+        Ok(())
+    }
+
+    #[test]
+    fn const_char() -> Result<()> {
+        let (field, _) = parser(&TEST_CONFIG)
+            .parse("public const char field = 'c';")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.value, Some(Value::Char('c')));
+        Ok(())
+    }
+
+    #[test]
+    fn const_verbatim_string() -> Result<()> {
+        let (field, _) = parser(&TEST_CONFIG)
+            .parse(r#"public const string field = @"C:\dir";"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.value, Some(Value::String(r"C:\dir".into())));
+        Ok(())
+    }
+
+    #[test]
+    fn unrepresentable_value() {
+        let result = parser(&TEST_CONFIG)
+            .parse("public const ulong field = 0x1_0000_0000_0000_0000_0000_0000_0000_0000;")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_const_has_no_value() -> Result<()> {
+        let (field, _) = parser(&TEST_CONFIG)
+            .parse("public static int field = 5;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.value, None);
+        assert_eq!(field.default, Some(Value::Int(5)));
+        Ok(())
+    }
+
     #[test]
     fn complex_field() -> Result<()> {
         let input = "public static Dictionary<string, List<int>> field = 0;";
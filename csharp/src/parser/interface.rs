@@ -0,0 +1,124 @@
+use crate::parser::visibility::Visibility;
+use crate::parser::{attributes, comment, property, rpc, ty, util, visibility, Config};
+use apyxl::model::{Attributes, Interface};
+use apyxl::parser::error::Error;
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+/// Parses an `interface` into an [Interface]. Methods and property accessors become rpcs. Member
+/// visibility is ignored since interface members are public by default.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Interface<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("interface").then(text::whitespace().at_least(1));
+    let rpcs = choice((
+        rpc::parser(config).map(|(rpc, _)| vec![rpc]),
+        property::parser(config)
+            .map(|(rpcs, _)| rpcs.into_iter().map(|(rpc, _)| rpc).collect_vec()),
+        // Catch comments after all children
+        comment::single().padded().map(|_| vec![]),
+    ))
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|v| v.into_iter().flatten().collect_vec())
+    .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(attributes::attributes().padded())
+        .then(visibility::parser(Visibility::Private))
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(ty::type_params().or_not())
+        .then(ty::base_types(config).or_not())
+        .then_ignore(ty::constraints().or_not())
+        .then(rpcs)
+        .map(
+            |(((((mut comments, mut user), visibility), name), super_interfaces), rpcs)| {
+                (
+                    Interface {
+                        name,
+                        rpcs,
+                        super_interfaces: super_interfaces.unwrap_or_default(),
+                        attributes: Attributes {
+                            docs: comment::take_docs(&mut comments),
+                            comments,
+                            deprecation: attributes::take_deprecation(&mut user),
+                            user,
+                            ..Default::default()
+                        },
+                    },
+                    visibility,
+                )
+            },
+        )
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::interface;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::EntityId;
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (interface, visibility) = interface::parser(&TEST_CONFIG)
+            .parse("public interface IService {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(interface.name, "IService");
+        assert_eq!(visibility, Visibility::Public);
+        assert!(interface.rpcs.is_empty());
+        assert!(interface.super_interfaces.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn rpcs() -> Result<()> {
+        let (interface, _) = interface::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            interface IService {
+                Dto Get(int id);
+                // comment
+                void WithDefault() {}
+                int Count { get; }
+            }
+            "#
+                .trim(),
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            interface
+                .rpcs
+                .iter()
+                .map(|rpc| rpc.name.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["Get", "WithDefault", "get_Count"]
+        );
+        assert_eq!(interface.rpc("Get").unwrap().params.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn super_interfaces() -> Result<()> {
+        let (interface, _) = interface::parser(&TEST_CONFIG)
+            .parse("interface IService<T> : a.IBase, IOther<T> where T : class {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            interface.super_interfaces,
+            vec![
+                EntityId::new_unqualified("a.IBase"),
+                EntityId::new_unqualified("IOther"),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -21,12 +21,14 @@ mod dto;
 mod en;
 mod expr_block;
 mod field;
+mod interface;
 mod is_static;
 mod namespace;
 mod property;
 mod rpc;
 mod ty;
 mod ty_alias;
+mod value;
 mod visibility;
 
 #[derive(Default)]
@@ -47,7 +49,7 @@ impl apyxl::Parser for CSharpParser {
             // These must come before asmdefs, but still be appended to the top level
             // namespace aliases.
             let imports = choice((
-                ty_alias::parser(config).map(Import::Alias),
+                ty_alias::parser(config).map(|alias| Import::Alias(Box::new(alias))),
                 import().map(Import::Namespace),
             ))
             .padded()
@@ -65,7 +67,9 @@ impl apyxl::Parser for CSharpParser {
                     let (imports, mut aliases): (Vec<_>, Vec<_>) =
                         imports.into_iter().partition_map(|import| match import {
                             Import::Namespace(id) => Either::Left(id),
-                            Import::Alias(alias) => Either::Right(NamespaceChild::TypeAlias(alias)),
+                            Import::Alias(alias) => {
+                                Either::Right(NamespaceChild::TypeAlias(*alias))
+                            }
                         });
                     children.append(&mut aliases);
                     (children, imports)
@@ -83,6 +87,7 @@ impl apyxl::Parser for CSharpParser {
                 children,
                 attributes: Default::default(),
                 is_virtual: false,
+                implements: vec![],
             };
 
             apply_local_ty_aliases(&mut api)?;
@@ -125,7 +130,7 @@ impl apyxl::Parser for CSharpParser {
 
 enum Import<'a> {
     Namespace(EntityId),
-    Alias(TypeAlias<'a>),
+    Alias(Box<TypeAlias<'a>>),
 }
 
 /// Replace types in this chunk referred to by local aliases like `using X = A.B.C;`. This will
@@ -240,6 +245,15 @@ fn apply_imports(
                 imports,
             )?;
         }
+        if let Some(error_ty) = &mut rpc.error_type {
+            apply_imports_to_type(
+                all_entity_ids,
+                &local_entity_ids,
+                &namespace_id,
+                error_ty,
+                imports,
+            )?;
+        }
         Ok(())
     };
 
@@ -267,6 +281,9 @@ fn apply_imports(
         for rpc in &mut dto.rpcs {
             apply_import_to_rpc(rpc, dto_id.clone(), &dto_entity_ids)?;
         }
+        for id in dto.extends.iter_mut().chain(&mut dto.implements) {
+            apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+        }
         // note: enums have no type refs.
     }
 
@@ -274,6 +291,16 @@ fn apply_imports(
         apply_import_to_rpc(rpc, namespace_id.clone(), &local_entity_ids)?;
     }
 
+    for interface in namespace.interfaces_mut() {
+        let interface_id = namespace_id.child_unqualified(interface.name);
+        for rpc in &mut interface.rpcs {
+            apply_import_to_rpc(rpc, interface_id.clone(), &local_entity_ids)?;
+        }
+        for id in &mut interface.super_interfaces {
+            apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+        }
+    }
+
     for field in namespace.fields_mut() {
         apply_import_to_field(field, namespace_id.clone(), &local_entity_ids)?;
     }
@@ -316,14 +343,32 @@ fn apply_imports_to_type(
         | Type::String
         | Type::StringView
         | Type::Bytes
-        | Type::User(_) => {}
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
 
         Type::Array(ty) => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
         }
+        Type::Set(ty) => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
+        }
+        Type::FixedArray { ty, .. } => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
+        }
         Type::Optional(ty) => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, ty, imports)?
         }
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(
+                    all_entity_ids,
+                    local_entity_ids,
+                    namespace_id,
+                    &mut element.ty,
+                    imports,
+                )?;
+            }
+        }
         Type::Map { key, value } => {
             apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, key, imports)?;
             apply_imports_to_type(
@@ -334,14 +379,11 @@ fn apply_imports_to_type(
                 imports,
             )?;
         }
-        Type::Api(id) => {
-            if !local_entity_ids.contains(id) {
-                for import in imports {
-                    if let Some(qualified) = qualify_by_import(all_entity_ids, import, id)? {
-                        *id = qualified;
-                        break;
-                    }
-                }
+        Type::Api(id) => apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?;
+            for arg in args {
+                apply_imports_to_type(all_entity_ids, local_entity_ids, namespace_id, arg, imports)?;
             }
         }
         Type::Function { params, return_ty } => {
@@ -368,6 +410,23 @@ fn apply_imports_to_type(
     Ok(())
 }
 
+fn apply_imports_to_id(
+    all_entity_ids: &HashSet<EntityId>,
+    local_entity_ids: &HashSet<EntityId>,
+    id: &mut EntityId,
+    imports: &[EntityId],
+) -> Result<()> {
+    if !local_entity_ids.contains(id) {
+        for import in imports {
+            if let Some(qualified) = qualify_by_import(all_entity_ids, import, id)? {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn qualify_by_import(
     all_entity_ids: &HashSet<EntityId>,
     import: &EntityId,
@@ -469,7 +528,15 @@ fn collect_type_ids(namespace: &Namespace, namespace_id: EntityId, type_ids: &mu
                 }
             }
             NamespaceChild::Rpc(rpc) => handle_rpc(&namespace_id, rpc, type_ids),
-            NamespaceChild::Enum(_) => { /* none */ }
+            NamespaceChild::Interface(interface) => {
+                let interface_id = namespace_id
+                    .child(EntityType::Interface, interface.name)
+                    .unwrap();
+                for rpc in &interface.rpcs {
+                    handle_rpc(&interface_id, rpc, type_ids);
+                }
+            }
+            NamespaceChild::Enum(_) | NamespaceChild::Union(_) => { /* none */ }
             NamespaceChild::TypeAlias(alias) => {
                 let alias_id = namespace_id
                     .child(EntityType::TypeAlias, alias.name)
@@ -516,7 +583,7 @@ fn import<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
 mod tests {
     use crate::parser::{assembly_definitions, CSharpParser};
     use anyhow::Result;
-    use apyxl::model::{Builder, UNDEFINED_NAMESPACE};
+    use apyxl::model::{Builder, EntityId, UNDEFINED_NAMESPACE};
     use apyxl::parser::Config;
     use apyxl::test_util::executor::TEST_CONFIG;
     use apyxl::{input, Parser};
@@ -612,6 +679,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn interface_imports() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        using a;
+        namespace a {
+            public interface IBase {}
+            public interface IService : IBase {}
+        }
+        public class dto : IService {}
+        "#,
+        );
+        let mut builder = Builder::default();
+        CSharpParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(
+            model.api().dto("dto").unwrap().implements,
+            vec![EntityId::try_from("a.i:IService")?]
+        );
+        assert_eq!(
+            model
+                .api()
+                .find_interface(&EntityId::try_from("a.IService")?)
+                .unwrap()
+                .super_interfaces,
+            vec![EntityId::try_from("a.i:IBase")?]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn base_class_imports() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        using a;
+        namespace a {
+            public class Entity {
+                public int Id;
+            }
+        }
+        public class Player : Entity {}
+        "#,
+        );
+        let mut builder = Builder::default();
+        CSharpParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(
+            model.api().dto("Player").unwrap().extends,
+            vec![EntityId::try_from("a.d:Entity")?]
+        );
+        Ok(())
+    }
+
     #[test]
     fn assembly_definition_parser() {
         let result = assembly_definitions()
@@ -930,6 +1050,43 @@ mod tests {
             })
         }
 
+        #[test]
+        fn rpc_error_ty() -> Result<()> {
+            let errs = r#"
+            namespace Errs {
+                struct ApiError {}
+            }
+            "#;
+            let test = r#"
+            using Errs;
+            struct Entity {
+                [Throws(typeof(ApiError))]
+                void rpc() {}
+            }
+            "#;
+
+            run_chunked_test(&[("errs", errs), ("test", test)], |model| {
+                let actual = model
+                    .api()
+                    .find_rpc(&EntityId::new_unqualified("Entity.rpc"))
+                    .unwrap()
+                    .error_type
+                    .as_ref()
+                    .ok_or(anyhow!("no error type"))?
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from("ns:Errs.d:ApiError").unwrap();
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+
         #[test]
         fn namespace_full() -> Result<()> {
             let a = "namespace a { namespace b { namespace c { struct Id {} } } }";
@@ -6,7 +6,7 @@ use itertools::Itertools;
 use crate::parser::error::Error;
 use crate::parser::util::keyword_ex;
 use crate::parser::visibility::Visibility;
-use crate::parser::{attributes, comment, dto, en, ty_alias};
+use crate::parser::{attributes, comment, dto, en, interface, ty_alias};
 use apyxl::model::{Attributes, Namespace, NamespaceChild};
 use apyxl::parser::Config;
 
@@ -24,17 +24,20 @@ pub fn parser(config: &Config) -> impl Parser<&str, Namespace, Error> {
             .then_ignore(prefix)
             .then(name_chain)
             .then(body)
-            .map(|(((comments, user), mut name_chain), children)| {
+            .map(|(((mut comments, mut user), mut name_chain), children)| {
                 let name = name_chain.remove(name_chain.len() - 1);
                 let mut namespace = Namespace {
                     name: Cow::Borrowed(name),
                     children,
                     attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
                         comments,
+                        deprecation: attributes::take_deprecation(&mut user),
                         user,
                         ..Default::default()
                     },
                     is_virtual: false,
+                    implements: vec![],
                 };
                 // For inline nested namespaces e.g. `namespace a.b.c`, walk the name_chain
                 // in reverse, and wrapping each level in a new namespace.
@@ -59,6 +62,7 @@ pub fn children<'a>(
     choice((
         dto::parser(config).map(|(c, v)| Some((NamespaceChild::Dto(c), v))),
         en::parser().map(|(c, v)| Some((NamespaceChild::Enum(c), v))),
+        interface::parser(config).map(|(c, v)| Some((NamespaceChild::Interface(c), v))),
         ty_alias::parser(config).map(|c| Some((NamespaceChild::TypeAlias(c), Visibility::Public))),
         namespace.map(|c| Some((NamespaceChild::Namespace(c), Visibility::Public))),
         // Catch comments after all children
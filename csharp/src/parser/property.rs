@@ -1,9 +1,10 @@
 use crate::parser::is_static::is_static;
 use crate::parser::visibility::Visibility;
-use crate::parser::{attributes, comment, expr_block, ty, visibility};
-use apyxl::model::{Attributes, Rpc};
+use crate::parser::{attributes, comment, expr_block, ty, value, visibility};
+use apyxl::model::{Attributes, Field, Rpc, RpcKind};
 use apyxl::parser::error::Error;
 use apyxl::parser::{util, Config};
+use chumsky::error::Rich;
 use chumsky::prelude::{any, choice, just};
 use chumsky::{text, IterParser, Parser};
 use itertools::Itertools;
@@ -14,14 +15,21 @@ enum Accessor {
     Set,
 }
 
-pub fn parser(config: &Config) -> impl Parser<&str, Vec<(Rpc, Visibility)>, Error> {
+/// Accessor rpcs and, if the property has an initializer, its backing field.
+type Property<'a> = (Vec<(Rpc<'a>, Visibility)>, Option<(Field<'a>, Visibility)>);
+
+/// Parses a property into its accessor rpcs. Properties with an initializer, e.g.
+/// `int X { get; set; } = 3;`, also produce a backing field with the initializer as its default.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Property<'a>, Error<'a>> {
     let semicolon = just(';').padded();
     let anything_until_semicolon = any()
         .and_is(just(';').not())
         .repeated()
         .slice()
         .then(semicolon);
-    let initializer = just('=').padded().then(anything_until_semicolon);
+    let initializer = just('=')
+        .padded()
+        .ignore_then(anything_until_semicolon.map(|(initializer, _)| initializer));
 
     // x => value;
     let arrow_shorthand = just("=>").padded().then(anything_until_semicolon);
@@ -48,7 +56,7 @@ pub fn parser(config: &Config) -> impl Parser<&str, Vec<(Rpc, Visibility)>, Erro
     .at_most(2)
     .collect::<Vec<_>>()
     .delimited_by(just('{').padded(), just('}').padded())
-    .then_ignore(initializer.or_not());
+    .then(initializer.or_not());
 
     let field = ty::parser(config)
         .then_ignore(text::whitespace().at_least(1))
@@ -61,15 +69,40 @@ pub fn parser(config: &Config) -> impl Parser<&str, Vec<(Rpc, Visibility)>, Erro
         .then(field)
         .then(choice((
             // Public vis = use field visibility.
-            arrow_shorthand.map(|_| vec![(Accessor::Get, Visibility::Public)]),
+            arrow_shorthand.map(|_| (vec![(Accessor::Get, Visibility::Public)], None)),
             accessor_block,
         )))
-        .map(
+        .validate(
             |(
-                ((((comments, user), visibility), is_static), (return_ty, field_name)),
-                accessors,
-            )| {
-                accessors
+                ((((mut comments, mut user), visibility), is_static), (return_ty, field_name)),
+                (accessors, initializer),
+            ),
+             span,
+             emitter| {
+                let docs = comment::take_docs(&mut comments);
+                let deprecation = attributes::take_deprecation(&mut user);
+                let field = initializer.map(|initializer| {
+                    let default = value::parse(initializer, &return_ty).unwrap_or_else(|err| {
+                        emitter.emit(Rich::custom(span, err));
+                        None
+                    });
+                    let field = Field {
+                        name: field_name,
+                        ty: return_ty.clone(),
+                        attributes: Attributes {
+                            comments: comments.clone(),
+                            deprecation: deprecation.clone(),
+                            docs: docs.clone(),
+                            user: user.clone(),
+                            ..Default::default()
+                        },
+                        is_static,
+                        value: None,
+                        default,
+                    };
+                    (field, visibility)
+                });
+                let rpcs = accessors
                     .into_iter()
                     .map(|(accessor, accessor_visibility)| {
                         let name = match accessor {
@@ -79,14 +112,19 @@ pub fn parser(config: &Config) -> impl Parser<&str, Vec<(Rpc, Visibility)>, Erro
 
                         let rpc = Rpc {
                             name: Cow::Owned(name),
+                            type_params: vec![],
                             params: vec![],
                             return_type: Some(return_ty.clone()),
+                            error_type: None,
                             attributes: Attributes {
                                 comments: comments.clone(),
+                                deprecation: deprecation.clone(),
+                                docs: docs.clone(),
                                 user: user.clone(),
                                 ..Default::default()
                             },
                             is_static,
+                            kind: RpcKind::default(),
                         };
 
                         let visibility = match (accessor_visibility, visibility) {
@@ -100,7 +138,8 @@ pub fn parser(config: &Config) -> impl Parser<&str, Vec<(Rpc, Visibility)>, Erro
 
                         (rpc, visibility)
                     })
-                    .collect_vec()
+                    .collect_vec();
+                (rpcs, field)
             },
         )
 }
@@ -111,7 +150,7 @@ mod tests {
     use crate::parser::visibility::Visibility;
     use anyhow::Result;
     use apyxl::model::attributes::User;
-    use apyxl::model::{Comment, Rpc, Semantics, Type, TypeRef};
+    use apyxl::model::{Comment, Rpc, Semantics, Type, TypeRef, Value};
     use apyxl::parser::test_util::wrap_test_err;
     use apyxl::test_util::executor::TEST_CONFIG;
     use chumsky::Parser;
@@ -236,6 +275,51 @@ mod tests {
         )
     }
 
+    #[test]
+    fn initializer_default() -> Result<()> {
+        let (rpcs, field) = parser(&TEST_CONFIG)
+            .parse("public int prop { get; set; } = 3;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpcs.len(), 2);
+        let (field, visibility) = field.expect("backing field");
+        assert_eq!(field.name, "prop");
+        assert_eq!(field.ty, TypeRef::new(Type::I32, Semantics::Value));
+        assert_eq!(field.default, Some(Value::Int(3)));
+        assert_eq!(field.value, None);
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn non_literal_initializer_no_default() -> Result<()> {
+        let (_, field) = parser(&TEST_CONFIG)
+            .parse("int prop { get; } = Create();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let (field, _) = field.expect("backing field");
+        assert_eq!(field.default, None);
+        Ok(())
+    }
+
+    #[test]
+    fn no_initializer_no_field() -> Result<()> {
+        let (_, field) = parser(&TEST_CONFIG)
+            .parse("int prop { get; set; }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(field.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn unrepresentable_initializer() {
+        let result = parser(&TEST_CONFIG)
+            .parse("ulong prop { get; set; } = 0x1_0000_0000_0000_0000_0000_0000_0000_0000;")
+            .into_result();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn public_accessor_uses_field_visibility() -> Result<()> {
         check_visibility("private int prop { public get; }", Visibility::Private)?;
@@ -385,10 +469,10 @@ mod tests {
     }
 
     fn parse_property(input: &'static str) -> Result<Vec<(Rpc<'static>, Visibility)>> {
-        let prop = parser(&TEST_CONFIG)
+        let (rpcs, _) = parser(&TEST_CONFIG)
             .parse(input)
             .into_result()
             .map_err(wrap_test_err)?;
-        Ok(prop)
+        Ok(rpcs)
     }
 }
@@ -1,68 +1,246 @@
 use crate::parser::is_static::is_static;
 use crate::parser::visibility::Visibility;
-use crate::parser::{attributes, comment, expr_block, ty, visibility};
-use apyxl::model::{Attributes, Field, Rpc};
+use crate::parser::{attributes, comment, expr_block, ty, value, visibility};
+use apyxl::model::attributes::{User, UserData};
+use apyxl::model::{
+    Attributes, EntityId, Field, Rpc, RpcKind, Semantics, Streaming, Type, TypeRef,
+};
 use apyxl::parser::error::Error;
-use apyxl::parser::Config;
+use apyxl::parser::{util, Config};
 use chumsky::prelude::*;
 use std::borrow::Cow;
 
+/// C# spellings of awaitable types, which make an [Rpc] async, e.g. `Task<T>`.
+const TASK_TYPES: &[&str] = &[
+    "Task",
+    "ValueTask",
+    "System.Threading.Tasks.Task",
+    "System.Threading.Tasks.ValueTask",
+];
+
+/// C# spellings of async stream types, which make an [Rpc] streaming, e.g. `IAsyncEnumerable<T>`.
+const STREAM_TYPES: &[&str] = &[
+    "IAsyncEnumerable",
+    "System.Collections.Generic.IAsyncEnumerable",
+];
+
+/// Names of the attribute that declares the error type of an [Rpc], e.g. `[Throws(typeof(E))]`.
+const THROWS_ATTRIBUTES: &[&str] = &["Throws", "ThrowsAttribute"];
+
 pub fn parser(config: &Config) -> impl Parser<&str, (Rpc, Visibility), Error> {
+    let is_async = util::keyword_ex("async")
+        .padded()
+        .or_not()
+        .map(|x| x.is_some());
     let return_type = choice((just("void").map(|_| None), ty::parser(config).map(Some)))
         .then_ignore(text::whitespace().at_least(1));
     let name = text::ident();
-    let params = params(config).delimited_by(
-        just('(').padded(),
-        just(')').padded().recover_with(skip_then_retry_until(
-            none_of(")").ignored(),
-            just(')').ignored(),
-        )),
-    );
+    let params = params(config)
+        .delimited_by(
+            just('(').padded(),
+            just(')').padded().recover_with(skip_then_retry_until(
+                none_of(")").ignored(),
+                just(')').ignored(),
+            )),
+        )
+        .validate(|params: Vec<Field>, span, emitter| {
+            // Client streaming applies to the whole rpc, so it can't express which param streams.
+            let streams = params
+                .iter()
+                .filter(|param| matches!(unwrap(&param.ty, STREAM_TYPES), Some(Some(_))))
+                .count();
+            if streams != 0 && streams != params.len() {
+                emitter.emit(Rich::custom(
+                    span,
+                    "rpcs that stream some but not all of their params are not supported",
+                ));
+            }
+            params
+        });
     comment::multi()
         .then(attributes::attributes().padded())
         .then(visibility::parser(Visibility::Private))
         .then(is_static())
+        .then(is_async)
         .then(return_type)
         .then(name)
+        .then(ty::type_params().or_not())
         .then(params)
-        .then_ignore(expr_block::parser().padded())
+        .then_ignore(ty::constraints().or_not())
+        .then_ignore(choice((
+            expr_block::parser().padded().ignored(),
+            // Interface and abstract methods have no body.
+            just(';').padded().ignored(),
+        )))
         .map(
-            |((((((comments, user), visibility), is_static), return_type), name), params)| {
+            move |(
                 (
-                    Rpc {
-                        name: Cow::Borrowed(name),
-                        params,
-                        return_type,
-                        attributes: Attributes {
-                            comments,
-                            user,
-                            ..Default::default()
-                        },
-                        is_static,
+                    (
+                        (
+                            ((((mut comments, mut user), visibility), is_static), is_async),
+                            mut return_type,
+                        ),
+                        name,
+                    ),
+                    type_params,
+                ),
+                mut params,
+            )| {
+                let type_params = type_params.unwrap_or_default();
+                let kind = unwrap_kind(is_async, &mut params, &mut return_type);
+                let error_type = take_error_type(config, &mut user);
+                let mut rpc = Rpc {
+                    name: Cow::Borrowed(name),
+                    type_params: type_params.clone(),
+                    params,
+                    return_type,
+                    error_type,
+                    attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
+                        comments,
+                        deprecation: attributes::take_deprecation(&mut user),
+                        user,
+                        ..Default::default()
                     },
-                    visibility,
-                )
+                    is_static,
+                    kind,
+                };
+                rpc.apply_type_params(&type_params);
+                (rpc, visibility)
             },
         )
 }
 
+/// Removes [TASK_TYPES] and [STREAM_TYPES] from the `return_type` and [STREAM_TYPES] from the
+/// `params`, returning the [RpcKind] they imply.
+fn unwrap_kind(
+    mut is_async: bool,
+    params: &mut [Field],
+    return_type: &mut Option<TypeRef>,
+) -> RpcKind {
+    if let Some(inner) = return_type.as_ref().and_then(|ty| unwrap(ty, TASK_TYPES)) {
+        is_async = true;
+        *return_type = inner;
+    }
+    let mut server_streaming = false;
+    if let Some(inner) = return_type.as_ref().and_then(|ty| unwrap(ty, STREAM_TYPES)) {
+        server_streaming = true;
+        *return_type = inner;
+    }
+    let mut client_streaming = false;
+    for param in params {
+        if let Some(Some(inner)) = unwrap(&param.ty, STREAM_TYPES) {
+            client_streaming = true;
+            param.ty = inner;
+        }
+    }
+    RpcKind {
+        is_async,
+        streaming: Streaming::new(client_streaming, server_streaming),
+    }
+}
+
+/// Removes a [THROWS_ATTRIBUTES] attribute from `user`, returning its type. The attribute is kept
+/// if its value is not a `typeof` expression of a valid type.
+fn take_error_type<'a>(config: &'a Config, user: &mut Vec<User<'a>>) -> Option<TypeRef> {
+    let index = user
+        .iter()
+        .position(|attr| THROWS_ATTRIBUTES.contains(&attr.name.as_ref()))?;
+    let ty = match user[index].data.as_slice() {
+        [UserData {
+            key: None,
+            value: Cow::Borrowed(value),
+        }] => {
+            let name = value.strip_prefix("typeof(")?.strip_suffix(')')?;
+            ty::parser(config).padded().parse(name).into_output()?
+        }
+        _ => return None,
+    };
+    user.remove(index);
+    Some(ty)
+}
+
+/// Returns the type wrapped by `ty` if it is one of the wrapper types in `names`, e.g. `T` in
+/// `Task<T>`. The inner [Option] is `None` for non-generic wrappers, e.g. `Task`.
+fn unwrap(ty: &TypeRef, names: &[&str]) -> Option<Option<TypeRef>> {
+    let is_wrapper = |id: &EntityId| {
+        names
+            .iter()
+            .any(|name| id.component_names().eq(name.split('.')))
+    };
+    match &ty.value {
+        Type::Api(id) if is_wrapper(id) => Some(None),
+        Type::Generic { ty: id, args } if args.len() == 1 && is_wrapper(id) => {
+            Some(Some(args[0].clone()))
+        }
+        _ => None,
+    }
+}
+
 fn param(config: &Config) -> impl Parser<&str, Field, Error> {
-    let field = ty::parser(config)
+    // Strings may contain `,` or `)`, but nested parens, e.g. `new Dto(a, b)`, are not supported.
+    let string = just('"')
+        .then(none_of('"').repeated())
+        .then(just('"'))
+        .ignored();
+    let default = just('=')
+        .padded()
+        .ignore_then(string.or(none_of(",)").ignored()).repeated().slice());
+    let ty = modifier()
+        .or_not()
+        .then(ty::parser(config))
+        .map(|(modifier, ty)| match modifier {
+            Some(semantics) => TypeRef::new(ty.value, semantics),
+            None => ty,
+        });
+    let field = ty
         .then_ignore(text::whitespace().at_least(1))
-        .then(text::ident());
+        .then(text::ident())
+        .then(default.or_not());
     comment::multi()
         .then(attributes::attributes().padded())
         .then(field)
-        .map(|((comments, user), (ty, name))| Field {
-            name,
-            ty,
-            attributes: Attributes {
-                comments,
-                user,
-                ..Default::default()
+        .validate(
+            |((mut comments, mut user), ((ty, name), default)), span, emitter| {
+                let default = default
+                    .map(|default| value::parse(default, &ty))
+                    .transpose()
+                    .unwrap_or_else(|err| {
+                        emitter.emit(Rich::custom(span, err));
+                        None
+                    })
+                    .flatten();
+                Field {
+                    name,
+                    ty,
+                    attributes: Attributes {
+                        docs: comment::take_docs(&mut comments),
+                        comments,
+                        deprecation: attributes::take_deprecation(&mut user),
+                        user,
+                        ..Default::default()
+                    },
+                    is_static: false,
+                    value: None,
+                    default,
+                }
             },
-            is_static: false,
-        })
+        )
+}
+
+/// Parameter modifiers: `ref` and `out` are [Semantics::Mut], and `in` and `ref readonly` are
+/// [Semantics::Ref].
+fn modifier<'a>() -> impl Parser<'a, &'a str, Semantics, Error<'a>> {
+    choice((
+        util::keyword_ex("ref")
+            .then(text::whitespace().at_least(1))
+            .then(util::keyword_ex("readonly"))
+            .map(|_| Semantics::Ref),
+        util::keyword_ex("ref").map(|_| Semantics::Mut),
+        util::keyword_ex("out").map(|_| Semantics::Mut),
+        util::keyword_ex("in").map(|_| Semantics::Ref),
+    ))
+    .then_ignore(text::whitespace().at_least(1))
 }
 
 fn params(config: &Config) -> impl Parser<&str, Vec<Field>, Error> {
@@ -79,7 +257,9 @@ mod tests {
 
     use crate::parser::rpc;
     use crate::parser::visibility::Visibility;
-    use apyxl::model::{attributes, Comment};
+    use apyxl::model::{
+        attributes, Comment, EntityId, RpcKind, Semantics, Streaming, Type, TypeRef, Value,
+    };
     use apyxl::parser::test_util::wrap_test_err;
     use apyxl::test_util::executor::TEST_CONFIG;
 
@@ -167,6 +347,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn docs() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// <summary>Summary.</summary>
+            /// <param name="a">The param.</param>
+            /// <returns>The result.</returns>
+            int rpc(int a) {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.attributes.comments.is_empty());
+        let docs = rpc.attributes.docs.unwrap();
+        assert_eq!(docs.summary, Comment::unowned(&["Summary."]));
+        assert_eq!(docs.param("a"), Some(&Comment::unowned(&["The param."])));
+        assert_eq!(docs.returns, Comment::unowned(&["The result."]));
+        Ok(())
+    }
+
     #[test]
     fn single_param() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -342,6 +543,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn generic() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            Page<T> rpc_name<T>(T param) where T : struct {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.type_params, vec!["T"]);
+        assert_eq!(
+            rpc.params[0].ty,
+            TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value)
+        );
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new_generic(
+                EntityId::new_unqualified("Page"),
+                vec![TypeRef::new(
+                    Type::TypeParam("T".to_string()),
+                    Semantics::Value
+                )],
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
     #[test]
     fn return_type_weird_spacing() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -361,6 +591,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn param_defaults() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            void rpc(int timeout = 30, string name = "a, b", Dto dto = null, bool flag) {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.params
+                .into_iter()
+                .map(|param| param.default)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(Value::Int(30)),
+                Some(Value::String("a, b".into())),
+                None,
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn param_modifiers() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            void rpc(ref int a, out Dto b, in Dto c, ref readonly Dto d, int inner) {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.params
+                .iter()
+                .map(|param| param.ty.semantics)
+                .collect::<Vec<_>>(),
+            vec![
+                Semantics::Mut,
+                Semantics::Mut,
+                Semantics::Ref,
+                Semantics::Ref,
+                Semantics::Value
+            ]
+        );
+        assert_eq!(rpc.params[4].name, "inner");
+        Ok(())
+    }
+
     #[test]
     fn attributes() -> Result<()> {
         let (rpc, _) = rpc::parser(&TEST_CONFIG)
@@ -381,4 +663,136 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn task() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                public static async Task<int> rpc() {}
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.is_static);
+        assert_eq!(
+            rpc.kind,
+            RpcKind {
+                is_async: true,
+                streaming: Streaming::Unary,
+            }
+        );
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::I32, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn task_without_result() -> Result<()> {
+        for data in [
+            "Task rpc();",
+            "System.Threading.Tasks.ValueTask rpc();",
+            "async void rpc() {}",
+        ] {
+            let (rpc, _) = rpc::parser(&TEST_CONFIG)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert!(rpc.kind.is_async, "{}", data);
+            assert_eq!(rpc.return_type, None, "{}", data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn server_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("IAsyncEnumerable<int> rpc();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(!rpc.kind.is_async);
+        assert_eq!(rpc.kind.streaming, Streaming::Server);
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::I32, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn client_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("Task<bool> rpc(IAsyncEnumerable<int> values);")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.kind.is_async);
+        assert_eq!(rpc.kind.streaming, Streaming::Client);
+        assert_eq!(rpc.params[0].ty, TypeRef::new(Type::I32, Semantics::Value));
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::Bool, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn partial_client_streaming() {
+        let result = rpc::parser(&TEST_CONFIG)
+            .parse("Task rpc(int id, IAsyncEnumerable<int> values);")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bidirectional_streaming() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("IAsyncEnumerable<bool> rpc(IAsyncEnumerable<int> values);")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.kind.streaming, Streaming::Bidirectional);
+        Ok(())
+    }
+
+    #[test]
+    fn throws() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                [flag, Throws(typeof(a.ApiError))]
+                Task<int> rpc();
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.error_type,
+            Some(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("a.ApiError")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            rpc.attributes.user,
+            vec![attributes::User::new_flag("flag")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn throws_not_type_of() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+                [Throws(ApiError)]
+                void rpc();
+                "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.error_type, None);
+        assert_eq!(rpc.attributes.user.len(), 1);
+        Ok(())
+    }
 }
@@ -1,18 +1,31 @@
 use chumsky::input::InputRef;
 use chumsky::prelude::*;
 
-use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::model::{well_known, EntityId, Semantics, TupleElement, Type, TypeRef};
 use apyxl::parser::error::Error;
-use apyxl::parser::Config;
+use apyxl::parser::{util, Config};
 
 const ALLOWED_TYPE_NAME_CHARS: &str = "_<>";
 const LANGUAGE_RESERVED_KEYWORDS: &[&str] = &["namespace", "class", "struct", "interface", "enum"];
 
+/// Fully qualified C# spellings of [well_known] types. Bare names, e.g. `Guid`, are parsed as API
+/// types since the API may define its own.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    ("System.Guid", well_known::UUID),
+    ("System.DateTimeOffset", well_known::TIMESTAMP),
+    ("System.DateTime", well_known::TIMESTAMP),
+    ("System.TimeSpan", well_known::DURATION),
+    ("decimal", well_known::DECIMAL),
+    ("char", well_known::CHAR),
+];
+
 pub fn parser(config: &Config) -> impl Parser<&str, TypeRef, Error> {
     recursive(|nested| {
         let optional_parser = optional(config, nested.clone());
         let array_parser = array(config, nested.clone(), optional_parser.clone());
-        ty(config, nested, array_parser, optional_parser).boxed()
+        span(nested.clone())
+            .or(ty(config, nested, array_parser, optional_parser))
+            .boxed()
     })
 }
 
@@ -42,10 +55,13 @@ fn ty<'a>(
     ))
     .or(choice((
         user_ty(config).map(Type::User),
+        well_known_ty(config).map(Type::User),
         list(nested.clone()),
+        set(nested.clone()),
         map(nested.clone()),
         function(nested.clone()),
-        entity_id().map(Type::Api),
+        tuple(nested.clone()),
+        api_ty(nested),
     )))
     .boxed();
     ty.map(|ty| TypeRef {
@@ -54,6 +70,41 @@ fn ty<'a>(
     })
 }
 
+/// Generic type parameters, e.g. `<T, U>`. Variance modifiers and constraints are not supported,
+/// but see [constraints].
+pub fn type_params<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .padded()
+        .separated_by(just(','))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .delimited_by(just('<'), just('>'))
+}
+
+/// Skips generic type constraints, e.g. `where T : class, new()`.
+pub fn constraints<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex("where")
+        .then(none_of("{;").repeated())
+        .padded()
+        .ignored()
+}
+
+/// Base types of a class, struct or interface, e.g. `: Base, IService<T>`. Generic args are
+/// dropped.
+pub fn base_types<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<EntityId>, Error<'a>> {
+    let base_type = parser(config).try_map(|ty, span| match ty.value {
+        Type::Api(id) | Type::Generic { ty: id, .. } => Ok(id),
+        _ => Err(Rich::custom(span, "base type must be an api type")),
+    });
+    just(':').padded().ignore_then(
+        base_type
+            .padded()
+            .separated_by(just(','))
+            .at_least(1)
+            .collect::<Vec<_>>(),
+    )
+}
+
 fn type_name<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
     any()
         // first char
@@ -86,6 +137,37 @@ fn list<'a>(
         .map(Type::new_array)
 }
 
+fn set<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    just("HashSet<")
+        .or(just("ISet<"))
+        .ignore_then(ty.padded())
+        .then_ignore(just('>'))
+        .map(Type::new_set)
+}
+
+/// `ReadOnlySpan<T>` is an array with [Semantics::Slice] and `Span<T>` is an array with
+/// [Semantics::Mut]. Spans of `char` and `byte` are string views and bytes.
+fn span<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    let element = choice((
+        just("char")
+            .padded()
+            .then(just('>'))
+            .map(|_| Type::StringView),
+        just("byte").padded().then(just('>')).map(|_| Type::Bytes),
+        ty.padded().then_ignore(just('>')).map(Type::new_array),
+    ));
+    choice((
+        just("ReadOnlySpan<").map(|_| Semantics::Slice),
+        just("Span<").map(|_| Semantics::Mut),
+    ))
+    .then(element)
+    .map(|(semantics, ty)| TypeRef::new(ty, semantics))
+}
+
 fn array<'a>(
     config: &'a Config,
     nested_ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone + 'a,
@@ -194,6 +276,24 @@ fn function<'a>(
     ))
 }
 
+/// Value tuples with optionally named elements, e.g. `(int, string)` or `(int x, int y)`.
+fn tuple<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let name = text::whitespace()
+        .at_least(1)
+        .ignore_then(text::ident())
+        .or_not();
+    ty.then(name)
+        .padded()
+        .map(|(ty, name)| TupleElement::new(name.map(str::to_string), ty))
+        .separated_by(just(','))
+        .at_least(2)
+        .collect::<Vec<_>>()
+        .delimited_by(just('('), just(')'))
+        .map(Type::Tuple)
+}
+
 fn user_ty(config: &Config) -> impl Parser<&str, String, Error> {
     custom(move |input| {
         for (i, ty) in config.user_types.iter().enumerate() {
@@ -219,6 +319,51 @@ fn user_ty(config: &Config) -> impl Parser<&str, String, Error> {
     })
 }
 
+/// One of the [WELL_KNOWN_TYPES] that is enabled in `config`. The spelling must not be followed by
+/// more of a type name, e.g. `DateTime` matches but `DateTimeKind` does not.
+fn well_known_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        let start = input.offset();
+        for (parse, name) in WELL_KNOWN_TYPES {
+            if !config.is_well_known_type_enabled(name) {
+                continue;
+            }
+            let marker = input.save();
+            let spelling = just(*parse).then_ignore(
+                any()
+                    .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+                    .not(),
+            );
+            if input.parse(spelling).is_ok() {
+                return Ok(name.to_string());
+            }
+            input.rewind(marker);
+        }
+        Err(Rich::custom(
+            input.span_since(start),
+            "not a well-known type",
+        ))
+    })
+}
+
+/// A type within the API, optionally with generic type arguments, e.g. `a.Page<int>`.
+fn api_ty<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let args = ty
+        .padded()
+        .separated_by(just(','))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .delimited_by(just('<'), just('>'));
+    entity_id()
+        .then(args.or_not())
+        .map(|(id, args)| match args {
+            Some(args) => Type::new_generic(id, args),
+            None => Type::Api(id),
+        })
+}
+
 fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
     type_name()
         .separated_by(just("."))
@@ -235,7 +380,7 @@ mod tests {
         use lazy_static::lazy_static;
 
         use crate::parser::ty;
-        use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+        use apyxl::model::{well_known, EntityId, Semantics, TupleElement, Type, TypeRef};
         use apyxl::parser::test_util::wrap_test_err;
         use apyxl::parser::{Config, UserType};
 
@@ -245,6 +390,7 @@ mod tests {
                     parse: "user_type".to_string(),
                     name: "user".to_string()
                 }],
+                disabled_well_known_types: vec![],
                 enable_parse_private: true,
             };
         }
@@ -368,6 +514,24 @@ mod tests {
             )
         );
 
+        // Set.
+        test!(
+            set,
+            "HashSet<a.b.c>",
+            TypeRef::new_set(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.b.c")),
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+        test!(
+            iset,
+            "ISet< string >",
+            TypeRef::new_set(TypeRef::new(Type::String, Semantics::Value), Semantics::Value)
+        );
+
         // Map.
         test!(
             map,
@@ -421,6 +585,50 @@ mod tests {
             )
         );
 
+        // Span.
+        test!(
+            read_only_span,
+            "ReadOnlySpan<a.b.c>",
+            TypeRef::new_array(
+                TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("a.b.c")),
+                    Semantics::Value
+                ),
+                Semantics::Slice
+            )
+        );
+        test!(
+            read_only_span_char,
+            "ReadOnlySpan<char>",
+            TypeRef::new(Type::StringView, Semantics::Slice)
+        );
+        test!(
+            read_only_span_byte,
+            "ReadOnlySpan< byte >",
+            TypeRef::new(Type::Bytes, Semantics::Slice)
+        );
+        test!(
+            read_only_span_bytes,
+            "ReadOnlySpan<byte[]>",
+            TypeRef::new_array(
+                TypeRef::new(Type::Bytes, Semantics::Value),
+                Semantics::Slice
+            )
+        );
+        test!(
+            span,
+            "Span<int>",
+            TypeRef::new_array(TypeRef::new(Type::I32, Semantics::Value), Semantics::Mut)
+        );
+        test!(
+            span_nested,
+            "List<ReadOnlySpan<byte>>",
+            TypeRef::new_array(
+                TypeRef::new(Type::Bytes, Semantics::Slice),
+                Semantics::Value
+            )
+        );
+
         // Option.
         test!(
             option,
@@ -507,6 +715,60 @@ mod tests {
             )
         );
 
+        test!(
+            generic,
+            "Page<int, a.B>",
+            TypeRef::new_generic(
+                EntityId::new_unqualified("Page"),
+                vec![
+                    TypeRef::new(Type::I32, Semantics::Value),
+                    TypeRef::new(
+                        Type::Api(EntityId::new_unqualified("a.B")),
+                        Semantics::Value
+                    ),
+                ],
+                Semantics::Value
+            )
+        );
+
+        test!(
+            tuple,
+            "(int, a.B)",
+            TypeRef::new_tuple(
+                [
+                    TypeRef::new(Type::I32, Semantics::Value),
+                    TypeRef::new(Type::Api(EntityId::new_unqualified("a.B")), Semantics::Value),
+                ],
+                Semantics::Value
+            )
+        );
+        test!(
+            tuple_named,
+            "( int x, List<(float, float)> points )",
+            TypeRef::new(
+                Type::Tuple(vec![
+                    TupleElement::new(
+                        Some("x".to_string()),
+                        TypeRef::new(Type::I32, Semantics::Value)
+                    ),
+                    TupleElement::new(
+                        Some("points".to_string()),
+                        TypeRef::new_array(
+                            TypeRef::new_tuple(
+                                [
+                                    TypeRef::new(Type::F32, Semantics::Value),
+                                    TypeRef::new(Type::F32, Semantics::Value),
+                                ],
+                                Semantics::Value
+                            ),
+                            Semantics::Value
+                        )
+                    ),
+                ]),
+                Semantics::Value
+            )
+        );
+
         // Defined in CONFIG.
         test!(
             user,
@@ -514,6 +776,40 @@ mod tests {
             TypeRef::new(Type::User("user".to_string()), Semantics::Value)
         );
 
+        // Well-known.
+        test!(
+            well_known,
+            "System.Guid",
+            TypeRef::new(Type::User(well_known::UUID.to_string()), Semantics::Value)
+        );
+        test!(
+            well_known_optional,
+            "System.DateTimeOffset?",
+            TypeRef::new_optional(
+                TypeRef::new(
+                    Type::User(well_known::TIMESTAMP.to_string()),
+                    Semantics::Value
+                ),
+                Semantics::Value
+            )
+        );
+        test!(
+            well_known_bare,
+            "Guid",
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Guid")),
+                Semantics::Value
+            )
+        );
+        test!(
+            well_known_prefix,
+            "DateTimeKind",
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("DateTimeKind")),
+                Semantics::Value
+            )
+        );
+
         fn run_test(data: &'static str, expected: TypeRef) -> Result<()> {
             let ty = ty::parser(&TY_TEST_CONFIG)
                 .parse(data)
@@ -552,6 +848,26 @@ mod tests {
         }
     }
 
+    mod well_known_ty {
+        use chumsky::Parser;
+
+        use crate::parser::ty::well_known_ty;
+        use apyxl::model::well_known;
+        use apyxl::parser::Config;
+
+        #[test]
+        fn disabled() {
+            let config = Config {
+                disabled_well_known_types: vec![well_known::DECIMAL.to_string()],
+                ..Default::default()
+            };
+            let ty = well_known_ty(&config).parse("char").into_output();
+            assert_eq!(ty, Some(well_known::CHAR.to_string()));
+            let ty = well_known_ty(&config).parse("decimal").into_output();
+            assert_eq!(ty, None);
+        }
+    }
+
     mod entity_id {
         use anyhow::Result;
         use chumsky::Parser;
@@ -14,11 +14,14 @@ pub fn parser(config: &Config) -> impl Parser<&str, TypeAlias, Error> {
         .then(alias_name)
         .then(ty::parser(config))
         .then_ignore(just(';').padded())
-        .map(|(((comments, user), name), target)| TypeAlias {
+        .map(|(((mut comments, mut user), name), target)| TypeAlias {
             name,
+            type_params: vec![],
             target_ty: target,
             attributes: Attributes {
+                docs: comment::take_docs(&mut comments),
                 comments,
+                deprecation: attributes::take_deprecation(&mut user),
                 user,
                 ..Default::default()
             },
@@ -0,0 +1,242 @@
+use std::borrow::Cow;
+use std::str::Chars;
+
+use apyxl::model::{TypeRef, Value};
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+/// Literal value of a constant with type `ty`, e.g. `5`, `-1.5f`, `"str"`, `@"str"`, `'c'`, `true`,
+/// or `Status.Active`. Anything else, e.g. expressions like `1 << 2` or references to other
+/// constants, is `None`. Literals that can't be represented, e.g. integers that overflow
+/// `UInt128`, are an error.
+pub fn parse<'a>(initializer: &'a str, ty: &TypeRef) -> Result<Option<Value<'a>>, &'static str> {
+    let initializer = initializer.trim();
+    match literal().parse(initializer).into_result() {
+        Ok(value) => value.map(Some),
+        Err(_) => Ok(path()
+            .parse(initializer)
+            .into_result()
+            .ok()
+            .and_then(|path| util::enum_value(&path, ty))),
+    }
+}
+
+/// A literal, or the reason it can't be represented as a [Value].
+fn literal<'a>() -> impl Parser<'a, &'a str, Result<Value<'a>, &'static str>, Error<'a>> {
+    let boolean = choice((
+        util::keyword_ex("true").to(Ok(Value::Bool(true))),
+        util::keyword_ex("false").to(Ok(Value::Bool(false))),
+    ));
+    let escape = just('\\').then(any()).ignored();
+    let string = escape
+        .or(none_of("\\\"").ignored())
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'))
+        .map(|s| {
+            util::unescape(s, escape_sequence)
+                .map(Value::String)
+                .ok_or("invalid escape sequence")
+        });
+    // Verbatim strings have no escape sequences other than `""` for a quote.
+    let verbatim_string = just("\"\"")
+        .ignored()
+        .or(none_of('"').ignored())
+        .repeated()
+        .slice()
+        .delimited_by(just("@\""), just('"'))
+        .map(|s: &str| {
+            Ok(Value::String(if s.contains("\"\"") {
+                Cow::Owned(s.replace("\"\"", "\""))
+            } else {
+                Cow::Borrowed(s)
+            }))
+        });
+    let char = escape
+        .or(none_of("\\'").ignored())
+        .repeated()
+        .at_least(1)
+        .slice()
+        .delimited_by(just('\''), just('\''))
+        .map(|s| {
+            util::unescape(s, escape_sequence)
+                .and_then(|s| s.chars().exactly_one().ok())
+                .map(Value::Char)
+                .ok_or("invalid char literal")
+        });
+    let number = just('-')
+        .or_not()
+        .then(text::digits(10))
+        .then(
+            any()
+                .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+                .repeated(),
+        )
+        .slice()
+        .map(|s| number(s).ok_or("invalid number literal"));
+    choice((boolean, string, verbatim_string, char, number))
+}
+
+/// Integer or real literal with optional `_` separators, `0x`/`0b` prefixes, and suffixes, e.g.
+/// `1_000u` or `2.5f`.
+fn number(s: &str) -> Option<Value<'_>> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let s = s.replace('_', "");
+    for (prefix, radix) in [("0x", 16), ("0X", 16), ("0b", 2), ("0B", 2)] {
+        if let Some(digits) = s.strip_prefix(prefix) {
+            let digits = digits.trim_end_matches(['u', 'U', 'l', 'L']);
+            return u128::from_str_radix(digits, radix)
+                .ok()
+                .and_then(|n| util::int_value(negative, n));
+        }
+    }
+    let real_suffixes = ['f', 'F', 'd', 'D', 'm', 'M'];
+    let (digits, suffix) = s.split_at(
+        s.find(['u', 'U', 'l', 'L'])
+            .or_else(|| s.find(real_suffixes))
+            .unwrap_or(s.len()),
+    );
+    if suffix.starts_with(real_suffixes) || digits.contains(['.', 'e', 'E']) {
+        let n = digits.parse::<f64>().ok()?;
+        Some(Value::Float(if negative { -n } else { n }))
+    } else {
+        digits
+            .parse::<u128>()
+            .ok()
+            .and_then(|n| util::int_value(negative, n))
+    }
+}
+
+/// C# string or char escape sequence following a `\`, e.g. `n`, `x41`, or `u00E9`.
+fn escape_sequence(chars: &mut Chars) -> Option<char> {
+    Some(match chars.next()? {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        '0' => '\0',
+        'a' => '\u{7}',
+        'b' => '\u{8}',
+        'f' => '\u{c}',
+        'v' => '\u{b}',
+        c @ ('\\' | '\'' | '"') => c,
+        'x' => util::hex_escape(chars, 1, 4)?,
+        'u' => util::hex_escape(chars, 4, 4)?,
+        'U' => util::hex_escape(chars, 8, 8)?,
+        _ => return None,
+    })
+}
+
+fn path<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(2)
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::value;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef, Value};
+
+    #[test]
+    fn int() {
+        let ty = TypeRef::new(Type::I64, Semantics::Value);
+        assert_eq!(value::parse("5", &ty), Ok(Some(Value::Int(5))));
+        assert_eq!(value::parse("-5", &ty), Ok(Some(Value::Int(-5))));
+        assert_eq!(value::parse("1_000UL", &ty), Ok(Some(Value::Int(1000))));
+        assert_eq!(value::parse("0xFF", &ty), Ok(Some(Value::Int(255))));
+    }
+
+    #[test]
+    fn uint() {
+        let ty = TypeRef::new(Type::U64, Semantics::Value);
+        assert_eq!(
+            value::parse("18446744073709551615UL", &ty),
+            Ok(Some(Value::UInt(u64::MAX as u128)))
+        );
+        assert_eq!(
+            value::parse("0xFFFFFFFFFFFFFFFF", &ty),
+            Ok(Some(Value::UInt(u64::MAX as u128)))
+        );
+        assert!(value::parse("340282366920938463463374607431768211456", &ty).is_err());
+    }
+
+    #[test]
+    fn float() {
+        let ty = TypeRef::new(Type::F32, Semantics::Value);
+        assert_eq!(value::parse("1.5", &ty), Ok(Some(Value::Float(1.5))));
+        assert_eq!(value::parse("-2.5f", &ty), Ok(Some(Value::Float(-2.5))));
+        assert_eq!(value::parse("2d", &ty), Ok(Some(Value::Float(2.0))));
+        assert_eq!(value::parse("1e3", &ty), Ok(Some(Value::Float(1000.0))));
+        assert_eq!(value::parse("1.5m", &ty), Ok(Some(Value::Float(1.5))));
+    }
+
+    #[test]
+    fn bool() {
+        let ty = TypeRef::new(Type::Bool, Semantics::Value);
+        assert_eq!(value::parse("true", &ty), Ok(Some(Value::Bool(true))));
+        assert_eq!(value::parse("false", &ty), Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn char() {
+        let ty = TypeRef::new(Type::User("char".to_string()), Semantics::Value);
+        assert_eq!(value::parse("'a'", &ty), Ok(Some(Value::Char('a'))));
+        assert_eq!(value::parse(r"'\n'", &ty), Ok(Some(Value::Char('\n'))));
+        assert_eq!(value::parse(r"'\u00e9'", &ty), Ok(Some(Value::Char('\u{e9}'))));
+        assert!(value::parse("'ab'", &ty).is_err());
+    }
+
+    #[test]
+    fn string() {
+        let ty = TypeRef::new(Type::String, Semantics::Value);
+        assert_eq!(
+            value::parse(r#""name""#, &ty),
+            Ok(Some(Value::String("name".into())))
+        );
+        assert_eq!(
+            value::parse(r#""\a\x41\u00e9\U0001F600\"""#, &ty),
+            Ok(Some(Value::String("\u{7}A\u{e9}\u{1F600}\"".into())))
+        );
+        assert!(value::parse(r#""\q""#, &ty).is_err());
+    }
+
+    #[test]
+    fn verbatim_string() {
+        let ty = TypeRef::new(Type::String, Semantics::Value);
+        assert_eq!(
+            value::parse(r#"@"C:\dir""#, &ty),
+            Ok(Some(Value::String(r"C:\dir".into())))
+        );
+        assert_eq!(
+            value::parse(r#"@"a ""b""""#, &ty),
+            Ok(Some(Value::String(r#"a "b""#.into())))
+        );
+    }
+
+    #[test]
+    fn enum_value() {
+        let ty = TypeRef::new(
+            Type::Api(EntityId::new_unqualified("Status")),
+            Semantics::Value,
+        );
+        assert_eq!(
+            value::parse("Status.Active", &ty),
+            Ok(Some(Value::EnumValue("Active".into())))
+        );
+        assert_eq!(value::parse("Math.PI", &ty), Ok(None));
+    }
+
+    #[test]
+    fn not_literal() {
+        let ty = TypeRef::new(Type::I32, Semantics::Value);
+        assert_eq!(value::parse("1 << 2", &ty), Ok(None));
+        assert_eq!(value::parse("OtherConst", &ty), Ok(None));
+        assert_eq!(value::parse("int.MaxValue", &ty), Ok(None));
+    }
+}
@@ -0,0 +1,3 @@
+pub use parser::GoParser as Parser;
+
+mod parser;
@@ -0,0 +1,61 @@
+use apyxl::model::Comment;
+use apyxl::parser::comment;
+use apyxl::parser::error::Error;
+use chumsky::Parser;
+use chumsky::prelude::*;
+
+pub fn single<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
+    comment::single(just("//"), just("/*"), just("*/"))
+}
+
+pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> {
+    comment::multi(just("//"), just("/*"), just("*/"))
+}
+
+/// Line comment on the same line as a declaration, e.g. the comment in `Name string // comment`.
+/// Unlike [single], this never continues onto the following lines, since those belong to the
+/// next declaration.
+pub fn trailing<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
+    text::inline_whitespace()
+        .then(just("//"))
+        .then(just(' ').or_not())
+        .ignore_then(none_of('\n').repeated().slice())
+        .map(|s: &str| Comment::from(vec![s.trim_end()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::prelude::*;
+
+    use crate::parser::comment;
+    use apyxl::model::Comment;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn block_and_line() -> Result<()> {
+        let comments = comment::multi()
+            .parse("/* block */\n// line 1\n// line 2\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            comments,
+            vec![
+                Comment::unowned(&["block"]),
+                Comment::unowned(&["line 1", "line 2"])
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn trailing() -> Result<()> {
+        let comment = comment::trailing()
+            .then_ignore(just("\n// next\n"))
+            .parse("  // trailing \n// next\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(comment, Comment::unowned(&["trailing"]));
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use chumsky::prelude::*;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, expr_block, field, rpc};
+use apyxl::model::{Dto, Field, Rpc};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `struct` and `interface` type specs without the leading `type` keyword, e.g.
+/// `Name struct { ... }` or `Name interface { ... }`. Comments before the `type` keyword belong
+/// to the spec, so they are attached by the caller.
+///
+/// Struct fields are [Field]s, and interface methods are [Rpc]s. Embedded fields and interfaces,
+/// as well as type constraints like `~int | ~string`, are skipped. Unexported members are
+/// filtered using [Visibility::of].
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Dto<'a>, Error<'a>> {
+    let field = choice((
+        field::parser(config),
+        // Catch comments after all fields.
+        comment::single().padded().map(|_| vec![]),
+    ));
+    let fields = field
+        .recover_with(skip_then_retry_until(
+            choice((expr_block::parser().ignored(), any().ignored())),
+            just('}').ignored(),
+        ))
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}'))
+        .map(|fields| Members {
+            fields: fields.into_iter().flatten().collect(),
+            rpcs: vec![],
+        });
+    let method = choice((
+        rpc::parser(config).map(Some),
+        // Catch comments before embedded interfaces.
+        comment::single().padded().map(|_| None),
+        none_of("\n;}")
+            .repeated()
+            .at_least(1)
+            .padded()
+            .map(|_| None),
+    ));
+    let methods = method
+        .then_ignore(just(';').padded().or_not())
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}'))
+        .map(|rpcs| Members {
+            fields: vec![],
+            rpcs: rpcs.into_iter().flatten().collect(),
+        });
+    text::ident()
+        .then_ignore(text::inline_whitespace())
+        .then(choice((
+            body_keyword("struct").ignore_then(fields),
+            body_keyword("interface").ignore_then(methods),
+        )))
+        .map(move |(name, members)| Dto {
+            name,
+            type_params: vec![],
+            fields: members
+                .fields
+                .into_iter()
+                .filter(|field| Visibility::of(field.name).is_visible(config))
+                .collect(),
+            rpcs: members
+                .rpcs
+                .into_iter()
+                .filter(|rpc| Visibility::of(&rpc.name).is_visible(config))
+                .collect(),
+            extends: vec![],
+            implements: vec![],
+            attributes: Default::default(),
+            namespace: None,
+        })
+}
+
+/// `struct` and `interface` may be immediately followed by the opening brace.
+fn body_keyword<'a>(keyword: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex(keyword).then(text::whitespace()).ignored()
+}
+
+struct Members<'a> {
+    fields: Vec<Field<'a>>,
+    rpcs: Vec<Rpc<'a>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use apyxl::model::{Comment, Type};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn empty() -> Result<()> {
+        let dto = dto::parser(&TEST_CONFIG)
+            .parse("Name struct{}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Name");
+        assert!(dto.fields.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn fields() -> Result<()> {
+        let dto = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"Name struct {
+                Base
+                // comment
+                A int32 `json:"a"`
+                B, C string // trailing
+
+                d bool
+                // end comment
+            }"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 4);
+        assert_eq!(dto.fields[0].name, "A");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "B");
+        assert_eq!(dto.fields[2].name, "C");
+        assert_eq!(dto.fields[3].name, "d");
+        assert_eq!(dto.fields[3].ty.value, Type::Bool);
+        Ok(())
+    }
+
+    #[test]
+    fn unexported_fields_filtered() -> Result<()> {
+        let dto = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse("Name struct { A int; b int }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "A");
+        Ok(())
+    }
+
+    #[test]
+    fn interface() -> Result<()> {
+        let dto = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"Service interface {
+                io.Closer
+                // Get comment
+                Get(id string) (*User, error)
+                List() []User
+                internal()
+            }"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Service");
+        assert!(dto.fields.is_empty());
+        assert_eq!(dto.rpcs.len(), 2);
+        assert_eq!(dto.rpcs[0].name, "Get");
+        assert_eq!(
+            dto.rpcs[0].attributes.comments,
+            vec![Comment::unowned(&["Get comment"])]
+        );
+        assert_eq!(dto.rpcs[1].name, "List");
+        Ok(())
+    }
+
+    #[test]
+    fn constraint() -> Result<()> {
+        let dto = dto::parser(&TEST_CONFIG)
+            .parse("Number interface {\n ~int | ~float64\n}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(dto.rpcs.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,415 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, expr_block};
+use apyxl::model::{
+    Attributes, Comment, Enum, EnumKind, EnumValue, EnumValueNumber, NamespaceChild, TypeAlias,
+};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+
+/// A named constant, e.g. `Active Status = iota`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Const<'a> {
+    pub name: &'a str,
+    /// Name of the constant's type, or `None` if it is untyped.
+    pub ty: Option<&'a str>,
+    /// `None` if the value is not an integer constant expression, e.g. a string.
+    pub number: Option<EnumValueNumber>,
+    pub comments: Vec<Comment<'a>>,
+}
+
+/// `const` declarations, e.g. `const A Status = 1` or a grouped `const ( ... )` block.
+///
+/// Specs without a value repeat the type and expression of the previous spec with the next
+/// `iota`, so typed `iota` blocks like `const ( A Status = iota; B; C )` are supported. Blank
+/// (`_`) constants are skipped but still consume an `iota`.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Const<'a>>, Error<'a>> {
+    let group = spec()
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .delimited_by(just('(').padded(), just(')'));
+    comment::multi()
+        .padded()
+        .then_ignore(keyword("const"))
+        .then(choice((group, spec().map(|spec| vec![spec]))))
+        .padded()
+        .map(|(comments, mut specs)| {
+            if let [spec] = specs.as_mut_slice()
+                && spec.comments.is_empty()
+            {
+                spec.comments = comments;
+            }
+            consts(specs)
+        })
+}
+
+/// Converts a defined type into an enum if there are constants of that type, e.g. `type Status int`
+/// and `const ( Active Status = iota; Inactive )`. Constants are numbered by their value if it
+/// could be evaluated, otherwise after the previous constant. Defined types without constants
+/// are type aliases of their underlying type.
+pub fn from_consts<'a>(
+    alias: TypeAlias<'a>,
+    consts: &[Const<'a>],
+    config: &Config,
+) -> NamespaceChild<'a> {
+    let values = consts
+        .iter()
+        .filter(|c| c.ty == Some(alias.name))
+        .filter(|c| Visibility::of(c.name).is_visible(config))
+        .map(|c| EnumValue {
+            name: c.name,
+            number: c.number.unwrap_or(INVALID_ENUM_NUMBER),
+            attributes: Attributes {
+                comments: c.comments.clone(),
+                ..Default::default()
+            },
+        })
+        .collect_vec();
+    if values.is_empty() {
+        NamespaceChild::TypeAlias(alias)
+    } else {
+        NamespaceChild::Enum(Enum {
+            name: alias.name,
+            values: apply_enum_value_number_defaults(values),
+            kind: EnumKind::Plain,
+            underlying_type: None,
+            attributes: alias.attributes,
+        })
+    }
+}
+
+struct Spec<'a> {
+    names: Vec<&'a str>,
+    ty: Option<&'a str>,
+    /// `None` if the spec has no value and repeats the previous one. `Some(None)` if the value
+    /// could not be parsed as an [Expr].
+    value: Option<Option<Expr>>,
+    comments: Vec<Comment<'a>>,
+}
+
+fn spec<'a>() -> impl Parser<'a, &'a str, Spec<'a>, Error<'a>> {
+    let names = text::ident()
+        .separated_by(just(',').padded())
+        .at_least(1)
+        .collect::<Vec<_>>();
+    let ty = text::ident().separated_by(just('.')).at_least(1).slice();
+    let spec_end = choice((just("//").ignored(), one_of("\n;)").ignored(), end()));
+    let value = just('=')
+        .padded_by(text::inline_whitespace())
+        .ignore_then(choice((
+            expr()
+                .then_ignore(text::inline_whitespace())
+                .then_ignore(spec_end.rewind())
+                .map(Some),
+            expr_block::expr("\n;)").map(|_| None),
+        )));
+    comment::multi()
+        .padded()
+        .then(names)
+        .then(text::inline_whitespace().ignore_then(ty).or_not())
+        .then(text::inline_whitespace().ignore_then(value).or_not())
+        .then(comment::trailing().or_not())
+        .then_ignore(just(';').or_not())
+        .map(|((((mut comments, names), ty), value), trailing)| {
+            comments.extend(trailing);
+            Spec {
+                names,
+                ty,
+                value,
+                comments,
+            }
+        })
+}
+
+fn consts(specs: Vec<Spec>) -> Vec<Const> {
+    let mut consts = vec![];
+    let mut ty = None;
+    let mut expr = None;
+    for (iota, spec) in (0..).zip(specs) {
+        if let Some(value) = spec.value {
+            ty = spec.ty;
+            expr = value;
+        }
+        // Values of multiple constants in one spec, e.g. `A, B = 1, 2`, are not evaluated.
+        let number = match spec.names.len() {
+            1 => expr.as_ref().and_then(|expr| expr.eval(iota)),
+            _ => None,
+        };
+        for name in spec.names {
+            if name == "_" {
+                continue;
+            }
+            consts.push(Const {
+                name,
+                ty,
+                number,
+                comments: spec.comments.clone(),
+            });
+        }
+    }
+    consts
+}
+
+/// Integer constant expressions of `iota`, e.g. `1 << iota` or `-(iota + 1)`.
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Int(EnumValueNumber),
+    Iota,
+    Neg(Box<Expr>),
+    Binary(Box<Expr>, Op, Box<Expr>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Or,
+    Xor,
+    Mul,
+    And,
+    Shl,
+    Shr,
+}
+
+impl Expr {
+    /// `None` if the expression overflows.
+    fn eval(&self, iota: EnumValueNumber) -> Option<EnumValueNumber> {
+        match self {
+            Expr::Int(value) => Some(*value),
+            Expr::Iota => Some(iota),
+            Expr::Neg(expr) => expr.eval(iota)?.checked_neg(),
+            Expr::Binary(lhs, op, rhs) => {
+                let lhs = lhs.eval(iota)?;
+                let rhs = rhs.eval(iota)?;
+                match op {
+                    Op::Add => lhs.checked_add(rhs),
+                    Op::Sub => lhs.checked_sub(rhs),
+                    Op::Or => Some(lhs | rhs),
+                    Op::Xor => Some(lhs ^ rhs),
+                    Op::Mul => lhs.checked_mul(rhs),
+                    Op::And => Some(lhs & rhs),
+                    Op::Shl => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shl(rhs)),
+                    Op::Shr => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shr(rhs)),
+                }
+            }
+        }
+    }
+}
+
+fn expr<'a>() -> impl Parser<'a, &'a str, Expr, Error<'a>> {
+    recursive(|expr| {
+        let hex = choice((just("0x"), just("0X")))
+            .ignore_then(text::digits(16).slice())
+            .try_map(|s: &str, span| {
+                EnumValueNumber::from_str_radix(s, 16)
+                    .map_err(|err| Rich::custom(span, err.to_string()))
+            });
+        let dec = text::int(10).try_map(|s: &str, span| {
+            s.parse::<EnumValueNumber>()
+                .map_err(|err| Rich::custom(span, err.to_string()))
+        });
+        let atom = choice((
+            hex.map(Expr::Int),
+            dec.map(Expr::Int),
+            util::keyword_ex("iota").to(Expr::Iota),
+            expr.delimited_by(just('('), just(')')),
+        ))
+        .padded_by(text::inline_whitespace());
+        let unary = just('-')
+            .padded_by(text::inline_whitespace())
+            .repeated()
+            .foldr(atom, |_, expr| Expr::Neg(Box::new(expr)))
+            .boxed();
+        let product_op = choice((
+            just("<<").to(Op::Shl),
+            just(">>").to(Op::Shr),
+            just("*").to(Op::Mul),
+            just("&").to(Op::And),
+        ));
+        let product = unary
+            .clone()
+            .foldl(product_op.then(unary).repeated(), |lhs, (op, rhs)| {
+                Expr::Binary(Box::new(lhs), op, Box::new(rhs))
+            });
+        let sum_op = choice((
+            just('+').to(Op::Add),
+            just('-').to(Op::Sub),
+            just('|').to(Op::Or),
+            just('^').to(Op::Xor),
+        ));
+        product
+            .clone()
+            .foldl(sum_op.then(product).repeated(), |lhs, (op, rhs)| {
+                Expr::Binary(Box::new(lhs), op, Box::new(rhs))
+            })
+    })
+}
+
+fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
+    let mut i = 0;
+    for value in &mut values {
+        if value.number == INVALID_ENUM_NUMBER {
+            value.number = i;
+            i += 1;
+        } else {
+            i = value.number + 1;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use crate::parser::en::Const;
+    use apyxl::model::{Comment, NamespaceChild, Semantics, Type, TypeAlias, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn iota() -> Result<()> {
+        let consts = en::parser()
+            .parse(
+                r#"
+            const (
+                // zero comment
+                Zero Status = iota
+                One // one comment
+                _
+                Three
+            )
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            consts,
+            vec![
+                Const {
+                    name: "Zero",
+                    ty: Some("Status"),
+                    number: Some(0),
+                    comments: vec![Comment::unowned(&["zero comment"])],
+                },
+                Const {
+                    name: "One",
+                    ty: Some("Status"),
+                    number: Some(1),
+                    comments: vec![Comment::unowned(&["one comment"])],
+                },
+                Const {
+                    name: "Three",
+                    ty: Some("Status"),
+                    number: Some(3),
+                    comments: vec![],
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expressions() -> Result<()> {
+        let consts = en::parser()
+            .parse(
+                r#"const (
+                A Flag = 1 << iota
+                B
+                C
+                D Flag = 0x10 | (2 * -3 + iota)
+                E = -1
+            )"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let numbers = consts.iter().map(|c| c.number).collect::<Vec<_>>();
+        assert_eq!(
+            numbers,
+            vec![Some(1), Some(2), Some(4), Some(0x10 | -3), Some(-1)]
+        );
+        assert_eq!(consts[4].ty, None, "value resets type");
+        Ok(())
+    }
+
+    #[test]
+    fn unevaluated() -> Result<()> {
+        let consts = en::parser()
+            .parse(
+                r#"const (
+                A Kind = "a" // comment
+                B Kind = strings.ToUpper("b)")
+                C, D Kind = 1, 2
+            )"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(consts.len(), 4);
+        assert!(consts.iter().all(|c| c.number.is_none()));
+        assert!(consts.iter().all(|c| c.ty == Some("Kind")));
+        Ok(())
+    }
+
+    #[test]
+    fn single() -> Result<()> {
+        let consts = en::parser()
+            .parse("// comment\nconst Max Size = 100")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            consts,
+            vec![Const {
+                name: "Max",
+                ty: Some("Size"),
+                number: Some(100),
+                comments: vec![Comment::unowned(&["comment"])],
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_consts() -> Result<()> {
+        let consts = en::parser()
+            .parse("const (\n A Status = iota + 2\n b\n C Status = iota * 10\n D\n)")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let child = en::from_consts(alias("Status"), &consts, &TEST_CONFIG);
+        let NamespaceChild::Enum(en) = child else {
+            panic!("expected enum");
+        };
+        let values = en
+            .values
+            .iter()
+            .map(|v| (v.name, v.number))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![("A", 2), ("b", 3), ("C", 20), ("D", 30)]);
+
+        let child = en::from_consts(alias("Other"), &consts, &TEST_CONFIG);
+        assert!(matches!(child, NamespaceChild::TypeAlias(_)));
+
+        let child = en::from_consts(alias("Status"), &consts, &TEST_PUB_ONLY_CONFIG);
+        let NamespaceChild::Enum(en) = child else {
+            panic!("expected enum");
+        };
+        assert_eq!(en.values.len(), 3);
+        Ok(())
+    }
+
+    fn alias(name: &str) -> TypeAlias<'_> {
+        TypeAlias {
+            name,
+            type_params: vec![],
+            target_ty: TypeRef::new(Type::I32, Semantics::Value),
+            attributes: Default::default(),
+        }
+    }
+}
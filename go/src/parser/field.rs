@@ -0,0 +1,267 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, Field, Semantics, Type, TypeRef, attributes};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Struct field declaration e.g. `Name, Alias string `json:"name"``. Multiple names are parsed as
+/// separate fields that share the same comments and tags. Embedded fields like `Base` or
+/// `*pkg.Base` are parsed as no fields.
+///
+/// A comment on the same line as the field is appended to its comments.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    let named = names()
+        .then_ignore(text::inline_whitespace().at_least(1))
+        .then(ty::parser(config))
+        .map(Some);
+    let embedded = just('*')
+        .or_not()
+        .then(text::ident().separated_by(just('.')).at_least(1))
+        .map(|_| None);
+    comment::multi()
+        .padded()
+        .then(choice((named, embedded)))
+        .then(text::inline_whitespace().ignore_then(tags()).or_not())
+        .then(comment::trailing().or_not())
+        .then_ignore(just(';').or_not())
+        .padded()
+        .map(|(((mut comments, named), user), trailing)| {
+            let Some((names, ty)) = named else {
+                return vec![];
+            };
+            comments.extend(trailing);
+            let user = user.unwrap_or_default();
+            names
+                .into_iter()
+                .map(|name| Field {
+                    name,
+                    ty: ty.clone(),
+                    attributes: Attributes {
+                        comments: comments.clone(),
+                        user: user.clone(),
+                        ..Default::default()
+                    },
+                    is_static: false,
+                    value: None,
+                    default: None,
+                })
+                .collect_vec()
+        })
+}
+
+/// Struct tags e.g. `` `json:"name,omitempty" db:"name"` ``. Each key is a user attribute with
+/// the comma-separated parts of its value as the data, e.g. `json(name, omitempty)`.
+fn tags<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> {
+    let value = none_of('"')
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'));
+    let tag = none_of(": \t\n`\"")
+        .repeated()
+        .at_least(1)
+        .slice()
+        .then_ignore(just(':'))
+        .then(value)
+        .map(|(key, value): (&str, &str)| attributes::User {
+            name: Cow::Borrowed(key),
+            data: value
+                .split(',')
+                .map(|part| attributes::UserData::new(None, part))
+                .collect(),
+        });
+    tag.padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('`'), just('`'))
+}
+
+/// Function or method parameters within parentheses, e.g. `(ctx context.Context, a, b int)`.
+/// Results use the same syntax, so this also parses result lists like `(int, error)`.
+///
+/// Unnamed parameters are named `_`. A variadic parameter like `names ...string` is parsed as an
+/// array.
+pub fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    let ty =
+        just("...")
+            .or_not()
+            .then(ty::parser(config))
+            .map(|(is_variadic, ty)| match is_variadic {
+                Some(_) => TypeRef::new(Type::new_array(ty), Semantics::Value),
+                None => ty,
+            });
+    let end = text::whitespace().then(just(')'));
+    let named = names()
+        .then_ignore(text::inline_whitespace().at_least(1))
+        .then(ty.clone())
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(end)
+        .map(|groups| {
+            groups
+                .into_iter()
+                .flat_map(|(names, ty)| names.into_iter().map(move |name| param(name, ty.clone())))
+                .collect_vec()
+        });
+    let unnamed = ty
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(end)
+        .map(|tys| tys.into_iter().map(|ty| param("_", ty)).collect_vec());
+    just('(').padded().ignore_then(choice((named, unnamed)))
+}
+
+fn names<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> + Clone {
+    text::ident()
+        .separated_by(just(',').padded())
+        .at_least(1)
+        .collect::<Vec<_>>()
+}
+
+fn param<'a>(name: &'a str, ty: TypeRef) -> Field<'a> {
+    Field {
+        name,
+        ty,
+        attributes: Default::default(),
+        is_static: false,
+        value: None,
+        default: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::field;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn field() -> Result<()> {
+        let fields = field::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            // field comment
+            Name []*Dto `json:"name,omitempty" db:"name"` // trailing comment
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "Name");
+        assert_eq!(
+            fields[0].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::new_optional(TypeRef::new(
+                    Type::Api(EntityId::new_unqualified("Dto")),
+                    Semantics::Value
+                )),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            fields[0].attributes.comments,
+            vec![
+                Comment::unowned(&["field comment"]),
+                Comment::unowned(&["trailing comment"])
+            ]
+        );
+        assert_eq!(
+            fields[0].attributes.user,
+            vec![
+                attributes::User::new(
+                    "json",
+                    vec![
+                        attributes::UserData::new(None, "name"),
+                        attributes::UserData::new(None, "omitempty"),
+                    ]
+                ),
+                attributes::User::new("db", vec![attributes::UserData::new(None, "name")]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_names() -> Result<()> {
+        let fields = field::parser(&TEST_CONFIG)
+            .parse("X, Y float64")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "X");
+        assert_eq!(fields[1].name, "Y");
+        assert!(fields.iter().all(|field| field.ty.value == Type::F64));
+        Ok(())
+    }
+
+    #[test]
+    fn embedded() -> Result<()> {
+        for data in ["Base", "*pkg.Base", "Base `json:\"base\"`"] {
+            let fields = field::parser(&TEST_CONFIG)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert!(fields.is_empty(), "{}", data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn named_params() -> Result<()> {
+        let params = field::params(&TEST_CONFIG)
+            .parse("(a, b int, names ...string)")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[0].name, "a");
+        assert_eq!(params[1].name, "b");
+        assert_eq!(params[1].ty.value, Type::I64);
+        assert_eq!(params[2].name, "names");
+        assert_eq!(
+            params[2].ty.value,
+            Type::new_array(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unnamed_params() -> Result<()> {
+        let params = field::params(&TEST_CONFIG)
+            .parse("(int, *Dto)")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(params.len(), 2);
+        assert!(params.iter().all(|param| param.name == "_"));
+        assert_eq!(params[0].ty.value, Type::I64);
+        Ok(())
+    }
+
+    #[test]
+    fn multiline_params() -> Result<()> {
+        let params = field::params(&TEST_CONFIG)
+            .parse("(\n    a int,\n    b string,\n)")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(params.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_params() -> Result<()> {
+        let params = field::params(&TEST_CONFIG)
+            .parse("()")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(params.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use crate::parser::visibility::keyword;
+use apyxl::parser::error::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Import<'a> {
+    /// The name the package is referred to by within the file, e.g. `alias` in
+    /// `import alias "a/b"`. `None` if the package's own name is used.
+    pub alias: Option<&'a str>,
+    /// The import path, e.g. `github.com/acme/api/user`.
+    pub path: &'a str,
+}
+
+impl Import<'_> {
+    /// Blank (`_`) and dot (`.`) imports don't introduce a package name.
+    pub fn is_named(&self) -> bool {
+        !matches!(self.alias, Some("_") | Some("."))
+    }
+}
+
+/// `package name`
+pub fn package<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("package"))
+        .ignore_then(text::ident())
+        .then_ignore(just(';').or_not())
+}
+
+/// `import "a/b"`, `import alias "a/b"`, or a grouped `import ( ... )` declaration.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Import<'a>>, Error<'a>> {
+    let group = spec()
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .delimited_by(just('(').padded(), just(')'));
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("import"))
+        .ignore_then(choice((group, spec().map(|spec| vec![spec]))))
+}
+
+fn spec<'a>() -> impl Parser<'a, &'a str, Import<'a>, Error<'a>> {
+    let path = none_of('"')
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'));
+    let alias = choice((text::ident(), just("."))).then_ignore(text::inline_whitespace());
+    comment::multi()
+        .padded()
+        .ignore_then(alias.or_not())
+        .then(path)
+        .then_ignore(comment::trailing().or_not())
+        .then_ignore(just(';').or_not())
+        .map(|(alias, path)| Import { alias, path })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn package() -> Result<()> {
+        let package = import::package()
+            .parse("// license\npackage user")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(package, "user");
+        Ok(())
+    }
+
+    #[test]
+    fn single() -> Result<()> {
+        let imports = import::parser()
+            .parse(r#"import "github.com/acme/api/user""#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            imports,
+            vec![Import {
+                alias: None,
+                path: "github.com/acme/api/user"
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn group() -> Result<()> {
+        let imports = import::parser()
+            .parse(
+                r#"import (
+                "fmt"
+                // comment
+                u "github.com/acme/api/user" // trailing
+                _ "embed"
+                . "strings"
+            )"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            imports,
+            vec![
+                Import {
+                    alias: None,
+                    path: "fmt"
+                },
+                Import {
+                    alias: Some("u"),
+                    path: "github.com/acme/api/user"
+                },
+                Import {
+                    alias: Some("_"),
+                    path: "embed"
+                },
+                Import {
+                    alias: Some("."),
+                    path: "strings"
+                },
+            ]
+        );
+        assert!(imports[1].is_named());
+        assert!(!imports[2].is_named());
+        assert!(!imports[3].is_named());
+        Ok(())
+    }
+}
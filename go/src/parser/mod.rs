@@ -0,0 +1,499 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use itertools::Itertools;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+
+use apyxl::model::{Api, EntityId, Field, Namespace, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::import::Import;
+
+mod comment;
+mod dto;
+mod en;
+mod expr_block;
+mod field;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod ty_alias;
+mod visibility;
+
+/// Parses a subset of Go declarations, i.e. structs, interfaces, type aliases, and defined types
+/// along with their constants, which are parsed as enums.
+///
+/// Each directory is a package, so the directory of each chunk's file path determines its
+/// namespace. The `package` clause is only used as the default name of a package when it is
+/// imported by another chunk.
+#[derive(Default)]
+pub struct GoParser {}
+
+impl apyxl::Parser for GoParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut parsed_chunks = Vec::new();
+        let mut packages = HashMap::<Vec<String>, &str>::new();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let imports = import::parser().padded().repeated().collect::<Vec<_>>();
+
+            let ((package, imports), children) = import::package()
+                .or_not()
+                .then(imports)
+                .then(namespace::children(config).padded())
+                .then_ignore(end())
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Default::default(),
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            let path = chunk
+                .relative_file_path
+                .as_deref()
+                .map(package_path)
+                .unwrap_or_default();
+            if let Some(package) = package {
+                packages.insert(path.clone(), package);
+            }
+
+            let imports = imports.into_iter().flatten().collect_vec();
+            parsed_chunks.push((chunk, path, imports, api));
+        }
+
+        // Imports can only be resolved once all chunks are parsed, since import paths refer to
+        // package directories rather than to the names of the packages within them.
+
+        for (chunk, path, imports, mut api) in parsed_chunks {
+            let chunk_path = chunk.relative_file_path.as_deref();
+
+            debug!("applying imports to chunk {:?}...", chunk_path);
+            let imports = resolve_imports(&packages, &imports);
+            apply_imports(&mut api, &imports)?;
+
+            for component in &path {
+                builder.enter_namespace(component)
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Namespace of a package, i.e. the components of the chunk's parent directory.
+fn package_path(path: &Path) -> Vec<String> {
+    path.parent()
+        .map(|parent| {
+            parent
+                .components()
+                .filter_map(|component| component.as_os_str().to_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Map of the names packages are referred to by within a chunk to their namespace.
+type ResolvedImports<'a> = HashMap<&'a str, &'a [String]>;
+
+/// Imports resolve to the parsed package whose directory is the longest suffix of the import
+/// path, e.g. `github.com/acme/api/user` resolves to the package parsed from `api/user`.
+/// Imports of packages that were not parsed, e.g. from the standard library, are skipped.
+fn resolve_imports<'a>(
+    packages: &'a HashMap<Vec<String>, &'a str>,
+    imports: &'a [Import<'a>],
+) -> ResolvedImports<'a> {
+    let mut resolved = ResolvedImports::new();
+    for import in imports.iter().filter(|import| import.is_named()) {
+        let components = import.path.split('/').collect_vec();
+        let package = packages
+            .iter()
+            .filter(|(dir, _)| !dir.is_empty() && is_suffix(dir, &components))
+            .max_by_key(|(dir, _)| dir.len());
+        if let Some((dir, name)) = package {
+            resolved.insert(import.alias.unwrap_or(name), dir.as_slice());
+        }
+    }
+    resolved
+}
+
+fn is_suffix(dir: &[String], components: &[&str]) -> bool {
+    dir.len() <= components.len()
+        && dir
+            .iter()
+            .rev()
+            .zip(components.iter().rev())
+            .all(|(a, b)| a == b)
+}
+
+fn apply_imports(namespace: &mut Namespace, imports: &ResolvedImports) -> Result<()> {
+    let apply_import_to_field =
+        |field: &mut Field| -> Result<()> { apply_imports_to_type(&mut field.ty, imports) };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_imports_to_type(&mut param.ty, imports)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_imports_to_type(return_ty, imports)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_imports_to_type(&mut alias.target_ty, imports)?;
+    }
+
+    // note: enums have no type refs.
+
+    Ok(())
+}
+
+fn apply_imports_to_type(ty: &mut TypeRef, imports: &ResolvedImports) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Set(ty) => apply_imports_to_type(ty, imports)?,
+        Type::FixedArray { ty, .. } => apply_imports_to_type(ty, imports)?,
+        Type::Optional(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(&mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(key, imports)?;
+            apply_imports_to_type(value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(id, imports)?;
+            for arg in args {
+                apply_imports_to_type(arg, imports)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_imports_to_type(param, imports)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_imports_to_type(return_ty, imports)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(id: &mut EntityId, imports: &ResolvedImports) -> Result<()> {
+    // Qualified names are always `package.Name`.
+    if let Some((package, name)) = id.component_names().collect_tuple()
+        && let Some(dir) = imports.get(package)
+    {
+        *id = EntityId::new_unqualified_vec(dir.iter().map(String::as_str).chain([name]));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::GoParser;
+    use apyxl::model::{Builder, Chunk, Comment, EntityId, UNDEFINED_NAMESPACE};
+    use apyxl::parser::Config;
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // license
+        package api
+
+        import "fmt"
+
+        // dto comment
+        type Dto struct {
+            Field []int
+        }
+
+        func (d Dto) String() string { return fmt.Sprint(d.Field) }
+
+        type Service interface {
+            Get() Dto
+        }
+
+        type En int
+
+        const (
+            A En = iota
+        )
+
+        type Alias = Dto
+        // end comment ignored
+        "#,
+        );
+        let mut builder = Builder::default();
+        GoParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("Dto").is_some(), "dto");
+        assert!(model.api().dto("Service").is_some(), "interface");
+        assert!(model.api().en("En").is_some(), "en");
+        assert!(model.api().ty_alias("Alias").is_some(), "alias");
+        assert_eq!(
+            model.api().dto("Dto").unwrap().attributes.comments,
+            vec![Comment::unowned(&["dto comment"])],
+            "comment after import attributed to dto"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        package api
+        type En int
+        const ( A En = iota )
+        type ignoredEn int
+        const ( b ignoredEn = iota )
+        type Dto struct {}
+        type ignoredDto struct {}
+        "#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        GoParser::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("Dto").is_some());
+        assert!(model.api().en("En").is_some());
+        assert!(model.api().dto("ignoredDto").is_none());
+        assert!(model.api().en("ignoredEn").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn directory_namespace() -> Result<()> {
+        let mut input = input::ChunkBuffer::new();
+        input.add_chunk(
+            Chunk::with_relative_file_path("a/b/dto.go"),
+            "package b\ntype Dto struct {}",
+        );
+        let mut builder = Builder::default();
+        GoParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(
+            model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.b.Dto"))
+                .is_some()
+        );
+        Ok(())
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::GoParser;
+        use apyxl::model::{Builder, Chunk, EntityId, Model, Type};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn package_name() -> Result<()> {
+            let a = "package ids\ntype Id struct {}";
+            let test = r#"
+            package test
+            import "github.com/acme/api/x/ids"
+            type Entity struct { Id ids.Id }
+            "#;
+            run_dto_chunked_test(
+                &[("api/x/ids/id.go", a), ("api/test/entity.go", test)],
+                "api.test.Entity",
+                "ns:api.ns:x.ns:ids.d:Id",
+            )
+        }
+
+        #[test]
+        fn package_name_differs_from_dir() -> Result<()> {
+            let a = "package identity\ntype Id struct {}";
+            let test = r#"
+            package test
+            import "github.com/acme/api/ids"
+            type Entity struct { Id *identity.Id }
+            "#;
+            run_chunked_test(&[("ids/id.go", a), ("test/entity.go", test)], |model| {
+                let ty = &model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("test.Entity"))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value;
+                let Type::Optional(ty) = ty else {
+                    panic!("expected optional");
+                };
+                let expected = EntityId::try_from("ns:ids.d:Id")?;
+                assert_eq!(ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn alias() -> Result<()> {
+            let a = "package ids\ntype Id struct {}";
+            let test = r#"
+            package test
+            import (
+                "fmt"
+                i "example.com/ids"
+            )
+            type Entity struct { Id i.Id }
+            "#;
+            run_dto_chunked_test(
+                &[("ids/id.go", a), ("test/entity.go", test)],
+                "test.Entity",
+                "ns:ids.d:Id",
+            )
+        }
+
+        #[test]
+        fn same_package() -> Result<()> {
+            let a = "package test\ntype Id struct {}";
+            let test = r#"
+            package test
+            type Entity struct { Id Id }
+            "#;
+            run_dto_chunked_test(
+                &[("test/id.go", a), ("test/entity.go", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn rpc() -> Result<()> {
+            let a = "package ids\ntype Id struct {}";
+            let test = r#"
+            package test
+            import "example.com/ids"
+            type Service interface {
+                Get(id ids.Id) ([]ids.Id, error)
+            }
+            "#;
+            run_chunked_test(&[("ids/id.go", a), ("test/service.go", test)], |model| {
+                let rpc = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("test.Service"))
+                    .unwrap()
+                    .rpc("Get")
+                    .unwrap();
+                let expected = EntityId::try_from("ns:ids.d:Id")?;
+                assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            GoParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+    }
+}
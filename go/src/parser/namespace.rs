@@ -0,0 +1,233 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::en::Const;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, dto, en, expr_block, ty_alias};
+use apyxl::model::{Comment, NamespaceChild, TypeAlias};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Top-level declarations within a file. Only type declarations and the constants of defined
+/// types are part of the model, so functions, methods, and variables are skipped. Unexported
+/// types are filtered using [Visibility::of].
+pub fn children<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Vec<NamespaceChild<'a>>, Error<'a>> {
+    choice((
+        type_decl(config),
+        en::parser().map(|consts| vec![Decl::Consts(consts)]),
+        func().map(|_| vec![]),
+        var().map(|_| vec![]),
+        // Catch comments after all children
+        comment::single().padded().map(|_| vec![]),
+    ))
+    .recover_with(skip_then_retry_until(
+        choice((expr_block::parser().ignored(), any().ignored())),
+        end(),
+    ))
+    .then_ignore(just(';').or_not())
+    .padded()
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(move |decls| {
+        let decls = decls.into_iter().flatten().collect_vec();
+        let consts = decls
+            .iter()
+            .filter_map(|decl| match decl {
+                Decl::Consts(consts) => Some(consts.as_slice()),
+                _ => None,
+            })
+            .flatten()
+            .cloned()
+            .collect_vec();
+        decls
+            .into_iter()
+            .filter_map(|decl| match decl {
+                Decl::Type(child) => Visibility::of(child.name()).filter(*child, config),
+                Decl::DefinedType(alias) => Visibility::of(alias.name)
+                    .filter(*alias, config)
+                    .map(|alias| en::from_consts(alias, &consts, config)),
+                Decl::Consts(_) => None,
+            })
+            .collect_vec()
+    })
+}
+
+enum Decl<'a> {
+    Type(Box<NamespaceChild<'a>>),
+    /// `type Name Type`, which becomes an enum if there are constants of the type.
+    DefinedType(Box<TypeAlias<'a>>),
+    Consts(Vec<Const<'a>>),
+}
+
+impl<'a> Decl<'a> {
+    fn set_comments(&mut self, comments: Vec<Comment<'a>>) {
+        match self {
+            Decl::Type(child) => child.attributes_mut().comments = comments,
+            Decl::DefinedType(alias) => alias.attributes.comments = comments,
+            Decl::Consts(_) => {}
+        }
+    }
+}
+
+/// `type` declarations, e.g. `type Name struct { ... }` or a grouped `type ( ... )` declaration.
+fn type_decl<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Decl<'a>>, Error<'a>> {
+    let group = comment::multi()
+        .padded()
+        .then(type_spec(config))
+        .map(|(comments, mut decl)| {
+            decl.set_comments(comments);
+            decl
+        })
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .delimited_by(just('(').padded(), just(')'));
+    comment::multi()
+        .padded()
+        .then_ignore(keyword("type"))
+        .then(choice((
+            group.map(|decls| (decls, false)),
+            type_spec(config).map(|decl| (vec![decl], true)),
+        )))
+        .map(|(comments, (mut decls, is_single))| {
+            if is_single {
+                decls[0].set_comments(comments);
+            }
+            decls
+        })
+}
+
+fn type_spec<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Decl<'a>, Error<'a>> {
+    choice((
+        dto::parser(config).map(|dto| Decl::Type(Box::new(NamespaceChild::Dto(dto)))),
+        ty_alias::parser(config).map(|(alias, kind)| match kind {
+            ty_alias::Kind::Alias => Decl::Type(Box::new(NamespaceChild::TypeAlias(alias))),
+            ty_alias::Kind::Defined => Decl::DefinedType(Box::new(alias)),
+        }),
+    ))
+}
+
+/// Functions and methods, e.g. `func (d *Dto) Name() string { ... }`.
+fn func<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(keyword("func"))
+        .then(expr_block::expr("{\n"))
+        .then(expr_block::parser().or_not())
+        .ignored()
+}
+
+/// `var` declarations, e.g. `var x = 1` or a grouped `var ( ... )` declaration.
+fn var<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let group = expr_block::expr(")")
+        .or_not()
+        .ignored()
+        .delimited_by(just('('), just(')'));
+    comment::multi()
+        .padded()
+        .then(keyword("var"))
+        .then(choice((group, expr_block::expr("\n;"))))
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use apyxl::model::{Comment, Namespace};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn children() -> Result<()> {
+        let children = namespace::children(&TEST_CONFIG)
+            .parse(
+                r#"
+            // dto comment
+            type Dto struct {
+                A int
+            }
+
+            func (d *Dto) Method() string {
+                return "}"
+            }
+
+            var Default = Dto{A: 1}
+
+            type (
+                // alias comment
+                Alias = []string
+                Service interface {
+                    Get() Dto
+                }
+            )
+
+            type Status int32
+
+            const (
+                Active Status = iota
+                Inactive
+            )
+
+            type Plain string
+            // trailing comment
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let namespace = Namespace {
+            children,
+            ..Default::default()
+        };
+        assert_eq!(namespace.children.len(), 5);
+        assert_eq!(
+            namespace.dto("Dto").unwrap().attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(
+            namespace.ty_alias("Alias").unwrap().attributes.comments,
+            vec![Comment::unowned(&["alias comment"])]
+        );
+        assert!(namespace.dto("Service").is_some());
+        assert_eq!(namespace.en("Status").unwrap().values.len(), 2);
+        assert!(namespace.ty_alias("Plain").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn consts_before_type() -> Result<()> {
+        let children = namespace::children(&TEST_CONFIG)
+            .parse("const A Status = 1\ntype Status int")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let namespace = Namespace {
+            children,
+            ..Default::default()
+        };
+        assert_eq!(namespace.en("Status").unwrap().values[0].number, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn unexported_children_filtered() -> Result<()> {
+        let children = namespace::children(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            type Dto struct {}
+            type dto struct {}
+            type status int
+            const active status = 1
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name(), "Dto");
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::{comment, field, ty};
+use apyxl::model::{Attributes, EntityId, Rpc, RpcKind, Type, TypeRef};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Interface method e.g. `Get(ctx context.Context, id string) (*User, error)`.
+///
+/// A trailing `error` result is dropped since it is how Go reports failures rather than part of
+/// the return value. Methods with more than one remaining result are not supported. Similarly,
+/// `context.Context` parameters carry request-scoped values rather than data, so they are dropped.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Rpc<'a>, Error<'a>> {
+    let results = text::inline_whitespace().ignore_then(choice((
+        field::params(config).map(|params| params.into_iter().map(|param| param.ty).collect()),
+        ty::parser(config).map(|ty| vec![ty]),
+    )));
+    comment::multi()
+        .padded()
+        .then(text::ident())
+        .then(field::params(config).map(|params| {
+            params
+                .into_iter()
+                .filter(|param| !is_context(&param.ty))
+                .collect::<Vec<_>>()
+        }))
+        .then(results.or_not())
+        .then(comment::trailing().or_not())
+        .then_ignore(just(';').or_not())
+        .padded()
+        .try_map(
+            |((((mut comments, name), params), results), trailing), span| {
+                comments.extend(trailing);
+                let mut results: Vec<TypeRef> = results.unwrap_or_default();
+                if results.last().is_some_and(is_error) {
+                    results.pop();
+                }
+                if results.len() > 1 {
+                    return Err(Rich::custom(
+                        span,
+                        format!(
+                            "method {} has multiple results which is not supported",
+                            name
+                        ),
+                    ));
+                }
+                Ok(Rpc {
+                    name: Cow::Borrowed(name),
+                    type_params: vec![],
+                    params,
+                    return_type: results.pop(),
+                    error_type: None,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                    is_static: false,
+                    kind: RpcKind::default(),
+                })
+            },
+        )
+}
+
+fn is_error(ty: &TypeRef) -> bool {
+    ty.value == Type::Api(EntityId::new_unqualified("error"))
+}
+
+fn is_context(ty: &TypeRef) -> bool {
+    ty.value == Type::Api(EntityId::new_unqualified("context.Context"))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn rpc() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            // rpc comment
+            Get(ctx context.Context, id string) (*User, error)
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "Get");
+        assert_eq!(rpc.params.len(), 1, "context dropped");
+        assert_eq!(rpc.params[0].name, "id");
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("User")),
+                Semantics::Value
+            )))
+        );
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn single_result() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("Count() int")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.return_type.map(|ty| ty.value), Some(Type::I64));
+        Ok(())
+    }
+
+    #[test]
+    fn error_only() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("Close() error")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.return_type, None);
+        Ok(())
+    }
+
+    #[test]
+    fn no_results() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("Reset()")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.params.is_empty());
+        assert_eq!(rpc.return_type, None);
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_results() {
+        let result = rpc::parser(&TEST_CONFIG)
+            .parse("Pair() (int, string, error)")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use chumsky::prelude::*;
+
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Go types, e.g. `int32`, `*string`, `[]Dto`, or `map[string]pkg.Dto`.
+///
+/// Pointers are parsed as optional, and `[]byte` is parsed as bytes. Both slices and arrays are
+/// parsed as arrays. Note that `int` and `uint` are parsed as 64-bit, and `rune` as `int32`.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let primitive = choice((
+            util::keyword_ex("bool").map(|_| Type::Bool),
+            util::keyword_ex("int8").map(|_| Type::I8),
+            util::keyword_ex("int16").map(|_| Type::I16),
+            choice((util::keyword_ex("int32"), util::keyword_ex("rune"))).map(|_| Type::I32),
+            choice((util::keyword_ex("int64"), util::keyword_ex("int"))).map(|_| Type::I64),
+            choice((util::keyword_ex("uint8"), util::keyword_ex("byte"))).map(|_| Type::U8),
+            util::keyword_ex("uint16").map(|_| Type::U16),
+            util::keyword_ex("uint32").map(|_| Type::U32),
+            choice((util::keyword_ex("uint64"), util::keyword_ex("uint"))).map(|_| Type::U64),
+            util::keyword_ex("uintptr").map(|_| Type::USIZE),
+            util::keyword_ex("float32").map(|_| Type::F32),
+            util::keyword_ex("float64").map(|_| Type::F64),
+            util::keyword_ex("string").map(|_| Type::String),
+        ));
+        let bytes = just("[]")
+            .then(choice((
+                util::keyword_ex("byte"),
+                util::keyword_ex("uint8"),
+            )))
+            .map(|_| Type::Bytes);
+        let array = none_of(']')
+            .repeated()
+            .delimited_by(just('['), just(']'))
+            .ignore_then(nested.clone())
+            .map(Type::new_array);
+        let map = util::keyword_ex("map")
+            .ignore_then(nested.clone().padded().delimited_by(just('['), just(']')))
+            .then(nested.clone())
+            .map(|(key, value)| Type::new_map(key, value));
+        let ty = choice((
+            primitive,
+            user_ty(config).map(Type::User),
+            bytes,
+            array,
+            map,
+            // Note that entity_id should come last because it is greedy.
+            entity_id().map(Type::Api),
+        ))
+        .map(|ty| TypeRef::new(ty, Semantics::Value));
+
+        choice((just('*').ignore_then(nested).map(pointer), ty)).boxed()
+    })
+}
+
+/// Pointers to optionals, e.g. `**T`, are parsed as a single optional.
+fn pointer(ty: TypeRef) -> TypeRef {
+    match ty.value {
+        Type::Optional(_) => ty,
+        _ => TypeRef::new(Type::new_optional(ty), Semantics::Value),
+    }
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+/// Local or package-qualified type names, e.g. `Dto` or `pkg.Dto`.
+fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .at_most(2)
+        .collect::<Vec<_>>()
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "time.Time".to_string(),
+                name: "time".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(bool, "bool", Type::Bool);
+    test!(int8, "int8", Type::I8);
+    test!(int16, "int16", Type::I16);
+    test!(int32, "int32", Type::I32);
+    test!(rune, "rune", Type::I32);
+    test!(int64, "int64", Type::I64);
+    test!(int, "int", Type::I64);
+    test!(uint8, "uint8", Type::U8);
+    test!(byte, "byte", Type::U8);
+    test!(uint16, "uint16", Type::U16);
+    test!(uint32, "uint32", Type::U32);
+    test!(uint64, "uint64", Type::U64);
+    test!(uint, "uint", Type::U64);
+    test!(uintptr, "uintptr", Type::USIZE);
+    test!(float32, "float32", Type::F32);
+    test!(float64, "float64", Type::F64);
+    test!(string, "string", Type::String);
+    test!(bytes, "[]byte", Type::Bytes);
+    test!(user, "time.Time", Type::User("time".to_string()));
+    test!(
+        entity_id,
+        "pkg.Type",
+        Type::Api(EntityId::new_unqualified("pkg.Type"))
+    );
+    test!(
+        keyword_prefixed_entity_id,
+        "integer",
+        Type::Api(EntityId::new_unqualified("integer"))
+    );
+    test!(slice, "[]int32", Type::new_array(ty(Type::I32)));
+    test!(array, "[4]int32", Type::new_array(ty(Type::I32)));
+    test!(nested_slice, "[][]byte", Type::new_array(ty(Type::Bytes)));
+    test!(
+        map,
+        "map[string][]int",
+        Type::new_map(ty(Type::String), ty(Type::new_array(ty(Type::I64))))
+    );
+    test!(pointer, "*string", Type::new_optional(ty(Type::String)));
+    test!(
+        double_pointer,
+        "**string",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        slice_of_pointers,
+        "[]*Dto",
+        Type::new_array(ty(Type::new_optional(ty(Type::Api(
+            EntityId::new_unqualified("Dto")
+        )))))
+    );
+
+    #[test]
+    fn unsupported_generic() {
+        let result = ty::parser(&TY_TEST_CONFIG)
+            .parse("Pair[int, int]")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    fn run_test(data: &'static str, expected: Type) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty.value, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
@@ -0,0 +1,85 @@
+use chumsky::prelude::*;
+
+use crate::parser::ty;
+use apyxl::model::TypeAlias;
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Kind {
+    /// `Name = Type`
+    Alias,
+    /// `Name Type`, which may become an enum if there are constants of the type. See
+    /// [crate::parser::en::from_consts].
+    Defined,
+}
+
+/// Alias and defined type specs without the leading `type` keyword, e.g. `Name = Type` or
+/// `Name Type`. Comments before the `type` keyword belong to the spec, so they are attached by
+/// the caller.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (TypeAlias<'a>, Kind), Error<'a>> {
+    text::ident()
+        .then_ignore(text::inline_whitespace())
+        .then(just('=').or_not())
+        .then_ignore(text::inline_whitespace())
+        .then(ty::parser(config))
+        .map(|((name, is_alias), target_ty)| {
+            let kind = match is_alias {
+                Some(_) => Kind::Alias,
+                None => Kind::Defined,
+            };
+            (
+                TypeAlias {
+                    name,
+                    type_params: vec![],
+                    target_ty,
+                    attributes: Default::default(),
+                },
+                kind,
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ty_alias;
+    use crate::parser::ty_alias::Kind;
+    use apyxl::model::{Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        let (alias, kind) = ty_alias::parser(&TEST_CONFIG)
+            .parse("Alias = map[string]int")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "Alias");
+        assert_eq!(
+            alias.target_ty.value,
+            Type::new_map(
+                TypeRef::new(Type::String, Semantics::Value),
+                TypeRef::new(Type::I64, Semantics::Value)
+            )
+        );
+        assert_eq!(kind, Kind::Alias);
+        Ok(())
+    }
+
+    #[test]
+    fn defined() -> Result<()> {
+        let (alias, kind) = ty_alias::parser(&TEST_CONFIG)
+            .parse("Status int32")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "Status");
+        assert_eq!(alias.target_ty.value, Type::I32);
+        assert_eq!(kind, Kind::Defined);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use chumsky::prelude::*;
+
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Identifiers are exported if they start with an uppercase letter. Unexported identifiers are not
+/// visible outside of their package so they are considered private.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn of(name: &str) -> Self {
+        if name.starts_with(char::is_uppercase) {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        }
+    }
+
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Keyword that must be followed by whitespace, e.g. `type `.
+pub fn keyword<'a>(keyword: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex(keyword)
+        .then(text::whitespace().at_least(1))
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::visibility::Visibility;
+
+    #[test]
+    fn exported() {
+        assert_eq!(Visibility::of("Name"), Visibility::Public);
+        assert_eq!(Visibility::of("Ωmega"), Visibility::Public);
+    }
+
+    #[test]
+    fn unexported() {
+        assert_eq!(Visibility::of("name"), Visibility::Private);
+        assert_eq!(Visibility::of("_Name"), Visibility::Private);
+    }
+}
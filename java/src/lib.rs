@@ -0,0 +1,3 @@
+pub use parser::JavaParser as Parser;
+
+mod parser;
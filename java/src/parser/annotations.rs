@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use apyxl::model::attributes;
+use apyxl::parser::error::Error;
+
+/// Zero or more annotations, e.g. `@JsonProperty("name") @a.b.Flag`.
+pub fn annotations<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> {
+    annotation().padded().repeated().collect::<Vec<_>>()
+}
+
+/// Single annotation, e.g. `@Annotation(1, key = "value", values = {1, 2})`.
+///
+/// Annotation arguments are parsed as data. String arguments have their quotes stripped, and
+/// other arguments (including array initializers) are kept verbatim.
+pub fn annotation<'a>() -> impl Parser<'a, &'a str, attributes::User<'a>, Error<'a>> {
+    let name = text::ident().separated_by(just('.')).at_least(1).slice();
+    let string = none_of('"')
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'));
+    let array = none_of('}')
+        .repeated()
+        .delimited_by(just('{'), just('}'))
+        .slice();
+    let value = choice((
+        string,
+        array,
+        none_of(",(){}\"")
+            .repeated()
+            .at_least(1)
+            .slice()
+            .map(str::trim),
+    ));
+    let data = text::ident()
+        .then_ignore(just('=').padded())
+        .or_not()
+        .then(value.padded())
+        .map(|(key, value)| attributes::UserData::new(key, value));
+    let data_list = data
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').then(text::whitespace()), just(')'));
+    just('@')
+        .ignore_then(name)
+        .then(data_list.or_not())
+        .map(|(name, data)| attributes::User {
+            name: Cow::Borrowed(name),
+            data: data.unwrap_or_default(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::annotations;
+    use apyxl::model::attributes;
+    use apyxl::model::attributes::UserData;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn flags() -> Result<()> {
+        run_test(
+            "@Override @a.b.Flag",
+            vec![
+                attributes::User::new_flag("Override"),
+                attributes::User::new_flag("a.b.Flag"),
+            ],
+        )
+    }
+
+    #[test]
+    fn data() -> Result<()> {
+        run_test(
+            r#"@Annotation(1, key = "value", other = Enum.VALUE, values = {"a", "b"})"#,
+            vec![attributes::User::new(
+                "Annotation",
+                vec![
+                    UserData::new(None, "1"),
+                    UserData::new(Some("key"), "value"),
+                    UserData::new(Some("other"), "Enum.VALUE"),
+                    UserData::new(Some("values"), r#"{"a", "b"}"#),
+                ],
+            )],
+        )
+    }
+
+    fn run_test(data: &'static str, expected: Vec<attributes::User<'static>>) -> Result<()> {
+        let annotations = annotations::annotations()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(annotations, expected);
+        Ok(())
+    }
+}
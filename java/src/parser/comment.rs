@@ -0,0 +1,93 @@
+use apyxl::model::Comment;
+use apyxl::parser::comment;
+use apyxl::parser::error::Error;
+use chumsky::Parser;
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+pub fn single<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
+    choice((
+        javadoc(),
+        comment::single(line_start(), just("/*"), block_end()),
+    ))
+}
+
+pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> {
+    single().padded().repeated().collect::<Vec<_>>()
+}
+
+/// Javadoc comment e.g. `/** ... */`. Each line is an element in the comment with the leading
+/// `*` stripped.
+fn javadoc<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> {
+    any()
+        .and_is(block_end().not())
+        .repeated()
+        .slice()
+        .delimited_by(just("/**"), block_end())
+        .map(|s: &str| {
+            let lines = s
+                .trim()
+                .lines()
+                .map(|line| {
+                    let line = line.trim();
+                    match line.strip_prefix('*') {
+                        Some(line) => line.strip_prefix(' ').unwrap_or(line),
+                        None => line,
+                    }
+                })
+                .collect_vec();
+            Comment::from(lines)
+        })
+}
+
+fn line_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    choice((just("///"), just("//")))
+}
+
+fn block_end<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
+    just("*/")
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::comment;
+    use apyxl::model::Comment;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn javadoc() -> Result<()> {
+        let comments = comment::multi()
+            .parse(
+                r#"
+            /**
+             * Summary.
+             *
+             * @param a details
+             */
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            comments,
+            vec![Comment::unowned(&["Summary.", "", "@param a details"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn block_and_line() -> Result<()> {
+        let comments = comment::multi()
+            .parse("/* block */\n// line\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            comments,
+            vec![Comment::unowned(&["block"]), Comment::unowned(&["line"])]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::visibility::{Modifiers, Visibility, keyword};
+use crate::parser::{comment, en, expr_block, field, rpc, ty, visibility};
+use apyxl::model::{Attributes, Dto, Namespace, NamespaceChild};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// `class`, `interface`, and `record` declarations, e.g.
+/// `public record Name(int a) implements Base { ... }`.
+///
+/// Record components and non-static fields are [apyxl::model::Field]s, and non-static methods
+/// are [apyxl::model::Rpc]s. Static members and nested types are placed in the [Dto]'s
+/// namespace. Interface members are public by default and interface fields are implicitly
+/// static. Type parameters, supertypes, constructors, and initializer blocks are skipped.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, (Dto<'a>, Modifiers), Error<'a>> {
+    recursive(|nested| {
+        let kind = choice((
+            keyword("class").to(Kind::Class),
+            keyword("interface").to(Kind::Interface),
+            record_keyword().to(Kind::Record),
+        ));
+        let components = field::params(config).map(|fields| {
+            let modifiers = Modifiers {
+                visibility: Some(Visibility::Public),
+                is_static: false,
+            };
+            fields
+                .into_iter()
+                .map(|field| (NamespaceChild::Field(field), modifiers))
+                .collect_vec()
+        });
+        let member = choice((
+            nested.map(|(dto, m)| vec![(NamespaceChild::Dto(dto), m)]),
+            en::parser().map(|(en, m)| vec![(NamespaceChild::Enum(en), m)]),
+            rpc::parser(config).map(|(rpc, m)| vec![(NamespaceChild::Rpc(rpc), m)]),
+            field::parser(config).map(|(fields, m)| {
+                fields
+                    .into_iter()
+                    .map(|field| (NamespaceChild::Field(field), m))
+                    .collect_vec()
+            }),
+            skipped().map(|_| vec![]),
+            // Catch comments after all members.
+            comment::single().padded().map(|_| vec![]),
+        ));
+        let body = member
+            .then_ignore(just(';').padded().or_not())
+            .recover_with(skip_then_retry_until(
+                choice((expr_block::parser().ignored(), any().ignored())),
+                just('}').ignored(),
+            ))
+            .repeated()
+            .collect::<Vec<_>>()
+            .delimited_by(just('{').padded(), just('}').padded());
+        comment::multi()
+            .padded()
+            .then(visibility::modifiers())
+            .then(kind)
+            .then(text::ident())
+            .then_ignore(ty::type_params().padded().or_not())
+            .then(components.or_not())
+            .then_ignore(expr_block::expr("{").or_not())
+            .then(body)
+            .map(
+                move |(((((comments, (user, modifiers)), kind), name), components), members)| {
+                    let default_visibility = match kind {
+                        Kind::Interface => Visibility::Public,
+                        Kind::Class | Kind::Record => Visibility::Private,
+                    };
+                    let members = components
+                        .unwrap_or_default()
+                        .into_iter()
+                        .chain(members.into_iter().flatten());
+                    let mut children = vec![];
+                    for (mut child, member_modifiers) in members {
+                        if kind == Kind::Interface
+                            && let NamespaceChild::Field(field) = &mut child
+                        {
+                            field.is_static = true;
+                        }
+                        let visibility = member_modifiers.visibility_or(default_visibility);
+                        children.extend(visibility.filter(child, config));
+                    }
+                    let mut namespace = Namespace {
+                        children,
+                        ..Default::default()
+                    };
+                    let (fields, rpcs) = namespace.extract_non_static();
+                    let namespace = if namespace.children.is_empty() {
+                        None
+                    } else {
+                        Some(namespace)
+                    };
+                    let dto = Dto {
+                        name,
+                        type_params: vec![],
+                        fields,
+                        rpcs,
+                        extends: vec![],
+                        implements: vec![],
+                        attributes: Attributes {
+                            comments,
+                            user,
+                            ..Default::default()
+                        },
+                        namespace,
+                    };
+                    (dto, modifiers)
+                },
+            )
+            .boxed()
+    })
+}
+
+/// Declarations that are valid within a class body but are not part of the model, i.e.
+/// constructors, compact record constructors, and initializer blocks.
+fn skipped<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let ctor = text::ident()
+        .then(expr_block::expr("{;").or_not())
+        .then(expr_block::parser())
+        .ignored();
+    let initializer = expr_block::parser().ignored();
+    comment::multi()
+        .padded()
+        .then(visibility::modifiers())
+        .then(choice((ctor, initializer)))
+        .padded()
+        .ignored()
+}
+
+/// `record` is a contextual keyword, so it must be followed by the record name.
+fn record_keyword<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    keyword("record")
+        .then(text::ident().then(one_of("<(").padded()).rewind())
+        .ignored()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Kind {
+    Class,
+    Interface,
+    Record,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (dto, modifiers) = dto::parser(&TEST_CONFIG)
+            .parse("public final class Dto {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert!(dto.fields.is_empty());
+        assert!(dto.namespace.is_none());
+        assert_eq!(modifiers.visibility, Some(Visibility::Public));
+        Ok(())
+    }
+
+    #[test]
+    fn package_private() -> Result<()> {
+        let (_, modifiers) = dto::parser(&TEST_CONFIG)
+            .parse("class Dto {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(modifiers.visibility, None);
+        Ok(())
+    }
+
+    #[test]
+    fn class() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** dto comment */
+            @Entity
+            public class Dto<T> extends Base<T> implements Serializable, Comparable<Dto> {
+                // field comment
+                public String field0;
+                public Integer field1 = null;
+                private List<Other> field2 = new ArrayList<>();
+
+                public static final int CONSTANT = 1;
+
+                static {
+                    init();
+                }
+
+                public Dto(String field0) {
+                    this.field0 = field0;
+                }
+
+                public String method(int a) {
+                    return field0 + a;
+                }
+
+                public static Dto create() { return new Dto("a"); }
+
+                public static class Nested {}
+                enum En { A, B }
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(
+            dto.attributes.user,
+            vec![attributes::User::new_flag("Entity")]
+        );
+        assert_eq!(dto.fields.len(), 3);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "field1");
+        assert_eq!(dto.fields[1].ty.value, Type::I32);
+        assert_eq!(dto.fields[2].name, "field2");
+        assert_eq!(
+            dto.fields[2].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Other")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "method");
+        let namespace = dto.namespace.expect("namespace");
+        assert!(namespace.field("CONSTANT").unwrap().is_static);
+        assert!(namespace.rpc("create").unwrap().is_static);
+        assert!(namespace.dto("Nested").is_some());
+        assert!(namespace.en("En").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn record() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            public record Dto(@JsonProperty("a") int a, Optional<String> b) implements Base {
+                public Dto {
+                    Objects.requireNonNull(b);
+                }
+
+                public boolean hasB() { return b.isPresent(); }
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 2);
+        assert_eq!(dto.fields[0].name, "a");
+        assert_eq!(dto.fields[0].ty.value, Type::I32);
+        assert_eq!(
+            dto.fields[0].attributes.user,
+            vec![attributes::User::new(
+                "JsonProperty",
+                vec![attributes::UserData::new(None, "a")]
+            )]
+        );
+        assert_eq!(dto.fields[1].name, "b");
+        assert_eq!(
+            dto.fields[1].ty.value,
+            Type::new_optional(TypeRef::new(Type::String, Semantics::Value))
+        );
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "hasB");
+        Ok(())
+    }
+
+    #[test]
+    fn interface() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            public sealed interface Dto extends Base permits A, B {
+                int CONSTANT = 1;
+                String method(String a);
+                default boolean other() { return true; }
+                static Dto create() { return null; }
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(dto.fields.is_empty());
+        assert_eq!(dto.rpcs.len(), 2);
+        assert_eq!(dto.rpcs[0].name, "method");
+        assert_eq!(dto.rpcs[1].name, "other");
+        let namespace = dto.namespace.expect("namespace");
+        assert!(namespace.field("CONSTANT").unwrap().is_static);
+        assert!(namespace.rpc("create").unwrap().is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn private_members_filtered() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            public class Dto {
+                public int field0;
+                int field1;
+                private int field2;
+                protected void method() {}
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert!(dto.rpcs.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Modifiers, keyword};
+use crate::parser::{comment, expr_block, visibility};
+use apyxl::model::{Attributes, Enum, EnumKind, EnumValue, EnumValueNumber, attributes};
+use apyxl::parser::error::Error;
+
+const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+
+/// Name of the user attribute that holds the constructor arguments of an enum constant.
+pub const ARGS_ATTRIBUTE: &str = "args";
+
+/// `enum Name implements Base { ... }`. Interfaces and members after the constants are skipped.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Modifiers), Error<'a>> {
+    let skipped = expr_block::expr("{");
+    let members = just(';')
+        .padded()
+        .then(expr_block::expr("}").or_not())
+        .ignored();
+    let values = en_value()
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .then_ignore(members.or_not())
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(visibility::modifiers())
+        .then_ignore(keyword("enum"))
+        .then(text::ident())
+        .then_ignore(skipped.or_not())
+        .then(values)
+        .map(|(((comments, (user, modifiers)), name), values)| {
+            (
+                Enum {
+                    name,
+                    values: apply_enum_value_number_defaults(values),
+                    kind: EnumKind::Plain,
+                    underlying_type: None,
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                },
+                modifiers,
+            )
+        })
+}
+
+/// Constants with constructor arguments, e.g. `A(1, "a")`, use the first argument as their number
+/// if it is an integer literal. Otherwise, the constant is numbered after the previous constant.
+///
+/// The arguments are also kept in an [ARGS_ATTRIBUTE] user attribute so that values other than
+/// the number aren't lost, e.g. `A(1, "a")` has the attribute `args(1, a)`. String arguments
+/// have their quotes stripped.
+fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
+    let string = none_of('"')
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'))
+        .then_ignore(text::whitespace().then(one_of(",)")).rewind());
+    let arg = choice((string, expr_block::expr(",)").slice().map(str::trim)));
+    let args = arg
+        .padded()
+        .separated_by(just(','))
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), just(')').padded());
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(text::ident())
+        .then(args.or_not())
+        .then_ignore(expr_block::parser().or_not())
+        .padded()
+        .map(|(((comments, mut user), name), args)| {
+            let args = args.unwrap_or_default();
+            let number = args
+                .first()
+                .and_then(|arg| str::parse::<EnumValueNumber>(arg).ok())
+                .unwrap_or(INVALID_ENUM_NUMBER);
+            if !args.is_empty() {
+                user.push(attributes::User {
+                    name: Cow::Borrowed(ARGS_ATTRIBUTE),
+                    data: args
+                        .into_iter()
+                        .map(|arg| attributes::UserData::new(None, arg))
+                        .collect(),
+                });
+            }
+            EnumValue {
+                name,
+                number,
+                attributes: Attributes {
+                    comments,
+                    user,
+                    ..Default::default()
+                },
+            }
+        })
+}
+
+fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
+    let mut i = 0;
+    for value in &mut values {
+        if value.number == INVALID_ENUM_NUMBER {
+            value.number = i;
+            i += 1;
+        } else {
+            i = value.number + 1;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn values() -> Result<()> {
+        let (en, modifiers) = en::parser()
+            .parse(
+                r#"
+            /** enum comment */
+            public enum En {
+                // value comment
+                Zero,
+                One,
+                Two,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(modifiers.visibility, Some(Visibility::Public));
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["enum comment"])]
+        );
+        assert_eq!(en.values.len(), 3);
+        assert_eq!(en.values[0].name, "Zero");
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        assert!(en.values[0].attributes.user.is_empty());
+        assert_eq!(en.values[1].number, 1);
+        assert_eq!(en.values[2].number, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn ctor_values() -> Result<()> {
+        let (en, _) = en::parser()
+            .parse(
+                r#"
+            enum En implements Labeled {
+                Five(5, "five"),
+                Six(compute(1, 5), "six, seven"),
+                @Deprecated
+                Named("named"),
+                Negative(-1, "negative") {
+                    @Override
+                    public String describe() { return label; }
+                };
+
+                private final int value;
+                private final String label;
+
+                En(int value, String label) {
+                    this.value = value;
+                    this.label = label;
+                }
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.values.len(), 4);
+        assert_eq!(en.values[0].number, 5);
+        assert_eq!(en.values[1].number, 6);
+        assert_eq!(en.values[2].number, 7);
+        assert_eq!(en.values[3].number, -1);
+        assert_eq!(
+            en.values[1].attributes.user,
+            vec![attributes::User::new(
+                en::ARGS_ATTRIBUTE,
+                vec![
+                    attributes::UserData::new(None, "compute(1, 5)"),
+                    attributes::UserData::new(None, "six, seven"),
+                ]
+            )]
+        );
+        assert_eq!(
+            en.values[2].attributes.user,
+            vec![
+                attributes::User::new_flag("Deprecated"),
+                attributes::User::new(
+                    en::ARGS_ATTRIBUTE,
+                    vec![attributes::UserData::new(None, "named")]
+                ),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::parser::comment;
+use apyxl::model::Comment;
+use apyxl::parser::error::Error;
+use chumsky::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExprBlock<'a> {
+    Comment(Comment<'a>),
+    Body(&'a str),
+    Nested(Vec<ExprBlock<'a>>),
+}
+
+/// Nested `{}` blocks, e.g. method bodies. Braces within string and char literals are not
+/// treated as delimiters.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<ExprBlock<'a>>, Error<'a>> + Clone {
+    let body = choice((literal(), none_of("{}\"'").ignored()))
+        .repeated()
+        .at_least(1)
+        .slice()
+        .map(&str::trim);
+    recursive(|nested| {
+        choice((
+            comment::single().boxed().padded().map(ExprBlock::Comment),
+            nested.map(ExprBlock::Nested),
+            body.map(ExprBlock::Body),
+        ))
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+        .recover_with(via_parser(nested_delimiters('{', '}', [], |_| vec![])))
+    })
+}
+
+/// Skips an expression up to (but not including) any of the `terminators` that are not nested
+/// within brackets or literals, e.g. a default value or an expression body.
+pub fn expr<'a>(terminators: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let nested = recursive(|nested| {
+        let inner = choice((nested, literal(), none_of("()[]{}\"'").ignored())).repeated();
+        choice((
+            inner.clone().delimited_by(just('('), just(')')),
+            inner.clone().delimited_by(just('['), just(']')),
+            inner.delimited_by(just('{'), just('}')),
+        ))
+        .ignored()
+    });
+    choice((
+        nested,
+        literal(),
+        any().filter(|c: &char| !"()[]{}\"'".contains(*c)).ignored(),
+    ))
+    .and_is(one_of(terminators).not())
+    .repeated()
+    .at_least(1)
+    .ignored()
+}
+
+/// String or char literal, e.g. `"a\"b"` or `'}'`.
+fn literal<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    let contents = |delimiter| {
+        choice((
+            just('\\').then(any()).ignored(),
+            none_of(delimiter).ignored(),
+        ))
+        .repeated()
+        .delimited_by(just(delimiter), just(delimiter))
+    };
+    choice((contents('"'), contents('\''))).ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::prelude::*;
+
+    use crate::parser::expr_block;
+    use crate::parser::expr_block::ExprBlock;
+
+    #[test]
+    fn nested() {
+        let result = expr_block::parser()
+            .parse("{ int x = run(() -> { return 1; }); if (x) { return; } }")
+            .into_result();
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                ExprBlock::Body("int x = run(() ->"),
+                ExprBlock::Nested(vec![ExprBlock::Body("return 1;")]),
+                ExprBlock::Body("); if (x)"),
+                ExprBlock::Nested(vec![ExprBlock::Body("return;")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn literals() {
+        let result = expr_block::parser()
+            .parse(r#"{ String s = "}\"{"; char c = '{'; }"#)
+            .into_result();
+        assert_eq!(
+            result.unwrap(),
+            vec![ExprBlock::Body(r#"String s = "}\"{"; char c = '{';"#)]
+        );
+    }
+
+    #[test]
+    fn expr() {
+        let result = expr_block::expr(",)")
+            .then(just(",").then(any().repeated()))
+            .parse(r#"List.of(1, 2).stream().map(i -> i + 1) + ",)", rest"#)
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn expr_terminator() {
+        let result = expr_block::expr("\n")
+            .then_ignore(just('\n'))
+            .then_ignore(text::ident())
+            .parse("1 + 2\nnext")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn empty() {
+        let result = expr_block::parser().parse("{}").into_result();
+        assert_eq!(result.unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,176 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::visibility::Modifiers;
+use crate::parser::{comment, expr_block, ty, visibility};
+use apyxl::model::{Attributes, Field, Semantics, Type, TypeRef};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Field declaration e.g. `private final String name = "a";`. Multiple declarators like
+/// `int a, b = 1;` are parsed as separate fields that share the same comments and annotations.
+/// Initializers are skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Vec<Field<'a>>, Modifiers), Error<'a>> {
+    let initializer = just('=').padded().then(expr_block::expr(",;"));
+    let declarator = text::ident().padded().then_ignore(initializer.or_not());
+    comment::multi()
+        .padded()
+        .then(visibility::modifiers())
+        .then(ty::parser(config))
+        .then(
+            declarator
+                .separated_by(just(','))
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .then_ignore(just(';').padded())
+        .map(|(((comments, (user, modifiers)), ty), names)| {
+            let fields = names
+                .into_iter()
+                .map(|name| Field {
+                    name,
+                    ty: ty.clone(),
+                    attributes: Attributes {
+                        comments: comments.clone(),
+                        user: user.clone(),
+                        ..Default::default()
+                    },
+                    is_static: modifiers.is_static,
+                    value: None,
+                    default: None,
+                })
+                .collect_vec();
+            (fields, modifiers)
+        })
+}
+
+/// Method or record component parameter e.g. `final @Annotation Type name`. A varargs parameter
+/// like `Type... name` is parsed as an array.
+pub fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(visibility::modifiers())
+        .then(ty::parser(config))
+        .then(just("...").padded().or_not())
+        .then(text::ident().padded())
+        .map(|((((comments, (user, _)), ty), is_varargs), name)| Field {
+            name,
+            ty: match is_varargs {
+                Some(_) => TypeRef::new(Type::new_array(ty), Semantics::Value),
+                None => ty,
+            },
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+            is_static: false,
+            value: None,
+            default: None,
+        })
+}
+
+/// Comma-separated parameters within parentheses, e.g. `(int a, String b)`.
+pub fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    param(config)
+        .separated_by(just(',').padded())
+        .collect::<Vec<_>>()
+        .delimited_by(
+            just('(').padded(),
+            just(')').padded().recover_with(skip_then_retry_until(
+                none_of(")").ignored(),
+                just(')').ignored(),
+            )),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::field;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn field() -> Result<()> {
+        let (fields, modifiers) = field::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** field comment */
+            @JsonProperty("name")
+            private final List<Dto> name;
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "name");
+        assert_eq!(
+            fields[0].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(
+            fields[0].attributes.user,
+            vec![attributes::User::new(
+                "JsonProperty",
+                vec![attributes::UserData::new(None, "name")]
+            )]
+        );
+        assert_eq!(modifiers.visibility, Some(Visibility::Private));
+        assert!(!fields[0].is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn static_initializer() -> Result<()> {
+        let (fields, modifiers) = field::parser(&TEST_CONFIG)
+            .parse(r#"public static final Map<String, Integer> NAMES = Map.of("a", 1);"#)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields[0].name, "NAMES");
+        assert!(fields[0].is_static);
+        assert_eq!(modifiers.visibility, Some(Visibility::Public));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_declarators() -> Result<()> {
+        let (fields, _) = field::parser(&TEST_CONFIG)
+            .parse("int a, b = foo(1, 2), c;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0].name, "a");
+        assert_eq!(fields[1].name, "b");
+        assert_eq!(fields[2].name, "c");
+        assert!(fields.iter().all(|field| field.ty.value == Type::I32));
+        Ok(())
+    }
+
+    #[test]
+    fn varargs_param() -> Result<()> {
+        let field = field::param(&TEST_CONFIG)
+            .parse("final String... names")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "names");
+        assert_eq!(
+            field.ty.value,
+            Type::new_array(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+}
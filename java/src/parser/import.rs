@@ -0,0 +1,113 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use crate::parser::visibility::keyword;
+use apyxl::model::EntityId;
+use apyxl::parser::error::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Import {
+    /// `import a.b.C;`
+    Single(EntityId),
+    /// `import a.b.*;`
+    Star(EntityId),
+}
+
+impl Import {
+    /// The name the import is referred to by within the file, if any.
+    pub fn local_name(&self) -> Option<&str> {
+        match self {
+            Import::Single(id) => id.component_names().last(),
+            Import::Star(_) => None,
+        }
+    }
+}
+
+/// `package a.b.c;`
+pub fn package<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("package"))
+        .ignore_then(qualified_name())
+        .then_ignore(just(';').padded())
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+/// `import` declarations. Static imports refer to members rather than types, so they are parsed
+/// as `None`.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Option<Import>, Error<'a>> {
+    let single = qualified_name()
+        .map(|components| Import::Single(EntityId::new_unqualified_vec(components.into_iter())));
+    let star = qualified_name()
+        .then_ignore(just(".*"))
+        .map(|components| Import::Star(EntityId::new_unqualified_vec(components.into_iter())));
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("import"))
+        .ignore_then(keyword("static").or_not())
+        .then(choice((star, single)))
+        .then_ignore(just(';').padded())
+        .map(|(is_static, import)| match is_static {
+            Some(_) => None,
+            None => Some(import),
+        })
+}
+
+fn qualified_name<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::model::EntityId;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn package() -> Result<()> {
+        let package = import::package()
+            .parse("package a.b.c;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(package, EntityId::new_unqualified("a.b.c"));
+        Ok(())
+    }
+
+    #[test]
+    fn single() -> Result<()> {
+        run_test(
+            "import a.b.C;",
+            Some(Import::Single(EntityId::new_unqualified("a.b.C"))),
+        )
+    }
+
+    #[test]
+    fn star() -> Result<()> {
+        run_test(
+            "import a.b.*;",
+            Some(Import::Star(EntityId::new_unqualified("a.b"))),
+        )
+    }
+
+    #[test]
+    fn static_import() -> Result<()> {
+        run_test("import static a.b.C.method;", None)?;
+        run_test("import static a.b.C.*;", None)
+    }
+
+    fn run_test(data: &'static str, expected: Option<Import>) -> Result<()> {
+        let import = import::parser()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(import, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,517 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use apyxl::model::{
+    Api, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::annotations::annotations;
+use crate::parser::import::Import;
+
+mod annotations;
+mod comment;
+mod dto;
+mod en;
+mod expr_block;
+mod field;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod visibility;
+
+/// Parses a subset of Java declarations, i.e. classes, records, interfaces, and enums along with
+/// their fields and method signatures.
+///
+/// The `package` of each chunk determines its namespace. The chunk's file path is not used since
+/// the package already determines where the types live.
+#[derive(Default)]
+pub struct JavaParser {}
+
+impl apyxl::Parser for JavaParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut parsed_chunks = Vec::new();
+        let mut all_entity_ids = HashSet::<EntityId>::default();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let imports = import::parser().padded().repeated().collect::<Vec<_>>();
+
+            let ((package, imports), children) = comment::multi()
+                .padded()
+                .ignore_then(annotations())
+                .ignore_then(import::package().or_not())
+                .then(imports)
+                .then(namespace::children(config).padded())
+                .then_ignore(end())
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Default::default(),
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            let package = package.unwrap_or_default();
+            collect_referenceable_entity_ids(&api, package.clone(), &mut all_entity_ids);
+
+            let imports = imports.into_iter().flatten().collect::<Vec<_>>();
+            parsed_chunks.push((chunk, package, imports, api));
+        }
+
+        // Imports can only be resolved once all chunks are parsed, since star imports don't say
+        // what's in the package they import.
+
+        for (chunk, package, imports, mut api) in parsed_chunks {
+            let chunk_path = chunk.relative_file_path.as_deref();
+
+            debug!("applying imports to chunk {:?}...", chunk_path);
+            let mut local_entity_ids = HashSet::new();
+            collect_referenceable_entity_ids(&api, EntityId::default(), &mut local_entity_ids);
+            let imports = resolve_imports(&all_entity_ids, &local_entity_ids, &imports);
+            apply_imports(&mut api, &imports)?;
+
+            for component in package.component_names() {
+                builder.enter_namespace(component)
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Imports that refer to entities parsed from any chunk.
+struct ResolvedImports<'a> {
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    /// Map of local names to the fully qualified [EntityId] they were imported from.
+    single: HashMap<String, EntityId>,
+    /// Packages imported with `.*`.
+    star: Vec<&'a EntityId>,
+}
+
+/// Imports of entities that were not parsed, e.g. from external libraries, are skipped.
+fn resolve_imports<'a>(
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    imports: &'a [Import],
+) -> ResolvedImports<'a> {
+    let mut resolved = ResolvedImports {
+        all_entity_ids,
+        local_entity_ids,
+        single: HashMap::new(),
+        star: vec![],
+    };
+    for import in imports {
+        match import {
+            Import::Single(id) => {
+                if let Some(local_name) = import.local_name()
+                    && all_entity_ids.contains(id)
+                {
+                    resolved.single.insert(local_name.to_string(), id.clone());
+                }
+            }
+            Import::Star(package) => resolved.star.push(package),
+        }
+    }
+    resolved
+}
+
+fn collect_referenceable_entity_ids(ns: &Namespace, id: EntityId, set: &mut HashSet<EntityId>) {
+    for child in &ns.children {
+        let id = id.child_unqualified(child.name());
+        set.insert(id.clone());
+        if let NamespaceChild::Dto(dto) = child
+            && let Some(ns) = &dto.namespace
+        {
+            collect_referenceable_entity_ids(ns, id, set)
+        }
+    }
+}
+
+fn apply_imports(namespace: &mut Namespace, imports: &ResolvedImports) -> Result<()> {
+    let apply_import_to_field =
+        |field: &mut Field| -> Result<()> { apply_imports_to_type(&mut field.ty, imports) };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_imports_to_type(&mut param.ty, imports)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_imports_to_type(return_ty, imports)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+        if let Some(dto_ns) = &mut dto.namespace {
+            apply_imports(dto_ns, imports)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_import_to_rpc(rpc)?;
+    }
+
+    for field in namespace.fields_mut() {
+        apply_import_to_field(field)?;
+    }
+
+    // note: enums have no type refs.
+
+    Ok(())
+}
+
+fn apply_imports_to_type(ty: &mut TypeRef, imports: &ResolvedImports) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Set(ty) => apply_imports_to_type(ty, imports)?,
+        Type::FixedArray { ty, .. } => apply_imports_to_type(ty, imports)?,
+        Type::Optional(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(&mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(key, imports)?;
+            apply_imports_to_type(value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(id, imports)?;
+            for arg in args {
+                apply_imports_to_type(arg, imports)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_imports_to_type(param, imports)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_imports_to_type(return_ty, imports)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(id: &mut EntityId, imports: &ResolvedImports) -> Result<()> {
+    let imported = id
+        .component_names()
+        .next()
+        .and_then(|name| imports.single.get(name));
+    if let Some(imported) = imported {
+        let mut rest = id.clone();
+        rest.pop_front();
+        *id = imported.concat(&rest)?;
+    } else if !imports.local_entity_ids.contains(id) {
+        for package in &imports.star {
+            let qualified = package.concat(id)?;
+            if imports.all_entity_ids.contains(&qualified) {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::JavaParser;
+    use apyxl::model::{Builder, Comment, EntityId, UNDEFINED_NAMESPACE};
+    use apyxl::parser::Config;
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // comment
+        import java.util.List;
+        /** dto comment */
+        public class Dto {
+            public List<Integer> field;
+            public void method() {}
+        }
+        record Record(int a) {}
+        interface Interface {}
+        enum En { A }
+        // end comment ignored
+        "#,
+        );
+        let mut builder = Builder::default();
+        JavaParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("Dto").is_some(), "dto");
+        assert!(model.api().dto("Record").is_some(), "record");
+        assert!(model.api().dto("Interface").is_some(), "interface");
+        assert!(model.api().en("En").is_some(), "en");
+        assert_eq!(
+            model.api().dto("Dto").unwrap().attributes.comments,
+            vec![Comment::unowned(&["dto comment"])],
+            "comment after import attributed to dto"
+        );
+        assert!(
+            model.api().dto("Dto").unwrap().rpc("method").is_some(),
+            "dto method"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        public enum En { A }
+        enum IgnoredEn { A }
+        public class Dto {}
+        class IgnoredDto {}
+        "#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        JavaParser::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("Dto").is_some());
+        assert!(model.api().en("En").is_some());
+        assert!(model.api().dto("IgnoredDto").is_none());
+        assert!(model.api().en("IgnoredEn").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn package() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // license
+        package a.b.c;
+
+        public class Dto {
+            public static class Nested {}
+            public Nested nested;
+        }
+        "#,
+        );
+        let mut builder = Builder::default();
+        JavaParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(
+            model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.b.c.Dto.Nested"))
+                .is_some()
+        );
+        Ok(())
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::JavaParser;
+        use apyxl::model::{Builder, Chunk, EntityId, Model};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn single() -> Result<()> {
+            let a = "package a;\npublic class Id {}";
+            let test = r#"
+            package test;
+            import a.Id;
+            public record Entity(Id id) {}
+            "#;
+            run_dto_chunked_test(
+                &[("a/Id.java", a), ("test/Entity.java", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn nested() -> Result<()> {
+            let a = "package a;\npublic class Outer { public static class Id {} }";
+            let test = r#"
+            package test;
+            import a.Outer.Id;
+            public record Entity(Id id) {}
+            "#;
+            run_dto_chunked_test(
+                &[("a/Outer.java", a), ("test/Entity.java", test)],
+                "test.Entity",
+                "ns:a.d:Outer.d:Id",
+            )
+        }
+
+        #[test]
+        fn star() -> Result<()> {
+            let a = "package x.a;\npublic class Id {}";
+            let test = r#"
+            package y.test;
+            import x.a.*;
+            import java.util.*;
+            public record Entity(Id id) {}
+            "#;
+            run_dto_chunked_test(
+                &[("x/a/Id.java", a), ("y/test/Entity.java", test)],
+                "y.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn star_local_precedence() -> Result<()> {
+            let a = "package a;\npublic class Id {}";
+            let test = r#"
+            package test;
+            import a.*;
+            class Id {}
+            public record Entity(Id id) {}
+            "#;
+            run_dto_chunked_test(
+                &[("a/Id.java", a), ("test/Entity.java", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn same_package() -> Result<()> {
+            let a = "package test;\npublic class Id {}";
+            let test = r#"
+            package test;
+            public record Entity(Id id) {}
+            "#;
+            run_dto_chunked_test(
+                &[("test/Id.java", a), ("test/Entity.java", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn rpc() -> Result<()> {
+            let a = "package a;\npublic class Id {}";
+            let test = r#"
+            package test;
+            import a.Id;
+            import static a.Id.create;
+            public interface Service {
+                List<Id> rpc(Id id);
+            }
+            "#;
+            run_chunked_test(&[("a/Id.java", a), ("test/Service.java", test)], |model| {
+                let rpc = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("test.Service"))
+                    .unwrap()
+                    .rpc("rpc")
+                    .unwrap();
+                let expected = EntityId::try_from("ns:a.d:Id")?;
+                assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            JavaParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, dto, en, expr_block};
+use apyxl::model::NamespaceChild;
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Top-level type declarations within a file. Types without an access modifier are
+/// package-private.
+pub fn children<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Vec<NamespaceChild<'a>>, Error<'a>> {
+    choice((
+        dto::parser(config).map(|(c, m)| Some((NamespaceChild::Dto(c), m))),
+        en::parser().map(|(c, m)| Some((NamespaceChild::Enum(c), m))),
+        // Catch comments after all children
+        comment::single().padded().map(|_| None),
+    ))
+    .recover_with(skip_then_retry_until(
+        choice((expr_block::parser().ignored(), any().ignored())),
+        end(),
+    ))
+    .map(|opt| match opt {
+        Some((child, modifiers)) => modifiers
+            .visibility_or(Visibility::Private)
+            .filter(child, config),
+        None => None,
+    })
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|v| v.into_iter().flatten().collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use apyxl::model::Namespace;
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn children() -> Result<()> {
+        let children = namespace::children(&TEST_CONFIG)
+            .parse(
+                r#"
+            public class Dto {}
+            public record Record(int a) {}
+            interface Interface {}
+            public enum En { A, B }
+            // trailing comment
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let namespace = Namespace {
+            children,
+            ..Default::default()
+        };
+        assert!(namespace.dto("Dto").is_some());
+        assert!(namespace.dto("Record").is_some());
+        assert!(namespace.dto("Interface").is_some());
+        assert!(namespace.en("En").is_some());
+        assert_eq!(namespace.children.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn package_private_children_filtered() -> Result<()> {
+        let children = namespace::children(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            public class Dto {}
+            class PackagePrivateDto {}
+            enum PackagePrivateEn { A }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name(), "Dto");
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::visibility::{Modifiers, keyword};
+use crate::parser::{comment, expr_block, field, ty, visibility};
+use apyxl::model::{Attributes, Rpc, RpcKind};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Method signature e.g. `public <T> String name(int a) throws IOException { ... }`. Bodies,
+/// type parameters, and `throws` clauses are skipped.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, (Rpc<'a>, Modifiers), Error<'a>> {
+    let throws = keyword("throws").padded().then(expr_block::expr("{;"));
+    let body = choice((
+        expr_block::parser().padded().ignored(),
+        just(';').padded().ignored(),
+    ));
+    comment::multi()
+        .padded()
+        .then(visibility::modifiers())
+        .then_ignore(ty::type_params().padded().or_not())
+        .then(ty::return_ty(config))
+        .then(text::ident().padded())
+        .then(field::params(config))
+        .then_ignore(throws.or_not())
+        .then_ignore(body)
+        .map(
+            |((((comments, (user, modifiers)), return_type), name), params)| {
+                (
+                    Rpc {
+                        name: Cow::Borrowed(name),
+                        type_params: vec![],
+                        params,
+                        return_type,
+                        error_type: None,
+                        attributes: Attributes {
+                            comments,
+                            user,
+                            ..Default::default()
+                        },
+                        is_static: modifiers.is_static,
+                        kind: RpcKind::default(),
+                    },
+                    modifiers,
+                )
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let (rpc, modifiers) = rpc::parser(&TEST_CONFIG)
+            .parse("void rpc_name();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert!(!rpc.is_static);
+        assert_eq!(modifiers.visibility, None);
+        Ok(())
+    }
+
+    #[test]
+    fn modifiers() -> Result<()> {
+        let (rpc, modifiers) = rpc::parser(&TEST_CONFIG)
+            .parse("private static synchronized void rpc_name() {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.is_static);
+        assert_eq!(modifiers.visibility, Some(Visibility::Private));
+        Ok(())
+    }
+
+    #[test]
+    fn body_skipped() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            public <T extends Dto> String rpc_name() throws IOException, IllegalStateException {
+                if (true) { return "a"; }
+                return "}";
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("Dto rpc_name(int a, @Nullable final Dto b, String... c);")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.params.len(), 3);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(rpc.params[0].ty.value, Type::I32);
+        assert_eq!(rpc.params[1].name, "b");
+        assert_eq!(
+            rpc.params[1].ty.value,
+            Type::Api(EntityId::new_unqualified("Dto"))
+        );
+        assert_eq!(
+            rpc.params[1].attributes.user,
+            vec![attributes::User::new_flag("Nullable")]
+        );
+        assert_eq!(
+            rpc.params[2].ty.value,
+            Type::new_array(TypeRef::new(Type::String, Semantics::Value))
+        );
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::Api(EntityId::new_unqualified("Dto")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comment() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** rpc comment */
+            @Override
+            public void rpc_name() {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        assert_eq!(
+            rpc.attributes.user,
+            vec![attributes::User::new_flag("Override")]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Java types, e.g. `int`, `Integer`, `List<String>`, `Map<String, a.b.Dto>`, or `Dto[]`.
+///
+/// Boxed primitives are parsed as their primitive type, and `byte[]` is parsed as bytes. Note
+/// that sets are parsed as arrays since there is no set type in the model.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let primitive = choice((
+            choice((util::keyword_ex("boolean"), util::keyword_ex("Boolean"))).map(|_| Type::Bool),
+            choice((util::keyword_ex("byte"), util::keyword_ex("Byte"))).map(|_| Type::I8),
+            choice((util::keyword_ex("short"), util::keyword_ex("Short"))).map(|_| Type::I16),
+            choice((util::keyword_ex("int"), util::keyword_ex("Integer"))).map(|_| Type::I32),
+            choice((util::keyword_ex("long"), util::keyword_ex("Long"))).map(|_| Type::I64),
+            choice((util::keyword_ex("float"), util::keyword_ex("Float"))).map(|_| Type::F32),
+            choice((util::keyword_ex("double"), util::keyword_ex("Double"))).map(|_| Type::F64),
+            choice((util::keyword_ex("char"), util::keyword_ex("Character"))).map(|_| Type::U16),
+            util::keyword_ex("String").map(|_| Type::String),
+        ));
+        let ty = choice((
+            primitive,
+            user_ty(config).map(Type::User),
+            array(nested.clone()),
+            map(nested.clone()),
+            optional(nested),
+            // Note that entity_id should come last because it is greedy.
+            entity_id().map(Type::Api),
+        ));
+
+        let dimension = just('[').padded().then(just(']'));
+        ty.then(dimension.repeated().count())
+            .map(|(ty, dimensions)| {
+                let mut ty = TypeRef::new(ty, Semantics::Value);
+                for _ in 0..dimensions {
+                    ty = match ty.value {
+                        Type::I8 => TypeRef::new(Type::Bytes, Semantics::Value),
+                        _ => TypeRef::new(Type::new_array(ty), Semantics::Value),
+                    };
+                }
+                ty
+            })
+            .boxed()
+    })
+}
+
+/// Return types, where `void` is treated as no return type.
+pub fn return_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Option<TypeRef>, Error<'a>> {
+    choice((
+        util::keyword_ex("void").map(|_| None),
+        parser(config).map(Some),
+    ))
+}
+
+/// Generic type parameters, e.g. `<T extends Comparable<T>>`, are skipped.
+pub fn type_params<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    recursive(|nested| {
+        choice((nested, none_of("<>").ignored()))
+            .repeated()
+            .delimited_by(just('<'), just('>'))
+            .ignored()
+    })
+}
+
+/// Generic type arguments. Annotations and wildcard bounds like `? extends T` are ignored.
+fn generic_arg<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    let wildcard =
+        just('?').then(choice((util::keyword_ex("extends"), util::keyword_ex("super"))).padded());
+    annotations()
+        .ignore_then(wildcard.or_not())
+        .ignore_then(ty.padded())
+}
+
+/// Lists, collections, and sets, e.g. `List<T>` or `HashSet<T>`.
+fn array<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((
+        util::keyword_ex("List"),
+        util::keyword_ex("ArrayList"),
+        util::keyword_ex("LinkedList"),
+        util::keyword_ex("Collection"),
+        util::keyword_ex("Iterable"),
+        util::keyword_ex("Set"),
+        util::keyword_ex("HashSet"),
+        util::keyword_ex("LinkedHashSet"),
+        util::keyword_ex("SortedSet"),
+        util::keyword_ex("TreeSet"),
+    ))
+    .then(just('<').padded())
+    .ignore_then(generic_arg(ty))
+    .then_ignore(just('>'))
+    .map(Type::new_array)
+}
+
+/// Maps, e.g. `Map<K, V>` or `HashMap<K, V>`.
+fn map<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((
+        util::keyword_ex("Map"),
+        util::keyword_ex("HashMap"),
+        util::keyword_ex("LinkedHashMap"),
+        util::keyword_ex("SortedMap"),
+        util::keyword_ex("TreeMap"),
+    ))
+    .then(just('<').padded())
+    .ignore_then(generic_arg(ty.clone()))
+    .then_ignore(just(','))
+    .then(generic_arg(ty))
+    .then_ignore(just('>'))
+    .map(|(key, value)| Type::new_map(key, value))
+}
+
+/// `Optional<T>`.
+fn optional<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    util::keyword_ex("Optional")
+        .then(just('<').padded())
+        .ignore_then(generic_arg(ty))
+        .then_ignore(just('>'))
+        .map(Type::new_optional)
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "Instant".to_string(),
+                name: "instant".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(boolean, "boolean", Type::Bool);
+    test!(byte, "byte", Type::I8);
+    test!(short, "short", Type::I16);
+    test!(int, "int", Type::I32);
+    test!(long, "long", Type::I64);
+    test!(float, "float", Type::F32);
+    test!(double, "double", Type::F64);
+    test!(char, "char", Type::U16);
+    test!(string, "String", Type::String);
+    test!(boxed_boolean, "Boolean", Type::Bool);
+    test!(boxed_byte, "Byte", Type::I8);
+    test!(boxed_short, "Short", Type::I16);
+    test!(boxed_int, "Integer", Type::I32);
+    test!(boxed_long, "Long", Type::I64);
+    test!(boxed_float, "Float", Type::F32);
+    test!(boxed_double, "Double", Type::F64);
+    test!(boxed_char, "Character", Type::U16);
+    test!(bytes, "byte[]", Type::Bytes);
+    test!(user, "Instant", Type::User("instant".to_string()));
+    test!(
+        entity_id,
+        "a.b.Type",
+        Type::Api(EntityId::new_unqualified("a.b.Type"))
+    );
+    test!(
+        keyword_prefixed_entity_id,
+        "Integers",
+        Type::Api(EntityId::new_unqualified("Integers"))
+    );
+    test!(array, "int[]", Type::new_array(ty(Type::I32)));
+    test!(nested_array, "byte[] []", Type::new_array(ty(Type::Bytes)));
+    test!(list, "List<String>", Type::new_array(ty(Type::String)));
+    test!(
+        wildcard,
+        "Collection<? extends Integer>",
+        Type::new_array(ty(Type::I32))
+    );
+    test!(set, "Set<Long>", Type::new_array(ty(Type::I64)));
+    test!(
+        map,
+        "Map<String, List<Integer>>",
+        Type::new_map(ty(Type::String), ty(Type::new_array(ty(Type::I32))))
+    );
+    test!(
+        optional,
+        "Optional<String>",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        annotated_generic_arg,
+        "List<@NonNull String>",
+        Type::new_array(ty(Type::String))
+    );
+
+    #[test]
+    fn return_ty_void() -> Result<()> {
+        let ty = ty::return_ty(&TY_TEST_CONFIG)
+            .parse("void")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty, None);
+        Ok(())
+    }
+
+    #[test]
+    fn type_params() {
+        let result = ty::type_params()
+            .parse("<K extends Comparable<K>, V>")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn unsupported_generic() {
+        let result = ty::parser(&TY_TEST_CONFIG)
+            .parse("Pair<Integer, Integer>")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    fn run_test(data: &'static str, expected: Type) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty.value, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
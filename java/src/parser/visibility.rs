@@ -0,0 +1,176 @@
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotation;
+use apyxl::model::attributes;
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `private`, `protected`, and package-private declarations are not visible outside of their
+/// package so they are considered private.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Modifiers that affect the parsed model.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Modifiers {
+    /// `None` if there is no access modifier, since the default depends on where the declaration
+    /// is, e.g. interface members are public by default.
+    pub visibility: Option<Visibility>,
+    pub is_static: bool,
+}
+
+impl Modifiers {
+    pub fn visibility_or(&self, default: Visibility) -> Visibility {
+        self.visibility.unwrap_or(default)
+    }
+}
+
+/// Modifiers that don't affect the parsed model.
+const IGNORED_MODIFIERS: &[&str] = &[
+    "final",
+    "abstract",
+    "default",
+    "sealed",
+    "non-sealed",
+    "synchronized",
+    "native",
+    "transient",
+    "volatile",
+    "strictfp",
+];
+
+const PRIVATE_MODIFIERS: &[&str] = &["private", "protected"];
+
+/// Zero or more modifiers and annotations in any order, e.g. `@Deprecated public static final`.
+pub fn modifiers<'a>() -> impl Parser<'a, &'a str, (Vec<attributes::User<'a>>, Modifiers), Error<'a>>
+{
+    let modifier = choice((just("non-sealed"), text::ident()))
+        .try_map(|modifier: &str, span| {
+            if modifier == "public"
+                || modifier == "static"
+                || IGNORED_MODIFIERS.contains(&modifier)
+                || PRIVATE_MODIFIERS.contains(&modifier)
+            {
+                Ok(modifier)
+            } else {
+                Err(Rich::custom(
+                    span,
+                    format!("{} is not a modifier", modifier),
+                ))
+            }
+        })
+        .then_ignore(text::whitespace().at_least(1));
+    choice((
+        annotation().padded().map(Item::Annotation),
+        modifier.map(Item::Modifier),
+    ))
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|items| {
+        let mut user = vec![];
+        let mut modifiers = Modifiers::default();
+        for item in items {
+            match item {
+                Item::Annotation(annotation) => user.push(annotation),
+                Item::Modifier("public") => modifiers.visibility = Some(Visibility::Public),
+                Item::Modifier("static") => modifiers.is_static = true,
+                Item::Modifier(modifier) if PRIVATE_MODIFIERS.contains(&modifier) => {
+                    modifiers.visibility = Some(Visibility::Private)
+                }
+                Item::Modifier(_) => {}
+            }
+        }
+        (user, modifiers)
+    })
+}
+
+/// Keyword that must be followed by whitespace, e.g. `class `.
+pub fn keyword<'a>(keyword: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex(keyword)
+        .then(text::whitespace().at_least(1))
+        .ignored()
+}
+
+enum Item<'a> {
+    Annotation(attributes::User<'a>),
+    Modifier(&'a str),
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::visibility;
+    use crate::parser::visibility::{Modifiers, Visibility};
+    use apyxl::model::attributes;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn none() -> Result<()> {
+        let (user, modifiers) = visibility::modifiers()
+            .parse("final ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(user.is_empty());
+        assert_eq!(modifiers, Modifiers::default());
+        Ok(())
+    }
+
+    #[test]
+    fn public_static() -> Result<()> {
+        let (_, modifiers) = visibility::modifiers()
+            .parse("public static final ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(modifiers.visibility, Some(Visibility::Public));
+        assert!(modifiers.is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        for modifier in ["private ", "protected abstract ", "non-sealed private "] {
+            let (_, modifiers) = visibility::modifiers()
+                .parse(modifier)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(modifiers.visibility, Some(Visibility::Private));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn interleaved_annotations() -> Result<()> {
+        let (user, modifiers) = visibility::modifiers()
+            .parse("@Deprecated public @Nullable ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            user,
+            vec![
+                attributes::User::new_flag("Deprecated"),
+                attributes::User::new_flag("Nullable"),
+            ]
+        );
+        assert_eq!(modifiers.visibility, Some(Visibility::Public));
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub use parser::KotlinParser as Parser;
+
+mod parser;
@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use apyxl::model::attributes;
+use apyxl::parser::error::Error;
+
+/// Zero or more annotations, e.g. `@Serializable @SerialName("name") @a.b.Flag`. Use-site
+/// targets like `@get:` are ignored.
+///
+/// Annotation arguments are parsed as data, e.g. `@Annotation(1, key = "value")`. String
+/// arguments have their quotes stripped, and other arguments are kept verbatim.
+pub fn annotations<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> {
+    let use_site_target = text::ident().then(just(':')).or_not();
+    let name = text::ident().separated_by(just('.')).at_least(1).slice();
+    let string = none_of('"')
+        .repeated()
+        .slice()
+        .delimited_by(just('"'), just('"'));
+    let value = choice((
+        string,
+        none_of(",()\"")
+            .repeated()
+            .at_least(1)
+            .slice()
+            .map(str::trim),
+    ));
+    let data = text::ident()
+        .then_ignore(just('=').padded())
+        .or_not()
+        .then(value.padded())
+        .map(|(key, value)| attributes::UserData::new(key, value));
+    let data_list = data
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').then(text::whitespace()), just(')'));
+    just('@')
+        .ignore_then(use_site_target)
+        .ignore_then(name)
+        .then(data_list.or_not())
+        .map(|(name, data)| attributes::User {
+            name: Cow::Borrowed(name),
+            data: data.unwrap_or_default(),
+        })
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::annotations;
+    use apyxl::model::attributes;
+    use apyxl::model::attributes::UserData;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn flags() -> Result<()> {
+        run_test(
+            "@Serializable @a.b.Flag",
+            vec![
+                attributes::User::new_flag("Serializable"),
+                attributes::User::new_flag("a.b.Flag"),
+            ],
+        )
+    }
+
+    #[test]
+    fn data() -> Result<()> {
+        run_test(
+            r#"@Annotation(1, key = "value", other = Enum.VALUE)"#,
+            vec![attributes::User::new(
+                "Annotation",
+                vec![
+                    UserData::new(None, "1"),
+                    UserData::new(Some("key"), "value"),
+                    UserData::new(Some("other"), "Enum.VALUE"),
+                ],
+            )],
+        )
+    }
+
+    #[test]
+    fn use_site_target() -> Result<()> {
+        run_test(
+            r#"@get:JvmName("name")"#,
+            vec![attributes::User::new(
+                "JvmName",
+                vec![UserData::new(None, "name")],
+            )],
+        )
+    }
+
+    fn run_test(data: &'static str, expected: Vec<attributes::User<'static>>) -> Result<()> {
+        let annotations = annotations::annotations()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(annotations, expected);
+        Ok(())
+    }
+}
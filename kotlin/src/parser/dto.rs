@@ -0,0 +1,304 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, en, expr_block, field, rpc, visibility};
+use apyxl::model::{Attributes, Dto, Field, Namespace, NamespaceChild, Rpc};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `class`, `data class`, and `interface` declarations, e.g. `data class Name(val a: Int) { ... }`.
+///
+/// Properties in the primary constructor and the body are [Field]s, functions are [Rpc]s, and
+/// nested classes and enums are placed in the [Dto]'s namespace. Supertypes, `init` blocks,
+/// secondary constructors, and objects (including companion objects) are skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Dto<'a>, Visibility), Error<'a>> {
+    recursive(|nested| {
+        let prefix = choice((keyword("class"), keyword("interface")));
+        let ctor_params = field::ctor_param(config)
+            .separated_by(just(',').padded())
+            .allow_trailing()
+            .collect::<Vec<_>>()
+            .delimited_by(just('(').padded(), just(')').padded());
+        let ctor = annotations()
+            .then(visibility::parser())
+            .then(util::keyword_ex("constructor"))
+            .padded()
+            .or_not()
+            .ignore_then(ctor_params)
+            .map(|params| params.into_iter().flatten().collect_vec());
+        let member = choice((
+            nested.map(|(dto, v)| Member::Child(NamespaceChild::Dto(dto), v)),
+            en::parser().map(|(en, v)| Member::Child(NamespaceChild::Enum(en), v)),
+            rpc::parser(config).map(|(rpc, v)| Member::Rpc(rpc, v)),
+            field::parser(config).map(|(field, v)| Member::Field(field, v)),
+            skipped().map(|_| Member::Skipped),
+            // Catch comments after all members.
+            comment::single().padded().map(|_| Member::Skipped),
+        ));
+        let body = member
+            .then_ignore(just(';').padded().or_not())
+            .recover_with(skip_then_retry_until(
+                choice((expr_block::parser().ignored(), any().ignored())),
+                just('}').ignored(),
+            ))
+            .repeated()
+            .collect::<Vec<_>>()
+            .delimited_by(just('{').padded(), just('}').padded());
+        comment::multi()
+            .padded()
+            .then(annotations())
+            .then(visibility::parser())
+            .then_ignore(prefix)
+            .then(text::ident())
+            .then(ctor.or_not())
+            .then_ignore(supertypes().or_not())
+            .then(body.or_not())
+            .padded()
+            .map(
+                move |(((((comments, user), visibility), name), ctor_fields), members)| {
+                    let mut fields = vec![];
+                    let mut rpcs = vec![];
+                    let mut children = vec![];
+                    let ctor_fields = ctor_fields.unwrap_or_default().into_iter();
+                    let members = ctor_fields
+                        .map(|(field, v)| Member::Field(field, v))
+                        .chain(members.unwrap_or_default());
+                    for member in members {
+                        match member {
+                            Member::Field(field, v) => fields.extend(v.filter(field, config)),
+                            Member::Rpc(rpc, v) => rpcs.extend(v.filter(rpc, config)),
+                            Member::Child(child, v) => children.extend(v.filter(child, config)),
+                            Member::Skipped => {}
+                        }
+                    }
+                    let namespace = if children.is_empty() {
+                        None
+                    } else {
+                        Some(Namespace {
+                            children,
+                            ..Default::default()
+                        })
+                    };
+                    let dto = Dto {
+                        name,
+                        type_params: vec![],
+                        fields,
+                        rpcs,
+                        extends: vec![],
+                        implements: vec![],
+                        attributes: Attributes {
+                            comments,
+                            user,
+                            ..Default::default()
+                        },
+                        namespace,
+                    };
+                    (dto, visibility)
+                },
+            )
+            .boxed()
+    })
+}
+
+/// Declarations that are valid within a class body or file but are not part of the model, e.g.
+/// `object`s, `init` blocks, and secondary constructors.
+pub fn skipped<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let object = keyword("companion")
+        .or_not()
+        .then(util::keyword_ex("object"))
+        .then(text::whitespace().then(text::ident()).or_not())
+        .then(supertypes().or_not())
+        .then(expr_block::parser().padded().or_not())
+        .ignored();
+    let init = util::keyword_ex("init")
+        .then(expr_block::parser().padded())
+        .ignored();
+    let ctor = util::keyword_ex("constructor")
+        .then(expr_block::expr("{\n;}"))
+        .then(expr_block::parser().padded().or_not())
+        .ignored();
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then(choice((object, init, ctor)))
+        .padded()
+        .ignored()
+}
+
+/// Supertypes, e.g. `: Base(1), Interface`, are skipped.
+fn supertypes<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    just(':').padded().then(expr_block::expr("{\n;}")).ignored()
+}
+
+enum Member<'a> {
+    Field(Field<'a>, Visibility),
+    Rpc(Rpc<'a>, Visibility),
+    Child(NamespaceChild<'a>, Visibility),
+    Skipped,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (dto, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("class Dto")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert!(dto.fields.is_empty());
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("internal class Dto {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn data_class() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** dto comment */
+            @Serializable
+            data class Dto(
+                // field comment
+                val field0: String,
+                var field1: Int? = null,
+                notProperty: Int,
+                val field2: List<Other> = emptyList(),
+            ) : Base(), Interface
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(
+            dto.attributes.user,
+            vec![attributes::User::new_flag("Serializable")]
+        );
+        assert_eq!(dto.fields.len(), 3);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "field1");
+        assert_eq!(
+            dto.fields[1].ty.value,
+            Type::new_optional(TypeRef::new(Type::I32, Semantics::Value))
+        );
+        assert_eq!(dto.fields[2].name, "field2");
+        assert_eq!(
+            dto.fields[2].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Other")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn members() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            class Dto private constructor(val field0: String) : Base {
+                val field1: Int = 1
+                var field2: String = ""
+                    private set
+
+                init { require(field0.isNotEmpty()) }
+
+                constructor(other: Dto) : this(other.field0) {}
+
+                fun method(a: String): Boolean = a == field0
+
+                companion object {
+                    const val CONSTANT: Int = 1
+                    fun create(): Dto = Dto("a")
+                }
+
+                class Nested
+                enum class En { A, B }
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 3);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert_eq!(dto.fields[1].name, "field1");
+        assert_eq!(dto.fields[2].name, "field2");
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "method");
+        let namespace = dto.namespace.expect("namespace");
+        assert!(namespace.dto("Nested").is_some());
+        assert!(namespace.en("En").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn interface() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            interface Dto : Base {
+                val field: String
+                fun method(a: String): Boolean
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.rpcs.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn private_members_filtered() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            class Dto(val field0: Int, private val field1: Int) {
+                private val field2: Int = 1
+                protected fun method() {}
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert!(dto.rpcs.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, expr_block, visibility};
+use apyxl::model::{Attributes, Enum, EnumKind, EnumValue, EnumValueNumber};
+use apyxl::parser::error::Error;
+
+const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+
+/// `enum class Name { ... }`. Constructor parameters, supertypes, and members after the entries
+/// are skipped.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a>> {
+    let prefix = keyword("enum").then(keyword("class"));
+    let skipped = expr_block::expr("{");
+    let members = just(';')
+        .padded()
+        .then(expr_block::expr("}").or_not())
+        .ignored();
+    let values = en_value()
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .then_ignore(members.or_not())
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(skipped.or_not())
+        .then(values)
+        .map(|((((comments, user), visibility), name), values)| {
+            (
+                Enum {
+                    name,
+                    values: apply_enum_value_number_defaults(values),
+                    kind: EnumKind::Plain,
+                    underlying_type: None,
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                },
+                visibility,
+            )
+        })
+}
+
+/// Entries with constructor arguments, e.g. `A(1, "a")`, use the first argument as their number
+/// if it is an integer literal, e.g. `-1`, `0x1F`, `0b0100_0000`, or `1L`. Other numeric
+/// expressions, e.g. `1 + 5`, emit an error. Otherwise, the entry is numbered after the previous
+/// entry.
+fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
+    let literal = just('-')
+        .or_not()
+        .then(number())
+        .then_ignore(text::whitespace().then(one_of(",)")).rewind())
+        .map(|(negative, number)| match negative {
+            Some(_) => -number,
+            None => number,
+        });
+    let numeric_expr = just('-')
+        .or_not()
+        .then(one_of("0123456789"))
+        .rewind()
+        .ignore_then(expr_block::expr(",)"))
+        .validate(|_, span, emitter| {
+            emitter.emit(Rich::custom(
+                span,
+                "enum entry number must be an integer literal",
+            ));
+            INVALID_ENUM_NUMBER
+        });
+    let args = choice((literal, numeric_expr))
+        .padded()
+        .or_not()
+        .then_ignore(expr_block::expr(")").or_not())
+        .delimited_by(just('(').padded(), just(')').padded());
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(text::ident())
+        .then(args.or_not())
+        .then_ignore(expr_block::parser().or_not())
+        .padded()
+        .map(|(((comments, user), name), number)| EnumValue {
+            name,
+            number: number.flatten().unwrap_or(INVALID_ENUM_NUMBER),
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+        })
+}
+
+/// Integer literal with optional `_` separators, `0x`/`0b` prefixes, and suffixes, e.g. `10`,
+/// `0x1F`, `0b0100_0000`, or `1L`.
+fn number<'a>() -> impl Parser<'a, &'a str, EnumValueNumber, Error<'a>> {
+    let digits = |radix: u32| {
+        any()
+            .filter(move |c: &char| c.is_digit(radix) || *c == '_')
+            .repeated()
+            .at_least(1)
+            .slice()
+            .try_map(move |digits: &str, span| {
+                EnumValueNumber::from_str_radix(&digits.replace('_', ""), radix)
+                    .map_err(|_| Rich::custom(span, "invalid integer literal"))
+            })
+    };
+    choice((
+        just("0x").or(just("0X")).ignore_then(digits(16)),
+        just("0b").or(just("0B")).ignore_then(digits(2)),
+        digits(10),
+    ))
+    .then_ignore(one_of("uUL").repeated())
+}
+
+fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
+    let mut i = 0;
+    for value in &mut values {
+        if value.number == INVALID_ENUM_NUMBER {
+            value.number = i;
+            i += 1;
+        } else {
+            i = value.number + 1;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::Comment;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn values() -> Result<()> {
+        let (en, visibility) = en::parser()
+            .parse(
+                r#"
+            /** enum comment */
+            enum class En {
+                // value comment
+                Zero,
+                One,
+                Two,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["enum comment"])]
+        );
+        assert_eq!(en.values.len(), 3);
+        assert_eq!(en.values[0].name, "Zero");
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        assert_eq!(en.values[1].number, 1);
+        assert_eq!(en.values[2].number, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn ctor_values() -> Result<()> {
+        let (en, _) = en::parser()
+            .parse(
+                r#"
+            private enum class En(val value: Int, val label: String) : Labeled {
+                Five(5, "five"),
+                Named("named"),
+                Negative(-1, "negative") {
+                    override fun describe(): String = label
+                };
+
+                fun describe(): String = label
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.values.len(), 3);
+        assert_eq!(en.values[0].number, 5);
+        assert_eq!(en.values[1].number, 6);
+        assert_eq!(en.values[2].number, -1);
+        Ok(())
+    }
+
+    #[test]
+    fn ctor_literal_values() -> Result<()> {
+        let (en, _) = en::parser()
+            .parse(
+                r#"
+            enum class Color(val rgb: Int) {
+                RED(0xFF0000),
+                GREEN(0x00_FF_00),
+                BLUE(0b1111_1111),
+                BIG(1_000_000L),
+                NEGATIVE(-0x10),
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            en.values.iter().map(|v| v.number).collect::<Vec<_>>(),
+            vec![0xFF0000, 0x00FF00, 0b1111_1111, 1_000_000, -0x10]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ctor_expr_value() {
+        let result = en::parser()
+            .parse("enum class En(val value: Int) { Six(1 + 5) }")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
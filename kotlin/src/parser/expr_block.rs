@@ -0,0 +1,102 @@
+use crate::parser::comment;
+use apyxl::model::Comment;
+use apyxl::parser::error::Error;
+use chumsky::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExprBlock<'a> {
+    Comment(Comment<'a>),
+    Body(&'a str),
+    Nested(Vec<ExprBlock<'a>>),
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<ExprBlock<'a>>, Error<'a>> + Clone {
+    let body = none_of("{}").repeated().at_least(1).slice().map(&str::trim);
+    recursive(|nested| {
+        choice((
+            comment::single().boxed().padded().map(ExprBlock::Comment),
+            nested.map(ExprBlock::Nested),
+            body.map(ExprBlock::Body),
+        ))
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+        .recover_with(via_parser(nested_delimiters('{', '}', [], |_| vec![])))
+    })
+}
+
+/// Skips an expression up to (but not including) any of the `terminators` that are not nested
+/// within brackets or string literals, e.g. a default value or an expression body.
+pub fn expr<'a>(terminators: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let string = just('"')
+        .then(choice((just('\\').then(any()).ignored(), none_of('"').ignored())).repeated())
+        .then(just('"'))
+        .ignored();
+    let nested = recursive(|nested| {
+        let inner = choice((nested, string, none_of("()[]{}\"").ignored())).repeated();
+        choice((
+            inner.clone().delimited_by(just('('), just(')')),
+            inner.clone().delimited_by(just('['), just(']')),
+            inner.delimited_by(just('{'), just('}')),
+        ))
+        .ignored()
+    });
+    choice((
+        nested,
+        string,
+        any().filter(|c: &char| !"()[]{}\"".contains(*c)).ignored(),
+    ))
+    .and_is(one_of(terminators).not())
+    .repeated()
+    .at_least(1)
+    .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::prelude::*;
+
+    use crate::parser::expr_block;
+    use crate::parser::expr_block::ExprBlock;
+
+    #[test]
+    fn nested() {
+        let result = expr_block::parser()
+            .parse("{ val x = run { 1 }; if (x) { return } }")
+            .into_result();
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                ExprBlock::Body("val x = run"),
+                ExprBlock::Nested(vec![ExprBlock::Body("1")]),
+                ExprBlock::Body("; if (x)"),
+                ExprBlock::Nested(vec![ExprBlock::Body("return")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn expr() {
+        let result = expr_block::expr(",)")
+            .then(just(",").then(any().repeated()))
+            .parse(r#"listOf(1, 2).map { it + 1 } + ",)", rest"#)
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn expr_terminator() {
+        let result = expr_block::expr("\n")
+            .then_ignore(just('\n'))
+            .then_ignore(text::ident())
+            .parse("1 + 2\nnext")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn empty() {
+        let result = expr_block::parser().parse("{}").into_result();
+        assert_eq!(result.unwrap(), vec![]);
+    }
+}
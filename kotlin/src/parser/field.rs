@@ -0,0 +1,241 @@
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, expr_block, ty, visibility};
+use apyxl::model::{Attributes, Field, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Property declaration e.g. `val name: Type = value`. Initializers, delegates, and accessors are
+/// skipped. Properties must have an explicit type since types are not inferred.
+///
+/// All properties are parsed as non-static. Top-level properties are made static by the
+/// namespace parser.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Field<'a>, Visibility), Error<'a>> {
+    let initializer = choice((just('=').padded().ignored(), keyword("by").padded()))
+        .then(expr_block::expr("\n;}"));
+    let ty = just(':')
+        .padded()
+        .ignore_then(ty::parser(config))
+        .or_not()
+        .try_map(|ty, span| {
+            ty.ok_or_else(|| Rich::custom(span, "properties must have an explicit type"))
+        });
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then_ignore(choice((keyword("val"), keyword("var"))))
+        .then(text::ident())
+        .then(ty)
+        .then_ignore(initializer.or_not())
+        .then_ignore(accessor(config).repeated())
+        .then_ignore(just(';').padded().or_not())
+        .map(|((((comments, user), visibility), name), ty)| {
+            (
+                Field {
+                    name,
+                    ty,
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                    is_static: false,
+                    value: None,
+                    default: None,
+                },
+                visibility,
+            )
+        })
+}
+
+/// Primary constructor parameter. Only parameters declared with `val` or `var` are properties, so
+/// plain parameters are parsed as `None`. Default values are skipped.
+pub fn ctor_param<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Option<(Field<'a>, Visibility)>, Error<'a>> {
+    let default = just('=').padded().then(expr_block::expr(",)"));
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then(choice((keyword("val"), keyword("var"))).or_not())
+        .then(param(config))
+        .then_ignore(default.or_not())
+        .map(
+            |((((comments, user), visibility), is_property), mut field)| {
+                is_property?;
+                field.attributes.comments = comments;
+                field.attributes.user = user;
+                Some((field, visibility))
+            },
+        )
+}
+
+/// Function parameter e.g. `name: Type`. A `vararg` parameter is parsed as an array.
+pub fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    keyword("vararg")
+        .or_not()
+        .then(text::ident())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .map(|((is_vararg, name), ty)| Field {
+            name,
+            ty: match is_vararg {
+                Some(_) => TypeRef::new(Type::new_array(ty), Semantics::Value),
+                None => ty,
+            },
+            attributes: Default::default(),
+            is_static: false,
+            value: None,
+            default: None,
+        })
+}
+
+/// Getters and setters e.g. `get() = value`, `private set`, or `set(value) { ... }`.
+fn accessor<'a>(config: &'a Config) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let body = choice((
+        expr_block::parser().ignored(),
+        just('=').padded().then(expr_block::expr("\n;}")).ignored(),
+    ));
+    let params = text::ident()
+        .then(just(':').padded().then(ty::parser(config)).or_not())
+        .or_not()
+        .delimited_by(just('(').padded(), just(')').padded());
+    annotations()
+        .then(visibility::parser())
+        .then(choice((util::keyword_ex("get"), util::keyword_ex("set"))).padded())
+        .then(
+            params
+                .then(just(':').padded().then(ty::parser(config)).or_not())
+                .then(body.padded())
+                .or_not(),
+        )
+        .padded()
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::field;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn property() -> Result<()> {
+        let (field, visibility) = field::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** field comment */
+            @Transient
+            val name: String?
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        assert_eq!(
+            field.ty.value,
+            Type::new_optional(TypeRef::new(Type::String, Semantics::Value))
+        );
+        assert_eq!(
+            field.attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(
+            field.attributes.user,
+            vec![attributes::User::new_flag("Transient")]
+        );
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = field::parser(&TEST_CONFIG)
+            .parse("private var name: Int = 1")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn initializer_and_accessors() -> Result<()> {
+        let (field, _) = field::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            var name: List<Int> = listOf(1, 2)
+                get() = field.filter { it > 0 }
+                private set
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        Ok(())
+    }
+
+    #[test]
+    fn delegate() -> Result<()> {
+        let (field, _) = field::parser(&TEST_CONFIG)
+            .parse("val name: String by lazy { \"a\" }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        Ok(())
+    }
+
+    #[test]
+    fn inferred_type_unsupported() {
+        let result = field::parser(&TEST_CONFIG)
+            .parse("val name = 1")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn ctor_param() -> Result<()> {
+        let param = field::ctor_param(&TEST_CONFIG)
+            .parse("private val name: Int = 1")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let (field, visibility) = param.expect("property");
+        assert_eq!(field.name, "name");
+        assert_eq!(field.ty.value, Type::I32);
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn ctor_param_not_property() -> Result<()> {
+        let param = field::ctor_param(&TEST_CONFIG)
+            .parse("name: Int")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(param.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn vararg_param() -> Result<()> {
+        let field = field::param(&TEST_CONFIG)
+            .parse("vararg names: String")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            field.ty.value,
+            Type::new_array(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use crate::parser::visibility::keyword;
+use apyxl::model::EntityId;
+use apyxl::parser::error::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Import<'a> {
+    /// `import a.b.C` or `import a.b.C as D` where the second element is the local name.
+    Single(EntityId, &'a str),
+    /// `import a.b.*`
+    Star(EntityId),
+}
+
+/// `package a.b.c`
+pub fn package<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("package"))
+        .ignore_then(qualified_name())
+        .then_ignore(just(';').padded().or_not())
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Import<'a>, Error<'a>> {
+    let alias = keyword("as").padded().ignore_then(text::ident());
+    let single = qualified_name()
+        .then(alias.or_not())
+        .map(|(components, alias)| {
+            let local_name = alias.unwrap_or(components[components.len() - 1]);
+            Import::Single(
+                EntityId::new_unqualified_vec(components.into_iter()),
+                local_name,
+            )
+        });
+    let star = qualified_name()
+        .then_ignore(just(".*"))
+        .map(|components| Import::Star(EntityId::new_unqualified_vec(components.into_iter())));
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("import"))
+        .ignore_then(choice((star, single)))
+        .then_ignore(just(';').padded().or_not())
+}
+
+fn qualified_name<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::model::EntityId;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn package() -> Result<()> {
+        let package = import::package()
+            .parse("package a.b.c")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(package, EntityId::new_unqualified("a.b.c"));
+        Ok(())
+    }
+
+    #[test]
+    fn single() -> Result<()> {
+        run_test(
+            "import a.b.C",
+            Import::Single(EntityId::new_unqualified("a.b.C"), "C"),
+        )
+    }
+
+    #[test]
+    fn alias() -> Result<()> {
+        run_test(
+            "import a.b.C as D",
+            Import::Single(EntityId::new_unqualified("a.b.C"), "D"),
+        )
+    }
+
+    #[test]
+    fn star() -> Result<()> {
+        run_test(
+            "import a.b.*",
+            Import::Star(EntityId::new_unqualified("a.b")),
+        )
+    }
+
+    fn run_test(data: &'static str, expected: Import) -> Result<()> {
+        let import = import::parser()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(import, expected);
+        Ok(())
+    }
+}
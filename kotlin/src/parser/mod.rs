@@ -0,0 +1,532 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use apyxl::model::{
+    Api, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::annotations::annotations;
+use crate::parser::import::Import;
+
+mod annotations;
+mod comment;
+mod dto;
+mod en;
+mod expr_block;
+mod field;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod ty_alias;
+mod visibility;
+
+/// Parses a subset of Kotlin declarations, i.e. the types that are typically shared in DTO and
+/// interface definition files.
+///
+/// The `package` of each chunk determines its namespace. The chunk's file path is not used since
+/// Kotlin does not require that the directory structure matches the package.
+#[derive(Default)]
+pub struct KotlinParser {}
+
+impl apyxl::Parser for KotlinParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut parsed_chunks = Vec::new();
+        let mut all_entity_ids = HashSet::<EntityId>::default();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let imports = import::parser().padded().repeated().collect::<Vec<_>>();
+
+            let ((package, imports), children) = comment::multi()
+                .padded()
+                .ignore_then(annotations())
+                .ignore_then(import::package().or_not())
+                .then(imports)
+                .then(namespace::children(config).padded())
+                .then_ignore(end())
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Default::default(),
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            let package = package.unwrap_or_default();
+            collect_referenceable_entity_ids(&api, package.clone(), &mut all_entity_ids);
+
+            parsed_chunks.push((chunk, package, imports, api));
+        }
+
+        // Imports can only be resolved once all chunks are parsed, since star imports don't say
+        // what's in the package they import.
+
+        for (chunk, package, imports, mut api) in parsed_chunks {
+            let chunk_path = chunk.relative_file_path.as_deref();
+
+            debug!("applying imports to chunk {:?}...", chunk_path);
+            let mut local_entity_ids = HashSet::new();
+            collect_referenceable_entity_ids(&api, EntityId::default(), &mut local_entity_ids);
+            let imports = resolve_imports(&all_entity_ids, &local_entity_ids, &imports);
+            apply_imports(&mut api, &imports)?;
+
+            for component in package.component_names() {
+                builder.enter_namespace(component)
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Imports that refer to entities parsed from any chunk.
+struct ResolvedImports<'a> {
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    /// Map of local names to the fully qualified [EntityId] they were imported from.
+    single: HashMap<String, EntityId>,
+    /// Packages imported with `.*`.
+    star: Vec<&'a EntityId>,
+}
+
+/// Imports of entities that were not parsed, e.g. from external libraries, are skipped.
+fn resolve_imports<'a>(
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    imports: &'a [Import],
+) -> ResolvedImports<'a> {
+    let mut resolved = ResolvedImports {
+        all_entity_ids,
+        local_entity_ids,
+        single: HashMap::new(),
+        star: vec![],
+    };
+    for import in imports {
+        match import {
+            Import::Single(id, local_name) => {
+                if all_entity_ids.contains(id) {
+                    resolved.single.insert(local_name.to_string(), id.clone());
+                }
+            }
+            Import::Star(package) => resolved.star.push(package),
+        }
+    }
+    resolved
+}
+
+fn collect_referenceable_entity_ids(ns: &Namespace, id: EntityId, set: &mut HashSet<EntityId>) {
+    for child in &ns.children {
+        let id = id.child_unqualified(child.name());
+        set.insert(id.clone());
+        if let NamespaceChild::Dto(dto) = child
+            && let Some(ns) = &dto.namespace
+        {
+            collect_referenceable_entity_ids(ns, id, set)
+        }
+    }
+}
+
+fn apply_imports(namespace: &mut Namespace, imports: &ResolvedImports) -> Result<()> {
+    let apply_import_to_field =
+        |field: &mut Field| -> Result<()> { apply_imports_to_type(&mut field.ty, imports) };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_imports_to_type(&mut param.ty, imports)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_imports_to_type(return_ty, imports)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+        if let Some(dto_ns) = &mut dto.namespace {
+            apply_imports(dto_ns, imports)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_import_to_rpc(rpc)?;
+    }
+
+    for field in namespace.fields_mut() {
+        apply_import_to_field(field)?;
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_imports_to_type(&mut alias.target_ty, imports)?;
+    }
+
+    // note: enums have no type refs.
+
+    Ok(())
+}
+
+fn apply_imports_to_type(ty: &mut TypeRef, imports: &ResolvedImports) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Set(ty) => apply_imports_to_type(ty, imports)?,
+        Type::FixedArray { ty, .. } => apply_imports_to_type(ty, imports)?,
+        Type::Optional(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(&mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(key, imports)?;
+            apply_imports_to_type(value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(id, imports)?;
+            for arg in args {
+                apply_imports_to_type(arg, imports)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_imports_to_type(param, imports)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_imports_to_type(return_ty, imports)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(id: &mut EntityId, imports: &ResolvedImports) -> Result<()> {
+    let imported = id
+        .component_names()
+        .next()
+        .and_then(|name| imports.single.get(name));
+    if let Some(imported) = imported {
+        let mut rest = id.clone();
+        rest.pop_front();
+        *id = imported.concat(&rest)?;
+    } else if !imports.local_entity_ids.contains(id) {
+        for package in &imports.star {
+            let qualified = package.concat(id)?;
+            if imports.all_entity_ids.contains(&qualified) {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::KotlinParser;
+    use apyxl::model::{Builder, Comment, EntityId, UNDEFINED_NAMESPACE};
+    use apyxl::parser::Config;
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // comment
+        import kotlinx.serialization.Serializable
+        /** rpc comment */
+        fun rpc() {
+            return
+        }
+        val field: String = "blah"
+        typealias alias = Int
+        private typealias private_alias = Int
+        @Serializable
+        data class dto(val a: Int) {
+            fun method() {}
+        }
+        private class private_dto
+        enum class en { A }
+        private enum class private_en { A }
+        // end comment ignored
+        "#,
+        );
+        let mut builder = Builder::default();
+        KotlinParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("dto").is_some(), "dto");
+        assert!(model.api().rpc("rpc").is_some(), "rpc");
+        assert!(model.api().en("en").is_some(), "en");
+        assert!(model.api().field("field").is_some(), "field");
+        assert!(model.api().ty_alias("alias").is_some(), "alias");
+        assert!(model.api().dto("private_dto").is_some(), "private_dto");
+        assert!(model.api().en("private_en").is_some(), "private_en");
+        assert!(
+            model.api().ty_alias("private_alias").is_some(),
+            "private_alias"
+        );
+        assert_eq!(
+            model.api().rpc("rpc").unwrap().attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])],
+            "comment after import attributed to rpc"
+        );
+        assert!(
+            model.api().dto("dto").unwrap().rpc("method").is_some(),
+            "dto method"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        fun rpc()
+        private fun ignored_rpc() {}
+        enum class en { A }
+        internal enum class ignored_en { A }
+        class dto
+        private class ignored_dto
+        private typealias ignored_alias = Int
+        typealias alias = Int
+        "#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        KotlinParser::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("dto").is_some());
+        assert!(model.api().rpc("rpc").is_some());
+        assert!(model.api().en("en").is_some());
+        assert!(model.api().ty_alias("alias").is_some());
+        assert!(model.api().dto("ignored_dto").is_none());
+        assert!(model.api().rpc("ignored_rpc").is_none());
+        assert!(model.api().en("ignored_en").is_none());
+        assert!(model.api().ty_alias("ignored_alias").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn package() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // license
+        @file:JvmName("Dtos")
+        package a.b.c
+
+        class Dto
+        "#,
+        );
+        let mut builder = Builder::default();
+        KotlinParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(
+            model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.b.c.Dto"))
+                .is_some()
+        );
+        Ok(())
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::KotlinParser;
+        use apyxl::model::{Builder, Chunk, EntityId, Model};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn single() -> Result<()> {
+            let a = "package a\nclass Id";
+            let test = r#"
+            package test
+            import a.Id
+            class Entity(val id: Id)
+            "#;
+            run_dto_chunked_test(
+                &[("a.kt", a), ("test.kt", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn alias() -> Result<()> {
+            let a = "package a\nclass Id";
+            let test = r#"
+            package test
+            import a.Id as OtherId
+            class Entity(val id: OtherId)
+            "#;
+            run_dto_chunked_test(
+                &[("a.kt", a), ("test.kt", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn star() -> Result<()> {
+            let a = "package x.a\nclass Id";
+            let test = r#"
+            package y.test
+            import x.a.*
+            class Entity(val id: Id)
+            "#;
+            run_dto_chunked_test(
+                &[("a.kt", a), ("test.kt", test)],
+                "y.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn star_local_precedence() -> Result<()> {
+            let a = "package a\nclass Id";
+            let test = r#"
+            package test
+            import a.*
+            class Id
+            class Entity(val id: Id)
+            "#;
+            run_dto_chunked_test(
+                &[("a.kt", a), ("test.kt", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn same_package() -> Result<()> {
+            let a = "package test\nclass Id";
+            let test = r#"
+            package test
+            class Entity(val id: Id)
+            "#;
+            run_dto_chunked_test(
+                &[("a.kt", a), ("test.kt", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn rpc() -> Result<()> {
+            let a = "package a\nclass Id";
+            let test = r#"
+            package test
+            import a.Id
+            fun rpc(id: Id): List<Id>
+            "#;
+            run_chunked_test(&[("a.kt", a), ("test.kt", test)], |model| {
+                let rpc = model
+                    .api()
+                    .find_rpc(&EntityId::new_unqualified("test.rpc"))
+                    .unwrap();
+                let expected = EntityId::try_from("ns:a.d:Id")?;
+                assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            KotlinParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::{comment, dto, en, expr_block, field, rpc, ty_alias};
+use apyxl::model::NamespaceChild;
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Top-level declarations within a file. Top-level functions and properties are static.
+pub fn children<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Vec<NamespaceChild<'a>>, Error<'a>> {
+    choice((
+        dto::parser(config).map(|(c, v)| Some((NamespaceChild::Dto(c), v))),
+        en::parser().map(|(c, v)| Some((NamespaceChild::Enum(c), v))),
+        ty_alias::parser(config).map(|(c, v)| Some((NamespaceChild::TypeAlias(c), v))),
+        rpc::parser(config).map(|(mut c, v)| {
+            c.is_static = true;
+            Some((NamespaceChild::Rpc(c), v))
+        }),
+        field::parser(config).map(|(mut c, v)| {
+            c.is_static = true;
+            Some((NamespaceChild::Field(c), v))
+        }),
+        dto::skipped().map(|_| None),
+        // Catch comments after all children
+        comment::single().padded().map(|_| None),
+    ))
+    .recover_with(skip_then_retry_until(
+        choice((expr_block::parser().ignored(), any().ignored())),
+        end(),
+    ))
+    .map(|opt| match opt {
+        Some((child, visibility)) => visibility.filter(child, config),
+        None => None,
+    })
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|v| v.into_iter().flatten().collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use apyxl::model::Namespace;
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn children() -> Result<()> {
+        let children = namespace::children(&TEST_CONFIG)
+            .parse(
+                r#"
+            data class Dto(val a: Int)
+            enum class En { A, B }
+            typealias Alias = String
+            fun rpc(): Dto = Dto(1)
+            val field: Int = 1
+            object Singleton {
+                val ignored: Int = 1
+            }
+            // trailing comment
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let namespace = Namespace {
+            children,
+            ..Default::default()
+        };
+        assert!(namespace.dto("Dto").is_some());
+        assert!(namespace.en("En").is_some());
+        assert!(namespace.ty_alias("Alias").is_some());
+        assert!(namespace.rpc("rpc").unwrap().is_static);
+        assert!(namespace.field("field").unwrap().is_static);
+        assert_eq!(namespace.children.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn private_children_filtered() -> Result<()> {
+        let children = namespace::children(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            class Dto
+            private class PrivateDto
+            internal fun private_rpc() {}
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name(), "Dto");
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, expr_block, field, ty, visibility};
+use apyxl::model::{Attributes, Field, Rpc, RpcKind};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Function signature e.g. `fun name(a: Int): ReturnType`. Bodies are skipped. Functions with an
+/// expression body must have an explicit return type since types are not inferred.
+///
+/// All functions are parsed as non-static. Top-level functions are made static by the namespace
+/// parser.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Rpc<'a>, Visibility), Error<'a>> {
+    let return_ty = just(':').padded().ignore_then(ty::return_ty(config));
+    let body = choice((
+        expr_block::parser().padded().map(|_| false),
+        just('=')
+            .padded()
+            .then(expr_block::expr("\n;}"))
+            .map(|_| true),
+    ));
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then_ignore(keyword("fun"))
+        .then(text::ident())
+        .then(params(config))
+        .then(return_ty.or_not())
+        .then(body.or_not())
+        .then_ignore(just(';').padded().or_not())
+        .try_map(
+            |((((((comments, user), visibility), name), params), return_type), is_expr_body),
+             span| {
+                if return_type.is_none() && is_expr_body == Some(true) {
+                    return Err(Rich::custom(
+                        span,
+                        "functions with an expression body must have an explicit return type",
+                    ));
+                }
+                Ok((
+                    Rpc {
+                        name: Cow::Borrowed(name),
+                        type_params: vec![],
+                        params,
+                        return_type: return_type.flatten(),
+                        error_type: None,
+                        attributes: Attributes {
+                            comments,
+                            user,
+                            ..Default::default()
+                        },
+                        is_static: false,
+                        kind: RpcKind::default(),
+                    },
+                    visibility,
+                ))
+            },
+        )
+}
+
+fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    let default = just('=').padded().then(expr_block::expr(",)"));
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(field::param(config))
+        .then_ignore(default.or_not())
+        .map(|((comments, user), mut field)| {
+            field.attributes.comments = comments;
+            field.attributes.user = user;
+            field
+        })
+}
+
+fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    param(config)
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(
+            just('(').padded(),
+            just(')').padded().recover_with(skip_then_retry_until(
+                none_of(")").ignored(),
+                just(')').ignored(),
+            )),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let (rpc, visibility) = rpc::parser(&TEST_CONFIG)
+            .parse("fun rpc_name()")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = rpc::parser(&TEST_CONFIG)
+            .parse("private suspend fun rpc_name(): Unit")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn body_skipped() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            fun rpc_name(): String {
+                if (true) { return "a" }
+                return "${1}"
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expr_body() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("fun rpc_name(): Int = listOf(1, 2).sum()")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::I32, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expr_body_inferred_unsupported() {
+        let result = rpc::parser(&TEST_CONFIG)
+            .parse("fun rpc_name() = 1")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("fun rpc_name(a: Int, @Flag b: Dto? = null): Dto")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.params.len(), 2);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(rpc.params[0].ty.value, Type::I32);
+        assert_eq!(rpc.params[1].name, "b");
+        assert_eq!(
+            rpc.params[1].ty.value,
+            Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            rpc.params[1].attributes.user,
+            vec![attributes::User::new_flag("Flag")]
+        );
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::Api(EntityId::new_unqualified("Dto")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comment() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** rpc comment */
+            fun rpc_name()
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        Ok(())
+    }
+}
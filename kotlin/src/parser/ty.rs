@@ -0,0 +1,296 @@
+use chumsky::prelude::*;
+
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Kotlin types, e.g. `Int`, `List<String?>`, `Map<String, a.b.Dto>`, or `(Int) -> Unit`.
+///
+/// Note that `Set<T>` is parsed as an array since there is no set type in the model.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let primitive = choice((
+            util::keyword_ex("Boolean").map(|_| Type::Bool),
+            util::keyword_ex("Byte").map(|_| Type::I8),
+            util::keyword_ex("Short").map(|_| Type::I16),
+            util::keyword_ex("Int").map(|_| Type::I32),
+            util::keyword_ex("Long").map(|_| Type::I64),
+            util::keyword_ex("UByte").map(|_| Type::U8),
+            util::keyword_ex("UShort").map(|_| Type::U16),
+            util::keyword_ex("UInt").map(|_| Type::U32),
+            util::keyword_ex("ULong").map(|_| Type::U64),
+            util::keyword_ex("Float").map(|_| Type::F32),
+            util::keyword_ex("Double").map(|_| Type::F64),
+            util::keyword_ex("Char").map(|_| Type::U16),
+            util::keyword_ex("String").map(|_| Type::String),
+            util::keyword_ex("ByteArray").map(|_| Type::Bytes),
+        ));
+        let ty = choice((
+            primitive,
+            user_ty(config).map(Type::User),
+            array(nested.clone()),
+            map(nested.clone()),
+            function(nested.clone()),
+            // Note that entity_id should come last because it is greedy.
+            entity_id().map(Type::Api),
+        ))
+        .map(|ty| TypeRef::new(ty, Semantics::Value));
+
+        let primary = choice((
+            ty,
+            nested
+                .clone()
+                .delimited_by(just('(').padded(), just(')').padded()),
+        ));
+
+        primary
+            .then(just('?').padded().or_not())
+            .map(|(ty, is_optional)| match is_optional {
+                Some(_) => optional(ty),
+                None => ty,
+            })
+            .boxed()
+    })
+}
+
+/// Return types, where `Unit` is treated as no return type.
+pub fn return_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Option<TypeRef>, Error<'a>> {
+    choice((
+        util::keyword_ex("Unit")
+            .then_ignore(just('?').not().rewind())
+            .map(|_| None),
+        parser(config).map(Some),
+    ))
+}
+
+/// Wraps `ty` in [Type::Optional] unless it is already optional.
+pub fn optional(ty: TypeRef) -> TypeRef {
+    if let Type::Optional(_) = ty.value {
+        ty
+    } else {
+        TypeRef::new(Type::new_optional(ty), Semantics::Value)
+    }
+}
+
+/// Generic type arguments. Variance modifiers like `out T` are ignored.
+fn generic_arg<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    choice((util::keyword_ex("out"), util::keyword_ex("in")))
+        .then(text::whitespace().at_least(1))
+        .or_not()
+        .ignore_then(ty.padded())
+}
+
+/// `List<T>`, `MutableList<T>`, `Array<T>`, and sets.
+fn array<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((
+        util::keyword_ex("List"),
+        util::keyword_ex("MutableList"),
+        util::keyword_ex("Array"),
+        util::keyword_ex("Set"),
+        util::keyword_ex("MutableSet"),
+    ))
+    .then(just('<').padded())
+    .ignore_then(generic_arg(ty))
+    .then_ignore(just('>'))
+    .map(Type::new_array)
+}
+
+/// `Map<K, V>` and `MutableMap<K, V>`.
+fn map<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((util::keyword_ex("Map"), util::keyword_ex("MutableMap")))
+        .then(just('<').padded())
+        .ignore_then(generic_arg(ty.clone()))
+        .then_ignore(just(','))
+        .then(generic_arg(ty))
+        .then_ignore(just('>'))
+        .map(|(key, value)| Type::new_map(key, value))
+}
+
+/// `(A, b: B) -> R`. Parameter names are optional, and a `Unit` return is treated as no return
+/// type.
+fn function<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let param = text::ident()
+        .then(just(':').padded())
+        .or_not()
+        .ignore_then(ty.clone());
+    let params = param
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), just(')').padded());
+    let return_ty = choice((
+        util::keyword_ex("Unit")
+            .then_ignore(just('?').not().rewind())
+            .map(|_| None),
+        ty.map(Some),
+    ));
+    util::keyword_ex("suspend")
+        .then(text::whitespace().at_least(1))
+        .or_not()
+        .ignore_then(params)
+        .then_ignore(just("->").padded())
+        .then(return_ty)
+        .map(|(params, return_ty)| Type::new_function(params, return_ty))
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "Instant".to_string(),
+                name: "instant".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(boolean, "Boolean", Type::Bool);
+    test!(byte, "Byte", Type::I8);
+    test!(short, "Short", Type::I16);
+    test!(int, "Int", Type::I32);
+    test!(long, "Long", Type::I64);
+    test!(ubyte, "UByte", Type::U8);
+    test!(ushort, "UShort", Type::U16);
+    test!(uint, "UInt", Type::U32);
+    test!(ulong, "ULong", Type::U64);
+    test!(float, "Float", Type::F32);
+    test!(double, "Double", Type::F64);
+    test!(char, "Char", Type::U16);
+    test!(string, "String", Type::String);
+    test!(bytes, "ByteArray", Type::Bytes);
+    test!(user, "Instant", Type::User("instant".to_string()));
+    test!(
+        entity_id,
+        "a.b.Type",
+        Type::Api(EntityId::new_unqualified("a.b.Type"))
+    );
+    test!(
+        keyword_prefixed_entity_id,
+        "Integer",
+        Type::Api(EntityId::new_unqualified("Integer"))
+    );
+    test!(list, "List<String>", Type::new_array(ty(Type::String)));
+    test!(
+        mutable_list,
+        "MutableList< out Int >",
+        Type::new_array(ty(Type::I32))
+    );
+    test!(array, "Array<Int>", Type::new_array(ty(Type::I32)));
+    test!(set, "Set<Int>", Type::new_array(ty(Type::I32)));
+    test!(
+        map,
+        "Map<String, List<Int>>",
+        Type::new_map(ty(Type::String), ty(Type::new_array(ty(Type::I32))))
+    );
+    test!(nullable, "String?", Type::new_optional(ty(Type::String)));
+    test!(
+        nullable_generic_arg,
+        "List<String?>?",
+        Type::new_optional(ty(Type::new_array(ty(Type::new_optional(ty(
+            Type::String
+        ))))))
+    );
+    test!(
+        function,
+        "(Int, b: String) -> Boolean",
+        Type::new_function([ty(Type::I32), ty(Type::String)], Some(ty(Type::Bool)))
+    );
+    test!(function_unit, "() -> Unit", Type::new_function([], None));
+    test!(
+        nullable_function,
+        "(() -> Unit)?",
+        Type::new_optional(ty(Type::new_function([], None)))
+    );
+
+    #[test]
+    fn return_ty_unit() -> Result<()> {
+        let ty = ty::return_ty(&TY_TEST_CONFIG)
+            .parse("Unit")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty, None);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_generic() {
+        let result = ty::parser(&TY_TEST_CONFIG)
+            .parse("Pair<Int, Int>")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    fn run_test(data: &'static str, expected: Type) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty.value, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
@@ -0,0 +1,88 @@
+use chumsky::prelude::*;
+
+use crate::parser::annotations::annotations;
+use crate::parser::visibility::{Visibility, keyword};
+use crate::parser::{comment, ty, visibility};
+use apyxl::model::{Attributes, TypeAlias};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// `typealias Name = Type`.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (TypeAlias<'a>, Visibility), Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(annotations())
+        .then(visibility::parser())
+        .then_ignore(keyword("typealias"))
+        .then(text::ident())
+        .then_ignore(just('=').padded())
+        .then(ty::parser(config))
+        .then_ignore(just(';').padded().or_not())
+        .padded()
+        .map(|((((comments, user), visibility), name), target_ty)| {
+            (
+                TypeAlias {
+                    name,
+                    type_params: vec![],
+                    target_ty,
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                },
+                visibility,
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ty_alias;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        let (alias, visibility) = ty_alias::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** alias comment */
+            typealias Alias = Map<String, Int>
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "Alias");
+        assert_eq!(
+            alias.target_ty.value,
+            Type::new_map(
+                TypeRef::new(Type::String, Semantics::Value),
+                TypeRef::new(Type::I32, Semantics::Value)
+            )
+        );
+        assert_eq!(
+            alias.attributes.comments,
+            vec![Comment::unowned(&["alias comment"])]
+        );
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = ty_alias::parser(&TEST_CONFIG)
+            .parse("private typealias Alias = Int")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+}
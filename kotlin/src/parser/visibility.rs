@@ -0,0 +1,119 @@
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+use chumsky::prelude::*;
+
+/// Kotlin declarations are public by default. `private`, `protected`, and `internal` declarations
+/// are not visible outside of their module so they are considered private.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Modifiers that don't affect the parsed model.
+const IGNORED_MODIFIERS: &[&str] = &[
+    "public",
+    "data",
+    "open",
+    "abstract",
+    "sealed",
+    "final",
+    "override",
+    "suspend",
+    "inline",
+    "value",
+    "const",
+    "lateinit",
+    "external",
+    "operator",
+    "infix",
+    "tailrec",
+    "actual",
+    "expect",
+    "inner",
+    "noinline",
+    "crossinline",
+];
+
+const PRIVATE_MODIFIERS: &[&str] = &["private", "protected", "internal"];
+
+/// Zero or more modifiers, e.g. `private data`.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Visibility, Error<'a>> {
+    text::ident()
+        .try_map(|modifier: &str, span| {
+            if IGNORED_MODIFIERS.contains(&modifier) || PRIVATE_MODIFIERS.contains(&modifier) {
+                Ok(modifier)
+            } else {
+                Err(Rich::custom(
+                    span,
+                    format!("{} is not a modifier", modifier),
+                ))
+            }
+        })
+        .then_ignore(text::whitespace().at_least(1))
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(|modifiers| {
+            if modifiers
+                .iter()
+                .any(|modifier| PRIVATE_MODIFIERS.contains(modifier))
+            {
+                Visibility::Private
+            } else {
+                Visibility::Public
+            }
+        })
+}
+
+/// Keyword that must be followed by whitespace, e.g. `class `.
+pub fn keyword<'a>(keyword: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex(keyword)
+        .then(text::whitespace().at_least(1))
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::visibility;
+    use crate::parser::visibility::Visibility;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn public_by_default() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("data ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        for modifier in ["private ", "protected open ", "internal "] {
+            let visibility = visibility::parser()
+                .parse(modifier)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(visibility, Visibility::Private);
+        }
+        Ok(())
+    }
+}
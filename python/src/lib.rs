@@ -0,0 +1,3 @@
+pub use parser::PythonParser as Parser;
+
+mod parser;
@@ -0,0 +1,146 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::{empty_lines, eol, expr};
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+/// An indented block of `item`s, e.g. the body of a class. This must start at the beginning of the
+/// line following a block header like `class A:`.
+///
+/// The indentation of the first non-empty line is the indentation of the block, and the block
+/// ends at the first line with a different indentation. Like Python itself, lines that only contain
+/// comments don't affect the indentation. Each `item` must consume the rest of its line (including
+/// nested blocks), but not the indentation of the next line.
+pub fn block<'a, O>(
+    item: impl Parser<'a, &'a str, O, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Vec<O>, Error<'a>> + Clone {
+    let comment_lines = text::inline_whitespace()
+        .then(just('#'))
+        .then(none_of('\n').repeated())
+        .then(just('\n'))
+        .then(empty_lines())
+        .repeated();
+    custom(move |input| {
+        input.parse(empty_lines())?;
+        let line_start = input.save();
+        input.parse(comment_lines.clone())?;
+        let block_indent: &str = input.parse(text::inline_whitespace().at_least(1).slice())?;
+        input.rewind(line_start);
+        input.parse(text::inline_whitespace())?;
+        let mut items = vec![input.parse(item.clone())?];
+        loop {
+            let marker = input.save();
+            input.parse(empty_lines())?;
+            let line_start = input.save();
+            input.parse(comment_lines.clone())?;
+            let line_indent = input.parse(text::inline_whitespace().slice())?;
+            if line_indent != block_indent || input.peek().is_none() {
+                input.rewind(marker);
+                break;
+            }
+            input.rewind(line_start);
+            input.parse(text::inline_whitespace())?;
+            items.push(input.parse(item.clone())?);
+        }
+        Ok(items)
+    })
+}
+
+/// Skips a single statement, including the block of a compound statement like `if a:`.
+pub fn statement<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    recursive(|statement| {
+        let keyword = choice((
+            util::keyword_ex("if"),
+            util::keyword_ex("elif"),
+            util::keyword_ex("else"),
+            util::keyword_ex("for"),
+            util::keyword_ex("while"),
+            util::keyword_ex("try"),
+            util::keyword_ex("except"),
+            util::keyword_ex("finally"),
+            util::keyword_ex("with"),
+            util::keyword_ex("def"),
+            util::keyword_ex("class"),
+            util::keyword_ex("async"),
+            util::keyword_ex("match"),
+            util::keyword_ex("case"),
+        ));
+        let compound = keyword
+            .then(expr(":\n").or_not())
+            .then(just(':'))
+            .then(body(statement))
+            .ignored();
+        let simple = expr("\n").then(eol()).ignored();
+        choice((compound, simple)).boxed()
+    })
+}
+
+/// The body following the `:` of a block header. This is either an indented block on the
+/// following lines or a simple statement on the same line, e.g. `class A: pass`.
+pub fn body<'a, O>(
+    item: impl Parser<'a, &'a str, O, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Vec<O>, Error<'a>> + Clone {
+    choice((
+        eol().ignore_then(block(item)),
+        text::inline_whitespace()
+            .then(expr("\n"))
+            .then(eol())
+            .map(|_| vec![]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::prelude::*;
+
+    use crate::parser::block;
+
+    #[test]
+    fn block() {
+        let result = block::block(text::ident().then_ignore(just('\n')))
+            .then_ignore(text::ident())
+            .parse("\n  a\n\n  b\n  c\nd")
+            .into_result();
+        assert_eq!(result, Ok(vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn block_nested() {
+        let result = block::block(block::statement())
+            .then_ignore(text::ident())
+            .parse(
+                r#"
+    if a:
+        b = [
+    1,
+        ]
+    else:
+        pass
+    try: pass
+    except Exception as e:
+        # comment
+        raise e
+next"#,
+            )
+            .into_result();
+        assert_eq!(result.map(|items| items.len()), Ok(4));
+    }
+
+    #[test]
+    fn block_trailing_whitespace() {
+        let result = block::block(text::ident().then_ignore(just('\n')))
+            .then_ignore(text::whitespace())
+            .parse("  a\n  ")
+            .into_result();
+        assert_eq!(result, Ok(vec!["a"]));
+    }
+
+    #[test]
+    fn block_comment_indentation() {
+        let result = block::block(block::statement())
+            .then_ignore(text::ident())
+            .parse("\n# a\n  b\n    # c\n  d\nf")
+            .into_result();
+        assert_eq!(result.map(|items| items.len()), Ok(4));
+    }
+}
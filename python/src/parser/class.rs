@@ -0,0 +1,76 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use crate::parser::decorator::decorators;
+use crate::parser::expr::expr;
+use apyxl::model::{Comment, attributes};
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+/// Everything before the body of a class, e.g. `@dataclass class Name(Base):`.
+pub struct Header<'a> {
+    pub comments: Vec<Comment<'a>>,
+    pub user: Vec<attributes::User<'a>>,
+    pub name: &'a str,
+    /// Base classes and keyword arguments, e.g. `enum.Enum` or `metaclass=ABCMeta`, verbatim.
+    pub bases: Vec<&'a str>,
+}
+
+impl Header<'_> {
+    /// True if any base class has one of the `names`, ignoring the module prefix.
+    pub fn has_base(&self, names: &[&str]) -> bool {
+        self.bases
+            .iter()
+            .filter_map(|base| base.rsplit('.').next())
+            .any(|base| names.contains(&base))
+    }
+}
+
+pub fn header<'a>() -> impl Parser<'a, &'a str, Header<'a>, Error<'a>> + Clone {
+    let bases = expr(",)")
+        .slice()
+        .map(str::trim)
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), text::whitespace().then(just(')')));
+    comment::multi()
+        .then_ignore(text::inline_whitespace())
+        .then(decorators())
+        .then_ignore(util::keyword_ex("class"))
+        .then_ignore(text::inline_whitespace())
+        .then(text::ident())
+        .then(bases.or_not())
+        .then_ignore(text::inline_whitespace().then(just(':')))
+        .map(|(((comments, user), name), bases)| Header {
+            comments,
+            user,
+            name,
+            bases: bases.unwrap_or_default(),
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::class;
+    use apyxl::model::{Comment, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn header() -> Result<()> {
+        let header = class::header()
+            .parse("# comment\n@dataclass\nclass Name(enum.IntEnum, metaclass=Meta):")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(header.name, "Name");
+        assert_eq!(header.comments, vec![Comment::unowned(&["comment"])]);
+        assert_eq!(header.user, vec![attributes::User::new_flag("dataclass")]);
+        assert_eq!(header.bases, vec!["enum.IntEnum", "metaclass=Meta"]);
+        assert!(header.has_base(&["IntEnum"]));
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::{empty_lines, string};
+use apyxl::model::Comment;
+use apyxl::parser::comment;
+use apyxl::parser::error::Error;
+
+/// Zero or more groups of `#` line comments. Unlike other languages, this does not consume the
+/// indentation following the comments.
+pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> + Clone {
+    comment::line_comment(just("#"))
+        .then_ignore(empty_lines())
+        .repeated()
+        .collect::<Vec<_>>()
+        .boxed()
+}
+
+/// A string literal statement, e.g. the first statement in a module, class, or function. Each line
+/// of the docstring is trimmed.
+pub fn docstring<'a>() -> impl Parser<'a, &'a str, Comment<'a>, Error<'a>> + Clone {
+    string().map(|s: &str| {
+        let s = s.trim();
+        if s.is_empty() {
+            Comment::default()
+        } else {
+            Comment::from(s.lines().map(str::trim).collect::<Vec<_>>())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::comment;
+    use apyxl::model::Comment;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn multi() -> Result<()> {
+        let comments = comment::multi()
+            .parse("# a\n  # b\n\n# c\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            comments,
+            vec![Comment::unowned(&["a", "b"]), Comment::unowned(&["c"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn docstring() -> Result<()> {
+        let comment = comment::docstring()
+            .parse(
+                r#""""
+            Summary.
+
+            Details.
+            """"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(comment, Comment::unowned(&["Summary.", "", "Details."]));
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::expr::{eol, expr, string};
+use apyxl::model::attributes;
+use apyxl::parser::error::Error;
+
+/// Zero or more decorator lines, e.g. `@dataclass(frozen=True)`, each followed by the indentation
+/// of the next line. Decorator arguments are parsed as data. String arguments have their quotes
+/// stripped, and other arguments are kept verbatim.
+pub fn decorators<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> + Clone {
+    let name = text::ident().separated_by(just('.')).at_least(1).slice();
+    let value = choice((string(), expr(",)").slice().map(str::trim)));
+    let data = text::ident()
+        .then_ignore(just('=').padded())
+        .or_not()
+        .then(value.padded())
+        .map(|(key, value)| attributes::UserData::new(key, value));
+    let data_list = data
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').then(text::whitespace()), just(')'));
+    just('@')
+        .ignore_then(name)
+        .then(data_list.or_not())
+        .then_ignore(eol())
+        .then_ignore(text::inline_whitespace())
+        .map(|(name, data)| attributes::User {
+            name: Cow::Borrowed(name),
+            data: data.unwrap_or_default(),
+        })
+        .repeated()
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::decorator;
+    use apyxl::model::attributes;
+    use apyxl::model::attributes::UserData;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn decorators() -> Result<()> {
+        let decorators = decorator::decorators()
+            .parse("@dataclasses.dataclass(frozen=True, name=\"a\")\n    @unique\n    ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            decorators,
+            vec![
+                attributes::User::new(
+                    "dataclasses.dataclass",
+                    vec![
+                        UserData::new(Some("frozen"), "True"),
+                        UserData::new(Some("name"), "a"),
+                    ],
+                ),
+                attributes::User::new_flag("unique"),
+            ]
+        );
+        Ok(())
+    }
+}
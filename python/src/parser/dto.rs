@@ -0,0 +1,234 @@
+use chumsky::prelude::*;
+
+use crate::parser::block::{body, statement};
+use crate::parser::expr::eol;
+use crate::parser::visibility::Visibility;
+use crate::parser::{class, comment, en, field, rpc};
+use apyxl::model::{Attributes, Comment, Dto, Field, Namespace, NamespaceChild, Rpc};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Any class that isn't an enum, e.g. a `@dataclass`.
+///
+/// Annotated attributes are [Field]s and methods are [Rpc]s. Static and class methods as well as
+/// nested classes and enums are placed in the [Dto]'s namespace. A docstring at the start of the
+/// body is parsed as a comment. Base classes and other statements are skipped.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Dto<'a>, Visibility), Error<'a>> + Clone {
+    recursive(|nested| {
+        let member = choice((
+            comment::docstring().then_ignore(eol()).map(Member::Doc),
+            en::parser().map(|(en, v)| Member::Child(NamespaceChild::Enum(en), v)),
+            nested.map(|(dto, v)| Member::Child(NamespaceChild::Dto(dto), v)),
+            rpc::parser(config, true).map(|rpc| match rpc {
+                Some((rpc, v)) if rpc.is_static => Member::Child(NamespaceChild::Rpc(rpc), v),
+                Some((rpc, v)) => Member::Rpc(rpc, v),
+                None => Member::Skipped,
+            }),
+            field::parser(config).map(|field| match field {
+                Some((field, v)) => Member::Field(field, v),
+                None => Member::Skipped,
+            }),
+            statement().map(|_| Member::Skipped),
+        ));
+        class::header()
+            .then(body(member))
+            .map(move |(header, members)| {
+                let mut comments = header.comments;
+                let mut fields = vec![];
+                let mut rpcs = vec![];
+                let mut children = vec![];
+                for (i, member) in members.into_iter().enumerate() {
+                    match member {
+                        Member::Doc(comment) if i == 0 => comments.push(comment),
+                        Member::Field(field, v) => fields.extend(v.filter(field, config)),
+                        Member::Rpc(rpc, v) => rpcs.extend(v.filter(rpc, config)),
+                        Member::Child(child, v) => children.extend(v.filter(child, config)),
+                        Member::Doc(_) | Member::Skipped => {}
+                    }
+                }
+                let namespace = if children.is_empty() {
+                    None
+                } else {
+                    Some(Namespace {
+                        children,
+                        ..Default::default()
+                    })
+                };
+                let dto = Dto {
+                    name: header.name,
+                    type_params: vec![],
+                    fields,
+                    rpcs,
+                    extends: vec![],
+                    implements: vec![],
+                    attributes: Attributes {
+                        comments,
+                        user: header.user,
+                        ..Default::default()
+                    },
+                    namespace,
+                };
+                (dto, Visibility::from_name(header.name))
+            })
+            .boxed()
+    })
+}
+
+enum Member<'a> {
+    Doc(Comment<'a>),
+    Field(Field<'a>, Visibility),
+    Rpc(Rpc<'a>, Visibility),
+    Child(NamespaceChild<'a>, Visibility),
+    Skipped,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (dto, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("class Dto: pass")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert!(dto.fields.is_empty());
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("class _Dto:\n    ...\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn dataclass() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"# dto comment
+@dataclass(frozen=True)
+class Dto(Base):
+    """Docstring."""
+
+    # field comment
+    field0: str
+    field1: int | None = None
+    CONSTANT: ClassVar[int] = 1
+    not_field = 1
+    field2: list[Other] = field(
+        default_factory=list,
+    )
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(
+            dto.attributes.comments,
+            vec![
+                Comment::unowned(&["dto comment"]),
+                Comment::unowned(&["Docstring."])
+            ]
+        );
+        assert_eq!(
+            dto.attributes.user,
+            vec![attributes::User::new(
+                "dataclass",
+                vec![attributes::UserData::new(Some("frozen"), "True")]
+            )]
+        );
+        assert_eq!(dto.fields.len(), 3);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "field1");
+        assert_eq!(
+            dto.fields[1].ty.value,
+            Type::new_optional(TypeRef::new(Type::I64, Semantics::Value))
+        );
+        assert_eq!(dto.fields[2].name, "field2");
+        assert_eq!(
+            dto.fields[2].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Other")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn members() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"class Dto:
+    field: int
+
+    def __init__(self, field: int):
+        self.field = field
+
+    def method(self, a: str) -> bool:
+        return a == str(self.field)
+
+    @staticmethod
+    def create() -> "Dto":
+        return Dto(1)
+
+    class Nested:
+        a: int
+
+# unindented comment
+    class En(Enum):
+        A = 1
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "field");
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "method");
+        let namespace = dto.namespace.expect("namespace");
+        assert!(namespace.dto("Nested").is_some());
+        assert!(namespace.en("En").is_some());
+        assert!(namespace.rpc("create").unwrap().is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn private_members_filtered() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"class Dto:
+    field0: int
+    _field1: int
+
+    def _method(self) -> None: ...
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert!(dto.rpcs.is_empty());
+        Ok(())
+    }
+}
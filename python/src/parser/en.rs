@@ -0,0 +1,223 @@
+use chumsky::prelude::*;
+
+use crate::parser::block::{body, statement};
+use crate::parser::expr::{eol, expr};
+use crate::parser::visibility::Visibility;
+use crate::parser::{class, comment};
+use apyxl::model::{Attributes, Comment, Enum, EnumKind, EnumValue, EnumValueNumber};
+use apyxl::parser::error::Error;
+
+const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+
+const ENUM_BASES: &[&str] = &["Enum", "IntEnum", "StrEnum", "Flag", "IntFlag"];
+
+const FLAG_BASES: &[&str] = &["Flag", "IntFlag"];
+
+/// Classes that derive from one of the `enum` module's base classes, e.g. `class Name(IntEnum):`.
+/// Methods and other statements within the class are skipped. `Flag` and `IntFlag` classes are
+/// [EnumKind::Flags] enums.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a>> + Clone {
+    let header = class::header().try_map(|header, span| {
+        if header.has_base(ENUM_BASES) {
+            Ok(header)
+        } else {
+            Err(Rich::custom(span, "class is not an enum"))
+        }
+    });
+    let member = choice((
+        comment::docstring().then_ignore(eol()).map(Member::Doc),
+        en_value().map(Member::Value),
+        statement().map(|_| Member::Skipped),
+    ));
+    header
+        .then(body(member))
+        .map(|(header, members)| {
+            let kind = if header.has_base(FLAG_BASES) {
+                EnumKind::Flags
+            } else {
+                EnumKind::Plain
+            };
+            let mut comments = header.comments;
+            if let Some(Member::Doc(comment)) = members.first() {
+                comments.push(comment.clone());
+            }
+            let values = members
+                .into_iter()
+                .filter_map(|member| match member {
+                    Member::Value(value) => Some(value),
+                    _ => None,
+                })
+                .collect();
+            (
+                Enum {
+                    name: header.name,
+                    values: apply_enum_value_number_defaults(values, kind),
+                    kind,
+                    underlying_type: None,
+                    attributes: Attributes {
+                        comments,
+                        user: header.user,
+                        ..Default::default()
+                    },
+                },
+                Visibility::from_name(header.name),
+            )
+        })
+        .boxed()
+}
+
+/// Values with an integer literal use it as their number, e.g. `A = 1`. Other values such as
+/// `auto()` or strings are numbered after the previous value.
+fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> + Clone {
+    let number = just('-')
+        .or_not()
+        .then(text::int(10))
+        .slice()
+        .then_ignore(eol().rewind())
+        .try_map(|s: &str, span| {
+            str::parse::<EnumValueNumber>(s)
+                .map_err(|_| chumsky::error::Error::<&'a str>::expected_found(None, None, span))
+        });
+    let value = choice((number, expr("\n").map(|_| INVALID_ENUM_NUMBER)));
+    comment::multi()
+        .then_ignore(text::inline_whitespace())
+        .then(text::ident())
+        .then_ignore(just('=').padded_by(text::inline_whitespace()))
+        .then(value)
+        .then_ignore(eol())
+        .map(|((comments, name), number)| EnumValue {
+            name,
+            number,
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+        })
+}
+
+/// Flags without a number use the next power of two after the previous value like `auto()` does.
+fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>, kind: EnumKind) -> Vec<EnumValue> {
+    let mut i = match kind {
+        EnumKind::Plain => 0,
+        EnumKind::Flags => 1,
+    };
+    for value in &mut values {
+        if value.number == INVALID_ENUM_NUMBER {
+            value.number = i;
+        }
+        i = match kind {
+            EnumKind::Plain => value.number + 1,
+            EnumKind::Flags if value.number <= 0 => 1,
+            EnumKind::Flags => 1 << (EnumValueNumber::BITS - value.number.leading_zeros()),
+        };
+    }
+    values
+}
+
+enum Member<'a> {
+    Doc(Comment<'a>),
+    Value(EnumValue<'a>),
+    Skipped,
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EnumKind};
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn values() -> Result<()> {
+        let (en, visibility) = en::parser()
+            .parse(
+                r#"# enum comment
+class En(enum.IntEnum):
+    """Docstring."""
+
+    # value comment
+    ZERO = 0
+    ONE = 1
+    FIVE = 5  # trailing comment
+    SIX = enum.auto()
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            en.attributes.comments,
+            vec![
+                Comment::unowned(&["enum comment"]),
+                Comment::unowned(&["Docstring."])
+            ]
+        );
+        assert_eq!(en.values.len(), 4);
+        assert_eq!(en.values[0].name, "ZERO");
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        assert_eq!(en.values[1].number, 1);
+        assert_eq!(en.values[2].number, 5);
+        assert_eq!(en.values[3].number, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn str_values_with_methods() -> Result<()> {
+        let (en, visibility) = en::parser()
+            .parse(
+                r#"class _En(str, Enum):
+    A = "a"
+    B = "b"
+
+    @property
+    def label(self) -> str:
+        return self.value.upper()
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        assert_eq!(en.values.len(), 2);
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(en.values[1].number, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn flags() -> Result<()> {
+        let (en, _) = en::parser()
+            .parse(
+                r#"class Permissions(enum.Flag):
+    NONE = 0
+    READ = enum.auto()
+    WRITE = enum.auto()
+    EXEC = 8
+    ADMIN = enum.auto()
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.kind, EnumKind::Flags);
+        assert_eq!(
+            en.values.iter().map(|v| v.number).collect::<Vec<_>>(),
+            vec![0, 1, 2, 8, 16]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn not_enum() {
+        let result = en::parser()
+            .parse("class Dto(Base):\n    a = 1\n")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use chumsky::prelude::*;
+
+use apyxl::parser::error::Error;
+
+/// Skips an expression up to (but not including) any of the `terminators` that are not nested
+/// within brackets, string literals, or comments, e.g. a default value or a whole statement.
+/// Newlines within brackets and escaped newlines are part of the expression.
+pub fn expr<'a>(terminators: &'static str) -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    let nested = recursive(|nested| {
+        let inner = choice((
+            nested,
+            string().ignored(),
+            comment(),
+            escaped(),
+            none_of("()[]{}\"'#\\").ignored(),
+        ))
+        .repeated();
+        choice((
+            inner.clone().delimited_by(just('('), just(')')),
+            inner.clone().delimited_by(just('['), just(']')),
+            inner.delimited_by(just('{'), just('}')),
+        ))
+        .ignored()
+    });
+    choice((
+        nested,
+        string().ignored(),
+        comment(),
+        escaped(),
+        none_of("()[]{}\"'#\\").ignored(),
+    ))
+    .and_is(one_of(terminators).not())
+    .repeated()
+    .at_least(1)
+    .ignored()
+}
+
+/// String literal including prefixes like `f` or `r`, e.g. `"a"`, `'b'`, or `"""c"""`. The
+/// output is the contents of the string.
+pub fn string<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
+    let triple = |quote: &'static str| {
+        choice((escaped(), any().and_is(just(quote).not()).ignored()))
+            .repeated()
+            .slice()
+            .delimited_by(just(quote), just(quote))
+    };
+    let single = |quote: char| {
+        choice((escaped(), none_of([quote, '\\', '\n']).ignored()))
+            .repeated()
+            .slice()
+            .delimited_by(just(quote), just(quote))
+    };
+    one_of("rRbBfFuU")
+        .repeated()
+        .at_most(2)
+        .ignore_then(choice((
+            triple("\"\"\""),
+            triple("'''"),
+            single('"'),
+            single('\''),
+        )))
+}
+
+/// Escaped characters, including escaped newlines.
+fn escaped<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    just('\\').then(any()).ignored()
+}
+
+/// `# comment` up to (but not including) the end of the line.
+fn comment<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    just('#').then(none_of('\n').repeated()).ignored()
+}
+
+/// Optional trailing comment followed by the end of the line.
+pub fn eol<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    text::inline_whitespace()
+        .then(comment().or_not())
+        .then(choice((just('\n').ignored(), end())))
+        .ignored()
+}
+
+/// Lines that only contain whitespace.
+pub fn empty_lines<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    text::inline_whitespace()
+        .then(just('\n'))
+        .repeated()
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::prelude::*;
+
+    use crate::parser::expr;
+
+    #[test]
+    fn expr_nested() {
+        let result = expr::expr(",)")
+            .then(just(",").then(any().repeated()))
+            .parse("call(1, [2, 3]) + {'a': \")\"}, rest")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn expr_multiline_brackets() {
+        let result = expr::expr("\n")
+            .then_ignore(just('\n'))
+            .then_ignore(text::ident())
+            .parse("call(\n  1, # comment )\n  2,\n)\nnext")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn expr_comment() {
+        let result = expr::expr("\n")
+            .then_ignore(end())
+            .parse("1 + 2 # comment (")
+            .into_result();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn string() {
+        for (data, expected) in [
+            ("\"a\"", "a"),
+            ("'b'", "b"),
+            ("f\"{c}\"", "{c}"),
+            ("rb'\\d'", "\\d"),
+            ("\"\"\"multi\n\"line\"\n\"\"\"", "multi\n\"line\"\n"),
+        ] {
+            let result = expr::string().parse(data).into_result();
+            assert_eq!(result, Ok(expected));
+        }
+    }
+}
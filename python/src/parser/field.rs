@@ -0,0 +1,141 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::{eol, expr};
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, Field};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Annotated attribute, e.g. `name: Type = value`. Default values are skipped.
+///
+/// Annotations that don't declare an instance attribute, i.e. `ClassVar`, `InitVar`, `KW_ONLY`,
+/// and `Final` without a type, are parsed as `None`.
+///
+/// All attributes are parsed as non-static. Top-level attributes are made static by the namespace
+/// parser.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Option<(Field<'a>, Visibility)>, Error<'a>> + Clone {
+    let default = just('=')
+        .padded_by(text::inline_whitespace())
+        .then(expr("\n"));
+    let ty = choice((
+        skipped_annotation().map(|_| None),
+        ty::parser(config).map(Some),
+    ));
+    comment::multi()
+        .then_ignore(text::inline_whitespace())
+        .then(text::ident())
+        .then_ignore(just(':').padded_by(text::inline_whitespace()))
+        .then(ty)
+        .then_ignore(default.or_not())
+        .then_ignore(eol())
+        .map(|((comments, name), ty)| {
+            ty.map(|ty| {
+                (
+                    Field {
+                        name,
+                        ty,
+                        attributes: Attributes {
+                            comments,
+                            ..Default::default()
+                        },
+                        is_static: false,
+                        value: None,
+                        default: None,
+                    },
+                    Visibility::from_name(name),
+                )
+            })
+        })
+        .boxed()
+}
+
+fn skipped_annotation<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    let prefix = choice((just("typing."), just("dataclasses."))).or_not();
+    let pseudo_ty = choice((
+        util::keyword_ex("ClassVar"),
+        util::keyword_ex("InitVar"),
+        util::keyword_ex("KW_ONLY"),
+    ))
+    .then(expr("]").delimited_by(just('['), just(']')).or_not())
+    .ignored();
+    let bare_final = util::keyword_ex("Final").then(just('[').not()).ignored();
+    prefix.then(choice((pseudo_ty, bare_final))).ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::field;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn field() -> Result<()> {
+        let (field, visibility) = field::parser(&TEST_CONFIG)
+            .parse("# comment\nname: list[int] | None = field(default_factory=list)  # trailing\n")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("field");
+        assert_eq!(field.name, "name");
+        assert_eq!(
+            field.ty.value,
+            Type::new_optional(TypeRef::new(
+                Type::new_array(TypeRef::new(Type::I64, Semantics::Value)),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            field.attributes.comments,
+            vec![Comment::unowned(&["comment"])]
+        );
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = field::parser(&TEST_CONFIG)
+            .parse("_name: str")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("field");
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn skipped() -> Result<()> {
+        for data in [
+            "a: ClassVar[int] = 1",
+            "a: typing.ClassVar[int] = 1",
+            "a: InitVar[str]",
+            "_: dataclasses.KW_ONLY",
+            "a: Final = 1",
+        ] {
+            let field = field::parser(&TEST_CONFIG)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert!(field.is_none(), "{}", data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn final_ty() -> Result<()> {
+        let (field, _) = field::parser(&TEST_CONFIG)
+            .parse("a: Final[int] = 1")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("field");
+        assert_eq!(field.ty.value, Type::I64);
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::eol;
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Import<'a> {
+    /// `import a.b` or `import a.b as c`.
+    Module {
+        path: Vec<&'a str>,
+        alias: Option<&'a str>,
+    },
+    /// `from .a import B` or `from a import B as C`. `level` is the number of leading dots of a
+    /// relative import.
+    From {
+        level: usize,
+        module: Vec<&'a str>,
+        name: &'a str,
+        alias: Option<&'a str>,
+    },
+    /// `from a import *`.
+    Star { level: usize, module: Vec<&'a str> },
+}
+
+/// `import` or `from ... import` statement. A single statement may contain multiple imports.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<Import<'a>>, Error<'a>> + Clone {
+    let ws = || text::inline_whitespace().at_least(1);
+    let path = || {
+        text::ident()
+            .separated_by(just('.'))
+            .at_least(1)
+            .collect::<Vec<_>>()
+    };
+    let alias = || {
+        ws().then(util::keyword_ex("as"))
+            .then(ws())
+            .ignore_then(text::ident())
+    };
+    let module = util::keyword_ex("import").then(ws()).ignore_then(
+        path()
+            .then(alias().or_not())
+            .map(|(path, alias)| Import::Module { path, alias })
+            .separated_by(just(',').padded_by(text::inline_whitespace()))
+            .at_least(1)
+            .collect::<Vec<_>>(),
+    );
+
+    let name = || text::ident().then(alias().or_not());
+    let from_names = choice((
+        just('*').map(|_| None),
+        name()
+            .separated_by(just(',').padded())
+            .at_least(1)
+            .allow_trailing()
+            .collect::<Vec<_>>()
+            .delimited_by(just('(').padded(), text::whitespace().then(just(')')))
+            .map(Some),
+        name()
+            .separated_by(just(',').padded_by(text::inline_whitespace()))
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(Some),
+    ));
+    let from = util::keyword_ex("from")
+        .then(ws())
+        .ignore_then(just('.').repeated().count())
+        .then(path().or_not())
+        .then_ignore(ws())
+        .then_ignore(util::keyword_ex("import"))
+        .then_ignore(text::inline_whitespace())
+        .then(from_names)
+        .map(|((level, module), names)| {
+            let module = module.unwrap_or_default();
+            match names {
+                None => vec![Import::Star { level, module }],
+                Some(names) => names
+                    .into_iter()
+                    .map(|(name, alias)| Import::From {
+                        level,
+                        module: module.clone(),
+                        name,
+                        alias,
+                    })
+                    .collect(),
+            }
+        });
+
+    choice((module, from)).then_ignore(eol()).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn module() -> Result<()> {
+        let imports = import::parser()
+            .parse("import a.b, c as d  # comment\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            imports,
+            vec![
+                Import::Module {
+                    path: vec!["a", "b"],
+                    alias: None
+                },
+                Import::Module {
+                    path: vec!["c"],
+                    alias: Some("d")
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from() -> Result<()> {
+        let imports = import::parser()
+            .parse("from a.b import C, D as E")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            imports,
+            vec![
+                Import::From {
+                    level: 0,
+                    module: vec!["a", "b"],
+                    name: "C",
+                    alias: None,
+                },
+                Import::From {
+                    level: 0,
+                    module: vec!["a", "b"],
+                    name: "D",
+                    alias: Some("E"),
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_relative_parenthesized() -> Result<()> {
+        let imports = import::parser()
+            .parse("from .. import (\n    a,\n    b,\n)")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(imports.len(), 2);
+        assert_eq!(
+            imports[1],
+            Import::From {
+                level: 2,
+                module: vec![],
+                name: "b",
+                alias: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn star() -> Result<()> {
+        let imports = import::parser()
+            .parse("from .a import *")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            imports,
+            vec![Import::Star {
+                level: 1,
+                module: vec!["a"]
+            }]
+        );
+        Ok(())
+    }
+}
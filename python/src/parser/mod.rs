@@ -0,0 +1,658 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use apyxl::model::{
+    Api, Attributes, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef,
+    UNDEFINED_NAMESPACE,
+};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::import::Import;
+
+mod block;
+mod class;
+mod comment;
+mod decorator;
+mod dto;
+mod en;
+mod expr;
+mod field;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod ty_alias;
+mod visibility;
+
+/// Parses the type-annotated subset of Python that is typically used to define DTOs and
+/// interfaces, e.g. `@dataclass`es, `Enum`s, and annotated functions.
+///
+/// Each chunk is a module, and its namespace is determined by the chunk's file path, e.g.
+/// `a/b/c.py` is parsed into namespace `a.b.c` and `a/b/__init__.py` into `a.b`. Chunks without a
+/// file path are parsed into the root namespace.
+#[derive(Default)]
+pub struct PythonParser {}
+
+impl apyxl::Parser for PythonParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut parsed_chunks = Vec::new();
+        let mut all_entity_ids = HashSet::<EntityId>::default();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let module = namespace::module(config)
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children: module.children,
+                attributes: Attributes {
+                    comments: module.comments,
+                    ..Default::default()
+                },
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            let chunk_path = chunk.relative_file_path.as_deref();
+            let module_path = chunk_path.map(module_path).unwrap_or_default();
+            let module_id = EntityId::new_unqualified_vec(module_path.iter());
+            let mut parent_id = module_id.clone();
+            while let Some(parent) = parent_id.parent() {
+                all_entity_ids.insert(parent_id);
+                parent_id = parent;
+            }
+            collect_referenceable_entity_ids(&api, module_id, &mut all_entity_ids);
+
+            let package_path = chunk_path.map(package_path).unwrap_or_default();
+            parsed_chunks.push((chunk, module_path, package_path, module.imports, api));
+        }
+
+        // Imports can only be resolved once all chunks are parsed, since it's otherwise unknown
+        // whether an imported name refers to a parsed entity.
+
+        for (chunk, module_path, package_path, imports, mut api) in parsed_chunks {
+            let chunk_path = chunk.relative_file_path.as_deref();
+
+            debug!("applying imports to chunk {:?}...", chunk_path);
+            let mut local_entity_ids = HashSet::new();
+            collect_referenceable_entity_ids(&api, EntityId::default(), &mut local_entity_ids);
+            let imports =
+                resolve_imports(&all_entity_ids, &local_entity_ids, &package_path, &imports);
+            apply_imports(&mut api, &imports)?;
+
+            for component in &module_path {
+                builder.enter_namespace(component)
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Module path of a file, e.g. `a/b/c.py` is `[a, b, c]` and `a/b/__init__.py` is `[a, b]`.
+fn module_path(path: &Path) -> Vec<String> {
+    let mut components = package_path(path);
+    if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+        && stem != "__init__"
+    {
+        components.push(stem.to_string());
+    }
+    components
+}
+
+/// Path of the package that contains a file, which relative imports are relative to.
+fn package_path(path: &Path) -> Vec<String> {
+    path.parent()
+        .map(|parent| {
+            parent
+                .components()
+                .filter_map(|component| component.as_os_str().to_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Imports that refer to entities parsed from any chunk.
+struct ResolvedImports<'a> {
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    /// Map of local names to the fully qualified [EntityId] they were imported from.
+    single: HashMap<String, EntityId>,
+    /// Modules imported with `*`.
+    star: Vec<EntityId>,
+}
+
+/// Imports of entities that were not parsed, e.g. from external libraries, are skipped.
+fn resolve_imports<'a>(
+    all_entity_ids: &'a HashSet<EntityId>,
+    local_entity_ids: &'a HashSet<EntityId>,
+    package_path: &[String],
+    imports: &[Import],
+) -> ResolvedImports<'a> {
+    let mut resolved = ResolvedImports {
+        all_entity_ids,
+        local_entity_ids,
+        single: HashMap::new(),
+        star: vec![],
+    };
+    let module_id = |level: usize, module: &[&str]| {
+        let base: &[String] = if level == 0 {
+            &[]
+        } else {
+            &package_path[..package_path.len().saturating_sub(level - 1)]
+        };
+        EntityId::new_unqualified_vec(
+            base.iter()
+                .map(String::as_str)
+                .chain(module.iter().copied()),
+        )
+    };
+    for import in imports {
+        match import {
+            Import::Module { path, alias } => {
+                // Unaliased module imports are referenced by their full path already.
+                let id = module_id(0, path);
+                if let Some(alias) = alias
+                    && all_entity_ids.contains(&id)
+                {
+                    resolved.single.insert(alias.to_string(), id);
+                }
+            }
+            Import::From {
+                level,
+                module,
+                name,
+                alias,
+            } => {
+                let id = module_id(*level, module).child_unqualified(name);
+                if all_entity_ids.contains(&id) {
+                    resolved
+                        .single
+                        .insert(alias.unwrap_or(name).to_string(), id);
+                }
+            }
+            Import::Star { level, module } => resolved.star.push(module_id(*level, module)),
+        }
+    }
+    resolved
+}
+
+fn collect_referenceable_entity_ids(ns: &Namespace, id: EntityId, set: &mut HashSet<EntityId>) {
+    for child in &ns.children {
+        let id = id.child_unqualified(child.name());
+        set.insert(id.clone());
+        if let NamespaceChild::Dto(dto) = child
+            && let Some(ns) = &dto.namespace
+        {
+            collect_referenceable_entity_ids(ns, id, set)
+        }
+    }
+}
+
+fn apply_imports(namespace: &mut Namespace, imports: &ResolvedImports) -> Result<()> {
+    let apply_import_to_field =
+        |field: &mut Field| -> Result<()> { apply_imports_to_type(&mut field.ty, imports) };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_imports_to_type(&mut param.ty, imports)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_imports_to_type(return_ty, imports)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+        if let Some(dto_ns) = &mut dto.namespace {
+            apply_imports(dto_ns, imports)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_import_to_rpc(rpc)?;
+    }
+
+    for field in namespace.fields_mut() {
+        apply_import_to_field(field)?;
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_imports_to_type(&mut alias.target_ty, imports)?;
+    }
+
+    // note: enums have no type refs.
+
+    Ok(())
+}
+
+fn apply_imports_to_type(ty: &mut TypeRef, imports: &ResolvedImports) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Set(ty) => apply_imports_to_type(ty, imports)?,
+        Type::FixedArray { ty, .. } => apply_imports_to_type(ty, imports)?,
+        Type::Optional(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(&mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(key, imports)?;
+            apply_imports_to_type(value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(id, imports)?;
+            for arg in args {
+                apply_imports_to_type(arg, imports)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_imports_to_type(param, imports)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_imports_to_type(return_ty, imports)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(id: &mut EntityId, imports: &ResolvedImports) -> Result<()> {
+    let imported = id
+        .component_names()
+        .next()
+        .and_then(|name| imports.single.get(name));
+    if let Some(imported) = imported {
+        let mut rest = id.clone();
+        rest.pop_front();
+        *id = imported.concat(&rest)?;
+    } else if !imports.local_entity_ids.contains(id) {
+        for module in &imports.star {
+            let qualified = module.concat(id)?;
+            if imports.all_entity_ids.contains(&qualified) {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::PythonParser;
+    use apyxl::model::{Builder, Chunk, Comment, EntityId, UNDEFINED_NAMESPACE};
+    use apyxl::parser::Config;
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+# comment
+from dataclasses import dataclass
+
+def rpc() -> None:
+    """rpc comment"""
+    return
+
+field: str = "blah"
+Alias: TypeAlias = int
+_private_alias: TypeAlias = int
+
+@dataclass
+class Dto:
+    a: int
+
+    def method(self) -> None: ...
+
+class _PrivateDto: ...
+
+class En(Enum):
+    A = 1
+
+class _PrivateEn(Enum):
+    A = 1
+# end comment ignored
+"#,
+        );
+        let mut builder = Builder::default();
+        PythonParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("Dto").is_some(), "dto");
+        assert!(model.api().rpc("rpc").is_some(), "rpc");
+        assert!(model.api().en("En").is_some(), "en");
+        assert!(model.api().field("field").is_some(), "field");
+        assert!(model.api().ty_alias("Alias").is_some(), "alias");
+        assert!(model.api().dto("_PrivateDto").is_some(), "private_dto");
+        assert!(model.api().en("_PrivateEn").is_some(), "private_en");
+        assert!(
+            model.api().ty_alias("_private_alias").is_some(),
+            "private_alias"
+        );
+        assert_eq!(
+            model.api().rpc("rpc").unwrap().attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])],
+        );
+        assert!(
+            model.api().dto("Dto").unwrap().rpc("method").is_some(),
+            "dto method"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+def rpc() -> None: ...
+def _ignored_rpc() -> None: ...
+class En(Enum):
+    A = 1
+class _IgnoredEn(Enum):
+    A = 1
+class Dto: ...
+class _IgnoredDto: ...
+_IgnoredAlias: TypeAlias = int
+Alias: TypeAlias = int
+"#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        PythonParser::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("Dto").is_some());
+        assert!(model.api().rpc("rpc").is_some());
+        assert!(model.api().en("En").is_some());
+        assert!(model.api().ty_alias("Alias").is_some());
+        assert!(model.api().dto("_IgnoredDto").is_none());
+        assert!(model.api().rpc("_ignored_rpc").is_none());
+        assert!(model.api().en("_IgnoredEn").is_none());
+        assert!(model.api().ty_alias("_IgnoredAlias").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn module_path() -> Result<()> {
+        let mut input = input::ChunkBuffer::new();
+        input.add_chunk(
+            Chunk::with_relative_file_path("a/b/c.py"),
+            "\"\"\"Module c.\"\"\"\nclass Dto: ...\n",
+        );
+        input.add_chunk(
+            Chunk::with_relative_file_path("a/b/__init__.py"),
+            "class Init: ...\n",
+        );
+        let mut builder = Builder::default();
+        PythonParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(
+            model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.b.c.Dto"))
+                .is_some()
+        );
+        assert!(
+            model
+                .api()
+                .find_dto(&EntityId::new_unqualified("a.b.Init"))
+                .is_some()
+        );
+        assert_eq!(
+            model
+                .api()
+                .find_namespace(&EntityId::new_unqualified("a.b.c"))
+                .unwrap()
+                .attributes
+                .comments,
+            vec![Comment::unowned(&["Module c."])]
+        );
+        Ok(())
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::PythonParser;
+        use apyxl::model::{Builder, Chunk, EntityId, Model};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn from() -> Result<()> {
+            let test = r#"
+from a import Id
+class Entity:
+    id: Id
+"#;
+            run_dto_chunked_test(
+                &[("a.py", "class Id: ..."), ("test.py", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn from_alias() -> Result<()> {
+            let test = r#"
+from a import Id as OtherId
+class Entity:
+    id: OtherId
+"#;
+            run_dto_chunked_test(
+                &[("a.py", "class Id: ..."), ("test.py", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn from_module() -> Result<()> {
+            let test = r#"
+from x import a
+class Entity:
+    id: a.Id
+"#;
+            run_dto_chunked_test(
+                &[("x/a.py", "class Id: ..."), ("test.py", test)],
+                "test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn module_alias() -> Result<()> {
+            let test = r#"
+import x.a as m
+class Entity:
+    id: "m.Id"
+"#;
+            run_dto_chunked_test(
+                &[("x/a.py", "class Id: ..."), ("test.py", test)],
+                "test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn relative() -> Result<()> {
+            let test = r#"
+from ..a import Id
+class Entity:
+    id: Id
+"#;
+            run_dto_chunked_test(
+                &[("x/a.py", "class Id: ..."), ("x/y/test.py", test)],
+                "x.y.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn type_checking() -> Result<()> {
+            let test = r#"
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from .a import Id
+
+class Entity:
+    id: "Id"
+"#;
+            run_dto_chunked_test(
+                &[("x/a.py", "class Id: ..."), ("x/test.py", test)],
+                "x.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn star() -> Result<()> {
+            let test = r#"
+from x.a import *
+class Entity:
+    id: Id
+"#;
+            run_dto_chunked_test(
+                &[("x/a.py", "class Id: ..."), ("y/test.py", test)],
+                "y.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn star_local_precedence() -> Result<()> {
+            let test = r#"
+from a import *
+class Id: ...
+class Entity:
+    id: Id
+"#;
+            run_dto_chunked_test(
+                &[("a.py", "class Id: ..."), ("test.py", test)],
+                "test.Entity",
+                "ns:test.d:Id",
+            )
+        }
+
+        #[test]
+        fn rpc() -> Result<()> {
+            let test = r#"
+from a import Id
+def rpc(id: Id) -> list[Id]: ...
+"#;
+            run_chunked_test(&[("a.py", "class Id: ..."), ("test.py", test)], |model| {
+                let rpc = model
+                    .api()
+                    .find_rpc(&EntityId::new_unqualified("test.rpc"))
+                    .unwrap();
+                let expected = EntityId::try_from("ns:a.d:Id")?;
+                assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            PythonParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+    }
+}
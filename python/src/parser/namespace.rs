@@ -0,0 +1,190 @@
+use chumsky::prelude::*;
+
+use crate::parser::block::{body, statement};
+use crate::parser::expr::{empty_lines, eol};
+use crate::parser::import::Import;
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, dto, en, field, import, rpc, ty_alias};
+use apyxl::model::{Comment, NamespaceChild};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// The contents of a single module (file).
+pub struct Module<'a> {
+    /// The module's docstring.
+    pub comments: Vec<Comment<'a>>,
+    pub children: Vec<NamespaceChild<'a>>,
+    pub imports: Vec<Import<'a>>,
+}
+
+enum Item<'a> {
+    Child(Box<NamespaceChild<'a>>, Visibility),
+    Imports(Vec<Import<'a>>),
+    Skipped,
+}
+
+/// Top-level declarations and imports within a module. Top-level functions and attributes are
+/// static. Imports within an `if TYPE_CHECKING:` block are also parsed, and all other statements
+/// are skipped.
+pub fn module<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Module<'a>, Error<'a>> {
+    let docstring = empty_lines()
+        .ignore_then(comment::multi())
+        .ignore_then(comment::docstring())
+        .then_ignore(eol());
+    let item = choice((
+        en::parser().map(|(c, v)| Item::Child(Box::new(NamespaceChild::Enum(c)), v)),
+        dto::parser(config).map(|(c, v)| Item::Child(Box::new(NamespaceChild::Dto(c)), v)),
+        rpc::parser(config, false).map(|rpc| match rpc {
+            Some((c, v)) => Item::Child(Box::new(NamespaceChild::Rpc(c)), v),
+            None => Item::Skipped,
+        }),
+        type_checking().map(Item::Imports),
+        import::parser().map(Item::Imports),
+        ty_alias::parser(config)
+            .map(|(c, v)| Item::Child(Box::new(NamespaceChild::TypeAlias(c)), v)),
+        field::parser(config).map(|field| match field {
+            Some((mut c, v)) => {
+                c.is_static = true;
+                Item::Child(Box::new(NamespaceChild::Field(c)), v)
+            }
+            None => Item::Skipped,
+        }),
+        statement().map(|_| Item::Skipped),
+    ));
+    docstring
+        .or_not()
+        .then(
+            empty_lines()
+                .ignore_then(item)
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .then_ignore(text::whitespace())
+        .then_ignore(end())
+        .map(move |(docstring, items)| {
+            let mut module = Module {
+                comments: docstring
+                    .filter(|comment| *comment != Comment::default())
+                    .into_iter()
+                    .collect(),
+                children: vec![],
+                imports: vec![],
+            };
+            for item in items {
+                match item {
+                    Item::Child(child, v) => module.children.extend(v.filter(*child, config)),
+                    Item::Imports(imports) => module.imports.extend(imports),
+                    Item::Skipped => {}
+                }
+            }
+            module
+        })
+}
+
+/// `if TYPE_CHECKING:` blocks, which typically contain imports only needed for type hints.
+fn type_checking<'a>() -> impl Parser<'a, &'a str, Vec<Import<'a>>, Error<'a>> {
+    let item = choice((import::parser(), statement().map(|_| vec![])));
+    util::keyword_ex("if")
+        .then(text::inline_whitespace())
+        .then(just("typing.").or_not())
+        .then(util::keyword_ex("TYPE_CHECKING"))
+        .then(text::inline_whitespace())
+        .then(just(':'))
+        .ignore_then(body(item))
+        .map(|imports| imports.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use apyxl::model::{Comment, Namespace};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn module() -> Result<()> {
+        let module = namespace::module(&TEST_CONFIG)
+            .parse(
+                r#"#!/usr/bin/env python3
+"""Module docstring."""
+from __future__ import annotations
+
+import typing
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from a import B
+
+logger = logging.getLogger(__name__)
+
+
+@dataclass
+class Dto:
+    a: int
+
+
+class En(IntEnum):
+    A = 1
+
+
+Alias = list[Dto]
+
+
+def rpc(dto: Dto) -> None:
+    pass
+
+
+def inferred(dto):
+    pass
+
+
+field: int = 1
+
+if __name__ == "__main__":
+    main()
+# trailing comment
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            module.comments,
+            vec![Comment::unowned(&["Module docstring."])]
+        );
+        assert_eq!(module.imports.len(), 4);
+        let namespace = Namespace {
+            children: module.children,
+            ..Default::default()
+        };
+        assert!(namespace.dto("Dto").is_some());
+        assert!(namespace.en("En").is_some());
+        assert!(namespace.ty_alias("Alias").is_some());
+        assert!(namespace.rpc("rpc").unwrap().is_static);
+        assert!(namespace.field("field").unwrap().is_static);
+        assert_eq!(namespace.children.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn private_children_filtered() -> Result<()> {
+        let module = namespace::module(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"class Dto:
+    pass
+
+class _PrivateDto:
+    pass
+
+def _private_rpc() -> None: ...
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(module.children.len(), 1);
+        assert_eq!(module.children[0].name(), "Dto");
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::block::{body, statement};
+use crate::parser::decorator::decorators;
+use crate::parser::expr::{eol, expr};
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, Field, Rpc, RpcKind, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Function definition, e.g. `def name(a: int, b: str = "b") -> ReturnType:`. Bodies and default
+/// values are skipped. A docstring at the start of the body is parsed as a comment.
+///
+/// Types are not inferred, so functions with an unannotated parameter or without a return
+/// annotation are parsed as `None`. A `-> None` return annotation means no return type.
+///
+/// Within a class (`is_method`), the first parameter (`self` or `cls`) is dropped unless the
+/// function is a `@staticmethod`. Functions outside of classes as well as static and class methods
+/// are parsed as static.
+pub fn parser<'a>(
+    config: &'a Config,
+    is_method: bool,
+) -> impl Parser<'a, &'a str, Option<(Rpc<'a>, Visibility)>, Error<'a>> + Clone {
+    let return_ty = just("->").padded().ignore_then(ty::return_ty(config));
+    let body_item = choice((
+        comment::docstring().then_ignore(eol()).map(Some),
+        statement().map(|_| None),
+    ));
+    comment::multi()
+        .then_ignore(text::inline_whitespace())
+        .then(decorators())
+        .then_ignore(
+            util::keyword_ex("async")
+                .then(text::inline_whitespace())
+                .or_not(),
+        )
+        .then_ignore(util::keyword_ex("def"))
+        .then_ignore(text::inline_whitespace())
+        .then(text::ident())
+        .then(params(config))
+        .then(return_ty.or_not())
+        .then_ignore(text::inline_whitespace().then(just(':')))
+        .then(body(body_item))
+        .map(
+            move |(((((comments, user), name), params), return_type), body)| {
+                let is_decorated = |decorator: &str| user.iter().any(|user| user.name == decorator);
+                let is_static_method = is_decorated("staticmethod");
+                let is_static = !is_method || is_static_method || is_decorated("classmethod");
+                let skip = if is_method && !is_static_method { 1 } else { 0 };
+                let params = params
+                    .into_iter()
+                    .skip(skip)
+                    .filter_map(|param| match param {
+                        Param::Field(field) => Some(Some(*field)),
+                        Param::Unannotated => Some(None),
+                        Param::Marker => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let mut comments = comments;
+                if let Some(Some(comment)) = body.into_iter().next() {
+                    comments.push(comment);
+                }
+                Some((
+                    Rpc {
+                        name: Cow::Borrowed(name),
+                        type_params: vec![],
+                        params,
+                        return_type: return_type?,
+                        error_type: None,
+                        attributes: Attributes {
+                            comments,
+                            user,
+                            ..Default::default()
+                        },
+                        is_static,
+                        kind: RpcKind::default(),
+                    },
+                    Visibility::from_name(name),
+                ))
+            },
+        )
+        .boxed()
+}
+
+enum Param<'a> {
+    Field(Box<Field<'a>>),
+    Unannotated,
+    /// `/` or `*`, which separate positional-only and keyword-only parameters.
+    Marker,
+}
+
+/// Parameters in parentheses. `*args: T` is parsed as an array of `T` and `**kwargs: T` as a map
+/// of strings to `T`.
+fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Param<'a>>, Error<'a>> {
+    let annotation = just(':').padded().ignore_then(ty::parser(config));
+    let default = just('=').padded().then(expr(",)"));
+    let param = |prefix: &'static str, wrap: fn(TypeRef) -> Type| {
+        just(prefix)
+            .ignore_then(text::ident())
+            .then(annotation.clone().or_not())
+            .then_ignore(default.clone().or_not())
+            .map(move |(name, ty)| match ty {
+                Some(ty) => Param::Field(Box::new(Field {
+                    name,
+                    ty: TypeRef::new(wrap(ty), Semantics::Value),
+                    attributes: Default::default(),
+                    is_static: false,
+                    value: None,
+                    default: None,
+                })),
+                None => Param::Unannotated,
+            })
+    };
+    choice((
+        param("**", |ty| {
+            Type::new_map(TypeRef::new(Type::String, Semantics::Value), ty)
+        }),
+        param("*", Type::new_array),
+        one_of("/*").map(|_| Param::Marker),
+        param("", |ty| ty.value),
+    ))
+    .separated_by(just(',').padded())
+    .allow_trailing()
+    .collect::<Vec<_>>()
+    .delimited_by(just('(').padded(), text::whitespace().then(just(')')))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let (rpc, visibility) = rpc::parser(&TEST_CONFIG, false)
+            .parse("def rpc_name() -> None:\n    pass\n")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("rpc");
+        assert_eq!(rpc.name, "rpc_name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert!(rpc.is_static);
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn private() -> Result<()> {
+        let (_, visibility) = rpc::parser(&TEST_CONFIG, false)
+            .parse("async def _rpc_name() -> None: ...")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("rpc");
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn body_skipped() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, false)
+            .parse(
+                r#"# comment
+@decorator
+def rpc_name() -> str:
+    """Docstring."""
+    if True:
+        return "a"
+    return f"{1}"
+"#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("rpc");
+        assert_eq!(rpc.name, "rpc_name");
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::String, Semantics::Value))
+        );
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![
+                Comment::unowned(&["comment"]),
+                Comment::unowned(&["Docstring."])
+            ]
+        );
+        assert_eq!(
+            rpc.attributes.user,
+            vec![attributes::User::new_flag("decorator")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, false)
+            .parse(
+                r#"def rpc_name(
+    a: int,
+    /,
+    b: Dto | None = None,
+    *args: str,
+    c: dict[str, int] = {"a": 1},
+    **kwargs: int,
+) -> Dto: ..."#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("rpc");
+        assert_eq!(rpc.params.len(), 5);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(rpc.params[0].ty.value, Type::I64);
+        assert_eq!(rpc.params[1].name, "b");
+        assert_eq!(
+            rpc.params[1].ty.value,
+            Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(rpc.params[2].name, "args");
+        assert_eq!(
+            rpc.params[2].ty.value,
+            Type::new_array(TypeRef::new(Type::String, Semantics::Value))
+        );
+        assert_eq!(rpc.params[3].name, "c");
+        assert_eq!(rpc.params[4].name, "kwargs");
+        assert_eq!(
+            rpc.params[4].ty.value,
+            Type::new_map(
+                TypeRef::new(Type::String, Semantics::Value),
+                TypeRef::new(Type::I64, Semantics::Value)
+            )
+        );
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::Api(EntityId::new_unqualified("Dto")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn method() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG, true)
+            .parse("def method(self, a: int) -> bool: ...")
+            .into_result()
+            .map_err(wrap_test_err)?
+            .expect("rpc");
+        assert_eq!(rpc.params.len(), 1);
+        assert_eq!(rpc.params[0].name, "a");
+        assert!(!rpc.is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn static_method() -> Result<()> {
+        for (data, expected_params) in [
+            ("@staticmethod\ndef method(a: int) -> bool: ...", 1),
+            ("@classmethod\ndef method(cls, a: int) -> bool: ...", 1),
+        ] {
+            let (rpc, _) = rpc::parser(&TEST_CONFIG, true)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?
+                .expect("rpc");
+            assert_eq!(rpc.params.len(), expected_params, "{}", data);
+            assert!(rpc.is_static, "{}", data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unannotated() -> Result<()> {
+        for data in [
+            "def rpc_name(a) -> int: ...",
+            "def rpc_name(a: int): ...",
+            "def method(self, *args) -> int: ...",
+        ] {
+            let rpc = rpc::parser(&TEST_CONFIG, false)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert!(rpc.is_none(), "{}", data);
+        }
+        Ok(())
+    }
+}
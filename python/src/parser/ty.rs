@@ -0,0 +1,351 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::expr;
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Type hints, e.g. `int`, `list[str] | None`, `Optional[Dict[str, a.Dto]]`, or `"Dto"`.
+///
+/// Names from `typing` may be prefixed with `typing.`. Note that sets are parsed as arrays since
+/// there is no set type in the model.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let primitive = choice((
+            util::keyword_ex("int").map(|_| Type::I64),
+            util::keyword_ex("float").map(|_| Type::F64),
+            util::keyword_ex("bool").map(|_| Type::Bool),
+            util::keyword_ex("str").map(|_| Type::String),
+            util::keyword_ex("bytes").map(|_| Type::Bytes),
+            util::keyword_ex("bytearray").map(|_| Type::Bytes),
+        ));
+        let ty = choice((
+            generic(nested.clone()),
+            function(nested.clone()),
+            primitive,
+            user_ty(config).map(Type::User),
+            // Note that entity_id should come last because it is greedy.
+            entity_id().map(Type::Api),
+        ))
+        .map(|ty| TypeRef::new(ty, Semantics::Value));
+
+        let forward_ref = choice((
+            nested.clone().delimited_by(just('"'), just('"')),
+            nested.clone().delimited_by(just('\''), just('\'')),
+        ));
+
+        let member = choice((
+            util::keyword_ex("None").map(|_| None),
+            choice((forward_ref, ty)).map(Some),
+        ));
+
+        member
+            .separated_by(just('|').padded())
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .try_map(union)
+            .boxed()
+    })
+}
+
+/// Return types, where `None` is treated as no return type.
+pub fn return_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Option<TypeRef>, Error<'a>> {
+    choice((
+        parser(config).map(Some),
+        util::keyword_ex("None").map(|_| None),
+    ))
+}
+
+/// Wraps `ty` in [Type::Optional] unless it is already optional.
+pub fn optional(ty: TypeRef) -> TypeRef {
+    if let Type::Optional(_) = ty.value {
+        ty
+    } else {
+        TypeRef::new(Type::new_optional(ty), Semantics::Value)
+    }
+}
+
+/// Unions are only supported with `None`, which makes the type optional.
+fn union<'a>(members: Vec<Option<TypeRef>>, span: SimpleSpan) -> Result<TypeRef, Rich<'a, char>> {
+    let is_optional = members.iter().any(Option::is_none);
+    let mut tys = members.into_iter().flatten().collect::<Vec<_>>();
+    match (tys.pop(), tys.is_empty()) {
+        (Some(ty), true) if is_optional => Ok(optional(ty)),
+        (Some(ty), true) => Ok(ty),
+        (None, _) => Err(Rich::custom(span, "type cannot only be `None`")),
+        (Some(_), false) => Err(Rich::custom(
+            span,
+            "union types are only supported with `None`",
+        )),
+    }
+}
+
+/// Generic types from builtins and `typing`, e.g. `list[T]` or `typing.Dict[K, V]`. `Final[T]`
+/// and `Annotated[T, ...]` are parsed as `T`.
+fn generic<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let args = |n: usize| {
+        ty.clone()
+            .separated_by(just(',').padded())
+            .exactly(n)
+            .allow_trailing()
+            .collect::<Vec<_>>()
+            .delimited_by(just('[').padded(), text::whitespace().then(just(']')))
+    };
+    let array = typing_name(&[
+        "list",
+        "List",
+        "Sequence",
+        "MutableSequence",
+        "set",
+        "Set",
+        "frozenset",
+        "FrozenSet",
+        "AbstractSet",
+        "MutableSet",
+    ])
+    .ignore_then(args(1))
+    .map(|mut args| Type::new_array(args.remove(0)));
+    let map = typing_name(&["dict", "Dict", "Mapping", "MutableMapping"])
+        .ignore_then(args(2))
+        .map(|mut args| {
+            let value = args.remove(1);
+            Type::new_map(args.remove(0), value)
+        });
+    let optional = typing_name(&["Optional"])
+        .ignore_then(args(1))
+        .map(|mut args| self::optional(args.remove(0)).value);
+    let union_member = choice((util::keyword_ex("None").map(|_| None), ty.clone().map(Some)));
+    let union = typing_name(&["Union"])
+        .ignore_then(
+            union_member
+                .separated_by(just(',').padded())
+                .at_least(1)
+                .allow_trailing()
+                .collect::<Vec<_>>()
+                .delimited_by(just('[').padded(), text::whitespace().then(just(']'))),
+        )
+        .try_map(union)
+        .map(|ty| ty.value);
+    let final_ty = typing_name(&["Final"])
+        .ignore_then(args(1))
+        .map(|mut args| args.remove(0).value);
+    let annotated = typing_name(&["Annotated"])
+        .ignore_then(
+            ty.clone()
+                .then_ignore(just(',').padded().then(expr("]")).or_not())
+                .delimited_by(just('[').padded(), text::whitespace().then(just(']'))),
+        )
+        .map(|ty| ty.value);
+    choice((array, map, optional, union, final_ty, annotated))
+}
+
+/// `Callable[[A, B], R]`. A `None` return is treated as no return type.
+fn function<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let params = ty
+        .clone()
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('[').padded(), text::whitespace().then(just(']')));
+    let return_ty = choice((ty.map(Some), util::keyword_ex("None").map(|_| None)));
+    typing_name(&["Callable"])
+        .ignore_then(
+            params
+                .then_ignore(just(',').padded())
+                .then(return_ty.padded())
+                .delimited_by(just('[').padded(), text::whitespace().then(just(']'))),
+        )
+        .map(|(params, return_ty)| Type::new_function(params, return_ty))
+}
+
+/// One of `names`, optionally prefixed with `typing.`.
+fn typing_name<'a>(
+    names: &'static [&'static str],
+) -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    just("typing.")
+        .or_not()
+        .ignore_then(text::ident())
+        .try_map(move |name: &str, span| {
+            if names.contains(&name) {
+                Ok(())
+            } else {
+                Err(Rich::custom(span, format!("unexpected type {}", name)))
+            }
+        })
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "datetime".to_string(),
+                name: "datetime".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(int, "int", Type::I64);
+    test!(float, "float", Type::F64);
+    test!(bool, "bool", Type::Bool);
+    test!(str, "str", Type::String);
+    test!(bytes, "bytes", Type::Bytes);
+    test!(bytearray, "bytearray", Type::Bytes);
+    test!(user, "datetime", Type::User("datetime".to_string()));
+    test!(
+        entity_id,
+        "a.b.Type",
+        Type::Api(EntityId::new_unqualified("a.b.Type"))
+    );
+    test!(
+        keyword_prefixed_entity_id,
+        "integer",
+        Type::Api(EntityId::new_unqualified("integer"))
+    );
+    test!(
+        forward_ref,
+        "'Type'",
+        Type::Api(EntityId::new_unqualified("Type"))
+    );
+    test!(list, "list[str]", Type::new_array(ty(Type::String)));
+    test!(
+        typing_list,
+        "typing.List[ int ]",
+        Type::new_array(ty(Type::I64))
+    );
+    test!(set, "set[int]", Type::new_array(ty(Type::I64)));
+    test!(
+        dict,
+        "dict[str, list[int]]",
+        Type::new_map(ty(Type::String), ty(Type::new_array(ty(Type::I64))))
+    );
+    test!(
+        optional,
+        "Optional[str]",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        union_none,
+        "str | None",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        union_none_first,
+        r#"None | "Dto""#,
+        Type::new_optional(ty(Type::Api(EntityId::new_unqualified("Dto"))))
+    );
+    test!(
+        typing_union_none,
+        "Union[str, None]",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        optional_union_none,
+        "Optional[str | None]",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(final_ty, "Final[int]", Type::I64);
+    test!(annotated, "Annotated[int, Field(gt=0)]", Type::I64);
+    test!(
+        callable,
+        "Callable[[int, str], bool]",
+        Type::new_function([ty(Type::I64), ty(Type::String)], Some(ty(Type::Bool)))
+    );
+    test!(
+        callable_none,
+        "Callable[[], None]",
+        Type::new_function([], None)
+    );
+
+    #[test]
+    fn union_unsupported() {
+        let result = ty::parser(&TY_TEST_CONFIG).parse("str | int").into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn only_none() {
+        let result = ty::parser(&TY_TEST_CONFIG).parse("None").into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn return_ty_none() -> Result<()> {
+        let ty = ty::return_ty(&TY_TEST_CONFIG)
+            .parse("None")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty, None);
+        Ok(())
+    }
+
+    fn run_test(data: &'static str, expected: Type) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty.value, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
@@ -0,0 +1,131 @@
+use chumsky::prelude::*;
+
+use crate::parser::expr::eol;
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, Type, TypeAlias};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Type aliases in any of the forms:
+/// - `Name: TypeAlias = Type`
+/// - `type Name = Type`
+/// - `Name = Type`
+///
+/// A plain assignment is only an alias if the whole value is a type. Since `Name = Other` could
+/// also be a variable, values that are a single name (other than a builtin like `int`) or a string
+/// are not aliases.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (TypeAlias<'a>, Visibility), Error<'a>> + Clone {
+    let assign = || just('=').padded_by(text::inline_whitespace());
+    let explicit = text::ident()
+        .then_ignore(just(':').padded_by(text::inline_whitespace()))
+        .then_ignore(just("typing.").or_not())
+        .then_ignore(util::keyword_ex("TypeAlias"))
+        .then_ignore(assign())
+        .then(ty::parser(config));
+    let statement = util::keyword_ex("type")
+        .then(text::inline_whitespace())
+        .ignore_then(text::ident())
+        .then_ignore(assign())
+        .then(ty::parser(config));
+    let implicit = text::ident()
+        .then_ignore(assign())
+        .then_ignore(one_of("\"'").not())
+        .then(ty::parser(config))
+        .try_map(|(name, target_ty), span| match target_ty.value {
+            Type::Api(_) => Err(Rich::custom(span, "ambiguous type alias")),
+            _ => Ok((name, target_ty)),
+        });
+    comment::multi()
+        .then_ignore(text::inline_whitespace())
+        .then(choice((explicit, statement, implicit)))
+        .then_ignore(eol())
+        .map(|(comments, (name, target_ty))| {
+            (
+                TypeAlias {
+                    name,
+                    type_params: vec![],
+                    target_ty,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                },
+                Visibility::from_name(name),
+            )
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ty_alias;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        for data in [
+            "# alias comment\nAlias: TypeAlias = dict[str, int]",
+            "# alias comment\nAlias: typing.TypeAlias = dict[str, int]",
+            "# alias comment\ntype Alias = dict[str, int]",
+            "# alias comment\nAlias = dict[str, int]",
+        ] {
+            let (alias, visibility) = ty_alias::parser(&TEST_CONFIG)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(alias.name, "Alias");
+            assert_eq!(
+                alias.target_ty.value,
+                Type::new_map(
+                    TypeRef::new(Type::String, Semantics::Value),
+                    TypeRef::new(Type::I64, Semantics::Value)
+                ),
+                "{}",
+                data
+            );
+            assert_eq!(
+                alias.attributes.comments,
+                vec![Comment::unowned(&["alias comment"])]
+            );
+            assert_eq!(visibility, Visibility::Public);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_api() -> Result<()> {
+        let (alias, _) = ty_alias::parser(&TEST_CONFIG)
+            .parse("_Alias: TypeAlias = Dto")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            alias.target_ty.value,
+            Type::Api(EntityId::new_unqualified("Dto"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn not_alias() {
+        for data in [
+            "a = Dto",
+            "a = True",
+            "a = \"int\"",
+            "a = 1",
+            "a = call(int)",
+            "a = int + 1",
+        ] {
+            let result = ty_alias::parser(&TEST_CONFIG).parse(data).into_result();
+            assert!(result.is_err(), "{}", data);
+        }
+    }
+}
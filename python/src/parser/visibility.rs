@@ -0,0 +1,43 @@
+use apyxl::parser::Config;
+
+/// Python has no visibility keywords. By convention, names that start with an underscore are
+/// private.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn from_name(name: &str) -> Self {
+        if name.starts_with('_') {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        }
+    }
+
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::visibility::Visibility;
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Visibility::from_name("name"), Visibility::Public);
+        assert_eq!(Visibility::from_name("_name"), Visibility::Private);
+        assert_eq!(Visibility::from_name("__init__"), Visibility::Private);
+    }
+}
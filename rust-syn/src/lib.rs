@@ -0,0 +1,3 @@
+pub use parser::SynRustParser as Parser;
+
+mod parser;
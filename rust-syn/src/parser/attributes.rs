@@ -0,0 +1,298 @@
+use std::borrow::Cow;
+
+use quote::ToTokens;
+use syn::{AttrStyle, Attribute, Expr, Lit, Meta};
+
+use apyxl::model::{Attributes, Comment, attributes};
+use apyxl::parser::{Config, docs, util};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    /// Restricted visibility such as `pub(crate)` is not part of the public API so it is treated
+    /// as private.
+    pub fn of(vis: &syn::Visibility) -> Self {
+        match vis {
+            syn::Visibility::Public(_) => Visibility::Public,
+            syn::Visibility::Restricted(_) | syn::Visibility::Inherited => Visibility::Private,
+        }
+    }
+
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || (*self == Visibility::Private && config.enable_parse_private)
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Outer doc attributes become comments, or docs if they have rustdoc sections. `#[deprecated]`
+/// becomes the deprecation, and all other outer attributes become user attributes.
+pub fn parse<'a>(attrs: &[Attribute]) -> Attributes<'a> {
+    let mut comments = comments(attrs);
+    let mut user = user(attrs);
+    Attributes {
+        docs: docs::take_rustdoc(&mut comments),
+        comments,
+        deprecation: util::take_deprecation(&mut user, &["deprecated"]),
+        user,
+        ..Default::default()
+    }
+}
+
+/// Outer doc attributes, i.e. `///` and `/** */`. Consecutive `///` lines are grouped into a single
+/// comment, while a block doc is a single trimmed element like
+/// [apyxl::parser::comment::block_comment].
+///
+/// Note that regular `//` comments are not part of the syntax tree so they are not kept.
+pub fn comments<'a>(attrs: &[Attribute]) -> Vec<Comment<'a>> {
+    doc_comments(attrs.iter().filter(|attr| is_outer(attr)))
+}
+
+/// Inner doc attributes, i.e. `//!` and `/*! */`. See [comments].
+pub fn inner_comments<'a>(attrs: &[Attribute]) -> Vec<Comment<'a>> {
+    doc_comments(attrs.iter().filter(|attr| !is_outer(attr)))
+}
+
+fn doc_comments<'a, 'b>(attrs: impl Iterator<Item = &'b Attribute>) -> Vec<Comment<'a>> {
+    let mut comments = Vec::new();
+    let mut lines = Vec::<Cow<'a, str>>::new();
+    for attr in attrs {
+        let Some(doc) = doc(attr) else {
+            continue;
+        };
+        if doc.contains('\n') {
+            if !lines.is_empty() {
+                comments.push(Comment::from(std::mem::take(&mut lines)));
+            }
+            comments.push(Comment::from(vec![Cow::Owned(doc.trim().to_string())]));
+        } else {
+            let line = doc.strip_prefix(' ').unwrap_or(&doc);
+            lines.push(Cow::Owned(line.trim_end().to_string()));
+        }
+    }
+    if !lines.is_empty() {
+        comments.push(Comment::from(lines));
+    }
+    comments
+}
+
+fn is_outer(attr: &Attribute) -> bool {
+    matches!(attr.style, AttrStyle::Outer)
+}
+
+fn doc(attr: &Attribute) -> Option<String> {
+    match &attr.meta {
+        Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Non-doc outer attributes, e.g. `#[flag]`, `#[list(a, b = c)]`, or `#[name = "value"]`. List
+/// items that are not paths or `key = value` pairs are kept as a single value with the list's
+/// tokens.
+pub fn user<'a>(attrs: &[Attribute]) -> Vec<attributes::User<'a>> {
+    attrs
+        .iter()
+        .filter(|attr| is_outer(attr) && doc(attr).is_none())
+        .map(|attr| {
+            let name = Cow::Owned(path(attr.path()));
+            let data = match &attr.meta {
+                Meta::Path(_) => vec![],
+                Meta::NameValue(nv) => vec![attributes::UserData {
+                    key: None,
+                    value: Cow::Owned(value(&nv.value)),
+                }],
+                Meta::List(list) => list_data(attr).unwrap_or_else(|_| {
+                    vec![attributes::UserData {
+                        key: None,
+                        value: Cow::Owned(list.tokens.to_string()),
+                    }]
+                }),
+            };
+            attributes::User { name, data }
+        })
+        .collect()
+}
+
+fn list_data<'a>(attr: &Attribute) -> syn::Result<Vec<attributes::UserData<'a>>> {
+    let mut data = Vec::new();
+    attr.parse_nested_meta(|meta| {
+        let name = path(&meta.path);
+        if meta.input.peek(syn::Token![=]) {
+            let value = meta.value()?.parse::<Expr>()?;
+            data.push(attributes::UserData {
+                key: Some(Cow::Owned(name)),
+                value: Cow::Owned(self::value(&value)),
+            });
+        } else if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+            data.push(attributes::UserData {
+                key: None,
+                value: Cow::Owned(name),
+            });
+        } else {
+            return Err(meta.error("unsupported attribute data"));
+        }
+        Ok(())
+    })?;
+    Ok(data)
+}
+
+fn path(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// String literals are unquoted, all other values are kept as tokens.
+fn value(expr: &Expr) -> String {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => s.value(),
+            lit => lit.to_token_stream().to_string(),
+        },
+        expr => expr.to_token_stream().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use syn::ItemStruct;
+
+    use crate::parser::attributes;
+    use apyxl::model::attributes::{User, UserData};
+    use apyxl::model::{Comment, Deprecation};
+
+    #[test]
+    fn line_docs() {
+        let item = syn::parse_str::<ItemStruct>(
+            r#"
+            /// line 1
+            ///     line 2
+            ///
+            struct Dto;
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            attributes::comments(&item.attrs),
+            vec![Comment::unowned(&["line 1", "    line 2", ""])]
+        );
+    }
+
+    #[test]
+    fn block_docs() {
+        let item = syn::parse_str::<ItemStruct>(
+            r#"
+            /// line
+            /** block
+            */
+            /// after
+            struct Dto;
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            attributes::comments(&item.attrs),
+            vec![
+                Comment::unowned(&["line"]),
+                Comment::unowned(&["block"]),
+                Comment::unowned(&["after"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn deprecation() {
+        let item = syn::parse_str::<ItemStruct>(
+            r#"
+            #[deprecated(since = "1.2", note = "use other")]
+            #[flag]
+            struct Dto;
+            "#,
+        )
+        .unwrap();
+        let attributes = attributes::parse(&item.attrs);
+        assert_eq!(
+            attributes.deprecation,
+            Some(Deprecation {
+                since: Some(Cow::Borrowed("1.2")),
+                note: Some(Cow::Borrowed("use other")),
+                replacement: None,
+            })
+        );
+        assert_eq!(attributes.user, vec![User::new_flag("flag")]);
+    }
+
+    #[test]
+    fn docs() {
+        let item = syn::parse_str::<ItemStruct>(
+            r#"
+            /// Summary.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// let dto = Dto;
+            /// ```
+            struct Dto;
+            "#,
+        )
+        .unwrap();
+        let attributes = attributes::parse(&item.attrs);
+        assert!(attributes.comments.is_empty());
+        let docs = attributes.docs.unwrap();
+        assert_eq!(docs.summary, Comment::unowned(&["Summary."]));
+        assert_eq!(docs.examples, vec![Comment::unowned(&["let dto = Dto;"])]);
+    }
+
+    #[test]
+    fn user() {
+        let item = syn::parse_str::<ItemStruct>(
+            r#"
+            /// docs are not user attributes
+            #[flag]
+            #[list(a, b = c, d = "e")]
+            #[serde::name = "value"]
+            #[nested(a(b))]
+            struct Dto;
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            attributes::user(&item.attrs),
+            vec![
+                User::new_flag("flag"),
+                User::new(
+                    "list",
+                    vec![
+                        UserData::new(None, "a"),
+                        UserData::new(Some("b"), "c"),
+                        UserData::new(Some("d"), "e"),
+                    ]
+                ),
+                User::new("serde::name", vec![UserData::new(None, "value")]),
+                User::new("nested", vec![UserData::new(None, "a (b)")]),
+            ]
+        );
+    }
+}
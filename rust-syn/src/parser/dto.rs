@@ -0,0 +1,201 @@
+use anyhow::{Result, bail};
+use syn::{Fields, ItemStruct};
+
+use crate::parser::attributes::Visibility;
+use crate::parser::{Source, attributes, ty, value};
+use apyxl::model::{Dto, Field};
+use apyxl::parser::Config;
+
+/// Structs with named fields, e.g. `struct Dto<T> { a: T }`, or unit structs, e.g. `struct Dto;`.
+/// Tuple structs are not supported.
+pub fn parse<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    item: &ItemStruct,
+) -> Result<(Dto<'a>, Visibility)> {
+    let type_params = ty::type_params(source, &item.generics)?;
+    let fields = match &item.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().expect("named field");
+                let ty = ty::parse(config, &field.ty)?;
+                let mut attributes = attributes::parse(&field.attrs);
+                let default = value::take_default(source, &field.attrs, &mut attributes, &ty)?;
+                let parsed = Field {
+                    name: source.ident(ident),
+                    ty,
+                    attributes,
+                    is_static: false,
+                    value: None,
+                    default,
+                };
+                Ok(Visibility::of(&field.vis).filter(parsed, config))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>>>()?,
+        Fields::Unit => vec![],
+        Fields::Unnamed(_) => bail!("tuple structs are not supported"),
+    };
+    let mut dto = Dto {
+        name: source.ident(&item.ident),
+        type_params: type_params.clone(),
+        fields,
+        rpcs: vec![],
+        extends: vec![],
+        implements: vec![],
+        attributes: attributes::parse(&item.attrs),
+        namespace: None,
+    };
+    dto.apply_type_params(&type_params);
+    Ok((dto, Visibility::of(&item.vis)))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use syn::ItemStruct;
+
+    use crate::parser::attributes::Visibility;
+    use crate::parser::{Source, dto};
+    use apyxl::model::attributes::User;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, Value};
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn fields() -> Result<()> {
+        let data = r#"
+            /// dto comment
+            pub struct Dto<'a> {
+                /// field comment
+                pub a: &'a str,
+                b: Option<u32>,
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, visibility) = dto::parse(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(dto.fields.len(), 2);
+        assert_eq!(dto.fields[0].name, "a");
+        assert_eq!(
+            dto.fields[0].ty,
+            TypeRef::new(Type::StringView, Semantics::Slice)
+        );
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "b");
+        Ok(())
+    }
+
+    #[test]
+    fn field_default() -> Result<()> {
+        let data = r#"
+            pub struct Dto {
+                #[default("name")]
+                #[flag]
+                pub a: String,
+                #[default(a + b)]
+                pub b: u32,
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, _) = dto::parse(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(dto.fields[0].default, Some(Value::String("name".into())));
+        assert_eq!(dto.fields[0].attributes.user, vec![User::new_flag("flag")]);
+        assert_eq!(dto.fields[1].default, None);
+        assert_eq!(dto.fields[1].attributes.user.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn function_field() -> Result<()> {
+        let data = "pub struct Dto { pub callback: fn(u32) -> bool }";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, _) = dto::parse(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(
+            dto.fields[0].ty,
+            TypeRef::new_function(
+                [TypeRef::new(Type::U32, Semantics::Value)],
+                Some(TypeRef::new(Type::Bool, Semantics::Value)),
+                Semantics::Value
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn private_fields_filtered() -> Result<()> {
+        let data = "pub struct Dto { pub a: u32, b: u32, pub(crate) c: u32 }";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, _) = dto::parse(&TEST_PUB_ONLY_CONFIG, &source, &item)?;
+        assert_eq!(dto.fields.len(), 1);
+        assert_eq!(dto.fields[0].name, "a");
+        Ok(())
+    }
+
+    #[test]
+    fn unit() -> Result<()> {
+        let data = "struct Dto;";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, visibility) = dto::parse(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(visibility, Visibility::Private);
+        assert!(dto.fields.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn generic() -> Result<()> {
+        let data = "struct Page<'a, T> { items: &'a [T], next: Option<Page<'a, T>> }";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemStruct>(data)?;
+        let (dto, _) = dto::parse(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(dto.type_params, vec!["T"]);
+        let t = TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value);
+        assert_eq!(
+            dto.fields[0].ty,
+            TypeRef::new(Type::new_array(t.clone()), Semantics::Slice)
+        );
+        assert_eq!(
+            dto.fields[1].ty,
+            TypeRef::new(
+                Type::new_optional(TypeRef::new(
+                    Type::new_generic(EntityId::new_unqualified("Page"), vec![t]),
+                    Semantics::Value
+                )),
+                Semantics::Value
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported() {
+        for data in [
+            "struct Dto(u32);",
+            "struct Dto<const N: usize> {}",
+            "struct Dto { a: *const u8 }",
+        ] {
+            let source = Source::new(data, "");
+            let item = syn::parse_str::<ItemStruct>(data).unwrap();
+            assert!(
+                dto::parse(&TEST_CONFIG, &source, &item).is_err(),
+                "{}",
+                data
+            );
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow, bail};
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    Attribute, BinOp, Expr, Fields, Ident, ItemEnum, ItemMacro, Lit, Member, Token, UnOp, braced,
+};
+
+use crate::parser::attributes::Visibility;
+use crate::parser::{Source, attributes, ty};
+use apyxl::model::{
+    Enum, EnumKind, EnumValue, EnumValueNumber, Field, Union, Variant, VariantPayload,
+};
+use apyxl::parser::Config;
+
+/// Enums with unit variants and optional integer discriminants, e.g. `enum En { A, B = 5 }`.
+/// Variants without a discriminant are numbered from the previous variant like Rust does.
+///
+/// Enums with a `#[flags]` attribute are [EnumKind::Flags] enums.
+pub fn parse<'a>(source: &Source<'a>, item: &ItemEnum) -> Result<(Enum<'a>, Visibility)> {
+    if !ty::type_params(source, &item.generics)?.is_empty() {
+        bail!("generic enums are not supported");
+    }
+    let mut next: EnumValueNumber = 0;
+    let values = item
+        .variants
+        .iter()
+        .map(|variant| {
+            if !variant.fields.is_empty() {
+                bail!("enum variants with data are not supported");
+            }
+            let number = match &variant.discriminant {
+                Some((_, expr)) => number(expr)?,
+                None => next,
+            };
+            next = number + 1;
+            Ok(EnumValue {
+                name: source.ident(&variant.ident),
+                number,
+                attributes: attributes::parse(&variant.attrs),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut attributes = attributes::parse(&item.attrs);
+    let len = attributes.user.len();
+    attributes
+        .user
+        .retain(|attr| attr.name != "flags" || !attr.data.is_empty());
+    let kind = if attributes.user.len() != len {
+        EnumKind::Flags
+    } else {
+        EnumKind::Plain
+    };
+    Ok((
+        Enum {
+            name: source.ident(&item.ident),
+            values,
+            kind,
+            underlying_type: None,
+            attributes,
+        },
+        Visibility::of(&item.vis),
+    ))
+}
+
+/// `true` if the macro is a `bitflags!` invocation, in which case it is parsed with [parse_flags].
+pub fn is_flags(item: &ItemMacro) -> bool {
+    item.mac
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "bitflags")
+}
+
+/// `bitflags!` invocations with a single struct, e.g.
+/// `bitflags! { pub struct Flags: u32 { const A = 1; const B = 1 << 1; } }`.
+///
+/// Flag values can be integer literals, shifts, and `|` combinations of previous flags, e.g.
+/// `Self::A.bits() | Self::B.bits()`.
+pub fn parse_flags<'a>(source: &Source<'a>, item: &ItemMacro) -> Result<(Enum<'a>, Visibility)> {
+    let flags = item.mac.parse_body::<Flags>()?;
+    let mut evaluated = HashMap::new();
+    let values = flags
+        .values
+        .iter()
+        .map(|(attrs, ident, expr)| {
+            let name = source.ident(ident);
+            let number = flags_number(expr, &evaluated)?;
+            evaluated.insert(name, number);
+            Ok(EnumValue {
+                name,
+                number,
+                attributes: attributes::parse(attrs),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let attrs = item
+        .attrs
+        .iter()
+        .chain(&flags.attrs)
+        .cloned()
+        .collect::<Vec<_>>();
+    Ok((
+        Enum {
+            name: source.ident(&flags.ident),
+            values,
+            kind: EnumKind::Flags,
+            underlying_type: ty::integer(&flags.ty),
+            attributes: attributes::parse(&attrs),
+        },
+        Visibility::of(&flags.vis),
+    ))
+}
+
+/// The body of a `bitflags!` invocation.
+struct Flags {
+    attrs: Vec<Attribute>,
+    vis: syn::Visibility,
+    ident: Ident,
+    ty: syn::Type,
+    values: Vec<(Vec<Attribute>, Ident, Expr)>,
+}
+
+impl Parse for Flags {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let content;
+        braced!(content in input);
+        let mut values = Vec::new();
+        while !content.is_empty() {
+            let attrs = content.call(Attribute::parse_outer)?;
+            content.parse::<Token![const]>()?;
+            let ident = content.parse()?;
+            content.parse::<Token![=]>()?;
+            let expr = content.parse()?;
+            content.parse::<Token![;]>()?;
+            values.push((attrs, ident, expr));
+        }
+        if !input.is_empty() {
+            return Err(input.error("only a single struct per bitflags! is supported"));
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            ty,
+            values,
+        })
+    }
+}
+
+/// Integer literals, shifts, and `|` combinations of `evaluated` flags, e.g. `1 << 2` or
+/// `Self::A.bits() | B`.
+fn flags_number(
+    expr: &Expr,
+    evaluated: &HashMap<&str, EnumValueNumber>,
+) -> Result<EnumValueNumber> {
+    match expr {
+        Expr::Lit(_) => number(expr),
+        Expr::Binary(binary) => {
+            let lhs = flags_number(&binary.left, evaluated)?;
+            let rhs = flags_number(&binary.right, evaluated)?;
+            match binary.op {
+                BinOp::BitOr(_) => Ok(lhs | rhs),
+                BinOp::Shl(_) => u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| lhs.checked_shl(rhs))
+                    .ok_or_else(|| anyhow!("invalid shift")),
+                _ => bail!("flag values must be literals, shifts, or combinations of flags"),
+            }
+        }
+        Expr::Path(path) => {
+            let ident = &path
+                .path
+                .segments
+                .last()
+                .ok_or_else(|| anyhow!("empty path"))?
+                .ident;
+            evaluated
+                .get(ident.to_string().as_str())
+                .copied()
+                .ok_or_else(|| anyhow!("unknown flag {}", ident))
+        }
+        Expr::MethodCall(call) if call.method == "bits" => flags_number(&call.receiver, evaluated),
+        Expr::Field(field) if matches!(&field.member, Member::Named(ident) if ident == "bits") => {
+            flags_number(&field.base, evaluated)
+        }
+        Expr::Paren(paren) => flags_number(&paren.expr, evaluated),
+        Expr::Group(group) => flags_number(&group.expr, evaluated),
+        _ => bail!("flag values must be literals, shifts, or combinations of flags"),
+    }
+}
+
+/// `true` if any variant carries data, in which case the enum is parsed with [parse_union].
+pub fn has_data(item: &ItemEnum) -> bool {
+    item.variants
+        .iter()
+        .any(|variant| !variant.fields.is_empty())
+}
+
+/// Enums with data, e.g. `enum Event { Login { user: Id }, Move(i32, i32), Logout }`.
+pub fn parse_union<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    item: &ItemEnum,
+) -> Result<(Union<'a>, Visibility)> {
+    if !ty::type_params(source, &item.generics)?.is_empty() {
+        bail!("generic enums are not supported");
+    }
+    let variants = item
+        .variants
+        .iter()
+        .map(|variant| {
+            let payload = match &variant.fields {
+                Fields::Unit => None,
+                Fields::Unnamed(fields) => Some(VariantPayload::Tuple(
+                    fields
+                        .unnamed
+                        .iter()
+                        .map(|field| ty::parse(config, &field.ty))
+                        .collect::<Result<Vec<_>>>()?,
+                )),
+                Fields::Named(fields) => Some(VariantPayload::Struct(
+                    fields
+                        .named
+                        .iter()
+                        .map(|field| {
+                            let ident = field.ident.as_ref().expect("named field");
+                            Ok(Field {
+                                name: source.ident(ident),
+                                ty: ty::parse(config, &field.ty)?,
+                                attributes: attributes::parse(&field.attrs),
+                                is_static: false,
+                                value: None,
+                                default: None,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                )),
+            };
+            Ok(Variant {
+                name: source.ident(&variant.ident),
+                payload,
+                attributes: attributes::parse(&variant.attrs),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((
+        Union {
+            name: source.ident(&item.ident),
+            variants,
+            attributes: attributes::parse(&item.attrs),
+        },
+        Visibility::of(&item.vis),
+    ))
+}
+
+/// Integer literal discriminants, optionally negated, e.g. `5`, `0x10`, or `-1`.
+fn number(expr: &Expr) -> Result<EnumValueNumber> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Ok(int.base10_parse::<EnumValueNumber>()?),
+            _ => bail!("enum discriminants must be integers"),
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => Ok(-number(&unary.expr)?),
+        Expr::Paren(paren) => number(&paren.expr),
+        Expr::Group(group) => number(&group.expr),
+        _ => bail!("enum discriminants must be integer literals"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use syn::{ItemEnum, ItemMacro};
+
+    use crate::parser::attributes::Visibility;
+
+    use crate::parser::{Source, en};
+    use apyxl::model::{Comment, EnumKind, Semantics, Type, TypeRef, VariantPayload, attributes};
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn values() -> Result<()> {
+        let data = r#"
+            /// en comment
+            pub enum En {
+                /// value comment
+                A,
+                B = 5,
+                C,
+                D = -0x2,
+                E,
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemEnum>(data)?;
+        let (en, _) = en::parse(&source, &item)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["en comment"])]
+        );
+        assert_eq!(
+            en.values
+                .iter()
+                .map(|value| (value.name, value.number))
+                .collect::<Vec<_>>(),
+            vec![("A", 0), ("B", 5), ("C", 6), ("D", -2), ("E", -1)]
+        );
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn flags_attribute() -> Result<()> {
+        let data = r#"
+            #[flags]
+            #[flag1]
+            pub enum En {
+                A = 1,
+                B = 2,
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemEnum>(data)?;
+        let (en, _) = en::parse(&source, &item)?;
+        assert_eq!(en.kind, EnumKind::Flags);
+        assert_eq!(
+            en.attributes.user,
+            vec![attributes::User::new_flag("flag1")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bitflags() -> Result<()> {
+        let data = r#"
+            /// outer comment
+            bitflags::bitflags! {
+                /// flags comment
+                #[derive(Debug)]
+                pub struct Permissions: u32 {
+                    /// value comment
+                    const READ = 0b0001;
+                    const WRITE = 1 << 1;
+                    const EXEC = 0x4;
+                    const READ_WRITE = Self::READ.bits() | Self::WRITE.bits();
+                    const ALL = READ_WRITE | Self::EXEC.bits;
+                }
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemMacro>(data)?;
+        assert!(en::is_flags(&item));
+        let (en, visibility) = en::parse_flags(&source, &item)?;
+        assert_eq!(en.name, "Permissions");
+        assert_eq!(en.kind, EnumKind::Flags);
+        assert_eq!(en.underlying_type, Some(Type::U32));
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["outer comment", "flags comment"])]
+        );
+        assert_eq!(en.attributes.user.len(), 1);
+        assert_eq!(
+            en.values
+                .iter()
+                .map(|value| (value.name, value.number))
+                .collect::<Vec<_>>(),
+            vec![
+                ("READ", 1),
+                ("WRITE", 2),
+                ("EXEC", 4),
+                ("READ_WRITE", 3),
+                ("ALL", 7)
+            ]
+        );
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bitflags_unknown_flag() -> Result<()> {
+        let data = "bitflags! { struct Flags: u8 { const A = B; } }";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemMacro>(data)?;
+        assert!(en::parse_flags(&source, &item).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn union() -> Result<()> {
+        let data = r#"
+            /// union comment
+            pub enum Event {
+                Logout,
+                Move(i32, i32),
+                /// variant comment
+                Login { user: String },
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemEnum>(data)?;
+        assert!(en::has_data(&item));
+        let (union, _) = en::parse_union(&TEST_CONFIG, &source, &item)?;
+        assert_eq!(union.name, "Event");
+        assert_eq!(
+            union.attributes.comments,
+            vec![Comment::unowned(&["union comment"])]
+        );
+        assert_eq!(union.variants.len(), 3);
+        assert_eq!(union.variants[0].payload, None);
+        assert_eq!(
+            union.variants[1].payload,
+            Some(VariantPayload::Tuple(vec![
+                TypeRef::new(Type::I32, Semantics::Value),
+                TypeRef::new(Type::I32, Semantics::Value),
+            ]))
+        );
+        assert_eq!(
+            union.variants[2].attributes.comments,
+            vec![Comment::unowned(&["variant comment"])]
+        );
+        match &union.variants[2].payload {
+            Some(VariantPayload::Struct(fields)) => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name, "user");
+                assert_eq!(fields[0].ty.value, Type::String);
+            }
+            _ => panic!("expected struct payload"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported() {
+        for data in [
+            "enum En { A(u32) }",
+            "enum En { A { a: u32 } }",
+            "enum En { A = 1 << 2 }",
+            "enum En<T> { A }",
+        ] {
+            let source = Source::new(data, "");
+            let item = syn::parse_str::<ItemEnum>(data).unwrap();
+            assert!(en::parse(&source, &item).is_err(), "{}", data);
+        }
+    }
+}
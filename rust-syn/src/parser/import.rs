@@ -0,0 +1,154 @@
+use anyhow::{Result, bail};
+use syn::{ItemUse, UseTree};
+
+use apyxl::model::EntityId;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Import {
+    /// Single import e.g. `crate::a;` or `crate::a::Dto;`. Each import in a group like
+    /// `crate::a::{Dto1, b::Dto2}` is a separate single import.
+    Single(EntityId),
+
+    /// Renamed import e.g. `crate::a::Dto as Other;`
+    Renamed(EntityId, String),
+
+    /// Blanket module import e.g. `crate::a::*;`
+    Blanket(EntityId),
+}
+
+/// Flattens the `use` tree of `item` into its imports. Paths starting with `crate` are relative to
+/// the root namespace, and paths starting with `self` or `super` are made relative to the root
+/// namespace using `module`, the namespace the `use` item is in.
+pub fn parse(item: &ItemUse, module: &EntityId) -> Result<Vec<Import>> {
+    let mut imports = Vec::new();
+    flatten(&item.tree, vec![], &mut imports);
+    imports
+        .into_iter()
+        .map(|(path, kind)| {
+            let id = absolute(path, module)?;
+            Ok(match kind {
+                Kind::Single => Import::Single(id),
+                Kind::Renamed(alias) => Import::Renamed(id, alias),
+                Kind::Blanket => Import::Blanket(id),
+            })
+        })
+        .collect()
+}
+
+enum Kind {
+    Single,
+    Renamed(String),
+    Blanket,
+}
+
+fn flatten(tree: &UseTree, mut path: Vec<String>, imports: &mut Vec<(Vec<String>, Kind)>) {
+    match tree {
+        UseTree::Path(tree) => {
+            path.push(tree.ident.to_string());
+            flatten(&tree.tree, path, imports);
+        }
+        UseTree::Name(tree) => {
+            // `a::{self}` imports `a` itself.
+            if tree.ident != "self" {
+                path.push(tree.ident.to_string());
+            }
+            imports.push((path, Kind::Single));
+        }
+        UseTree::Rename(tree) => {
+            if tree.ident != "self" {
+                path.push(tree.ident.to_string());
+            }
+            imports.push((path, Kind::Renamed(tree.rename.to_string())));
+        }
+        UseTree::Glob(_) => imports.push((path, Kind::Blanket)),
+        UseTree::Group(group) => {
+            for tree in &group.items {
+                flatten(tree, path.clone(), imports);
+            }
+        }
+    }
+}
+
+fn absolute(path: Vec<String>, module: &EntityId) -> Result<EntityId> {
+    let mut path = path.into_iter().peekable();
+    let mut module = module.clone();
+    match path.peek().map(String::as_str) {
+        Some("crate") => {
+            path.next();
+            return Ok(EntityId::new_unqualified_vec(path));
+        }
+        Some("self") => {
+            path.next();
+        }
+        Some("super") => {
+            while path.next_if(|component| component == "super").is_some() {
+                module = match module.parent() {
+                    Some(parent) => parent,
+                    None => bail!("`super` used outside of a module"),
+                };
+            }
+        }
+        _ => return Ok(EntityId::new_unqualified_vec(path)),
+    }
+    Ok(EntityId::new_unqualified_vec(
+        module.component_names().map(str::to_string).chain(path),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use syn::ItemUse;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::model::EntityId;
+
+    #[test]
+    fn single() -> Result<()> {
+        run_test(
+            "use crate::a::Dto;",
+            "",
+            vec![Import::Single(EntityId::new_unqualified("a.Dto"))],
+        )
+    }
+
+    #[test]
+    fn group() -> Result<()> {
+        run_test(
+            "pub use a::{self, b::Dto1, Dto2 as Other, c::*};",
+            "",
+            vec![
+                Import::Single(EntityId::new_unqualified("a")),
+                Import::Single(EntityId::new_unqualified("a.b.Dto1")),
+                Import::Renamed(EntityId::new_unqualified("a.Dto2"), "Other".to_string()),
+                Import::Blanket(EntityId::new_unqualified("a.c")),
+            ],
+        )
+    }
+
+    #[test]
+    fn relative() -> Result<()> {
+        run_test(
+            "use {self::b::Dto1, super::super::Dto2};",
+            "x.y.z",
+            vec![
+                Import::Single(EntityId::new_unqualified("x.y.z.b.Dto1")),
+                Import::Single(EntityId::new_unqualified("x.Dto2")),
+            ],
+        )
+    }
+
+    #[test]
+    fn super_outside_module() {
+        let item = syn::parse_str::<ItemUse>("use super::Dto;").unwrap();
+        assert!(import::parse(&item, &EntityId::default()).is_err());
+    }
+
+    fn run_test(data: &str, module: &str, expected: Vec<Import>) -> Result<()> {
+        let item = syn::parse_str::<ItemUse>(data)?;
+        let module = EntityId::new_unqualified_vec(module.split('.').filter(|s| !s.is_empty()));
+        assert_eq!(import::parse(&item, &module)?, expected);
+        Ok(())
+    }
+}
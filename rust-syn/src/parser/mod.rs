@@ -0,0 +1,602 @@
+use anyhow::{Result, anyhow};
+use log::debug;
+use proc_macro2::Span;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use apyxl::model::{
+    Api, Attributes, EntityId, Namespace, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+    VariantPayload,
+};
+use apyxl::parser::Config;
+use apyxl::{Input, model, rust_util};
+
+use crate::parser::import::Import;
+
+mod attributes;
+mod dto;
+mod en;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod value;
+
+/// Parses Rust using the full Rust grammar via `syn`, producing the same model as
+/// [apyxl::parser::Rust].
+///
+/// Since the whole file is parsed, any valid Rust is accepted. Items that can't be represented in
+/// the model, e.g. traits, macros, tuple structs, or generics, are skipped with a warning rather
+/// than failing the chunk. Only doc comments are kept as comments since regular comments are not
+/// part of the syntax tree.
+#[derive(Default)]
+pub struct SynRustParser {}
+
+impl apyxl::Parser for SynRustParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut chunked_apis = Vec::new();
+        let mut all_entity_ids = HashSet::<EntityId>::default();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let chunk_entity_id = chunk
+                .relative_file_path
+                .as_ref()
+                .map(|file_path| rust_util::path_to_entity_id(file_path))
+                .unwrap_or_default();
+            let path = chunk
+                .relative_file_path
+                .as_ref()
+                .map(|file_path| file_path.display().to_string())
+                .unwrap_or_default();
+            let source = Source::new(strip_preamble(data), path);
+
+            let file = syn::parse_str::<syn::File>(source.data)
+                .map_err(|err| anyhow!("{}: {}", source.location(err.span()), err))?;
+
+            let mut imports = Vec::new();
+            let children =
+                namespace::children(config, &source, &file.items, &chunk_entity_id, &mut imports);
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Attributes {
+                    comments: attributes::inner_comments(&file.attrs),
+                    ..Default::default()
+                },
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            // Keep track of all EntityIds in this chunk for use in blanket imports.
+            collect_referenceable_entity_ids(&api, chunk_entity_id.clone(), &mut all_entity_ids);
+
+            chunked_apis.push((chunk, chunk_entity_id, api, imports));
+        }
+
+        // Necessary to separate API parsing from merging to builder so that we have the complete
+        // API available to qualify imported types. Otherwise, we wouldn't be able to resolve
+        // blanket imports like `use a::b::*`.
+
+        for (chunk, chunk_entity_id, mut api, mut imports) in chunked_apis {
+            for component in chunk_entity_id.component_names() {
+                builder.enter_namespace(component)
+            }
+
+            debug!(
+                "applying imports to chunk {:?}...",
+                chunk.relative_file_path
+            );
+
+            // Need to know what's in this chunk so it has precedence over those in others.
+            let mut local_entity_ids = HashSet::new();
+            collect_referenceable_entity_ids(&api, EntityId::default(), &mut local_entity_ids);
+
+            sort_imports(&mut imports);
+            apply_imports(&all_entity_ids, &local_entity_ids, &mut api, &imports)?;
+
+            debug!("merging chunk {:?}...", chunk.relative_file_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Source text of a chunk. Model names borrow from the source text using the spans of the parsed
+/// identifiers.
+pub struct Source<'a> {
+    data: &'a str,
+    path: String,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(data: &'a str, path: impl ToString) -> Self {
+        Self {
+            data,
+            path: path.to_string(),
+        }
+    }
+
+    /// The identifier's text in the source, without the `r#` prefix of raw identifiers.
+    pub fn ident(&self, ident: &syn::Ident) -> &'a str {
+        let name = self.text(ident.span());
+        name.strip_prefix("r#").unwrap_or(name)
+    }
+
+    /// The text of `span` in the source.
+    pub fn text(&self, span: Span) -> &'a str {
+        &self.data[span.byte_range()]
+    }
+
+    /// `path:line:column` of `span` for reporting.
+    pub fn location(&self, span: Span) -> String {
+        let start = span.start();
+        format!("{}:{}:{}", self.path, start.line, start.column + 1)
+    }
+}
+
+/// Strips a leading byte order mark and shebang like [syn::parse_file] does, but before parsing
+/// so that spans are relative to the returned data.
+fn strip_preamble(data: &str) -> &str {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+    match data.strip_prefix("#!") {
+        Some(rest) if !rest.trim_start().starts_with('[') => {
+            &data[data.find('\n').unwrap_or(data.len())..]
+        }
+        _ => data,
+    }
+}
+
+fn sort_imports(imports: &mut [Import]) {
+    // Sort single/renamed before blanket as they take priority.
+    imports.sort_by(|a, b| match (a, b) {
+        (Import::Blanket(_), Import::Blanket(_)) => Ordering::Equal,
+        (Import::Blanket(_), _) => Ordering::Greater,
+        (_, Import::Blanket(_)) => Ordering::Less,
+        _ => Ordering::Equal,
+    });
+}
+
+fn collect_referenceable_entity_ids(ns: &Namespace, id: EntityId, set: &mut HashSet<EntityId>) {
+    for child in &ns.children {
+        let id = id.child_unqualified(child.name());
+        set.insert(id.clone());
+        if let NamespaceChild::Namespace(ns) = child {
+            collect_referenceable_entity_ids(ns, id, set);
+        }
+    }
+}
+
+fn apply_imports(
+    all_entity_ids: &HashSet<EntityId>,
+    local_entity_ids: &HashSet<EntityId>,
+    namespace: &mut Namespace,
+    imports: &[Import],
+) -> Result<()> {
+    // Also add ids from this portion of the hierarchy tree in order to catch nested ids
+    // referencing less-nested types.
+    let mut local_entity_ids = local_entity_ids.clone();
+    collect_referenceable_entity_ids(namespace, EntityId::default(), &mut local_entity_ids);
+
+    let apply_import_to_type = |ty: &mut TypeRef| -> Result<()> {
+        apply_imports_to_type(all_entity_ids, &local_entity_ids, ty, imports)
+    };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_import_to_type(&mut param.ty)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_import_to_type(return_ty)?;
+        }
+        if let Some(error_ty) = &mut rpc.error_type {
+            apply_import_to_type(error_ty)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_type(&mut field.ty)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_import_to_rpc(rpc)?;
+    }
+
+    for interface in namespace.interfaces_mut() {
+        for rpc in &mut interface.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+        for id in &mut interface.super_interfaces {
+            apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+        }
+    }
+
+    for id in &mut namespace.implements {
+        apply_imports_to_id(all_entity_ids, &local_entity_ids, id, imports)?;
+    }
+
+    for field in namespace.fields_mut() {
+        apply_import_to_type(&mut field.ty)?;
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_import_to_type(&mut alias.target_ty)?;
+    }
+
+    for union in namespace.unions_mut() {
+        for variant in &mut union.variants {
+            match &mut variant.payload {
+                Some(VariantPayload::Tuple(tys)) => {
+                    for ty in tys {
+                        apply_import_to_type(ty)?;
+                    }
+                }
+                Some(VariantPayload::Struct(fields)) => {
+                    for field in fields {
+                        apply_import_to_type(&mut field.ty)?;
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    // note: enums have no type refs.
+
+    for ns in namespace.namespaces_mut() {
+        apply_imports(all_entity_ids, &local_entity_ids, ns, imports)?;
+    }
+
+    Ok(())
+}
+
+fn apply_imports_to_type(
+    all_entity_ids: &HashSet<EntityId>,
+    local_entity_ids: &HashSet<EntityId>,
+    ty: &mut TypeRef,
+    imports: &[Import],
+) -> Result<()> {
+    match &mut ty.value {
+        Type::Array(ty) | Type::Set(ty) | Type::FixedArray { ty, .. } | Type::Optional(ty) => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, ty, imports)?
+        }
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(all_entity_ids, local_entity_ids, &mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(all_entity_ids, local_entity_ids, key, imports)?;
+            apply_imports_to_type(all_entity_ids, local_entity_ids, value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(all_entity_ids, local_entity_ids, id, imports)?;
+            for arg in args {
+                apply_imports_to_type(all_entity_ids, local_entity_ids, arg, imports)?;
+            }
+        }
+        _ => {}
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(
+    all_entity_ids: &HashSet<EntityId>,
+    local_entity_ids: &HashSet<EntityId>,
+    id: &mut EntityId,
+    imports: &[Import],
+) -> Result<()> {
+    if !local_entity_ids.contains(id) {
+        for import in imports {
+            if let Some(qualified) = qualify_by_import(all_entity_ids, import, id)? {
+                *id = qualified;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn qualify_by_import(
+    all_entity_ids: &HashSet<EntityId>,
+    import: &Import,
+    id: &EntityId,
+) -> Result<Option<EntityId>> {
+    match import {
+        Import::Single(import) => {
+            // The import's name matches the first component of the id, e.g. `a::b` for `b::Dto`.
+            if import.component_names().last() == id.component_names().next()
+                && let Some(parent) = import.parent()
+            {
+                return Ok(Some(parent.concat(id)?));
+            }
+        }
+        Import::Renamed(import, alias) => {
+            // The alias replaces the first component of the id, e.g. `a::b as c` for `c::Dto`.
+            if id.component_names().next() == Some(alias.as_str()) {
+                let mut rest = id.clone();
+                rest.pop_front();
+                return Ok(Some(import.concat(&rest)?));
+            }
+        }
+        Import::Blanket(import) => {
+            let qualified = import.concat(id)?;
+            if all_entity_ids.contains(&qualified) {
+                return Ok(Some(qualified));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::{SynRustParser, strip_preamble};
+    use apyxl::model::{Builder, Comment, EntityId, Type, TypeRef, UNDEFINED_NAMESPACE};
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        //! file comment
+        use asdf;
+        /// rpc comment
+        pub fn rpc() {}
+        pub const field: &str = "blah";
+        pub type alias = u32;
+        pub enum en { A }
+        pub struct dto {}
+        impl dto {
+            pub fn method(&self) {}
+            pub fn r#static() {}
+        }
+        pub mod namespace {}
+        pub trait Unsupported {}
+        macro_rules! unsupported { () => {} }
+        "#,
+        );
+        let mut builder = Builder::default();
+        SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert_eq!(
+            model.api().attributes.comments,
+            vec![Comment::unowned(&["file comment"])]
+        );
+        assert_eq!(
+            model.api().rpc("rpc").unwrap().attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        assert!(model.api().field("field").is_some(), "field");
+        assert!(model.api().ty_alias("alias").is_some(), "alias");
+        assert!(model.api().en("en").is_some(), "en");
+        assert!(model.api().namespace("namespace").is_some(), "namespace");
+        let dto = model.api().dto("dto").unwrap();
+        assert!(dto.rpc("method").is_some(), "method");
+        assert!(
+            dto.namespace.as_ref().unwrap().rpc("static").is_some(),
+            "static"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn syntax_error() {
+        let mut input = input::Buffer::new("pub struct {}");
+        let mut builder = Builder::default();
+        let result = SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn preamble() {
+        assert_eq!(strip_preamble("\u{feff}struct A;"), "struct A;");
+        assert_eq!(strip_preamble("#!/bin/rust\nstruct A;"), "\nstruct A;");
+        assert_eq!(strip_preamble("#![attr]\nstruct A;"), "#![attr]\nstruct A;");
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::SynRustParser;
+        use crate::parser::tests::unqualified_id;
+        use apyxl::model::{Builder, Chunk, EntityId, VariantPayload};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn single_and_relative() -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path("b.rs"),
+                r#"
+                use crate::c::Dto;
+                pub mod nested {
+                    use super::super::c::Other;
+                    pub struct Inner { pub other: Other }
+                }
+                pub struct Local { pub dto: Dto }
+                "#,
+            );
+            input.add_chunk(
+                Chunk::with_relative_file_path("c.rs"),
+                "pub struct Dto {} pub struct Other {}",
+            );
+            let mut builder = Builder::default();
+            SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            let b = model
+                .api()
+                .find_namespace(&EntityId::new_unqualified("b"))
+                .unwrap();
+            assert_eq!(
+                unqualified_id(&b.dto("Local").unwrap().fields[0].ty),
+                EntityId::new_unqualified("c.Dto")
+            );
+            assert_eq!(
+                unqualified_id(&b.namespace("nested").unwrap().dto("Inner").unwrap().fields[0].ty),
+                EntityId::new_unqualified("c.Other")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn renamed_and_blanket() -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path("a.rs"),
+                r#"
+                use crate::b::Dto as Renamed;
+                use crate::c::*;
+                pub struct Local { pub renamed: Renamed, pub blanket: Other }
+                "#,
+            );
+            input.add_chunk(Chunk::with_relative_file_path("b.rs"), "pub struct Dto {}");
+            input.add_chunk(
+                Chunk::with_relative_file_path("c/mod.rs"),
+                "pub struct Other {}",
+            );
+            let mut builder = Builder::default();
+            SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            let local = model
+                .api()
+                .find_namespace(&EntityId::new_unqualified("a"))
+                .and_then(|a| a.dto("Local"))
+                .unwrap();
+            assert_eq!(
+                unqualified_id(&local.fields[0].ty),
+                EntityId::new_unqualified("b.Dto")
+            );
+            assert_eq!(
+                unqualified_id(&local.fields[1].ty),
+                EntityId::new_unqualified("c.Other")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn rpc_error_ty() -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path("a.rs"),
+                r#"
+                use crate::errs::ApiError;
+                pub fn get() -> Result<u32, ApiError> {}
+                "#,
+            );
+            input.add_chunk(
+                Chunk::with_relative_file_path("errs.rs"),
+                "pub struct ApiError {}",
+            );
+            let mut builder = Builder::default();
+            SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            let rpc = model
+                .api()
+                .find_rpc(&EntityId::new_unqualified("a.get"))
+                .unwrap();
+            assert_eq!(
+                unqualified_id(rpc.error_type.as_ref().unwrap()),
+                EntityId::new_unqualified("errs.ApiError")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn union_payloads() -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path("ev.rs"),
+                r#"
+                use crate::ids::UserId;
+                pub enum Event { Login { user: UserId }, Move(UserId) }
+                "#,
+            );
+            input.add_chunk(
+                Chunk::with_relative_file_path("ids.rs"),
+                "pub struct UserId {}",
+            );
+            let mut builder = Builder::default();
+            SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            let union = model
+                .api()
+                .find_union(&EntityId::new_unqualified("ev.Event"))
+                .unwrap();
+            let expected = EntityId::new_unqualified("ids.UserId");
+            match &union.variants[0].payload {
+                Some(VariantPayload::Struct(fields)) => {
+                    assert_eq!(unqualified_id(&fields[0].ty), expected)
+                }
+                payload => panic!("unexpected payload {:?}", payload),
+            }
+            match &union.variants[1].payload {
+                Some(VariantPayload::Tuple(tys)) => assert_eq!(unqualified_id(&tys[0]), expected),
+                payload => panic!("unexpected payload {:?}", payload),
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn traits() -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path("a.rs"),
+                r#"
+                use crate::b::{Base, Service};
+                pub struct Local {}
+                impl Service for Local {}
+                "#,
+            );
+            input.add_chunk(
+                Chunk::with_relative_file_path("b.rs"),
+                "pub trait Base {} pub trait Service: Base {}",
+            );
+            let mut builder = Builder::default();
+            SynRustParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            let local = model
+                .api()
+                .find_namespace(&EntityId::new_unqualified("a"))
+                .and_then(|a| a.dto("Local"))
+                .unwrap();
+            assert_eq!(local.implements, vec![EntityId::try_from("b.i:Service")?]);
+            let service = model
+                .api()
+                .find_interface(&EntityId::new_unqualified("b.Service"))
+                .unwrap();
+            assert_eq!(
+                service.super_interfaces,
+                vec![EntityId::try_from("b.i:Base")?]
+            );
+            Ok(())
+        }
+    }
+
+    fn unqualified_id(ty: &TypeRef) -> EntityId {
+        match &ty.value {
+            Type::Api(id) => id.to_unqualified(),
+            ty => panic!("expected api type, found {:?}", ty),
+        }
+    }
+}
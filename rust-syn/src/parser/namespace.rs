@@ -0,0 +1,575 @@
+use std::borrow::Cow;
+
+use anyhow::{Result, anyhow, bail};
+use log::warn;
+use syn::spanned::Spanned;
+use syn::{ImplItem, Item, ItemImpl, ItemMod, ItemTrait, TraitItem, TypeParamBound};
+
+use crate::parser::attributes::Visibility;
+use crate::parser::import::Import;
+use crate::parser::{Source, attributes, dto, en, import, rpc, ty, value};
+use apyxl::model::{Attributes, EntityId, Field, Interface, Namespace, NamespaceChild, TypeAlias};
+use apyxl::parser::Config;
+
+/// Items within a file or module. `use` items are added to `imports` instead of the returned
+/// children, with `module` being the namespace of `items` used to resolve relative imports.
+///
+/// Items that can't be represented in the model, e.g. macros or tuple structs, are skipped with a
+/// warning rather than failing the whole chunk.
+pub fn children<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    items: &[Item],
+    module: &EntityId,
+    imports: &mut Vec<Import>,
+) -> Vec<NamespaceChild<'a>> {
+    let mut children = Vec::new();
+    for item in items {
+        let child = match item {
+            Item::Struct(item) => {
+                dto::parse(config, source, item).map(|(c, v)| (NamespaceChild::Dto(c), v))
+            }
+            Item::Enum(item) if en::has_data(item) => {
+                en::parse_union(config, source, item).map(|(c, v)| (NamespaceChild::Union(c), v))
+            }
+            Item::Enum(item) => en::parse(source, item).map(|(c, v)| (NamespaceChild::Enum(c), v)),
+            Item::Macro(item) if en::is_flags(item) => {
+                en::parse_flags(source, item).map(|(c, v)| (NamespaceChild::Enum(c), v))
+            }
+            Item::Fn(item) => rpc::parse(config, source, &item.sig, &item.attrs)
+                .map(|c| (NamespaceChild::Rpc(c), Visibility::of(&item.vis))),
+            Item::Const(item) => field(
+                config,
+                source,
+                &item.ident,
+                &item.ty,
+                Some(&item.expr),
+                &item.attrs,
+            )
+            .map(|c| (NamespaceChild::Field(c), Visibility::of(&item.vis))),
+            Item::Static(item) => field(config, source, &item.ident, &item.ty, None, &item.attrs)
+                .map(|c| (NamespaceChild::Field(c), Visibility::of(&item.vis))),
+            Item::Type(item) => ty_alias(
+                config,
+                source,
+                &item.ident,
+                &item.generics,
+                &item.ty,
+                &item.attrs,
+            )
+            .map(|c| (NamespaceChild::TypeAlias(c), Visibility::of(&item.vis))),
+            Item::Mod(item) => Ok((
+                NamespaceChild::Namespace(namespace(config, source, item, module, imports)),
+                Visibility::of(&item.vis),
+            )),
+            Item::Trait(item) => interface(config, source, item)
+                .map(|c| (NamespaceChild::Interface(c), Visibility::of(&item.vis))),
+            Item::Impl(item) => impl_block(config, source, item)
+                .map(|c| (NamespaceChild::Namespace(c), Visibility::Public)),
+            Item::Use(item) => {
+                match import::parse(item, module) {
+                    Ok(parsed) => imports.extend(parsed),
+                    Err(err) => warn_skipped(source, item, "use", err),
+                }
+                continue;
+            }
+            _ => Err(anyhow!("not supported")),
+        };
+        match child {
+            Ok((child, visibility)) => children.extend(visibility.filter(child, config)),
+            Err(err) => warn_skipped(source, item, &describe(item), err),
+        }
+    }
+    children
+}
+
+/// `mod name;` or `mod name { ... }`. Inner doc comments, e.g. `//!`, are included in the
+/// namespace's comments.
+fn namespace<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    item: &ItemMod,
+    module: &EntityId,
+    imports: &mut Vec<Import>,
+) -> Namespace<'a> {
+    let name = source.ident(&item.ident);
+    let mut attributes = attributes::parse(&item.attrs);
+    attributes
+        .comments
+        .extend(attributes::inner_comments(&item.attrs));
+    let children = match &item.content {
+        Some((_, items)) => children(
+            config,
+            source,
+            items,
+            &module.child_unqualified(name),
+            imports,
+        ),
+        None => vec![],
+    };
+    Namespace {
+        name: Cow::Borrowed(name),
+        children,
+        attributes,
+        is_virtual: false,
+        implements: vec![],
+    }
+}
+
+/// `const` and `static` items, which are parsed as static fields.
+fn field<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    ident: &syn::Ident,
+    ty: &syn::Type,
+    expr: Option<&syn::Expr>,
+    attrs: &[syn::Attribute],
+) -> Result<Field<'a>> {
+    let ty = ty::parse(config, ty)?;
+    let value = match expr {
+        Some(expr) => value::parse(source, expr, &ty)?,
+        None => None,
+    };
+    Ok(Field {
+        name: source.ident(ident),
+        ty,
+        attributes: attributes::parse(attrs),
+        is_static: true,
+        value,
+        default: None,
+    })
+}
+
+fn ty_alias<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    ty: &syn::Type,
+    attrs: &[syn::Attribute],
+) -> Result<TypeAlias<'a>> {
+    let type_params = ty::type_params(source, generics)?;
+    let mut target_ty = ty::parse(config, ty)?;
+    target_ty.apply_type_params(&type_params);
+    Ok(TypeAlias {
+        name: source.ident(ident),
+        type_params,
+        target_ty,
+        attributes: attributes::parse(attrs),
+    })
+}
+
+/// Parses to a 'virtual' namespace that will be merged into the DTO with the same name. Trait
+/// impls only record the implemented trait since their items are already part of the trait.
+///
+/// Type parameters of generic impls, e.g. `impl<T> Dto<T>`, are applied to all items and must
+/// match the names of the DTO's type parameters.
+fn impl_block<'a>(config: &Config, source: &Source<'a>, item: &ItemImpl) -> Result<Namespace<'a>> {
+    let type_params = ty::type_params(source, &item.generics)?;
+    let name = match item.self_ty.as_ref() {
+        syn::Type::Path(path) if path.qself.is_none() && path.path.segments.len() == 1 => {
+            source.ident(&path.path.segments[0].ident)
+        }
+        _ => bail!("impl blocks must be for a local type"),
+    };
+
+    // Items of trait impls are already part of the trait.
+    if let Some((bang, path, _)) = &item.trait_ {
+        if bang.is_some() {
+            bail!("negative trait impls are not supported");
+        }
+        return Ok(Namespace {
+            name: Cow::Borrowed(name),
+            is_virtual: true,
+            implements: vec![ty::path_id(path)],
+            ..Default::default()
+        });
+    }
+
+    let mut children = Vec::new();
+    for impl_item in &item.items {
+        let child = match impl_item {
+            ImplItem::Fn(item) => rpc::parse(config, source, &item.sig, &item.attrs)
+                .map(|c| (NamespaceChild::Rpc(c), Visibility::of(&item.vis))),
+            ImplItem::Const(item) => field(
+                config,
+                source,
+                &item.ident,
+                &item.ty,
+                Some(&item.expr),
+                &item.attrs,
+            )
+            .map(|c| (NamespaceChild::Field(c), Visibility::of(&item.vis))),
+            ImplItem::Type(item) => ty_alias(
+                config,
+                source,
+                &item.ident,
+                &item.generics,
+                &item.ty,
+                &item.attrs,
+            )
+            .map(|c| (NamespaceChild::TypeAlias(c), Visibility::of(&item.vis))),
+            _ => Err(anyhow!("not supported")),
+        };
+        match child {
+            Ok((child, visibility)) => children.extend(visibility.filter(child, config)),
+            Err(err) => warn_skipped(source, impl_item, &format!("item in impl {}", name), err),
+        }
+    }
+
+    let mut namespace = Namespace {
+        name: Cow::Borrowed(name),
+        children,
+        attributes: Attributes {
+            comments: attributes::comments(&item.attrs),
+            ..Default::default()
+        },
+        is_virtual: true,
+        implements: vec![],
+    };
+    namespace.apply_type_params(&type_params);
+    Ok(namespace)
+}
+
+/// `trait Name: Super { ... }`. Methods are included regardless of visibility since they share
+/// the visibility of the trait. Lifetime bounds are ignored and associated items are skipped.
+fn interface<'a>(config: &Config, source: &Source<'a>, item: &ItemTrait) -> Result<Interface<'a>> {
+    let type_params = ty::type_params(source, &item.generics)?;
+    let name = source.ident(&item.ident);
+
+    let mut rpcs = Vec::new();
+    for trait_item in &item.items {
+        let rpc = match trait_item {
+            TraitItem::Fn(item) => rpc::parse(config, source, &item.sig, &item.attrs),
+            _ => Err(anyhow!("not supported")),
+        };
+        match rpc {
+            Ok(rpc) => rpcs.push(rpc),
+            Err(err) => warn_skipped(source, trait_item, &format!("item in trait {}", name), err),
+        }
+    }
+
+    let super_interfaces = item
+        .supertraits
+        .iter()
+        .filter_map(|bound| match bound {
+            TypeParamBound::Trait(bound) => Some(ty::path_id(&bound.path)),
+            _ => None,
+        })
+        .collect();
+
+    let mut interface = Interface {
+        name,
+        rpcs,
+        super_interfaces,
+        attributes: attributes::parse(&item.attrs),
+    };
+    interface.apply_type_params(&type_params);
+    Ok(interface)
+}
+
+fn describe(item: &Item) -> String {
+    match item {
+        Item::Const(item) => format!("const {}", item.ident),
+        Item::Enum(item) => format!("enum {}", item.ident),
+        Item::ExternCrate(item) => format!("extern crate {}", item.ident),
+        Item::Fn(item) => format!("fn {}", item.sig.ident),
+        Item::ForeignMod(_) => "extern block".to_string(),
+        Item::Impl(_) => "impl".to_string(),
+        Item::Macro(item) => match &item.ident {
+            Some(ident) => format!("macro {}", ident),
+            None => "macro invocation".to_string(),
+        },
+        Item::Mod(item) => format!("mod {}", item.ident),
+        Item::Static(item) => format!("static {}", item.ident),
+        Item::Struct(item) => format!("struct {}", item.ident),
+        Item::Trait(item) => format!("trait {}", item.ident),
+        Item::TraitAlias(item) => format!("trait alias {}", item.ident),
+        Item::Type(item) => format!("type {}", item.ident),
+        Item::Union(item) => format!("union {}", item.ident),
+        Item::Use(_) => "use".to_string(),
+        _ => "item".to_string(),
+    }
+}
+
+fn warn_skipped(source: &Source, item: &impl Spanned, description: &str, err: anyhow::Error) {
+    warn!(
+        "{}: skipping {}: {}",
+        source.location(item.span()),
+        description,
+        err
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::import::Import;
+    use crate::parser::{Source, namespace};
+    use apyxl::model::{
+        Comment, EntityId, Namespace, NamespaceChild, Semantics, Type, TypeRef, Value,
+    };
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn children() -> Result<()> {
+        let data = r#"
+            use crate::a::Other;
+
+            /// dto comment
+            pub struct Dto {}
+
+            impl Dto {
+                pub fn method(&self) -> u32 { 0 }
+                pub const CONST: u32 = 5;
+                pub type Alias = String;
+            }
+
+            pub fn rpc() {}
+            pub enum En { A }
+            pub type Alias = Vec<Dto>;
+            pub const FIELD: &str = "field";
+            pub static STATIC: u32 = 1;
+
+            /// namespace comment
+            pub mod ns {
+                //! inner comment
+                use super::Dto;
+                pub struct Nested {}
+            }
+
+            pub mod decl;
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let mut imports = vec![];
+        let children = namespace::children(
+            &TEST_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut imports,
+        );
+        let namespace = Namespace {
+            children,
+            ..Default::default()
+        };
+        assert_eq!(namespace.children.len(), 9);
+        assert_eq!(
+            namespace.dto("Dto").unwrap().attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert!(namespace.rpc("rpc").unwrap().is_static);
+        assert!(namespace.en("En").is_some());
+        assert!(namespace.ty_alias("Alias").is_some());
+        assert!(namespace.field("FIELD").unwrap().is_static);
+        assert_eq!(
+            namespace.field("FIELD").unwrap().value,
+            Some(Value::String("field".into()))
+        );
+        assert!(namespace.field("STATIC").unwrap().is_static);
+        assert_eq!(namespace.field("STATIC").unwrap().value, None);
+        assert!(namespace.namespace("decl").is_some());
+
+        let ns = namespace.namespace("ns").unwrap();
+        assert_eq!(
+            ns.attributes.comments,
+            vec![
+                Comment::unowned(&["namespace comment"]),
+                Comment::unowned(&["inner comment"])
+            ]
+        );
+        assert!(ns.dto("Nested").is_some());
+
+        let impl_block = namespace
+            .children
+            .iter()
+            .find_map(|child| match child {
+                NamespaceChild::Namespace(ns) if ns.is_virtual => Some(ns),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(impl_block.name, "Dto");
+        assert!(!impl_block.rpc("method").unwrap().is_static);
+        assert_eq!(
+            impl_block.field("CONST").unwrap().value,
+            Some(Value::Int(5))
+        );
+        assert!(impl_block.ty_alias("Alias").is_some());
+
+        assert_eq!(
+            imports,
+            vec![
+                Import::Single(EntityId::new_unqualified("a.Other")),
+                Import::Single(EntityId::new_unqualified("Dto")),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn generic_impl_block() -> Result<()> {
+        let data = r#"
+            impl<T> Page<T> {
+                pub fn items(&self) -> Vec<T> { todo!() }
+                pub type Item = T;
+            }
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let children = namespace::children(
+            &TEST_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut vec![],
+        );
+        let NamespaceChild::Namespace(impl_block) = &children[0] else {
+            panic!("expected impl block");
+        };
+        assert_eq!(impl_block.name, "Page");
+        let t = TypeRef::new(Type::TypeParam("T".to_string()), Semantics::Value);
+        assert_eq!(
+            impl_block.rpc("items").unwrap().return_type,
+            Some(TypeRef::new(Type::new_array(t.clone()), Semantics::Value))
+        );
+        assert_eq!(impl_block.ty_alias("Item").unwrap().target_ty, t);
+        Ok(())
+    }
+
+    #[test]
+    fn trait_item() -> Result<()> {
+        let data = r#"
+            pub trait Service: a::Base + Send + 'static {
+                type Associated;
+                fn get(&self, id: u32) -> Dto;
+                fn with_default(&self) {}
+            }
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let children = namespace::children(
+            &TEST_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut vec![],
+        );
+        let NamespaceChild::Interface(interface) = &children[0] else {
+            panic!("expected interface");
+        };
+        assert_eq!(interface.name, "Service");
+        assert_eq!(
+            interface
+                .rpcs
+                .iter()
+                .map(|rpc| rpc.name.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["get", "with_default"]
+        );
+        assert_eq!(
+            interface.super_interfaces,
+            vec![
+                EntityId::new_unqualified("a.Base"),
+                EntityId::new_unqualified("Send"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn trait_impl_block() -> Result<()> {
+        let data = r#"
+            impl crate::a::Trait<u32> for Dto {
+                fn f(&self) {}
+            }
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let children = namespace::children(
+            &TEST_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut vec![],
+        );
+        let NamespaceChild::Namespace(impl_block) = &children[0] else {
+            panic!("expected impl block");
+        };
+        assert!(impl_block.is_virtual);
+        assert_eq!(impl_block.name, "Dto");
+        assert_eq!(
+            impl_block.implements,
+            vec![EntityId::new_unqualified("a.Trait")]
+        );
+        assert!(impl_block.children.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_items_skipped() -> Result<()> {
+        let data = r#"
+            pub struct Dto {}
+            pub struct Const<const N: usize> {}
+            pub struct Tuple(u32);
+            impl !Send for Dto {}
+            impl<const N: usize> Const<N> {}
+            impl Dto {
+                pub fn generic<const N: usize>() {}
+                pub fn method(&self) {}
+                m!();
+            }
+            macro_rules! m { () => {} }
+            m!();
+            pub union Union { a: u32 }
+            pub fn rpc() -> *const u8 { todo!() }
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let children = namespace::children(
+            &TEST_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut vec![],
+        );
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].name(), "Dto");
+        let NamespaceChild::Namespace(impl_block) = &children[1] else {
+            panic!("expected impl block");
+        };
+        assert_eq!(impl_block.children.len(), 1);
+        assert_eq!(impl_block.children[0].name(), "method");
+        Ok(())
+    }
+
+    #[test]
+    fn private_children_filtered() -> Result<()> {
+        let data = r#"
+            pub struct Dto {}
+            struct PrivateDto {}
+            pub(crate) fn crate_rpc() {}
+            impl Dto {
+                pub fn method(&self) {}
+                fn private_method(&self) {}
+            }
+            "#;
+        let source = Source::new(data, "");
+        let file = syn::parse_file(data)?;
+        let children = namespace::children(
+            &TEST_PUB_ONLY_CONFIG,
+            &source,
+            &file.items,
+            &EntityId::default(),
+            &mut vec![],
+        );
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].name(), "Dto");
+        let NamespaceChild::Namespace(impl_block) = &children[1] else {
+            panic!("expected impl block");
+        };
+        assert_eq!(impl_block.children.len(), 1);
+        Ok(())
+    }
+}
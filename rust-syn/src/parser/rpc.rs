@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+
+use anyhow::{Result, bail};
+use syn::{
+    Attribute, FnArg, GenericArgument, Pat, PathArguments, ReturnType, Signature, TypeParamBound,
+};
+
+use crate::parser::{Source, attributes, ty, value};
+use apyxl::model::{Field, Rpc, RpcKind, Streaming};
+use apyxl::parser::Config;
+
+/// Function signatures of free functions and methods, e.g. `fn rpc(&self, a: u32) -> Dto`.
+/// Functions without a `self` parameter are static. Parameters must be simple identifiers, so
+/// patterns like `(a, b): (u32, u32)` are not supported. `async fn`s are async, and streams, e.g.
+/// `impl Stream<Item = T>`, are parsed as their item type with the corresponding [Streaming].
+/// `Result<T, E>` return types are parsed as a return type `T` and an error type `E`.
+pub fn parse<'a>(
+    config: &Config,
+    source: &Source<'a>,
+    sig: &Signature,
+    attrs: &[Attribute],
+) -> Result<Rpc<'a>> {
+    let type_params = ty::type_params(source, &sig.generics)?;
+    if sig.variadic.is_some() {
+        bail!("variadic functions are not supported");
+    }
+    let mut is_static = true;
+    let mut client_streaming = false;
+    let mut streams = 0;
+    let mut params = Vec::new();
+    for input in &sig.inputs {
+        match input {
+            FnArg::Receiver(_) => is_static = false,
+            FnArg::Typed(param) => {
+                let Pat::Ident(pat) = param.pat.as_ref() else {
+                    bail!("parameters must be identifiers");
+                };
+                let ty = match stream_item(&param.ty) {
+                    Some(item) => {
+                        client_streaming = true;
+                        streams += 1;
+                        ty::parse(config, item)?
+                    }
+                    None => ty::parse(config, &param.ty)?,
+                };
+                let mut attributes = attributes::parse(&param.attrs);
+                let default = value::take_default(source, &param.attrs, &mut attributes, &ty)?;
+                params.push(Field {
+                    name: source.ident(&pat.ident),
+                    ty,
+                    attributes,
+                    is_static: false,
+                    value: None,
+                    default,
+                });
+            }
+        }
+    }
+    // Client streaming applies to the whole rpc, so it can't express which param streams.
+    if client_streaming && streams != params.len() {
+        bail!("rpcs that stream some but not all of their params are not supported");
+    }
+    let (output, error_type) = match &sig.output {
+        ReturnType::Type(arrow, ty) => match result_types(ty) {
+            Some((ok, err)) => (
+                ReturnType::Type(*arrow, Box::new(ok.clone())),
+                Some(ty::parse(config, err)?),
+            ),
+            None => (sig.output.clone(), None),
+        },
+        ReturnType::Default => (ReturnType::Default, None),
+    };
+    let stream_return = match &output {
+        ReturnType::Type(_, ty) => stream_item(ty),
+        ReturnType::Default => None,
+    };
+    let return_type = match stream_return {
+        Some(item) => Some(ty::parse(config, item)?),
+        None => ty::parse_return(config, &output)?,
+    };
+    let mut rpc = Rpc {
+        name: Cow::Borrowed(source.ident(&sig.ident)),
+        type_params: type_params.clone(),
+        params,
+        return_type,
+        error_type,
+        attributes: attributes::parse(attrs),
+        is_static,
+        kind: RpcKind {
+            is_async: sig.asyncness.is_some(),
+            streaming: Streaming::new(client_streaming, stream_return.is_some()),
+        },
+    };
+    rpc.apply_type_params(&type_params);
+    Ok(rpc)
+}
+
+/// The ok and error types of a `Result<T, E>`, matched by name regardless of its path.
+fn result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+    match args.args.iter().collect::<Vec<_>>().as_slice() {
+        [GenericArgument::Type(ok), GenericArgument::Type(err)] => Some((ok, err)),
+        _ => None,
+    }
+}
+
+/// The item type of a stream, e.g. `T` in `impl futures::Stream<Item = T> + Send`.
+fn stream_item(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::ImplTrait(impl_trait) = ty else {
+        return None;
+    };
+    impl_trait.bounds.iter().find_map(|bound| {
+        let TypeParamBound::Trait(bound) = bound else {
+            return None;
+        };
+        let segment = bound.path.segments.last()?;
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        if segment.ident != "Stream" {
+            return None;
+        }
+        args.args.iter().find_map(|arg| match arg {
+            GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+            _ => None,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use syn::ItemFn;
+
+    use crate::parser::{Source, rpc};
+    use apyxl::model::{Comment, EntityId, Semantics, Streaming, Type, TypeRef, Value};
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn rpc() -> Result<()> {
+        let data = r#"
+            /// rpc comment
+            pub async fn rpc<'a>(a: &'a str, mut b: Vec<Dto>) -> Option<Dto> {
+                todo!()
+            }
+            "#;
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert_eq!(rpc.name, "rpc");
+        assert!(rpc.is_static);
+        assert!(rpc.kind.is_async);
+        assert_eq!(rpc.params.len(), 2);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(
+            rpc.params[0].ty,
+            TypeRef::new(Type::StringView, Semantics::Slice)
+        );
+        assert_eq!(rpc.params[1].name, "b");
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            )))
+        );
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn param_default() -> Result<()> {
+        let data = "pub fn rpc(#[default(-5)] a: i32, b: i32) {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert_eq!(rpc.params[0].default, Some(Value::Int(-5)));
+        assert!(rpc.params[0].attributes.user.is_empty());
+        assert_eq!(rpc.params[1].default, None);
+        Ok(())
+    }
+
+    #[test]
+    fn method() -> Result<()> {
+        let data = "fn rpc(&mut self, a: u32) {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert!(!rpc.is_static);
+        assert_eq!(rpc.params.len(), 1);
+        assert_eq!(rpc.return_type, None);
+        Ok(())
+    }
+
+    #[test]
+    fn generic() -> Result<()> {
+        let data = "fn rpc<T: Clone>(t: &T) -> Vec<T> { todo!() }";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert_eq!(rpc.type_params, vec!["T"]);
+        let t = Type::TypeParam("T".to_string());
+        assert_eq!(rpc.params[0].ty, TypeRef::new(t.clone(), Semantics::Ref));
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(
+                Type::new_array(TypeRef::new(t, Semantics::Value)),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn streaming() -> Result<()> {
+        let data =
+            "fn rpc(a: impl Stream<Item = u32>) -> impl futures::Stream<Item = Dto> + Send {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert!(!rpc.kind.is_async);
+        assert_eq!(rpc.kind.streaming, Streaming::Bidirectional);
+        assert_eq!(rpc.params[0].ty, TypeRef::new(Type::U32, Semantics::Value));
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn partial_streaming() -> Result<()> {
+        let data = "fn rpc(id: u32, a: impl Stream<Item = u32>) {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        assert!(rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn result() -> Result<()> {
+        let data = "fn rpc() -> std::result::Result<(), ApiError> {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<ItemFn>(data)?;
+        let rpc = rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs)?;
+        assert_eq!(rpc.return_type, None);
+        assert_eq!(
+            rpc.error_type,
+            Some(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("ApiError")),
+                Semantics::Value
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported() {
+        for data in [
+            "fn rpc<const N: usize>() {}",
+            "fn rpc((a, b): (u32, u32)) {}",
+            "fn rpc() -> impl Iterator<Item = u32> {}",
+        ] {
+            let source = Source::new(data, "");
+            let item = syn::parse_str::<ItemFn>(data).unwrap();
+            assert!(
+                rpc::parse(&TEST_CONFIG, &source, &item.sig, &item.attrs).is_err(),
+                "{}",
+                data
+            );
+        }
+    }
+}
@@ -0,0 +1,567 @@
+use anyhow::{Result, anyhow, bail};
+use quote::ToTokens;
+use syn::{Expr, ExprLit, GenericArgument, GenericParam, Generics, Lit, PathArguments, ReturnType};
+
+use crate::parser::Source;
+use apyxl::model::{EntityId, Semantics, Type, TypeRef, well_known};
+use apyxl::parser::Config;
+
+/// Fully qualified Rust spellings of [well_known] types, without whitespace. Bare names, e.g.
+/// `Duration`, are parsed as API types since the API may define its own.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    ("uuid::Uuid", well_known::UUID),
+    ("std::time::SystemTime", well_known::TIMESTAMP),
+    ("chrono::DateTime<chrono::Utc>", well_known::TIMESTAMP),
+    ("chrono::DateTime<Utc>", well_known::TIMESTAMP),
+    ("std::time::Duration", well_known::DURATION),
+    ("rust_decimal::Decimal", well_known::DECIMAL),
+    ("char", well_known::CHAR),
+];
+
+/// Rust types, e.g. `u32`, `&str`, `Vec<Dto>`, or `Option<crate::a::Dto>`.
+///
+/// `Vec<u8>` and `[u8]` are parsed as bytes and slices are parsed as arrays. Arrays with literal
+/// lengths, e.g. `[u8; 32]`, are parsed as fixed arrays, and other arrays as arrays. Shared
+/// references to slices and `str` have [Semantics::Slice], and `Box`, `Rc`, and `Arc` are parsed
+/// as their contained type with [Semantics::Boxed] or [Semantics::Shared], e.g. `Box<[u8]>` is
+/// boxed bytes. Function pointers, e.g. `fn(u32) -> bool`, are parsed as function types.
+/// `Vec`, `HashSet`, `BTreeSet`, `HashMap`, and `Option` are matched by name regardless of their
+/// path, e.g. `std::collections::HashMap`. Lifetimes are ignored. Any other type with generic
+/// arguments is parsed as a generic API type, e.g. `Page<Dto>`. Fully qualified spellings of
+/// [well_known] types, e.g. `uuid::Uuid`, are parsed as user types unless disabled in `config`.
+pub fn parse(config: &Config, ty: &syn::Type) -> Result<TypeRef> {
+    match ty {
+        syn::Type::Reference(reference) => {
+            let semantics = match reference.mutability {
+                Some(_) => Semantics::Mut,
+                None if is_slice(&reference.elem) => Semantics::Slice,
+                None => Semantics::Ref,
+            };
+            Ok(TypeRef::new(value(config, &reference.elem)?, semantics))
+        }
+        ty => match pointer(ty) {
+            Some((semantics, elem)) => Ok(TypeRef::new(value(config, elem)?, semantics)),
+            None => Ok(TypeRef::new(value(config, ty)?, Semantics::Value)),
+        },
+    }
+}
+
+/// Return type of a function where both no return type and `()` are parsed as `None`.
+pub fn parse_return(config: &Config, ty: &ReturnType) -> Result<Option<TypeRef>> {
+    match ty {
+        ReturnType::Default => Ok(None),
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Ok(None),
+            ty => parse(config, ty).map(Some),
+        },
+    }
+}
+
+/// Names of the type parameters in `generics`. Lifetimes, bounds, and `where` clauses are ignored.
+/// Fails if `generics` has any const parameters.
+pub fn type_params<'a>(source: &Source<'a>, generics: &Generics) -> Result<Vec<&'a str>> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(_) => None,
+            GenericParam::Type(param) => Some(Ok(source.ident(&param.ident))),
+            GenericParam::Const(param) => Some(Err(anyhow!(
+                "const generic parameter `{}` is not supported",
+                param.ident
+            ))),
+        })
+        .collect()
+}
+
+fn value(config: &Config, ty: &syn::Type) -> Result<Type> {
+    if let Some(user_ty) = user_ty(config, ty) {
+        return Ok(user_ty);
+    }
+    if let Some(well_known_ty) = well_known_ty(config, ty) {
+        return Ok(well_known_ty);
+    }
+    match ty {
+        syn::Type::Paren(paren) => value(config, &paren.elem),
+        syn::Type::Group(group) => value(config, &group.elem),
+        syn::Type::Slice(slice) if is_u8(&slice.elem) => Ok(Type::Bytes),
+        syn::Type::Slice(slice) => Ok(Type::new_array(parse(config, &slice.elem)?)),
+        syn::Type::Array(array) => match array_len(&array.len) {
+            Some(len) => Ok(Type::new_fixed_array(parse(config, &array.elem)?, len)),
+            None => Ok(Type::new_array(parse(config, &array.elem)?)),
+        },
+        syn::Type::Tuple(tuple) if !tuple.elems.is_empty() => Ok(Type::new_tuple(
+            tuple
+                .elems
+                .iter()
+                .map(|elem| parse(config, elem))
+                .collect::<Result<Vec<_>>>()?,
+        )),
+        syn::Type::BareFn(bare_fn) if bare_fn.variadic.is_none() => Ok(Type::new_function(
+            bare_fn
+                .inputs
+                .iter()
+                .map(|arg| parse(config, &arg.ty))
+                .collect::<Result<Vec<_>>>()?,
+            parse_return(config, &bare_fn.output)?,
+        )),
+        syn::Type::Path(path) if path.qself.is_none() => path_value(config, &path.path),
+        ty => Err(unsupported(ty)),
+    }
+}
+
+/// Unqualified id of `path` ignoring any generic arguments, e.g. `a::Dto` for `a::Dto<u32>`.
+pub fn path_id(path: &syn::Path) -> EntityId {
+    let mut components = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .peekable();
+    // `crate::` is equivalent to the root namespace.
+    components.next_if(|component| component == "crate");
+    EntityId::new_unqualified_vec(components)
+}
+
+fn path_value(config: &Config, path: &syn::Path) -> Result<Type> {
+    let last = path
+        .segments
+        .last()
+        .ok_or_else(|| anyhow!("empty type path"))?;
+    if path.segments.len() == 1
+        && last.arguments.is_none()
+        && let Some(ty) = primitive(&last.ident.to_string())
+    {
+        return Ok(ty);
+    }
+
+    let args = type_args(path)?;
+    match (last.ident.to_string().as_str(), args.as_slice()) {
+        ("Vec", [ty]) if is_u8(ty) => return Ok(Type::Bytes),
+        ("Vec", [ty]) => return Ok(Type::new_array(parse(config, ty)?)),
+        ("HashSet" | "BTreeSet", [ty]) => return Ok(Type::new_set(parse(config, ty)?)),
+        ("HashMap", [key, value]) => {
+            return Ok(Type::new_map(parse(config, key)?, parse(config, value)?));
+        }
+        ("Option", [ty]) => return Ok(Type::new_optional(parse(config, ty)?)),
+        _ => {}
+    }
+
+    let id = path_id(path);
+    if args.is_empty() {
+        Ok(Type::Api(id))
+    } else {
+        let args = args
+            .into_iter()
+            .map(|arg| parse(config, arg))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Type::new_generic(id, args))
+    }
+}
+
+/// Type arguments of all segments in `path`, ignoring lifetimes. Fails if any segment other than
+/// the last has type arguments, e.g. `a::<T>::B`.
+fn type_args(path: &syn::Path) -> Result<Vec<&syn::Type>> {
+    let mut args = Vec::new();
+    for (i, segment) in path.segments.iter().enumerate() {
+        let segment_args = match &segment.arguments {
+            PathArguments::None => continue,
+            PathArguments::AngleBracketed(angle) => angle
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Lifetime(_) => None,
+                    GenericArgument::Type(ty) => Some(Ok(ty)),
+                    arg => Some(Err(anyhow!(
+                        "generic argument `{}` is not supported",
+                        arg.to_token_stream()
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            PathArguments::Parenthesized(_) => {
+                bail!(
+                    "function type `{}` is not supported",
+                    path.to_token_stream()
+                )
+            }
+        };
+        if !segment_args.is_empty() && i != path.segments.len() - 1 {
+            bail!("generic type `{}` is not supported", path.to_token_stream())
+        }
+        args.extend(segment_args);
+    }
+    Ok(args)
+}
+
+/// Integer primitives, e.g. `u32` in `bitflags! { struct Flags: u32 { ... } }`.
+pub fn integer(ty: &syn::Type) -> Option<Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    primitive(&path.path.get_ident()?.to_string()).filter(|ty| {
+        matches!(
+            ty,
+            Type::U8
+                | Type::U16
+                | Type::U32
+                | Type::U64
+                | Type::U128
+                | Type::USIZE
+                | Type::I8
+                | Type::I16
+                | Type::I32
+                | Type::I64
+                | Type::I128
+        )
+    })
+}
+
+fn primitive(name: &str) -> Option<Type> {
+    Some(match name {
+        "bool" => Type::Bool,
+        "u8" => Type::U8,
+        "u16" => Type::U16,
+        "u32" => Type::U32,
+        "u64" => Type::U64,
+        "u128" => Type::U128,
+        "usize" => Type::USIZE,
+        "i8" => Type::I8,
+        "i16" => Type::I16,
+        "i32" => Type::I32,
+        "i64" => Type::I64,
+        "i128" => Type::I128,
+        "f8" => Type::F8,
+        "f16" => Type::F16,
+        "f32" => Type::F32,
+        "f64" => Type::F64,
+        "f128" => Type::F128,
+        "str" => Type::StringView,
+        "String" => Type::String,
+        _ => return None,
+    })
+}
+
+fn array_len(len: &Expr) -> Option<usize> {
+    match len {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+fn is_u8(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8"))
+}
+
+fn is_slice(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Slice(_) => true,
+        syn::Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// Semantics and contained type of `Box<T>`, `Rc<T>`, or `Arc<T>`, matched by name regardless of
+/// their path.
+fn pointer(ty: &syn::Type) -> Option<(Semantics, &syn::Type)> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let semantics = match path.path.segments.last()?.ident.to_string().as_str() {
+        "Box" => Semantics::Boxed,
+        "Rc" | "Arc" => Semantics::Shared,
+        _ => return None,
+    };
+    match type_args(&path.path).ok()?.as_slice() {
+        [elem] => Some((semantics, *elem)),
+        _ => None,
+    }
+}
+
+/// User types are matched against the type's tokens with all whitespace removed.
+fn user_ty(config: &Config, ty: &syn::Type) -> Option<Type> {
+    if config.user_types.is_empty() {
+        return None;
+    }
+    let tokens = strip_whitespace(&ty.to_token_stream().to_string());
+    config
+        .user_types
+        .iter()
+        .find(|user_ty| strip_whitespace(&user_ty.parse) == tokens)
+        .map(|user_ty| Type::User(user_ty.name.clone()))
+}
+
+/// Well-known types are matched the same way as user types.
+fn well_known_ty(config: &Config, ty: &syn::Type) -> Option<Type> {
+    let tokens = strip_whitespace(&ty.to_token_stream().to_string());
+    WELL_KNOWN_TYPES
+        .iter()
+        .find(|(parse, name)| *parse == tokens && config.is_well_known_type_enabled(name))
+        .map(|(_, name)| Type::User(name.to_string()))
+}
+
+fn strip_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn unsupported(ty: &syn::Type) -> anyhow::Error {
+    anyhow!("type `{}` is not supported", ty.to_token_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use lazy_static::lazy_static;
+
+    use crate::parser::{Source, ty};
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef, well_known};
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "chrono::DateTime<Utc>".to_string(),
+                name: "datetime".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(bool, "bool", ty(Type::Bool));
+    test!(u8, "u8", ty(Type::U8));
+    test!(u128, "u128", ty(Type::U128));
+    test!(usize, "usize", ty(Type::USIZE));
+    test!(i64, "i64", ty(Type::I64));
+    test!(f16, "f16", ty(Type::F16));
+    test!(f64, "f64", ty(Type::F64));
+    test!(string, "String", ty(Type::String));
+    test!(
+        string_view,
+        "&'a str",
+        TypeRef::new(Type::StringView, Semantics::Slice)
+    );
+    test!(bytes, "Vec<u8>", ty(Type::Bytes));
+    test!(
+        byte_slice,
+        "&[u8]",
+        TypeRef::new(Type::Bytes, Semantics::Slice)
+    );
+    test!(
+        slice,
+        "&[Dto]",
+        TypeRef::new(
+            Type::new_array(ty(Type::Api(EntityId::new_unqualified("Dto")))),
+            Semantics::Slice
+        )
+    );
+    test!(
+        mut_slice,
+        "&mut [u32]",
+        TypeRef::new(Type::new_array(ty(Type::U32)), Semantics::Mut)
+    );
+    test!(
+        mut_byte_slice,
+        "&mut [u8]",
+        TypeRef::new(Type::Bytes, Semantics::Mut)
+    );
+    test!(
+        boxed,
+        "Box<str>",
+        TypeRef::new(Type::StringView, Semantics::Boxed)
+    );
+    test!(
+        boxed_bytes,
+        "Box<[u8]>",
+        TypeRef::new(Type::Bytes, Semantics::Boxed)
+    );
+    test!(
+        boxed_slice,
+        "Box<[Dto]>",
+        TypeRef::new(
+            Type::new_array(ty(Type::Api(EntityId::new_unqualified("Dto")))),
+            Semantics::Boxed
+        )
+    );
+    test!(
+        function,
+        "fn(u32, &str) -> bool",
+        ty(Type::new_function(
+            [
+                ty(Type::U32),
+                TypeRef::new(Type::StringView, Semantics::Slice)
+            ],
+            Some(ty(Type::Bool))
+        ))
+    );
+    test!(function_unit, "fn()", ty(Type::new_function([], None)));
+    test!(
+        shared,
+        "std::sync::Arc<Vec<u32>>",
+        TypeRef::new(Type::new_array(ty(Type::U32)), Semantics::Shared)
+    );
+    test!(
+        mut_ref,
+        "&mut Dto",
+        TypeRef::new(Type::Api(EntityId::new_unqualified("Dto")), Semantics::Mut)
+    );
+    test!(
+        user,
+        "chrono::DateTime<Utc>",
+        ty(Type::User("datetime".to_string()))
+    );
+    test!(
+        well_known,
+        "uuid :: Uuid",
+        ty(Type::User(well_known::UUID.to_string()))
+    );
+    test!(
+        well_known_generic,
+        "chrono::DateTime<chrono::Utc>",
+        ty(Type::User(well_known::TIMESTAMP.to_string()))
+    );
+    test!(
+        well_known_bare,
+        "Uuid",
+        ty(Type::Api(EntityId::new_unqualified("Uuid")))
+    );
+    test!(
+        well_known_char,
+        "char",
+        ty(Type::User(well_known::CHAR.to_string()))
+    );
+    test!(
+        entity_id,
+        "crate::a::Dto",
+        ty(Type::Api(EntityId::new_unqualified("a.Dto")))
+    );
+    test!(vec, "Vec<i32>", ty(Type::new_array(ty(Type::I32))));
+    test!(
+        array,
+        "[i32; 4]",
+        ty(Type::new_fixed_array(ty(Type::I32), 4))
+    );
+    test!(
+        array_const_len,
+        "[i32; LEN]",
+        ty(Type::new_array(ty(Type::I32)))
+    );
+    test!(
+        tuple,
+        "(u32, Vec<Dto>)",
+        ty(Type::new_tuple([
+            ty(Type::U32),
+            ty(Type::new_array(ty(Type::Api(EntityId::new_unqualified(
+                "Dto"
+            ))))),
+        ]))
+    );
+    test!(
+        set,
+        "std::collections::BTreeSet<Dto>",
+        ty(Type::new_set(ty(Type::Api(EntityId::new_unqualified(
+            "Dto"
+        )))))
+    );
+    test!(
+        map,
+        "std::collections::HashMap<String, Vec<bool>>",
+        ty(Type::new_map(
+            ty(Type::String),
+            ty(Type::new_array(ty(Type::Bool)))
+        ))
+    );
+    test!(
+        option,
+        "Option<&'static str>",
+        ty(Type::new_optional(TypeRef::new(
+            Type::StringView,
+            Semantics::Slice
+        )))
+    );
+    test!(
+        lifetime_args,
+        "Dto<'a>",
+        ty(Type::Api(EntityId::new_unqualified("Dto")))
+    );
+
+    test!(
+        generic,
+        "crate::a::Page<Vec<Dto>, u32>",
+        ty(Type::new_generic(
+            EntityId::new_unqualified("a.Page"),
+            vec![
+                ty(Type::new_array(ty(Type::Api(EntityId::new_unqualified(
+                    "Dto"
+                ))))),
+                ty(Type::U32),
+            ]
+        ))
+    );
+
+    #[test]
+    fn unsupported() {
+        for data in ["a::<T>::B", "()", "impl Fn()", "dyn Trait", "*const u8"] {
+            let ty = syn::parse_str::<syn::Type>(data).unwrap();
+            assert!(ty::parse(&TY_TEST_CONFIG, &ty).is_err(), "{}", data);
+        }
+    }
+
+    #[test]
+    fn disabled_well_known() -> Result<()> {
+        let config = Config {
+            disabled_well_known_types: vec![well_known::DURATION.to_string()],
+            ..Default::default()
+        };
+        let duration = syn::parse_str::<syn::Type>("std::time::Duration")?;
+        assert_eq!(
+            ty::parse(&config, &duration)?,
+            ty(Type::Api(EntityId::new_unqualified("std.time.Duration")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn type_params() -> Result<()> {
+        let data = "struct Dto<'a, T: Clone, U> where U: Default {}";
+        let source = Source::new(data, "");
+        let item = syn::parse_str::<syn::ItemStruct>(data)?;
+        assert_eq!(ty::type_params(&source, &item.generics)?, vec!["T", "U"]);
+
+        let item = syn::parse_str::<syn::ItemStruct>("struct Dto<const N: usize> {}")?;
+        assert!(ty::type_params(&source, &item.generics).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unit_return() -> Result<()> {
+        let ty = syn::parse_str::<syn::ReturnType>("-> ()")?;
+        assert_eq!(ty::parse_return(&TY_TEST_CONFIG, &ty)?, None);
+        Ok(())
+    }
+
+    fn run_test(data: &'static str, expected: TypeRef) -> Result<()> {
+        let ty = syn::parse_str::<syn::Type>(data)?;
+        assert_eq!(ty::parse(&TY_TEST_CONFIG, &ty)?, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use anyhow::{Result, anyhow};
+use quote::ToTokens;
+use syn::{Attribute, Expr, Lit, UnOp};
+
+use crate::parser::Source;
+use apyxl::model::{Attributes, TypeRef, Value};
+use apyxl::parser::util;
+
+/// Literal value of a `const` with type `ty`, e.g. `5`, `-1.5`, `"str"`, `'c'`, `true`, or
+/// `Status::Active`. Anything else, e.g. expressions like `1 << 2` or references to other
+/// constants, is `None`. Literals that can't be represented, e.g. integers above `u128::MAX`, are
+/// an error.
+pub fn parse<'a>(source: &Source<'a>, expr: &Expr, ty: &TypeRef) -> Result<Option<Value<'a>>> {
+    Ok(match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Some(
+                int.base10_parse::<u128>()
+                    .ok()
+                    .and_then(|n| util::int_value(false, n))
+                    .ok_or_else(|| anyhow!("`{}` is out of range", int))?,
+            ),
+            Lit::Float(float) => Some(Value::Float(float.base10_parse::<f64>()?)),
+            Lit::Bool(b) => Some(Value::Bool(b.value)),
+            Lit::Char(c) => Some(Value::Char(c.value())),
+            // Borrowed from the source unless syn had to resolve escapes or raw string quoting.
+            Lit::Str(s) => Some(Value::String(
+                match source
+                    .text(s.span())
+                    .strip_prefix('"')
+                    .and_then(|text| text.strip_suffix('"'))
+                {
+                    Some(text) if !text.contains('\\') => Cow::Borrowed(text),
+                    _ => Cow::Owned(s.value()),
+                },
+            )),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            let negated = match parse(source, &unary.expr, ty)? {
+                Some(Value::Int(n)) => util::int_value(n > 0, n.unsigned_abs().into()),
+                Some(Value::UInt(n)) => util::int_value(true, n),
+                Some(Value::Float(n)) => Some(Value::Float(-n)),
+                _ => return Ok(None),
+            };
+            Some(negated.ok_or_else(|| anyhow!("`{}` is out of range", expr.to_token_stream()))?)
+        }
+        Expr::Paren(paren) => parse(source, &paren.expr, ty)?,
+        Expr::Group(group) => parse(source, &group.expr, ty)?,
+        Expr::Path(path) if path.qself.is_none() => {
+            let segments = path
+                .path
+                .segments
+                .iter()
+                .map(|segment| source.ident(&segment.ident))
+                .collect::<Vec<_>>();
+            util::enum_value(&segments, ty)
+        }
+        _ => None,
+    })
+}
+
+/// Value of a `#[default(<value>)]` attribute if it is a literal of type `ty`, in which case the
+/// attribute is removed from the user attributes in `attributes`.
+pub fn take_default<'a>(
+    source: &Source<'a>,
+    attrs: &[Attribute],
+    attributes: &mut Attributes<'a>,
+    ty: &TypeRef,
+) -> Result<Option<Value<'a>>> {
+    let Some(expr) = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("default"))
+        .and_then(|attr| attr.parse_args::<Expr>().ok())
+    else {
+        return Ok(None);
+    };
+    let value = parse(source, &expr, ty)?;
+    if value.is_some() {
+        attributes.user.retain(|attr| attr.name != "default");
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::Expr;
+
+    use crate::parser::{Source, value};
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef, Value};
+
+    #[test]
+    fn literals() {
+        let ty = TypeRef::new(Type::I32, Semantics::Value);
+        for (data, expected) in [
+            ("5", Some(Value::Int(5))),
+            ("-0x10", Some(Value::Int(-16))),
+            ("1_000u32", Some(Value::Int(1000))),
+            ("-9223372036854775808", Some(Value::Int(i64::MIN))),
+            (
+                "18446744073709551615u64",
+                Some(Value::UInt(u64::MAX as u128)),
+            ),
+            ("-1.5", Some(Value::Float(-1.5))),
+            ("true", Some(Value::Bool(true))),
+            ("'c'", Some(Value::Char('c'))),
+            (r#""a \"b\"""#, Some(Value::String(r#"a "b""#.into()))),
+            (r##"r#"a \b"#"##, Some(Value::String(r#"a \b"#.into()))),
+            ("1 << 2", None),
+            ("OTHER", None),
+        ] {
+            let source = Source::new(data, "");
+            let expr = syn::parse_str::<Expr>(data).unwrap();
+            assert_eq!(
+                value::parse(&source, &expr, &ty).unwrap(),
+                expected,
+                "{}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let ty = TypeRef::new(Type::U128, Semantics::Value);
+        for data in [
+            "340282366920938463463374607431768211456",
+            "-9223372036854775809",
+        ] {
+            let source = Source::new(data, "");
+            let expr = syn::parse_str::<Expr>(data).unwrap();
+            assert!(value::parse(&source, &expr, &ty).is_err(), "{}", data);
+        }
+    }
+
+    #[test]
+    fn enum_value() {
+        let ty = TypeRef::new(
+            Type::Api(EntityId::new_unqualified("Status")),
+            Semantics::Value,
+        );
+        for (data, expected) in [
+            ("Status::Active", Some(Value::EnumValue("Active".into()))),
+            (
+                "crate::Status::Active",
+                Some(Value::EnumValue("Active".into())),
+            ),
+            ("Other::Active", None),
+        ] {
+            let source = Source::new(data, "");
+            let expr = syn::parse_str::<Expr>(data).unwrap();
+            assert_eq!(
+                value::parse(&source, &expr, &ty).unwrap(),
+                expected,
+                "{}",
+                data
+            );
+        }
+    }
+}
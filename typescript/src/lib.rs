@@ -0,0 +1,3 @@
+pub use parser::TypeScriptParser as Parser;
+
+mod parser;
@@ -0,0 +1,190 @@
+use chumsky::prelude::*;
+use itertools::{Either, Itertools};
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, field, rpc, ty, visibility};
+use apyxl::model::{Attributes, Dto, Field, Rpc};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `interface Name { ... }`. Base interfaces in `extends` are ignored.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Dto<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("interface").then(text::whitespace().at_least(1));
+    let extends = util::keyword_ex("extends")
+        .padded()
+        .then(
+            ty::parser(config)
+                .separated_by(just(',').padded())
+                .at_least(1),
+        )
+        .ignored();
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(extends.or_not())
+        .then(members(config))
+        .map(|(((comments, visibility), name), (fields, rpcs))| {
+            (dto(name, fields, rpcs, comments), visibility)
+        })
+}
+
+/// Object type alias e.g. `type Name = { ... };`, which is parsed as a [Dto].
+pub fn object_ty<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Dto<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("type").then(text::whitespace().at_least(1));
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(just('=').padded())
+        .then(members(config))
+        .then_ignore(just(';').padded().or_not())
+        .map(|(((comments, visibility), name), (fields, rpcs))| {
+            (dto(name, fields, rpcs, comments), visibility)
+        })
+}
+
+fn dto<'a>(
+    name: &'a str,
+    fields: Vec<Field<'a>>,
+    rpcs: Vec<Rpc<'a>>,
+    comments: Vec<apyxl::model::Comment<'a>>,
+) -> Dto<'a> {
+    Dto {
+        name,
+        type_params: vec![],
+        fields,
+        rpcs,
+        extends: vec![],
+        implements: vec![],
+        attributes: Attributes {
+            comments,
+            ..Default::default()
+        },
+        namespace: None,
+    }
+}
+
+fn members<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Vec<Field<'a>>, Vec<Rpc<'a>>), Error<'a>> {
+    let separator = one_of(";,").padded().or_not();
+    choice((
+        // Method before property since property would fail on the param list anyway.
+        rpc::method(config).map(Either::Right),
+        field::property(config).map(Either::Left),
+    ))
+    .then_ignore(separator)
+    .recover_with(skip_then_retry_until(any().ignored(), just('}').ignored()))
+    .repeated()
+    .collect::<Vec<_>>()
+    .then_ignore(comment::multi())
+    .delimited_by(just('{').padded(), just('}').padded())
+    .map(|members| members.into_iter().partition_map(|member| member))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (dto, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("interface Dto {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert!(dto.fields.is_empty());
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn export() -> Result<()> {
+        let (_, visibility) = dto::parser(&TEST_CONFIG)
+            .parse("export interface Dto {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn members() -> Result<()> {
+        let (dto, _) = dto::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** dto comment */
+            interface Dto extends Base, other.Base {
+                // field comment
+                field0: string;
+                readonly field1?: number,
+                field2: Array<Other>
+                method(a: string): boolean;
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(dto.fields.len(), 3);
+        assert_eq!(dto.fields[0].name, "field0");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "field1");
+        assert_eq!(
+            dto.fields[1].ty.value,
+            Type::new_optional(TypeRef::new(Type::F64, Semantics::Value))
+        );
+        assert_eq!(dto.fields[2].name, "field2");
+        assert_eq!(
+            dto.fields[2].ty.value,
+            Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Other")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "method");
+        Ok(())
+    }
+
+    #[test]
+    fn object_ty() -> Result<()> {
+        let (dto, visibility) = dto::object_ty(&TEST_CONFIG)
+            .parse(
+                r#"
+            export type Dto = {
+                field0: string;
+                field1: number;
+            };
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "Dto");
+        assert_eq!(dto.fields.len(), 2);
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+}
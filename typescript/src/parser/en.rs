@@ -0,0 +1,170 @@
+use chumsky::prelude::*;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, visibility};
+use apyxl::model::{Attributes, Enum, EnumKind, EnumValue, EnumValueNumber};
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+const INVALID_ENUM_NUMBER: EnumValueNumber = EnumValueNumber::MAX;
+
+/// `enum Name { ... }` and `const enum Name { ... }`.
+pub fn parser<'a>() -> impl Parser<'a, &'a str, (Enum<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("const")
+        .then(text::whitespace().at_least(1))
+        .or_not()
+        .then(util::keyword_ex("enum"))
+        .then(text::whitespace().at_least(1));
+    let values = en_value()
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi())
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then(values)
+        .map(|(((comments, visibility), name), values)| {
+            (
+                Enum {
+                    name,
+                    values: apply_enum_value_number_defaults(values),
+                    kind: EnumKind::Plain,
+                    underlying_type: None,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                },
+                visibility,
+            )
+        })
+}
+
+/// Values without an initializer are numbered after the previous value. String initializers
+/// emit an error since the model's enum values can only be numbers.
+fn en_value<'a>() -> impl Parser<'a, &'a str, EnumValue<'a>, Error<'a>> {
+    let number = just('-')
+        .or_not()
+        .then(text::int(10))
+        .slice()
+        .try_map(|s: &str, span| {
+            str::parse::<EnumValueNumber>(s)
+                .map_err(|_| chumsky::error::Error::<&'a str>::expected_found(None, None, span))
+        })
+        .map(Some);
+    let string = choice((
+        none_of('"').repeated().delimited_by(just('"'), just('"')),
+        none_of('\'')
+            .repeated()
+            .delimited_by(just('\''), just('\'')),
+    ))
+    .validate(|_, span, emitter| {
+        emitter.emit(Rich::custom(
+            span,
+            "string enum values are not supported by the model",
+        ));
+        None
+    });
+    let initializer = just('=').padded().ignore_then(choice((number, string)));
+    comment::multi()
+        .padded()
+        .then(text::ident())
+        .then(initializer.or_not())
+        .padded()
+        .map(|((comments, name), number)| EnumValue {
+            name,
+            number: number.flatten().unwrap_or(INVALID_ENUM_NUMBER),
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+        })
+}
+
+fn apply_enum_value_number_defaults(mut values: Vec<EnumValue>) -> Vec<EnumValue> {
+    let mut i = 0;
+    for value in &mut values {
+        if value.number == INVALID_ENUM_NUMBER {
+            value.number = i;
+            i += 1;
+        } else {
+            i = value.number + 1;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::Comment;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn values() -> Result<()> {
+        let (en, visibility) = en::parser()
+            .parse(
+                r#"
+            /** enum comment */
+            export enum En {
+                // value comment
+                Zero,
+                Five = 5,
+                Six,
+                Negative = -1,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(visibility, Visibility::Public);
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["enum comment"])]
+        );
+        assert_eq!(en.values.len(), 4);
+        assert_eq!(en.values[0].name, "Zero");
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        assert_eq!(en.values[1].number, 5);
+        assert_eq!(en.values[2].number, 6);
+        assert_eq!(en.values[3].number, -1);
+        Ok(())
+    }
+
+    #[test]
+    fn const_enum() -> Result<()> {
+        let (en, _) = en::parser()
+            .parse("declare const enum En { A, B }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "En");
+        assert_eq!(en.values.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn string_values_unsupported() {
+        for data in [r#"enum En { A = "a" }"#, "enum En { A = 1, B = 'b' }"] {
+            let errs = en::parser().parse(data).into_result().expect_err(data);
+            assert!(
+                errs[0].to_string().contains("not supported"),
+                "{}: {:?}",
+                data,
+                errs
+            );
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::parser::comment;
+use apyxl::model::Comment;
+use apyxl::parser::error::Error;
+use chumsky::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExprBlock<'a> {
+    Comment(Comment<'a>),
+    Body(&'a str),
+    Nested(Vec<ExprBlock<'a>>),
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Vec<ExprBlock<'a>>, Error<'a>> + Clone {
+    let body = none_of("{}").repeated().at_least(1).slice().map(&str::trim);
+    recursive(|nested| {
+        choice((
+            comment::single().boxed().padded().map(ExprBlock::Comment),
+            nested.map(ExprBlock::Nested),
+            body.map(ExprBlock::Body),
+        ))
+        .repeated()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+        .recover_with(via_parser(nested_delimiters('{', '}', [], |_| vec![])))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::Parser;
+
+    use crate::parser::expr_block;
+    use crate::parser::expr_block::ExprBlock;
+
+    #[test]
+    fn nested() {
+        let result = expr_block::parser()
+            .parse("{ const x = { a: 1 }; if (x) { return; } }")
+            .into_result();
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                ExprBlock::Body("const x ="),
+                ExprBlock::Nested(vec![ExprBlock::Body("a: 1")]),
+                ExprBlock::Body("; if (x)"),
+                ExprBlock::Nested(vec![ExprBlock::Body("return;")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let result = expr_block::parser().parse("{}").into_result();
+        assert_eq!(result.unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,146 @@
+use chumsky::prelude::*;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, ty, visibility};
+use apyxl::model::{Attributes, Field};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// Namespace-level variable declaration e.g. `export const name: Type = value;`.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Field<'a>, Visibility), Error<'a>> {
+    let prefix = choice((
+        util::keyword_ex("const"),
+        util::keyword_ex("let"),
+        util::keyword_ex("var"),
+    ))
+    .then(text::whitespace().at_least(1));
+    let initializer = just('=').padded().then(none_of(";\n").repeated().slice());
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .then_ignore(initializer.or_not())
+        .then_ignore(just(';').padded().or_not())
+        .map(|(((comments, visibility), name), ty)| {
+            (
+                Field {
+                    name,
+                    ty,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                    is_static: true,
+                    value: None,
+                    default: None,
+                },
+                visibility,
+            )
+        })
+}
+
+/// Property inside of an interface or object type e.g. `readonly name?: Type`.
+pub fn property<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    comment::multi()
+        .padded()
+        .then_ignore(
+            util::keyword_ex("readonly")
+                .then(text::whitespace().at_least(1))
+                .or_not(),
+        )
+        .then(text::ident())
+        .then(just('?').padded().or_not())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .map(|(((comments, name), is_optional), ty)| Field {
+            name,
+            ty: match is_optional {
+                Some(_) => ty::optional(ty),
+                None => ty,
+            },
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+            is_static: false,
+            value: None,
+            default: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::field;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declare_const() -> Result<()> {
+        let (field, visibility) = field::parser(&TEST_CONFIG)
+            .parse("export declare const name: string;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        assert_eq!(field.ty.value, Type::String);
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn initializer() -> Result<()> {
+        let (field, _) = field::parser(&TEST_CONFIG)
+            .parse("const name: number = 1 + 2")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        assert_eq!(field.ty.value, Type::F64);
+        Ok(())
+    }
+
+    #[test]
+    fn optional_property() -> Result<()> {
+        let field = field::property(&TEST_CONFIG)
+            .parse("readonly name?: string")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "name");
+        assert_eq!(
+            field.ty.value,
+            Type::new_optional(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn optional_property_already_optional() -> Result<()> {
+        let field = field::property(&TEST_CONFIG)
+            .parse("name?: string | undefined")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            field.ty.value,
+            Type::new_optional(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn property_named_readonly() -> Result<()> {
+        let field = field::property(&TEST_CONFIG)
+            .parse("readonly: boolean")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(field.name, "readonly");
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use apyxl::parser::error::Error;
+use apyxl::parser::util;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Import<'a> {
+    /// `import { A, B as C } from './path';` where each element is (name, local_name).
+    Named(Vec<(&'a str, &'a str)>, &'a str),
+    /// `import * as ns from './path';`
+    Namespace(&'a str, &'a str),
+    /// Default imports, side-effect imports, and re-exports. These have no equivalent in the
+    /// model and are skipped.
+    Ignored,
+}
+
+impl<'a> Import<'a> {
+    pub fn path(&self) -> Option<&'a str> {
+        match self {
+            Import::Named(_, path) | Import::Namespace(_, path) => Some(path),
+            Import::Ignored => None,
+        }
+    }
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Import<'a>, Error<'a>> {
+    let namespace = just('*')
+        .padded()
+        .ignore_then(util::keyword_ex("as"))
+        .then(text::whitespace().at_least(1))
+        .ignore_then(text::ident());
+    let default = text::ident().then(just(',').padded().then(named()).or_not());
+
+    let import = util::keyword_ex("import")
+        .then(text::whitespace().at_least(1))
+        .ignore_then(type_only())
+        .ignore_then(choice((
+            named()
+                .then(from())
+                .map(|(names, path)| Import::Named(names, path)),
+            namespace
+                .then(from())
+                .map(|(alias, path)| Import::Namespace(alias, path)),
+            default.then(from()).map(|_| Import::Ignored),
+            path().map(|_| Import::Ignored),
+        )));
+    let re_export = util::keyword_ex("export")
+        .then(text::whitespace().at_least(1))
+        .then(type_only())
+        .then(choice((just('*').padded().ignored(), named().ignored())))
+        .then(from())
+        .map(|_| Import::Ignored);
+
+    comment::multi()
+        .padded()
+        .ignore_then(choice((import, re_export)))
+        .then_ignore(just(';').padded().or_not())
+}
+
+/// `{ A, type B, C as D }`
+fn named<'a>() -> impl Parser<'a, &'a str, Vec<(&'a str, &'a str)>, Error<'a>> {
+    let alias = text::whitespace()
+        .at_least(1)
+        .ignore_then(util::keyword_ex("as"))
+        .then(text::whitespace().at_least(1))
+        .ignore_then(text::ident());
+    type_only()
+        .ignore_then(text::ident())
+        .then(alias.or_not())
+        .map(|(name, local)| (name, local.unwrap_or(name)))
+        .padded()
+        .separated_by(just(','))
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), just('}').padded())
+}
+
+fn type_only<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> {
+    util::keyword_ex("type")
+        .then(text::whitespace().at_least(1))
+        .or_not()
+        .ignored()
+}
+
+fn from<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    util::keyword_ex("from").padded().ignore_then(path())
+}
+
+fn path<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    choice((
+        none_of('"')
+            .repeated()
+            .slice()
+            .delimited_by(just('"'), just('"')),
+        none_of('\'')
+            .repeated()
+            .slice()
+            .delimited_by(just('\''), just('\'')),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::Import;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn named() -> Result<()> {
+        run_test(
+            "import { A, B as C } from './b';",
+            Import::Named(vec![("A", "A"), ("B", "C")], "./b"),
+        )
+    }
+
+    #[test]
+    fn named_type_only() -> Result<()> {
+        run_test(
+            r#"import type { A, type B, } from "../b""#,
+            Import::Named(vec![("A", "A"), ("B", "B")], "../b"),
+        )
+    }
+
+    #[test]
+    fn namespace() -> Result<()> {
+        run_test("import * as b from './b';", Import::Namespace("b", "./b"))
+    }
+
+    #[test]
+    fn default() -> Result<()> {
+        run_test("import React from 'react';", Import::Ignored)
+    }
+
+    #[test]
+    fn side_effect() -> Result<()> {
+        run_test("import './polyfill';", Import::Ignored)
+    }
+
+    #[test]
+    fn re_export() -> Result<()> {
+        run_test("export { A, B } from './b';", Import::Ignored)?;
+        run_test("export * from './b';", Import::Ignored)
+    }
+
+    fn run_test(data: &'static str, expected: Import) -> Result<()> {
+        let import = import::parser()
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(import, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,607 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Component, Path};
+
+use apyxl::model::{Api, EntityId, Field, Namespace, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::import::Import;
+
+mod comment;
+mod dto;
+mod en;
+mod expr_block;
+mod field;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod ty_alias;
+mod visibility;
+
+/// File extensions that are stripped from chunk paths and relative import paths to determine the
+/// namespace of a module. Order matters, e.g. `.d.ts` must come before `.ts`.
+const MODULE_EXTENSIONS: &[&str] = &[".d.ts", ".ts", ".tsx", ".js"];
+
+/// Parses a subset of TypeScript declarations, i.e. the types that are typically shared in
+/// `.ts` and `.d.ts` files.
+///
+/// Each chunk is a module. The relative file path of the chunk (without extensions) determines its
+/// namespace, and an `index` module is placed in its parent directory's namespace.
+#[derive(Default)]
+pub struct TypeScriptParser {}
+
+impl apyxl::Parser for TypeScriptParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let imports = import::parser().padded().repeated().collect::<Vec<_>>();
+
+            let (imports, children) = imports
+                .then(
+                    namespace::children(config, namespace::parser(config), end().ignored())
+                        .padded(),
+                )
+                .then_ignore(end())
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            let mut api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Default::default(),
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            let chunk_path = chunk.relative_file_path.as_deref();
+
+            debug!("applying imports to chunk {:?}...", chunk_path);
+            let imports = resolve_imports(chunk_path, &imports);
+            apply_imports(&mut api, &imports)?;
+
+            if let Some(file_path) = chunk_path {
+                for component in path_to_entity_id(file_path).component_names() {
+                    builder.enter_namespace(component)
+                }
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Map of local names to the fully qualified [EntityId] they were imported from.
+type ResolvedImports = HashMap<String, EntityId>;
+
+/// Only relative imports (e.g. `./b` or `../a/b`) can be resolved since they reference other
+/// chunks. Anything else is assumed to be an external package and is skipped.
+fn resolve_imports(chunk_path: Option<&Path>, imports: &[Import]) -> ResolvedImports {
+    let mut resolved = ResolvedImports::new();
+    for import in imports {
+        let module_id = match import
+            .path()
+            .and_then(|path| module_entity_id(chunk_path, path))
+        {
+            None => continue,
+            Some(id) => id,
+        };
+        match import {
+            Import::Named(names, _) => {
+                for (name, local_name) in names {
+                    resolved.insert(local_name.to_string(), module_id.child_unqualified(name));
+                }
+            }
+            Import::Namespace(alias, _) => {
+                resolved.insert(alias.to_string(), module_id);
+            }
+            Import::Ignored => {}
+        }
+    }
+    resolved
+}
+
+fn module_entity_id(chunk_path: Option<&Path>, import_path: &str) -> Option<EntityId> {
+    if !import_path.starts_with("./") && !import_path.starts_with("../") {
+        return None;
+    }
+    let mut components = chunk_path
+        .and_then(Path::parent)
+        .map(path_components)
+        .unwrap_or_default();
+    for component in Path::new(import_path).components() {
+        match component {
+            Component::ParentDir => {
+                components.pop();
+            }
+            Component::Normal(name) => components.push(name.to_string_lossy().to_string()),
+            _ => {}
+        }
+    }
+    Some(module_components_to_entity_id(components))
+}
+
+fn path_to_entity_id(path: &Path) -> EntityId {
+    module_components_to_entity_id(path_components(path))
+}
+
+fn path_components(path: &Path) -> Vec<String> {
+    path.iter()
+        .map(|component| component.to_string_lossy().to_string())
+        .collect()
+}
+
+/// Strips the file extension from the last component and removes it entirely if it's an `index`.
+fn module_components_to_entity_id(mut components: Vec<String>) -> EntityId {
+    if let Some(last) = components.pop() {
+        let name = MODULE_EXTENSIONS
+            .iter()
+            .find_map(|ext| last.strip_suffix(ext))
+            .unwrap_or(&last);
+        if name != "index" {
+            components.push(name.to_string());
+        }
+    }
+    EntityId::new_unqualified_vec(components.into_iter())
+}
+
+fn apply_imports(namespace: &mut Namespace, imports: &ResolvedImports) -> Result<()> {
+    if imports.is_empty() {
+        return Ok(());
+    }
+
+    let apply_import_to_field =
+        |field: &mut Field| -> Result<()> { apply_imports_to_type(&mut field.ty, imports) };
+
+    let apply_import_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_imports_to_type(&mut param.ty, imports)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_imports_to_type(return_ty, imports)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_import_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_import_to_rpc(rpc)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_import_to_rpc(rpc)?;
+    }
+
+    for field in namespace.fields_mut() {
+        apply_import_to_field(field)?;
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_imports_to_type(&mut alias.target_ty, imports)?;
+    }
+
+    // note: enums have no type refs.
+
+    for ns in namespace.namespaces_mut() {
+        apply_imports(ns, imports)?;
+    }
+
+    Ok(())
+}
+
+fn apply_imports_to_type(ty: &mut TypeRef, imports: &ResolvedImports) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Set(ty) => apply_imports_to_type(ty, imports)?,
+        Type::FixedArray { ty, .. } => apply_imports_to_type(ty, imports)?,
+        Type::Optional(ty) => apply_imports_to_type(ty, imports)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_imports_to_type(&mut element.ty, imports)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_imports_to_type(key, imports)?;
+            apply_imports_to_type(value, imports)?;
+        }
+        Type::Api(id) => apply_imports_to_id(id, imports)?,
+        Type::Generic { ty: id, args } => {
+            apply_imports_to_id(id, imports)?;
+            for arg in args {
+                apply_imports_to_type(arg, imports)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_imports_to_type(param, imports)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_imports_to_type(return_ty, imports)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_imports_to_id(id: &mut EntityId, imports: &ResolvedImports) -> Result<()> {
+    let imported = id
+        .component_names()
+        .next()
+        .and_then(|name| imports.get(name));
+    if let Some(imported) = imported {
+        let mut rest = id.clone();
+        rest.pop_front();
+        *id = imported.concat(&rest)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::TypeScriptParser;
+    use apyxl::model::{Builder, Comment, UNDEFINED_NAMESPACE};
+    use apyxl::parser::Config;
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        // comment
+        import { Something } from 'some-package';
+        import * as React from 'react';
+        /** rpc comment */
+        export function rpc(): void {
+            return;
+        }
+        export const field: string = "blah";
+        export type alias = number;
+        type private_alias = number;
+        export interface dto {
+            method(): void;
+        }
+        interface private_dto {}
+        export type object = { field: string };
+        export enum en {}
+        enum private_en {}
+        export namespace namespace {}
+        namespace private_namespace {}
+        // end comment ignored
+        "#,
+        );
+        let mut builder = Builder::default();
+        TypeScriptParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert_eq!(model.api().name, UNDEFINED_NAMESPACE);
+        assert!(model.api().dto("dto").is_some(), "dto");
+        assert!(model.api().dto("object").is_some(), "object");
+        assert!(model.api().rpc("rpc").is_some(), "rpc");
+        assert!(model.api().en("en").is_some(), "en");
+        assert!(model.api().field("field").is_some(), "field");
+        assert!(model.api().ty_alias("alias").is_some(), "alias");
+        assert!(model.api().namespace("namespace").is_some(), "namespace");
+        assert!(model.api().dto("private_dto").is_some(), "private_dto");
+        assert!(model.api().en("private_en").is_some(), "private_en");
+        assert!(
+            model.api().ty_alias("private_alias").is_some(),
+            "private_alias"
+        );
+        assert!(
+            model.api().namespace("private_namespace").is_some(),
+            "private_namespace"
+        );
+        assert_eq!(
+            model.api().rpc("rpc").unwrap().attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])],
+            "comment after import attributed to rpc"
+        );
+        assert!(
+            model.api().dto("dto").unwrap().rpc("method").is_some(),
+            "dto method"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_parse_private() -> Result<()> {
+        let mut input = input::Buffer::new(
+            r#"
+        export function rpc(): void;
+        function ignored_rpc(): void {}
+        export enum en {}
+        enum ignored_en {}
+        export interface dto {}
+        declare interface declared_dto {}
+        interface ignored_dto {}
+        type ignored_alias = number;
+        export type alias = number;
+        export namespace namespace {}
+        namespace ignored_namespace {}
+        "#,
+        );
+        let mut builder = Builder::default();
+        let config = Config {
+            enable_parse_private: false,
+            ..Default::default()
+        };
+        TypeScriptParser::default().parse(&config, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+        assert!(model.api().dto("dto").is_some());
+        assert!(model.api().dto("declared_dto").is_some());
+        assert!(model.api().rpc("rpc").is_some());
+        assert!(model.api().en("en").is_some());
+        assert!(model.api().ty_alias("alias").is_some());
+        assert!(model.api().namespace("namespace").is_some());
+        assert!(model.api().dto("ignored_dto").is_none());
+        assert!(model.api().rpc("ignored_rpc").is_none());
+        assert!(model.api().en("ignored_en").is_none());
+        assert!(model.api().ty_alias("ignored_alias").is_none());
+        assert!(model.api().namespace("ignored_namespace").is_none());
+        Ok(())
+    }
+
+    mod file_path_to_namespace {
+        use anyhow::Result;
+
+        use crate::parser::TypeScriptParser;
+        use apyxl::model::{Builder, Chunk, EntityId};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn ts() -> Result<()> {
+            run_test("a/b/c.ts", "a.b.c.Dto")
+        }
+
+        #[test]
+        fn d_ts() -> Result<()> {
+            run_test("a/b/c.d.ts", "a.b.c.Dto")
+        }
+
+        #[test]
+        fn index() -> Result<()> {
+            run_test("a/b/index.ts", "a.b.Dto")
+        }
+
+        fn run_test(path: &str, expected_dto_id: &str) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            input.add_chunk(
+                Chunk::with_relative_file_path(path),
+                "export interface Dto {}",
+            );
+            let mut builder = Builder::default();
+            TypeScriptParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+            assert!(
+                model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(expected_dto_id))
+                    .is_some()
+            );
+            Ok(())
+        }
+    }
+
+    mod imports {
+        use anyhow::Result;
+
+        use crate::parser::{ResolvedImports, TypeScriptParser, apply_imports_to_type};
+        use apyxl::model::{Builder, Chunk, EntityId, Model, Semantics, Type, TypeRef};
+        use apyxl::test_util::executor::TEST_CONFIG;
+        use apyxl::{Parser, input};
+
+        #[test]
+        fn named() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import { Id } from './a';
+            export interface Entity {
+                id: Id;
+            }
+            "#;
+            run_dto_chunked_test(
+                &[("a.ts", a), ("test.ts", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn named_alias() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import { Id as OtherId } from './a';
+            export interface Entity {
+                id: OtherId;
+            }
+            "#;
+            run_dto_chunked_test(
+                &[("a.ts", a), ("test.ts", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn namespace() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import * as a_ns from './a';
+            export interface Entity {
+                id: a_ns.Id;
+            }
+            "#;
+            run_dto_chunked_test(
+                &[("a.ts", a), ("test.ts", test)],
+                "test.Entity",
+                "ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn parent_dir() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import { Id } from '../x/a';
+            export interface Entity {
+                id: Id;
+            }
+            "#;
+            run_dto_chunked_test(
+                &[("x/a.ts", a), ("y/test.ts", test)],
+                "y.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn index() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import { Id } from './a';
+            export interface Entity {
+                id: Id;
+            }
+            "#;
+            run_dto_chunked_test(
+                &[("x/a/index.d.ts", a), ("x/test.ts", test)],
+                "x.test.Entity",
+                "ns:x.ns:a.d:Id",
+            )
+        }
+
+        #[test]
+        fn rpc() -> Result<()> {
+            let a = "export interface Id {}";
+            let test = r#"
+            import { Id } from './a';
+            export function rpc(id: Id): Id[];
+            "#;
+            run_chunked_test(&[("a.ts", a), ("test.ts", test)], |model| {
+                let rpc = model
+                    .api()
+                    .find_rpc(&EntityId::new_unqualified("test.rpc"))
+                    .unwrap();
+                let expected = EntityId::try_from("ns:a.d:Id")?;
+                assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn generic() -> Result<()> {
+            // Type arguments aren't parsed from TypeScript, but generic types are still resolved.
+            let imports = ResolvedImports::from([
+                ("Page".to_string(), EntityId::try_from("a.Page")?),
+                ("Id".to_string(), EntityId::try_from("a.Id")?),
+            ]);
+            let generic = |page, id| {
+                TypeRef::new(
+                    Type::new_generic(
+                        EntityId::try_from(page).unwrap(),
+                        vec![TypeRef::new(
+                            Type::Array(Box::new(TypeRef::new_api(id, Semantics::Value).unwrap())),
+                            Semantics::Value,
+                        )],
+                    ),
+                    Semantics::Value,
+                )
+            };
+            let mut ty = generic("Page", "Id");
+            apply_imports_to_type(&mut ty, &imports)?;
+            assert_eq!(ty, generic("a.Page", "a.Id"));
+            Ok(())
+        }
+
+        fn run_chunked_test(
+            inputs: &[(&str, &str)],
+            assertions: impl FnOnce(&Model) -> Result<()>,
+        ) -> Result<()> {
+            let mut input = input::ChunkBuffer::new();
+            for (path, data) in inputs {
+                input.add_chunk(Chunk::with_relative_file_path(path), data);
+            }
+            let mut builder = Builder::default();
+            TypeScriptParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+            let model = builder.build().unwrap();
+
+            assertions(&model)?;
+            Ok(())
+        }
+
+        fn run_dto_chunked_test(
+            inputs: &[(&str, &str)],
+            dto_id: &str,
+            expected_entity_id: &str,
+        ) -> Result<()> {
+            run_chunked_test(inputs, |model| {
+                let actual = model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified(dto_id))
+                    .unwrap()
+                    .fields[0]
+                    .ty
+                    .value
+                    .api()
+                    .unwrap();
+
+                let expected = EntityId::try_from(expected_entity_id)?;
+                assert_eq!(
+                    expected, *actual,
+                    "expected: {}, actual: {}",
+                    expected, actual
+                );
+                Ok(())
+            })
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+use itertools::Itertools;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, dto, en, field, rpc, ty_alias, visibility};
+use apyxl::model::{Attributes, Namespace, NamespaceChild};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `namespace a.b.c { ... }` or `module a { ... }`.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Namespace<'a>, Visibility), Error<'a>> {
+    recursive(|nested| {
+        let prefix = choice((util::keyword_ex("namespace"), util::keyword_ex("module")))
+            .then(text::whitespace().at_least(1));
+        let name_chain = text::ident()
+            .separated_by(just('.'))
+            .at_least(1)
+            .collect::<Vec<_>>();
+        let body = children(config, nested, just('}').ignored())
+            .delimited_by(just('{').padded(), just('}').padded());
+        comment::multi()
+            .padded()
+            .then(visibility::parser())
+            .then_ignore(prefix)
+            .then(name_chain)
+            .then(body)
+            .map(|(((comments, visibility), mut name_chain), children)| {
+                let name = name_chain.remove(name_chain.len() - 1);
+                let mut namespace = Namespace {
+                    name: Cow::Borrowed(name),
+                    children,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                    is_virtual: false,
+                    implements: vec![],
+                };
+                // For inline nested namespaces e.g. `namespace a.b.c`, walk the name_chain
+                // in reverse, and wrapping each level in a new namespace.
+                for parent in name_chain.into_iter().rev() {
+                    namespace = Namespace {
+                        name: Cow::Borrowed(parent),
+                        children: vec![NamespaceChild::Namespace(namespace)],
+                        ..Default::default()
+                    }
+                }
+                (namespace, visibility)
+            })
+            .boxed()
+    })
+}
+
+pub fn children<'a>(
+    config: &'a Config,
+    namespace: impl Parser<'a, &'a str, (Namespace<'a>, Visibility), Error<'a>>,
+    end_delimiter: impl Parser<'a, &'a str, (), Error<'a>>,
+) -> impl Parser<'a, &'a str, Vec<NamespaceChild<'a>>, Error<'a>> {
+    choice((
+        dto::parser(config).map(|(c, v)| Some((NamespaceChild::Dto(c), v))),
+        // Object types must come before aliases since both start with `type X =`.
+        dto::object_ty(config).map(|(c, v)| Some((NamespaceChild::Dto(c), v))),
+        ty_alias::parser(config).map(|(c, v)| Some((NamespaceChild::TypeAlias(c), v))),
+        rpc::parser(config).map(|(c, v)| Some((NamespaceChild::Rpc(c), v))),
+        en::parser().map(|(c, v)| Some((NamespaceChild::Enum(c), v))),
+        field::parser(config).map(|(c, v)| Some((NamespaceChild::Field(c), v))),
+        namespace.map(|(c, v)| Some((NamespaceChild::Namespace(c), v))),
+        // Catch comments after all children
+        comment::single().padded().map(|_| None),
+    ))
+    .recover_with(skip_then_retry_until(
+        any().ignored(),
+        end_delimiter.ignored(),
+    ))
+    .map(|opt| match opt {
+        Some((child, visibility)) => visibility.filter(child, config),
+        None => None,
+    })
+    .repeated()
+    .collect::<Vec<_>>()
+    .map(|v| v.into_iter().flatten().collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, NamespaceChild};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::{TEST_CONFIG, TEST_PUB_ONLY_CONFIG};
+
+    #[test]
+    fn empty() -> Result<()> {
+        let (namespace, visibility) = namespace::parser(&TEST_CONFIG)
+            .parse("namespace empty {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(namespace.name, "empty");
+        assert!(namespace.children.is_empty());
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn module() -> Result<()> {
+        let (namespace, visibility) = namespace::parser(&TEST_CONFIG)
+            .parse("declare module empty {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(namespace.name, "empty");
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn with_children() -> Result<()> {
+        let (namespace, _) = namespace::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** ns comment */
+            export namespace ns {
+                export interface Dto {}
+                export type Object = { a: string };
+                export type Alias = string;
+                export function rpc(): void;
+                export enum En {}
+                export const field: number;
+                export namespace nested {}
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(namespace.name, "ns");
+        assert_eq!(
+            namespace.attributes.comments,
+            vec![Comment::unowned(&["ns comment"])]
+        );
+        assert!(namespace.dto("Dto").is_some());
+        assert!(namespace.dto("Object").is_some());
+        assert!(namespace.ty_alias("Alias").is_some());
+        assert!(namespace.rpc("rpc").is_some());
+        assert!(namespace.en("En").is_some());
+        assert!(namespace.field("field").is_some());
+        assert!(namespace.namespace("nested").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn dotted_name() -> Result<()> {
+        let (namespace, _) = namespace::parser(&TEST_CONFIG)
+            .parse("namespace a.b.c { interface Dto {} }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(namespace.name, "a");
+        let c = namespace
+            .namespace("b")
+            .and_then(|b| b.namespace("c"))
+            .expect("nested namespaces");
+        assert!(matches!(c.children[0], NamespaceChild::Dto(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn private_children_filtered() -> Result<()> {
+        let (namespace, _) = namespace::parser(&TEST_PUB_ONLY_CONFIG)
+            .parse(
+                r#"
+            export namespace ns {
+                export interface Dto {}
+                interface PrivateDto {}
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(namespace.dto("Dto").is_some());
+        assert!(namespace.dto("PrivateDto").is_none());
+        Ok(())
+    }
+}
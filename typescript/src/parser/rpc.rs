@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, expr_block, ty, visibility};
+use apyxl::model::{Attributes, Field, Rpc, RpcKind, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+/// `function name(params): ReturnType;` with an optional body.
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Rpc<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("function").then(text::whitespace().at_least(1));
+    let end = choice((
+        just(';').padded().ignored(),
+        expr_block::parser().padded().ignored(),
+    ))
+    .or_not();
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then(params(config))
+        .then(return_type(config))
+        .then_ignore(end)
+        .map(|((((comments, visibility), name), params), return_type)| {
+            (
+                Rpc {
+                    name: Cow::Borrowed(name),
+                    type_params: vec![],
+                    params,
+                    return_type,
+                    error_type: None,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                    is_static: true,
+                    kind: RpcKind::default(),
+                },
+                visibility,
+            )
+        })
+}
+
+/// Method signature inside of an interface or object type, e.g. `name(params): ReturnType`.
+pub fn method<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Rpc<'a>, Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(text::ident())
+        .then_ignore(just('?').padded().or_not())
+        .then(params(config))
+        .then(return_type(config))
+        .map(|(((comments, name), params), return_type)| Rpc {
+            name: Cow::Borrowed(name),
+            type_params: vec![],
+            params,
+            return_type,
+            error_type: None,
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+            is_static: false,
+            kind: RpcKind::default(),
+        })
+}
+
+fn return_type<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Option<TypeRef>, Error<'a>> {
+    just(':')
+        .padded()
+        .ignore_then(choice((
+            util::keyword_ex("void").map(|_| None),
+            ty::parser(config).map(Some),
+        )))
+        .or_not()
+        .map(Option::flatten)
+}
+
+fn param<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Field<'a>, Error<'a>> {
+    comment::multi()
+        .padded()
+        .then(text::ident())
+        .then(just('?').padded().or_not())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .map(|(((comments, name), is_optional), ty)| Field {
+            name,
+            ty: match is_optional {
+                Some(_) => ty::optional(ty),
+                None => ty,
+            },
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+            is_static: false,
+            value: None,
+            default: None,
+        })
+}
+
+fn params<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> {
+    param(config)
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(
+            just('(').padded(),
+            just(')').padded().recover_with(skip_then_retry_until(
+                none_of(")").ignored(),
+                just(')').ignored(),
+            )),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let (rpc, visibility) = rpc::parser(&TEST_CONFIG)
+            .parse("function rpc_name();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn export() -> Result<()> {
+        let (_, visibility) = rpc::parser(&TEST_CONFIG)
+            .parse("export function rpc_name(): void;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn body_skipped() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            function rpc_name(): string {
+                if (true) { return "a"; }
+                return `${1}`;
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc_name");
+        assert_eq!(
+            rpc.return_type,
+            Some(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse("function rpc_name(a: number, b?: Dto): Dto;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.params.len(), 2);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(rpc.params[0].ty.value, Type::F64);
+        assert_eq!(rpc.params[1].name, "b");
+        assert_eq!(
+            rpc.params[1].ty.value,
+            Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("Dto")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::Api(EntityId::new_unqualified("Dto")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn comment() -> Result<()> {
+        let (rpc, _) = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /** rpc comment */
+            export function rpc_name(): void;
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn method() -> Result<()> {
+        let rpc = rpc::method(&TEST_CONFIG)
+            .parse("method(a: string): boolean")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "method");
+        assert_eq!(rpc.params.len(), 1);
+        assert!(!rpc.is_static);
+        Ok(())
+    }
+}
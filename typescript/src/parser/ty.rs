@@ -0,0 +1,306 @@
+use chumsky::prelude::*;
+
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let ty = choice((
+            util::keyword_ex("boolean").map(|_| Type::Bool),
+            util::keyword_ex("number").map(|_| Type::F64),
+            util::keyword_ex("bigint").map(|_| Type::I128),
+            util::keyword_ex("string").map(|_| Type::String),
+            util::keyword_ex("Uint8Array").map(|_| Type::Bytes),
+            util::keyword_ex("ArrayBuffer").map(|_| Type::Bytes),
+        ))
+        .or(choice((
+            user_ty(config).map(Type::User),
+            array(nested.clone()),
+            map(nested.clone()),
+            function(nested.clone()),
+            // Note that entity_id should come last because it is greedy.
+            entity_id().map(Type::Api),
+        )))
+        .map(|ty| TypeRef::new(ty, Semantics::Value));
+
+        let primary = choice((
+            ty,
+            nested
+                .clone()
+                .delimited_by(just('(').padded(), just(')').padded()),
+        ));
+
+        let member = choice((
+            util::keyword_ex("undefined").map(|_| None),
+            util::keyword_ex("null").map(|_| None),
+            array_suffix(primary).map(Some),
+        ));
+
+        just('|')
+            .padded()
+            .or_not()
+            .ignore_then(
+                member
+                    .separated_by(just('|').padded())
+                    .at_least(1)
+                    .collect::<Vec<_>>(),
+            )
+            .try_map(|members, span| {
+                let is_optional = members.iter().any(Option::is_none);
+                let mut tys = members.into_iter().flatten().collect::<Vec<_>>();
+                match (tys.pop(), tys.is_empty()) {
+                    (Some(ty), true) if is_optional => Ok(optional(ty)),
+                    (Some(ty), true) => Ok(ty),
+                    (None, _) => Err(Rich::custom(
+                        span,
+                        "type cannot only be `undefined` or `null`",
+                    )),
+                    (Some(_), false) => Err(Rich::custom(
+                        span,
+                        "union types are only supported with `undefined` or `null`",
+                    )),
+                }
+            })
+            .boxed()
+    })
+}
+
+/// Wraps `ty` in [Type::Optional] unless it is already optional.
+pub fn optional(ty: TypeRef) -> TypeRef {
+    if let Type::Optional(_) = ty.value {
+        ty
+    } else {
+        TypeRef::new(Type::new_optional(ty), Semantics::Value)
+    }
+}
+
+/// `T[]`, `T[][]`, etc.
+fn array_suffix<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> {
+    ty.then(just('[').padded().then(just(']')).repeated().count())
+        .map(|(ty, depth)| {
+            (0..depth).fold(ty, |ty, _| {
+                TypeRef::new(Type::new_array(ty), Semantics::Value)
+            })
+        })
+}
+
+/// `Array<T>` and `ReadonlyArray<T>`.
+fn array<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>>,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((util::keyword_ex("Array"), util::keyword_ex("ReadonlyArray")))
+        .then(just('<').padded())
+        .ignore_then(ty.padded())
+        .then_ignore(just('>'))
+        .map(Type::new_array)
+}
+
+/// `Record<K, V>` and `Map<K, V>`.
+fn map<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    choice((util::keyword_ex("Record"), util::keyword_ex("Map")))
+        .then(just('<').padded())
+        .ignore_then(ty.clone().padded())
+        .then_ignore(just(',').padded())
+        .then(ty.padded())
+        .then_ignore(just('>'))
+        .map(|(key, value)| Type::new_map(key, value))
+}
+
+/// `(a: T, b?: U) => R`. A `void` return is treated as no return type.
+fn function<'a>(
+    ty: impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Type, Error<'a>> {
+    let param = text::ident()
+        .ignore_then(just('?').padded().or_not())
+        .then_ignore(just(':').padded())
+        .then(ty.clone())
+        .map(|(is_optional, ty)| match is_optional {
+            Some(_) => optional(ty),
+            None => ty,
+        });
+    let params = param
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), just(')').padded());
+    let return_ty = choice((util::keyword_ex("void").map(|_| None), ty.map(Some)));
+    params
+        .then_ignore(just("=>").padded())
+        .then(return_ty)
+        .map(|(params, return_ty)| Type::new_function(params, return_ty))
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(just(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+fn entity_id<'a>() -> impl Parser<'a, &'a str, EntityId, Error<'a>> {
+    text::ident()
+        .separated_by(just('.'))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|components| EntityId::new_unqualified_vec(components.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "Date".to_string(),
+                name: "date".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(boolean, "boolean", Type::Bool);
+    test!(number, "number", Type::F64);
+    test!(bigint, "bigint", Type::I128);
+    test!(string, "string", Type::String);
+    test!(bytes, "Uint8Array", Type::Bytes);
+    test!(array_buffer, "ArrayBuffer", Type::Bytes);
+    test!(user, "Date", Type::User("date".to_string()));
+    test!(
+        entity_id,
+        "a.b.Type",
+        Type::Api(EntityId::new_unqualified("a.b.Type"))
+    );
+    test!(
+        keyword_prefixed_entity_id,
+        "numberish",
+        Type::Api(EntityId::new_unqualified("numberish"))
+    );
+    test!(array_suffix, "string[]", Type::new_array(ty(Type::String)));
+    test!(
+        array_suffix_nested,
+        "string[][]",
+        Type::new_array(ty(Type::new_array(ty(Type::String))))
+    );
+    test!(
+        array_generic,
+        "Array<string>",
+        Type::new_array(ty(Type::String))
+    );
+    test!(
+        readonly_array_generic,
+        "ReadonlyArray< number >",
+        Type::new_array(ty(Type::F64))
+    );
+    test!(
+        record,
+        "Record<string, number>",
+        Type::new_map(ty(Type::String), ty(Type::F64))
+    );
+    test!(
+        map,
+        "Map<string, Array<number>>",
+        Type::new_map(ty(Type::String), ty(Type::new_array(ty(Type::F64))))
+    );
+    test!(
+        union_undefined,
+        "string | undefined",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        union_null_first,
+        "null | string",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        union_null_and_undefined,
+        "string | null | undefined",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        leading_pipe,
+        "| string | null",
+        Type::new_optional(ty(Type::String))
+    );
+    test!(
+        parenthesized_array,
+        "(string | null)[]",
+        Type::new_array(ty(Type::new_optional(ty(Type::String))))
+    );
+    test!(
+        function,
+        "(a: string, b?: number) => boolean",
+        Type::new_function(
+            [ty(Type::String), ty(Type::new_optional(ty(Type::F64)))],
+            Some(ty(Type::Bool))
+        )
+    );
+    test!(function_void, "() => void", Type::new_function([], None));
+
+    #[test]
+    fn union_unsupported() {
+        let result = ty::parser(&TY_TEST_CONFIG)
+            .parse("string | number")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn only_null() {
+        let result = ty::parser(&TY_TEST_CONFIG).parse("null").into_result();
+        assert!(result.is_err());
+    }
+
+    fn run_test(data: &'static str, expected: Type) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty.value, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+}
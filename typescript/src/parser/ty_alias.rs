@@ -0,0 +1,78 @@
+use chumsky::prelude::*;
+
+use crate::parser::visibility::Visibility;
+use crate::parser::{comment, ty, visibility};
+use apyxl::model::{Attributes, TypeAlias};
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (TypeAlias<'a>, Visibility), Error<'a>> {
+    let prefix = util::keyword_ex("type").then(text::whitespace().at_least(1));
+    comment::multi()
+        .padded()
+        .then(visibility::parser())
+        .then_ignore(prefix)
+        .then(text::ident())
+        .then_ignore(just('=').padded())
+        .then(ty::parser(config))
+        .then_ignore(just(';').padded().or_not())
+        .map(|(((comments, visibility), name), target_ty)| {
+            (
+                TypeAlias {
+                    name,
+                    type_params: vec![],
+                    target_ty,
+                    attributes: Attributes {
+                        comments,
+                        ..Default::default()
+                    },
+                },
+                visibility,
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ty_alias;
+    use crate::parser::visibility::Visibility;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        let (alias, visibility) = ty_alias::parser(&TEST_CONFIG)
+            .parse("export type Alias = a.b.Type;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "Alias");
+        assert_eq!(
+            alias.target_ty,
+            TypeRef::new(
+                Type::Api(EntityId::new_unqualified("a.b.Type")),
+                Semantics::Value
+            )
+        );
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn optional() -> Result<()> {
+        let (alias, _) = ty_alias::parser(&TEST_CONFIG)
+            .parse("type Alias = string | null")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            alias.target_ty.value,
+            Type::new_optional(TypeRef::new(Type::String, Semantics::Value))
+        );
+        Ok(())
+    }
+}
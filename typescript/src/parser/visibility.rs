@@ -0,0 +1,84 @@
+use apyxl::parser::error::Error;
+use apyxl::parser::{Config, util};
+use chumsky::prelude::*;
+
+/// TypeScript has no visibility keywords for top-level declarations. Instead, anything that is
+/// `export`ed or `declare`d (i.e. ambient declarations in `.d.ts` files) is considered public.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+impl Visibility {
+    pub fn is_visible(&self, config: &Config) -> bool {
+        *self == Visibility::Public || config.enable_parse_private
+    }
+
+    pub fn filter<T>(&self, value: T, config: &Config) -> Option<T> {
+        if self.is_visible(config) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Visibility, Error<'a>> {
+    choice((util::keyword_ex("export"), util::keyword_ex("declare")))
+        .then_ignore(text::whitespace().at_least(1))
+        .repeated()
+        .count()
+        .map(|count| {
+            if count > 0 {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::visibility;
+    use crate::parser::visibility::Visibility;
+    use anyhow::Result;
+    use apyxl::parser::test_util::wrap_test_err;
+    use chumsky::Parser;
+
+    #[test]
+    fn private() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Private);
+        Ok(())
+    }
+
+    #[test]
+    fn export() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("export ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn export_declare() -> Result<()> {
+        let visibility = visibility::parser()
+            .parse("export declare ")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(visibility, Visibility::Public);
+        Ok(())
+    }
+
+    #[test]
+    fn requires_whitespace() {
+        let result = visibility::parser().parse("export").into_result();
+        assert!(result.is_err());
+    }
+}
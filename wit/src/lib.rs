@@ -0,0 +1,3 @@
+pub use parser::WitParser as Parser;
+
+mod parser;
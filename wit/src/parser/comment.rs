@@ -0,0 +1,21 @@
+use apyxl::model::Comment;
+use apyxl::parser::comment;
+use apyxl::parser::error::Error;
+use chumsky::Parser;
+use chumsky::prelude::*;
+
+pub fn multi<'a>() -> impl Parser<'a, &'a str, Vec<Comment<'a>>, Error<'a>> + Clone {
+    comment::multi(line_start(), block_start(), block_end()).boxed()
+}
+
+fn line_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    choice((just("///"), just("//")))
+}
+
+fn block_start<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> {
+    choice((just("/**"), just("/*")))
+}
+
+fn block_end<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
+    just("*/")
+}
@@ -0,0 +1,209 @@
+use chumsky::prelude::*;
+
+use crate::parser::gates::gates;
+use crate::parser::ident::{ident, keyword};
+use crate::parser::{comment, rpc, ty};
+use apyxl::model::{Attributes, Dto, Field, Namespace, NamespaceChild, Rpc};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// `record name { a: T, b: U }`
+pub fn record<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Dto<'a>, Error<'a>> + Clone {
+    let field = comment::multi()
+        .padded()
+        .then(gates())
+        .then(ident())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .map(|(((comments, user), name), ty)| Field {
+            name,
+            ty,
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+            is_static: false,
+            value: None,
+            default: None,
+        });
+    let fields = field
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi().padded())
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("record"))
+        .then(ident())
+        .then(fields)
+        .map(|(((comments, user), name), fields)| Dto {
+            name,
+            type_params: vec![],
+            fields,
+            rpcs: vec![],
+            extends: vec![],
+            implements: vec![],
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+            namespace: None,
+        })
+        .boxed()
+}
+
+/// `resource name;` or `resource name { ... }`. Methods are parsed as rpcs of the dto, and
+/// `static` functions are parsed as static rpcs within the dto's namespace. Constructors are
+/// skipped.
+pub fn resource<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Dto<'a>, Error<'a>> + Clone {
+    let constructor = comment::multi()
+        .padded()
+        .then(gates())
+        .then(keyword("constructor"))
+        .then(rpc::params(config))
+        .then(just(';').padded())
+        .map(|_| None);
+    let member = choice((constructor, rpc::parser(config).map(Some)));
+    let body = member
+        .repeated()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi().padded())
+        .delimited_by(just('{').padded(), just('}').padded());
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("resource"))
+        .then(ident())
+        .then(choice((body, just(';').padded().map(|_| vec![]))))
+        .map(|(((comments, user), name), members)| {
+            let (statics, rpcs): (Vec<Rpc>, Vec<Rpc>) =
+                members.into_iter().flatten().partition(|rpc| rpc.is_static);
+            let namespace = if statics.is_empty() {
+                None
+            } else {
+                Some(Namespace {
+                    children: statics.into_iter().map(NamespaceChild::Rpc).collect(),
+                    ..Default::default()
+                })
+            };
+            Dto {
+                name,
+                type_params: vec![],
+                fields: vec![],
+                rpcs,
+                extends: vec![],
+                implements: vec![],
+                attributes: Attributes {
+                    comments,
+                    user,
+                    ..Default::default()
+                },
+                namespace,
+            }
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::dto;
+    use apyxl::model::{Comment, EntityId, Type};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn record() -> Result<()> {
+        let dto = dto::record(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// dto comment
+            record dto-name {
+                // field comment
+                a: u32,
+                b: list<other>,
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "dto-name");
+        assert_eq!(
+            dto.attributes.comments,
+            vec![Comment::unowned(&["dto comment"])]
+        );
+        assert_eq!(dto.fields.len(), 2);
+        assert_eq!(dto.fields[0].name, "a");
+        assert_eq!(
+            dto.fields[0].attributes.comments,
+            vec![Comment::unowned(&["field comment"])]
+        );
+        assert_eq!(dto.fields[1].name, "b");
+        Ok(())
+    }
+
+    #[test]
+    fn empty_record() -> Result<()> {
+        let dto = dto::record(&TEST_CONFIG)
+            .parse("record empty {}")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(dto.fields.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn resource() -> Result<()> {
+        let dto = dto::resource(&TEST_CONFIG)
+            .parse(
+                r#"
+            resource descriptor {
+                constructor(path: string);
+                /// method comment
+                read: func(len: u64) -> list<u8>;
+                open: static func(path: string) -> descriptor;
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "descriptor");
+        assert!(dto.fields.is_empty());
+        assert_eq!(dto.rpcs.len(), 1);
+        assert_eq!(dto.rpcs[0].name, "read");
+        assert_eq!(
+            dto.rpcs[0].attributes.comments,
+            vec![Comment::unowned(&["method comment"])]
+        );
+        let open = dto
+            .namespace
+            .as_ref()
+            .and_then(|ns| ns.rpc("open"))
+            .unwrap();
+        assert!(open.is_static);
+        assert_eq!(
+            open.return_type.as_ref().map(|ty| &ty.value),
+            Some(&Type::Api(EntityId::new_unqualified("descriptor")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opaque_resource() -> Result<()> {
+        let dto = dto::resource(&TEST_CONFIG)
+            .parse("resource handle;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(dto.name, "handle");
+        assert!(dto.rpcs.is_empty());
+        assert!(dto.namespace.is_none());
+        Ok(())
+    }
+}
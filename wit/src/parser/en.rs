@@ -0,0 +1,256 @@
+use chumsky::prelude::*;
+
+use crate::parser::gates::gates;
+use crate::parser::ident::{ident, keyword};
+use crate::parser::{comment, ty};
+use apyxl::model::{
+    Attributes, Comment, Enum, EnumKind, EnumValue, EnumValueNumber, Type, TypeRef, Union, Variant,
+    VariantPayload, attributes,
+};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Enum,
+    Flags,
+    Variant,
+}
+
+/// `enum name { a, b }`, `flags name { a, b }`, or `variant name { a, b }`.
+///
+/// Enum and variant cases are numbered by their position. Flags are numbered by powers of two so
+/// that they can be combined, i.e. the first flag is `1`, the second `2`, and so on. Like the
+/// canonical ABI, flags are stored as the smallest unsigned integer with a bit for each flag.
+///
+/// Variants with case payloads, e.g. `variant v { a(u32) }`, are not matched. See [union_parser].
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Enum<'a>, Error<'a>> + Clone {
+    let kind = choice((
+        keyword("enum").to(Kind::Enum),
+        keyword("flags").to(Kind::Flags),
+        keyword("variant").to(Kind::Variant),
+    ));
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then(kind)
+        .then(ident())
+        .then(cases(ident().map(|name| (name, None))))
+        .map(|((((comments, user), kind), name), values)| {
+            let values = values
+                .into_iter()
+                .enumerate()
+                .map(|(i, (comments, user, (name, _)))| EnumValue {
+                    name,
+                    number: match kind {
+                        Kind::Flags => 1 << i,
+                        Kind::Enum | Kind::Variant => i as EnumValueNumber,
+                    },
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                })
+                .collect::<Vec<_>>();
+            let underlying_type = match (kind, values.len()) {
+                (Kind::Flags, 0..=8) => Some(Type::U8),
+                (Kind::Flags, 9..=16) => Some(Type::U16),
+                (Kind::Flags, 17..=32) => Some(Type::U32),
+                (Kind::Flags, 33..=64) => Some(Type::U64),
+                _ => None,
+            };
+            Enum {
+                name,
+                values,
+                kind: match kind {
+                    Kind::Flags => EnumKind::Flags,
+                    Kind::Enum | Kind::Variant => EnumKind::Plain,
+                },
+                underlying_type,
+                attributes: Attributes {
+                    comments,
+                    user,
+                    ..Default::default()
+                },
+            }
+        })
+        .boxed()
+}
+
+/// `variant name { a, b(u32) }` with at least one case payload. Each payload is parsed as a
+/// [VariantPayload::Tuple] with a single type.
+pub fn union_parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Union<'a>, Error<'a>> + Clone {
+    let payload = ty::parser(config).delimited_by(just('(').padded(), just(')').padded());
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("variant"))
+        .then(ident())
+        .then(cases(ident().then(payload.or_not())))
+        .map(|(((comments, user), name), cases)| Union {
+            name,
+            variants: cases
+                .into_iter()
+                .map(|(comments, user, (name, ty))| Variant {
+                    name,
+                    payload: ty.map(|ty| VariantPayload::Tuple(vec![ty])),
+                    attributes: Attributes {
+                        comments,
+                        user,
+                        ..Default::default()
+                    },
+                })
+                .collect(),
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+        })
+        .boxed()
+}
+
+type Case<'a> = (
+    Vec<Comment<'a>>,
+    Vec<attributes::User<'a>>,
+    (&'a str, Option<TypeRef>),
+);
+
+/// `{ a, b }` where each case is parsed by `case`.
+fn cases<'a>(
+    case: impl Parser<'a, &'a str, (&'a str, Option<TypeRef>), Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Vec<Case<'a>>, Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then(case)
+        .map(|((comments, user), case)| (comments, user, case))
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi().padded())
+        .delimited_by(just('{').padded(), just('}').padded())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::en;
+    use apyxl::model::{Comment, EnumKind, Semantics, Type, TypeRef, VariantPayload};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn enum_values() -> Result<()> {
+        let en = en::parser()
+            .parse(
+                r#"
+            /// enum comment
+            enum error-code {
+                // value comment
+                access,
+                would-block,
+                %type,
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "error-code");
+        assert_eq!(
+            en.attributes.comments,
+            vec![Comment::unowned(&["enum comment"])]
+        );
+        assert_eq!(en.values.len(), 3);
+        assert_eq!(en.values[0].name, "access");
+        assert_eq!(en.values[0].number, 0);
+        assert_eq!(
+            en.values[0].attributes.comments,
+            vec![Comment::unowned(&["value comment"])]
+        );
+        assert_eq!(en.values[1].name, "would-block");
+        assert_eq!(en.values[1].number, 1);
+        assert_eq!(en.values[2].name, "type");
+        assert_eq!(en.values[2].number, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn flags() -> Result<()> {
+        let en = en::parser()
+            .parse("flags permissions { read, write, exec }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.name, "permissions");
+        assert_eq!(en.kind, EnumKind::Flags);
+        assert_eq!(en.underlying_type, Some(Type::U8));
+        assert_eq!(
+            en.values.iter().map(|v| v.number).collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn variant_without_payloads() -> Result<()> {
+        let en = en::parser()
+            .parse("variant filter { all, none }")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(en.values.len(), 2);
+        assert_eq!(en.values[1].name, "none");
+        assert_eq!(en.values[1].number, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn variant_with_payloads_not_enum() {
+        let result = en::parser()
+            .parse("variant filter { all, some(list<string>) }")
+            .into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn union() -> Result<()> {
+        let union = en::union_parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// union comment
+            variant filter {
+                all,
+                // variant comment
+                some(list<string>),
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(union.name, "filter");
+        assert_eq!(
+            union.attributes.comments,
+            vec![Comment::unowned(&["union comment"])]
+        );
+        assert_eq!(union.variants.len(), 2);
+        assert_eq!(union.variants[0].name, "all");
+        assert_eq!(union.variants[0].payload, None);
+        assert_eq!(union.variants[1].name, "some");
+        assert_eq!(
+            union.variants[1].attributes.comments,
+            vec![Comment::unowned(&["variant comment"])]
+        );
+        assert_eq!(
+            union.variants[1].payload,
+            Some(VariantPayload::Tuple(vec![TypeRef::new(
+                Type::new_array(TypeRef::new(Type::String, Semantics::Value)),
+                Semantics::Value
+            )]))
+        );
+        Ok(())
+    }
+}
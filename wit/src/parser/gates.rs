@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::ident::ident;
+use apyxl::model::attributes;
+use apyxl::parser::error::Error;
+
+/// Zero or more feature gates, e.g. `@since(version = 0.2.0)` or `@unstable(feature = name)`,
+/// which are parsed as user attributes.
+pub fn gates<'a>() -> impl Parser<'a, &'a str, Vec<attributes::User<'a>>, Error<'a>> + Clone {
+    let data = ident()
+        .then_ignore(just('=').padded())
+        .then(none_of(",()").repeated().at_least(1).slice().map(str::trim))
+        .map(|(key, value)| attributes::UserData::new(Some(key), value));
+    let data_list = data
+        .padded()
+        .separated_by(just(','))
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('('), just(')'));
+    just('@')
+        .ignore_then(ident())
+        .then(data_list.or_not())
+        .map(|(name, data)| attributes::User {
+            name: Cow::Borrowed(name),
+            data: data.unwrap_or_default(),
+        })
+        .padded()
+        .repeated()
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::gates;
+    use apyxl::model::attributes;
+    use apyxl::model::attributes::UserData;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn gates() -> Result<()> {
+        let gates = gates::gates()
+            .parse("@since(version = 0.2.0)\n@unstable(feature = fancy-foo)\n")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            gates,
+            vec![
+                attributes::User::new("since", vec![UserData::new(Some("version"), "0.2.0")]),
+                attributes::User::new(
+                    "unstable",
+                    vec![UserData::new(Some("feature"), "fancy-foo")]
+                ),
+            ]
+        );
+        Ok(())
+    }
+}
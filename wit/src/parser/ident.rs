@@ -0,0 +1,89 @@
+use chumsky::prelude::*;
+
+use apyxl::parser::error::Error;
+
+/// Kebab-case identifier, e.g. `descriptor-stat`. Identifiers prefixed with `%` can be keywords,
+/// e.g. `%type`, and the `%` is not part of the name.
+pub fn ident<'a>() -> impl Parser<'a, &'a str, &'a str, Error<'a>> + Clone {
+    let word = any()
+        .filter(char::is_ascii_alphabetic)
+        .then(any().filter(char::is_ascii_alphanumeric).repeated());
+    just('%')
+        .or_not()
+        .ignore_then(word.separated_by(just('-')).at_least(1).slice())
+}
+
+/// Keywords must be followed by whitespace or punctuation, e.g. `record` but not `record-id`.
+pub fn keyword<'a>(keyword: &'a str) -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    just(keyword)
+        .then(
+            any()
+                .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '-')
+                .not(),
+        )
+        .ignored()
+        .padded()
+}
+
+/// Package name without the version, e.g. `[wasi, io]` for `wasi:io@0.2.0`.
+pub fn package_name<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> + Clone {
+    ident()
+        .separated_by(just(':'))
+        .at_least(2)
+        .collect::<Vec<_>>()
+        .then_ignore(just('@').then(version()).or_not())
+}
+
+/// Semver version. This does not consume a trailing `.` before a `{`, e.g. in
+/// `use a:b/c@1.0.0.{d}`.
+pub fn version<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    any()
+        .filter(|c: &char| c.is_ascii_alphanumeric() || ".+-".contains(*c))
+        .and_is(just(".{").not())
+        .repeated()
+        .at_least(1)
+        .ignored()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ident;
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn ident() -> Result<()> {
+        for (data, expected) in [
+            ("a", "a"),
+            ("input-stream", "input-stream"),
+            ("%type", "type"),
+            ("HTTP-v2", "HTTP-v2"),
+        ] {
+            let name = ident::ident()
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(name, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn keyword_prefix() {
+        let result = ident::keyword("record").parse("record-id").into_result();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn package_name() -> Result<()> {
+        let name = ident::package_name()
+            .then_ignore(chumsky::prelude::just(".{"))
+            .parse("wasi:io@0.2.0-rc.1.{")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(name, vec!["wasi", "io"]);
+        Ok(())
+    }
+}
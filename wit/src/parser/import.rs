@@ -0,0 +1,146 @@
+use chumsky::prelude::*;
+
+use crate::parser::comment;
+use crate::parser::ident::{ident, keyword, package_name, version};
+use apyxl::parser::error::Error;
+
+/// Path to an interface, e.g. `streams` or `wasi:io/streams@0.2.0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsePath<'a> {
+    /// Interface within the same package.
+    Local(&'a str),
+    /// Interface within another package. The version is not part of the path.
+    Package {
+        package: Vec<&'a str>,
+        interface: &'a str,
+    },
+}
+
+/// `use path.{a, b as c};`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Use<'a> {
+    pub path: UsePath<'a>,
+    /// Pairs of the imported name and local name.
+    pub names: Vec<(&'a str, &'a str)>,
+}
+
+/// `package a:b@1.0.0;`
+pub fn package<'a>() -> impl Parser<'a, &'a str, Vec<&'a str>, Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("package"))
+        .ignore_then(package_name())
+        .then_ignore(just(';').padded())
+}
+
+/// `use` statements within an interface or world, e.g. `use wasi:io/streams@0.2.0.{input-stream};`
+pub fn parser<'a>() -> impl Parser<'a, &'a str, Use<'a>, Error<'a>> + Clone {
+    let alias = keyword("as").ignore_then(ident());
+    let name = ident()
+        .then_ignore(text::whitespace())
+        .then(alias.or_not())
+        .map(|(name, alias)| (name, alias.unwrap_or(name)));
+    let names = name
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('{').padded(), text::whitespace().then(just('}')));
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("use"))
+        .ignore_then(path())
+        .then_ignore(just('.'))
+        .then(names)
+        .then_ignore(just(';').padded())
+        .map(|(path, names)| Use { path, names })
+        .boxed()
+}
+
+/// Top-level `use` statements, e.g. `use wasi:io/streams as s;`, which only alias interfaces for
+/// worlds and are skipped.
+pub fn top_level<'a>() -> impl Parser<'a, &'a str, (), Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .ignore_then(keyword("use"))
+        .ignore_then(path())
+        .then(keyword("as").ignore_then(ident()).or_not())
+        .then_ignore(just(';').padded())
+        .ignored()
+}
+
+/// Path to an interface, e.g. `streams` or `wasi:io/streams@0.2.0`.
+pub fn path<'a>() -> impl Parser<'a, &'a str, UsePath<'a>, Error<'a>> + Clone {
+    let package = package_name()
+        .then_ignore(just('/'))
+        .then(ident())
+        .then_ignore(just('@').then(version()).or_not())
+        .map(|(package, interface)| UsePath::Package { package, interface });
+    choice((package, ident().map(UsePath::Local)))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::import;
+    use crate::parser::import::{Use, UsePath};
+    use apyxl::parser::test_util::wrap_test_err;
+
+    #[test]
+    fn package() -> Result<()> {
+        let package = import::package()
+            .parse("// comment\npackage wasi:filesystem@0.2.0;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(package, vec!["wasi", "filesystem"]);
+        Ok(())
+    }
+
+    #[test]
+    fn local() -> Result<()> {
+        let u = import::parser()
+            .parse("use types.{descriptor, error-code as code};")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            u,
+            Use {
+                path: UsePath::Local("types"),
+                names: vec![("descriptor", "descriptor"), ("error-code", "code")],
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn package_qualified() -> Result<()> {
+        let u = import::parser()
+            .parse("use wasi:io/streams@0.2.0.{ input-stream, output-stream, };")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            u,
+            Use {
+                path: UsePath::Package {
+                    package: vec!["wasi", "io"],
+                    interface: "streams",
+                },
+                names: vec![
+                    ("input-stream", "input-stream"),
+                    ("output-stream", "output-stream")
+                ],
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn top_level() -> Result<()> {
+        import::top_level()
+            .parse("use wasi:io/streams@0.2.0 as streams;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        Ok(())
+    }
+}
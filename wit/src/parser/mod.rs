@@ -0,0 +1,479 @@
+use anyhow::{Result, anyhow};
+use chumsky::prelude::*;
+use log::debug;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use apyxl::model::{
+    Api, EntityId, Field, Namespace, NamespaceChild, Rpc, Type, TypeRef, UNDEFINED_NAMESPACE,
+    VariantPayload,
+};
+use apyxl::parser::{Config, error};
+use apyxl::{Input, model};
+
+use crate::parser::import::{Use, UsePath};
+use crate::parser::namespace::Interface;
+
+mod comment;
+mod dto;
+mod en;
+mod gates;
+mod ident;
+mod import;
+mod namespace;
+mod rpc;
+mod ty;
+mod ty_alias;
+
+/// Parses WebAssembly Interface Type (WIT) definitions of the wasm component model.
+///
+/// The `package` of each chunk determines its namespace, e.g. `package wasi:io@0.2.0;` is parsed
+/// into namespace `wasi.io`, and each `interface` and `world` is parsed into a namespace within
+/// it. Since only one file of a package needs to declare it, chunks without a `package` are parsed
+/// into the package declared by the other chunks if there is exactly one, or the root namespace
+/// otherwise.
+#[derive(Default)]
+pub struct WitParser {}
+
+impl apyxl::Parser for WitParser {
+    fn parse<'a, I: Input + 'a>(
+        &self,
+        config: &'a Config,
+        input: &'a mut I,
+        builder: &mut model::Builder<'a>,
+    ) -> Result<()> {
+        let mut parsed_chunks = Vec::new();
+        let mut packages = HashSet::new();
+        for (chunk, data) in input.chunks() {
+            debug!("parsing chunk {:?}", chunk.relative_file_path);
+
+            let file = namespace::file(config)
+                .parse(data)
+                .into_result()
+                .map_err(|errs| {
+                    let return_err = anyhow!("errors encountered while parsing: {:?}", &errs);
+                    error::report_errors(chunk, data, errs.clone());
+                    return_err
+                })?;
+
+            if let Some(package) = &file.package {
+                packages.insert(package.clone());
+            }
+            parsed_chunks.push((chunk, file));
+        }
+
+        let default_package = if packages.len() == 1 {
+            packages.into_iter().next().unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        for (chunk, file) in parsed_chunks {
+            let chunk_path = chunk.relative_file_path.as_deref();
+            let package = file.package.unwrap_or_else(|| default_package.clone());
+
+            debug!("applying uses to chunk {:?}...", chunk_path);
+            let children = file
+                .interfaces
+                .into_iter()
+                .map(|interface| {
+                    resolve_interface(interface, &package, &HashMap::new())
+                        .map(NamespaceChild::Namespace)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let api = Api {
+                name: Cow::Borrowed(UNDEFINED_NAMESPACE),
+                children,
+                attributes: Default::default(),
+                is_virtual: false,
+                implements: vec![],
+            };
+
+            for component in &package {
+                builder.enter_namespace(component)
+            }
+
+            debug!("merging chunk {:?}...", chunk_path);
+            builder.merge_from_chunk(api, chunk);
+            builder.clear_namespace();
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies the interface's `use`s, along with those `inherited` from its world, to all types
+/// within the interface. Returns the interface's namespace, including any inline interfaces.
+fn resolve_interface<'a>(
+    interface: Interface<'a>,
+    package: &[&str],
+    inherited: &HashMap<String, EntityId>,
+) -> Result<Namespace<'a>> {
+    let mut namespace = interface.namespace;
+    let mut uses = inherited.clone();
+    uses.retain(|name, _| namespace.children.iter().all(|child| child.name() != name));
+    for u in &interface.uses {
+        for (name, local_name) in &u.names {
+            uses.insert(local_name.to_string(), use_entity_id(u, package, name));
+        }
+    }
+    apply_uses(&mut namespace, &uses)?;
+    for nested in interface.interfaces {
+        let nested = resolve_interface(nested, package, &uses)?;
+        namespace.children.push(NamespaceChild::Namespace(nested));
+    }
+    Ok(namespace)
+}
+
+/// Fully qualified [EntityId] of `name` within the [Use]'s interface.
+fn use_entity_id(u: &Use, package: &[&str], name: &str) -> EntityId {
+    let components = match &u.path {
+        UsePath::Local(interface) => package.iter().chain([interface, &name]),
+        UsePath::Package { package, interface } => package.iter().chain([interface, &name]),
+    };
+    EntityId::new_unqualified_vec(components)
+}
+
+fn apply_uses(namespace: &mut Namespace, uses: &HashMap<String, EntityId>) -> Result<()> {
+    let apply_uses_to_field =
+        |field: &mut Field| -> Result<()> { apply_uses_to_type(&mut field.ty, uses) };
+
+    let apply_uses_to_rpc = |rpc: &mut Rpc| -> Result<()> {
+        for param in &mut rpc.params {
+            apply_uses_to_type(&mut param.ty, uses)?;
+        }
+        if let Some(return_ty) = &mut rpc.return_type {
+            apply_uses_to_type(return_ty, uses)?;
+        }
+        if let Some(error_ty) = &mut rpc.error_type {
+            apply_uses_to_type(error_ty, uses)?;
+        }
+        Ok(())
+    };
+
+    for dto in namespace.dtos_mut() {
+        for field in &mut dto.fields {
+            apply_uses_to_field(field)?;
+        }
+        for rpc in &mut dto.rpcs {
+            apply_uses_to_rpc(rpc)?;
+        }
+        if let Some(dto_ns) = &mut dto.namespace {
+            apply_uses(dto_ns, uses)?;
+        }
+    }
+
+    for rpc in namespace.rpcs_mut() {
+        apply_uses_to_rpc(rpc)?;
+    }
+
+    for alias in namespace.ty_aliases_mut() {
+        apply_uses_to_type(&mut alias.target_ty, uses)?;
+    }
+
+    for union in namespace.unions_mut() {
+        for variant in &mut union.variants {
+            if let Some(VariantPayload::Tuple(tys)) = &mut variant.payload {
+                for ty in tys {
+                    apply_uses_to_type(ty, uses)?;
+                }
+            }
+        }
+    }
+
+    // note: enums have no type refs.
+
+    Ok(())
+}
+
+fn apply_uses_to_type(ty: &mut TypeRef, uses: &HashMap<String, EntityId>) -> Result<()> {
+    match &mut ty.value {
+        Type::Bool
+        | Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::U64
+        | Type::U128
+        | Type::USIZE
+        | Type::I8
+        | Type::I16
+        | Type::I32
+        | Type::I64
+        | Type::I128
+        | Type::F8
+        | Type::F16
+        | Type::F32
+        | Type::F64
+        | Type::F128
+        | Type::String
+        | Type::StringView
+        | Type::Bytes
+        | Type::User(_)
+        | Type::TypeParam(_) => {}
+
+        Type::Array(ty) => apply_uses_to_type(ty, uses)?,
+        Type::Set(ty) => apply_uses_to_type(ty, uses)?,
+        Type::FixedArray { ty, .. } => apply_uses_to_type(ty, uses)?,
+        Type::Optional(ty) => apply_uses_to_type(ty, uses)?,
+        Type::Tuple(elements) => {
+            for element in elements {
+                apply_uses_to_type(&mut element.ty, uses)?;
+            }
+        }
+        Type::Map { key, value } => {
+            apply_uses_to_type(key, uses)?;
+            apply_uses_to_type(value, uses)?;
+        }
+        Type::Api(id) => apply_uses_to_id(id, uses)?,
+        Type::Generic { ty: id, args } => {
+            apply_uses_to_id(id, uses)?;
+            for arg in args {
+                apply_uses_to_type(arg, uses)?;
+            }
+        }
+        Type::Function { params, return_ty } => {
+            for param in params {
+                apply_uses_to_type(param, uses)?;
+            }
+            if let Some(return_ty) = return_ty {
+                apply_uses_to_type(return_ty, uses)?;
+            }
+        }
+    };
+    Ok(())
+}
+
+fn apply_uses_to_id(id: &mut EntityId, uses: &HashMap<String, EntityId>) -> Result<()> {
+    let used = id.component_names().next().and_then(|name| uses.get(name));
+    if let Some(used) = used {
+        let mut rest = id.clone();
+        rest.pop_front();
+        *id = used.concat(&rest)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::parser::WitParser;
+    use apyxl::model::{Builder, Chunk, EntityId, Model, Semantics, Type, TypeRef, VariantPayload};
+    use apyxl::test_util::executor::TEST_CONFIG;
+    use apyxl::{Parser, input};
+
+    #[test]
+    fn root_namespace() -> Result<()> {
+        let data = r#"
+            interface types {
+                record dto { a: u32 }
+                enum en { a, b }
+                flags fl { a, b }
+                type alias = list<dto>;
+                rpc: func(dto: dto) -> option<en>;
+            }
+            "#;
+        run_test(&[("types.wit", data)], |model| {
+            let ns = model.api().namespace("types").unwrap();
+            assert!(ns.dto("dto").is_some(), "dto");
+            assert!(ns.en("en").is_some(), "en");
+            assert!(ns.en("fl").is_some(), "flags");
+            assert!(ns.ty_alias("alias").is_some(), "alias");
+            assert!(ns.rpc("rpc").is_some(), "rpc");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn package() -> Result<()> {
+        let a = "package wasi:io@0.2.0;\ninterface error { resource error; }";
+        let b = "interface streams { record dto {} }";
+        run_test(&[("a.wit", a), ("b.wit", b)], |model| {
+            assert!(
+                model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("wasi.io.error.error"))
+                    .is_some()
+            );
+            assert!(
+                model
+                    .api()
+                    .find_dto(&EntityId::new_unqualified("wasi.io.streams.dto"))
+                    .is_some(),
+                "package applied to chunk without package declaration"
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn local_use() -> Result<()> {
+        let data = r#"
+            package a:b;
+            interface types {
+                record id {}
+            }
+            interface api {
+                use types.{id as other-id};
+                get: func(id: other-id) -> list<other-id>;
+            }
+            "#;
+        run_test(&[("a.wit", data)], |model| {
+            let rpc = model
+                .api()
+                .find_rpc(&EntityId::new_unqualified("a.b.api.get"))
+                .unwrap();
+            let expected = EntityId::try_from("ns:a.ns:b.ns:types.d:id")?;
+            assert_eq!(rpc.params[0].ty.value.api(), Some(&expected));
+            assert_eq!(
+                rpc.return_type.as_ref().map(|ty| &ty.value),
+                Some(&Type::new_array(TypeRef::new(
+                    Type::Api(expected),
+                    Semantics::Value
+                )))
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn package_use() -> Result<()> {
+        let io = "package wasi:io@0.2.0;\ninterface streams { resource input-stream; }";
+        let http = r#"
+            package wasi:http@0.2.0;
+            interface types {
+                use wasi:io/streams@0.2.0.{input-stream};
+                record body { %stream: own<input-stream> }
+            }
+            "#;
+        run_test(&[("io.wit", io), ("http.wit", http)], |model| {
+            let dto = model
+                .api()
+                .find_dto(&EntityId::new_unqualified("wasi.http.types.body"))
+                .unwrap();
+            let expected = EntityId::try_from("ns:wasi.ns:io.ns:streams.d:input-stream")?;
+            assert_eq!(dto.fields[0].ty.value.api(), Some(&expected));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn world_use_inherited() -> Result<()> {
+        let data = r#"
+            package a:b;
+            interface types {
+                record req {}
+            }
+            world w {
+                use types.{req};
+                export run: func(r: req);
+                export inline: interface {
+                    handle: func(r: req);
+                }
+            }
+            "#;
+        run_test(&[("a.wit", data)], |model| {
+            let expected = EntityId::try_from("ns:a.ns:b.ns:types.d:req")?;
+            let run = model
+                .api()
+                .find_rpc(&EntityId::new_unqualified("a.b.w.run"))
+                .unwrap();
+            assert_eq!(run.params[0].ty.value.api(), Some(&expected));
+            let handle = model
+                .api()
+                .find_rpc(&EntityId::new_unqualified("a.b.w.inline.handle"))
+                .unwrap();
+            assert_eq!(handle.params[0].ty.value.api(), Some(&expected));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn local_use_in_result() -> Result<()> {
+        let data = r#"
+            package a:b;
+            interface types {
+                enum error-code { access }
+                record id {}
+            }
+            interface api {
+                use types.{error-code, id};
+                get: func() -> result<id, error-code>;
+            }
+            "#;
+        run_test(&[("a.wit", data)], |model| {
+            let rpc = model
+                .api()
+                .find_rpc(&EntityId::new_unqualified("a.b.api.get"))
+                .unwrap();
+            let id = EntityId::try_from("ns:a.ns:b.ns:types.d:id")?;
+            let error_code = EntityId::try_from("ns:a.ns:b.ns:types.e:error-code")?;
+            assert_eq!(
+                rpc.return_type.as_ref().and_then(|ty| ty.value.api()),
+                Some(&id)
+            );
+            assert_eq!(
+                rpc.error_type.as_ref().and_then(|ty| ty.value.api()),
+                Some(&error_code)
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn local_use_in_variant() -> Result<()> {
+        let data = r#"
+            package a:b;
+            interface types {
+                record id {}
+            }
+            interface api {
+                use types.{id};
+                variant filter { all, some(id) }
+            }
+            "#;
+        run_test(&[("a.wit", data)], |model| {
+            let id = EntityId::try_from("ns:a.ns:b.ns:types.d:id")?;
+            let union = model
+                .api()
+                .find_union(&EntityId::new_unqualified("a.b.api.filter"))
+                .unwrap();
+            assert_eq!(
+                union.variants[1].payload,
+                Some(VariantPayload::Tuple(vec![TypeRef::new(
+                    Type::Api(id),
+                    Semantics::Value
+                )]))
+            );
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn unsupported_types() {
+        for data in [
+            "interface i { f: func() -> result<u32>; }",
+            "interface i { type t = stream<u8>; }",
+        ] {
+            let result = run_test(&[("a.wit", data)], |_| Ok(()));
+            assert!(result.is_err(), "{}", data);
+        }
+    }
+
+    fn run_test(
+        inputs: &[(&str, &str)],
+        assertions: impl FnOnce(&Model) -> Result<()>,
+    ) -> Result<()> {
+        let mut input = input::ChunkBuffer::new();
+        for (path, data) in inputs {
+            input.add_chunk(Chunk::with_relative_file_path(path), data);
+        }
+        let mut builder = Builder::default();
+        WitParser::default().parse(&TEST_CONFIG, &mut input, &mut builder)?;
+        let model = builder.build().unwrap();
+
+        assertions(&model)?;
+        Ok(())
+    }
+}
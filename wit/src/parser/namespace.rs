@@ -0,0 +1,322 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::gates::gates;
+use crate::parser::ident::{ident, keyword};
+use crate::parser::import::{Use, path};
+use crate::parser::{comment, dto, en, import, rpc, ty_alias};
+use apyxl::model::{Attributes, Namespace, NamespaceChild};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// The contents of a single file (chunk).
+pub struct File<'a> {
+    /// Package components from the `package` declaration, e.g. `[wasi, io]`.
+    pub package: Option<Vec<&'a str>>,
+    pub interfaces: Vec<Interface<'a>>,
+}
+
+/// An `interface` or `world`, which are both parsed as namespaces.
+pub struct Interface<'a> {
+    pub namespace: Namespace<'a>,
+    pub uses: Vec<Use<'a>>,
+    /// Interfaces declared inline within a world, e.g. `export name: interface { ... }`.
+    pub interfaces: Vec<Interface<'a>>,
+}
+
+enum Item<'a> {
+    Child(Box<NamespaceChild<'a>>),
+    Use(Use<'a>),
+    Interface(Box<Interface<'a>>),
+    Skipped,
+}
+
+pub fn file<'a>(config: &'a Config) -> impl Parser<'a, &'a str, File<'a>, Error<'a>> {
+    let item = choice((
+        interface(config).map(Some),
+        world(config).map(Some),
+        import::top_level().map(|_| None),
+    ));
+    import::package()
+        .or_not()
+        .then(item.repeated().collect::<Vec<_>>())
+        .then_ignore(comment::multi().padded())
+        .then_ignore(end())
+        .map(|(package, items)| File {
+            package,
+            interfaces: items.into_iter().flatten().collect(),
+        })
+}
+
+/// `interface name { ... }`. Functions declared within an interface are static.
+pub fn interface<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Interface<'a>, Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("interface"))
+        .then(ident())
+        .then(body(items(config)))
+        .map(|(((comments, user), name), items)| {
+            new_interface(
+                name,
+                Attributes {
+                    comments,
+                    user,
+                    ..Default::default()
+                },
+                items,
+            )
+        })
+        .boxed()
+}
+
+/// `world name { ... }`. Imported and exported functions are parsed as static rpcs and inline
+/// interfaces as nested namespaces. Imports and exports of other interfaces and `include`s are
+/// skipped.
+pub fn world<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Interface<'a>, Error<'a>> + Clone {
+    let inline_interface = ident()
+        .then_ignore(just(':').padded())
+        .then_ignore(keyword("interface"))
+        .then(body(items(config)))
+        .map(|(name, items)| new_interface(name, Attributes::default(), items));
+    let import_export = comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(choice((keyword("import"), keyword("export"))))
+        .then(choice((
+            inline_interface.map(|interface| Item::Interface(Box::new(interface))),
+            rpc::func(config).map(|mut rpc| {
+                rpc.is_static = true;
+                Item::Child(Box::new(NamespaceChild::Rpc(rpc)))
+            }),
+            path()
+                .then_ignore(just(';').padded())
+                .map(|_| Item::Skipped),
+        )))
+        .map(|((comments, user), mut item)| {
+            let attributes = match &mut item {
+                Item::Interface(interface) => Some(&mut interface.namespace.attributes),
+                Item::Child(child) => match child.as_mut() {
+                    NamespaceChild::Rpc(rpc) => Some(&mut rpc.attributes),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(attributes) = attributes {
+                attributes.comments = comments;
+                attributes.user = user;
+            }
+            item
+        });
+    let include = comment::multi()
+        .padded()
+        .then(keyword("include"))
+        .then(path())
+        .then(
+            keyword("with")
+                .then(none_of('}').repeated())
+                .then(just('}'))
+                .or_not(),
+        )
+        .then(just(';').padded().or_not())
+        .map(|_| Item::Skipped);
+    let item = choice((import_export, include, items(config))).boxed();
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("world"))
+        .then(ident())
+        .then(body(item))
+        .map(|(((comments, user), name), items)| {
+            new_interface(
+                name,
+                Attributes {
+                    comments,
+                    user,
+                    ..Default::default()
+                },
+                items,
+            )
+        })
+        .boxed()
+}
+
+/// Items shared by interfaces and worlds.
+fn items<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Item<'a>, Error<'a>> + Clone {
+    choice((
+        import::parser().map(Item::Use),
+        ty_alias::parser(config).map(|c| Item::Child(Box::new(NamespaceChild::TypeAlias(c)))),
+        dto::record(config).map(|c| Item::Child(Box::new(NamespaceChild::Dto(c)))),
+        dto::resource(config).map(|c| Item::Child(Box::new(NamespaceChild::Dto(c)))),
+        en::parser().map(|c| Item::Child(Box::new(NamespaceChild::Enum(c)))),
+        en::union_parser(config).map(|c| Item::Child(Box::new(NamespaceChild::Union(c)))),
+        rpc::parser(config).map(|mut c| {
+            c.is_static = true;
+            Item::Child(Box::new(NamespaceChild::Rpc(c)))
+        }),
+    ))
+    .boxed()
+}
+
+fn body<'a>(
+    item: impl Parser<'a, &'a str, Item<'a>, Error<'a>> + Clone,
+) -> impl Parser<'a, &'a str, Vec<Item<'a>>, Error<'a>> + Clone {
+    item.repeated()
+        .collect::<Vec<_>>()
+        .then_ignore(comment::multi().padded())
+        .delimited_by(just('{').padded(), just('}').padded())
+}
+
+fn new_interface<'a>(
+    name: &'a str,
+    attributes: Attributes<'a>,
+    items: Vec<Item<'a>>,
+) -> Interface<'a> {
+    let mut interface = Interface {
+        namespace: Namespace {
+            name: Cow::Borrowed(name),
+            attributes,
+            ..Default::default()
+        },
+        uses: vec![],
+        interfaces: vec![],
+    };
+    for item in items {
+        match item {
+            Item::Child(child) => interface.namespace.children.push(*child),
+            Item::Use(u) => interface.uses.push(u),
+            Item::Interface(i) => interface.interfaces.push(*i),
+            Item::Skipped => {}
+        }
+    }
+    interface
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::namespace;
+    use apyxl::model::{Comment, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn interface() -> Result<()> {
+        let interface = namespace::interface(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// interface comment
+            @since(version = 0.2.0)
+            interface types {
+                use wasi:io/streams@0.2.0.{input-stream};
+
+                type size = u64;
+                record stat { size: size }
+                resource descriptor;
+                enum error-code { access }
+                flags permissions { read }
+                variant kind { file, directory }
+                variant entry { none, named(string) }
+                stat: func(d: borrow<descriptor>) -> stat;
+                // trailing comment
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let ns = &interface.namespace;
+        assert_eq!(ns.name, "types");
+        assert_eq!(
+            ns.attributes.comments,
+            vec![Comment::unowned(&["interface comment"])]
+        );
+        assert_eq!(
+            ns.attributes.user,
+            vec![attributes::User::new(
+                "since",
+                vec![attributes::UserData::new(Some("version"), "0.2.0")]
+            )]
+        );
+        assert_eq!(interface.uses.len(), 1);
+        assert!(ns.ty_alias("size").is_some());
+        assert!(ns.dto("stat").is_some());
+        assert!(ns.dto("descriptor").is_some());
+        assert!(ns.en("error-code").is_some());
+        assert!(ns.en("permissions").is_some());
+        assert!(ns.en("kind").is_some());
+        assert!(ns.union("entry").is_some());
+        assert!(ns.rpc("stat").unwrap().is_static);
+        assert_eq!(ns.children.len(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn world() -> Result<()> {
+        let world = namespace::world(&TEST_CONFIG)
+            .parse(
+                r#"
+            world proxy {
+                include wasi:cli/imports@0.2.0;
+                include other with { a as b }
+                import wasi:io/streams@0.2.0;
+                export handler;
+                use types.{request};
+                record config { name: string }
+                /// import comment
+                import log: func(msg: string);
+                export run: func(req: request) -> u32;
+                export custom: interface {
+                    use types.{response};
+                    get: func() -> response;
+                }
+            }
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        let ns = &world.namespace;
+        assert_eq!(ns.name, "proxy");
+        assert_eq!(world.uses.len(), 1);
+        assert!(ns.dto("config").is_some());
+        let log = ns.rpc("log").unwrap();
+        assert!(log.is_static);
+        assert_eq!(
+            log.attributes.comments,
+            vec![Comment::unowned(&["import comment"])]
+        );
+        assert!(ns.rpc("run").is_some());
+        assert_eq!(ns.children.len(), 3);
+        assert_eq!(world.interfaces.len(), 1);
+        assert_eq!(world.interfaces[0].namespace.name, "custom");
+        assert_eq!(world.interfaces[0].uses.len(), 1);
+        assert!(world.interfaces[0].namespace.rpc("get").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn file() -> Result<()> {
+        let file = namespace::file(&TEST_CONFIG)
+            .parse(
+                r#"
+            // license
+            package wasi:http@0.2.0;
+
+            use wasi:io/streams@0.2.0 as streams;
+
+            interface types {}
+            world proxy {}
+            // end comment
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(file.package, Some(vec!["wasi", "http"]));
+        assert_eq!(file.interfaces.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+
+use chumsky::prelude::*;
+
+use crate::parser::gates::gates;
+use crate::parser::ident::{ident, keyword};
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, Field, Rpc, RpcKind};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// Function declaration with comments and feature gates, e.g. `name: func(a: u32) -> string;`.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Rpc<'a>, Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then(func(config))
+        .map(|((comments, user), mut rpc)| {
+            rpc.attributes.comments = comments;
+            rpc.attributes.user = user;
+            rpc
+        })
+        .boxed()
+}
+
+/// Function declaration without comments or feature gates, e.g. the part of
+/// `export run: func();` following `export`. Functions are only parsed as static if they are
+/// declared `static`, e.g. within a resource.
+///
+/// A `result<T, E>` return type is parsed as the return type `T` and the error type `E`.
+pub fn func<'a>(config: &'a Config) -> impl Parser<'a, &'a str, Rpc<'a>, Error<'a>> + Clone {
+    let return_ty = just("->").padded().ignore_then(choice((
+        ty::result(config).map(|(return_type, error_type)| (return_type, Some(error_type))),
+        ty::parser(config).map(|ty| (Some(ty), None)),
+    )));
+    ident()
+        .then_ignore(just(':').padded())
+        .then(keyword("static").or_not())
+        .then_ignore(keyword("async").or_not())
+        .then_ignore(keyword("func"))
+        .then(params(config))
+        .then(return_ty.or_not())
+        .then_ignore(just(';').padded())
+        .map(|(((name, is_static), params), return_ty)| {
+            let (return_type, error_type) = return_ty.unwrap_or_default();
+            Rpc {
+                name: Cow::Borrowed(name),
+                type_params: vec![],
+                params,
+                return_type,
+                error_type,
+                attributes: Attributes::default(),
+                is_static: is_static.is_some(),
+                kind: RpcKind::default(),
+            }
+        })
+        .boxed()
+}
+
+/// `(a: T, b: U)`
+pub fn params<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, Vec<Field<'a>>, Error<'a>> + Clone {
+    let param = comment::multi()
+        .padded()
+        .then(ident())
+        .then_ignore(just(':').padded())
+        .then(ty::parser(config))
+        .map(|((comments, name), ty)| Field {
+            name,
+            ty,
+            attributes: Attributes {
+                comments,
+                ..Default::default()
+            },
+            is_static: false,
+            value: None,
+            default: None,
+        });
+    param
+        .separated_by(just(',').padded())
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .delimited_by(just('(').padded(), just(')').padded())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::rpc;
+    use apyxl::model::{Comment, EntityId, Semantics, Type, TypeRef, attributes};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn declaration() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("rpc-name: func();")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.name, "rpc-name");
+        assert!(rpc.params.is_empty());
+        assert!(rpc.return_type.is_none());
+        assert!(!rpc.is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// rpc comment
+            @since(version = 0.2.0)
+            rpc-name: async func(
+                a: u32,
+                // param comment
+                %type: option<dto>,
+            ) -> list<dto>;
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(
+            rpc.attributes.comments,
+            vec![Comment::unowned(&["rpc comment"])]
+        );
+        assert_eq!(
+            rpc.attributes.user,
+            vec![attributes::User::new(
+                "since",
+                vec![attributes::UserData::new(Some("version"), "0.2.0")]
+            )]
+        );
+        assert_eq!(rpc.params.len(), 2);
+        assert_eq!(rpc.params[0].name, "a");
+        assert_eq!(rpc.params[0].ty.value, Type::U32);
+        assert_eq!(rpc.params[1].name, "type");
+        assert_eq!(
+            rpc.params[1].attributes.comments,
+            vec![Comment::unowned(&["param comment"])]
+        );
+        assert_eq!(
+            rpc.params[1].ty.value,
+            Type::new_optional(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("dto")),
+                Semantics::Value
+            ))
+        );
+        assert_eq!(
+            rpc.return_type.map(|ty| ty.value),
+            Some(Type::new_array(TypeRef::new(
+                Type::Api(EntityId::new_unqualified("dto")),
+                Semantics::Value
+            )))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn static_func() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("create: static func() -> own<res>;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.is_static);
+        Ok(())
+    }
+
+    #[test]
+    fn result() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("rpc-name: func() -> result<string, error-code>;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(rpc.return_type.map(|ty| ty.value), Some(Type::String));
+        assert_eq!(
+            rpc.error_type.map(|ty| ty.value),
+            Some(Type::Api(EntityId::new_unqualified("error-code")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn result_without_ok_type() -> Result<()> {
+        let rpc = rpc::parser(&TEST_CONFIG)
+            .parse("rpc-name: func() -> result<_, error-code>;")
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert!(rpc.return_type.is_none());
+        assert!(rpc.error_type.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn result_without_error_type_unsupported() {
+        let result = rpc::parser(&TEST_CONFIG)
+            .parse("rpc-name: func() -> result<string>;")
+            .into_result();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use chumsky::prelude::*;
+
+use crate::parser::ident::{ident, keyword};
+use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// WIT types, e.g. `u32`, `list<string>`, `option<borrow<descriptor>>`, or `error-code`.
+///
+/// `char` is parsed as `Type::U32` since it is a Unicode scalar value. `own<T>` is parsed as `T`
+/// and `borrow<T>` as `T` with [Semantics::Ref].
+///
+/// `tuple<T, U>` is parsed as a tuple of unnamed elements. `result` types can only be represented
+/// as the return type of an rpc (see [result]), and `future` and `stream` types not at all, so
+/// they are parsed but emit an error.
+pub fn parser<'a>(config: &'a Config) -> impl Parser<'a, &'a str, TypeRef, Error<'a>> + Clone {
+    recursive(|nested| {
+        let primitive = choice((
+            keyword("bool").to(Type::Bool),
+            keyword("s8").to(Type::I8),
+            keyword("s16").to(Type::I16),
+            keyword("s32").to(Type::I32),
+            keyword("s64").to(Type::I64),
+            keyword("u8").to(Type::U8),
+            keyword("u16").to(Type::U16),
+            keyword("u32").to(Type::U32),
+            keyword("u64").to(Type::U64),
+            keyword("f32").to(Type::F32),
+            keyword("float32").to(Type::F32),
+            keyword("f64").to(Type::F64),
+            keyword("float64").to(Type::F64),
+            keyword("char").to(Type::U32),
+            keyword("string").to(Type::String),
+        ))
+        .map(|ty| TypeRef::new(ty, Semantics::Value));
+
+        let arg = || {
+            nested
+                .clone()
+                .delimited_by(just('<').padded(), just('>').padded())
+        };
+        let list = keyword("list")
+            .ignore_then(arg())
+            .map(|ty| TypeRef::new(Type::new_array(ty), Semantics::Value));
+        let option = keyword("option")
+            .ignore_then(arg())
+            .map(|ty| TypeRef::new(Type::new_optional(ty), Semantics::Value));
+        let own = keyword("own").ignore_then(arg());
+        let borrow = keyword("borrow")
+            .ignore_then(arg())
+            .map(|ty| TypeRef::new(ty.value, Semantics::Ref));
+
+        let tuple = keyword("tuple")
+            .ignore_then(
+                nested
+                    .clone()
+                    .separated_by(just(',').padded())
+                    .allow_trailing()
+                    .at_least(1)
+                    .collect::<Vec<_>>()
+                    .delimited_by(just('<').padded(), just('>').padded()),
+            )
+            .map(|tys| TypeRef::new(Type::new_tuple(tys), Semantics::Value));
+
+        let unsupported_args = choice((nested.clone().ignored(), just('_').padded().ignored()))
+            .separated_by(just(','))
+            .allow_trailing()
+            .delimited_by(just('<').padded(), just('>').padded());
+        let unsupported = choice((
+            keyword("result").to("result"),
+            keyword("future").to("future"),
+            keyword("stream").to("stream"),
+        ))
+        .then_ignore(unsupported_args.or_not())
+        .validate(|name, span, emitter| {
+            emitter.emit(Rich::custom(
+                span,
+                format!("`{}` types are not supported by the model", name),
+            ));
+            TypeRef::new(Type::Api(EntityId::new_unqualified(name)), Semantics::Value)
+        });
+
+        choice((
+            primitive,
+            list,
+            option,
+            own,
+            borrow,
+            tuple,
+            unsupported,
+            user_ty(config).map(|name| TypeRef::new(Type::User(name), Semantics::Value)),
+            ident().padded().map(|name| {
+                TypeRef::new(Type::Api(EntityId::new_unqualified(name)), Semantics::Value)
+            }),
+        ))
+        .boxed()
+    })
+}
+
+/// `result<T, E>` or `result<_, E>` as the return and error types of an rpc. Results without an
+/// error type, e.g. `result<T>`, are not matched since the model can't represent them.
+pub fn result<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, (Option<TypeRef>, TypeRef), Error<'a>> + Clone {
+    keyword("result").ignore_then(
+        choice((just('_').padded().to(None), parser(config).map(Some)))
+            .then_ignore(just(',').padded())
+            .then(parser(config))
+            .delimited_by(just('<').padded(), just('>').padded()),
+    )
+}
+
+fn user_ty<'a>(config: &'a Config) -> impl Parser<'a, &'a str, String, Error<'a>> + Clone {
+    custom(move |input| {
+        for (i, ty) in config.user_types.iter().enumerate() {
+            let marker = input.save();
+            match input.parse(keyword(ty.parse.as_str())) {
+                Ok(_) => {
+                    return Ok(ty.name.to_string());
+                }
+                Err(err) => {
+                    input.rewind(marker);
+                    if i == config.user_types.len() - 1 {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        // Just need _any error_.
+        Err(chumsky::error::Error::<&str>::expected_found(
+            None,
+            None,
+            input.span_since(input.offset()),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+    use lazy_static::lazy_static;
+
+    use crate::parser::ty;
+    use apyxl::model::{EntityId, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::parser::{Config, UserType};
+
+    lazy_static! {
+        static ref TY_TEST_CONFIG: Config = Config {
+            user_types: vec![UserType {
+                parse: "datetime".to_string(),
+                name: "datetime".to_string(),
+            }],
+            disabled_well_known_types: vec![],
+            enable_parse_private: true,
+        };
+    }
+
+    macro_rules! test {
+        ($name: ident, $data:literal, $expected:expr) => {
+            #[test]
+            fn $name() -> Result<()> {
+                run_test($data, $expected)
+            }
+        };
+    }
+
+    test!(bool, "bool", ty(Type::Bool));
+    test!(s8, "s8", ty(Type::I8));
+    test!(s16, "s16", ty(Type::I16));
+    test!(s32, "s32", ty(Type::I32));
+    test!(s64, "s64", ty(Type::I64));
+    test!(u8, "u8", ty(Type::U8));
+    test!(u16, "u16", ty(Type::U16));
+    test!(u32, "u32", ty(Type::U32));
+    test!(u64, "u64", ty(Type::U64));
+    test!(f32, "f32", ty(Type::F32));
+    test!(float32, "float32", ty(Type::F32));
+    test!(f64, "f64", ty(Type::F64));
+    test!(float64, "float64", ty(Type::F64));
+    test!(char, "char", ty(Type::U32));
+    test!(string, "string", ty(Type::String));
+    test!(user, "datetime", ty(Type::User("datetime".to_string())));
+    test!(
+        api,
+        "input-stream",
+        ty(Type::Api(EntityId::new_unqualified("input-stream")))
+    );
+    test!(
+        keyword_prefixed_api,
+        "string-list",
+        ty(Type::Api(EntityId::new_unqualified("string-list")))
+    );
+    test!(
+        list,
+        "list< option<u8> >",
+        ty(Type::new_array(ty(Type::new_optional(ty(Type::U8)))))
+    );
+    test!(
+        own,
+        "own<descriptor>",
+        ty(Type::Api(EntityId::new_unqualified("descriptor")))
+    );
+    test!(
+        borrow,
+        "borrow<descriptor>",
+        TypeRef::new(
+            Type::Api(EntityId::new_unqualified("descriptor")),
+            Semantics::Ref
+        )
+    );
+
+    test!(
+        tuple,
+        "tuple<u32, list<string>>",
+        ty(Type::new_tuple([
+            ty(Type::U32),
+            ty(Type::new_array(ty(Type::String)))
+        ]))
+    );
+
+    #[test]
+    fn result() -> Result<()> {
+        for (data, expected) in [
+            (
+                "result<list<u8>, error-code>",
+                (
+                    Some(ty(Type::new_array(ty(Type::U8)))),
+                    api_ty("error-code"),
+                ),
+            ),
+            ("result<_, error-code>", (None, api_ty("error-code"))),
+        ] {
+            let result = ty::result(&TY_TEST_CONFIG)
+                .parse(data)
+                .into_result()
+                .map_err(wrap_test_err)?;
+            assert_eq!(result, expected, "{}", data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported() {
+        for data in [
+            "result",
+            "result<_, error-code>",
+            "result<list<u8>, error-code>",
+            "stream<u8>",
+        ] {
+            let result = ty::parser(&TY_TEST_CONFIG).parse(data).into_result();
+            let errs = result.expect_err(data);
+            assert!(
+                errs[0].to_string().contains("not supported"),
+                "{}: {:?}",
+                data,
+                errs
+            );
+        }
+    }
+
+    fn run_test(data: &'static str, expected: TypeRef) -> Result<()> {
+        let ty = ty::parser(&TY_TEST_CONFIG)
+            .parse(data)
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(ty, expected);
+        Ok(())
+    }
+
+    fn ty(ty: Type) -> TypeRef {
+        TypeRef::new(ty, Semantics::Value)
+    }
+
+    fn api_ty(name: &str) -> TypeRef {
+        ty(Type::Api(EntityId::new_unqualified(name)))
+    }
+}
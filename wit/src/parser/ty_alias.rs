@@ -0,0 +1,67 @@
+use chumsky::prelude::*;
+
+use crate::parser::gates::gates;
+use crate::parser::ident::{ident, keyword};
+use crate::parser::{comment, ty};
+use apyxl::model::{Attributes, TypeAlias};
+use apyxl::parser::Config;
+use apyxl::parser::error::Error;
+
+/// `type name = T;`
+pub fn parser<'a>(
+    config: &'a Config,
+) -> impl Parser<'a, &'a str, TypeAlias<'a>, Error<'a>> + Clone {
+    comment::multi()
+        .padded()
+        .then(gates())
+        .then_ignore(keyword("type"))
+        .then(ident())
+        .then_ignore(just('=').padded())
+        .then(ty::parser(config))
+        .then_ignore(just(';').padded())
+        .map(|(((comments, user), name), target_ty)| TypeAlias {
+            name,
+            type_params: vec![],
+            target_ty,
+            attributes: Attributes {
+                comments,
+                user,
+                ..Default::default()
+            },
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chumsky::Parser;
+
+    use crate::parser::ty_alias;
+    use apyxl::model::{Comment, Semantics, Type, TypeRef};
+    use apyxl::parser::test_util::wrap_test_err;
+    use apyxl::test_util::executor::TEST_CONFIG;
+
+    #[test]
+    fn alias() -> Result<()> {
+        let alias = ty_alias::parser(&TEST_CONFIG)
+            .parse(
+                r#"
+            /// alias comment
+            type bytes = list<u8>;
+            "#,
+            )
+            .into_result()
+            .map_err(wrap_test_err)?;
+        assert_eq!(alias.name, "bytes");
+        assert_eq!(
+            alias.target_ty.value,
+            Type::new_array(TypeRef::new(Type::U8, Semantics::Value))
+        );
+        assert_eq!(
+            alias.attributes.comments,
+            vec![Comment::unowned(&["alias comment"])]
+        );
+        Ok(())
+    }
+}